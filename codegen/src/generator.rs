@@ -1,3 +1,5 @@
+use std::collections::BTreeSet;
+
 use inflector::Inflector;
 
 use crate::json_schema::*;
@@ -19,6 +21,50 @@ pub static AUTOGEN_HEADER: &str = "
 
 ";
 
+/// Modules with hand-written helpers in an `ext` module, re-exported alongside the generated
+/// functions.
+const EXTENDED_MODULES: &[&str] = &[
+    "admin.usergroups",
+    "chat",
+    "conversations",
+    "dnd",
+    "files",
+    "reactions",
+    "stars",
+    "team",
+    "usergroups",
+    "users",
+    "views",
+];
+
+/// Traits derived by every response type and the objects it nests, so responses can be compared
+/// and stored as JSON again.
+const RESPONSE_DERIVES: &str = "Clone, Debug, Deserialize, PartialEq, Serialize";
+
+/// Traits derived by particular types on top of `RESPONSE_DERIVES`. A type can only derive `Eq` and
+/// `Hash` when all of its fields can, which rules out numbers, maps and most objects.
+const EXTRA_DERIVES: &[(&str, &str)] = &[
+    ("ChannelPurpose", "Eq, Hash"),
+    ("ChannelTopic", "Eq, Hash"),
+    ("ConversationPurpose", "Eq, Hash"),
+    ("ConversationTopic", "Eq, Hash"),
+    ("Paging", "Eq, Hash"),
+    ("Reaction", "Eq, Hash"),
+    ("ResponseMetadata", "Eq, Hash"),
+];
+
+/// The traits a response type derives.
+fn response_derives(ty_name: &str) -> String {
+    match EXTRA_DERIVES.iter().find(|&&(name, _)| name == ty_name) {
+        Some(&(_, extra)) => format!("{}, {}", RESPONSE_DERIVES, extra),
+        None => RESPONSE_DERIVES.to_owned(),
+    }
+}
+
+/// Undocumented modules, which are only compiled with the `unstable-api` feature since Slack may
+/// change or remove them without notice.
+const UNSTABLE_MODULES: &[&str] = &["users.admin"];
+
 #[derive(Deserialize, Clone, Debug)]
 pub struct Module {
     pub name: String,
@@ -27,7 +73,7 @@ pub struct Module {
 }
 
 impl Module {
-    pub fn generate(&self, gen_mode: GenMode) -> String {
+    pub fn generate(&self, gen_mode: GenMode, shared: &SharedObjects) -> String {
         let type_imports = format!(
             "
             #[allow(unused_imports)]
@@ -38,7 +84,7 @@ impl Module {
         "
         );
 
-        let imports = match gen_mode {
+        let mut imports = match gen_mode {
             GenMode::Types => vec![type_imports],
             GenMode::Sync => vec![
                 format!("use crate::sync::requests::SlackWebRequestSender;"),
@@ -55,6 +101,19 @@ impl Module {
                 ),
             ],
         };
+        if EXTENDED_MODULES.contains(&&self.name[..]) {
+            match gen_mode {
+                GenMode::Types => {}
+                GenMode::Sync => imports.push(format!(
+                    "pub use crate::sync::ext::{}::*;",
+                    self.get_safe_name()
+                )),
+                GenMode::Async => imports.push(format!(
+                    "pub use crate::async_impl::ext::{}::*;",
+                    self.get_safe_name()
+                )),
+            }
+        }
 
         format!(
             "{header}
@@ -73,15 +132,94 @@ impl Module {
             methods = self
                 .methods
                 .iter()
-                .map(|p| p.generate(gen_mode))
+                .map(|p| p.generate(gen_mode, shared))
                 .collect::<Vec<String>>()
                 .join("\n"),
             imports = imports.join("\n"),
         )
     }
 
+    pub fn is_unstable(&self) -> bool {
+        UNSTABLE_MODULES.contains(&&self.name[..])
+    }
+
     pub fn get_safe_name(&self) -> String {
-        self.name.replace('.', "_")
+        self.name
+            .split('.')
+            .map(|part| part.to_snake_case())
+            .collect::<Vec<_>>()
+            .join("_")
+    }
+}
+
+/// Objects nested with the same name and fields in several responses. They're generated once, next
+/// to the object types, and the modules nesting them alias them, so the responses share a type.
+#[derive(Default)]
+pub struct SharedObjects {
+    objects: Vec<JsonObject>,
+}
+
+impl SharedObjects {
+    /// Finds the objects the modules' responses share. Objects named like one of `object_names`,
+    /// or like another shared object, are left in their modules so names stay unique.
+    pub fn collect(modules: &[Module], object_names: &BTreeSet<String>) -> Self {
+        let response_types = modules
+            .iter()
+            .flat_map(|module| module.methods.iter())
+            .filter(|method| method.is_wrapped())
+            .map(|method| {
+                let type_prefix = method.name.split('.').last().unwrap().to_pascal_case();
+                method
+                    .response
+                    .get_response_type(&(type_prefix + "Response"))
+            })
+            .collect::<Vec<_>>();
+
+        let mut counts: Vec<(&JsonObject, usize)> = vec![];
+        for obj in response_types.iter().flat_map(|ty| ty.nested_objects()) {
+            match counts.iter_mut().find(|count| count.0 == obj) {
+                Some(count) => count.1 += 1,
+                None => counts.push((obj, 1)),
+            }
+        }
+        let repeated = counts
+            .into_iter()
+            .filter(|&(_, count)| count > 1)
+            .map(|(obj, _)| obj)
+            .collect::<Vec<_>>();
+
+        let mut objects = repeated
+            .iter()
+            .filter(|obj| {
+                !object_names.contains(&obj.name)
+                    && repeated.iter().filter(|o| o.name == obj.name).count() == 1
+            })
+            .map(|&obj| obj.clone())
+            .collect::<Vec<_>>();
+        objects.sort_by_key(|obj| obj.name.clone());
+        SharedObjects { objects }
+    }
+
+    /// Replaces the shared objects nested in `ty` with references to them, returning the names of
+    /// those replaced.
+    pub fn hoist(&self, ty: &mut PropType) -> Vec<String> {
+        ty.hoist(&self.objects)
+    }
+
+    /// The shared objects, each referring to the shared objects it nests.
+    pub fn to_code(&self) -> String {
+        self.objects
+            .iter()
+            .map(|obj| {
+                let mut ty = PropType::Obj(obj.clone());
+                self.hoist(&mut ty);
+                match ty {
+                    PropType::Obj(ref o) => o.to_code(),
+                    _ => unreachable!(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 }
 
@@ -117,12 +255,81 @@ pub struct Method {
     pub documentation_url: String,
     pub params: Vec<Param>,
     pub response: Response,
+    /// Why the method shouldn't be used anymore, and what to use instead.
+    #[serde(default)]
+    pub deprecated: Option<String>,
 }
 
 impl Method {
-    pub fn generate(&self, gen_mode: GenMode) -> String {
+    fn documentation(&self) -> String {
+        let mut docs = format_docs(
+            "///",
+            &[
+                &self.description,
+                "",
+                &format!("Wraps {}", self.documentation_url),
+            ]
+            .join("\n"),
+        );
+        if let Some(ref note) = self.deprecated {
+            docs.push_str(&format!("#[deprecated(note = {:?})]\n", note));
+        }
+        // The templates start the function on the next line.
+        docs.pop();
+        docs
+    }
+
+    /// Whether a function is generated for the method.
+    pub fn is_wrapped(&self) -> bool {
         // HACK: these methods requires multipart support, which is not yet supported by this library
-        if self.name == "files.upload" || self.name == "users.setPhoto" {
+        self.name != "files.upload" && self.name != "users.setPhoto"
+    }
+
+    /// The method's entry in the `METHODS` registry, or `None` if it isn't wrapped.
+    pub fn registry_entry(&self, unstable: bool) -> Option<String> {
+        if !self.is_wrapped() {
+            return None;
+        }
+        let params = self
+            .params
+            .iter()
+            .filter(|p| p.ty != "auth_token") // passed in method params instead
+            .filter(|p| p.name != "simple_latest") // HACK: simple_latest breaks deserialization
+            .map(|p| {
+                format!(
+                    "            ParamInfo {{
+                name: {name:?},
+                required: {required},
+                description: {description:?},
+            }},
+",
+                    name = p.name,
+                    required = !p.optional,
+                    description = p.description,
+                )
+            })
+            .collect::<String>();
+        Some(format!(
+            "    MethodInfo {{
+        name: {name:?},
+        description: {description:?},
+        unstable: {unstable},
+        params: {params},
+    }},
+",
+            name = self.name,
+            description = self.description,
+            unstable = unstable,
+            params = if params.is_empty() {
+                "&[]".to_owned()
+            } else {
+                format!("&[\n{}        ]", params)
+            },
+        ))
+    }
+
+    pub fn generate(&self, gen_mode: GenMode, shared: &SharedObjects) -> String {
+        if !self.is_wrapped() {
             return String::new();
         }
 
@@ -131,23 +338,23 @@ impl Method {
         let request_struct_name = type_prefix.clone() + "Request";
         let response_struct_name = type_prefix.clone() + "Response";
         let error_enum_name = type_prefix.clone() + "Error";
-        let response = self
-            .response
-            .generate(&response_struct_name, &error_enum_name);
+        let response =
+            self.response
+                .generate(&self.name, &response_struct_name, &error_enum_name, shared);
         let response_type = self.response.get_response_type(&response_struct_name);
 
-        let send_call = || {
+        let send_call = |params: &str| {
             let mut base_call = format!(
                 "\
                 let url = crate::get_slack_url_for_method(\"{name}\");
-                client.send(&url, &params[..])
+                let mut body = crate::response_buffer::ResponseBuffer::take();
+                client.send_into(&url, {params}, &mut body)
                     {dot_await}
-                    .map_err({error_type}::Client)
-                    .and_then(|result| {{
-                        serde_json::from_str::<{response_type}>(&result)
-                            .map_err(|e| {error_type}::MalformedResponse(result, e))
-                    }})",
+                    .map_err({error_type}::Client)?;
+                serde_json::from_slice::<{response_type}>(&body)
+                    .map_err(|e| {error_type}::MalformedResponse(body.to_string_lossy(), e))",
                 name = self.name,
+                params = params,
                 response_type = response_struct_name,
                 error_type = error_enum_name,
                 dot_await = gen_mode.dot_await(),
@@ -193,15 +400,11 @@ impl Method {
                     {send_call}
                 }}
                 ",
-                documentation = format_docs("///", &[
-                    &self.description,
-                    "",
-                    &format!("Wraps {}", self.documentation_url)
-                ].join("\n")),
+                documentation = self.documentation(),
                 method_name = fn_name,
                 response_type = response_struct_name,
                 error_type = error_enum_name,
-                send_call = send_call(),
+                send_call = send_call("&params[..]"),
                 fn_type = gen_mode.fn_type(),
             )
         } else if self.params.len() == 1 && self.params[0].ty == "auth_token" {
@@ -223,15 +426,11 @@ impl Method {
                     {send_call}
                 }}
                 ",
-                documentation = format_docs("///", &[
-                    &self.description,
-                    "",
-                    &format!("Wraps {}", self.documentation_url)
-                ].join("\n")),
+                documentation = self.documentation(),
                 method_name = fn_name,
                 response_type = response_struct_name,
                 error_type = error_enum_name,
-                send_call = send_call(),
+                send_call = send_call("&params[..]"),
                 fn_type = gen_mode.fn_type(),
             )
         } else {
@@ -263,19 +462,14 @@ impl Method {
                     where R: SlackWebRequestSender
                 {{
                     {local_vars}
-                    let params = vec![
+                    let params = [
                         {token}
                         {param_pairs}
                     ];
-                    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
                     {send_call}
                 }}
                 ",
-                documentation = format_docs("///", &[
-                    &self.description,
-                    "",
-                    &format!("Wraps {}", self.documentation_url)
-                ].join("\n")),
+                documentation = self.documentation(),
                 method_name = fn_name,
                 response_type = response_struct_name,
                 error_type = error_enum_name,
@@ -293,7 +487,7 @@ impl Method {
                     .map(Param::get_pair)
                     .collect::<Vec<String>>()
                     .join(",\n"),
-                send_call = send_call(),
+                send_call = send_call("IntoIterator::into_iter(params).flatten()"),
                 fn_type = gen_mode.fn_type(),
             )
         }
@@ -307,10 +501,27 @@ impl Method {
             .all(|p| p.ty == "integer" || p.ty == "boolean")
     }
 
+    /// Whether every request field has a default, which references to typed values don't.
+    fn has_default(&self) -> bool {
+        self.params.iter().all(|p| {
+            p.optional
+                || !matches!(
+                    &p.ty[..],
+                    "cursor"
+                        | "metadata"
+                        | "home_view"
+                        | "modal_view"
+                        | "function_visibility"
+                        | "role_id"
+                        | "trigger_id"
+                )
+        })
+    }
+
     fn get_request_struct(&self, ty_name: &str, gen_mode: GenMode) -> String {
         format!(
             "\
-            #[derive(Clone, Default, Debug)]
+            #[derive({derives})]
             pub struct {request_type}{lifetime} {{
                 {request_params}
             }}",
@@ -323,7 +534,12 @@ impl Method {
                 .map(|p| p.generate(gen_mode))
                 .collect::<Vec<String>>()
                 .join("\n"),
-            lifetime = if self.has_lifetime() { "<'a>" } else { "" }
+            lifetime = if self.has_lifetime() { "<'a>" } else { "" },
+            derives = if self.has_default() {
+                "Clone, Default, Debug"
+            } else {
+                "Clone, Debug"
+            }
         )
     }
 }
@@ -369,12 +585,12 @@ fn get_obj_to_response_impl(obj: &JsonObject, error_type: &str) -> Option<String
     if obj.has_ok() {
         Some(format!(
             "\
-            impl<E: Error> Into<Result<{name}, {error_ty}<E>>> for {name} {{
-                fn into(self) -> Result<{name}, {error_ty}<E>> {{
-                    if self.ok {{
-                        Ok(self)
+            impl<E: Error> From<{name}> for Result<{name}, {error_ty}<E>> {{
+                fn from(response: {name}) -> Self {{
+                    if response.ok {{
+                        Ok(response)
                     }} else {{
-                        Err(self.error.as_ref().map(String::as_ref).unwrap_or(\"\").into())
+                        Err(response.error.as_deref().unwrap_or(\"\").into())
                     }}
                 }}
             }}",
@@ -390,9 +606,9 @@ fn get_enum_to_response_impl(enm: &JsonEnum, error_type: &str) -> Option<String>
     if enm.has_ok() {
         Some(format!(
             "\
-            impl<E: Error> Into<Result<{name}, {error_ty}<E>>> for {name} {{
-                fn into(self) -> Result<{name}, {error_ty}<E>> {{
-                    match self {{
+            impl<E: Error> From<{name}> for Result<{name}, {error_ty}<E>> {{
+                fn from(response: {name}) -> Self {{
+                    match response {{
                         {matches}
                     }}
                 }}
@@ -438,8 +654,21 @@ pub struct Response {
 }
 
 impl Response {
-    pub fn generate(&self, ty_name: &str, error_ty: &str) -> String {
-        let (objs, to_result) = match PropType::from_schema(&self.schema, ty_name) {
+    pub fn generate(
+        &self,
+        method: &str,
+        ty_name: &str,
+        error_ty: &str,
+        shared: &SharedObjects,
+    ) -> String {
+        let mut ty = PropType::from_schema(&self.schema, ty_name);
+        // Aliased so the shared objects keep their paths in the module.
+        let aliases = shared
+            .hoist(&mut ty)
+            .iter()
+            .map(|name| format!("pub type {name} = crate::{name};", name = name))
+            .collect::<Vec<_>>();
+        let (objs, to_result) = match ty {
             PropType::Obj(ref o) => {
                 let to_result = get_obj_to_response_impl(o, error_ty);
                 (o.to_code(), to_result)
@@ -456,11 +685,13 @@ impl Response {
         format!(
             "\
             {objs}
+            {aliases}
             {slack_result}
             {errors}",
             objs = objs,
+            aliases = aliases.join("\n"),
             slack_result = to_result.unwrap_or_default(),
-            errors = self.get_error_enum(error_ty),
+            errors = self.get_error_enum(method, error_ty),
         )
     }
 
@@ -468,7 +699,7 @@ impl Response {
         PropType::from_schema(&self.schema, ty_name)
     }
 
-    fn get_error_enum(&self, error_ty: &str) -> String {
+    fn get_error_enum(&self, method: &str, error_ty: &str) -> String {
         format!(
             "\
             #[derive(Debug)]
@@ -495,11 +726,26 @@ impl Response {
                 fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {{
                     let d = match *self {{
                         {description_matches}
-                        {error_type}::MalformedResponse(_, ref e) => return write!(f, \"{{}}\", e),
-                        {error_type}::Unknown(ref s) => return write!(f, \"{{}}\", s),
-                        {error_type}::Client(ref inner) => return write!(f, \"{{}}\", inner),
+                        {error_type}::MalformedResponse(_, ref e) => return write!(f, \"{method} returned a malformed response: {{}}\", e),
+                        {error_type}::Unknown(ref s) => return write!(f, \"{method} failed: {{}}\", s),
+                        {error_type}::Client(ref inner) => return write!(f, \"{method} failed: {{}}\", inner),
+                    }};
+                     write!(f, \"{method} failed: {{}}\", d)
+                }}
+            }}
+
+            impl<E: Error> From<{error_type}<E>> for crate::SlackError<E> {{
+                fn from(e: {error_type}<E>) -> Self {{
+                    let method = \"{method}\";
+                    let error = match e {{
+                        {code_matches}
+                        {error_type}::MalformedResponse(body, error) => {{
+                            return crate::SlackError::MalformedResponse {{ method, body, error }}
+                        }}
+                        {error_type}::Unknown(s) => s,
+                        {error_type}::Client(error) => return crate::SlackError::Client {{ method, error }},
                     }};
-                     write!(f, \"{{}}\", d)
+                    crate::SlackError::Api {{ method, error }}
                 }}
             }}
 
@@ -513,6 +759,7 @@ impl Response {
                 }}
             }}",
             error_type = error_ty,
+            method = method,
             variants = self
                 .errors
                 .iter()
@@ -538,6 +785,19 @@ impl Response {
                 })
                 .collect::<Vec<String>>()
                 .join("\n"),
+            code_matches = self
+                .errors
+                .iter()
+                .map(|e| {
+                    format!(
+                        "{error_ty}::{ty_name} => \"{str_name}\".to_owned(),",
+                        error_ty = error_ty,
+                        str_name = e.name,
+                        ty_name = e.name.to_pascal_case()
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("\n"),
             description_matches = self
                 .errors
                 .iter()
@@ -570,28 +830,62 @@ impl Param {
         format!(
             "{documentation}\npub {name}: {ty},",
             documentation = format_docs("///", &self.description),
-            name = self.name,
+            name = self.field_name(),
             ty = self.get_rust_type()
         )
     }
 
     pub fn lifted(&self) -> Option<String> {
         match (&self.ty[..], self.optional) {
-            ("timestamp", true) => Some(format!(
+            (ty, true) if is_param_value_type(ty) => Some(format!(
                 "let {name} = request.{name}.as_ref().map(|t| t.to_param_value());",
-                name = self.name
+                name = self.field_name()
             )),
-            ("timestamp", false) => Some(format!(
+            (ty, false) if is_param_value_type(ty) => Some(format!(
                 "let {name} = request.{name}.to_param_value();",
-                name = self.name
+                name = self.field_name()
             )),
-            ("integer", true) => Some(format!(
+            ("integer", true) | ("integer64", true) => Some(format!(
                 "let {name} = request.{name}.map(|{name}| {name}.to_string());",
-                name = self.name
+                name = self.field_name()
             )),
-            ("integer", false) => Some(format!(
+            ("integer", false) | ("integer64", false) => Some(format!(
                 "let {name} = request.{name}.to_string();",
-                name = self.name
+                name = self.field_name()
+            )),
+            ("string_list", true) => Some(format!(
+                "let {name} = request.{name}.map(|{name}| {name}.join(\",\"));",
+                name = self.field_name()
+            )),
+            ("string_list", false) => Some(format!(
+                "let {name} = request.{name}.join(\",\");",
+                name = self.field_name()
+            )),
+            (ty, true) if is_id_list_type(ty) => Some(format!(
+                "let {name} = request.{name}.map(|{name}| {{
+                    {name}.iter().map(|id| id.as_str()).collect::<Vec<_>>().join(\",\")
+                }});",
+                name = self.field_name()
+            )),
+            (ty, false) if is_id_list_type(ty) => Some(format!(
+                "let {name} = request.{name}.iter().map(|id| id.as_str()).collect::<Vec<_>>().join(\",\");",
+                name = self.field_name()
+            )),
+            ("duration", true) => Some(format!(
+                "let {name} = request.{name}.map(|{name}| {name}.as_secs().to_string());",
+                name = self.field_name()
+            )),
+            ("duration", false) => Some(format!(
+                "let {name} = request.{name}.as_secs().to_string();",
+                name = self.field_name()
+            )),
+            (ty, true) if is_json_type(ty) => Some(format!(
+                "let {name} = request.{name}.map(crate::to_json_param);",
+                name = self.field_name()
+            )),
+            (ty, false) if is_json_type(ty) => Some(format!(
+                "let {name} = crate::to_json_param(request.{name});",
+                name = self.field_name()
             )),
             _ => None,
         }
@@ -600,48 +894,143 @@ impl Param {
     pub fn get_pair(&self) -> String {
         match (&self.ty[..], self.optional) {
             ("boolean", true) => format!(
-                "request.{name}.map(|{name}| (\"{name}\", if {name} {{ \"1\" }} else {{ \"0\" }}))",
-                name = self.name
+                "request.{name}.map(|{name}| (\"{key}\", if {name} {{ \"1\" }} else {{ \"0\" }}))",
+                name = self.field_name(),
+                key = self.name
             ),
             ("boolean", false) => format!(
-                "Some((\"{name}\", if request.{name} {{ \"1\" }} else {{ \"0\" }}))",
-                name = self.name
+                "Some((\"{key}\", if request.{name} {{ \"1\" }} else {{ \"0\" }}))",
+                name = self.field_name(),
+                key = self.name
             ),
-            ("integer", true) => {
+            ("integer", true)
+            | ("integer64", true)
+            | ("duration", true)
+            | ("string_list", true) => {
+                // lifted into local variable, using {name} instead of request.{name}
+                format!(
+                    "{name}.as_ref().map(|{name}| (\"{key}\", &{name}[..]))",
+                    name = self.field_name(),
+                    key = self.name
+                )
+            }
+            ("integer", false)
+            | ("integer64", false)
+            | ("duration", false)
+            | ("string_list", false) => {
+                // lifted into local variable, using {name} instead of request.{name}
+                format!(
+                    "Some((\"{key}\", &{name}[..]))",
+                    name = self.field_name(),
+                    key = self.name
+                )
+            }
+            (ty, true) if is_param_value_type(ty) => {
+                // lifted into local variable, using {name} instead of request.{name}
+                format!(
+                    "{name}.as_ref().map(|{name}| (\"{key}\", &{name}[..]))",
+                    name = self.field_name(),
+                    key = self.name
+                )
+            }
+            (ty, false) if is_param_value_type(ty) => {
                 // lifted into local variable, using {name} instead of request.{name}
                 format!(
-                    "{name}.as_ref().map(|{name}| (\"{name}\", &{name}[..]))",
-                    name = self.name
+                    "Some((\"{key}\", &{name}[..]))",
+                    name = self.field_name(),
+                    key = self.name
                 )
             }
-            ("integer", false) => {
+            (ty, true) if is_json_type(ty) => {
                 // lifted into local variable, using {name} instead of request.{name}
-                format!("Some((\"{name}\", &{name}[..]))", name = self.name)
+                format!(
+                    "{name}.as_ref().map(|{name}| (\"{key}\", &{name}[..]))",
+                    name = self.field_name(),
+                    key = self.name
+                )
             }
-            ("timestamp", true) => {
+            (ty, false) if is_json_type(ty) => {
                 // lifted into local variable, using {name} instead of request.{name}
                 format!(
-                    "{name}.as_ref().map(|{name}| (\"{name}\", &{name}[..]))",
-                    name = self.name
+                    "Some((\"{key}\", &{name}[..]))",
+                    name = self.field_name(),
+                    key = self.name
                 )
             }
-            ("timestamp", false) => {
+            (ty, true) if is_id_list_type(ty) => {
                 // lifted into local variable, using {name} instead of request.{name}
-                format!("Some((\"{name}\", &{name}[..]))", name = self.name)
+                format!(
+                    "{name}.as_ref().map(|{name}| (\"{key}\", &{name}[..]))",
+                    name = self.field_name(),
+                    key = self.name
+                )
+            }
+            (ty, false) if is_id_list_type(ty) => {
+                // lifted into local variable, using {name} instead of request.{name}
+                format!(
+                    "Some((\"{key}\", &{name}[..]))",
+                    name = self.field_name(),
+                    key = self.name
+                )
             }
+            ("cursor", true) | ("role_id", true) | ("trigger_id", true) => format!(
+                "request.{name}.map(|{name}| (\"{key}\", {name}.as_str()))",
+                name = self.field_name(),
+                key = self.name
+            ),
+            ("cursor", false) | ("role_id", false) | ("trigger_id", false) => format!(
+                "Some((\"{key}\", request.{name}.as_str()))",
+                name = self.field_name(),
+                key = self.name
+            ),
             (_, true) => format!(
-                "request.{name}.map(|{name}| (\"{name}\", {name}))",
-                name = self.name
+                "request.{name}.map(|{name}| (\"{key}\", {name}))",
+                name = self.field_name(),
+                key = self.name
+            ),
+            (_, false) => format!(
+                "Some((\"{key}\", request.{name}))",
+                name = self.field_name(),
+                key = self.name
             ),
-            (_, false) => format!("Some((\"{name}\", request.{name}))", name = self.name),
+        }
+    }
+
+    /// The request field for the param, escaping names that are Rust keywords.
+    fn field_name(&self) -> String {
+        match &self.name[..] {
+            keyword if RAW_KEYWORDS.contains(&keyword) => format!("r#{}", keyword),
+            name => name.to_owned(),
         }
     }
 
     fn get_rust_type(&self) -> String {
         let ty = match &self.ty[..] {
             "timestamp" => "crate::Timestamp",
+            "file_types" => "crate::FileTypes",
+            "conversation_types" => "crate::ConversationTypes",
+            "auth_policy_name" => "crate::AuthPolicyName",
+            "auth_policy_entity_type" => "crate::AuthPolicyEntityType",
+            "function_visibility" => "crate::FunctionVisibility",
+            "role_id" => "&'a crate::RoleId",
+            "role_ids" => "&'a [crate::RoleId]",
+            "role_entities" => "&'a [crate::RoleEntity]",
+            "string_list" => "&'a [&'a str]",
+            "cursor" => "&'a crate::Cursor",
+            "trigger_id" => "&'a crate::TriggerId",
             "boolean" => "bool",
             "integer" => "u32",
+            "integer64" => "u64",
+            "duration" => "std::time::Duration",
+            "blocks" => "&'a [crate::blocks::Block]",
+            "attachments" => "&'a [crate::blocks::Attachment]",
+            "metadata" => "&'a crate::metadata::MessageMetadata",
+            "home_view" => "&'a crate::blocks::HomeView",
+            "modal_view" => "&'a crate::blocks::ModalView",
+            "suggested_prompts" => "&'a [crate::SuggestedPrompt]",
+            "list_schema" => "&'a [crate::ListColumn]",
+            "list_fields" => "&'a [crate::ListField]",
+            "list_cells" => "&'a [crate::ListCell]",
             _ => "&'a str",
         };
         if self.optional {
@@ -652,15 +1041,72 @@ impl Param {
     }
 }
 
+/// Param types converted to a string with their `to_param_value` method.
+fn is_param_value_type(ty: &str) -> bool {
+    matches!(
+        ty,
+        "timestamp"
+            | "file_types"
+            | "conversation_types"
+            | "auth_policy_name"
+            | "auth_policy_entity_type"
+            | "function_visibility"
+    )
+}
+
+/// Param types which are slices of typed IDs, sent comma separated.
+fn is_id_list_type(ty: &str) -> bool {
+    matches!(ty, "role_ids" | "role_entities")
+}
+
+/// Param types passed to Slack as a JSON-encoded string of a typed value.
+fn is_json_type(ty: &str) -> bool {
+    matches!(
+        ty,
+        "blocks"
+            | "attachments"
+            | "metadata"
+            | "home_view"
+            | "modal_view"
+            | "suggested_prompts"
+            | "list_schema"
+            | "list_fields"
+            | "list_cells"
+    )
+}
+
+/// Keywords which can be used as field names written as raw identifiers.
+const RAW_KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "do", "dyn",
+    "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in", "let", "loop",
+    "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref", "return", "static",
+    "struct", "trait", "true", "try", "type", "typeof", "unsafe", "unsized", "use", "virtual",
+    "where", "while", "yield",
+];
+
+/// The `rename_all` rules serde applies to snake case field names, tried in order.
+const RENAME_ALL_RULES: &[&str] = &[
+    "camelCase",
+    "PascalCase",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+];
+
 impl JsonObjectFieldInfo {
-    pub fn to_code(&self) -> String {
+    pub fn to_code(&self, rename_all: Option<&str>) -> String {
         let mut prefix = String::new();
 
         if let Some(path) = self.deserialize_with {
             prefix.push_str(&format!("#[serde(deserialize_with = \"{}\")]\n", path));
         }
 
-        if self.default {
+        if let Some(path) = self.ty.serde_with() {
+            prefix.push_str(&format!("#[serde(with = \"{}\")]\n", path));
+        }
+
+        if self.default
+            || self.ty.serde_with().is_some() && matches!(self.ty, PropType::Optional(_))
+        {
             prefix.push_str("#[serde(default)]\n");
         }
 
@@ -670,17 +1116,90 @@ impl JsonObjectFieldInfo {
             prefix.push_str("pub");
         };
 
-        if let Some(ref rename) = self.rename {
+        let ty = match self.ty {
+            PropType::Optional(ref inner) if self.boxed => {
+                format!("Option<Box<{}>>", inner.to_rs_type())
+            }
+            ref ty if self.boxed => format!("Box<{}>", ty.to_rs_type()),
+            ref ty => ty.to_rs_type(),
+        };
+
+        let renamed = rename_all.map_or(self.serde_name(), |rule| {
+            apply_rename_rule(rule, &self.serde_name())
+        });
+        if renamed != self.name {
             format!(
                 "#[serde(rename = \"{}\")]\n{} {}: {},",
-                rename,
-                prefix,
                 self.name,
-                self.ty.to_rs_type()
+                prefix,
+                self.field_name(),
+                ty
             )
         } else {
-            format!("{} {}: {},", prefix, self.name, self.ty.to_rs_type())
+            format!("{} {}: {},", prefix, self.field_name(), ty)
+        }
+    }
+
+    /// The field's Rust name: its key in snake case, written as a raw identifier if it's a
+    /// keyword. `self` can't be a raw identifier, so is named `slf`.
+    pub fn field_name(&self) -> String {
+        let name = snake_case_name(&self.name);
+        match &name[..] {
+            "self" => "slf".to_owned(),
+            "crate" | "super" => format!("{}_", name),
+            keyword if RAW_KEYWORDS.contains(&keyword) => format!("r#{}", keyword),
+            _ => name,
+        }
+    }
+
+    /// The name serde gives the field, which drops the `r#` of raw identifiers.
+    fn serde_name(&self) -> String {
+        self.field_name().trim_start_matches("r#").to_owned()
+    }
+}
+
+/// Converts a JSON key to a snake case Rust identifier. Keys which already are one are kept as they
+/// are, digits included.
+fn snake_case_name(key: &str) -> String {
+    let is_snake_case = key
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    let name = if is_snake_case {
+        key.to_owned()
+    } else {
+        key.to_snake_case()
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+            .collect()
+    };
+    if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("_{}", name)
+    } else {
+        name
+    }
+}
+
+/// Renames a snake case field as serde's `rename_all` attribute does.
+fn apply_rename_rule(rule: &str, name: &str) -> String {
+    match rule {
+        "camelCase" | "PascalCase" => {
+            let mut renamed = String::new();
+            let mut capitalize = rule == "PascalCase";
+            for c in name.chars() {
+                if c == '_' {
+                    capitalize = true;
+                } else if capitalize {
+                    renamed.push(c.to_ascii_uppercase());
+                    capitalize = false;
+                } else {
+                    renamed.push(c);
+                }
+            }
+            renamed
         }
+        "SCREAMING_SNAKE_CASE" => name.to_ascii_uppercase(),
+        "kebab-case" => name.replace('_', "-"),
+        _ => name.to_owned(),
     }
 }
 
@@ -706,12 +1225,36 @@ impl JsonEnum {
                 "subtype",
                 "::serde_json::from_value::<MessageStandard>(value.clone())
                .map(Message::Standard)
-               .map_err(|e| D::Error::custom(&format!(\"{}\", e)))",
+               .map_err(D::Error::custom)",
             )
         } else {
             ("type", "Err(D::Error::missing_field(\"type\"))")
         };
 
+        // Messages of new subtypes are kept rather than failing to decode a whole history page
+        let (other_variant, variant_names, on_unknown_variant) = if self.name == "Message" {
+            (
+                "/// A message of a subtype that isn't typed by this library.
+                Other(::serde_json::Value),"
+                    .to_owned(),
+                String::new(),
+                "Ok(Message::Other(value))",
+            )
+        } else {
+            (
+                String::new(),
+                format!(
+                    "const VARIANTS: &[&str] = &[{}];",
+                    self.variants
+                        .iter()
+                        .map(|v| format!("\"{}\"", v.name.to_snake_case()))
+                        .collect::<Vec<_>>()
+                        .join(",")
+                ),
+                "Err(D::Error::unknown_variant(ty, VARIANTS))",
+            )
+        };
+
         let mut subobjs = self.variants.clone();
 
         subobjs.sort_by_key(|v| v.name.clone());
@@ -722,10 +1265,14 @@ impl JsonEnum {
             .collect::<Vec<_>>()
             .join("\n");
 
+        // Each variant's object holds the field telling the variants apart, so they serialize
+        // untagged.
         format!("\
-            #[derive(Clone, Debug)]
+            #[derive(Clone, Debug, PartialEq, Serialize)]
+            #[serde(untagged)]
             pub enum {name} {{
                 {variants}
+                {other_variant}
             }}
 
             impl<'de> ::serde::Deserialize<'de> for {name} {{
@@ -734,14 +1281,14 @@ impl JsonEnum {
                 {{
                     use ::serde::de::Error as SerdeError;
 
-                    const VARIANTS: &[&str] = &[{variant_names}];
+                    {variant_names}
 
                     let value = ::serde_json::Value::deserialize(deserializer)?;
                     if let Some(ty_val) = value.get(\"{variant_field}\") {{
                         if let Some(ty) = ty_val.as_str() {{
                             match ty {{
                                 {variant_matches}
-                                _ => Err(D::Error::unknown_variant(ty, VARIANTS))
+                                _ => {on_unknown_variant}
                             }}
                         }} else {{
                             Err(D::Error::invalid_type(::serde::de::Unexpected::Unit, &\"a string\"))
@@ -759,18 +1306,16 @@ impl JsonEnum {
                 .map(|v| v.to_code())
                 .collect::<Vec<_>>()
                 .join("\n"),
-            variant_names = self.variants
-                .iter()
-                .map(|v| format!("\"{}\"", v.name.to_snake_case()))
-                .collect::<Vec<_>>()
-                .join(","),
+            other_variant = other_variant,
+            variant_names = variant_names,
+            on_unknown_variant = on_unknown_variant,
             variant_matches = self.variants
                 .iter()
                 .map(|v| format!("\
                     \"{type_name}\" => {{
                         ::serde_json::from_value::<{variant_type}>(value.clone())
                            .map({variant_name})
-                           .map_err(|e| D::Error::custom(&format!(\"{{}}\", e)))
+                           .map_err(D::Error::custom)
                     }}",
                     type_name = v.name.to_snake_case(),
                     variant_type = v.inner.to_rs_type(),
@@ -807,21 +1352,89 @@ impl JsonObject {
         let mut fields = self.fields.clone();
         fields.sort_by_key(|f| f.name.clone());
 
-        let fields = fields.iter().map(|f| f.to_code()).collect::<Vec<_>>();
+        let pagination = self
+            .fields
+            .iter()
+            .filter_map(|f| get_pagination_impl(&self.name, f))
+            .collect::<Vec<_>>();
+
+        let rename_all = self.rename_all();
+        let attrs = rename_all.map_or(String::new(), |rule| {
+            format!("#[serde(rename_all = \"{}\")]\n", rule)
+        });
+        let fields = fields
+            .iter()
+            .map(|f| f.to_code(rename_all))
+            .collect::<Vec<_>>();
 
         format!(
             "\
-            #[derive(Clone, Debug, Deserialize)]
-            pub struct {name} {{
+            #[derive({derives})]
+            {attrs}pub struct {name} {{
                 {fields}
             }}
 
+            {pagination}
+
             {subobjs}",
+            derives = response_derives(&self.name),
+            attrs = attrs,
             name = self.name,
             fields = fields.join("\n"),
+            pagination = pagination.join("\n"),
             subobjs = subobjs.join("\n")
         )
     }
+
+    /// The `rename_all` rule which gives every field its JSON key, if more than one field needs
+    /// renaming. Fields are renamed one by one otherwise.
+    fn rename_all(&self) -> Option<&'static str> {
+        let renamed = self
+            .fields
+            .iter()
+            .filter(|f| f.serde_name() != f.name)
+            .count();
+        if renamed < 2 {
+            return None;
+        }
+        RENAME_ALL_RULES.iter().cloned().find(|&rule| {
+            self.fields
+                .iter()
+                .all(|f| apply_rename_rule(rule, &f.serde_name()) == f.name)
+        })
+    }
+}
+
+/// Implements `HasPagination` or `HasPaging` for objects carrying the shared pagination types.
+fn get_pagination_impl(obj_name: &str, field: &JsonObjectFieldInfo) -> Option<String> {
+    let (trait_name, ty) = match field.name.as_str() {
+        "response_metadata" => ("HasPagination", "ResponseMetadata"),
+        "paging" => ("HasPaging", "Paging"),
+        _ => return None,
+    };
+    let access = match field.ty {
+        PropType::Ref(ref name) if name == ty => format!("Some(&self.{})", field.field_name()),
+        PropType::Optional(ref inner) => match **inner {
+            PropType::Ref(ref name) if name == ty => {
+                format!("self.{}.as_ref()", field.field_name())
+            }
+            _ => return None,
+        },
+        _ => return None,
+    };
+    Some(format!(
+        "\
+        impl crate::{trait_name} for {name} {{
+            fn {field}(&self) -> Option<&crate::{ty}> {{
+                {access}
+            }}
+        }}",
+        trait_name = trait_name,
+        name = obj_name,
+        field = field.name,
+        ty = ty,
+        access = access
+    ))
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -3,51 +3,163 @@ use std::path::Path;
 
 use inflector::Inflector;
 
-#[derive(Deserialize, Clone, Debug)]
+#[derive(Deserialize, Serialize, Clone, Debug)]
 pub struct JsonSchema {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
-    #[serde(rename = "$schema")]
+    #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
     pub schema_ref: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
-    #[serde(rename = "type")]
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
     pub ty: Option<String>,
+    /// A richer type for a string or number: `slack_ts`, `unix_ts` or `url`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<BTreeMap<String, JsonSchema>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub required: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub definitions: Option<BTreeMap<String, JsonSchema>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Box<JsonSchema>>,
-    #[serde(rename = "patternProperties")]
+    #[serde(rename = "patternProperties", skip_serializing_if = "Option::is_none")]
     pub pattern_properties: Option<BTreeMap<String, JsonSchema>>,
     #[serde(default, rename = "additionalProperties")]
     pub additional_properties: bool,
-    #[serde(rename = "$ref")]
+    #[serde(rename = "$ref", skip_serializing_if = "Option::is_none")]
     pub definition_ref: Option<String>,
-    #[serde(rename = "oneOf")]
+    #[serde(rename = "oneOf", skip_serializing_if = "Option::is_none")]
     pub one_of: Option<Vec<JsonSchema>>,
 }
 
-#[derive(Clone, Debug)]
+impl JsonSchema {
+    /// Points every `$ref` at the definition of a combined schema named after the type generated
+    /// for the referenced file, so the schema can be embedded in one.
+    pub fn localize_refs(&mut self) {
+        if let Some(ref mut def) = self.definition_ref {
+            *def = format!("#/definitions/{}", ref_type_name(def));
+        }
+        let children = self
+            .properties
+            .iter_mut()
+            .chain(self.definitions.iter_mut())
+            .chain(self.pattern_properties.iter_mut())
+            .flat_map(|schemas| schemas.values_mut())
+            .chain(self.items.iter_mut().map(|item| &mut **item))
+            .chain(
+                self.one_of
+                    .iter_mut()
+                    .flat_map(|schemas| schemas.iter_mut()),
+            );
+        for child in children {
+            child.localize_refs();
+        }
+    }
+}
+
+/// The name of the type generated for the object schema a `$ref` refers to.
+// TODO: This ignores `#/` and assumes filenames refer to an existing struct with that name.
+pub fn ref_type_name(def: &str) -> String {
+    Path::new(def)
+        .file_stem()
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_pascal_case()
+}
+
+/// Fields Slack sends inconsistently, by object and field, with the function reading them. The
+/// fields are all optional, and default to `None` when missing.
+const FIELD_DESERIALIZERS: &[(&str, &str, &str)] = &[
+    // Slack writes an empty map as an empty array
+    ("MessageBotMessage", "icons", STRUCT_OR_EMPTY_ARRAY),
+    ("UserProfile", "fields", STRUCT_OR_EMPTY_ARRAY),
+    // Slack sends these as a number or a string holding one
+    ("BotProfile", "updated", STRING_OR_NUMBER),
+    ("Channel", "created", STRING_OR_NUMBER),
+    ("Channel", "num_members", STRING_OR_NUMBER),
+    ("Channel", "unread_count", STRING_OR_NUMBER),
+    ("Channel", "unread_count_display", STRING_OR_NUMBER),
+    ("Conversation", "created", STRING_OR_NUMBER),
+    ("Conversation", "num_members", STRING_OR_NUMBER),
+    ("Conversation", "unread_count", STRING_OR_NUMBER),
+    ("Conversation", "unread_count_display", STRING_OR_NUMBER),
+    ("File", "created", STRING_OR_NUMBER),
+    ("File", "size", STRING_OR_NUMBER),
+    ("Group", "created", STRING_OR_NUMBER),
+    ("Group", "unread_count", STRING_OR_NUMBER),
+    ("Group", "unread_count_display", STRING_OR_NUMBER),
+    ("Im", "created", STRING_OR_NUMBER),
+    ("Mpim", "created", STRING_OR_NUMBER),
+    ("Mpim", "unread_count", STRING_OR_NUMBER),
+    ("Mpim", "unread_count_display", STRING_OR_NUMBER),
+    ("Paging", "count", STRING_OR_NUMBER),
+    ("Paging", "page", STRING_OR_NUMBER),
+    ("Paging", "pages", STRING_OR_NUMBER),
+    ("Paging", "total", STRING_OR_NUMBER),
+    // Slack sends these as a bool, as 0 or 1, or as a string holding either
+    ("User", "is_admin", LENIENT_BOOL),
+    ("User", "is_owner", LENIENT_BOOL),
+    ("User", "is_primary_owner", LENIENT_BOOL),
+    ("User", "is_restricted", LENIENT_BOOL),
+    ("User", "is_ultra_restricted", LENIENT_BOOL),
+];
+
+const STRUCT_OR_EMPTY_ARRAY: &str = "crate::optional_struct_or_empty_array";
+const STRING_OR_NUMBER: &str = "crate::de::optional_string_or_number";
+const LENIENT_BOOL: &str = "crate::de::optional_lenient_bool";
+
+/// Fields kept behind a `Box`, by object and field, so that they don't bloat the enums holding
+/// their objects.
+const BOXED_FIELDS: &[(&str, &str)] = &[
+    ("MessageBotMessage", "bot_profile"),
+    ("MessageFileShare", "bot_profile"),
+    ("MessageStandard", "bot_profile"),
+    ("MessageThreadBroadcast", "bot_profile"),
+];
+
+/// The function reading a field of an object, if it isn't read as its type usually is.
+fn field_deserializer(obj_name: &str, field: &str) -> Option<&'static str> {
+    FIELD_DESERIALIZERS
+        .iter()
+        .find(|&&(o, f, _)| o == obj_name && f == field)
+        .map(|&(_, _, path)| path)
+}
+
+fn is_boxed(obj_name: &str, field: &str) -> bool {
+    BOXED_FIELDS
+        .iter()
+        .any(|&(o, f)| o == obj_name && f == field)
+}
+
+#[derive(Clone, Debug, PartialEq)]
 pub struct JsonObject {
     pub name: String,
     pub fields: Vec<JsonObjectFieldInfo>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct JsonObjectFieldInfo {
+    /// The field's key in the JSON object.
     pub name: String,
     pub ty: PropType,
-    pub rename: Option<String>,
     pub deserialize_with: Option<&'static str>,
     pub default: bool,
+    /// Whether the field's value is boxed, within its `Option` if it's optional.
+    pub boxed: bool,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct JsonEnum {
     pub name: String,
     pub variants: Vec<JsonEnumVariant>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct JsonEnumVariant {
     pub name: String,
     pub qualified_name: String,
@@ -55,13 +167,17 @@ pub struct JsonEnumVariant {
     pub inner: PropType,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum PropType {
     Str,
     Int,
     Num,
     Bool,
     Ref(String),
+    /// Seconds since the Unix epoch, as `crate::UnixTime`.
+    UnixTime,
+    /// A URL, as `crate::Url`.
+    Url,
     Obj(JsonObject),
     Arr(Box<PropType>),
     Map(Box<PropType>),
@@ -73,17 +189,7 @@ pub enum PropType {
 impl PropType {
     pub fn from_schema(schema: &JsonSchema, name: &str) -> Self {
         if let Some(ref def) = schema.definition_ref {
-            // TODO: This ignores `#/` and assumes filenames refer to an existing struct with that
-            //       name.
-            return PropType::Ref(
-                Path::new(def)
-                    .file_stem()
-                    .unwrap()
-                    .to_str()
-                    .unwrap()
-                    .to_owned()
-                    .to_pascal_case(),
-            );
+            return PropType::Ref(ref_type_name(def));
         }
 
         if let Some(ref one_of) = schema.one_of {
@@ -111,6 +217,13 @@ impl PropType {
             });
         }
 
+        match schema.format.as_ref().map(String::as_ref) {
+            Some("slack_ts") => return PropType::Ref("Timestamp".to_owned()),
+            Some("unix_ts") => return PropType::UnixTime,
+            Some("url") => return PropType::Url,
+            _ => {}
+        }
+
         match schema.ty.as_ref().map(String::as_ref) {
             Some("boolean") => PropType::Bool,
             Some("string") => PropType::Str,
@@ -151,11 +264,6 @@ impl PropType {
                                 let fields = p
                                     .iter()
                                     .map(|(orig_name, p)| {
-                                        let (field_name, rename) = match &orig_name[..] {
-                                            "type" => ("ty", Some("type".into())),
-                                            "self" => ("slf", Some("self".into())),
-                                            name => (name, None),
-                                        };
                                         let field_ty_name =
                                             name.to_owned() + &orig_name.to_pascal_case();
                                         let mut ty = Self::from_schema(p, &field_ty_name);
@@ -166,29 +274,13 @@ impl PropType {
                                         } else {
                                             ty = PropType::Optional(Box::new(ty));
                                         }
-                                        // Hack for slack bug which writes empty map as empty array
-                                        let default;
-                                        let deserialize_with;
-                                        if name == "UserProfile" && field_name == "fields" {
-                                            deserialize_with =
-                                                Some("crate::optional_struct_or_empty_array");
-                                            default = true;
-                                        } else if name == "MessageBotMessage"
-                                            && field_name == "icons"
-                                        {
-                                            deserialize_with =
-                                                Some("crate::optional_struct_or_empty_array");
-                                            default = true;
-                                        } else {
-                                            deserialize_with = None;
-                                            default = false;
-                                        }
+                                        let deserialize_with = field_deserializer(name, orig_name);
                                         JsonObjectFieldInfo {
-                                            name: field_name.into(),
+                                            name: orig_name.clone(),
                                             ty: ty,
-                                            rename: rename,
                                             deserialize_with: deserialize_with,
-                                            default: default,
+                                            default: deserialize_with.is_some(),
+                                            boxed: is_boxed(name, orig_name),
                                         }
                                     })
                                     .collect();
@@ -206,6 +298,72 @@ impl PropType {
         }
     }
 
+    /// The objects nested within the type, outermost first, not counting the type itself.
+    pub fn nested_objects(&self) -> Vec<&JsonObject> {
+        let mut objects = vec![];
+        for child in self.children() {
+            if let PropType::Obj(ref o) = *child {
+                objects.push(o);
+            }
+            objects.extend(child.nested_objects());
+        }
+        objects
+    }
+
+    /// Replaces the objects nested within the type which are one of `shared` with references to
+    /// them, returning their names.
+    pub fn hoist(&mut self, shared: &[JsonObject]) -> Vec<String> {
+        let mut hoisted = vec![];
+        for child in self.children_mut() {
+            let name = match *child {
+                PropType::Obj(ref o) if shared.contains(o) => Some(o.name.clone()),
+                _ => None,
+            };
+            match name {
+                Some(name) => {
+                    *child = PropType::Ref(name.clone());
+                    hoisted.push(name);
+                }
+                None => hoisted.extend(child.hoist(shared)),
+            }
+        }
+        hoisted
+    }
+
+    fn children(&self) -> Vec<&PropType> {
+        match *self {
+            PropType::Obj(ref o) => o.fields.iter().map(|f| &f.ty).collect(),
+            PropType::Enum(ref e) => e.variants.iter().map(|v| &v.inner).collect(),
+            PropType::Arr(ref prop) | PropType::Map(ref prop) | PropType::Optional(ref prop) => {
+                vec![&**prop]
+            }
+            _ => vec![],
+        }
+    }
+
+    fn children_mut(&mut self) -> Vec<&mut PropType> {
+        match *self {
+            PropType::Obj(ref mut o) => o.fields.iter_mut().map(|f| &mut f.ty).collect(),
+            PropType::Enum(ref mut e) => e.variants.iter_mut().map(|v| &mut v.inner).collect(),
+            PropType::Arr(ref mut prop)
+            | PropType::Map(ref mut prop)
+            | PropType::Optional(ref mut prop) => vec![&mut **prop],
+            _ => vec![],
+        }
+    }
+
+    /// The module serde reads and writes the type with, for types without their own impls.
+    pub fn serde_with(&self) -> Option<&'static str> {
+        match *self {
+            PropType::UnixTime => Some("crate::formats::unix_ts"),
+            PropType::Optional(ref prop) => match **prop {
+                PropType::UnixTime => Some("crate::formats::unix_ts::option"),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     pub fn to_rs_type(&self) -> String {
         match *self {
             PropType::Str => "String".into(),
@@ -215,6 +373,8 @@ impl PropType {
             PropType::Null => "()".into(),
             PropType::Obj(ref obj) => obj.name.clone(),
             PropType::Ref(ref name) => format!("crate::{}", name),
+            PropType::UnixTime => "crate::UnixTime".into(),
+            PropType::Url => "crate::Url".into(),
             PropType::Arr(ref prop) => format!("Vec<{}>", prop.to_rs_type()),
             PropType::Map(ref prop) => format!("HashMap<String, {}>", prop.to_rs_type()),
             PropType::Optional(ref prop) => format!("Option<{}>", prop.to_rs_type()),
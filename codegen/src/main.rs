@@ -3,6 +3,7 @@ extern crate serde_derive;
 
 use serde_json;
 
+use std::collections::{BTreeMap, BTreeSet};
 use std::fs::{self, File, OpenOptions};
 use std::io::{self, Read, Write};
 use std::path::Path;
@@ -20,7 +21,7 @@ use crate::generator::*;
 const SCHEMA_DIR: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/slack-api-schemas");
 const DEFAULT_OUT_DIR: &'static str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src");
 
-fn generate_types(output_path: &Path) -> io::Result<()> {
+fn generate_types(output_path: &Path, shared: &SharedObjects) -> io::Result<()> {
     let codegen_filepath = output_path.join("types.rs");
 
     let mut types_file = OpenOptions::new()
@@ -60,6 +61,8 @@ fn generate_types(output_path: &Path) -> io::Result<()> {
         }
     }
 
+    types_file.write_all(shared.to_code().as_bytes())?;
+
     Command::new("rustfmt")
         .args(&["--edition", "2018"])
         .arg(codegen_filepath)
@@ -68,7 +71,11 @@ fn generate_types(output_path: &Path) -> io::Result<()> {
     Ok(())
 }
 
-fn generate_modules(output_path: &Path, gen_mode: GenMode) -> io::Result<()> {
+fn generate_modules(
+    output_path: &Path,
+    gen_mode: GenMode,
+    shared: &SharedObjects,
+) -> io::Result<()> {
     let mut mods = vec![];
 
     let schema_path = Path::new(SCHEMA_DIR);
@@ -94,7 +101,7 @@ fn generate_modules(output_path: &Path, gen_mode: GenMode) -> io::Result<()> {
 
                 let mod_name = format!("{}{}", module.get_safe_name(), postfix);
 
-                mods.push(mod_name.clone());
+                mods.push((mod_name.clone(), module.is_unstable()));
 
                 let out_filepath = output_path.join(format!("{}.rs", mod_name));
 
@@ -105,7 +112,7 @@ fn generate_modules(output_path: &Path, gen_mode: GenMode) -> io::Result<()> {
                         .create(true)
                         .open(&out_filepath)?;
 
-                    out_file.write_all(module.generate(gen_mode).as_bytes())?;
+                    out_file.write_all(module.generate(gen_mode, shared).as_bytes())?;
                 }
 
                 Command::new("rustfmt")
@@ -124,7 +131,13 @@ fn generate_modules(output_path: &Path, gen_mode: GenMode) -> io::Result<()> {
 
     mod_file.write_all(
         mods.iter()
-            .map(|modname| format!("pub mod {};", modname))
+            .map(|&(ref modname, unstable)| {
+                if unstable {
+                    format!("#[cfg(feature = \"unstable-api\")]\npub mod {};", modname)
+                } else {
+                    format!("pub mod {};", modname)
+                }
+            })
             .collect::<Vec<_>>()
             .join("\n")
             .as_bytes(),
@@ -133,6 +146,173 @@ fn generate_modules(output_path: &Path, gen_mode: GenMode) -> io::Result<()> {
     Ok(())
 }
 
+/// Finds the objects nested in several responses, which are generated once in `types.rs`.
+fn collect_shared_objects() -> io::Result<SharedObjects> {
+    let mut modules = vec![];
+    let mut object_names = BTreeSet::new();
+
+    let schema_path = Path::new(SCHEMA_DIR);
+
+    for entry in fs::read_dir(schema_path.join("objects"))? {
+        if let Ok(e) = entry {
+            let path = e.path();
+            if path.is_file() {
+                object_names.insert(path.file_stem().unwrap().to_str().unwrap().to_pascal_case());
+            }
+        }
+    }
+
+    for entry in fs::read_dir(schema_path.join("web"))? {
+        if let Ok(e) = entry {
+            let path = e.path();
+            if path.is_file() {
+                let mut schema_file = File::open(&path)?;
+                let mut schema_contents = String::new();
+                schema_file.read_to_string(&mut schema_contents)?;
+
+                let module = serde_json::from_str::<Module>(&schema_contents).expect(&format!(
+                    "Could not parse module schema for {}",
+                    path.display()
+                ));
+
+                modules.push(module);
+            }
+        }
+    }
+
+    Ok(SharedObjects::collect(&modules, &object_names))
+}
+
+/// Writes `methods.rs`, the registry of every wrapped method and its parameters.
+fn generate_registry(output_path: &Path) -> io::Result<()> {
+    let mut methods = vec![];
+
+    let schema_path = Path::new(SCHEMA_DIR);
+
+    for entry in fs::read_dir(schema_path.join("web"))? {
+        if let Ok(e) = entry {
+            let path = e.path();
+            if path.is_file() {
+                let mut schema_file = File::open(&path)?;
+                let mut schema_contents = String::new();
+                schema_file.read_to_string(&mut schema_contents)?;
+
+                let module = serde_json::from_str::<Module>(&schema_contents).expect(&format!(
+                    "Could not parse module schema for {}",
+                    path.display()
+                ));
+
+                for method in &module.methods {
+                    if let Some(entry) = method.registry_entry(module.is_unstable()) {
+                        methods.push((method.name.clone(), entry));
+                    }
+                }
+            }
+        }
+    }
+
+    // Sorted so `MethodInfo::find` can binary search.
+    methods.sort();
+
+    let mut registry_file = OpenOptions::new()
+        .write(true)
+        .truncate(true)
+        .create(true)
+        .open(output_path.join("methods.rs"))?;
+
+    registry_file.write_all(generator::AUTOGEN_HEADER.as_bytes())?;
+    registry_file.write_all(
+        b"//! Every Web API method the crate wraps, with its parameters.
+
+use crate::method_info::{MethodInfo, ParamInfo};
+
+/// Every method, sorted by name.
+pub static METHODS: &[MethodInfo] = &[
+",
+    )?;
+    for (_, entry) in methods {
+        registry_file.write_all(entry.as_bytes())?;
+    }
+    registry_file.write_all(b"];\n")?;
+
+    Ok(())
+}
+
+/// Writes a JSON Schema with a definition for every object and every wrapped method's response,
+/// named as the generated types are, for validating responses and generating types in other
+/// languages.
+fn generate_json_schema(out_file: &Path) -> io::Result<()> {
+    let mut definitions = BTreeMap::new();
+
+    let schema_path = Path::new(SCHEMA_DIR);
+
+    for entry in fs::read_dir(schema_path.join("objects"))? {
+        if let Ok(e) = entry {
+            let path = e.path();
+            if path.is_file() {
+                let mut schema_file = File::open(&path)?;
+                let mut schema_contents = String::new();
+                schema_file.read_to_string(&mut schema_contents)?;
+
+                let mut schema = serde_json::from_str::<JsonSchema>(&schema_contents).expect(
+                    &format!("Could not parse object schema for {}", path.display()),
+                );
+
+                let ty_name = path.file_stem().unwrap().to_str().unwrap().to_pascal_case();
+
+                schema.localize_refs();
+                schema.id = None;
+                schema.schema_ref = None;
+                schema.title = Some(ty_name.clone());
+                definitions.insert(ty_name, schema);
+            }
+        }
+    }
+
+    for entry in fs::read_dir(schema_path.join("web"))? {
+        if let Ok(e) = entry {
+            let path = e.path();
+            if path.is_file() {
+                let mut schema_file = File::open(&path)?;
+                let mut schema_contents = String::new();
+                schema_file.read_to_string(&mut schema_contents)?;
+
+                let module = serde_json::from_str::<Module>(&schema_contents).expect(&format!(
+                    "Could not parse module schema for {}",
+                    path.display()
+                ));
+
+                for method in module.methods.iter().filter(|m| m.is_wrapped()) {
+                    // Prefixed with the module, since methods of different modules share names.
+                    let ty_name = format!(
+                        "{}{}Response",
+                        module.get_safe_name().to_pascal_case(),
+                        method.name.split('.').last().unwrap().to_pascal_case()
+                    );
+
+                    let mut schema = method.response.schema.clone();
+                    schema.localize_refs();
+                    schema.id = None;
+                    schema.schema_ref = None;
+                    schema.title = Some(ty_name.clone());
+                    schema.description = Some(format!(
+                        "The response of {}: {}",
+                        method.name, method.description
+                    ));
+                    definitions.insert(ty_name, schema);
+                }
+            }
+        }
+    }
+
+    let schema = serde_json::json!({
+        "$schema": "http://json-schema.org/draft-04/schema#",
+        "title": "Slack Web API",
+        "definitions": definitions,
+    });
+    fs::write(out_file, serde_json::to_string_pretty(&schema)?)
+}
+
 fn main() {
     let matches = App::new("slack-rs API Code Generator")
         .arg(
@@ -150,8 +330,21 @@ fn main() {
                     Ok(())
                 }),
         )
+        .arg(
+            Arg::with_name("json_schema")
+                .long("json-schema")
+                .value_name("FILE")
+                .help("Writes a JSON Schema of the response types to FILE instead of the modules"),
+        )
         .get_matches();
 
+    if let Some(out_file) = matches.value_of_os("json_schema") {
+        generate_json_schema(Path::new(out_file)).unwrap();
+        return;
+    }
+
+    let shared = collect_shared_objects().unwrap();
+
     let outdir = Path::new(matches.value_of_os("out_dir").unwrap());
     if !outdir.exists() {
         let _ = fs::create_dir(outdir);
@@ -163,7 +356,7 @@ fn main() {
             let _ = fs::create_dir(&moddir);
         }
 
-        generate_modules(&moddir, GenMode::Types).unwrap();
+        generate_modules(&moddir, GenMode::Types, &shared).unwrap();
     }
 
     {
@@ -172,7 +365,7 @@ fn main() {
             let _ = fs::create_dir(&moddir);
         }
 
-        generate_modules(&moddir, GenMode::Async).unwrap();
+        generate_modules(&moddir, GenMode::Async, &shared).unwrap();
     }
 
     {
@@ -181,8 +374,9 @@ fn main() {
             let _ = fs::create_dir(&moddir);
         }
 
-        generate_modules(&moddir, GenMode::Sync).unwrap();
+        generate_modules(&moddir, GenMode::Sync, &shared).unwrap();
     }
 
-    generate_types(outdir).unwrap();
+    generate_types(outdir, &shared).unwrap();
+    generate_registry(outdir).unwrap();
 }
@@ -0,0 +1,86 @@
+/// The ID of an admin role, such as a Channels Admin or Users Admin role, from
+/// `admin.roles.listAssignments`.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct RoleId(String);
+
+impl RoleId {
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        RoleId(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// What an admin role is scoped to.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum RoleEntity {
+    /// The whole Enterprise Grid organization, by its `E` ID.
+    Organization(String),
+    /// A workspace, by its `T` ID.
+    Workspace(String),
+    /// A channel, by its `C` ID.
+    Channel(String),
+}
+
+impl RoleEntity {
+    /// Tells an entity's kind from the prefix of its ID, or `None` if the prefix isn't known.
+    pub fn from_id(id: &str) -> Option<Self> {
+        let id = id.to_owned();
+        match id.chars().next()? {
+            'E' => Some(RoleEntity::Organization(id)),
+            'T' => Some(RoleEntity::Workspace(id)),
+            'C' | 'G' => Some(RoleEntity::Channel(id)),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match *self {
+            RoleEntity::Organization(ref id)
+            | RoleEntity::Workspace(ref id)
+            | RoleEntity::Channel(ref id) => id,
+        }
+    }
+}
+
+/// A user's assignment to an admin role.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RoleAssignment {
+    pub date_create: Option<crate::Timestamp>,
+    pub entity_id: Option<String>,
+    pub role_id: Option<RoleId>,
+    pub user_id: Option<String>,
+}
+
+impl RoleAssignment {
+    /// The organization, workspace or channel the role is scoped to.
+    pub fn entity(&self) -> Option<RoleEntity> {
+        self.entity_id.as_deref().and_then(RoleEntity::from_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_role_assignment_entity() {
+        let assignments: Vec<RoleAssignment> = serde_json::from_str(
+            r#"[{"role_id": "Rl0A", "entity_id": "E1", "user_id": "W1", "date_create": 1580248849},
+                {"role_id": "Rl0A", "entity_id": "C1", "user_id": "W2"}]"#,
+        )
+        .unwrap();
+        assert_eq!(assignments[0].role_id, Some(RoleId::new("Rl0A")));
+        assert_eq!(
+            assignments[0].entity(),
+            Some(RoleEntity::Organization("E1".to_owned()))
+        );
+        assert_eq!(
+            assignments[1].entity(),
+            Some(RoleEntity::Channel("C1".to_owned()))
+        );
+    }
+}
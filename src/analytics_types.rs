@@ -0,0 +1,149 @@
+//! Types shared by the helpers which compute statistics about a channel's activity.
+
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::events::message_author;
+use crate::threads_types::{message_reactions, message_ts};
+use crate::{Message, Timestamp};
+
+/// The number of most reacted messages kept in a [`ChannelActivity`].
+pub const TOP_REACTED: usize = 10;
+
+/// A day of the calendar, in UTC.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Day {
+    pub year: i32,
+    /// From 1 to 12.
+    pub month: u32,
+    /// From 1 to the length of the month.
+    pub day: u32,
+}
+
+impl Day {
+    /// The day a message was posted on.
+    pub fn of(ts: Timestamp) -> Self {
+        let days = (ts.to_micros() / 86_400_000_000) as i64;
+        // The inverse of `days_from_civil` in the scheduler's types.
+        let days = days + 719_468;
+        let era = days.div_euclid(146_097);
+        let day_of_era = days.rem_euclid(146_097);
+        let year_of_era =
+            (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        } as u32;
+        let year = (year_of_era + era * 400 + i64::from(month <= 2)) as i32;
+        Day { year, month, day }
+    }
+}
+
+impl fmt::Display for Day {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A message among the most reacted to in a channel.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ReactedMessage {
+    pub ts: Timestamp,
+    pub user: Option<String>,
+    /// The number of reactions to the message, over all emoji.
+    pub reactions: u32,
+}
+
+/// Statistics about the messages posted in a channel over a range of time, as returned by
+/// `analytics::channel_activity`.
+///
+/// Only the messages posted to the channel are counted, not replies in their threads, except
+/// replies also sent to the channel.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChannelActivity {
+    pub channel: String,
+    /// The number of messages posted by users and bots.
+    pub messages: usize,
+    /// The number of messages posted by each user. Bots without a user aren't included.
+    pub per_user: BTreeMap<String, usize>,
+    /// The number of messages posted on each day.
+    pub per_day: BTreeMap<Day, usize>,
+    /// The number of messages which started a thread.
+    pub threads: usize,
+    /// The messages with the most reactions, most reacted first, up to [`TOP_REACTED`] of them.
+    pub top_reacted: Vec<ReactedMessage>,
+}
+
+impl ChannelActivity {
+    pub(crate) fn new(channel: &str) -> Self {
+        ChannelActivity {
+            channel: channel.to_owned(),
+            ..Default::default()
+        }
+    }
+
+    /// The share of messages which started a thread, from 0 to 1.
+    pub fn thread_ratio(&self) -> f64 {
+        if self.messages == 0 {
+            0.0
+        } else {
+            self.threads as f64 / self.messages as f64
+        }
+    }
+
+    /// Counts a message, unless it's an event such as a user joining the channel.
+    pub(crate) fn add(&mut self, message: &Message) {
+        let ts = match message_ts(message) {
+            Some(ts) => ts,
+            None => return,
+        };
+        if let Message::Tombstone(_) = *message {
+            return;
+        }
+        self.messages += 1;
+        let user = message_author(message).0;
+        if let Some(user) = user {
+            *self.per_user.entry(user.to_owned()).or_default() += 1;
+        }
+        *self.per_day.entry(Day::of(ts)).or_default() += 1;
+        if let Message::Standard(ref m) = *message {
+            if m.thread_ts == Some(ts) {
+                self.threads += 1;
+            }
+        }
+
+        let reactions = message_reactions(message)
+            .iter()
+            .map(|r| r.count.unwrap_or(0).max(0) as u32)
+            .sum();
+        if reactions > 0 {
+            self.top_reacted.push(ReactedMessage {
+                ts,
+                user: user.map(str::to_owned),
+                reactions,
+            });
+            // Most reacted first, and the oldest of messages reacted to as much.
+            self.top_reacted
+                .sort_by(|a, b| b.reactions.cmp(&a.reactions).then(a.ts.cmp(&b.ts)));
+            self.top_reacted.truncate(TOP_REACTED);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_day_of_timestamp() {
+        let day = |ts: u64| Day::of(Timestamp::from(ts)).to_string();
+        assert_eq!(day(0), "1970-01-01");
+        assert_eq!(day(951_782_400), "2000-02-29");
+        assert_eq!(day(1_700_000_000), "2023-11-14");
+        assert_eq!(day(1_709_251_199), "2024-02-29");
+    }
+}
@@ -0,0 +1,34 @@
+/// A prompt suggested in an assistant thread, set with `assistant.threads.setSuggestedPrompts`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SuggestedPrompt {
+    /// The label of the prompt's button.
+    pub title: String,
+    /// The message posted as the user when the prompt is picked.
+    pub message: String,
+}
+
+impl SuggestedPrompt {
+    pub fn new<T: Into<String>, M: Into<String>>(title: T, message: M) -> Self {
+        SuggestedPrompt {
+            title: title.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// The most prompts an assistant thread can suggest at once.
+pub const MAX_SUGGESTED_PROMPTS: usize = 4;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggested_prompts_param() {
+        let prompts = [SuggestedPrompt::new("Summarize", "Summarize this channel")];
+        assert_eq!(
+            crate::to_json_param(&prompts[..]),
+            r#"[{"title":"Summarize","message":"Summarize this channel"}]"#
+        );
+    }
+}
@@ -0,0 +1,110 @@
+//! Statistics about the activity in channels, such as for internal dashboards.
+
+pub use crate::analytics_types::*;
+
+use std::ops::Range;
+
+use super::conversations;
+use super::requests::SlackWebRequestSender;
+use crate::threads_types::message_ts;
+use crate::Timestamp;
+
+/// Computes statistics about the messages posted in a channel between the start of `range` and,
+/// not including, its end, walking every page of the channel's history.
+pub async fn channel_activity<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    range: Range<Timestamp>,
+) -> Result<ChannelActivity, conversations::HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut activity = ChannelActivity::new(channel);
+    let mut cursor = None;
+    loop {
+        let page = conversations::history(
+            client,
+            token,
+            &conversations::HistoryRequest {
+                channel,
+                cursor: cursor.as_ref(),
+                oldest: Some(range.start),
+                latest: Some(range.end),
+                inclusive: Some(true),
+                limit: Some(200),
+            },
+        )
+        .await?;
+        for message in page.messages.iter().flatten() {
+            if message_ts(message) != Some(range.end) {
+                activity.add(message);
+            }
+        }
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(activity);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// Serves two pages of a channel's history.
+    fn history() -> MockSender {
+        MockSender::new().on_call("conversations.history", |call| {
+            assert_eq!(call.param("oldest"), "1700000000.000000");
+            assert_eq!(call.param("latest"), "1700200000.000000");
+            match call.param("cursor") {
+                "" => serde_json::json!({"ok": true, "has_more": true, "messages": [
+                    {"type": "message", "user": "U1", "text": "edge", "ts": "1700200000.000000"},
+                    {"type": "message", "user": "U1", "text": "release?",
+                     "ts": "1700100000.000000", "thread_ts": "1700100000.000000",
+                     "reactions": [{"name": "eyes", "count": 2, "users": ["U2", "U3"]}]},
+                    {"type": "message", "subtype": "channel_join", "user": "U3",
+                     "ts": "1700090000.000000"},
+                ], "response_metadata": {"next_cursor": "page2"}}),
+                "page2" => serde_json::json!({"ok": true, "has_more": false, "messages": [
+                    {"type": "message", "user": "U2", "text": "hi", "ts": "1700000100.000000",
+                     "reactions": [{"name": "wave", "count": 1, "users": ["U1"]}]},
+                    {"type": "message", "subtype": "bot_message", "bot_id": "B1",
+                     "text": "deployed", "ts": "1700000000.000000"},
+                ], "response_metadata": {"next_cursor": ""}}),
+                cursor => panic!("unexpected cursor {}", cursor),
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_channel_activity_across_pages() {
+        let range = Timestamp::from(1_700_000_000)..Timestamp::from(1_700_200_000);
+        let activity = channel_activity(&history(), "xoxb", "C1", range)
+            .await
+            .unwrap();
+        assert_eq!(activity.messages, 3);
+        assert_eq!(activity.per_user["U1"], 1);
+        assert_eq!(activity.per_user["U2"], 1);
+        assert_eq!(activity.per_user.len(), 2);
+        let days: Vec<_> = activity
+            .per_day
+            .iter()
+            .map(|(day, &count)| (day.to_string(), count))
+            .collect();
+        assert_eq!(
+            days,
+            vec![("2023-11-14".to_owned(), 2), ("2023-11-16".to_owned(), 1)]
+        );
+        assert_eq!(activity.threads, 1);
+        assert!((activity.thread_ratio() - 1.0 / 3.0).abs() < 1e-9);
+        let top: Vec<_> = activity
+            .top_reacted
+            .iter()
+            .map(|m| (m.user.as_deref(), m.reactions))
+            .collect();
+        assert_eq!(top, vec![(Some("U1"), 2), (Some("U2"), 1)]);
+    }
+}
@@ -0,0 +1,227 @@
+//! Keeps messages to the same channel in order while sending to different channels at once.
+
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+use std::task::{Context, Poll, Waker};
+
+use super::chat;
+use super::requests::SlackWebRequestSender;
+use crate::mod_types::chat_types::{PostMessageError, PostMessageRequest, PostMessageResponse};
+
+/// Serializes calls per channel, in the order they were queued, while calls to different
+/// channels run concurrently.
+///
+/// Sending a burst of messages concurrently lets them reach Slack out of order. Queueing them
+/// here instead keeps each channel's messages in the order `post_message` was called, without
+/// holding up other channels. A call that's dropped before its turn gives up its place.
+#[derive(Debug, Default)]
+pub struct ChannelQueue {
+    lanes: Mutex<HashMap<String, Lane>>,
+}
+
+#[derive(Debug, Default)]
+struct Lane {
+    /// The ticket the next call queued gets.
+    next: u64,
+    /// The ticket whose turn it is.
+    serving: u64,
+    waiting: HashMap<u64, Waker>,
+    abandoned: HashSet<u64>,
+}
+
+impl ChannelQueue {
+    pub fn new() -> Self {
+        ChannelQueue::default()
+    }
+
+    /// Posts a message once the messages queued for its channel before it have been posted.
+    ///
+    /// The message takes its place in the queue when this is called, not when the returned
+    /// future is first polled.
+    pub fn post_message<'a, R>(
+        &'a self,
+        client: &'a R,
+        token: &'a str,
+        request: &'a PostMessageRequest<'a>,
+    ) -> impl Future<Output = Result<PostMessageResponse, PostMessageError<R::Error>>> + 'a
+    where
+        R: SlackWebRequestSender,
+    {
+        self.run(request.channel, move || {
+            chat::post_message(client, token, request)
+        })
+    }
+
+    /// Runs the future `call` returns once the calls queued for `channel` before it have
+    /// finished, such as a `chat.update` that must follow the post it edits.
+    pub fn run<'a, F, Fut>(
+        &'a self,
+        channel: &str,
+        call: F,
+    ) -> impl Future<Output = Fut::Output> + 'a
+    where
+        F: FnOnce() -> Fut + 'a,
+        Fut: Future + 'a,
+    {
+        let turn = self.queue(channel);
+        async move {
+            let done = turn.await;
+            let output = call().await;
+            drop(done);
+            output
+        }
+    }
+
+    /// The number of channels with calls queued or running.
+    pub fn busy_channels(&self) -> usize {
+        self.lanes.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    fn queue(&self, channel: &str) -> Turn<'_> {
+        let mut lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        let lane = lanes.entry(channel.to_owned()).or_default();
+        let ticket = lane.next;
+        lane.next += 1;
+        Turn {
+            queue: self,
+            channel: channel.to_owned(),
+            ticket,
+            started: false,
+        }
+    }
+
+    /// Passes the turn on from `ticket`, either finished or given up.
+    fn finish(&self, channel: &str, ticket: u64) {
+        let mut lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        let lane = match lanes.get_mut(channel) {
+            Some(lane) => lane,
+            None => return,
+        };
+        if lane.serving != ticket {
+            lane.waiting.remove(&ticket);
+            lane.abandoned.insert(ticket);
+            return;
+        }
+        lane.serving += 1;
+        while lane.abandoned.remove(&lane.serving) {
+            lane.serving += 1;
+        }
+        if lane.serving == lane.next {
+            lanes.remove(channel);
+        } else if let Some(waker) = lane.waiting.remove(&lane.serving) {
+            waker.wake();
+        }
+    }
+}
+
+/// A place in a channel's queue, which resolves to a [`Done`] guard when it's this call's turn.
+struct Turn<'a> {
+    queue: &'a ChannelQueue,
+    channel: String,
+    ticket: u64,
+    started: bool,
+}
+
+impl<'a> Future for Turn<'a> {
+    type Output = Done<'a>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let turn = self.get_mut();
+        let mut lanes = turn.queue.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        let lane = lanes
+            .get_mut(&turn.channel)
+            .expect("a queued call's lane is kept until it finishes");
+        if lane.serving == turn.ticket {
+            turn.started = true;
+            Poll::Ready(Done {
+                queue: turn.queue,
+                channel: turn.channel.clone(),
+                ticket: turn.ticket,
+            })
+        } else {
+            lane.waiting.insert(turn.ticket, cx.waker().clone());
+            Poll::Pending
+        }
+    }
+}
+
+impl Drop for Turn<'_> {
+    fn drop(&mut self) {
+        if !self.started {
+            self.queue.finish(&self.channel, self.ticket);
+        }
+    }
+}
+
+/// Passes the turn on when the call finishes or is dropped.
+struct Done<'a> {
+    queue: &'a ChannelQueue,
+    channel: String,
+    ticket: u64,
+}
+
+impl Drop for Done<'_> {
+    fn drop(&mut self) {
+        self.queue.finish(&self.channel, self.ticket);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures_util::future::join_all;
+    use std::time::Duration;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// Takes as many milliseconds to post a message as the number in its text.
+    fn slow() -> MockSender {
+        MockSender::new()
+            .on("chat.postMessage", r#"{"ok": true}"#)
+            .delay(|call| Duration::from_millis(call.param("text").parse().unwrap()))
+    }
+
+    #[tokio::test]
+    async fn test_channel_queue_keeps_channel_order() {
+        let queue = ChannelQueue::new();
+        let client = slow();
+        let requests: Vec<_> = [("C1", "60"), ("C1", "1"), ("C2", "20"), ("C1", "2")]
+            .iter()
+            .map(|&(channel, text)| PostMessageRequest {
+                channel,
+                text,
+                ..PostMessageRequest::default()
+            })
+            .collect();
+        let posts: Vec<_> = requests
+            .iter()
+            .map(|request| queue.post_message(&client, "xoxb", request))
+            .collect();
+        assert_eq!(queue.busy_channels(), 2);
+        for result in join_all(posts).await {
+            result.unwrap();
+        }
+        let posted: Vec<_> = client
+            .calls()
+            .iter()
+            .map(|call| format!("{} {}", call.param("channel"), call.param("text")))
+            .collect();
+        assert_eq!(posted, ["C2 20", "C1 60", "C1 1", "C1 2"]);
+        assert_eq!(queue.busy_channels(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_call_gives_up_its_turn() {
+        let queue = ChannelQueue::new();
+        let first = queue.run("C1", || async { 1 });
+        let dropped = queue.run("C1", || async { 2 });
+        let last = queue.run("C1", || async { 3 });
+        drop(dropped);
+        assert_eq!(first.await, 1);
+        assert_eq!(last.await, 3);
+        assert_eq!(queue.busy_channels(), 0);
+    }
+}
@@ -0,0 +1,146 @@
+//! A request sender bundled with the token it authenticates with.
+
+use crate::custom_method::{check_ok, parse_response, to_params};
+use crate::mod_types::auth_types::TestError;
+use crate::requests::SlackWebRequestSender;
+use crate::response_buffer::ResponseBuffer;
+use crate::{CallMethodError, Identity};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::sync::{Arc, OnceLock};
+
+/// Pairs a [`SlackWebRequestSender`](crate::requests::SlackWebRequestSender) with the token to
+/// send requests with.
+///
+/// The generated API functions take the sender and token separately; use [`sender()`] and
+/// [`token()`] to pass them along.
+///
+/// [`sender()`]: #method.sender
+/// [`token()`]: #method.token
+#[derive(Clone, Debug)]
+pub struct SlackClient<R> {
+    sender: R,
+    token: String,
+    /// Shared by clones, so `auth.test` is called once for all of them.
+    identity: Arc<OnceLock<Identity>>,
+}
+
+impl<R> SlackClient<R> {
+    pub fn new<T: Into<String>>(sender: R, token: T) -> Self {
+        SlackClient {
+            sender,
+            token: token.into(),
+            identity: Arc::default(),
+        }
+    }
+
+    pub fn sender(&self) -> &R {
+        &self.sender
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Who the token belongs to, once [`whoami()`](#method.whoami) has looked it up.
+    pub fn identity(&self) -> Option<&Identity> {
+        self.identity.get()
+    }
+
+    /// The token's user ID, such as the bot user's, once [`whoami()`](#method.whoami) has looked it
+    /// up.
+    pub fn self_user_id(&self) -> Option<&str> {
+        self.identity().map(|identity| identity.user_id.as_str())
+    }
+
+    /// The token's team ID, once [`whoami()`](#method.whoami) has looked it up.
+    pub fn team_id(&self) -> Option<&str> {
+        self.identity().map(|identity| identity.team_id.as_str())
+    }
+}
+
+impl<R: SlackWebRequestSender> SlackClient<R> {
+    /// Looks up who the token belongs to with `auth.test`, caching the answer for this client and
+    /// its clones, so later calls and [`self_user_id()`](#method.self_user_id) don't send a
+    /// request.
+    ///
+    /// Concurrent calls made before the first has finished may each call `auth.test`.
+    pub async fn whoami(&self) -> Result<&Identity, TestError<R::Error>> {
+        if let Some(identity) = self.identity.get() {
+            return Ok(identity);
+        }
+        let response = crate::auth::test(&self.sender, &self.token).await?;
+        Ok(self.identity.get_or_init(|| response.into()))
+    }
+
+    /// Calls a Web API method this crate doesn't wrap, such as an internal or undocumented one.
+    ///
+    /// The fields of `request` are sent as parameters alongside the token: `None` fields are left
+    /// out, booleans are sent as `1`/`0`, and nested values as JSON. The response is checked for
+    /// `ok` before being deserialized as `Resp`.
+    pub async fn call_method<Req, Resp>(
+        &self,
+        method: &str,
+        request: &Req,
+    ) -> Result<Resp, CallMethodError<R::Error>>
+    where
+        Req: Serialize + ?Sized,
+        Resp: DeserializeOwned,
+    {
+        let mut params = to_params(request).map_err(CallMethodError::InvalidRequest)?;
+        params.push(("token".to_owned(), self.token.clone()));
+        let url = crate::get_slack_url_for_method(method);
+        let mut body = ResponseBuffer::take();
+        self.sender
+            .send_into(&url, &params[..], &mut body)
+            .await
+            .map_err(CallMethodError::Client)?;
+        parse_response(&body)
+    }
+
+    /// Like [`call_method()`](#method.call_method), but returns the response body once `ok` is
+    /// checked, for parsing into the borrowed types in [`crate::borrowed`].
+    pub async fn call_method_raw<Req>(
+        &self,
+        method: &str,
+        request: &Req,
+    ) -> Result<String, CallMethodError<R::Error>>
+    where
+        Req: Serialize + ?Sized,
+    {
+        let mut params = to_params(request).map_err(CallMethodError::InvalidRequest)?;
+        params.push(("token".to_owned(), self.token.clone()));
+        let url = crate::get_slack_url_for_method(method);
+        self.sender
+            .send(&url, &params[..])
+            .await
+            .map_err(CallMethodError::Client)
+            .and_then(check_ok)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    #[tokio::test]
+    async fn test_whoami_is_cached() {
+        let sender = MockSender::new().on(
+            "auth.test",
+            r#"{"ok": true, "url": "https://acme.slack.com/", "team": "Acme",
+                "user": "deploybot", "team_id": "T1", "user_id": "U1", "bot_id": "B1"}"#,
+        );
+        let client = SlackClient::new(sender, "xoxb");
+        assert_eq!(client.self_user_id(), None);
+
+        let identity = client.whoami().await.unwrap();
+        assert_eq!(identity.bot_id.as_deref(), Some("B1"));
+        let clone = client.clone();
+        clone.whoami().await.unwrap();
+        assert_eq!(clone.self_user_id(), Some("U1"));
+        assert_eq!(clone.team_id(), Some("T1"));
+        assert_eq!(client.sender().calls().len(), 1);
+    }
+}
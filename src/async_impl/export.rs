@@ -0,0 +1,410 @@
+//! Archives a workspace into a directory of JSON Lines files.
+//!
+//! An export holds `users.jsonl`, `conversations.jsonl`, `emoji.jsonl` and `files.jsonl` (file
+//! metadata, not their contents), and a file of messages for each channel in [`MESSAGES_DIR`],
+//! where each thread's replies follow the message they reply to. Each line is a record as Slack
+//! returned it.
+
+pub use crate::export_types::*;
+
+use std::fs;
+use std::time::Duration;
+
+use futures_timer::Delay;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::requests::{RateLimitRetry, SlackWebRequestSender};
+use super::SlackClient;
+use crate::export_types::{
+    is_not_in_channel, next_cursor, read_ids, take_records, thread_ts, Checkpoint, JsonLines,
+    PageRequest, Part, CONVERSATIONS, EMOJI, FILES, MESSAGE_COLUMNS, USERS,
+};
+use crate::rate_limit::RetryPolicy;
+use crate::CallMethodError;
+
+/// Exports the users, conversations, messages, file metadata and custom emoji of a workspace into
+/// `request.dir`.
+///
+/// Requests are paced by the request's `interval`, and rate limited requests are retried as the
+/// request's `retry_policy` allows. Each finished file is recorded in a checkpoint, so exporting into the same directory
+/// again after an error only fetches what's missing.
+pub async fn export<R>(
+    client: &SlackClient<R>,
+    request: &ExportRequest<'_>,
+) -> Result<ExportSummary, ExportError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let dir = request.dir;
+    fs::create_dir_all(dir.join(MESSAGES_DIR))?;
+    let mut checkpoint = Checkpoint::load(dir)?;
+    let interval = request.interval.unwrap_or(DEFAULT_EXPORT_INTERVAL);
+    let mut pacer = Pacer::new(client, interval, request.retry_policy.clone());
+    let mut summary = ExportSummary::default();
+
+    if !checkpoint.is_done(&USERS) {
+        let page = PageRequest {
+            limit: Some(200),
+            ..Default::default()
+        };
+        summary.users = pacer
+            .export_part(request, &USERS, "users.list", page, "members")
+            .await?;
+        checkpoint.done(dir, &USERS)?;
+    }
+
+    if !checkpoint.is_done(&CONVERSATIONS) {
+        let page = PageRequest {
+            types: request.types,
+            limit: Some(200),
+            ..Default::default()
+        };
+        summary.conversations = pacer
+            .export_part(
+                request,
+                &CONVERSATIONS,
+                "conversations.list",
+                page,
+                "channels",
+            )
+            .await?;
+        checkpoint.done(dir, &CONVERSATIONS)?;
+    }
+
+    if !checkpoint.is_done(&EMOJI) {
+        let mut out = JsonLines::create(dir, EMOJI.name, EMOJI.columns)?;
+        let mut page = pacer.call("emoji.list", &PageRequest::default()).await?;
+        if let Value::Object(emoji) = page["emoji"].take() {
+            for (name, url) in emoji {
+                out.write(&json!({ "name": name, "url": url }))?;
+            }
+        }
+        summary.emoji = out.finish(request)?;
+        checkpoint.done(dir, &EMOJI)?;
+    }
+
+    if !checkpoint.is_done(&FILES) {
+        let mut out = JsonLines::create(dir, FILES.name, FILES.columns)?;
+        let mut number = 1;
+        loop {
+            let request = PageRequest {
+                page: Some(number),
+                count: Some(200),
+                ..Default::default()
+            };
+            let mut page = pacer.call("files.list", &request).await?;
+            for file in take_records(&mut page, "files") {
+                out.write(&file)?;
+            }
+            let pages = page["paging"]["pages"].as_u64().unwrap_or(0);
+            if u64::from(number) >= pages {
+                break;
+            }
+            number += 1;
+        }
+        summary.files = out.finish(request)?;
+        checkpoint.done(dir, &FILES)?;
+    }
+
+    let oldest = request.oldest.map(|ts| ts.to_param_value());
+    for channel in read_ids(dir, &CONVERSATIONS)? {
+        if checkpoint.is_channel_done(&channel) {
+            continue;
+        }
+        let name = format!("{}/{}", MESSAGES_DIR, channel);
+        let mut out = JsonLines::create(dir, &name, MESSAGE_COLUMNS)?;
+        match pacer
+            .export_messages(&mut out, &channel, oldest.as_deref())
+            .await
+        {
+            Ok(()) => {}
+            Err(ref e) if is_not_in_channel(e) => {
+                drop(out);
+                fs::remove_file(dir.join(format!("{}.jsonl", name)))?;
+                summary.skipped_channels.push(channel);
+                continue;
+            }
+            Err(e) => return Err(e),
+        }
+        summary.messages += out.finish(request)?;
+        checkpoint.channel_done(dir, &channel)?;
+    }
+
+    Ok(summary)
+}
+
+/// Spaces out the requests of an export or import and retries those which were rate limited.
+pub(crate) struct Pacer<'a, R> {
+    client: SlackClient<RateLimitRetry<&'a R>>,
+    interval: Duration,
+    sent: bool,
+}
+
+impl<'a, R: SlackWebRequestSender> Pacer<'a, R> {
+    pub(crate) fn new(
+        client: &'a SlackClient<R>,
+        interval: Duration,
+        policy: Option<RetryPolicy>,
+    ) -> Self {
+        let sender = RateLimitRetry::new(client.sender()).policy(policy.unwrap_or_default());
+        Pacer {
+            client: SlackClient::new(sender, client.token()),
+            interval,
+            sent: false,
+        }
+    }
+
+    pub(crate) async fn send<Req>(
+        &mut self,
+        method: &str,
+        request: &Req,
+    ) -> Result<Value, CallMethodError<R::Error>>
+    where
+        Req: Serialize + ?Sized,
+    {
+        if self.sent {
+            Delay::new(self.interval).await;
+        }
+        self.sent = true;
+        self.client.call_method(method, request).await
+    }
+
+    async fn call(
+        &mut self,
+        method: &'static str,
+        request: &PageRequest<'_>,
+    ) -> Result<Value, ExportError<R::Error>> {
+        self.send(method, request)
+            .await
+            .map_err(|error| ExportError::Slack { method, error })
+    }
+
+    /// Writes the `key` records of every page of a cursor paginated method to `part`'s file.
+    async fn export_part(
+        &mut self,
+        request: &ExportRequest<'_>,
+        part: &Part,
+        method: &'static str,
+        first: PageRequest<'_>,
+        key: &str,
+    ) -> Result<usize, ExportError<R::Error>> {
+        let mut out = JsonLines::create(request.dir, part.name, part.columns)?;
+        let mut cursor = None;
+        loop {
+            let page_request = PageRequest {
+                cursor: cursor.as_deref(),
+                ..first
+            };
+            let mut page = self.call(method, &page_request).await?;
+            for record in take_records(&mut page, key) {
+                out.write(&record)?;
+            }
+            cursor = next_cursor(&page);
+            if cursor.is_none() {
+                return out.finish(request);
+            }
+        }
+    }
+
+    /// Writes a channel's messages, each followed by its thread's replies.
+    async fn export_messages(
+        &mut self,
+        out: &mut JsonLines,
+        channel: &str,
+        oldest: Option<&str>,
+    ) -> Result<(), ExportError<R::Error>> {
+        let mut cursor = None;
+        loop {
+            let request = PageRequest {
+                channel: Some(channel),
+                oldest,
+                cursor: cursor.as_deref(),
+                limit: Some(200),
+                ..Default::default()
+            };
+            let mut page = self.call("conversations.history", &request).await?;
+            for message in take_records(&mut page, "messages") {
+                out.write(&message)?;
+                if let Some(ts) = thread_ts(&message) {
+                    self.export_replies(out, channel, ts).await?;
+                }
+            }
+            cursor = next_cursor(&page);
+            if cursor.is_none() {
+                return Ok(());
+            }
+        }
+    }
+
+    async fn export_replies(
+        &mut self,
+        out: &mut JsonLines,
+        channel: &str,
+        ts: &str,
+    ) -> Result<(), ExportError<R::Error>> {
+        let mut cursor = None;
+        loop {
+            let request = PageRequest {
+                channel: Some(channel),
+                ts: Some(ts),
+                cursor: cursor.as_deref(),
+                limit: Some(200),
+                ..Default::default()
+            };
+            let mut page = self.call("conversations.replies", &request).await?;
+            for reply in take_records(&mut page, "messages") {
+                // Every page starts with the parent message, which is already written.
+                if reply["ts"].as_str() != Some(ts) {
+                    out.write(&reply)?;
+                }
+            }
+            cursor = next_cursor(&page);
+            if cursor.is_none() {
+                return Ok(());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+
+    use crate::async_impl::test_support::{Call, MockSender};
+
+    fn first_page(call: &Call) {
+        assert_eq!(
+            call.param("cursor"),
+            "",
+            "unexpected call to {}",
+            call.method
+        );
+    }
+
+    /// Serves a small workspace, failing `conversations.history` for C2 until `fixed` is set.
+    fn workspace(fixed: Arc<AtomicBool>) -> MockSender {
+        MockSender::new()
+            .on_call("users.list", |call| match call.param("cursor") {
+                "" => json!({"ok": true, "members": [{"id": "U1"}],
+                    "response_metadata": {"next_cursor": "u2"}}),
+                "u2" => json!({"ok": true, "members": [{"id": "U2"}],
+                    "response_metadata": {"next_cursor": ""}}),
+                cursor => panic!("unexpected call to users.list at {}", cursor),
+            })
+            .on_call("conversations.list", |call| {
+                first_page(call);
+                json!({"ok": true, "channels": [{"id": "C1"}, {"id": "C2"}, {"id": "C3"}]})
+            })
+            .on_call("emoji.list", |call| {
+                first_page(call);
+                json!({"ok": true, "emoji": {"party": "https://e/p.gif"}})
+            })
+            .on_call("files.list", |call| {
+                first_page(call);
+                json!({"ok": true, "files": [{"id": "F1"}], "paging": {"page": 1, "pages": 1}})
+            })
+            .on_call("conversations.history", move |call| {
+                first_page(call);
+                match call.param("channel") {
+                    "C1" => json!({"ok": true, "messages": [
+                        {"type": "message", "text": "b", "ts": "2.000000"},
+                        {"type": "message", "text": "a", "ts": "1.000000",
+                         "thread_ts": "1.000000", "reply_count": 1},
+                    ]}),
+                    "C2" if fixed.load(Ordering::SeqCst) => {
+                        json!({"ok": true, "messages": [{"type": "message", "ts": "3.000000"}]})
+                    }
+                    "C2" => json!({"ok": false, "error": "fatal_error"}),
+                    _ => json!({"ok": false, "error": "not_in_channel"}),
+                }
+            })
+            .on_call("conversations.replies", |call| {
+                first_page(call);
+                json!({"ok": true, "messages": [
+                    {"type": "message", "text": "a", "ts": "1.000000", "thread_ts": "1.000000"},
+                    {"type": "message", "text": "re", "ts": "1.500000", "thread_ts": "1.000000"},
+                ]})
+            })
+    }
+
+    fn export_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("slack-export-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn timestamps(dir: &std::path::Path, channel: &str) -> Vec<String> {
+        fs::read_to_string(dir.join(MESSAGES_DIR).join(format!("{}.jsonl", channel)))
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str::<Value>(line).unwrap()["ts"].to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_export_resumes_from_checkpoint() {
+        let dir = export_dir("resume");
+        let fixed = Arc::new(AtomicBool::new(false));
+        let client = SlackClient::new(workspace(fixed.clone()), "xoxp");
+        let mut request = ExportRequest::new(&dir);
+        request.interval = Some(Duration::from_millis(0));
+
+        match export(&client, &request).await {
+            Err(ExportError::Slack {
+                method: "conversations.history",
+                ..
+            }) => {}
+            other => panic!("expected C2's history to fail but got {:?}", other),
+        }
+        assert_eq!(
+            timestamps(&dir, "C1"),
+            [r#""2.000000""#, r#""1.000000""#, r#""1.500000""#]
+        );
+
+        fixed.store(true, Ordering::SeqCst);
+        let summary = export(&client, &request).await.unwrap();
+        assert_eq!(
+            summary,
+            ExportSummary {
+                messages: 1,
+                skipped_channels: vec!["C3".to_owned()],
+                ..Default::default()
+            }
+        );
+        assert_eq!(timestamps(&dir, "C2"), [r#""3.000000""#]);
+        assert!(!dir.join(MESSAGES_DIR).join("C3.jsonl").exists());
+        assert_eq!(
+            fs::read_to_string(dir.join("users.jsonl")).unwrap(),
+            "{\"id\":\"U1\"}\n{\"id\":\"U2\"}\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("emoji.jsonl")).unwrap(),
+            "{\"name\":\"party\",\"url\":\"https://e/p.gif\"}\n"
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(feature = "parquet")]
+    #[tokio::test]
+    async fn test_export_writes_parquet() {
+        use parquet_::file::reader::{FileReader, SerializedFileReader};
+
+        let dir = export_dir("parquet");
+        let client = SlackClient::new(workspace(Arc::new(AtomicBool::new(true))), "xoxp");
+        let mut request = ExportRequest::new(&dir);
+        request.interval = Some(Duration::from_millis(0));
+        request.parquet = true;
+        export(&client, &request).await.unwrap();
+
+        let file = fs::File::open(dir.join(MESSAGES_DIR).join("C1.parquet")).unwrap();
+        let reader = SerializedFileReader::new(file).unwrap();
+        assert_eq!(reader.metadata().file_metadata().num_rows(), 3);
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
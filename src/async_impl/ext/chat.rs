@@ -0,0 +1,220 @@
+pub use crate::async_impl::history::{
+    delete_range, DeleteRangeError, DeleteRangeRequest, DEFAULT_DELETE_INTERVAL,
+};
+pub use crate::ext_types::chat::*;
+
+use async_trait::async_trait;
+use std::borrow::Borrow;
+
+use crate::chat::{post_ephemeral, post_message};
+use crate::conversations::open;
+use crate::ext_types::chat::form_body;
+use crate::ext_types::files::UploadProgress;
+use crate::mod_types::chat_types::{
+    PostEphemeralError, PostEphemeralRequest, PostMessageError, PostMessageRequest,
+    PostMessageResponse,
+};
+use crate::mod_types::conversations_types::OpenRequest;
+use crate::requests::{SlackWebRequestSender, SlackWebUploadSender};
+
+/// Posts an ephemeral message to `request.user` in `request.channel`, or sends it to them as a
+/// direct message if they aren't in the channel.
+///
+/// The direct message isn't threaded, since `thread_ts` belongs to the channel.
+pub async fn post_ephemeral_or_dm<R>(
+    client: &R,
+    token: &str,
+    request: &PostEphemeralRequest<'_>,
+) -> Result<EphemeralDelivery, PostEphemeralOrDmError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    match post_ephemeral(client, token, request).await {
+        Ok(response) => return Ok(EphemeralDelivery::Ephemeral(response)),
+        Err(PostEphemeralError::UserNotInChannel) => {}
+        Err(e) => return Err(PostEphemeralOrDmError::Ephemeral(e)),
+    }
+    let im = open(
+        client,
+        token,
+        &OpenRequest {
+            users: Some(request.user),
+            ..OpenRequest::default()
+        },
+    )
+    .await
+    .map_err(PostEphemeralOrDmError::Open)?
+    .channel
+    .and_then(|c| c.id)
+    // Slack also takes the user's ID as the channel of their direct message with the app.
+    .unwrap_or_else(|| request.user.to_owned());
+    post_message(
+        client,
+        token,
+        &PostMessageRequest {
+            channel: &im,
+            text: request.text,
+            parse: request.parse,
+            link_names: request.link_names,
+            attachments: request.attachments,
+            blocks: request.blocks,
+            username: request.username,
+            as_user: request.as_user,
+            icon_url: request.icon_url,
+            icon_emoji: request.icon_emoji,
+            ..PostMessageRequest::default()
+        },
+    )
+    .await
+    .map(|response| EphemeralDelivery::Direct(Box::new(response)))
+    .map_err(PostEphemeralOrDmError::Direct)
+}
+
+/// Posts a message like `chat::post_message`, but sends it as a POST body in chunks, calling
+/// `on_progress` as they're sent, for messages large enough to show the progress of.
+pub async fn post_message_with_progress<R>(
+    client: &R,
+    token: &str,
+    request: &PostMessageRequest<'_>,
+    on_progress: &(dyn Fn(UploadProgress) + Send + Sync),
+) -> Result<PostMessageResponse, PostMessageError<R::Error>>
+where
+    R: SlackWebUploadSender + Sync,
+{
+    let client = FormSender {
+        sender: client,
+        on_progress,
+    };
+    post_message(&client, token, request).await
+}
+
+/// Sends the parameters of a call as a form body with `upload`, rather than in the query string.
+struct FormSender<'a, R> {
+    sender: &'a R,
+    on_progress: &'a (dyn Fn(UploadProgress) + Send + Sync),
+}
+
+#[async_trait]
+impl<R> SlackWebRequestSender for FormSender<'_, R>
+where
+    R: SlackWebUploadSender + Sync,
+{
+    type Error = R::Error;
+
+    async fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let body = form_body(params);
+        self.sender
+            .upload(
+                method_url.as_ref(),
+                "application/x-www-form-urlencoded",
+                body.len() as u64,
+                body.as_bytes(),
+                self.on_progress,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// `C1` has `U1` but not `U2`, whose direct message with the app is `D2`.
+    fn channel() -> MockSender {
+        MockSender::new()
+            .on_call("chat.postEphemeral", |call| {
+                if call.param("user") == "U1" {
+                    r#"{"ok": true, "message_ts": "1.000001"}"#
+                } else {
+                    r#"{"ok": false, "error": "user_not_in_channel"}"#
+                }
+            })
+            .on(
+                "conversations.open",
+                r#"{"ok": true, "channel": {"id": "D2"}}"#,
+            )
+            .on("chat.postMessage", r#"{"ok": true, "ts": "1.000002"}"#)
+    }
+
+    #[tokio::test]
+    async fn test_post_message_with_progress() {
+        let client = MockSender::new().on("upload", r#"{"ok": true, "ts": "1.000002"}"#);
+        let request = PostMessageRequest {
+            channel: "C1",
+            text: "50% done",
+            ..PostMessageRequest::default()
+        };
+        let progress = Mutex::new(Vec::new());
+        let response = post_message_with_progress(&client, "xoxb", &request, &|p| {
+            progress.lock().unwrap().push(p)
+        })
+        .await
+        .unwrap();
+        assert_eq!(response.ts, Some(crate::Timestamp::from(1.000002)));
+        let body = "token=xoxb&channel=C1&text=50%25+done";
+        let upload = &client.calls_to("upload")[0];
+        assert_eq!(
+            upload.param("url"),
+            "https://slack.com/api/chat.postMessage"
+        );
+        assert_eq!(
+            upload.param("content_type"),
+            "application/x-www-form-urlencoded"
+        );
+        assert_eq!(upload.param("body"), body);
+        assert_eq!(
+            *progress.lock().unwrap(),
+            [UploadProgress {
+                sent: body.len() as u64,
+                total: body.len() as u64,
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_post_ephemeral_or_dm() {
+        let client = channel();
+        let request = PostEphemeralRequest {
+            channel: "C1",
+            text: "Only you can see this",
+            user: "U1",
+            ..PostEphemeralRequest::default()
+        };
+        let delivery = post_ephemeral_or_dm(&client, "xoxb", &request).await;
+        assert!(matches!(delivery, Ok(EphemeralDelivery::Ephemeral(_))));
+        let request = PostEphemeralRequest {
+            user: "U2",
+            ..request
+        };
+        let delivery = post_ephemeral_or_dm(&client, "xoxb", &request).await;
+        assert!(matches!(delivery, Ok(EphemeralDelivery::Direct(_))));
+        let calls: Vec<_> = client
+            .calls()
+            .iter()
+            .map(|call| {
+                let call = format!("{} {}", call.method, call.param("channel"));
+                call.trim_end().to_owned()
+            })
+            .collect();
+        assert_eq!(
+            calls,
+            [
+                "chat.postEphemeral C1",
+                "chat.postEphemeral C1",
+                "conversations.open",
+                "chat.postMessage D2"
+            ]
+        );
+    }
+}
@@ -0,0 +1,264 @@
+pub use crate::ext_types::conversations::*;
+
+use std::collections::HashSet;
+use std::slice;
+
+use crate::bookmarks;
+use crate::conversations::{info, invite, kick, members};
+use crate::ext_types::conversations::{is_user_invite_error, is_user_kick_error, MAX_INVITE_USERS};
+use crate::mod_types::bookmarks_types::{
+    ListError as BookmarksListError, ListRequest as BookmarksListRequest,
+};
+use crate::mod_types::conversations_types::{
+    InfoError, InfoRequest, InviteError, InviteRequest, KickError, KickRequest, MembersError,
+    MembersRequest,
+};
+use crate::requests::SlackWebRequestSender;
+use crate::UserId;
+
+/// Invites `users` to `channel`, carrying on past users who can't be invited.
+///
+/// Users are invited up to 1000 per call. Slack reports only one failure per call, so a call that
+/// fails because of a user is retried one user at a time to find out who can't be invited. An
+/// error that isn't about a user, such as `ChannelNotFound`, stops the batch.
+pub async fn invite_many<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    users: &[UserId],
+) -> MembershipReport<InviteError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut report = MembershipReport::default();
+    for chunk in users.chunks(MAX_INVITE_USERS) {
+        if report.aborted.is_some() {
+            report.skip(chunk);
+            continue;
+        }
+        match invite_users(client, token, channel, chunk).await {
+            Err(ref e) if chunk.len() > 1 && is_user_invite_error(e) => {
+                for user in chunk {
+                    let user = slice::from_ref(user);
+                    if report.aborted.is_some() {
+                        report.skip(user);
+                        continue;
+                    }
+                    let result = invite_users(client, token, channel, user).await;
+                    report.record(user, result, is_user_invite_error);
+                }
+            }
+            result => report.record(chunk, result, is_user_invite_error),
+        }
+    }
+    report
+}
+
+/// Removes `users` from `channel` one at a time, carrying on past users who can't be removed.
+///
+/// An error that isn't about a user, such as `ChannelNotFound`, stops the batch.
+pub async fn kick_many<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    users: &[UserId],
+) -> MembershipReport<KickError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut report = MembershipReport::default();
+    for user in users {
+        let user = slice::from_ref(user);
+        if report.aborted.is_some() {
+            report.skip(user);
+            continue;
+        }
+        let result = kick(
+            client,
+            token,
+            &KickRequest {
+                channel,
+                user: user[0].as_str(),
+            },
+        )
+        .await
+        .map(drop);
+        report.record(user, result, is_user_kick_error);
+    }
+    report
+}
+
+/// Fetches every member of `channel`, following `next_cursor` until the last page.
+///
+/// Compare the result with the members the channel should have using [`MembershipDiff`].
+pub async fn members_all<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<HashSet<UserId>, MembersError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut users = HashSet::new();
+    let mut cursor = None;
+    loop {
+        let page = members(
+            client,
+            token,
+            &MembersRequest {
+                channel,
+                cursor: cursor.as_ref(),
+                limit: Some(1000),
+            },
+        )
+        .await?;
+        users.extend(page.members.into_iter().flatten().map(UserId::new));
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(users);
+        }
+    }
+}
+
+/// Looks up the file ID of `channel`'s canvas, or `None` if it doesn't have one.
+pub async fn canvas_id<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<Option<String>, InfoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let response = info(
+        client,
+        token,
+        &InfoRequest {
+            channel,
+            ..InfoRequest::default()
+        },
+    )
+    .await?;
+    Ok(response
+        .channel
+        .as_ref()
+        .and_then(|channel| channel.canvas_id())
+        .map(str::to_owned))
+}
+
+/// Counts the bookmarks of `channel`.
+pub async fn bookmark_count<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<usize, BookmarksListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let response = bookmarks::list(
+        client,
+        token,
+        &BookmarksListRequest {
+            channel_id: channel,
+        },
+    )
+    .await?;
+    Ok(response.bookmarks.map_or(0, |bookmarks| bookmarks.len()))
+}
+
+async fn invite_users<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    users: &[UserId],
+) -> Result<(), InviteError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    invite(
+        client,
+        token,
+        &InviteRequest {
+            channel,
+            users: &users
+                .iter()
+                .map(UserId::as_str)
+                .collect::<Vec<_>>()
+                .join(","),
+            force: None,
+        },
+    )
+    .await
+    .map(drop)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// Answers `conversations.invite` with `already_in_channel` whenever `U2` is invited and with
+    /// `channel_not_found` for `U4`.
+    fn invites() -> MockSender {
+        MockSender::new().on_call("conversations.invite", |call| {
+            let users = call.param("users");
+            if users.contains("U4") {
+                r#"{"ok": false, "error": "channel_not_found"}"#
+            } else if users.contains("U2") {
+                r#"{"ok": false, "error": "already_in_channel"}"#
+            } else {
+                r#"{"ok": true}"#
+            }
+        })
+    }
+
+    /// Serves the members of a channel in two pages.
+    fn member_pages() -> MockSender {
+        MockSender::new().on_call("conversations.members", |call| {
+            if call.param("cursor") == "next" {
+                r#"{"ok": true, "members": ["U3"], "response_metadata": {"next_cursor": ""}}"#
+            } else {
+                r#"{"ok": true, "members": ["U1", "U2"],
+                    "response_metadata": {"next_cursor": "next"}}"#
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_members_all_diff() {
+        let current = members_all(&member_pages(), "xoxb", "C1").await.unwrap();
+        let desired = ["U2", "U4", "U1"].iter().map(|&u| UserId::new(u)).collect();
+        let diff = MembershipDiff::between(&current, &desired);
+        assert_eq!(diff.to_invite, [UserId::new("U4")]);
+        assert_eq!(diff.to_kick, [UserId::new("U3")]);
+        assert!(MembershipDiff::between(&current, &current).is_empty());
+    }
+
+    fn users(ids: &[&str]) -> Vec<UserId> {
+        ids.iter().map(|&id| UserId::new(id)).collect()
+    }
+
+    #[tokio::test]
+    async fn test_invite_many_reports_each_user() {
+        let sender = invites();
+        let report = invite_many(&sender, "xoxp", "C1", &users(&["U1", "U2", "U3"])).await;
+        assert_eq!(report.succeeded, users(&["U1", "U3"]));
+        assert_eq!(report.failed.len(), 1);
+        assert!(matches!(
+            report.failed[0],
+            (ref user, InviteError::AlreadyInChannel) if user.as_str() == "U2"
+        ));
+        assert!(report.aborted.is_none());
+        let invited: Vec<_> = sender
+            .calls()
+            .iter()
+            .map(|call| call.param("users").to_owned())
+            .collect();
+        assert_eq!(invited, ["U1,U2,U3", "U1", "U2", "U3"]);
+
+        let report = invite_many(&sender, "xoxp", "C1", &users(&["U2", "U4", "U5"])).await;
+        assert!(matches!(report.aborted, Some(InviteError::ChannelNotFound)));
+        assert_eq!(report.skipped, users(&["U2", "U4", "U5"]));
+        assert!(!report.is_complete());
+    }
+}
@@ -0,0 +1,133 @@
+pub use crate::ext_types::dnd::*;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::chat::{post_message, schedule_message};
+use crate::dnd::info;
+use crate::ext_types::dnd::schedule_request;
+use crate::mod_types::chat_types::PostMessageRequest;
+use crate::mod_types::dnd_types::InfoRequest;
+use crate::requests::SlackWebRequestSender;
+use crate::Timestamp;
+
+/// Sends `message` to `user`, unless they're in Do Not Disturb, in which case `policy` decides
+/// whether it waits until Do Not Disturb ends or goes to another channel.
+///
+/// If the message's `channel` is empty, it's sent to the user as a direct message from the app.
+pub async fn send_respecting_dnd<R>(
+    client: &R,
+    token: &str,
+    user: &str,
+    message: &PostMessageRequest<'_>,
+    policy: DndPolicy<'_>,
+) -> Result<Delivery, SendRespectingDndError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let status = info(client, token, &InfoRequest { user: Some(user) })
+        .await
+        .map_err(SendRespectingDndError::Info)?;
+    let channel = if message.channel.is_empty() {
+        user
+    } else {
+        message.channel
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |since| since.as_secs_f64());
+    let ends_at = match dnd_ends_at(&status, Timestamp::from(now)) {
+        Some(ends_at) => ends_at,
+        None => {
+            return post_message(
+                client,
+                token,
+                &PostMessageRequest {
+                    channel,
+                    ..message.clone()
+                },
+            )
+            .await
+            .map(Delivery::Sent)
+            .map_err(SendRespectingDndError::Post)
+        }
+    };
+    match policy {
+        DndPolicy::Delay => {
+            schedule_message(client, token, &schedule_request(message, channel, ends_at))
+                .await
+                .map(Delivery::Scheduled)
+                .map_err(SendRespectingDndError::Schedule)
+        }
+        DndPolicy::Fallback(channel) => post_message(
+            client,
+            token,
+            &PostMessageRequest {
+                channel,
+                ..message.clone()
+            },
+        )
+        .await
+        .map(Delivery::FellBack)
+        .map_err(SendRespectingDndError::Post),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// `U1` has snoozed notifications until 2100 and `U2` isn't in Do Not Disturb.
+    fn dnd() -> MockSender {
+        MockSender::new()
+            .on_call("dnd.info", |call| {
+                if call.param("user") == "U1" {
+                    r#"{"ok": true, "snooze_enabled": true, "snooze_endtime": 4102444800}"#
+                } else {
+                    r#"{"ok": true, "dnd_enabled": false, "snooze_enabled": false}"#
+                }
+            })
+            .on_call("chat.scheduleMessage", |call| {
+                assert_eq!(call.param("post_at"), "4102444800");
+                r#"{"ok": true, "scheduled_message_id": "Q1"}"#
+            })
+            .on("chat.postMessage", r#"{"ok": true}"#)
+    }
+
+    #[tokio::test]
+    async fn test_send_respecting_dnd() {
+        let client = dnd();
+        let message = PostMessageRequest {
+            text: "The build is broken",
+            ..PostMessageRequest::default()
+        };
+        let sent = |user, policy| send_respecting_dnd(&client, "xoxb", user, &message, policy);
+        assert!(matches!(
+            sent("U2", DndPolicy::Delay).await.unwrap(),
+            Delivery::Sent(_)
+        ));
+        assert!(matches!(
+            sent("U1", DndPolicy::Delay).await.unwrap(),
+            Delivery::Scheduled(_)
+        ));
+        assert!(matches!(
+            sent("U1", DndPolicy::Fallback("C1")).await.unwrap(),
+            Delivery::FellBack(_)
+        ));
+        let messages: Vec<_> = client
+            .calls()
+            .iter()
+            .filter(|call| call.method.starts_with("chat."))
+            .map(|call| format!("{} {}", call.method, call.param("channel")))
+            .collect();
+        assert_eq!(
+            messages,
+            [
+                "chat.postMessage U2",
+                "chat.scheduleMessage U1",
+                "chat.postMessage C1"
+            ]
+        );
+    }
+}
@@ -0,0 +1,186 @@
+pub use crate::ext_types::files::*;
+
+use futures_util::io::AsyncRead;
+
+use super::pages::prefetch_pages;
+use crate::ext_types::files::{completed_files, parse_completed, parse_upload_url};
+use crate::files::list;
+use crate::mod_types::files_types::{ListError, ListRequest};
+use crate::requests::{SlackWebRequestSender, SlackWebUploadSender};
+use crate::File;
+
+/// Fetches every page of `files.list`, returning the files in order.
+///
+/// Once the first page reveals how many pages there are, the rest are fetched concurrently with
+/// at most `max_concurrent` requests in flight. `request.page` is ignored.
+pub async fn list_all<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+    max_concurrent: usize,
+) -> Result<Vec<File>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let first = list(
+        client,
+        token,
+        &ListRequest {
+            page: Some(1),
+            ..request.clone()
+        },
+    )
+    .await?;
+    let pages = prefetch_pages(first, max_concurrent, |page| {
+        let request = ListRequest {
+            page: Some(page),
+            ..request.clone()
+        };
+        async move { list(client, token, &request).await }
+    })
+    .await?;
+    Ok(pages
+        .into_iter()
+        .flat_map(|page| page.files.unwrap_or_default())
+        .collect())
+}
+
+/// Uploads a file read from `body` and, with `request.channel_id`, shares it, returning the
+/// uploaded files.
+///
+/// This is Slack's external upload flow: `files.getUploadURLExternal` gives a URL the file is
+/// streamed to in chunks, calling `on_progress` as they're sent, then
+/// `files.completeUploadExternal` finishes the upload. `body` must give exactly
+/// `request.length` bytes.
+pub async fn upload_external<R, B>(
+    client: &R,
+    token: &str,
+    request: &UploadExternalRequest<'_>,
+    body: B,
+    on_progress: &(dyn Fn(UploadProgress) + Send + Sync),
+) -> Result<Vec<File>, UploadExternalError<R::Error>>
+where
+    R: SlackWebUploadSender,
+    B: AsyncRead + Send + Unpin,
+{
+    let length = request.length.to_string();
+    let params = [
+        Some(("token", token)),
+        Some(("filename", request.filename)),
+        Some(("length", &length[..])),
+        request.alt_txt.map(|alt_txt| ("alt_txt", alt_txt)),
+        request
+            .snippet_type
+            .map(|snippet_type| ("snippet_type", snippet_type)),
+    ];
+    let url = crate::get_slack_url_for_method("files.getUploadURLExternal");
+    let result = client
+        .send(&url, IntoIterator::into_iter(params).flatten())
+        .await
+        .map_err(UploadExternalError::Client)?;
+    let target = parse_upload_url(result)?;
+
+    client
+        .upload(
+            &target.upload_url,
+            "application/octet-stream",
+            request.length,
+            body,
+            on_progress,
+        )
+        .await
+        .map_err(UploadExternalError::Client)?;
+
+    let files = completed_files(&target.file_id, request);
+    let thread_ts = request.thread_ts.map(|t| t.to_param_value());
+    let params = [
+        Some(("token", token)),
+        Some(("files", &files[..])),
+        request
+            .channel_id
+            .map(|channel_id| ("channel_id", channel_id)),
+        request
+            .initial_comment
+            .map(|initial_comment| ("initial_comment", initial_comment)),
+        thread_ts
+            .as_ref()
+            .map(|thread_ts| ("thread_ts", &thread_ts[..])),
+    ];
+    let url = crate::get_slack_url_for_method("files.completeUploadExternal");
+    let result = client
+        .send(&url, IntoIterator::into_iter(params).flatten())
+        .await
+        .map_err(UploadExternalError::Client)?;
+    parse_completed(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    use crate::async_impl::test_support::MockSender;
+    use crate::ext_types::chat::form_body;
+
+    /// Answers the upload methods and the upload itself.
+    fn uploads() -> MockSender {
+        MockSender::new()
+            .on(
+                "files.getUploadURLExternal",
+                r#"{"ok": true, "upload_url": "https://files.slack.com/upload/v1/abc", "file_id": "F1"}"#,
+            )
+            .on("upload", "OK - 6")
+            .on(
+                "files.completeUploadExternal",
+                r#"{"ok": true, "files": [{"id": "F1", "title": "Chart"}]}"#,
+            )
+    }
+
+    #[tokio::test]
+    async fn test_upload_external() {
+        let client = uploads();
+        let request = UploadExternalRequest {
+            filename: "chart.png",
+            length: 6,
+            title: Some("Chart"),
+            channel_id: Some("C1"),
+            thread_ts: Some(crate::Timestamp::from(1.5)),
+            ..UploadExternalRequest::default()
+        };
+        let progress = Mutex::new(Vec::new());
+        let files = upload_external(&client, "xoxb", &request, &b"pixels"[..], &|p| {
+            progress.lock().unwrap().push(p)
+        })
+        .await
+        .unwrap();
+        assert_eq!(files[0].id.as_deref(), Some("F1"));
+        assert_eq!(
+            *progress.lock().unwrap(),
+            [UploadProgress { sent: 6, total: 6 }]
+        );
+        let calls: Vec<_> = client
+            .calls()
+            .iter()
+            .map(|call| match &call.method[..] {
+                "upload" => format!(
+                    "{} {} {}",
+                    call.param("url"),
+                    call.param("content_type"),
+                    call.param("body")
+                ),
+                method => format!("{} {}", method, form_body(&call.params)),
+            })
+            .collect();
+        assert_eq!(
+            calls,
+            [
+                "files.getUploadURLExternal token=xoxb&filename=chart.png&length=6",
+                "https://files.slack.com/upload/v1/abc application/octet-stream pixels",
+                "files.completeUploadExternal token=xoxb\
+                 &files=%5B%7B%22id%22%3A%22F1%22%2C%22title%22%3A%22Chart%22%7D%5D\
+                 &channel_id=C1&thread_ts=1.500000",
+            ]
+        );
+    }
+}
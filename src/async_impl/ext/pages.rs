@@ -0,0 +1,55 @@
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+use std::future::Future;
+
+use crate::HasPaging;
+
+/// Fetches the pages after `first`, up to the number of pages it reports, with at most
+/// `max_concurrent` requests in flight, returning them after `first` in page order.
+pub(crate) async fn prefetch_pages<T, E, F, Fut>(
+    first: T,
+    max_concurrent: usize,
+    fetch: F,
+) -> Result<Vec<T>, E>
+where
+    T: HasPaging,
+    F: Fn(u32) -> Fut,
+    Fut: Future<Output = Result<T, E>>,
+{
+    let pages = first.pages();
+    let rest = stream::iter(2..=pages)
+        .map(fetch)
+        .buffered(max_concurrent.max(1))
+        .try_collect::<Vec<_>>()
+        .await?;
+    Ok(std::iter::once(first).chain(rest).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Page(u32);
+
+    impl HasPaging for Page {
+        fn paging(&self) -> Option<&crate::Paging> {
+            None
+        }
+
+        fn pages(&self) -> u32 {
+            5
+        }
+    }
+
+    #[tokio::test]
+    async fn test_prefetch_pages_keeps_page_order() {
+        let pages = prefetch_pages(Page(1), 2, |page| async move {
+            // Later pages finish first.
+            futures_timer::Delay::new(std::time::Duration::from_millis(u64::from(10 - page))).await;
+            Ok::<_, ()>(Page(page))
+        })
+        .await
+        .unwrap();
+        assert_eq!(pages, [Page(1), Page(2), Page(3), Page(4), Page(5)]);
+    }
+}
@@ -0,0 +1,117 @@
+pub use crate::ext_types::reactions::*;
+
+use super::pages::prefetch_pages;
+use crate::mod_types::reactions_types::{
+    AddError, AddRequest, ListError, ListRequest, ListResponseItem, RemoveError, RemoveRequest,
+};
+use crate::reactions::{add, list, remove};
+use crate::requests::SlackWebRequestSender;
+
+/// Adds a reaction to an item, or removes it if the authed user has already reacted with it.
+///
+/// Adding and removing are idempotent: a reaction removed by someone else in the meantime is
+/// still reported as removed.
+pub async fn toggle<R>(
+    client: &R,
+    token: &str,
+    name: &str,
+    target: ReactionTarget<'_>,
+) -> Result<Toggled, ToggleError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let (file, file_comment, channel, timestamp) = target.fields();
+    let added = add(
+        client,
+        token,
+        &AddRequest {
+            name,
+            file,
+            file_comment,
+            channel,
+            timestamp,
+        },
+    )
+    .await;
+    match added {
+        Ok(_) => return Ok(Toggled::Added),
+        Err(AddError::AlreadyReacted) => {}
+        Err(e) => return Err(ToggleError::Add(e)),
+    }
+    let removed = remove(
+        client,
+        token,
+        &RemoveRequest {
+            name,
+            file,
+            file_comment,
+            channel,
+            timestamp,
+        },
+    )
+    .await;
+    match removed {
+        Ok(_) | Err(RemoveError::NoReaction) => Ok(Toggled::Removed),
+        Err(e) => Err(ToggleError::Remove(e)),
+    }
+}
+
+/// Fetches every page of `reactions.list`, returning the items in order.
+///
+/// Once the first page reveals how many pages there are, the rest are fetched concurrently with
+/// at most `max_concurrent` requests in flight. `request.page` is ignored.
+pub async fn list_all<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+    max_concurrent: usize,
+) -> Result<Vec<ListResponseItem>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let first = list(
+        client,
+        token,
+        &ListRequest {
+            page: Some(1),
+            ..request.clone()
+        },
+    )
+    .await?;
+    let pages = prefetch_pages(first, max_concurrent, |page| {
+        let request = ListRequest {
+            page: Some(page),
+            ..request.clone()
+        };
+        async move { list(client, token, &request).await }
+    })
+    .await?;
+    Ok(pages
+        .into_iter()
+        .flat_map(|page| page.items.unwrap_or_default())
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    #[tokio::test]
+    async fn test_toggle_removes_existing_reaction() {
+        let sender = MockSender::new()
+            .on(
+                "reactions.add",
+                r#"{"ok": false, "error": "already_reacted"}"#,
+            )
+            .on("reactions.remove", r#"{"ok": true}"#);
+        let target = ReactionTarget::Message {
+            channel: "C1",
+            timestamp: crate::Timestamp::from(1),
+        };
+        let toggled = toggle(&sender, "xoxp", "thumbsup", target).await.unwrap();
+        assert_eq!(toggled, Toggled::Removed);
+        assert_eq!(sender.methods(), ["reactions.add", "reactions.remove"]);
+    }
+}
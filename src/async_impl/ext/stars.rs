@@ -0,0 +1,116 @@
+use super::pages::prefetch_pages;
+use crate::mod_types::stars_types::{ListError, ListRequest, ListResponseItem};
+use crate::requests::SlackWebRequestSender;
+use crate::stars::list;
+
+/// Fetches every page of `stars.list`, returning the items in order.
+///
+/// Once the first page reveals how many pages there are, the rest are fetched concurrently with
+/// at most `max_concurrent` requests in flight. `request.page` and `request.cursor`
+/// are ignored.
+pub async fn list_all<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+    max_concurrent: usize,
+) -> Result<Vec<ListResponseItem>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let first = list(
+        client,
+        token,
+        &ListRequest {
+            page: Some(1),
+            cursor: None,
+            ..request.clone()
+        },
+    )
+    .await?;
+    let pages = prefetch_pages(first, max_concurrent, |page| {
+        let request = ListRequest {
+            page: Some(page),
+            cursor: None,
+            ..request.clone()
+        };
+        async move { list(client, token, &request).await }
+    })
+    .await?;
+    Ok(pages
+        .into_iter()
+        .flat_map(|page| page.items.unwrap_or_default())
+        .collect())
+}
+
+/// Fetches every page of `stars.list` by following `next_cursor`, returning the items in order.
+///
+/// Pages are fetched one after another, starting from `request.cursor`. `request.page` is
+/// ignored.
+pub async fn list_all_by_cursor<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<Vec<ListResponseItem>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut items = Vec::new();
+    let mut cursor = request.cursor.cloned();
+    loop {
+        let page = list(
+            client,
+            token,
+            &ListRequest {
+                cursor: cursor.as_ref(),
+                page: None,
+                ..request.clone()
+            },
+        )
+        .await?;
+        items.extend(page.items.into_iter().flatten());
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(items);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// Serves two pages of `stars.list` by cursor.
+    fn cursor_pages() -> MockSender {
+        MockSender::new().on_call("stars.list", |call| {
+            assert_eq!(call.param("page"), "");
+            if call.param("cursor") == "next" {
+                r#"{"ok": true, "items": [{"type": "channel", "channel": "C2"}]}"#
+            } else {
+                r#"{"ok": true, "items": [{"type": "channel", "channel": "C1"}],
+                    "response_metadata": {"next_cursor": "next"}}"#
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_all_by_cursor() {
+        let request = ListRequest {
+            limit: Some(1),
+            page: Some(3),
+            ..Default::default()
+        };
+        let items = list_all_by_cursor(&cursor_pages(), "xoxp", &request)
+            .await
+            .unwrap();
+        let channels: Vec<_> = items
+            .iter()
+            .map(|item| match item {
+                ListResponseItem::Channel(c) => c.channel.as_str(),
+                _ => panic!("expected a channel"),
+            })
+            .collect();
+        assert_eq!(channels, ["C1", "C2"]);
+    }
+}
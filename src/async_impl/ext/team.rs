@@ -0,0 +1,40 @@
+use super::pages::prefetch_pages;
+use crate::mod_types::team_types::{AccessLogsError, AccessLogsRequest, AccessLogsResponseLogin};
+use crate::requests::SlackWebRequestSender;
+use crate::team::access_logs;
+
+/// Fetches every page of `team.accessLogs`, returning the logins in order.
+///
+/// Once the first page reveals how many pages there are, the rest are fetched concurrently with
+/// at most `max_concurrent` requests in flight. `request.page` is ignored.
+pub async fn access_logs_all<R>(
+    client: &R,
+    token: &str,
+    request: &AccessLogsRequest,
+    max_concurrent: usize,
+) -> Result<Vec<AccessLogsResponseLogin>, AccessLogsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let first = access_logs(
+        client,
+        token,
+        &AccessLogsRequest {
+            page: Some(1),
+            ..request.clone()
+        },
+    )
+    .await?;
+    let pages = prefetch_pages(first, max_concurrent, |page| {
+        let request = AccessLogsRequest {
+            page: Some(page),
+            ..request.clone()
+        };
+        async move { access_logs(client, token, &request).await }
+    })
+    .await?;
+    Ok(pages
+        .into_iter()
+        .flat_map(|page| page.logins.unwrap_or_default())
+        .collect())
+}
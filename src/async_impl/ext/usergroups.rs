@@ -0,0 +1,91 @@
+pub use crate::ext_types::usergroups::*;
+
+use crate::mod_types::usergroups_users_types::{ListRequest, UpdateRequest};
+use crate::requests::SlackWebRequestSender;
+use crate::usergroups_users::{list, update};
+
+/// Makes `desired` the members of a User Group, returning who was added and removed.
+///
+/// The group is only updated if its members differ, so calling this on every rotation of an
+/// on-call schedule costs one request when nothing changed.
+pub async fn sync_members<R>(
+    client: &R,
+    token: &str,
+    usergroup: &str,
+    desired: &[&str],
+) -> Result<MembershipChange, SyncMembersError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    if desired.is_empty() {
+        return Err(SyncMembersError::NoMembers);
+    }
+    let current = list(
+        client,
+        token,
+        &ListRequest {
+            usergroup,
+            include_disabled: Some(true),
+        },
+    )
+    .await
+    .map_err(SyncMembersError::List)?
+    .users
+    .unwrap_or_default();
+    let change = MembershipChange::diff(&current, desired);
+    if !change.is_empty() {
+        update(
+            client,
+            token,
+            &UpdateRequest {
+                usergroup,
+                users: &desired.join(","),
+                include_count: None,
+            },
+        )
+        .await
+        .map_err(SyncMembersError::Update)?;
+    }
+    Ok(change)
+}
+
+/// Adds `user` to a User Group, keeping its other members. Returns `false` without updating the
+/// group if the user was already a member.
+pub async fn add_member<R>(
+    client: &R,
+    token: &str,
+    usergroup: &str,
+    user: &str,
+) -> Result<bool, SyncMembersError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut members = list(
+        client,
+        token,
+        &ListRequest {
+            usergroup,
+            include_disabled: Some(true),
+        },
+    )
+    .await
+    .map_err(SyncMembersError::List)?
+    .users
+    .unwrap_or_default();
+    if members.iter().any(|u| u == user) {
+        return Ok(false);
+    }
+    members.push(user.to_owned());
+    update(
+        client,
+        token,
+        &UpdateRequest {
+            usergroup,
+            users: &members.join(","),
+            include_count: None,
+        },
+    )
+    .await
+    .map_err(SyncMembersError::Update)?;
+    Ok(true)
+}
@@ -0,0 +1,75 @@
+pub use crate::ext_types::users::*;
+
+use crate::mod_types::users_types::{ListError, ListRequest};
+use crate::requests::SlackWebRequestSender;
+use crate::users::list;
+use crate::User;
+
+/// Lists the active, full members of the workspace across all pages, leaving out deactivated
+/// users, guests and bots.
+pub async fn list_active<R>(client: &R, token: &str) -> Result<Vec<User>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut members = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = list(
+            client,
+            token,
+            &ListRequest {
+                cursor: cursor.as_ref(),
+                limit: Some(200),
+                ..Default::default()
+            },
+        )
+        .await?;
+        members.extend(page.members.into_iter().flatten().filter(is_active_member));
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(members);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    use crate::async_impl::test_support::MockSender;
+    use crate::requests::{RateLimitRetry, RetryPolicy};
+
+    /// Serves two pages of `users.list`, rate limiting the first request for the second page.
+    fn rate_limited_pages() -> MockSender {
+        let limited = AtomicBool::new(false);
+        MockSender::new().on_call("users.list", move |call| match call.param("cursor") {
+            "" => {
+                r#"{"ok": true, "members": [{"id": "U1"}],
+                    "response_metadata": {"next_cursor": "page2"}}"#
+            }
+            _ if !limited.swap(true, Ordering::SeqCst) => {
+                r#"{"ok": false, "error": "ratelimited", "retry_after": 0}"#
+            }
+            _ => r#"{"ok": true, "members": [{"id": "U2"}]}"#,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_list_active_resumes_after_rate_limit() {
+        let client = RateLimitRetry::new(rate_limited_pages())
+            .policy(RetryPolicy::new().max_wait(Duration::from_millis(10)));
+        let members = list_active(&client, "xoxb").await.unwrap();
+        let ids: Vec<_> = members.iter().filter_map(|u| u.id.as_deref()).collect();
+        assert_eq!(ids, ["U1", "U2"]);
+        let cursors: Vec<_> = client
+            .into_inner()
+            .calls()
+            .iter()
+            .map(|call| call.param("cursor").to_owned())
+            .collect();
+        assert_eq!(cursors, ["", "page2", "page2"]);
+    }
+}
@@ -0,0 +1,95 @@
+pub use crate::ext_types::views::*;
+
+use crate::blocks::HomeView;
+use crate::ext_types::views::view_hash;
+use crate::mod_types::views_types::{PublishError, PublishRequest};
+use crate::requests::SlackWebRequestSender;
+use crate::views::publish;
+
+/// Publishes a view to a user's Home tab unless `cache` records it as already published there.
+///
+/// A view is only recorded once Slack accepts it, so a failed publish is tried again next time.
+pub async fn publish_home<R>(
+    client: &R,
+    token: &str,
+    cache: &HomeTabCache,
+    user_id: &str,
+    view: &HomeView,
+) -> Result<HomePublished, PublishError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let hash = view_hash(view);
+    if cache.is_current(user_id, hash) {
+        return Ok(HomePublished::Unchanged);
+    }
+    publish(
+        client,
+        token,
+        &PublishRequest {
+            user_id,
+            view,
+            hash: None,
+        },
+    )
+    .await?;
+    cache.remember(user_id, hash);
+    Ok(HomePublished::Published)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    #[tokio::test]
+    async fn test_publish_home_skips_unchanged_views() {
+        let sender = MockSender::new().on(
+            "views.publish",
+            r#"{"ok": true, "view": {"id": "V1", "type": "home"}}"#,
+        );
+        let cache = HomeTabCache::new();
+        let view = HomeView {
+            private_metadata: Some("page=0".into()),
+            ..Default::default()
+        };
+        let published = publish_home(&sender, "xoxb", &cache, "U1", &view).await;
+        assert_eq!(published.unwrap(), HomePublished::Published);
+        let published = publish_home(&sender, "xoxb", &cache, "U1", &view).await;
+        assert_eq!(published.unwrap(), HomePublished::Unchanged);
+        let published = publish_home(&sender, "xoxb", &cache, "U2", &view).await;
+        assert_eq!(published.unwrap(), HomePublished::Published);
+        assert_eq!(sender.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_open_reports_expired_trigger_id() {
+        use crate::blocks::{ModalView, Text};
+        use crate::views::{open, OpenError, OpenRequest};
+        use crate::TriggerId;
+
+        let sender = MockSender::new().on_call("views.open", |call| {
+            assert_eq!(call.param("trigger_id"), "12345.98765.abcd");
+            r#"{"ok": false, "error": "expired_trigger_id"}"#
+        });
+        let view = ModalView {
+            title: Text::plain("Deploy"),
+            blocks: Vec::new(),
+            submit: None,
+            close: None,
+            private_metadata: None,
+            callback_id: None,
+            external_id: None,
+            notify_on_close: None,
+        };
+        let request = OpenRequest {
+            trigger_id: &TriggerId::new("12345.98765.abcd"),
+            view: &view,
+        };
+        assert!(matches!(
+            open(&sender, "xoxb", &request).await,
+            Err(OpenError::ExpiredTriggerId)
+        ));
+    }
+}
@@ -0,0 +1,215 @@
+//! Helpers which walk a channel's history.
+
+pub use crate::history_types::*;
+
+use futures_timer::Delay;
+
+use super::requests::RateLimitRetry;
+use super::requests::SlackWebRequestSender;
+use super::{chat, conversations};
+use crate::history_types::bot_message_ts;
+
+/// Deletes the messages posted by bots in a channel between `oldest` and `latest`,
+/// returning how many were deleted.
+///
+/// Deletions are paced by the request's `interval`, and rate limited calls are retried as the
+/// request's `retry_policy` allows.
+pub async fn delete_range<R>(
+    client: &R,
+    token: &str,
+    request: &DeleteRangeRequest<'_>,
+) -> Result<usize, DeleteRangeError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let client =
+        &RateLimitRetry::new(client).policy(request.retry_policy.clone().unwrap_or_default());
+    let interval = request.interval.unwrap_or(DEFAULT_DELETE_INTERVAL);
+    let mut cursor = None;
+    let mut deleted = 0;
+    loop {
+        let page = conversations::history(
+            client,
+            token,
+            &conversations::HistoryRequest {
+                channel: request.channel,
+                cursor: cursor.as_ref(),
+                latest: request.latest,
+                oldest: request.oldest,
+                limit: Some(200),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(DeleteRangeError::History)?;
+        let messages = page.messages.unwrap_or_default();
+        for ts in messages.iter().filter_map(bot_message_ts) {
+            if deleted > 0 {
+                Delay::new(interval).await;
+            }
+            let result = chat::delete(
+                client,
+                token,
+                &chat::DeleteRequest {
+                    ts,
+                    channel: request.channel,
+                    as_user: request.as_user,
+                },
+            )
+            .await;
+            match result {
+                Ok(_) => deleted += 1,
+                // Deleted by someone else since the history was fetched.
+                Err(chat::DeleteError::MessageNotFound) => {}
+                Err(error) => return Err(DeleteRangeError::Delete { deleted, error }),
+            }
+        }
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(deleted);
+        }
+    }
+}
+
+/// Counts the messages in a channel posted after the authed user's read cursor, as set by
+/// `conversations::mark`.
+///
+/// Every message is counted as unread if the channel has no read cursor, which is the case for
+/// bot tokens.
+pub async fn unread_count<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<usize, UnreadCountError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let info = conversations::info(
+        client,
+        token,
+        &conversations::InfoRequest {
+            channel,
+            ..Default::default()
+        },
+    )
+    .await
+    .map_err(UnreadCountError::Info)?;
+    let last_read = info.channel.and_then(|c| c.last_read);
+    let mut cursor = None;
+    let mut unread = 0;
+    loop {
+        let page = conversations::history(
+            client,
+            token,
+            &conversations::HistoryRequest {
+                channel,
+                cursor: cursor.as_ref(),
+                oldest: last_read,
+                inclusive: Some(false),
+                limit: Some(200),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(UnreadCountError::History)?;
+        unread += page.messages.map_or(0, |m| m.len());
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(unread);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::time::Duration;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// Serves two pages of history and records deletions.
+    fn history() -> MockSender {
+        MockSender::new()
+            .on_call("conversations.history", |call| match call.param("cursor") {
+                "" => serde_json::json!({"ok": true, "has_more": true, "messages": [
+                    {"type": "message", "subtype": "bot_message", "bot_id": "B1", "ts": "4.000000"},
+                    {"type": "message", "user": "U1", "text": "hi", "ts": "3.000000"},
+                ], "response_metadata": {"next_cursor": "page2"}}),
+                "page2" => serde_json::json!({"ok": true, "has_more": false, "messages": [
+                    {"type": "message", "bot_id": "B1", "text": "hi", "ts": "2.000000"},
+                ], "response_metadata": {"next_cursor": ""}}),
+                cursor => panic!("unexpected cursor {}", cursor),
+            })
+            .on("chat.delete", r#"{"ok": true}"#)
+    }
+
+    #[tokio::test]
+    async fn test_delete_range_deletes_bot_messages_across_pages() {
+        let sender = history();
+        let deleted = delete_range(
+            &sender,
+            "xoxb",
+            &DeleteRangeRequest {
+                channel: "C1",
+                interval: Some(Duration::from_millis(0)),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(deleted, 2);
+        let deleted: Vec<_> = sender
+            .calls_to("chat.delete")
+            .iter()
+            .map(|call| call.param("ts").to_owned())
+            .collect();
+        assert_eq!(deleted, ["4.000000", "2.000000"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_range_waits_out_rate_limits() {
+        let limited = AtomicBool::new(false);
+        let sender = history().on_call("chat.delete", move |_| {
+            if limited.swap(true, Ordering::SeqCst) {
+                r#"{"ok": true}"#
+            } else {
+                r#"{"ok": false, "error": "ratelimited", "retry_after": 0}"#
+            }
+        });
+        let deleted = chat::delete_range(
+            &sender,
+            "xoxb",
+            &chat::DeleteRangeRequest {
+                channel: "C1",
+                interval: Some(Duration::from_millis(0)),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert_eq!(deleted, 2);
+        assert_eq!(sender.calls_to("chat.delete").len(), 3);
+    }
+
+    /// Serves a channel whose read cursor is at the second of three messages.
+    fn unread() -> MockSender {
+        MockSender::new()
+            .on(
+                "conversations.info",
+                r#"{"ok": true, "channel": {"id": "C1", "last_read": "2.000000"}}"#,
+            )
+            .on_call("conversations.history", |call| {
+                assert_eq!(call.param("oldest"), "2.000000");
+                serde_json::json!({"ok": true, "messages": [
+                    {"type": "message", "user": "U1", "text": "hi", "ts": "3.000000"},
+                ]})
+            })
+    }
+
+    #[tokio::test]
+    async fn test_unread_count_from_read_cursor() {
+        assert_eq!(unread_count(&unread(), "xoxp", "C1").await.unwrap(), 1);
+    }
+}
@@ -0,0 +1,295 @@
+//! Replays an export made by [`export`](super::export) into another workspace.
+//!
+//! Channels are matched to the workspace's channels by name, and created if it doesn't have them.
+//! Messages are posted oldest first under the name and icon of their original author, which needs
+//! the `chat:write.customize` scope, and replies are posted to the thread they belonged to. Direct
+//! messages can't be recreated and are skipped, as are files, whose contents aren't exported.
+
+pub use crate::import_types::*;
+
+use std::collections::HashMap;
+
+use super::export::Pacer;
+use super::requests::SlackWebRequestSender;
+use super::SlackClient;
+use crate::export_types::{next_cursor, take_records, PageRequest};
+use crate::import_types::{
+    author_of, is_replayable, read_authors, read_channels, read_messages, CreateRequest, ImportLog,
+    JoinRequest, PostRequest, TargetChannel,
+};
+
+/// Imports the export in `request.dir` into the workspace `client` belongs to.
+///
+/// Requests are paced by the request's `interval`, and rate limited requests are retried as the
+/// request's `retry_policy` allows. Every channel found or created and every message posted is logged in the export's
+/// directory, so importing again after an error doesn't post anything twice.
+pub async fn import<R>(
+    client: &SlackClient<R>,
+    request: &ImportRequest<'_>,
+) -> Result<ImportSummary, ImportError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let dir = request.dir;
+    let authors = read_authors(dir)?;
+    let mut log = ImportLog::open(dir)?;
+    let interval = request.interval.unwrap_or(DEFAULT_IMPORT_INTERVAL);
+    let mut pacer = Pacer::new(client, interval, request.retry_policy.clone());
+    let mut targets = None;
+    let mut summary = ImportSummary::default();
+
+    for channel in read_channels(dir)? {
+        let messages = match read_messages(dir, &channel.id)? {
+            Some(messages) if !channel.is_im && !channel.is_mpim => messages,
+            _ => {
+                summary.skipped_channels.push(channel.id);
+                continue;
+            }
+        };
+        let target = match log.channel(&channel.id) {
+            Some(target) => target.to_owned(),
+            None => {
+                let name = channel.name.as_deref().unwrap_or(&channel.id);
+                if targets.is_none() {
+                    targets = Some(list_channels(&mut pacer).await?);
+                }
+                let target = match targets.as_ref().and_then(|t| t.get(name)) {
+                    Some(target) => {
+                        if !target.is_private {
+                            call(
+                                &mut pacer,
+                                "conversations.join",
+                                &JoinRequest {
+                                    channel: &target.id,
+                                },
+                            )
+                            .await?;
+                        }
+                        target.id.clone()
+                    }
+                    None => {
+                        let create = CreateRequest {
+                            name,
+                            is_private: channel.is_private,
+                        };
+                        let created = call(&mut pacer, "conversations.create", &create).await?;
+                        summary.channels_created += 1;
+                        created["channel"]["id"]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_owned()
+                    }
+                };
+                log.record_channel(&channel.id, &target)?;
+                target
+            }
+        };
+
+        for message in &messages {
+            let ts = match message["ts"].as_str() {
+                Some(ts) if log.message(&channel.id, ts).is_none() => ts,
+                _ => continue,
+            };
+            if !is_replayable(message) {
+                summary.skipped_messages += 1;
+                continue;
+            }
+            // A reply whose thread started before the export's `oldest` is posted on its own.
+            let thread_ts = message["thread_ts"]
+                .as_str()
+                .filter(|&thread_ts| thread_ts != ts)
+                .and_then(|thread_ts| log.message(&channel.id, thread_ts))
+                .map(str::to_owned);
+            let (username, icon_url) = author_of(message, &authors);
+            let post = PostRequest {
+                channel: &target,
+                text: message["text"].as_str().unwrap_or_default(),
+                blocks: Some(&message["blocks"]).filter(|b| b.is_array()),
+                attachments: Some(&message["attachments"]).filter(|a| a.is_array()),
+                username,
+                icon_url,
+                thread_ts: thread_ts.as_deref(),
+                unfurl_links: false,
+                unfurl_media: false,
+            };
+            let posted = call(&mut pacer, "chat.postMessage", &post).await?;
+            let new_ts = posted["ts"].as_str().unwrap_or_default();
+            log.record_message(&channel.id, ts, new_ts)?;
+            summary.messages += 1;
+        }
+    }
+
+    Ok(summary)
+}
+
+async fn call<R, Req>(
+    pacer: &mut Pacer<'_, R>,
+    method: &'static str,
+    request: &Req,
+) -> Result<serde_json::Value, ImportError<R::Error>>
+where
+    R: SlackWebRequestSender,
+    Req: serde::Serialize,
+{
+    pacer
+        .send(method, request)
+        .await
+        .map_err(|error| ImportError::Slack { method, error })
+}
+
+/// Maps the names of the workspace's unarchived channels to the channels.
+async fn list_channels<R>(
+    pacer: &mut Pacer<'_, R>,
+) -> Result<HashMap<String, TargetChannel>, ImportError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut channels = HashMap::new();
+    let mut cursor = None;
+    loop {
+        let request = PageRequest {
+            types: Some("public_channel,private_channel"),
+            exclude_archived: Some(true),
+            cursor: cursor.as_deref(),
+            limit: Some(200),
+            ..Default::default()
+        };
+        let mut page = call(pacer, "conversations.list", &request).await?;
+        for channel in take_records(&mut page, "channels") {
+            if let (Some(id), Some(name)) = (channel["id"].as_str(), channel["name"].as_str()) {
+                let target = TargetChannel {
+                    id: id.to_owned(),
+                    is_private: channel["is_private"].as_bool().unwrap_or(false),
+                };
+                channels.insert(name.to_owned(), target);
+            }
+        }
+        cursor = next_cursor(&page);
+        if cursor.is_none() {
+            return Ok(channels);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::json;
+    use std::fs;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::async_impl::test_support::MockSender;
+    use crate::export_types::MESSAGES_DIR;
+
+    /// A workspace with a `general` channel. Posting fails once `fail_after` messages have been
+    /// posted.
+    fn target(fail_after: Arc<AtomicUsize>) -> MockSender {
+        let posted = AtomicUsize::new(0);
+        MockSender::new()
+            .on(
+                "conversations.list",
+                r#"{"ok": true, "channels": [{"id": "G1", "name": "general", "is_private": false}]}"#,
+            )
+            .on("conversations.join", r#"{"ok": true}"#)
+            .on(
+                "conversations.create",
+                r#"{"ok": true, "channel": {"id": "N1"}}"#,
+            )
+            .on_call("chat.postMessage", move |_| {
+                let count = posted.load(Ordering::SeqCst);
+                if count == fail_after.load(Ordering::SeqCst) {
+                    return json!({"ok": false, "error": "fatal_error"});
+                }
+                posted.store(count + 1, Ordering::SeqCst);
+                json!({"ok": true, "ts": format!("900.00000{}", count)})
+            })
+    }
+
+    fn write_lines(path: std::path::PathBuf, records: &[serde_json::Value]) {
+        let lines = records
+            .iter()
+            .map(|r| format!("{}\n", r))
+            .collect::<String>();
+        fs::write(path, lines).unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_import_replays_threads_and_resumes() {
+        let dir = std::env::temp_dir().join(format!("slack-import-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join(MESSAGES_DIR)).unwrap();
+        write_lines(
+            dir.join("users.jsonl"),
+            &[json!({"id": "U1", "name": "ann", "profile": {
+                "display_name": "", "real_name": "Ann", "image_48": "https://i/ann.png"}})],
+        );
+        write_lines(
+            dir.join("conversations.jsonl"),
+            &[
+                json!({"id": "C1", "name": "general"}),
+                json!({"id": "C2", "name": "secret", "is_private": true}),
+                json!({"id": "D1", "is_im": true}),
+            ],
+        );
+        // As exported: newest first, with replies after the message starting their thread.
+        write_lines(
+            dir.join(MESSAGES_DIR).join("C1.jsonl"),
+            &[
+                json!({"type": "message", "user": "U1", "text": "later", "ts": "3.000000"}),
+                json!({"type": "message", "user": "U1", "text": "question", "ts": "1.000000",
+                    "thread_ts": "1.000000", "reply_count": 1}),
+                json!({"type": "message", "subtype": "bot_message", "username": "bot",
+                    "text": "answer", "ts": "2.000000", "thread_ts": "1.000000"}),
+            ],
+        );
+        write_lines(
+            dir.join(MESSAGES_DIR).join("C2.jsonl"),
+            &[
+                json!({"type": "message", "subtype": "channel_join", "user": "U1",
+                    "text": "<@U1> has joined", "ts": "5.000000"}),
+                json!({"type": "message", "user": "U2", "text": "hi", "ts": "6.000000"}),
+            ],
+        );
+        let fail_after = Arc::new(AtomicUsize::new(2));
+        let client = SlackClient::new(target(fail_after.clone()), "xoxb");
+        let mut request = ImportRequest::new(&dir);
+        request.interval = Some(Duration::from_millis(0));
+
+        assert!(import(&client, &request).await.is_err());
+        fail_after.store(usize::MAX, Ordering::SeqCst);
+        let summary = import(&client, &request).await.unwrap();
+        assert_eq!(
+            summary,
+            ImportSummary {
+                channels_created: 1,
+                messages: 2,
+                skipped_messages: 1,
+                skipped_channels: vec!["D1".to_owned()],
+            }
+        );
+
+        // The third post failed and was sent again when the import resumed.
+        let posts = client.sender().calls_to("chat.postMessage");
+        assert_eq!(posts.len(), 5);
+        assert_eq!(posts[2], posts[3]);
+        assert_eq!(posts[0].param("text"), "question");
+        assert_eq!(posts[0].param("channel"), "G1");
+        assert_eq!(posts[0].param("username"), "Ann");
+        assert_eq!(posts[0].param("icon_url"), "https://i/ann.png");
+        assert_eq!(posts[1].param("text"), "answer");
+        assert_eq!(posts[1].param("username"), "bot");
+        assert_eq!(posts[1].param("thread_ts"), "900.000000");
+        assert_eq!(posts[2].param("text"), "later");
+        assert!(posts[2].params.iter().all(|(k, _)| k != "thread_ts"));
+        assert_eq!(posts[4].param("channel"), "N1");
+        assert!(posts[4].params.iter().all(|(k, _)| k != "username"));
+        let creates = client.sender().calls_to("conversations.create");
+        assert_eq!(creates.len(), 1);
+        assert_eq!(creates[0].param("name"), "secret");
+        assert_eq!(creates[0].param("is_private"), "1");
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
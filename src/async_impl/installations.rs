@@ -0,0 +1,203 @@
+//! Looking up installations and routing requests to the right workspace.
+//!
+//! Apps distributed to many workspaces receive events from all of them, and each event has to be
+//! answered with the token of the workspace it came from. An [`InstallationStore`] records the
+//! token for every workspace, and a [`WorkspaceRouter`] turns an incoming event's team into a
+//! [`SlackClient`] carrying that token.
+
+use async_trait::async_trait;
+
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+
+use crate::storage::{self, Storage, StorageError};
+use crate::{Installation, InstallationQuery, MemoryInstallationStore, SlackClient};
+
+/// Storage for the installations of an app.
+#[async_trait]
+pub trait InstallationStore {
+    type Error: Error + Send + Sync + 'static;
+
+    /// Finds the installation which should be used to handle a request from a workspace.
+    ///
+    /// Implementations should fall back to an organization-wide installation when there is none
+    /// for the specific workspace.
+    async fn find(
+        &self,
+        query: &InstallationQuery<'_>,
+    ) -> Result<Option<Installation>, Self::Error>;
+
+    /// Saves an installation, typically after completing the OAuth flow.
+    async fn save(&self, installation: Installation) -> Result<(), Self::Error>;
+
+    /// Deletes an installation, typically after an `app_uninstalled` event.
+    async fn delete(&self, query: &InstallationQuery<'_>) -> Result<(), Self::Error>;
+}
+
+#[async_trait]
+impl InstallationStore for MemoryInstallationStore {
+    type Error = Infallible;
+
+    async fn find(
+        &self,
+        query: &InstallationQuery<'_>,
+    ) -> Result<Option<Installation>, Self::Error> {
+        Ok(self.get(query))
+    }
+
+    async fn save(&self, installation: Installation) -> Result<(), Self::Error> {
+        self.insert(installation);
+        Ok(())
+    }
+
+    async fn delete(&self, query: &InstallationQuery<'_>) -> Result<(), Self::Error> {
+        self.remove(query);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<T: Storage + Sync> InstallationStore for T {
+    type Error = StorageError<T::Error>;
+
+    async fn find(
+        &self,
+        query: &InstallationQuery<'_>,
+    ) -> Result<Option<Installation>, Self::Error> {
+        storage::find_installation(self, query)
+    }
+
+    async fn save(&self, installation: Installation) -> Result<(), Self::Error> {
+        storage::save_installation(self, &installation)
+    }
+
+    async fn delete(&self, query: &InstallationQuery<'_>) -> Result<(), Self::Error> {
+        storage::delete_installation(self, query)
+    }
+}
+
+/// Produces clients authenticated for the workspace a request came from.
+#[derive(Clone, Debug)]
+pub struct WorkspaceRouter<S, R> {
+    store: S,
+    sender: R,
+}
+
+impl<S, R> WorkspaceRouter<S, R>
+where
+    S: InstallationStore,
+    R: Clone,
+{
+    pub fn new(store: S, sender: R) -> Self {
+        WorkspaceRouter { store, sender }
+    }
+
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    /// Finds the installation for a workspace.
+    pub async fn installation(
+        &self,
+        query: &InstallationQuery<'_>,
+    ) -> Result<Installation, RouteError<S::Error>> {
+        self.store
+            .find(query)
+            .await
+            .map_err(RouteError::Store)?
+            .ok_or(RouteError::NotInstalled)
+    }
+
+    /// Gets a client using the bot token of the workspace's installation.
+    pub async fn client_for(
+        &self,
+        query: &InstallationQuery<'_>,
+    ) -> Result<SlackClient<R>, RouteError<S::Error>> {
+        let token = self
+            .installation(query)
+            .await?
+            .bot_token
+            .ok_or(RouteError::MissingToken)?;
+        Ok(SlackClient::new(self.sender.clone(), token))
+    }
+
+    /// Gets a client using the token of the user who installed the app into the workspace.
+    pub async fn user_client_for(
+        &self,
+        query: &InstallationQuery<'_>,
+    ) -> Result<SlackClient<R>, RouteError<S::Error>> {
+        let token = self
+            .installation(query)
+            .await?
+            .user_token
+            .ok_or(RouteError::MissingToken)?;
+        Ok(SlackClient::new(self.sender.clone(), token))
+    }
+}
+
+#[derive(Debug)]
+pub enum RouteError<E: Error> {
+    /// The app has not been installed into the workspace or its organization.
+    NotInstalled,
+    /// The installation does not have the kind of token requested.
+    MissingToken,
+    /// The installation store failed to look up the installation
+    Store(E),
+}
+
+impl<E: Error> fmt::Display for RouteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RouteError::NotInstalled => write!(f, "no installation found for workspace"),
+            RouteError::MissingToken => write!(f, "installation does not have the requested token"),
+            RouteError::Store(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for RouteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RouteError::Store(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_router_uses_workspace_token() {
+        let store = MemoryInstallationStore::new();
+        store.insert(Installation {
+            team_id: Some("T1".into()),
+            bot_token: Some("xoxb-1".into()),
+            ..Default::default()
+        });
+        let router = WorkspaceRouter::new(store, ());
+
+        let query = InstallationQuery {
+            team_id: Some("T1"),
+            ..Default::default()
+        };
+        let client = router.client_for(&query).await.unwrap();
+        assert_eq!(client.token(), "xoxb-1");
+
+        match router.user_client_for(&query).await {
+            Err(RouteError::MissingToken) => {}
+            r => panic!("expected MissingToken but got {:?}", r),
+        }
+
+        let query = InstallationQuery {
+            team_id: Some("T2"),
+            ..Default::default()
+        };
+        match router.client_for(&query).await {
+            Err(RouteError::NotInstalled) => {}
+            r => panic!("expected NotInstalled but got {:?}", r),
+        }
+    }
+}
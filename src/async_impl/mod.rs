@@ -1,7 +1,41 @@
-mod mods;
-pub use self::mods::*;
-
-pub mod requests;
-
-#[cfg(feature = "reqwest")]
-pub use self::requests::default_client;
+mod ext;
+mod mods;
+pub use self::mods::*;
+
+pub mod analytics;
+
+pub mod channel_queue;
+
+mod client;
+pub use self::client::SlackClient;
+
+pub mod export;
+
+pub mod history;
+
+pub mod import;
+
+pub mod installations;
+
+#[cfg(feature = "oidc")]
+pub mod oidc;
+
+pub mod onboarding;
+
+pub mod outbox;
+
+pub mod presence;
+
+pub mod provisioning;
+
+pub mod requests;
+
+pub mod scheduler;
+
+pub mod threads;
+
+#[cfg(test)]
+mod test_support;
+
+#[cfg(feature = "reqwest")]
+pub use self::requests::default_client;
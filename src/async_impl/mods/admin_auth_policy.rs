@@ -0,0 +1,114 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Assign users to the authentication policies of an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_auth_policy_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Assigns entities to an authentication policy.
+///
+/// Wraps https://api.slack.com/methods/admin.auth.policy.assignEntities
+pub async fn assign_entities<R>(
+    client: &R,
+    token: &str,
+    request: &AssignEntitiesRequest<'_>,
+) -> Result<AssignEntitiesResponse, AssignEntitiesError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let entity_ids = request.entity_ids.join(",");
+    let entity_type = request.entity_type.to_param_value();
+    let policy_name = request.policy_name.to_param_value();
+    let params = [
+        Some(("token", token)),
+        Some(("entity_ids", &entity_ids[..])),
+        Some(("entity_type", &entity_type[..])),
+        Some(("policy_name", &policy_name[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.auth.policy.assignEntities");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(AssignEntitiesError::Client)?;
+    serde_json::from_slice::<AssignEntitiesResponse>(&body)
+        .map_err(|e| AssignEntitiesError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Lists the entities assigned to an authentication policy.
+///
+/// Wraps https://api.slack.com/methods/admin.auth.policy.getEntities
+pub async fn get_entities<R>(
+    client: &R,
+    token: &str,
+    request: &GetEntitiesRequest<'_>,
+) -> Result<GetEntitiesResponse, GetEntitiesError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let policy_name = request.policy_name.to_param_value();
+    let entity_type = request.entity_type.as_ref().map(|t| t.to_param_value());
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = [
+        Some(("token", token)),
+        Some(("policy_name", &policy_name[..])),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        entity_type
+            .as_ref()
+            .map(|entity_type| ("entity_type", &entity_type[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.auth.policy.getEntities");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(GetEntitiesError::Client)?;
+    serde_json::from_slice::<GetEntitiesResponse>(&body)
+        .map_err(|e| GetEntitiesError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Removes entities from an authentication policy.
+///
+/// Wraps https://api.slack.com/methods/admin.auth.policy.removeEntities
+pub async fn remove_entities<R>(
+    client: &R,
+    token: &str,
+    request: &RemoveEntitiesRequest<'_>,
+) -> Result<RemoveEntitiesResponse, RemoveEntitiesError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let entity_ids = request.entity_ids.join(",");
+    let entity_type = request.entity_type.to_param_value();
+    let policy_name = request.policy_name.to_param_value();
+    let params = [
+        Some(("token", token)),
+        Some(("entity_ids", &entity_ids[..])),
+        Some(("entity_type", &entity_type[..])),
+        Some(("policy_name", &policy_name[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.auth.policy.removeEntities");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(RemoveEntitiesError::Client)?;
+    serde_json::from_slice::<RemoveEntitiesResponse>(&body)
+        .map_err(|e| RemoveEntitiesError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
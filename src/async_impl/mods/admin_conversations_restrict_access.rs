@@ -0,0 +1,101 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Limit who can join the private channels of an Enterprise Grid organization to members of allowlisted IDP groups.
+
+pub use crate::mod_types::admin_conversations_restrict_access_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Adds an IDP group to the allowlist of a private channel.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.restrictAccess.addGroup
+pub async fn add_group<R>(
+    client: &R,
+    token: &str,
+    request: &AddGroupRequest<'_>,
+) -> Result<AddGroupResponse, AddGroupError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel_id", request.channel_id)),
+        Some(("group_id", request.group_id)),
+        request.team_id.map(|team_id| ("team_id", team_id)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.conversations.restrictAccess.addGroup");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(AddGroupError::Client)?;
+    serde_json::from_slice::<AddGroupResponse>(&body)
+        .map_err(|e| AddGroupError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Lists the IDP groups allowed into a private channel.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.restrictAccess.listGroups
+pub async fn list_groups<R>(
+    client: &R,
+    token: &str,
+    request: &ListGroupsRequest<'_>,
+) -> Result<ListGroupsResponse, ListGroupsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel_id", request.channel_id)),
+        request.team_id.map(|team_id| ("team_id", team_id)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.conversations.restrictAccess.listGroups");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(ListGroupsError::Client)?;
+    serde_json::from_slice::<ListGroupsResponse>(&body)
+        .map_err(|e| ListGroupsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Removes an IDP group from the allowlist of a private channel.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.restrictAccess.removeGroup
+pub async fn remove_group<R>(
+    client: &R,
+    token: &str,
+    request: &RemoveGroupRequest<'_>,
+) -> Result<RemoveGroupResponse, RemoveGroupError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel_id", request.channel_id)),
+        Some(("group_id", request.group_id)),
+        Some(("team_id", request.team_id)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.conversations.restrictAccess.removeGroup");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(RemoveGroupError::Client)?;
+    serde_json::from_slice::<RemoveGroupResponse>(&body)
+        .map_err(|e| RemoveGroupError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
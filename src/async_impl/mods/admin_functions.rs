@@ -0,0 +1,49 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Govern the custom functions of the apps in an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_functions_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Lists the functions of apps.
+///
+/// Wraps https://api.slack.com/methods/admin.functions.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let app_ids = request.app_ids.join(",");
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = [
+        Some(("token", token)),
+        Some(("app_ids", &app_ids[..])),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request.team_id.map(|team_id| ("team_id", team_id)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.functions.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
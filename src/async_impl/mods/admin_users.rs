@@ -0,0 +1,122 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Invite users to and assign users to the workspaces of an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_users_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Adds a member of the organization to a workspace.
+///
+/// Wraps https://api.slack.com/methods/admin.users.assign
+pub async fn assign<R>(
+    client: &R,
+    token: &str,
+    request: &AssignRequest<'_>,
+) -> Result<AssignResponse, AssignError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let channel_ids = request.channel_ids.map(|channel_ids| channel_ids.join(","));
+    let params = [
+        Some(("token", token)),
+        Some(("team_id", request.team_id)),
+        Some(("user_id", request.user_id)),
+        channel_ids
+            .as_ref()
+            .map(|channel_ids| ("channel_ids", &channel_ids[..])),
+        request
+            .is_restricted
+            .map(|is_restricted| ("is_restricted", if is_restricted { "1" } else { "0" })),
+        request.is_ultra_restricted.map(|is_ultra_restricted| {
+            (
+                "is_ultra_restricted",
+                if is_ultra_restricted { "1" } else { "0" },
+            )
+        }),
+    ];
+    let url = crate::get_slack_url_for_method("admin.users.assign");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(AssignError::Client)?;
+    serde_json::from_slice::<AssignResponse>(&body)
+        .map_err(|e| AssignError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Invites a user to a workspace by email.
+///
+/// Wraps https://api.slack.com/methods/admin.users.invite
+pub async fn invite<R>(
+    client: &R,
+    token: &str,
+    request: &InviteRequest<'_>,
+) -> Result<InviteResponse, InviteError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let channel_ids = request.channel_ids.join(",");
+    let guest_expiration_ts = request
+        .guest_expiration_ts
+        .as_ref()
+        .map(|t| t.to_param_value());
+    let params = [
+        Some(("token", token)),
+        Some(("channel_ids", &channel_ids[..])),
+        Some(("email", request.email)),
+        Some(("team_id", request.team_id)),
+        request
+            .custom_message
+            .map(|custom_message| ("custom_message", custom_message)),
+        request
+            .email_password_policy_enabled
+            .map(|email_password_policy_enabled| {
+                (
+                    "email_password_policy_enabled",
+                    if email_password_policy_enabled {
+                        "1"
+                    } else {
+                        "0"
+                    },
+                )
+            }),
+        guest_expiration_ts
+            .as_ref()
+            .map(|guest_expiration_ts| ("guest_expiration_ts", &guest_expiration_ts[..])),
+        request
+            .is_restricted
+            .map(|is_restricted| ("is_restricted", if is_restricted { "1" } else { "0" })),
+        request.is_ultra_restricted.map(|is_ultra_restricted| {
+            (
+                "is_ultra_restricted",
+                if is_ultra_restricted { "1" } else { "0" },
+            )
+        }),
+        request.real_name.map(|real_name| ("real_name", real_name)),
+        request
+            .resend
+            .map(|resend| ("resend", if resend { "1" } else { "0" })),
+    ];
+    let url = crate::get_slack_url_for_method("admin.users.invite");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(InviteError::Client)?;
+    serde_json::from_slice::<InviteResponse>(&body)
+        .map_err(|e| InviteError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
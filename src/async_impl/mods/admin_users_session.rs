@@ -0,0 +1,194 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage the sessions of users in an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_users_session_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Lists the active user sessions of an organization.
+///
+/// Wraps https://api.slack.com/methods/admin.users.session.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = [
+        Some(("token", token)),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request.team_id.map(|team_id| ("team_id", team_id)),
+        request.user_id.map(|user_id| ("user_id", user_id)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.users.session.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Revokes a single session of a user, signing them out of that device.
+///
+/// Wraps https://api.slack.com/methods/admin.users.session.invalidate
+pub async fn invalidate<R>(
+    client: &R,
+    token: &str,
+    request: &InvalidateRequest<'_>,
+) -> Result<InvalidateResponse, InvalidateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let session_id = request.session_id.to_string();
+    let params = [
+        Some(("token", token)),
+        Some(("session_id", &session_id[..])),
+        Some(("team_id", request.team_id)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.users.session.invalidate");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(InvalidateError::Client)?;
+    serde_json::from_slice::<InvalidateResponse>(&body)
+        .map_err(|e| InvalidateError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Wipes all of a user's valid sessions across every device.
+///
+/// Wraps https://api.slack.com/methods/admin.users.session.reset
+pub async fn reset<R>(
+    client: &R,
+    token: &str,
+    request: &ResetRequest<'_>,
+) -> Result<ResetResponse, ResetError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("user_id", request.user_id)),
+        request
+            .mobile_only
+            .map(|mobile_only| ("mobile_only", if mobile_only { "1" } else { "0" })),
+        request
+            .web_only
+            .map(|web_only| ("web_only", if web_only { "1" } else { "0" })),
+    ];
+    let url = crate::get_slack_url_for_method("admin.users.session.reset");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(ResetError::Client)?;
+    serde_json::from_slice::<ResetResponse>(&body)
+        .map_err(|e| ResetError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Sets how long the given users' sessions last before they must sign in again.
+///
+/// Wraps https://api.slack.com/methods/admin.users.session.setSettings
+pub async fn set_settings<R>(
+    client: &R,
+    token: &str,
+    request: &SetSettingsRequest<'_>,
+) -> Result<SetSettingsResponse, SetSettingsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let duration = request
+        .duration
+        .map(|duration| duration.as_secs().to_string());
+    let params = [
+        Some(("token", token)),
+        Some(("user_ids", request.user_ids)),
+        request
+            .desktop_app_browser_quit
+            .map(|desktop_app_browser_quit| {
+                (
+                    "desktop_app_browser_quit",
+                    if desktop_app_browser_quit { "1" } else { "0" },
+                )
+            }),
+        duration
+            .as_ref()
+            .map(|duration| ("duration", &duration[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.users.session.setSettings");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(SetSettingsError::Client)?;
+    serde_json::from_slice::<SetSettingsResponse>(&body)
+        .map_err(|e| SetSettingsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Gets the session settings of the given users, if they have any.
+///
+/// Wraps https://api.slack.com/methods/admin.users.session.getSettings
+pub async fn get_settings<R>(
+    client: &R,
+    token: &str,
+    request: &GetSettingsRequest<'_>,
+) -> Result<GetSettingsResponse, GetSettingsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [Some(("token", token)), Some(("user_ids", request.user_ids))];
+    let url = crate::get_slack_url_for_method("admin.users.session.getSettings");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(GetSettingsError::Client)?;
+    serde_json::from_slice::<GetSettingsResponse>(&body)
+        .map_err(|e| GetSettingsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Clears the session settings of the given users, so the organization's defaults apply.
+///
+/// Wraps https://api.slack.com/methods/admin.users.session.clearSettings
+pub async fn clear_settings<R>(
+    client: &R,
+    token: &str,
+    request: &ClearSettingsRequest<'_>,
+) -> Result<ClearSettingsResponse, ClearSettingsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [Some(("token", token)), Some(("user_ids", request.user_ids))];
+    let url = crate::get_slack_url_for_method("admin.users.session.clearSettings");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(ClearSettingsError::Client)?;
+    serde_json::from_slice::<ClearSettingsResponse>(&body)
+        .map_err(|e| ClearSettingsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,93 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Govern the workflows of an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_workflows_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Searches the workflows of the organization.
+///
+/// Wraps https://api.slack.com/methods/admin.workflows.search
+pub async fn search<R>(
+    client: &R,
+    token: &str,
+    request: &SearchRequest<'_>,
+) -> Result<SearchResponse, SearchError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let collaborator_ids = request
+        .collaborator_ids
+        .map(|collaborator_ids| collaborator_ids.join(","));
+    let limit = request.limit.map(|limit| limit.to_string());
+    let num_trigger_ids = request
+        .num_trigger_ids
+        .map(|num_trigger_ids| num_trigger_ids.to_string());
+    let params = [
+        Some(("token", token)),
+        request.app_id.map(|app_id| ("app_id", app_id)),
+        collaborator_ids
+            .as_ref()
+            .map(|collaborator_ids| ("collaborator_ids", &collaborator_ids[..])),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request
+            .no_collaborators
+            .map(|no_collaborators| ("no_collaborators", if no_collaborators { "1" } else { "0" })),
+        num_trigger_ids
+            .as_ref()
+            .map(|num_trigger_ids| ("num_trigger_ids", &num_trigger_ids[..])),
+        request.query.map(|query| ("query", query)),
+        request.sort.map(|sort| ("sort", sort)),
+        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
+        request.source.map(|source| ("source", source)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.workflows.search");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(SearchError::Client)?;
+    serde_json::from_slice::<SearchResponse>(&body)
+        .map_err(|e| SearchError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Unpublishes workflows, stopping them from being run.
+///
+/// Wraps https://api.slack.com/methods/admin.workflows.unpublish
+pub async fn unpublish<R>(
+    client: &R,
+    token: &str,
+    request: &UnpublishRequest<'_>,
+) -> Result<UnpublishResponse, UnpublishError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let workflow_ids = request.workflow_ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("workflow_ids", &workflow_ids[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.workflows.unpublish");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(UnpublishError::Client)?;
+    serde_json::from_slice::<UnpublishResponse>(&body)
+        .map_err(|e| UnpublishError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
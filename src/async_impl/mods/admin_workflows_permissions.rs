@@ -0,0 +1,51 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Look up who can run the workflows of an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_workflows_permissions_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Looks up who can run workflows, keyed by workflow ID.
+///
+/// Wraps https://api.slack.com/methods/admin.workflows.permissions.lookup
+pub async fn lookup<R>(
+    client: &R,
+    token: &str,
+    request: &LookupRequest<'_>,
+) -> Result<LookupResponse, LookupError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let workflow_ids = request.workflow_ids.join(",");
+    let max_workflow_triggers = request
+        .max_workflow_triggers
+        .map(|max_workflow_triggers| max_workflow_triggers.to_string());
+    let params = [
+        Some(("token", token)),
+        Some(("workflow_ids", &workflow_ids[..])),
+        max_workflow_triggers
+            .as_ref()
+            .map(|max_workflow_triggers| ("max_workflow_triggers", &max_workflow_triggers[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.workflows.permissions.lookup");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(LookupError::Client)?;
+    serde_json::from_slice::<LookupResponse>(&body)
+        .map_err(|e| LookupError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,107 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage the threads of an app's AI assistant.
+
+pub use crate::mod_types::assistant_threads_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Shows a status, such as "is thinking...", in an assistant thread while a reply is prepared.
+///
+/// Wraps https://api.slack.com/methods/assistant.threads.setStatus
+pub async fn set_status<R>(
+    client: &R,
+    token: &str,
+    request: &SetStatusRequest<'_>,
+) -> Result<SetStatusResponse, SetStatusError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let thread_ts = request.thread_ts.to_param_value();
+    let params = [
+        Some(("token", token)),
+        Some(("channel_id", request.channel_id)),
+        Some(("thread_ts", &thread_ts[..])),
+        Some(("status", request.status)),
+    ];
+    let url = crate::get_slack_url_for_method("assistant.threads.setStatus");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(SetStatusError::Client)?;
+    serde_json::from_slice::<SetStatusResponse>(&body)
+        .map_err(|e| SetStatusError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Sets the prompts suggested in an assistant thread.
+///
+/// Wraps https://api.slack.com/methods/assistant.threads.setSuggestedPrompts
+pub async fn set_suggested_prompts<R>(
+    client: &R,
+    token: &str,
+    request: &SetSuggestedPromptsRequest<'_>,
+) -> Result<SetSuggestedPromptsResponse, SetSuggestedPromptsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let thread_ts = request.thread_ts.to_param_value();
+    let prompts = crate::to_json_param(request.prompts);
+    let params = [
+        Some(("token", token)),
+        Some(("channel_id", request.channel_id)),
+        Some(("thread_ts", &thread_ts[..])),
+        Some(("prompts", &prompts[..])),
+        request.title.map(|title| ("title", title)),
+    ];
+    let url = crate::get_slack_url_for_method("assistant.threads.setSuggestedPrompts");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(SetSuggestedPromptsError::Client)?;
+    serde_json::from_slice::<SetSuggestedPromptsResponse>(&body)
+        .map_err(|e| SetSuggestedPromptsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Sets the title of an assistant thread, shown in the user's history of threads.
+///
+/// Wraps https://api.slack.com/methods/assistant.threads.setTitle
+pub async fn set_title<R>(
+    client: &R,
+    token: &str,
+    request: &SetTitleRequest<'_>,
+) -> Result<SetTitleResponse, SetTitleError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let thread_ts = request.thread_ts.to_param_value();
+    let params = [
+        Some(("token", token)),
+        Some(("channel_id", request.channel_id)),
+        Some(("thread_ts", &thread_ts[..])),
+        Some(("title", request.title)),
+    ];
+    let url = crate::get_slack_url_for_method("assistant.threads.setTitle");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(SetTitleError::Client)?;
+    serde_json::from_slice::<SetTitleResponse>(&body)
+        .map_err(|e| SetTitleError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
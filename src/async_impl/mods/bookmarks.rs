@@ -0,0 +1,76 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage the bookmarks in a channel's bookmark bar.
+
+pub use crate::mod_types::bookmarks_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Add bookmark to a channel.
+///
+/// Wraps https://api.slack.com/methods/bookmarks.add
+pub async fn add<R>(
+    client: &R,
+    token: &str,
+    request: &AddRequest<'_>,
+) -> Result<AddResponse, AddError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel_id", request.channel_id)),
+        Some(("title", request.title)),
+        Some(("type", request.r#type)),
+        request.emoji.map(|emoji| ("emoji", emoji)),
+        request.entity_id.map(|entity_id| ("entity_id", entity_id)),
+        request.link.map(|link| ("link", link)),
+        request.parent_id.map(|parent_id| ("parent_id", parent_id)),
+    ];
+    let url = crate::get_slack_url_for_method("bookmarks.add");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(AddError::Client)?;
+    serde_json::from_slice::<AddResponse>(&body)
+        .map_err(|e| AddError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// List bookmark for the channel.
+///
+/// Wraps https://api.slack.com/methods/bookmarks.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel_id", request.channel_id)),
+    ];
+    let url = crate::get_slack_url_for_method("bookmarks.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
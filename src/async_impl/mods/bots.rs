@@ -18,7 +18,6 @@ use crate::requests::SlackWebRequestSender;
 /// Gets information about a bot user.
 ///
 /// Wraps https://api.slack.com/methods/bots.info
-
 pub async fn info<R>(
     client: &R,
     token: &str,
@@ -27,16 +26,14 @@ pub async fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), request.bot.map(|bot| ("bot", bot))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), request.bot.map(|bot| ("bot", bot))];
     let url = crate::get_slack_url_for_method("bots.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
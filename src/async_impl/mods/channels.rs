@@ -20,7 +20,6 @@ use crate::requests::SlackWebRequestSender;
 /// Archives a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.archive
-
 pub async fn archive<R>(
     client: &R,
     token: &str,
@@ -29,24 +28,21 @@ pub async fn archive<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("channels.archive");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(ArchiveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ArchiveResponse>(&result)
-                .map_err(|e| ArchiveError::MalformedResponse(result, e))
-        })
+        .map_err(ArchiveError::Client)?;
+    serde_json::from_slice::<ArchiveResponse>(&body)
+        .map_err(|e| ArchiveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Creates a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.create
-
 pub async fn create<R>(
     client: &R,
     token: &str,
@@ -55,30 +51,27 @@ pub async fn create<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("name", request.name)),
         request
             .validate
             .map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.create");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(CreateError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result)
-                .map_err(|e| CreateError::MalformedResponse(result, e))
-        })
+        .map_err(CreateError::Client)?;
+    serde_json::from_slice::<CreateResponse>(&body)
+        .map_err(|e| CreateError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Fetches history of messages and events from a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.history
-
 pub async fn history<R>(
     client: &R,
     token: &str,
@@ -90,7 +83,7 @@ where
     let latest = request.latest.as_ref().map(|t| t.to_param_value());
     let oldest = request.oldest.as_ref().map(|t| t.to_param_value());
     let count = request.count.map(|count| count.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         latest.as_ref().map(|latest| ("latest", &latest[..])),
@@ -103,23 +96,20 @@ where
             .unreads
             .map(|unreads| ("unreads", if unreads { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.history");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(HistoryError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<HistoryResponse>(&result)
-                .map_err(|e| HistoryError::MalformedResponse(result, e))
-        })
+        .map_err(HistoryError::Client)?;
+    serde_json::from_slice::<HistoryResponse>(&body)
+        .map_err(|e| HistoryError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets information about a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.info
-
 pub async fn info<R>(
     client: &R,
     token: &str,
@@ -128,24 +118,21 @@ pub async fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("channels.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Invites a user to a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.invite
-
 pub async fn invite<R>(
     client: &R,
     token: &str,
@@ -154,28 +141,25 @@ pub async fn invite<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("user", request.user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.invite");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(InviteError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InviteResponse>(&result)
-                .map_err(|e| InviteError::MalformedResponse(result, e))
-        })
+        .map_err(InviteError::Client)?;
+    serde_json::from_slice::<InviteResponse>(&body)
+        .map_err(|e| InviteError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Joins a channel, creating it if needed.
 ///
 /// Wraps https://api.slack.com/methods/channels.join
-
 pub async fn join<R>(
     client: &R,
     token: &str,
@@ -184,30 +168,27 @@ pub async fn join<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("name", request.name)),
         request
             .validate
             .map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.join");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(JoinError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<JoinResponse>(&result)
-                .map_err(|e| JoinError::MalformedResponse(result, e))
-        })
+        .map_err(JoinError::Client)?;
+    serde_json::from_slice::<JoinResponse>(&body)
+        .map_err(|e| JoinError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Removes a user from a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.kick
-
 pub async fn kick<R>(
     client: &R,
     token: &str,
@@ -216,28 +197,25 @@ pub async fn kick<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("user", request.user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.kick");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(KickError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<KickResponse>(&result)
-                .map_err(|e| KickError::MalformedResponse(result, e))
-        })
+        .map_err(KickError::Client)?;
+    serde_json::from_slice::<KickResponse>(&body)
+        .map_err(|e| KickError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Leaves a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.leave
-
 pub async fn leave<R>(
     client: &R,
     token: &str,
@@ -246,24 +224,21 @@ pub async fn leave<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("channels.leave");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(LeaveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<LeaveResponse>(&result)
-                .map_err(|e| LeaveError::MalformedResponse(result, e))
-        })
+        .map_err(LeaveError::Client)?;
+    serde_json::from_slice::<LeaveResponse>(&body)
+        .map_err(|e| LeaveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists all channels in a Slack team.
 ///
 /// Wraps https://api.slack.com/methods/channels.list
-
 pub async fn list<R>(
     client: &R,
     token: &str,
@@ -272,7 +247,7 @@ pub async fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request
             .exclude_archived
@@ -281,23 +256,20 @@ where
             .exclude_members
             .map(|exclude_members| ("exclude_members", if exclude_members { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the read cursor in a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.mark
-
 pub async fn mark<R>(
     client: &R,
     token: &str,
@@ -307,28 +279,25 @@ where
     R: SlackWebRequestSender,
 {
     let ts = request.ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("ts", &ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.mark");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(MarkError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<MarkResponse>(&result)
-                .map_err(|e| MarkError::MalformedResponse(result, e))
-        })
+        .map_err(MarkError::Client)?;
+    serde_json::from_slice::<MarkResponse>(&body)
+        .map_err(|e| MarkError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Renames a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.rename
-
 pub async fn rename<R>(
     client: &R,
     token: &str,
@@ -337,7 +306,7 @@ pub async fn rename<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("name", request.name)),
@@ -345,23 +314,20 @@ where
             .validate
             .map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.rename");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(RenameError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RenameResponse>(&result)
-                .map_err(|e| RenameError::MalformedResponse(result, e))
-        })
+        .map_err(RenameError::Client)?;
+    serde_json::from_slice::<RenameResponse>(&body)
+        .map_err(|e| RenameError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Retrieve a thread of messages posted to a channel
 ///
 /// Wraps https://api.slack.com/methods/channels.replies
-
 pub async fn replies<R>(
     client: &R,
     token: &str,
@@ -371,28 +337,25 @@ where
     R: SlackWebRequestSender,
 {
     let thread_ts = request.thread_ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("thread_ts", &thread_ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.replies");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(RepliesError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RepliesResponse>(&result)
-                .map_err(|e| RepliesError::MalformedResponse(result, e))
-        })
+        .map_err(RepliesError::Client)?;
+    serde_json::from_slice::<RepliesResponse>(&body)
+        .map_err(|e| RepliesError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the purpose for a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.setPurpose
-
 pub async fn set_purpose<R>(
     client: &R,
     token: &str,
@@ -401,28 +364,25 @@ pub async fn set_purpose<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("purpose", request.purpose)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.setPurpose");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(SetPurposeError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetPurposeResponse>(&result)
-                .map_err(|e| SetPurposeError::MalformedResponse(result, e))
-        })
+        .map_err(SetPurposeError::Client)?;
+    serde_json::from_slice::<SetPurposeResponse>(&body)
+        .map_err(|e| SetPurposeError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the topic for a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.setTopic
-
 pub async fn set_topic<R>(
     client: &R,
     token: &str,
@@ -431,28 +391,25 @@ pub async fn set_topic<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("topic", request.topic)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.setTopic");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(SetTopicError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetTopicResponse>(&result)
-                .map_err(|e| SetTopicError::MalformedResponse(result, e))
-        })
+        .map_err(SetTopicError::Client)?;
+    serde_json::from_slice::<SetTopicResponse>(&body)
+        .map_err(|e| SetTopicError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Unarchives a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.unarchive
-
 pub async fn unarchive<R>(
     client: &R,
     token: &str,
@@ -461,16 +418,14 @@ pub async fn unarchive<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("channels.unarchive");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(UnarchiveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<UnarchiveResponse>(&result)
-                .map_err(|e| UnarchiveError::MalformedResponse(result, e))
-        })
+        .map_err(UnarchiveError::Client)?;
+    serde_json::from_slice::<UnarchiveResponse>(&body)
+        .map_err(|e| UnarchiveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -0,0 +1,52 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Messages scheduled to be posted later.
+
+pub use crate::mod_types::chat_scheduled_messages_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Returns a list of scheduled messages.
+///
+/// Wraps https://api.slack.com/methods/chat.scheduledMessages.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let latest = request.latest.as_ref().map(|t| t.to_param_value());
+    let limit = request.limit.map(|limit| limit.to_string());
+    let oldest = request.oldest.as_ref().map(|t| t.to_param_value());
+    let params = [
+        Some(("token", token)),
+        request.channel.map(|channel| ("channel", channel)),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        latest.as_ref().map(|latest| ("latest", &latest[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
+        request.team_id.map(|team_id| ("team_id", team_id)),
+    ];
+    let url = crate::get_slack_url_for_method("chat.scheduledMessages.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
@@ -14,34 +14,32 @@
 
 //! Adjust and view Do Not Disturb settings for team members.
 
+pub use crate::async_impl::ext::dnd::*;
 pub use crate::mod_types::dnd_types::*;
 use crate::requests::SlackWebRequestSender;
 
 /// Ends the current user's Do Not Disturb session immediately.
 ///
 /// Wraps https://api.slack.com/methods/dnd.endDnd
-
 pub async fn end_dnd<R>(client: &R, token: &str) -> Result<EndDndResponse, EndDndError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("dnd.endDnd");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, &params[..], &mut body)
         .await
-        .map_err(EndDndError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<EndDndResponse>(&result)
-                .map_err(|e| EndDndError::MalformedResponse(result, e))
-        })
+        .map_err(EndDndError::Client)?;
+    serde_json::from_slice::<EndDndResponse>(&body)
+        .map_err(|e| EndDndError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Ends the current user's snooze mode immediately.
 ///
 /// Wraps https://api.slack.com/methods/dnd.endSnooze
-
 pub async fn end_snooze<R>(
     client: &R,
     token: &str,
@@ -51,21 +49,19 @@ where
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("dnd.endSnooze");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, &params[..], &mut body)
         .await
-        .map_err(EndSnoozeError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<EndSnoozeResponse>(&result)
-                .map_err(|e| EndSnoozeError::MalformedResponse(result, e))
-        })
+        .map_err(EndSnoozeError::Client)?;
+    serde_json::from_slice::<EndSnoozeResponse>(&body)
+        .map_err(|e| EndSnoozeError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Retrieves a user's current Do Not Disturb status.
 ///
 /// Wraps https://api.slack.com/methods/dnd.info
-
 pub async fn info<R>(
     client: &R,
     token: &str,
@@ -74,27 +70,24 @@ pub async fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.user.map(|user| ("user", user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("dnd.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Turns on Do Not Disturb mode for the current user, or changes its duration.
 ///
 /// Wraps https://api.slack.com/methods/dnd.setSnooze
-
 pub async fn set_snooze<R>(
     client: &R,
     token: &str,
@@ -104,27 +97,24 @@ where
     R: SlackWebRequestSender,
 {
     let num_minutes = request.num_minutes.to_string();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("num_minutes", &num_minutes[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("dnd.setSnooze");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(SetSnoozeError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetSnoozeResponse>(&result)
-                .map_err(|e| SetSnoozeError::MalformedResponse(result, e))
-        })
+        .map_err(SetSnoozeError::Client)?;
+    serde_json::from_slice::<SetSnoozeResponse>(&body)
+        .map_err(|e| SetSnoozeError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Retrieves the Do Not Disturb status for users on a team.
 ///
 /// Wraps https://api.slack.com/methods/dnd.teamInfo
-
 pub async fn team_info<R>(
     client: &R,
     token: &str,
@@ -133,19 +123,17 @@ pub async fn team_info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.users.map(|users| ("users", users)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("dnd.teamInfo");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(TeamInfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<TeamInfoResponse>(&result)
-                .map_err(|e| TeamInfoError::MalformedResponse(result, e))
-        })
+        .map_err(TeamInfoError::Client)?;
+    serde_json::from_slice::<TeamInfoResponse>(&body)
+        .map_err(|e| TeamInfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -18,20 +18,18 @@ use crate::requests::SlackWebRequestSender;
 /// Lists custom emoji for a team.
 ///
 /// Wraps https://api.slack.com/methods/emoji.list
-
 pub async fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("emoji.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, &params[..], &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -18,7 +18,7 @@ use crate::requests::SlackWebRequestSender;
 /// Add a comment to an existing file.
 ///
 /// Wraps https://api.slack.com/methods/files.comments.add
-
+#[deprecated(note = "File comments are retired; reply in the file's message thread instead")]
 pub async fn add<R>(
     client: &R,
     token: &str,
@@ -27,28 +27,26 @@ pub async fn add<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("file", request.file)),
         Some(("comment", request.comment)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("files.comments.add");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(AddError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
-        })
+        .map_err(AddError::Client)?;
+    serde_json::from_slice::<AddResponse>(&body)
+        .map_err(|e| AddError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Deletes an existing comment on a file.
 ///
 /// Wraps https://api.slack.com/methods/files.comments.delete
-
+#[deprecated(note = "File comments are retired; reply in the file's message thread instead")]
 pub async fn delete<R>(
     client: &R,
     token: &str,
@@ -57,28 +55,26 @@ pub async fn delete<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("file", request.file)),
         Some(("id", request.id)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("files.comments.delete");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(DeleteError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result)
-                .map_err(|e| DeleteError::MalformedResponse(result, e))
-        })
+        .map_err(DeleteError::Client)?;
+    serde_json::from_slice::<DeleteResponse>(&body)
+        .map_err(|e| DeleteError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Edit an existing file comment.
 ///
 /// Wraps https://api.slack.com/methods/files.comments.edit
-
+#[deprecated(note = "File comments are retired; reply in the file's message thread instead")]
 pub async fn edit<R>(
     client: &R,
     token: &str,
@@ -87,21 +83,19 @@ pub async fn edit<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("file", request.file)),
         Some(("id", request.id)),
         Some(("comment", request.comment)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("files.comments.edit");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(EditError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<EditResponse>(&result)
-                .map_err(|e| EditError::MalformedResponse(result, e))
-        })
+        .map_err(EditError::Client)?;
+    serde_json::from_slice::<EditResponse>(&body)
+        .map_err(|e| EditError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
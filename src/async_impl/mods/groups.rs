@@ -20,7 +20,6 @@ use crate::requests::SlackWebRequestSender;
 /// Archives a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.archive
-
 pub async fn archive<R>(
     client: &R,
     token: &str,
@@ -29,24 +28,21 @@ pub async fn archive<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("groups.archive");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(ArchiveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ArchiveResponse>(&result)
-                .map_err(|e| ArchiveError::MalformedResponse(result, e))
-        })
+        .map_err(ArchiveError::Client)?;
+    serde_json::from_slice::<ArchiveResponse>(&body)
+        .map_err(|e| ArchiveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Closes a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.close
-
 pub async fn close<R>(
     client: &R,
     token: &str,
@@ -55,24 +51,21 @@ pub async fn close<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("groups.close");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(CloseError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<CloseResponse>(&result)
-                .map_err(|e| CloseError::MalformedResponse(result, e))
-        })
+        .map_err(CloseError::Client)?;
+    serde_json::from_slice::<CloseResponse>(&body)
+        .map_err(|e| CloseError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Creates a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.create
-
 pub async fn create<R>(
     client: &R,
     token: &str,
@@ -81,30 +74,27 @@ pub async fn create<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("name", request.name)),
         request
             .validate
             .map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.create");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(CreateError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result)
-                .map_err(|e| CreateError::MalformedResponse(result, e))
-        })
+        .map_err(CreateError::Client)?;
+    serde_json::from_slice::<CreateResponse>(&body)
+        .map_err(|e| CreateError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Clones and archives a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.createChild
-
 pub async fn create_child<R>(
     client: &R,
     token: &str,
@@ -113,24 +103,21 @@ pub async fn create_child<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("groups.createChild");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(CreateChildError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<CreateChildResponse>(&result)
-                .map_err(|e| CreateChildError::MalformedResponse(result, e))
-        })
+        .map_err(CreateChildError::Client)?;
+    serde_json::from_slice::<CreateChildResponse>(&body)
+        .map_err(|e| CreateChildError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Fetches history of messages and events from a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.history
-
 pub async fn history<R>(
     client: &R,
     token: &str,
@@ -142,7 +129,7 @@ where
     let latest = request.latest.as_ref().map(|t| t.to_param_value());
     let oldest = request.oldest.as_ref().map(|t| t.to_param_value());
     let count = request.count.map(|count| count.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         latest.as_ref().map(|latest| ("latest", &latest[..])),
@@ -155,23 +142,20 @@ where
             .unreads
             .map(|unreads| ("unreads", if unreads { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.history");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(HistoryError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<HistoryResponse>(&result)
-                .map_err(|e| HistoryError::MalformedResponse(result, e))
-        })
+        .map_err(HistoryError::Client)?;
+    serde_json::from_slice::<HistoryResponse>(&body)
+        .map_err(|e| HistoryError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets information about a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.info
-
 pub async fn info<R>(
     client: &R,
     token: &str,
@@ -180,24 +164,21 @@ pub async fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("groups.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Invites a user to a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.invite
-
 pub async fn invite<R>(
     client: &R,
     token: &str,
@@ -206,28 +187,25 @@ pub async fn invite<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("user", request.user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.invite");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(InviteError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InviteResponse>(&result)
-                .map_err(|e| InviteError::MalformedResponse(result, e))
-        })
+        .map_err(InviteError::Client)?;
+    serde_json::from_slice::<InviteResponse>(&body)
+        .map_err(|e| InviteError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Removes a user from a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.kick
-
 pub async fn kick<R>(
     client: &R,
     token: &str,
@@ -236,28 +214,25 @@ pub async fn kick<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("user", request.user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.kick");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(KickError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<KickResponse>(&result)
-                .map_err(|e| KickError::MalformedResponse(result, e))
-        })
+        .map_err(KickError::Client)?;
+    serde_json::from_slice::<KickResponse>(&body)
+        .map_err(|e| KickError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Leaves a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.leave
-
 pub async fn leave<R>(
     client: &R,
     token: &str,
@@ -266,24 +241,21 @@ pub async fn leave<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("groups.leave");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(LeaveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<LeaveResponse>(&result)
-                .map_err(|e| LeaveError::MalformedResponse(result, e))
-        })
+        .map_err(LeaveError::Client)?;
+    serde_json::from_slice::<LeaveResponse>(&body)
+        .map_err(|e| LeaveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists private channels that the calling user has access to.
 ///
 /// Wraps https://api.slack.com/methods/groups.list
-
 pub async fn list<R>(
     client: &R,
     token: &str,
@@ -292,29 +264,26 @@ pub async fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request
             .exclude_archived
             .map(|exclude_archived| ("exclude_archived", if exclude_archived { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the read cursor in a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.mark
-
 pub async fn mark<R>(
     client: &R,
     token: &str,
@@ -324,28 +293,25 @@ where
     R: SlackWebRequestSender,
 {
     let ts = request.ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("ts", &ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.mark");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(MarkError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<MarkResponse>(&result)
-                .map_err(|e| MarkError::MalformedResponse(result, e))
-        })
+        .map_err(MarkError::Client)?;
+    serde_json::from_slice::<MarkResponse>(&body)
+        .map_err(|e| MarkError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Opens a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.open
-
 pub async fn open<R>(
     client: &R,
     token: &str,
@@ -354,24 +320,21 @@ pub async fn open<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("groups.open");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(OpenError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<OpenResponse>(&result)
-                .map_err(|e| OpenError::MalformedResponse(result, e))
-        })
+        .map_err(OpenError::Client)?;
+    serde_json::from_slice::<OpenResponse>(&body)
+        .map_err(|e| OpenError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Renames a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.rename
-
 pub async fn rename<R>(
     client: &R,
     token: &str,
@@ -380,7 +343,7 @@ pub async fn rename<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("name", request.name)),
@@ -388,23 +351,20 @@ where
             .validate
             .map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.rename");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(RenameError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RenameResponse>(&result)
-                .map_err(|e| RenameError::MalformedResponse(result, e))
-        })
+        .map_err(RenameError::Client)?;
+    serde_json::from_slice::<RenameResponse>(&body)
+        .map_err(|e| RenameError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Retrieve a thread of messages posted to a private channel
 ///
 /// Wraps https://api.slack.com/methods/groups.replies
-
 pub async fn replies<R>(
     client: &R,
     token: &str,
@@ -414,28 +374,25 @@ where
     R: SlackWebRequestSender,
 {
     let thread_ts = request.thread_ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("thread_ts", &thread_ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.replies");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(RepliesError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RepliesResponse>(&result)
-                .map_err(|e| RepliesError::MalformedResponse(result, e))
-        })
+        .map_err(RepliesError::Client)?;
+    serde_json::from_slice::<RepliesResponse>(&body)
+        .map_err(|e| RepliesError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the purpose for a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.setPurpose
-
 pub async fn set_purpose<R>(
     client: &R,
     token: &str,
@@ -444,28 +401,25 @@ pub async fn set_purpose<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("purpose", request.purpose)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.setPurpose");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(SetPurposeError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetPurposeResponse>(&result)
-                .map_err(|e| SetPurposeError::MalformedResponse(result, e))
-        })
+        .map_err(SetPurposeError::Client)?;
+    serde_json::from_slice::<SetPurposeResponse>(&body)
+        .map_err(|e| SetPurposeError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the topic for a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.setTopic
-
 pub async fn set_topic<R>(
     client: &R,
     token: &str,
@@ -474,28 +428,25 @@ pub async fn set_topic<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("topic", request.topic)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("groups.setTopic");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(SetTopicError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetTopicResponse>(&result)
-                .map_err(|e| SetTopicError::MalformedResponse(result, e))
-        })
+        .map_err(SetTopicError::Client)?;
+    serde_json::from_slice::<SetTopicResponse>(&body)
+        .map_err(|e| SetTopicError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Unarchives a private channel.
 ///
 /// Wraps https://api.slack.com/methods/groups.unarchive
-
 pub async fn unarchive<R>(
     client: &R,
     token: &str,
@@ -504,16 +455,14 @@ pub async fn unarchive<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("groups.unarchive");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(UnarchiveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<UnarchiveResponse>(&result)
-                .map_err(|e| UnarchiveError::MalformedResponse(result, e))
-        })
+        .map_err(UnarchiveError::Client)?;
+    serde_json::from_slice::<UnarchiveResponse>(&body)
+        .map_err(|e| UnarchiveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -20,7 +20,6 @@ use crate::requests::SlackWebRequestSender;
 /// Close a direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/im.close
-
 pub async fn close<R>(
     client: &R,
     token: &str,
@@ -29,24 +28,21 @@ pub async fn close<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("im.close");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(CloseError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<CloseResponse>(&result)
-                .map_err(|e| CloseError::MalformedResponse(result, e))
-        })
+        .map_err(CloseError::Client)?;
+    serde_json::from_slice::<CloseResponse>(&body)
+        .map_err(|e| CloseError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Fetches history of messages and events from direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/im.history
-
 pub async fn history<R>(
     client: &R,
     token: &str,
@@ -58,7 +54,7 @@ where
     let latest = request.latest.as_ref().map(|t| t.to_param_value());
     let oldest = request.oldest.as_ref().map(|t| t.to_param_value());
     let count = request.count.map(|count| count.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         latest.as_ref().map(|latest| ("latest", &latest[..])),
@@ -71,23 +67,20 @@ where
             .unreads
             .map(|unreads| ("unreads", if unreads { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("im.history");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(HistoryError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<HistoryResponse>(&result)
-                .map_err(|e| HistoryError::MalformedResponse(result, e))
-        })
+        .map_err(HistoryError::Client)?;
+    serde_json::from_slice::<HistoryResponse>(&body)
+        .map_err(|e| HistoryError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists direct message channels for the calling user.
 ///
 /// Wraps https://api.slack.com/methods/im.list
-
 pub async fn list<R>(
     client: &R,
     token: &str,
@@ -97,28 +90,25 @@ where
     R: SlackWebRequestSender,
 {
     let limit = request.limit.map(|limit| limit.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
-        request.cursor.map(|cursor| ("cursor", cursor)),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
         limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("im.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the read cursor in a direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/im.mark
-
 pub async fn mark<R>(
     client: &R,
     token: &str,
@@ -128,28 +118,25 @@ where
     R: SlackWebRequestSender,
 {
     let ts = request.ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("ts", &ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("im.mark");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(MarkError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<MarkResponse>(&result)
-                .map_err(|e| MarkError::MalformedResponse(result, e))
-        })
+        .map_err(MarkError::Client)?;
+    serde_json::from_slice::<MarkResponse>(&body)
+        .map_err(|e| MarkError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Opens a direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/im.open
-
 pub async fn open<R>(
     client: &R,
     token: &str,
@@ -158,30 +145,27 @@ pub async fn open<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("user", request.user)),
         request
             .return_im
             .map(|return_im| ("return_im", if return_im { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("im.open");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(OpenError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<OpenResponse>(&result)
-                .map_err(|e| OpenError::MalformedResponse(result, e))
-        })
+        .map_err(OpenError::Client)?;
+    serde_json::from_slice::<OpenResponse>(&body)
+        .map_err(|e| OpenError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Retrieve a thread of messages posted to a direct message conversation
 ///
 /// Wraps https://api.slack.com/methods/im.replies
-
 pub async fn replies<R>(
     client: &R,
     token: &str,
@@ -191,20 +175,18 @@ where
     R: SlackWebRequestSender,
 {
     let thread_ts = request.thread_ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("thread_ts", &thread_ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("im.replies");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(RepliesError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RepliesResponse>(&result)
-                .map_err(|e| RepliesError::MalformedResponse(result, e))
-        })
+        .map_err(RepliesError::Client)?;
+    serde_json::from_slice::<RepliesResponse>(&body)
+        .map_err(|e| RepliesError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -20,7 +20,6 @@ use crate::requests::SlackWebRequestSender;
 /// Closes a multiparty direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/mpim.close
-
 pub async fn close<R>(
     client: &R,
     token: &str,
@@ -29,24 +28,21 @@ pub async fn close<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("mpim.close");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(CloseError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<CloseResponse>(&result)
-                .map_err(|e| CloseError::MalformedResponse(result, e))
-        })
+        .map_err(CloseError::Client)?;
+    serde_json::from_slice::<CloseResponse>(&body)
+        .map_err(|e| CloseError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Fetches history of messages and events from a multiparty direct message.
 ///
 /// Wraps https://api.slack.com/methods/mpim.history
-
 pub async fn history<R>(
     client: &R,
     token: &str,
@@ -58,7 +54,7 @@ where
     let latest = request.latest.as_ref().map(|t| t.to_param_value());
     let oldest = request.oldest.as_ref().map(|t| t.to_param_value());
     let count = request.count.map(|count| count.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         latest.as_ref().map(|latest| ("latest", &latest[..])),
@@ -71,44 +67,39 @@ where
             .unreads
             .map(|unreads| ("unreads", if unreads { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("mpim.history");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(HistoryError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<HistoryResponse>(&result)
-                .map_err(|e| HistoryError::MalformedResponse(result, e))
-        })
+        .map_err(HistoryError::Client)?;
+    serde_json::from_slice::<HistoryResponse>(&body)
+        .map_err(|e| HistoryError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists multiparty direct message channels for the calling user.
 ///
 /// Wraps https://api.slack.com/methods/mpim.list
-
 pub async fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("mpim.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, &params[..], &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the read cursor in a multiparty direct message channel.
 ///
 /// Wraps https://api.slack.com/methods/mpim.mark
-
 pub async fn mark<R>(
     client: &R,
     token: &str,
@@ -118,28 +109,25 @@ where
     R: SlackWebRequestSender,
 {
     let ts = request.ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("ts", &ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("mpim.mark");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(MarkError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<MarkResponse>(&result)
-                .map_err(|e| MarkError::MalformedResponse(result, e))
-        })
+        .map_err(MarkError::Client)?;
+    serde_json::from_slice::<MarkResponse>(&body)
+        .map_err(|e| MarkError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// This method opens a multiparty direct message.
 ///
 /// Wraps https://api.slack.com/methods/mpim.open
-
 pub async fn open<R>(
     client: &R,
     token: &str,
@@ -148,24 +136,21 @@ pub async fn open<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("users", request.users))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("users", request.users))];
     let url = crate::get_slack_url_for_method("mpim.open");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(OpenError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<OpenResponse>(&result)
-                .map_err(|e| OpenError::MalformedResponse(result, e))
-        })
+        .map_err(OpenError::Client)?;
+    serde_json::from_slice::<OpenResponse>(&body)
+        .map_err(|e| OpenError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Retrieve a thread of messages posted to a direct message conversation from a multiparty direct message.
 ///
 /// Wraps https://api.slack.com/methods/mpim.replies
-
 pub async fn replies<R>(
     client: &R,
     token: &str,
@@ -175,20 +160,18 @@ where
     R: SlackWebRequestSender,
 {
     let thread_ts = request.thread_ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("thread_ts", &thread_ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("mpim.replies");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(RepliesError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RepliesResponse>(&result)
-                .map_err(|e| RepliesError::MalformedResponse(result, e))
-        })
+        .map_err(RepliesError::Client)?;
+    serde_json::from_slice::<RepliesResponse>(&body)
+        .map_err(|e| RepliesError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
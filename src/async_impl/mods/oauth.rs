@@ -18,7 +18,6 @@ use crate::requests::SlackWebRequestSender;
 /// Exchanges a temporary OAuth code for an API token.
 ///
 /// Wraps https://api.slack.com/methods/oauth.access
-
 pub async fn access<R>(
     client: &R,
     request: &AccessRequest<'_>,
@@ -26,7 +25,7 @@ pub async fn access<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("client_id", request.client_id)),
         Some(("client_secret", request.client_secret)),
         Some(("code", request.code)),
@@ -34,14 +33,12 @@ where
             .redirect_uri
             .map(|redirect_uri| ("redirect_uri", redirect_uri)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("oauth.access");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(AccessError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AccessResponse>(&result)
-                .map_err(|e| AccessError::MalformedResponse(result, e))
-        })
+        .map_err(AccessError::Client)?;
+    serde_json::from_slice::<AccessResponse>(&body)
+        .map_err(|e| AccessError::MalformedResponse(body.to_string_lossy(), e))
 }
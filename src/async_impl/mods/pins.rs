@@ -18,7 +18,6 @@ use crate::requests::SlackWebRequestSender;
 /// Pins an item to a channel.
 ///
 /// Wraps https://api.slack.com/methods/pins.add
-
 pub async fn add<R>(
     client: &R,
     token: &str,
@@ -28,7 +27,7 @@ where
     R: SlackWebRequestSender,
 {
     let timestamp = request.timestamp.as_ref().map(|t| t.to_param_value());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         request.file.map(|file| ("file", file)),
@@ -39,23 +38,20 @@ where
             .as_ref()
             .map(|timestamp| ("timestamp", &timestamp[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("pins.add");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(AddError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
-        })
+        .map_err(AddError::Client)?;
+    serde_json::from_slice::<AddResponse>(&body)
+        .map_err(|e| AddError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists items pinned to a channel.
 ///
 /// Wraps https://api.slack.com/methods/pins.list
-
 pub async fn list<R>(
     client: &R,
     token: &str,
@@ -64,24 +60,21 @@ pub async fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("pins.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Un-pins an item from a channel.
 ///
 /// Wraps https://api.slack.com/methods/pins.remove
-
 pub async fn remove<R>(
     client: &R,
     token: &str,
@@ -91,7 +84,7 @@ where
     R: SlackWebRequestSender,
 {
     let timestamp = request.timestamp.as_ref().map(|t| t.to_param_value());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         request.file.map(|file| ("file", file)),
@@ -102,15 +95,13 @@ where
             .as_ref()
             .map(|timestamp| ("timestamp", &timestamp[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("pins.remove");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(RemoveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
-        })
+        .map_err(RemoveError::Client)?;
+    serde_json::from_slice::<RemoveResponse>(&body)
+        .map_err(|e| RemoveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
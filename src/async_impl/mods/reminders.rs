@@ -18,7 +18,6 @@ use crate::requests::SlackWebRequestSender;
 /// Creates a reminder.
 ///
 /// Wraps https://api.slack.com/methods/reminders.add
-
 pub async fn add<R>(
     client: &R,
     token: &str,
@@ -28,29 +27,26 @@ where
     R: SlackWebRequestSender,
 {
     let time = request.time.to_string();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("text", request.text)),
         Some(("time", &time[..])),
         request.user.map(|user| ("user", user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("reminders.add");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(AddError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
-        })
+        .map_err(AddError::Client)?;
+    serde_json::from_slice::<AddResponse>(&body)
+        .map_err(|e| AddError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Marks a reminder as complete.
 ///
 /// Wraps https://api.slack.com/methods/reminders.complete
-
 pub async fn complete<R>(
     client: &R,
     token: &str,
@@ -59,24 +55,21 @@ pub async fn complete<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("reminder", request.reminder))];
     let url = crate::get_slack_url_for_method("reminders.complete");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(CompleteError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<CompleteResponse>(&result)
-                .map_err(|e| CompleteError::MalformedResponse(result, e))
-        })
+        .map_err(CompleteError::Client)?;
+    serde_json::from_slice::<CompleteResponse>(&body)
+        .map_err(|e| CompleteError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Deletes a reminder.
 ///
 /// Wraps https://api.slack.com/methods/reminders.delete
-
 pub async fn delete<R>(
     client: &R,
     token: &str,
@@ -85,24 +78,21 @@ pub async fn delete<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("reminder", request.reminder))];
     let url = crate::get_slack_url_for_method("reminders.delete");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(DeleteError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result)
-                .map_err(|e| DeleteError::MalformedResponse(result, e))
-        })
+        .map_err(DeleteError::Client)?;
+    serde_json::from_slice::<DeleteResponse>(&body)
+        .map_err(|e| DeleteError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets information about a reminder.
 ///
 /// Wraps https://api.slack.com/methods/reminders.info
-
 pub async fn info<R>(
     client: &R,
     token: &str,
@@ -111,37 +101,33 @@ pub async fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("reminder", request.reminder))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("reminder", request.reminder))];
     let url = crate::get_slack_url_for_method("reminders.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists all reminders created by or for a given user.
 ///
 /// Wraps https://api.slack.com/methods/reminders.list
-
 pub async fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("reminders.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, &params[..], &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -20,7 +20,6 @@ use crate::requests::SlackWebRequestSender;
 /// Searches for messages and files matching a query.
 ///
 /// Wraps https://api.slack.com/methods/search.all
-
 pub async fn all<R>(
     client: &R,
     token: &str,
@@ -31,7 +30,7 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("query", request.query)),
         request.sort.map(|sort| ("sort", sort)),
@@ -42,23 +41,20 @@ where
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("search.all");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(AllError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AllResponse>(&result)
-                .map_err(|e| AllError::MalformedResponse(result, e))
-        })
+        .map_err(AllError::Client)?;
+    serde_json::from_slice::<AllResponse>(&body)
+        .map_err(|e| AllError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Searches for files matching a query.
 ///
 /// Wraps https://api.slack.com/methods/search.files
-
 pub async fn files<R>(
     client: &R,
     token: &str,
@@ -69,7 +65,7 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("query", request.query)),
         request.sort.map(|sort| ("sort", sort)),
@@ -80,23 +76,20 @@ where
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("search.files");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(FilesError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<FilesResponse>(&result)
-                .map_err(|e| FilesError::MalformedResponse(result, e))
-        })
+        .map_err(FilesError::Client)?;
+    serde_json::from_slice::<FilesResponse>(&body)
+        .map_err(|e| FilesError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Searches for messages matching a query.
 ///
 /// Wraps https://api.slack.com/methods/search.messages
-
 pub async fn messages<R>(
     client: &R,
     token: &str,
@@ -107,7 +100,7 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("query", request.query)),
         request.sort.map(|sort| ("sort", sort)),
@@ -118,15 +111,13 @@ where
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("search.messages");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(MessagesError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<MessagesResponse>(&result)
-                .map_err(|e| MessagesError::MalformedResponse(result, e))
-        })
+        .map_err(MessagesError::Client)?;
+    serde_json::from_slice::<MessagesResponse>(&body)
+        .map_err(|e| MessagesError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -0,0 +1,102 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Create and manage lists.
+
+pub use crate::mod_types::slack_lists_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Creates a list.
+///
+/// Wraps https://api.slack.com/methods/slackLists.create
+pub async fn create<R>(
+    client: &R,
+    token: &str,
+    request: &CreateRequest<'_>,
+) -> Result<CreateResponse, CreateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let description_blocks = request.description_blocks.map(crate::to_json_param);
+    let schema = request.schema.map(crate::to_json_param);
+    let params = [
+        Some(("token", token)),
+        Some(("name", request.name)),
+        request
+            .copy_from_list_id
+            .map(|copy_from_list_id| ("copy_from_list_id", copy_from_list_id)),
+        description_blocks
+            .as_ref()
+            .map(|description_blocks| ("description_blocks", &description_blocks[..])),
+        request
+            .include_copied_list_records
+            .map(|include_copied_list_records| {
+                (
+                    "include_copied_list_records",
+                    if include_copied_list_records {
+                        "1"
+                    } else {
+                        "0"
+                    },
+                )
+            }),
+        schema.as_ref().map(|schema| ("schema", &schema[..])),
+        request
+            .todo_mode
+            .map(|todo_mode| ("todo_mode", if todo_mode { "1" } else { "0" })),
+    ];
+    let url = crate::get_slack_url_for_method("slackLists.create");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(CreateError::Client)?;
+    serde_json::from_slice::<CreateResponse>(&body)
+        .map_err(|e| CreateError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Updates the name, description or to-do mode of a list.
+///
+/// Wraps https://api.slack.com/methods/slackLists.update
+pub async fn update<R>(
+    client: &R,
+    token: &str,
+    request: &UpdateRequest<'_>,
+) -> Result<UpdateResponse, UpdateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let description_blocks = request.description_blocks.map(crate::to_json_param);
+    let params = [
+        Some(("token", token)),
+        Some(("id", request.id)),
+        description_blocks
+            .as_ref()
+            .map(|description_blocks| ("description_blocks", &description_blocks[..])),
+        request.name.map(|name| ("name", name)),
+        request
+            .todo_mode
+            .map(|todo_mode| ("todo_mode", if todo_mode { "1" } else { "0" })),
+    ];
+    let url = crate::get_slack_url_for_method("slackLists.update");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(UpdateError::Client)?;
+    serde_json::from_slice::<UpdateResponse>(&body)
+        .map_err(|e| UpdateError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
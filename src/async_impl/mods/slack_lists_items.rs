@@ -0,0 +1,202 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Add, update and remove the items of a list.
+
+pub use crate::mod_types::slack_lists_items_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Adds an item to a list.
+///
+/// Wraps https://api.slack.com/methods/slackLists.items.create
+pub async fn create<R>(
+    client: &R,
+    token: &str,
+    request: &CreateRequest<'_>,
+) -> Result<CreateResponse, CreateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let initial_fields = request.initial_fields.map(crate::to_json_param);
+    let params = [
+        Some(("token", token)),
+        Some(("list_id", request.list_id)),
+        request
+            .duplicated_item_id
+            .map(|duplicated_item_id| ("duplicated_item_id", duplicated_item_id)),
+        initial_fields
+            .as_ref()
+            .map(|initial_fields| ("initial_fields", &initial_fields[..])),
+        request
+            .parent_item_id
+            .map(|parent_item_id| ("parent_item_id", parent_item_id)),
+    ];
+    let url = crate::get_slack_url_for_method("slackLists.items.create");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(CreateError::Client)?;
+    serde_json::from_slice::<CreateResponse>(&body)
+        .map_err(|e| CreateError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Removes an item from a list.
+///
+/// Wraps https://api.slack.com/methods/slackLists.items.delete
+pub async fn delete<R>(
+    client: &R,
+    token: &str,
+    request: &DeleteRequest<'_>,
+) -> Result<DeleteResponse, DeleteError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("list_id", request.list_id)),
+        Some(("id", request.id)),
+    ];
+    let url = crate::get_slack_url_for_method("slackLists.items.delete");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(DeleteError::Client)?;
+    serde_json::from_slice::<DeleteResponse>(&body)
+        .map_err(|e| DeleteError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Removes several items from a list.
+///
+/// Wraps https://api.slack.com/methods/slackLists.items.deleteMultiple
+pub async fn delete_multiple<R>(
+    client: &R,
+    token: &str,
+    request: &DeleteMultipleRequest<'_>,
+) -> Result<DeleteMultipleResponse, DeleteMultipleError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let ids = request.ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("list_id", request.list_id)),
+        Some(("ids", &ids[..])),
+    ];
+    let url = crate::get_slack_url_for_method("slackLists.items.deleteMultiple");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(DeleteMultipleError::Client)?;
+    serde_json::from_slice::<DeleteMultipleResponse>(&body)
+        .map_err(|e| DeleteMultipleError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Gets an item of a list, with its subtasks.
+///
+/// Wraps https://api.slack.com/methods/slackLists.items.info
+pub async fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest<'_>,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("list_id", request.list_id)),
+        Some(("id", request.id)),
+        request.include_is_subscribed.map(|include_is_subscribed| {
+            (
+                "include_is_subscribed",
+                if include_is_subscribed { "1" } else { "0" },
+            )
+        }),
+    ];
+    let url = crate::get_slack_url_for_method("slackLists.items.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Lists the items of a list.
+///
+/// Wraps https://api.slack.com/methods/slackLists.items.list
+pub async fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = [
+        Some(("token", token)),
+        Some(("list_id", request.list_id)),
+        request
+            .archived
+            .map(|archived| ("archived", if archived { "1" } else { "0" })),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+    ];
+    let url = crate::get_slack_url_for_method("slackLists.items.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Sets fields of items of a list.
+///
+/// Wraps https://api.slack.com/methods/slackLists.items.update
+pub async fn update<R>(
+    client: &R,
+    token: &str,
+    request: &UpdateRequest<'_>,
+) -> Result<UpdateResponse, UpdateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let cells = crate::to_json_param(request.cells);
+    let params = [
+        Some(("token", token)),
+        Some(("list_id", request.list_id)),
+        Some(("cells", &cells[..])),
+    ];
+    let url = crate::get_slack_url_for_method("slackLists.items.update");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(UpdateError::Client)?;
+    serde_json::from_slice::<UpdateResponse>(&body)
+        .map_err(|e| UpdateError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
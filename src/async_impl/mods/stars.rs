@@ -12,13 +12,13 @@
 //
 //=============================================================================
 
+pub use crate::async_impl::ext::stars::*;
 pub use crate::mod_types::stars_types::*;
 use crate::requests::SlackWebRequestSender;
 
 /// Adds a star to an item.
 ///
 /// Wraps https://api.slack.com/methods/stars.add
-
 pub async fn add<R>(
     client: &R,
     token: &str,
@@ -28,7 +28,7 @@ where
     R: SlackWebRequestSender,
 {
     let timestamp = request.timestamp.as_ref().map(|t| t.to_param_value());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.file.map(|file| ("file", file)),
         request
@@ -39,55 +39,52 @@ where
             .as_ref()
             .map(|timestamp| ("timestamp", &timestamp[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("stars.add");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(AddError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
-        })
+        .map_err(AddError::Client)?;
+    serde_json::from_slice::<AddResponse>(&body)
+        .map_err(|e| AddError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists stars for a user.
 ///
 /// Wraps https://api.slack.com/methods/stars.list
-
 pub async fn list<R>(
     client: &R,
     token: &str,
-    request: &ListRequest,
+    request: &ListRequest<'_>,
 ) -> Result<ListResponse, ListError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let count = request.count.map(|count| count.to_string());
+    let limit = request.limit.map(|limit| limit.to_string());
     let page = request.page.map(|page| page.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         count.as_ref().map(|count| ("count", &count[..])),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("stars.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Removes a star from an item.
 ///
 /// Wraps https://api.slack.com/methods/stars.remove
-
 pub async fn remove<R>(
     client: &R,
     token: &str,
@@ -97,7 +94,7 @@ where
     R: SlackWebRequestSender,
 {
     let timestamp = request.timestamp.as_ref().map(|t| t.to_param_value());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.file.map(|file| ("file", file)),
         request
@@ -108,15 +105,13 @@ where
             .as_ref()
             .map(|timestamp| ("timestamp", &timestamp[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("stars.remove");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(RemoveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
-        })
+        .map_err(RemoveError::Client)?;
+    serde_json::from_slice::<RemoveResponse>(&body)
+        .map_err(|e| RemoveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
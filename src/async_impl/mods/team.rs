@@ -12,13 +12,13 @@
 //
 //=============================================================================
 
+pub use crate::async_impl::ext::team::*;
 pub use crate::mod_types::team_types::*;
 use crate::requests::SlackWebRequestSender;
 
 /// Gets the access logs for the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.accessLogs
-
 pub async fn access_logs<R>(
     client: &R,
     token: &str,
@@ -30,29 +30,26 @@ where
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let before = request.before.map(|before| before.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
         before.as_ref().map(|before| ("before", &before[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("team.accessLogs");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(AccessLogsError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AccessLogsResponse>(&result)
-                .map_err(|e| AccessLogsError::MalformedResponse(result, e))
-        })
+        .map_err(AccessLogsError::Client)?;
+    serde_json::from_slice::<AccessLogsResponse>(&body)
+        .map_err(|e| AccessLogsError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets billable users information for the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.billableInfo
-
 pub async fn billable_info<R>(
     client: &R,
     token: &str,
@@ -61,48 +58,43 @@ pub async fn billable_info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.user.map(|user| ("user", user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("team.billableInfo");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(BillableInfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<BillableInfoResponse>(&result)
-                .map_err(|e| BillableInfoError::MalformedResponse(result, e))
-        })
+        .map_err(BillableInfoError::Client)?;
+    serde_json::from_slice::<BillableInfoResponse>(&body)
+        .map_err(|e| BillableInfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets information about the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.info
-
 pub async fn info<R>(client: &R, token: &str) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("team.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, &params[..], &mut body)
         .await
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets the integration logs for the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.integrationLogs
-
 pub async fn integration_logs<R>(
     client: &R,
     token: &str,
@@ -113,7 +105,7 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request
             .service_id
@@ -126,15 +118,13 @@ where
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("team.integrationLogs");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(IntegrationLogsError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<IntegrationLogsResponse>(&result)
-                .map_err(|e| IntegrationLogsError::MalformedResponse(result, e))
-        })
+        .map_err(IntegrationLogsError::Client)?;
+    serde_json::from_slice::<IntegrationLogsResponse>(&body)
+        .map_err(|e| IntegrationLogsError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -0,0 +1,35 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+pub use crate::mod_types::team_preferences_types::*;
+use crate::requests::SlackWebRequestSender;
+
+/// Retrieve a list of a workspace's team preferences.
+///
+/// Wraps https://api.slack.com/methods/team.preferences.list
+pub async fn list<R>(client: &R, token: &str) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = &[("token", token)];
+    let url = crate::get_slack_url_for_method("team.preferences.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, &params[..], &mut body)
+        .await
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
@@ -18,7 +18,6 @@ use crate::requests::SlackWebRequestSender;
 /// Retrieve a team's profile.
 ///
 /// Wraps https://api.slack.com/methods/team.profile.get
-
 pub async fn get<R>(
     client: &R,
     token: &str,
@@ -27,21 +26,19 @@ pub async fn get<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request
             .visibility
             .map(|visibility| ("visibility", visibility)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("team.profile.get");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(GetError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<GetResponse>(&result)
-                .map_err(|e| GetError::MalformedResponse(result, e))
-        })
+        .map_err(GetError::Client)?;
+    serde_json::from_slice::<GetResponse>(&body)
+        .map_err(|e| GetError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
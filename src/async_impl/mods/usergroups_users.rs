@@ -18,7 +18,6 @@ use crate::requests::SlackWebRequestSender;
 /// List all users in a User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.users.list
-
 pub async fn list<R>(
     client: &R,
     token: &str,
@@ -27,30 +26,27 @@ pub async fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("usergroup", request.usergroup)),
         request
             .include_disabled
             .map(|include_disabled| ("include_disabled", if include_disabled { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("usergroups.users.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Update the list of users for a User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.users.update
-
 pub async fn update<R>(
     client: &R,
     token: &str,
@@ -59,7 +55,7 @@ pub async fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("usergroup", request.usergroup)),
         Some(("users", request.users)),
@@ -67,15 +63,13 @@ where
             .include_count
             .map(|include_count| ("include_count", if include_count { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("usergroups.users.update");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(UpdateError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result)
-                .map_err(|e| UpdateError::MalformedResponse(result, e))
-        })
+        .map_err(UpdateError::Client)?;
+    serde_json::from_slice::<UpdateResponse>(&body)
+        .map_err(|e| UpdateError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
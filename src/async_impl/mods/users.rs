@@ -14,13 +14,13 @@
 
 //! Get info on members of your Slack team.
 
+pub use crate::async_impl::ext::users::*;
 pub use crate::mod_types::users_types::*;
 use crate::requests::SlackWebRequestSender;
 
 /// Delete the user profile photo
 ///
 /// Wraps https://api.slack.com/methods/users.deletePhoto
-
 pub async fn delete_photo<R>(
     client: &R,
     token: &str,
@@ -30,21 +30,19 @@ where
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("users.deletePhoto");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, &params[..], &mut body)
         .await
-        .map_err(DeletePhotoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<DeletePhotoResponse>(&result)
-                .map_err(|e| DeletePhotoError::MalformedResponse(result, e))
-        })
+        .map_err(DeletePhotoError::Client)?;
+    serde_json::from_slice::<DeletePhotoResponse>(&body)
+        .map_err(|e| DeletePhotoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets user presence information.
 ///
 /// Wraps https://api.slack.com/methods/users.getPresence
-
 pub async fn get_presence<R>(
     client: &R,
     token: &str,
@@ -53,24 +51,21 @@ pub async fn get_presence<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("user", request.user))];
     let url = crate::get_slack_url_for_method("users.getPresence");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(GetPresenceError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<GetPresenceResponse>(&result)
-                .map_err(|e| GetPresenceError::MalformedResponse(result, e))
-        })
+        .map_err(GetPresenceError::Client)?;
+    serde_json::from_slice::<GetPresenceResponse>(&body)
+        .map_err(|e| GetPresenceError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Get a user's identity.
 ///
 /// Wraps https://api.slack.com/methods/users.identity
-
 pub async fn identity<R>(
     client: &R,
     token: &str,
@@ -80,21 +75,19 @@ where
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("users.identity");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, &params[..], &mut body)
         .await
-        .map_err(IdentityError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<IdentityResponse>(&result)
-                .map_err(|e| IdentityError::MalformedResponse(result, e))
-        })
+        .map_err(IdentityError::Client)?;
+    serde_json::from_slice::<IdentityResponse>(&body)
+        .map_err(|e| IdentityError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets information about a user.
 ///
 /// Wraps https://api.slack.com/methods/users.info
-
 pub async fn info<R>(
     client: &R,
     token: &str,
@@ -103,55 +96,85 @@ pub async fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("user", request.user))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [
+        Some(("token", token)),
+        Some(("user", request.user)),
+        request
+            .include_locale
+            .map(|include_locale| ("include_locale", if include_locale { "1" } else { "0" })),
+    ];
     let url = crate::get_slack_url_for_method("users.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists all users in a Slack team.
 ///
 /// Wraps https://api.slack.com/methods/users.list
-
 pub async fn list<R>(
     client: &R,
     token: &str,
-    request: &ListRequest,
+    request: &ListRequest<'_>,
 ) -> Result<ListResponse, ListError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = [
         Some(("token", token)),
         request
             .presence
             .map(|presence| ("presence", if presence { "1" } else { "0" })),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request
+            .include_locale
+            .map(|include_locale| ("include_locale", if include_locale { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("users.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .await
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Find a user with an email address.
+///
+/// Wraps https://api.slack.com/methods/users.lookupByEmail
+pub async fn lookup_by_email<R>(
+    client: &R,
+    token: &str,
+    request: &LookupByEmailRequest<'_>,
+) -> Result<LookupByEmailResponse, LookupByEmailError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [Some(("token", token)), Some(("email", request.email))];
+    let url = crate::get_slack_url_for_method("users.lookupByEmail");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .map_err(LookupByEmailError::Client)?;
+    serde_json::from_slice::<LookupByEmailResponse>(&body)
+        .map_err(|e| LookupByEmailError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Marks a user as active.
 ///
 /// Wraps https://api.slack.com/methods/users.setActive
-
+#[deprecated(note = "Slack no longer tracks activity this way, so users.setActive does nothing")]
 pub async fn set_active<R>(
     client: &R,
     token: &str,
@@ -161,21 +184,19 @@ where
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("users.setActive");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, &params[..], &mut body)
         .await
-        .map_err(SetActiveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetActiveResponse>(&result)
-                .map_err(|e| SetActiveError::MalformedResponse(result, e))
-        })
+        .map_err(SetActiveError::Client)?;
+    serde_json::from_slice::<SetActiveResponse>(&body)
+        .map_err(|e| SetActiveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Manually sets user presence.
 ///
 /// Wraps https://api.slack.com/methods/users.setPresence
-
 pub async fn set_presence<R>(
     client: &R,
     token: &str,
@@ -184,16 +205,14 @@ pub async fn set_presence<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("presence", request.presence))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("presence", request.presence))];
     let url = crate::get_slack_url_for_method("users.setPresence");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(SetPresenceError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetPresenceResponse>(&result)
-                .map_err(|e| SetPresenceError::MalformedResponse(result, e))
-        })
+        .map_err(SetPresenceError::Client)?;
+    serde_json::from_slice::<SetPresenceResponse>(&body)
+        .map_err(|e| SetPresenceError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -18,7 +18,6 @@ use crate::requests::SlackWebRequestSender;
 /// Retrieves a user's profile information.
 ///
 /// Wraps https://api.slack.com/methods/users.profile.get
-
 pub async fn get<R>(
     client: &R,
     token: &str,
@@ -27,30 +26,27 @@ pub async fn get<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.user.map(|user| ("user", user)),
         request
             .include_labels
             .map(|include_labels| ("include_labels", if include_labels { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("users.profile.get");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(GetError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<GetResponse>(&result)
-                .map_err(|e| GetError::MalformedResponse(result, e))
-        })
+        .map_err(GetError::Client)?;
+    serde_json::from_slice::<GetResponse>(&body)
+        .map_err(|e| GetError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Set the profile information for a user.
 ///
 /// Wraps https://api.slack.com/methods/users.profile.set
-
 pub async fn set<R>(
     client: &R,
     token: &str,
@@ -59,22 +55,20 @@ pub async fn set<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.user.map(|user| ("user", user)),
         request.profile.map(|profile| ("profile", profile)),
         request.name.map(|name| ("name", name)),
         request.value.map(|value| ("value", value)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("users.profile.set");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
         .await
-        .map_err(SetError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetResponse>(&result)
-                .map_err(|e| SetError::MalformedResponse(result, e))
-        })
+        .map_err(SetError::Client)?;
+    serde_json::from_slice::<SetResponse>(&body)
+        .map_err(|e| SetError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -0,0 +1,21 @@
+//! Verifying the ID tokens of Sign in with Slack.
+
+pub use crate::oidc_types::*;
+
+use super::requests::SlackWebRequestSender;
+use crate::oidc_types::parse_keys;
+
+/// Fetches the keys Slack signs ID tokens with, for an [`IdTokenVerifier`].
+///
+/// Slack rotates its keys, so fetch them again when verifying fails with
+/// [`IdTokenError::UnknownKey`].
+pub async fn fetch_keys<R>(client: &R) -> Result<Jwks, FetchKeysError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let body = client
+        .send(SLACK_JWKS_URL, std::iter::empty::<(&str, &str)>())
+        .await
+        .map_err(FetchKeysError::Client)?;
+    parse_keys(body)
+}
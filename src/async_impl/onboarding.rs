@@ -0,0 +1,186 @@
+//! Brings a user into a workspace, with their channels and User Groups, in one call.
+
+pub use crate::onboarding_types::*;
+
+use super::requests::SlackWebRequestSender;
+use crate::mod_types::admin_users_types::{AssignError, AssignRequest, InviteRequest};
+use crate::mod_types::conversations_types::{InviteError, InviteRequest as ChannelInviteRequest};
+use crate::mod_types::users_types::{LookupByEmailError, LookupByEmailRequest};
+use crate::usergroups::add_member;
+use crate::{admin_users, conversations, users};
+
+/// Brings the user with `email` into a workspace and adds them to channels and User Groups.
+///
+/// Someone without a Slack account is invited with `admin.users.invite`, joining the channels
+/// when they accept; their User Groups are left pending. A member of the organization is added
+/// to the workspace with `admin.users.assign`, or invited to each channel if they're already a
+/// member of it, and then added to each User Group.
+///
+/// The token needs the `admin.users:write` scope. Once the user is in the workspace or invited,
+/// failures are collected in the report instead of stopping the rest.
+pub async fn invite_user<R>(
+    client: &R,
+    token: &str,
+    request: &InviteUserRequest<'_>,
+) -> Result<OnboardingReport<R::Error>, InviteUserError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let lookup = users::lookup_by_email(
+        client,
+        token,
+        &LookupByEmailRequest {
+            email: request.email,
+        },
+    )
+    .await;
+    let user_id = match lookup {
+        Ok(response) => response.user.and_then(|u| u.id),
+        Err(LookupByEmailError::UsersNotFound) => None,
+        Err(e) => return Err(InviteUserError::Lookup(e)),
+    };
+
+    let user_id = match user_id {
+        Some(user_id) => user_id,
+        None => {
+            admin_users::invite(
+                client,
+                token,
+                &InviteRequest {
+                    channel_ids: request.channel_ids,
+                    email: request.email,
+                    team_id: request.team_id,
+                    custom_message: request.custom_message,
+                    real_name: request.real_name,
+                    ..InviteRequest::default()
+                },
+            )
+            .await
+            .map_err(InviteUserError::Invite)?;
+            let mut report = OnboardingReport::new(Onboarded::Invited);
+            report.usergroups_pending = request.usergroups.iter().map(|&g| g.to_owned()).collect();
+            return Ok(report);
+        }
+    };
+
+    let assign = admin_users::assign(
+        client,
+        token,
+        &AssignRequest {
+            team_id: request.team_id,
+            user_id: &user_id,
+            channel_ids: Some(request.channel_ids).filter(|c| !c.is_empty()),
+            ..AssignRequest::default()
+        },
+    )
+    .await;
+    let mut report = match assign {
+        Ok(_) => OnboardingReport::new(Onboarded::Assigned(user_id.clone())),
+        Err(AssignError::UserAlreadyTeamMember) => {
+            let mut report = OnboardingReport::new(Onboarded::AlreadyMember(user_id.clone()));
+            for &channel in request.channel_ids {
+                let invite = conversations::invite(
+                    client,
+                    token,
+                    &ChannelInviteRequest {
+                        channel,
+                        users: &user_id,
+                        force: None,
+                    },
+                )
+                .await;
+                match invite {
+                    Ok(_) | Err(InviteError::AlreadyInChannel) => {}
+                    Err(e) => report.channels_failed.push((channel.to_owned(), e)),
+                }
+            }
+            report
+        }
+        Err(e) => return Err(InviteUserError::Assign(e)),
+    };
+
+    for &usergroup in request.usergroups {
+        match add_member(client, token, usergroup, &user_id).await {
+            Ok(_) => report.usergroups_added.push(usergroup.to_owned()),
+            Err(e) => report.usergroups_failed.push((usergroup.to_owned(), e)),
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// A workspace where `known@example.com` is `U1`, already a member of the workspace. Channel
+    /// `C404` doesn't exist and User Group `S404` can't be updated.
+    fn workspace() -> MockSender {
+        MockSender::new()
+            .on_call("users.lookupByEmail", |call| {
+                if call.param("email") == "known@example.com" {
+                    r#"{"ok": true, "user": {"id": "U1"}}"#
+                } else {
+                    r#"{"ok": false, "error": "users_not_found"}"#
+                }
+            })
+            .on("admin.users.invite", r#"{"ok": true}"#)
+            .on(
+                "admin.users.assign",
+                r#"{"ok": false, "error": "user_already_team_member"}"#,
+            )
+            .on_call("conversations.invite", |call| {
+                if call.param("channel") == "C404" {
+                    r#"{"ok": false, "error": "channel_not_found"}"#
+                } else {
+                    r#"{"ok": false, "error": "already_in_channel"}"#
+                }
+            })
+            .on("usergroups.users.list", r#"{"ok": true, "users": ["U2"]}"#)
+            .on_call("usergroups.users.update", |call| {
+                if call.param("usergroup") == "S404" {
+                    return r#"{"ok": false, "error": "permission_denied"}"#;
+                }
+                assert_eq!(call.param("users"), "U2,U1");
+                r#"{"ok": true}"#
+            })
+    }
+
+    #[tokio::test]
+    async fn test_invite_user() {
+        let client = workspace();
+        let request = InviteUserRequest {
+            email: "new@example.com",
+            team_id: "T1",
+            channel_ids: &["C1"],
+            usergroups: &["S1"],
+            ..InviteUserRequest::default()
+        };
+        let report = invite_user(&client, "xoxp", &request).await.unwrap();
+        assert_eq!(report.onboarded, Onboarded::Invited);
+        assert_eq!(report.usergroups_pending, ["S1"]);
+        assert!(!report.is_complete());
+        assert_eq!(
+            client.methods(),
+            ["users.lookupByEmail", "admin.users.invite"]
+        );
+
+        let request = InviteUserRequest {
+            email: "known@example.com",
+            channel_ids: &["C1", "C404"],
+            usergroups: &["S1", "S404"],
+            ..request
+        };
+        let report = invite_user(&client, "xoxp", &request).await.unwrap();
+        assert_eq!(report.onboarded.user_id(), Some("U1"));
+        assert!(matches!(
+            report.channels_failed[..],
+            [(ref channel, InviteError::ChannelNotFound)] if channel == "C404"
+        ));
+        assert_eq!(report.usergroups_added, ["S1"]);
+        assert_eq!(report.usergroups_failed.len(), 1);
+        assert_eq!(report.usergroups_failed[0].0, "S404");
+        assert!(report.usergroups_pending.is_empty());
+    }
+}
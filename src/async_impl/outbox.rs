@@ -0,0 +1,96 @@
+//! A durable outbox for fire-and-forget calls, which survive restarts until Slack accepts them.
+
+pub use crate::outbox_types::*;
+
+use super::requests::SlackWebRequestSender;
+use crate::outbox_types::{outcome, Outcome};
+
+/// Sends the calls waiting in `outbox` with `token`, oldest first.
+///
+/// Flushing stops early, leaving the rest waiting, when Slack rate limits a call or the client
+/// fails to send one.
+pub async fn flush<S, R>(
+    outbox: &Outbox<S>,
+    client: &R,
+    token: &str,
+) -> Result<FlushReport, FlushError<S::Error, R::Error>>
+where
+    S: OutboxStore,
+    R: SlackWebRequestSender,
+{
+    let pending = outbox.store.pending().map_err(FlushError::Store)?;
+    let mut report = FlushReport::default();
+    for (i, entry) in pending.iter().enumerate() {
+        let url = crate::get_slack_url_for_method(&entry.method);
+        let params = Some(("token", token))
+            .into_iter()
+            .chain(entry.params.iter().map(|(k, v)| (&k[..], &v[..])));
+        let body = client
+            .send(&url, params)
+            .await
+            .map_err(FlushError::Client)?;
+        match outcome(&body) {
+            Outcome::Sent => report.sent.push(entry.key.clone()),
+            Outcome::Rejected(error) => report.rejected.push((entry.key.clone(), error)),
+            Outcome::Retry => {
+                report.pending = pending.len() - i;
+                return Ok(report);
+            }
+        }
+        outbox.store.remove(&entry.key).map_err(FlushError::Store)?;
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// Refuses messages to `C404` and rate limits messages to `C429`.
+    fn channels() -> MockSender {
+        MockSender::new().on_call("chat.postMessage", |call| match call.param("channel") {
+            "C404" => r#"{"ok": false, "error": "channel_not_found"}"#,
+            "C429" => r#"{"ok": false, "error": "ratelimited"}"#,
+            _ => r#"{"ok": true}"#,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_flush() {
+        let outbox = Outbox::new(MemoryStore::new());
+        for (key, channel) in &[
+            ("a", "C1"),
+            ("b", "C404"),
+            ("a", "C2"),
+            ("c", "C429"),
+            ("d", "C3"),
+        ] {
+            outbox
+                .enqueue(*key, "chat.postMessage", &[("channel", channel)])
+                .unwrap();
+        }
+        let client = channels();
+        let report = flush(&outbox, &client, "xoxb").await.unwrap();
+        assert_eq!(report.sent, ["a"]);
+        assert_eq!(
+            report.rejected,
+            [("b".to_owned(), "channel_not_found".to_owned())]
+        );
+        assert_eq!(report.pending, 2);
+        let channels: Vec<_> = client
+            .calls()
+            .iter()
+            .map(|call| call.param("channel").to_owned())
+            .collect();
+        assert_eq!(channels, ["C1", "C404", "C429"]);
+        let keys: Vec<_> = outbox
+            .pending()
+            .unwrap()
+            .into_iter()
+            .map(|e| e.key)
+            .collect();
+        assert_eq!(keys, ["c", "d"]);
+    }
+}
@@ -0,0 +1,140 @@
+//! Watching users' presence by polling `users.getPresence`, for apps without RTM.
+
+pub use crate::presence_types::*;
+
+use std::time::Duration;
+
+use futures_timer::Delay;
+use futures_util::future::{self, Either};
+
+use super::requests::{RateLimitRetry, SlackWebRequestSender};
+use super::users;
+use crate::CancelToken;
+
+/// Polls the presence of each of the poller's users once, returning the changes since the last
+/// poll.
+///
+/// Rate limited calls are retried as the poller's `retry_policy` allows.
+pub async fn poll<R>(
+    client: &R,
+    token: &str,
+    poller: &mut PresencePoller,
+) -> Result<Vec<PresenceChanged>, users::GetPresenceError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let client = &RateLimitRetry::new(client).policy(poller.retry_policy.clone());
+    let mut changes = Vec::new();
+    for (i, user) in poller.users.clone().iter().enumerate() {
+        if i > 0 {
+            Delay::new(poller.call_interval).await;
+        }
+        let response =
+            users::get_presence(client, token, &users::GetPresenceRequest { user }).await?;
+        if let Some(presence) = response.presence {
+            changes.extend(poller.record(user, presence.as_str().into()));
+        }
+    }
+    Ok(changes)
+}
+
+/// Polls the presence of the poller's users every `interval` until `cancel` is cancelled, passing
+/// each change to `on_change`.
+///
+/// Returns the first error a poll fails with, after which the poller can be run again.
+pub async fn run<R, F>(
+    client: &R,
+    token: &str,
+    poller: &mut PresencePoller,
+    cancel: &CancelToken,
+    mut on_change: F,
+) -> Result<(), users::GetPresenceError<R::Error>>
+where
+    R: SlackWebRequestSender,
+    F: FnMut(PresenceChanged),
+{
+    while !cancel.is_cancelled() {
+        poll(client, token, poller)
+            .await?
+            .into_iter()
+            .for_each(&mut on_change);
+        if wait(poller.interval, cancel).await {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Waits for `duration`, returning early with `true` if `cancel` is cancelled.
+async fn wait(duration: Duration, cancel: &CancelToken) -> bool {
+    match future::select(Delay::new(duration), cancel.cancelled()).await {
+        Either::Left(_) => false,
+        Either::Right(_) => true,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use crate::async_impl::test_support::MockSender;
+    use crate::requests::RetryPolicy;
+
+    /// Serves U1 as active and U2 as away until the third round, then as active.
+    fn presences() -> MockSender {
+        let calls = AtomicUsize::new(0);
+        MockSender::new().on_call("users.getPresence", move |call| {
+            let presence = match call.param("user") {
+                "U2" if calls.fetch_add(1, Ordering::SeqCst) < 2 => "away",
+                _ => "active",
+            };
+            serde_json::json!({"ok": true, "presence": presence})
+        })
+    }
+
+    #[tokio::test]
+    async fn test_run_reports_changes_until_cancelled() {
+        let sender = presences();
+        let mut poller = PresencePoller::new(vec!["U1", "U2"])
+            .interval(Duration::from_millis(0))
+            .call_interval(Duration::from_millis(0));
+        let cancel = CancelToken::new();
+        let mut changes = Vec::new();
+        run(&sender, "xoxb", &mut poller, &cancel, |change| {
+            changes.push((change.user.clone(), change.presence));
+            if changes.len() == 3 {
+                cancel.cancel();
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(
+            changes,
+            vec![
+                ("U1".to_owned(), Presence::Active),
+                ("U2".to_owned(), Presence::Away),
+                ("U2".to_owned(), Presence::Active),
+            ]
+        );
+        assert_eq!(sender.calls().len(), 6);
+    }
+
+    #[tokio::test]
+    async fn test_poll_retries_rate_limited_calls() {
+        let calls = AtomicUsize::new(0);
+        let sender = MockSender::new().on_call("users.getPresence", move |_| {
+            if calls.fetch_add(1, Ordering::SeqCst) == 0 {
+                serde_json::json!({"ok": false, "error": "ratelimited"})
+            } else {
+                serde_json::json!({"ok": true, "presence": "active"})
+            }
+        });
+        let mut poller = PresencePoller::new(vec!["U1"])
+            .retry_policy(RetryPolicy::new().default_wait(Duration::from_millis(0)));
+        let changes = poll(&sender, "xoxb", &mut poller).await.unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(sender.calls().len(), 2);
+    }
+}
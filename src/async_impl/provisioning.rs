@@ -0,0 +1,229 @@
+//! Helpers which set channels up the same way however many times they run.
+
+pub use crate::provisioning_types::*;
+
+use super::requests::SlackWebRequestSender;
+use super::{bookmarks, conversations};
+use crate::ext_types::conversations::MembershipReport;
+use crate::provisioning_types::{
+    find_by_name, missing_bookmarks, needs_update, settle_members, usable_channel_id,
+};
+use crate::{Conversation, ConversationTypes};
+
+/// Makes sure a channel exists with the request's topic, purpose, members and bookmarks, creating
+/// it if needed.
+///
+/// A channel whose name is taken is looked up and updated, so running this again is harmless:
+/// the topic and purpose are only set if they differ, members already in the channel count as
+/// invited and bookmarks are only added if their link isn't bookmarked yet. Updating an existing
+/// channel needs the caller to be a member of it.
+pub async fn ensure_channel<R>(
+    client: &R,
+    token: &str,
+    request: &EnsureChannelRequest<'_>,
+) -> Result<EnsuredChannel<R::Error>, EnsureChannelError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let created = conversations::create(
+        client,
+        token,
+        &conversations::CreateRequest {
+            name: request.name,
+            is_private: request.is_private,
+            team_id: request.team_id,
+        },
+    )
+    .await;
+    let (mut channel, created) = match created {
+        Ok(response) => (response.channel.ok_or(EnsureChannelError::NotFound)?, true),
+        Err(conversations::CreateError::NameTaken) => {
+            (find_channel(client, token, request).await?, false)
+        }
+        Err(e) => return Err(EnsureChannelError::Create(e)),
+    };
+    let id = usable_channel_id(&channel)?;
+
+    let topic = channel.topic.as_ref().and_then(|t| t.value.as_deref());
+    if let Some(topic) = needs_update(topic, request.topic) {
+        let response = conversations::set_topic(
+            client,
+            token,
+            &conversations::SetTopicRequest {
+                channel: &id,
+                topic,
+            },
+        )
+        .await
+        .map_err(EnsureChannelError::SetTopic)?;
+        channel = response.channel.unwrap_or(channel);
+    }
+    let purpose = channel.purpose.as_ref().and_then(|p| p.value.as_deref());
+    if let Some(purpose) = needs_update(purpose, request.purpose) {
+        let response = conversations::set_purpose(
+            client,
+            token,
+            &conversations::SetPurposeRequest {
+                channel: &id,
+                purpose,
+            },
+        )
+        .await
+        .map_err(EnsureChannelError::SetPurpose)?;
+        channel = response.channel.unwrap_or(channel);
+    }
+
+    let members = if request.members.is_empty() {
+        MembershipReport::default()
+    } else {
+        settle_members(conversations::invite_many(client, token, &id, request.members).await)
+    };
+
+    let mut bookmarks_added = 0;
+    if !request.bookmarks.is_empty() {
+        let existing = bookmarks::list(client, token, &bookmarks::ListRequest { channel_id: &id })
+            .await
+            .map_err(EnsureChannelError::ListBookmarks)?
+            .bookmarks
+            .unwrap_or_default();
+        for bookmark in missing_bookmarks(&existing, request.bookmarks) {
+            bookmarks::add(
+                client,
+                token,
+                &bookmarks::AddRequest {
+                    channel_id: &id,
+                    title: bookmark.title,
+                    r#type: "link",
+                    link: Some(bookmark.link),
+                    emoji: bookmark.emoji,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map_err(EnsureChannelError::AddBookmark)?;
+            bookmarks_added += 1;
+        }
+    }
+
+    Ok(EnsuredChannel {
+        channel,
+        created,
+        members,
+        bookmarks_added,
+    })
+}
+
+/// Finds the channel whose name is taken among the public and private channels the caller can
+/// see.
+async fn find_channel<R>(
+    client: &R,
+    token: &str,
+    request: &EnsureChannelRequest<'_>,
+) -> Result<Conversation, EnsureChannelError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut cursor = None;
+    loop {
+        let page = conversations::list(
+            client,
+            token,
+            &conversations::ListRequest {
+                cursor: cursor.as_ref(),
+                limit: Some(1000),
+                team_id: request.team_id,
+                types: Some(ConversationTypes::PUBLIC_CHANNEL | ConversationTypes::PRIVATE_CHANNEL),
+                ..Default::default()
+            },
+        )
+        .await
+        .map_err(EnsureChannelError::Lookup)?;
+        if let Some(channel) = find_by_name(page.channels, request.name) {
+            return Ok(channel);
+        }
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Err(EnsureChannelError::NotFound);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+    use crate::UserId;
+
+    /// A workspace where `#ops` exists with its topic already set and one bookmark.
+    fn existing_channel() -> MockSender {
+        MockSender::new()
+            .on(
+                "conversations.create",
+                r#"{"ok": false, "error": "name_taken"}"#,
+            )
+            .on(
+                "conversations.list",
+                r#"{"ok": true, "channels": [
+                    {"id": "C1", "name": "general"},
+                    {"id": "C2", "name": "ops", "topic": {"value": "Incidents"}}
+                ], "response_metadata": {"next_cursor": ""}}"#,
+            )
+            .on("conversations.setPurpose", r#"{"ok": true}"#)
+            .on(
+                "conversations.invite",
+                r#"{"ok": false, "error": "already_in_channel"}"#,
+            )
+            .on(
+                "bookmarks.list",
+                r#"{"ok": true, "bookmarks": [{"id": "Bk1", "link": "https://status.example.com"}]}"#,
+            )
+            .on("bookmarks.add", r#"{"ok": true}"#)
+    }
+
+    #[tokio::test]
+    async fn test_ensure_channel_updates_existing_channel() {
+        let sender = existing_channel();
+        let ensured = ensure_channel(
+            &sender,
+            "xoxp",
+            &EnsureChannelRequest {
+                name: "ops",
+                topic: Some("Incidents"),
+                purpose: Some("Keeping things running"),
+                members: &[UserId::new("U1")],
+                bookmarks: &[
+                    BookmarkLink {
+                        title: "Status",
+                        link: "https://status.example.com",
+                        emoji: None,
+                    },
+                    BookmarkLink {
+                        title: "Runbooks",
+                        link: "https://runbooks.example.com",
+                        emoji: Some(":books:"),
+                    },
+                ],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+        assert!(!ensured.created);
+        assert_eq!(ensured.channel.id.as_deref(), Some("C2"));
+        assert_eq!(ensured.members.succeeded, [UserId::new("U1")]);
+        assert!(ensured.members.is_complete());
+        assert_eq!(ensured.bookmarks_added, 1);
+        assert_eq!(
+            sender.methods(),
+            [
+                "conversations.create",
+                "conversations.list",
+                "conversations.setPurpose",
+                "conversations.invite",
+                "bookmarks.list",
+                "bookmarks.add",
+            ]
+        );
+    }
+}
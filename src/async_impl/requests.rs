@@ -1,15 +1,21 @@
 //! Functionality for sending requests to Slack.
 use async_trait::async_trait;
+use futures_timer::Delay;
+use futures_util::io::AsyncRead;
 
 use std::{borrow::Borrow, error};
 
+use crate::ext_types::files::UploadProgress;
+
 /// Functionality for sending authenticated and unauthenticated requests to Slack via HTTP.
 ///
 /// If you do not have a custom client to integrate with and just want to send requests, use
 /// the [`default_client()`] function to get a simple request sender.
+///
+/// Senders are `Sync`, so the futures of calls borrowing one can be sent between threads.
 #[async_trait]
-pub trait SlackWebRequestSender {
-    type Error: error::Error;
+pub trait SlackWebRequestSender: Sync {
+    type Error: error::Error + Send + Sync + 'static;
 
     /// Make an API call to Slack. Takes a map of parameters that get appended to the request as query
     /// params.
@@ -20,16 +26,132 @@ pub trait SlackWebRequestSender {
         V: AsRef<str>,
         I::Item: Borrow<(K, V)>,
         S: AsRef<str> + Send;
+
+    /// Make an API call like [`send()`](Self::send), also returning the HTTP status of the
+    /// response if the sender knows it. Unless overridden the status is `None`.
+    async fn send_with_status<I, K, V, S>(
+        &self,
+        method: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        self.send(method, params).await.map(|body| (None, body))
+    }
+
+    /// Make an API call like [`send_with_status()`](Self::send_with_status), reading the response
+    /// body into `body` in place of its contents, so one buffer can serve many calls. Unless
+    /// overridden the `String` from `send_with_status()` becomes the new `body`.
+    async fn send_into<I, K, V, S>(
+        &self,
+        method: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (status, response) = self.send_with_status(method, params).await?;
+        *body = response.into_bytes();
+        Ok(status)
+    }
+}
+
+/// Lets helpers wrap a borrowed sender, such as in a [`RateLimitRetry`].
+#[async_trait]
+impl<R> SlackWebRequestSender for &R
+where
+    R: SlackWebRequestSender + ?Sized,
+{
+    type Error = R::Error;
+
+    async fn send<I, K, V, S>(&self, method: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        (**self).send(method, params).await
+    }
+
+    async fn send_with_status<I, K, V, S>(
+        &self,
+        method: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        (**self).send_with_status(method, params).await
+    }
+
+    async fn send_into<I, K, V, S>(
+        &self,
+        method: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        (**self).send_into(method, params, body).await
+    }
+}
+
+/// A sender that can stream a request body, for uploading files and large messages.
+///
+/// Bodies are read and sent [`UPLOAD_CHUNK_SIZE`](crate::files::UPLOAD_CHUNK_SIZE) bytes at a
+/// time, so an upload doesn't hold the whole file in memory.
+#[async_trait]
+pub trait SlackWebUploadSender: SlackWebRequestSender {
+    /// POSTs the `length` bytes read from `body` to `url` as `content_type`, returning the response
+    /// body. `on_progress` is called each time a chunk of the body has been sent.
+    async fn upload<B>(
+        &self,
+        url: &str,
+        content_type: &str,
+        length: u64,
+        body: B,
+        on_progress: &(dyn Fn(UploadProgress) + Send + Sync),
+    ) -> Result<String, Self::Error>
+    where
+        B: AsyncRead + Send + Unpin;
 }
 
 #[cfg(feature = "reqwest")]
 mod reqwest_support {
     pub use self::reqwest::Error;
     use async_trait::async_trait;
+    use futures_channel::mpsc;
+    use futures_util::future::{join, poll_fn};
+    use futures_util::io::{AsyncRead, AsyncReadExt};
+    use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE};
     use reqwest_ as reqwest;
     use std::borrow::Borrow;
+    use std::io;
+    use std::time::Duration;
 
-    use super::SlackWebRequestSender;
+    use super::{SlackWebRequestSender, SlackWebUploadSender};
+    use crate::ext_types::files::{UploadProgress, UPLOAD_CHUNK_SIZE};
 
     type Client = reqwest::Client;
 
@@ -38,6 +160,40 @@ mod reqwest_support {
         type Error = reqwest::Error;
 
         async fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            let (_, body) = self.send_with_status(method_url, params).await?;
+            Ok(body)
+        }
+
+        async fn send_with_status<I, K, V, S>(
+            &self,
+            method_url: S,
+            params: I,
+        ) -> Result<(Option<u16>, String), Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            let mut body = Vec::new();
+            let status = self.send_into(method_url, params, &mut body).await?;
+            Ok((status, crate::response_buffer::into_string(body)))
+        }
+
+        async fn send_into<I, K, V, S>(
+            &self,
+            method_url: S,
+            params: I,
+            body: &mut Vec<u8>,
+        ) -> Result<Option<u16>, Self::Error>
         where
             I: IntoIterator + Send,
             K: AsRef<str>,
@@ -49,12 +205,102 @@ mod reqwest_support {
 
             url.query_pairs_mut().extend_pairs(params);
 
-            Ok(self.get(url).send().await?.text().await?)
+            let mut response = self.get(url).send().await?;
+            let status = Some(response.status().as_u16());
+            body.clear();
+            if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                let retry_after = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_owned);
+                let text = response.text().await?;
+                *body =
+                    crate::rate_limit::with_retry_after(text, retry_after.as_deref()).into_bytes();
+                return Ok(status);
+            }
+            while let Some(chunk) = response.chunk().await? {
+                body.extend_from_slice(&chunk);
+            }
+            Ok(status)
+        }
+    }
+
+    #[async_trait]
+    impl SlackWebUploadSender for Client {
+        async fn upload<B>(
+            &self,
+            url: &str,
+            content_type: &str,
+            length: u64,
+            mut body: B,
+            on_progress: &(dyn Fn(UploadProgress) + Send + Sync),
+        ) -> Result<String, Self::Error>
+        where
+            B: AsyncRead + Send + Unpin,
+        {
+            // Chunks are handed to the connection through a channel with room for one, so the
+            // next chunk is only read once the connection has taken the last.
+            let (mut chunks, receiver) = mpsc::channel::<io::Result<Vec<u8>>>(0);
+            let read = async move {
+                let mut buf = vec![0; UPLOAD_CHUNK_SIZE];
+                let mut sent = 0;
+                loop {
+                    let chunk = match body.read(&mut buf).await {
+                        Ok(0) => break,
+                        Ok(n) => Ok(buf[..n].to_vec()),
+                        Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                        Err(e) => Err(e),
+                    };
+                    let len = chunk.as_ref().map_or(0, Vec::len) as u64;
+                    let failed = chunk.is_err();
+                    // The channel closes if the request fails, which then returns the error.
+                    if poll_fn(|cx| chunks.poll_ready(cx)).await.is_err()
+                        || chunks.start_send(chunk).is_err()
+                        || failed
+                    {
+                        break;
+                    }
+                    sent += len;
+                    on_progress(UploadProgress {
+                        sent,
+                        total: length,
+                    });
+                }
+            };
+            let request = self
+                .post(url)
+                .header(CONTENT_TYPE, content_type)
+                .header(CONTENT_LENGTH, length)
+                .body(reqwest::Body::wrap_stream(receiver))
+                .send();
+            let (response, ()) = join(request, read).await;
+            let response = response?;
+            if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                let retry_after = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_owned);
+                let body = response.text().await?;
+                return Ok(crate::rate_limit::with_retry_after(
+                    body,
+                    retry_after.as_deref(),
+                ));
+            }
+            // Unlike API methods, upload URLs answer failures with an error status.
+            response.error_for_status()?.text().await
         }
     }
 
     /// Provides a default `reqwest` client to give to the API functions to send requests.
     ///
+    /// The client identifies itself as this crate in its `User-Agent` and asks for gzip or
+    /// brotli compressed responses, decompressing them. It keeps
+    /// connections alive and pools them, so reuse it (or clones of it, which share the pool)
+    /// rather than creating one per request. Use [`DefaultClientBuilder`] to tune
+    /// the pool.
+    ///
     /// # Examples
     ///
     /// ```
@@ -64,9 +310,1025 @@ mod reqwest_support {
     /// let response = slack_api::channels::list(&client, &token, &params);
     /// ```
     pub fn default_client() -> Result<Client, reqwest::Error> {
-        Ok(Client::new())
+        DefaultClientBuilder::new().build()
+    }
+
+    /// Configures the connection pool of the default `reqwest` client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let client = slack_api::requests::DefaultClientBuilder::new()
+    ///     .pool_max_idle_per_host(4)
+    ///     .timeout(Duration::from_secs(10))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct DefaultClientBuilder {
+        pool_max_idle_per_host: usize,
+        pool_idle_timeout: Option<Duration>,
+        tcp_keepalive: Option<Duration>,
+        timeout: Option<Duration>,
+        user_agent: String,
+    }
+
+    impl DefaultClientBuilder {
+        pub fn new() -> Self {
+            DefaultClientBuilder {
+                pool_max_idle_per_host: 32,
+                pool_idle_timeout: Some(Duration::from_secs(90)),
+                tcp_keepalive: Some(Duration::from_secs(60)),
+                timeout: None,
+                user_agent: crate::USER_AGENT.to_owned(),
+            }
+        }
+
+        /// The most idle connections kept open to Slack. Defaults to 32.
+        pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+            self.pool_max_idle_per_host = max;
+            self
+        }
+
+        /// How long an idle connection is kept before closing it, or `None` to keep it
+        /// indefinitely. Defaults to 90 seconds.
+        pub fn pool_idle_timeout<D: Into<Option<Duration>>>(mut self, timeout: D) -> Self {
+            self.pool_idle_timeout = timeout.into();
+            self
+        }
+
+        /// The interval of TCP keepalive probes on open connections, or `None` to disable them.
+        /// Defaults to 60 seconds.
+        pub fn tcp_keepalive<D: Into<Option<Duration>>>(mut self, interval: D) -> Self {
+            self.tcp_keepalive = interval.into();
+            self
+        }
+
+        /// A timeout for each whole request, from connecting until the body is read. Defaults to
+        /// `reqwest`'s own default.
+        pub fn timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = Some(timeout);
+            self
+        }
+
+        /// Identifies the app in the `User-Agent` header, after the crate's own name and version,
+        /// e.g. `"my-bot/1.2"`.
+        pub fn user_agent_suffix(mut self, suffix: &str) -> Self {
+            self.user_agent = format!("{} {}", crate::USER_AGENT, suffix);
+            self
+        }
+
+        pub fn build(self) -> Result<Client, reqwest::Error> {
+            let builder = Client::builder()
+                .user_agent(self.user_agent)
+                .gzip(true)
+                .brotli(true)
+                .pool_max_idle_per_host(self.pool_max_idle_per_host)
+                .pool_idle_timeout(self.pool_idle_timeout)
+                .tcp_keepalive(self.tcp_keepalive);
+            match self.timeout {
+                Some(timeout) => builder.timeout(timeout),
+                None => builder,
+            }
+            .build()
+        }
+    }
+
+    impl Default for DefaultClientBuilder {
+        fn default() -> Self {
+            DefaultClientBuilder::new()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        use std::collections::HashMap;
+        use std::io::{BufRead, BufReader, Read, Write};
+        use std::net::TcpListener;
+        use std::thread;
+
+        /// Answers a single request to the returned url, returning its headers by lowercase name.
+        fn serve_once() -> (String, thread::JoinHandle<HashMap<String, String>>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}/api/api.test", listener.local_addr().unwrap());
+            let server = thread::spawn(move || {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut headers = HashMap::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(colon) = line.find(':') {
+                        headers.insert(
+                            line[..colon].to_ascii_lowercase(),
+                            line[colon + 1..].trim().to_owned(),
+                        );
+                    }
+                }
+                let body = r#"{"ok": true}"#;
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                    body.len(),
+                    body
+                )
+                .unwrap();
+                headers
+            });
+            (url, server)
+        }
+
+        /// The headers of an upload by lowercase name, and its body.
+        type Upload = (HashMap<String, String>, Vec<u8>);
+
+        /// Answers a single upload to the returned url, returning what it received.
+        fn serve_upload() -> (String, thread::JoinHandle<Upload>) {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}/upload/v1/abc", listener.local_addr().unwrap());
+            let server = thread::spawn(move || {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut headers = HashMap::new();
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    if let Some(colon) = line.find(':') {
+                        headers.insert(
+                            line[..colon].to_ascii_lowercase(),
+                            line[colon + 1..].trim().to_owned(),
+                        );
+                    }
+                }
+                let mut body = vec![0; headers["content-length"].parse().unwrap()];
+                reader.read_exact(&mut body).unwrap();
+                let response = "OK - 200000";
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\n\r\n{}",
+                    response.len(),
+                    response
+                )
+                .unwrap();
+                (headers, body)
+            });
+            (url, server)
+        }
+
+        #[tokio::test]
+        async fn test_upload_streams_body_in_chunks() {
+            let (url, server) = serve_upload();
+            let client = default_client().unwrap();
+            let file: Vec<u8> = (0..200_000).map(|i| i as u8).collect();
+            let progress = std::sync::Mutex::new(Vec::new());
+            let response = client
+                .upload(&url, "image/png", 200_000, &file[..], &|p| {
+                    progress.lock().unwrap().push(p.sent)
+                })
+                .await
+                .unwrap();
+            assert_eq!(response, "OK - 200000");
+            let (headers, body) = server.join().unwrap();
+            assert_eq!(headers["content-type"], "image/png");
+            assert_eq!(headers["content-length"], "200000");
+            assert!(body == file);
+            assert_eq!(
+                *progress.lock().unwrap(),
+                [65_536, 131_072, 196_608, 200_000]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_default_client_accepts_compressed_responses() {
+            let (url, server) = serve_once();
+            let client = default_client().unwrap();
+            let body = client.send(&url, &[("token", "xoxb")][..]).await.unwrap();
+            assert_eq!(body, r#"{"ok": true}"#);
+            let accept_encoding = &server.join().unwrap()["accept-encoding"];
+            assert!(accept_encoding.contains("gzip"), "{}", accept_encoding);
+            assert!(accept_encoding.contains("br"), "{}", accept_encoding);
+        }
+
+        #[tokio::test]
+        async fn test_default_client_user_agent() {
+            let (url, server) = serve_once();
+            let client = DefaultClientBuilder::new()
+                .user_agent_suffix("my-bot/1.2")
+                .build()
+                .unwrap();
+            client.send(&url, &[("token", "xoxb")][..]).await.unwrap();
+            assert_eq!(
+                server.join().unwrap()["user-agent"],
+                format!("slack_api/{} my-bot/1.2", env!("CARGO_PKG_VERSION"))
+            );
+        }
     }
 }
 
 #[cfg(feature = "reqwest")]
 pub use self::reqwest_support::*;
+
+mod bounded {
+    use async_trait::async_trait;
+    use futures_util::future::{self, Either};
+    use futures_util::pin_mut;
+
+    use std::borrow::Borrow;
+    use std::error::Error;
+    use std::fmt;
+    use std::future::Future;
+    use std::time::{Duration, Instant};
+
+    use super::SlackWebRequestSender;
+    use crate::CancelToken;
+
+    /// Wraps a sender to bound the time spent on Slack calls and to abandon them on request.
+    ///
+    /// A timeout bounds each call on its own, while a deadline is shared by every call made
+    /// through the wrapper, so it bounds a whole handler's worth of calls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # async fn handle(cancel: slack_api::CancelToken) {
+    /// use slack_api::requests::{default_client, Bounded};
+    /// use std::time::{Duration, Instant};
+    ///
+    /// let sender = Bounded::new(default_client().unwrap())
+    ///     .timeout(Duration::from_secs(5))
+    ///     .deadline(Instant::now() + Duration::from_secs(30))
+    ///     .cancel_on(cancel);
+    /// let response = slack_api::api::test(&sender, &Default::default()).await;
+    /// # }
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct Bounded<R> {
+        sender: R,
+        deadline: Option<Instant>,
+        timeout: Option<Duration>,
+        cancel: Option<CancelToken>,
+    }
+
+    impl<R> Bounded<R> {
+        pub fn new(sender: R) -> Self {
+            Bounded {
+                sender,
+                deadline: None,
+                timeout: None,
+                cancel: None,
+            }
+        }
+
+        /// Fails calls that haven't finished by `deadline`.
+        pub fn deadline(mut self, deadline: Instant) -> Self {
+            self.deadline = Some(deadline);
+            self
+        }
+
+        /// Fails each call that hasn't finished within `timeout` of being sent.
+        pub fn timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = Some(timeout);
+            self
+        }
+
+        /// Abandons calls once `token` is cancelled.
+        pub fn cancel_on(mut self, token: CancelToken) -> Self {
+            self.cancel = Some(token);
+            self
+        }
+
+        pub fn into_inner(self) -> R {
+            self.sender
+        }
+
+        /// Runs a call to the wrapped sender unless the deadline, its timeout or the cancel token
+        /// ends it first.
+        async fn bound<T, F>(&self, send: F) -> Result<T, BoundedError<R::Error>>
+        where
+            R: SlackWebRequestSender,
+            F: Future<Output = Result<T, R::Error>>,
+        {
+            if self.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+                return Err(BoundedError::Cancelled);
+            }
+            let remaining = match self.deadline {
+                Some(deadline) => match deadline.checked_duration_since(Instant::now()) {
+                    Some(remaining) => Some(remaining),
+                    None => return Err(BoundedError::DeadlineExceeded),
+                },
+                None => None,
+            };
+            let remaining = match (remaining, self.timeout) {
+                (Some(remaining), Some(timeout)) => Some(remaining.min(timeout)),
+                (remaining, timeout) => remaining.or(timeout),
+            };
+
+            let expired = async {
+                match remaining {
+                    Some(remaining) => futures_timer::Delay::new(remaining).await,
+                    None => future::pending().await,
+                }
+            };
+            let cancelled = async {
+                match self.cancel {
+                    Some(ref token) => token.cancelled().await,
+                    None => future::pending().await,
+                }
+            };
+            pin_mut!(send, expired, cancelled);
+            match future::select(send, future::select(expired, cancelled)).await {
+                Either::Left((result, _)) => result.map_err(BoundedError::Sender),
+                Either::Right((Either::Left(_), _)) => Err(BoundedError::DeadlineExceeded),
+                Either::Right((Either::Right(_), _)) => Err(BoundedError::Cancelled),
+            }
+        }
+    }
+
+    #[async_trait]
+    impl<R> SlackWebRequestSender for Bounded<R>
+    where
+        R: SlackWebRequestSender + Send + Sync,
+        R::Error: 'static,
+    {
+        type Error = BoundedError<R::Error>;
+
+        async fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            let (_, body) = self.send_with_status(method_url, params).await?;
+            Ok(body)
+        }
+
+        async fn send_with_status<I, K, V, S>(
+            &self,
+            method_url: S,
+            params: I,
+        ) -> Result<(Option<u16>, String), Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            self.bound(self.sender.send_with_status(method_url, params))
+                .await
+        }
+
+        async fn send_into<I, K, V, S>(
+            &self,
+            method_url: S,
+            params: I,
+            body: &mut Vec<u8>,
+        ) -> Result<Option<u16>, Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            self.bound(self.sender.send_into(method_url, params, body))
+                .await
+        }
+    }
+
+    #[derive(Debug)]
+    pub enum BoundedError<E> {
+        /// The deadline or the call's timeout passed before the call finished
+        DeadlineExceeded,
+        /// The call was abandoned through its `CancelToken`
+        Cancelled,
+        /// The wrapped sender failed
+        Sender(E),
+    }
+
+    impl<E: Error> fmt::Display for BoundedError<E> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match *self {
+                BoundedError::DeadlineExceeded => write!(f, "deadline exceeded"),
+                BoundedError::Cancelled => write!(f, "cancelled"),
+                BoundedError::Sender(ref inner) => write!(f, "{}", inner),
+            }
+        }
+    }
+
+    impl<E: Error + 'static> Error for BoundedError<E> {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match *self {
+                BoundedError::Sender(ref inner) => Some(inner),
+                _ => None,
+            }
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        /// Answers after the given time.
+        struct Slow(Duration);
+
+        #[async_trait]
+        impl SlackWebRequestSender for Slow {
+            type Error = std::io::Error;
+
+            async fn send<I, K, V, S>(&self, _: S, _: I) -> Result<String, Self::Error>
+            where
+                I: IntoIterator + Send,
+                K: AsRef<str>,
+                V: AsRef<str>,
+                I::Item: Borrow<(K, V)>,
+                S: AsRef<str> + Send,
+            {
+                futures_timer::Delay::new(self.0).await;
+                Ok(r#"{"ok": true}"#.into())
+            }
+        }
+
+        #[tokio::test]
+        async fn test_bounded_times_out() {
+            let sender =
+                Bounded::new(Slow(Duration::from_secs(1))).timeout(Duration::from_millis(10));
+            match sender.send("api.test", &[("token", "xoxb")][..]).await {
+                Err(BoundedError::DeadlineExceeded) => {}
+                other => panic!("expected the deadline to pass but got {:?}", other),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_bounded_times_each_call() {
+            let sender =
+                Bounded::new(Slow(Duration::from_millis(40))).timeout(Duration::from_millis(200));
+            for _ in 0..10 {
+                sender
+                    .send("api.test", &[("token", "xoxb")][..])
+                    .await
+                    .unwrap();
+            }
+
+            let sender = sender.deadline(Instant::now() + Duration::from_millis(70));
+            sender
+                .send("api.test", &[("token", "xoxb")][..])
+                .await
+                .unwrap();
+            match sender.send("api.test", &[("token", "xoxb")][..]).await {
+                Err(BoundedError::DeadlineExceeded) => {}
+                other => panic!("expected the deadline to pass but got {:?}", other),
+            }
+        }
+
+        #[tokio::test]
+        async fn test_bounded_cancels_call_in_progress() {
+            let token = CancelToken::new();
+            let sender = Bounded::new(Slow(Duration::from_secs(1))).cancel_on(token.clone());
+            let cancel = async {
+                futures_timer::Delay::new(Duration::from_millis(10)).await;
+                token.cancel();
+            };
+            let (result, ()) =
+                futures_util::join!(sender.send("api.test", &[("token", "xoxb")][..]), cancel);
+            match result {
+                Err(BoundedError::Cancelled) => {}
+                other => panic!("expected the call to be cancelled but got {:?}", other),
+            }
+        }
+    }
+}
+
+pub use self::bounded::*;
+
+pub use crate::circuit_breaker::{CircuitBreaker, CircuitError, CircuitState};
+
+#[async_trait]
+impl<R> SlackWebRequestSender for CircuitBreaker<R>
+where
+    R: SlackWebRequestSender + Send + Sync,
+    R::Error: 'static,
+{
+    type Error = CircuitError<R::Error>;
+
+    async fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (_, body) = self.send_with_status(method_url, params).await?;
+        Ok(body)
+    }
+
+    async fn send_with_status<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let permit = self.permit().ok_or(CircuitError::Open)?;
+        let result = self.sender.send_with_status(method_url, params).await;
+        permit.record(&result);
+        result.map_err(CircuitError::Sender)
+    }
+
+    async fn send_into<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let permit = self.permit().ok_or(CircuitError::Open)?;
+        let result = self.sender.send_into(method_url, params, body).await;
+        permit.record(&result.as_ref().map(|status| (*status, ())));
+        result.map_err(CircuitError::Sender)
+    }
+}
+
+pub use crate::rate_limit::{RateLimitRetry, RateLimitStats, RetryPolicy};
+
+#[async_trait]
+impl<R> SlackWebRequestSender for RateLimitRetry<R>
+where
+    R: SlackWebRequestSender + Send + Sync,
+{
+    type Error = R::Error;
+
+    async fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (_, body) = self.send_with_status(method_url, params).await?;
+        Ok(body)
+    }
+
+    async fn send_with_status<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let params = crate::rate_limit::owned_params(params);
+        let mut stats = RateLimitStats::new(method_url.as_ref());
+        loop {
+            let (status, body) = match self
+                .sender
+                .send_with_status(method_url.as_ref(), &params)
+                .await
+            {
+                Ok(response) => response,
+                Err(e) => {
+                    self.report(&stats);
+                    return Err(e);
+                }
+            };
+            match self.next_wait(&mut stats, &body) {
+                Some(wait) => Delay::new(wait).await,
+                None => return Ok((status, body)),
+            }
+        }
+    }
+
+    async fn send_into<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let params = crate::rate_limit::owned_params(params);
+        let mut stats = RateLimitStats::new(method_url.as_ref());
+        loop {
+            let status = match self
+                .sender
+                .send_into(method_url.as_ref(), &params, body)
+                .await
+            {
+                Ok(status) => status,
+                Err(e) => {
+                    self.report(&stats);
+                    return Err(e);
+                }
+            };
+            let wait = self.next_wait(&mut stats, &String::from_utf8_lossy(body));
+            match wait {
+                Some(wait) => Delay::new(wait).await,
+                None => return Ok(status),
+            }
+        }
+    }
+}
+
+/// Uploads aren't retried, since their body can't be read again.
+#[async_trait]
+impl<R> SlackWebUploadSender for RateLimitRetry<R>
+where
+    R: SlackWebUploadSender + Send + Sync,
+{
+    async fn upload<B>(
+        &self,
+        url: &str,
+        content_type: &str,
+        length: u64,
+        body: B,
+        on_progress: &(dyn Fn(UploadProgress) + Send + Sync),
+    ) -> Result<String, Self::Error>
+    where
+        B: AsyncRead + Send + Unpin,
+    {
+        self.sender
+            .upload(url, content_type, length, body, on_progress)
+            .await
+    }
+}
+
+pub use crate::lossy::{DeserializeIssue, Lossy};
+
+#[async_trait]
+impl<R> SlackWebRequestSender for Lossy<R>
+where
+    R: SlackWebRequestSender + Send + Sync,
+{
+    type Error = R::Error;
+
+    async fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (_, body) = self.send_with_status(method_url, params).await?;
+        Ok(body)
+    }
+
+    async fn send_with_status<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (status, body) = self
+            .sender
+            .send_with_status(method_url.as_ref(), params)
+            .await?;
+        Ok((status, self.skip_invalid(method_url.as_ref(), body)))
+    }
+}
+
+#[cfg(feature = "log")]
+pub use crate::logged::Logged;
+
+#[cfg(feature = "log")]
+#[async_trait]
+impl<R> SlackWebRequestSender for Logged<R>
+where
+    R: SlackWebRequestSender + Send + Sync,
+{
+    type Error = R::Error;
+
+    async fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (_, body) = self.send_with_status(method_url, params).await?;
+        Ok(body)
+    }
+
+    async fn send_with_status<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let params = self.log_request(method_url.as_ref(), params);
+        let result = self.sender.send_with_status(method_url, &params).await;
+        self.log_response(&result);
+        result
+    }
+
+    async fn send_into<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let params = self.log_request(method_url.as_ref(), params);
+        let result = self.sender.send_into(method_url, &params, body).await;
+        self.log_response(&result.as_ref().map(|status| (*status, &body[..])));
+        result
+    }
+}
+
+#[cfg(feature = "tower")]
+mod tower_support {
+    use async_trait::async_trait;
+    use tower_service::Service;
+
+    use std::borrow::Borrow;
+    use std::error::Error;
+    use std::fmt;
+    use std::future::{poll_fn, Future};
+    use std::pin::Pin;
+    use std::task::{Context, Poll};
+
+    use super::SlackWebRequestSender;
+
+    /// A request to a Slack Web API method, as passed through a `tower::Service`.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct SlackRequest {
+        /// The full url of the method being called.
+        pub method_url: String,
+        pub params: Vec<(String, String)>,
+    }
+
+    /// Exposes a [`SlackWebRequestSender`] as a `tower::Service`, so that tower middleware can be
+    /// layered around it.
+    ///
+    /// Wrap the resulting service stack in a [`ServiceSender`] to use it with the API functions.
+    #[derive(Clone, Debug)]
+    pub struct SenderService<R> {
+        sender: R,
+    }
+
+    impl<R> SenderService<R> {
+        pub fn new(sender: R) -> Self {
+            SenderService { sender }
+        }
+
+        pub fn into_inner(self) -> R {
+            self.sender
+        }
+    }
+
+    impl<R> Service<SlackRequest> for SenderService<R>
+    where
+        R: SlackWebRequestSender + Clone + Send + Sync + 'static,
+    {
+        type Response = String;
+        type Error = R::Error;
+        type Future = Pin<Box<dyn Future<Output = Result<String, R::Error>> + Send>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: SlackRequest) -> Self::Future {
+            let sender = self.sender.clone();
+            Box::pin(async move { sender.send(&request.method_url, &request.params).await })
+        }
+    }
+
+    /// Sends requests through a `tower::Service`, such as a [`SenderService`] wrapped in
+    /// middleware.
+    #[derive(Clone, Debug)]
+    pub struct ServiceSender<S> {
+        service: S,
+    }
+
+    impl<S> ServiceSender<S> {
+        pub fn new(service: S) -> Self {
+            ServiceSender { service }
+        }
+
+        pub fn into_inner(self) -> S {
+            self.service
+        }
+    }
+
+    #[async_trait]
+    impl<S> SlackWebRequestSender for ServiceSender<S>
+    where
+        S: Service<SlackRequest, Response = String> + Clone + Send + Sync,
+        S::Error: Into<Box<dyn Error + Send + Sync>>,
+        S::Future: Send,
+    {
+        type Error = ServiceError;
+
+        async fn send<I, K, V, T>(&self, method_url: T, params: I) -> Result<String, Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            T: AsRef<str> + Send,
+        {
+            let request = SlackRequest {
+                method_url: method_url.as_ref().to_owned(),
+                params: params
+                    .into_iter()
+                    .map(|p| {
+                        let (k, v) = p.borrow();
+                        (k.as_ref().to_owned(), v.as_ref().to_owned())
+                    })
+                    .collect(),
+            };
+            let mut service = self.service.clone();
+            poll_fn(|cx| service.poll_ready(cx))
+                .await
+                .map_err(|e| ServiceError(e.into()))?;
+            service
+                .call(request)
+                .await
+                .map_err(|e| ServiceError(e.into()))
+        }
+    }
+
+    /// An error returned by a service sending requests for a [`ServiceSender`].
+    #[derive(Debug)]
+    pub struct ServiceError(pub Box<dyn Error + Send + Sync>);
+
+    impl fmt::Display for ServiceError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{}", self.0)
+        }
+    }
+
+    impl Error for ServiceError {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            self.0.source()
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[derive(Clone)]
+        struct Echo;
+
+        #[async_trait]
+        impl SlackWebRequestSender for Echo {
+            type Error = std::io::Error;
+
+            async fn send<I, K, V, T>(&self, _method: T, params: I) -> Result<String, Self::Error>
+            where
+                I: IntoIterator + Send,
+                K: AsRef<str>,
+                V: AsRef<str>,
+                I::Item: Borrow<(K, V)>,
+                T: AsRef<str> + Send,
+            {
+                let args = params
+                    .into_iter()
+                    .map(|p| {
+                        let (k, v) = p.borrow();
+                        (k.as_ref().to_owned(), v.as_ref().to_owned())
+                    })
+                    .collect::<std::collections::HashMap<_, _>>();
+                Ok(serde_json::json!({ "ok": true, "args": args }).to_string())
+            }
+        }
+
+        #[tokio::test]
+        async fn test_round_trip_through_service() {
+            let sender = ServiceSender::new(SenderService::new(Echo));
+            let response = crate::api::test(
+                &sender,
+                &crate::api::TestRequest {
+                    foo: Some("bar"),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+            assert_eq!(
+                response.args.unwrap().get("foo").map(String::as_str),
+                Some("bar")
+            );
+        }
+    }
+}
+
+#[cfg(feature = "tower")]
+pub use self::tower_support::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    use crate::async_impl::test_support::MockSender;
+
+    const URL: &str = "https://slack.com/api/api.test";
+
+    /// Sends two calls through a breaker around `wrap(sender)`, one with `send` and one with
+    /// `send_into`, where `sender` answers with a 503, and checks that the breaker opened.
+    async fn assert_opens<R, F>(wrap: F)
+    where
+        R: SlackWebRequestSender + Send + Sync,
+        F: FnOnce(MockSender) -> R,
+    {
+        let sender =
+            MockSender::new().on_status("api.test", 503, "<html>Service Unavailable</html>");
+        let breaker = CircuitBreaker::new(wrap(sender.clone())).failure_threshold(2);
+        let params = [("token", "xoxb")];
+        breaker.send(URL, &params[..]).await.unwrap();
+        let mut body = Vec::new();
+        breaker
+            .send_into(URL, &params[..], &mut body)
+            .await
+            .unwrap();
+        assert_eq!(body, b"<html>Service Unavailable</html>");
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(matches!(
+            breaker.send(URL, &params[..]).await,
+            Err(CircuitError::Open)
+        ));
+        assert_eq!(sender.calls().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_circuit_breaker_sees_server_errors_through_wrappers() {
+        assert_opens(|sender| sender).await;
+        assert_opens(RateLimitRetry::new).await;
+        assert_opens(|sender| RateLimitRetry::new(Bounded::new(sender))).await;
+        assert_opens(|sender| Lossy::new(sender, |_| {})).await;
+        #[cfg(feature = "log")]
+        assert_opens(Logged::new).await;
+    }
+
+    #[tokio::test]
+    async fn test_generated_functions_read_through_wrappers() {
+        let limited = AtomicBool::new(false);
+        let sender = MockSender::new().on_call("auth.test", move |_| {
+            if limited.swap(true, Ordering::SeqCst) {
+                r#"{"ok": true, "user_id": "U1", "team_id": "T1"}"#
+            } else {
+                r#"{"ok": false, "error": "ratelimited", "retry_after": 0}"#
+            }
+        });
+        let client = CircuitBreaker::new(RateLimitRetry::new(Bounded::new(sender.clone())));
+        let response = crate::auth::test(&client, "xoxb").await.unwrap();
+        assert_eq!(response.user_id.as_deref(), Some("U1"));
+        assert_eq!(sender.calls().len(), 2);
+    }
+}
@@ -0,0 +1,188 @@
+//! Schedules messages for a time on a user's clock, and lists or cancels a channel's scheduled
+//! messages.
+
+pub use crate::scheduler_types::*;
+
+use super::requests::SlackWebRequestSender;
+use crate::mod_types::chat_scheduled_messages_types::{ListError, ListRequest};
+use crate::mod_types::chat_types::{
+    DeleteScheduledMessageRequest, ScheduleMessageRequest, ScheduleMessageResponse,
+};
+use crate::mod_types::users_types::InfoRequest;
+use crate::{chat, chat_scheduled_messages, users, ScheduledMessage};
+
+/// Schedules a message for `at` on `user`'s clock, looking up their timezone with `users.info`.
+///
+/// The `post_at` of `request` is replaced. If its `channel` is empty, the message is sent to the
+/// user as a direct message from the app.
+pub async fn schedule_in_user_tz<R>(
+    client: &R,
+    token: &str,
+    user: &str,
+    at: LocalDateTime,
+    request: &ScheduleMessageRequest<'_>,
+) -> Result<ScheduleMessageResponse, ScheduleInUserTzError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let info = users::info(
+        client,
+        token,
+        &InfoRequest {
+            user,
+            include_locale: None,
+        },
+    )
+    .await
+    .map_err(ScheduleInUserTzError::UserInfo)?;
+    let post_at = match info.user {
+        Some(ref user) => at.post_at_for(user),
+        None => Err(PostAtError::NoTimezone),
+    }
+    .map_err(ScheduleInUserTzError::PostAt)?;
+    let channel = if request.channel.is_empty() {
+        user
+    } else {
+        request.channel
+    };
+    chat::schedule_message(
+        client,
+        token,
+        &ScheduleMessageRequest {
+            channel,
+            post_at,
+            ..request.clone()
+        },
+    )
+    .await
+    .map_err(ScheduleInUserTzError::Schedule)
+}
+
+/// Lists every message scheduled in `channel`, following the cursor through each page.
+pub async fn list_by_channel<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<Vec<ScheduledMessage>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut messages = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = chat_scheduled_messages::list(
+            client,
+            token,
+            &ListRequest {
+                channel: Some(channel),
+                cursor: cursor.as_ref(),
+                ..ListRequest::default()
+            },
+        )
+        .await?;
+        messages.extend(page.scheduled_messages.into_iter().flatten());
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(messages);
+        }
+    }
+}
+
+/// Deletes every message scheduled in `channel`, carrying on past messages which can't be
+/// deleted.
+pub async fn cancel_by_channel<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<CancelReport<R::Error>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut report = CancelReport::default();
+    for message in list_by_channel(client, token, channel).await? {
+        let id = match message.id {
+            Some(id) => id,
+            None => continue,
+        };
+        let result = chat::delete_scheduled_message(
+            client,
+            token,
+            &DeleteScheduledMessageRequest {
+                channel,
+                scheduled_message_id: &id,
+                as_user: None,
+            },
+        )
+        .await;
+        match result {
+            Ok(_) => report.cancelled.push(id),
+            Err(e) => report.failed.push((id, e)),
+        }
+    }
+    Ok(report)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+    use crate::mod_types::chat_types::DeleteScheduledMessageError;
+
+    /// `U1` lives at UTC-5 and channel `C1` has three scheduled messages over two pages, the
+    /// second of which has already been posted.
+    fn scheduler() -> MockSender {
+        MockSender::new()
+            .on(
+                "users.info",
+                r#"{"ok": true, "user": {"id": "U1", "tz_offset": -18000}}"#,
+            )
+            .on(
+                "chat.scheduleMessage",
+                r#"{"ok": true, "scheduled_message_id": "Q1"}"#,
+            )
+            .on_call("chat.scheduledMessages.list", |call| {
+                if call.param("cursor").is_empty() {
+                    r#"{"ok": true, "scheduled_messages": [{"id": "Q1"}, {"id": "Q2"}],
+                        "response_metadata": {"next_cursor": "2"}}"#
+                } else {
+                    r#"{"ok": true, "scheduled_messages": [{"id": "Q3"}],
+                        "response_metadata": {"next_cursor": ""}}"#
+                }
+            })
+            .on_call("chat.deleteScheduledMessage", |call| {
+                if call.param("scheduled_message_id") == "Q2" {
+                    r#"{"ok": false, "error": "invalid_scheduled_message_id"}"#
+                } else {
+                    r#"{"ok": true}"#
+                }
+            })
+    }
+
+    #[tokio::test]
+    async fn test_schedule_in_user_tz() {
+        let client = scheduler();
+        let request = ScheduleMessageRequest {
+            text: Some("Stand-up in 5 minutes"),
+            ..ScheduleMessageRequest::default()
+        };
+        let at = LocalDateTime::new(2024, 3, 10, 9, 0);
+        let response = schedule_in_user_tz(&client, "xoxb", "U1", at, &request)
+            .await
+            .unwrap();
+        assert_eq!(response.scheduled_message_id.as_deref(), Some("Q1"));
+        let scheduled = &client.calls_to("chat.scheduleMessage")[0];
+        assert_eq!(scheduled.param("channel"), "U1");
+        assert_eq!(scheduled.param("post_at"), "1710079200");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_by_channel() {
+        let report = cancel_by_channel(&scheduler(), "xoxb", "C1").await.unwrap();
+        assert_eq!(report.cancelled, ["Q1", "Q3"]);
+        assert!(matches!(
+            report.failed[..],
+            [(ref id, DeleteScheduledMessageError::InvalidScheduledMessageId)] if id == "Q2"
+        ));
+    }
+}
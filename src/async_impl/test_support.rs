@@ -0,0 +1,203 @@
+//! A scripted request sender for the tests of the async helpers.
+use async_trait::async_trait;
+use futures_util::io::{AsyncRead, AsyncReadExt};
+
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use super::requests::{SlackWebRequestSender, SlackWebUploadSender};
+use crate::ext_types::files::UploadProgress;
+
+/// A call made through a [`MockSender`].
+///
+/// Uploads are recorded as calls to `upload`, with `url`, `content_type` and `body` parameters.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Call {
+    pub method: String,
+    pub params: Vec<(String, String)>,
+}
+
+impl Call {
+    /// The value of the parameter `name`, or `""` if it wasn't sent.
+    pub fn param(&self, name: &str) -> &str {
+        self.params
+            .iter()
+            .find(|(k, _)| k == name)
+            .map_or("", |(_, v)| v)
+    }
+}
+
+type Respond = Box<dyn Fn(&Call) -> (Option<u16>, String) + Send + Sync>;
+type Delay = Box<dyn Fn(&Call) -> Duration + Send + Sync>;
+
+#[derive(Default)]
+struct Script {
+    responses: HashMap<String, Respond>,
+    delay: Option<Delay>,
+    calls: Mutex<Vec<Call>>,
+}
+
+/// A sender answering each method with a scripted response and recording every call as it's
+/// answered, by method name without the URL. A call to a method without a response panics.
+///
+/// Clones share their script and calls, so a clone can be handed to a client and inspected
+/// through the original.
+#[derive(Clone, Default)]
+pub(crate) struct MockSender {
+    script: Arc<Script>,
+}
+
+impl MockSender {
+    pub fn new() -> Self {
+        MockSender::default()
+    }
+
+    /// Answers calls to `method` with `body`.
+    pub fn on(self, method: &str, body: &str) -> Self {
+        let body = body.to_owned();
+        self.respond(method, move |_| (None, body.clone()))
+    }
+
+    /// Answers calls to `method` with the body `respond` returns for the call.
+    pub fn on_call<F, B>(self, method: &str, respond: F) -> Self
+    where
+        F: Fn(&Call) -> B + Send + Sync + 'static,
+        B: ToString,
+    {
+        self.respond(method, move |call| (None, respond(call).to_string()))
+    }
+
+    /// Answers calls to `method` with `body` and the HTTP status `status`.
+    pub fn on_status(self, method: &str, status: u16, body: &str) -> Self {
+        let body = body.to_owned();
+        self.respond(method, move |_| (Some(status), body.clone()))
+    }
+
+    /// Waits as long as `delay` returns before answering each call.
+    pub fn delay<F>(mut self, delay: F) -> Self
+    where
+        F: Fn(&Call) -> Duration + Send + Sync + 'static,
+    {
+        self.script_mut().delay = Some(Box::new(delay));
+        self
+    }
+
+    /// Every call answered so far, in the order they were answered.
+    pub fn calls(&self) -> Vec<Call> {
+        self.script.calls.lock().unwrap().clone()
+    }
+
+    /// The methods of every call answered so far.
+    pub fn methods(&self) -> Vec<String> {
+        self.calls().into_iter().map(|call| call.method).collect()
+    }
+
+    /// The calls to `method` answered so far.
+    pub fn calls_to(&self, method: &str) -> Vec<Call> {
+        let mut calls = self.calls();
+        calls.retain(|call| call.method == method);
+        calls
+    }
+
+    fn respond<F>(mut self, method: &str, respond: F) -> Self
+    where
+        F: Fn(&Call) -> (Option<u16>, String) + Send + Sync + 'static,
+    {
+        self.script_mut()
+            .responses
+            .insert(method.to_owned(), Box::new(respond));
+        self
+    }
+
+    fn script_mut(&mut self) -> &mut Script {
+        Arc::get_mut(&mut self.script).expect("scripted before being cloned")
+    }
+
+    async fn answer(&self, call: Call) -> (Option<u16>, String) {
+        let respond = self
+            .script
+            .responses
+            .get(&call.method)
+            .unwrap_or_else(|| panic!("unexpected call to {}", call.method));
+        if let Some(ref delay) = self.script.delay {
+            futures_timer::Delay::new(delay(&call)).await;
+        }
+        let response = respond(&call);
+        self.script.calls.lock().unwrap().push(call);
+        response
+    }
+}
+
+#[async_trait]
+impl SlackWebRequestSender for MockSender {
+    type Error = std::io::Error;
+
+    async fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (_, body) = self.send_with_status(method_url, params).await?;
+        Ok(body)
+    }
+
+    async fn send_with_status<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let method_url = method_url.as_ref();
+        let call = Call {
+            method: method_url
+                .rsplit('/')
+                .next()
+                .unwrap_or(method_url)
+                .to_owned(),
+            params: crate::rate_limit::owned_params(params),
+        };
+        Ok(self.answer(call).await)
+    }
+}
+
+#[async_trait]
+impl SlackWebUploadSender for MockSender {
+    async fn upload<B>(
+        &self,
+        url: &str,
+        content_type: &str,
+        length: u64,
+        mut body: B,
+        on_progress: &(dyn Fn(UploadProgress) + Send + Sync),
+    ) -> Result<String, Self::Error>
+    where
+        B: AsyncRead + Send + Unpin,
+    {
+        let mut sent = String::new();
+        body.read_to_string(&mut sent).await?;
+        on_progress(UploadProgress {
+            sent: sent.len() as u64,
+            total: length,
+        });
+        let call = Call {
+            method: "upload".to_owned(),
+            params: vec![
+                ("url".to_owned(), url.to_owned()),
+                ("content_type".to_owned(), content_type.to_owned()),
+                ("body".to_owned(), sent),
+            ],
+        };
+        Ok(self.answer(call).await.1)
+    }
+}
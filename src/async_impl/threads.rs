@@ -0,0 +1,85 @@
+//! Fetching a whole thread at once, such as for bots which summarize threads.
+
+pub use crate::threads_types::*;
+
+use super::conversations;
+use super::requests::SlackWebRequestSender;
+use crate::Timestamp;
+
+/// Fetches the thread started by the message at `thread_ts`, with every page of its replies, the
+/// users who took part and the reactions to all of its messages.
+pub async fn fetch<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    thread_ts: Timestamp,
+) -> Result<Thread, conversations::RepliesError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut cursor = None;
+    let mut messages = Vec::new();
+    loop {
+        let page = conversations::replies(
+            client,
+            token,
+            &conversations::RepliesRequest {
+                channel,
+                ts: thread_ts,
+                cursor: cursor.as_ref(),
+                limit: Some(200),
+                ..Default::default()
+            },
+        )
+        .await?;
+        messages.extend(page.messages.unwrap_or_default());
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(Thread::from_messages(channel, thread_ts, messages));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::async_impl::test_support::MockSender;
+
+    /// Serves a thread over two pages, repeating the parent on each.
+    fn replies() -> MockSender {
+        MockSender::new().on_call("conversations.replies", |call| {
+            assert_eq!(call.param("ts"), "1.000000");
+            let parent = serde_json::json!({"type": "message", "user": "U1", "text": "lunch?",
+                "ts": "1.000000", "thread_ts": "1.000000",
+                "reactions": [{"name": "taco", "count": 1, "users": ["U2"]}]});
+            match call.param("cursor") {
+                "" => serde_json::json!({"ok": true, "has_more": true, "messages": [
+                    parent,
+                    {"type": "message", "user": "U2", "text": "yes", "ts": "2.000000",
+                     "thread_ts": "1.000000",
+                     "reactions": [{"name": "taco", "count": 1, "users": ["U1"]}]},
+                ], "response_metadata": {"next_cursor": "page2"}}),
+                "page2" => serde_json::json!({"ok": true, "has_more": false, "messages": [
+                    parent,
+                    {"type": "message", "subtype": "bot_message", "bot_id": "B1",
+                     "text": "noted", "ts": "3.000000", "thread_ts": "1.000000"},
+                ], "response_metadata": {"next_cursor": ""}}),
+                cursor => panic!("unexpected cursor {}", cursor),
+            }
+        })
+    }
+
+    #[tokio::test]
+    async fn test_fetch_thread_across_pages() {
+        let thread = fetch(&replies(), "xoxb", "C1", Timestamp::from(1.0))
+            .await
+            .unwrap();
+        assert!(thread.parent.is_some());
+        assert_eq!(thread.replies.len(), 2);
+        assert_eq!(thread.participants, vec!["U1", "U2"]);
+        assert_eq!(thread.reactions.len(), 1);
+        assert_eq!(thread.reactions[0].count, 2);
+        assert_eq!(thread.reactions[0].users, vec!["U2", "U1"]);
+    }
+}
@@ -0,0 +1,63 @@
+/// An authentication policy of an Enterprise Grid organization.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub enum AuthPolicyName {
+    /// Lets the assigned entities sign in with an email and password instead of SSO.
+    #[default]
+    EmailPassword,
+}
+
+impl AuthPolicyName {
+    pub fn to_param_value(self) -> String {
+        match self {
+            AuthPolicyName::EmailPassword => "email_password".to_owned(),
+        }
+    }
+}
+
+/// The kind of entity an authentication policy is assigned to.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash, Deserialize, Serialize)]
+pub enum AuthPolicyEntityType {
+    #[default]
+    #[serde(rename = "USER")]
+    User,
+    /// A type Slack added after this version of the crate. It can't be sent back, and is sent as
+    /// `USER`.
+    #[serde(other)]
+    Unknown,
+}
+
+impl AuthPolicyEntityType {
+    pub fn to_param_value(self) -> String {
+        match self {
+            AuthPolicyEntityType::User | AuthPolicyEntityType::Unknown => "USER".to_owned(),
+        }
+    }
+}
+
+/// An entity assigned to an authentication policy.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AuthPolicyEntity {
+    pub date_added: Option<crate::Timestamp>,
+    pub entity_id: Option<String>,
+    pub entity_type: Option<AuthPolicyEntityType>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auth_policy_entity() {
+        let entities: Vec<AuthPolicyEntity> = serde_json::from_str(
+            r#"[{"entity_id": "W1", "entity_type": "USER", "date_added": 1614215551},
+                {"entity_id": "S1", "entity_type": "USERGROUP"}]"#,
+        )
+        .unwrap();
+        assert_eq!(entities[0].entity_type, Some(AuthPolicyEntityType::User));
+        assert_eq!(
+            entities[0].date_added.unwrap().to_param_value(),
+            "1614215551.000000"
+        );
+        assert_eq!(entities[1].entity_type, Some(AuthPolicyEntityType::Unknown));
+    }
+}
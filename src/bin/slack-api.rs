@@ -0,0 +1,455 @@
+//! Calls any method the crate wraps from the command line, printing the response as JSON.
+//!
+//! ```text
+//! slack-api methods
+//! slack-api help chat.postMessage
+//! slack-api chat.postMessage --channel C1234567890 --text "Hello"
+//! slack-api drift users.list conversations.history --channel C1234567890
+//! ```
+//!
+//! The token is read from the `SLACK_API_TOKEN` environment variable.
+//!
+//! `drift` is for maintainers keeping the schemas current: it calls read-only methods against a
+//! real workspace and reports what the generated types would lose of each response, such as
+//! fields they don't have and message subtypes or blocks they don't know.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::process;
+
+use serde_json::Value;
+use slack_api::methods::METHODS;
+use slack_api::sync;
+use slack_api::sync::{default_client, SlackClient};
+use slack_api::{Message, MethodInfo};
+
+const USAGE: &str = "\
+Usage:
+    slack-api methods                      List the methods
+    slack-api help <method>                Describe a method and its parameters
+    slack-api <method> [--<param> <value>]...
+                                           Call a method, printing the response as JSON
+    slack-api drift [<method> [--<param> <value>]...]...
+                                           Report what the generated types miss of responses to
+                                           read-only methods, by default those without required
+                                           parameters
+
+The token is read from the SLACK_API_TOKEN environment variable.";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let code = match args.first().map(String::as_str) {
+        None | Some("-h") | Some("--help") => {
+            println!("{}", USAGE);
+            0
+        }
+        Some("methods") => {
+            for method in METHODS.iter().filter(|m| is_available(m)) {
+                println!("{:<40} {}", method.name, summary(method.description));
+            }
+            0
+        }
+        Some("help") => match args.get(1).map(|name| find(name)) {
+            Some(Ok(method)) => {
+                describe(method);
+                0
+            }
+            Some(Err(message)) => fail(&message),
+            None => fail(USAGE),
+        },
+        Some("drift") => match parse_calls(&args[1..]) {
+            Ok(calls) => drift(&calls),
+            Err(message) => fail(&message),
+        },
+        Some(name) => match find(name).and_then(|method| parse_params(method, &args[1..])) {
+            Ok(params) => call(name, &params),
+            Err(message) => fail(&message),
+        },
+    };
+    process::exit(code);
+}
+
+fn call(method: &str, params: &BTreeMap<&str, &str>) -> i32 {
+    let token = match env::var("SLACK_API_TOKEN") {
+        Ok(token) => token,
+        Err(_) => return fail("SLACK_API_TOKEN must be set"),
+    };
+    let sender = match default_client() {
+        Ok(sender) => sender,
+        Err(e) => return fail(&format!("Could not create a client: {}", e)),
+    };
+    match SlackClient::new(sender, token).call_method::<_, Value>(method, params) {
+        Ok(response) => {
+            println!("{:#}", response);
+            0
+        }
+        Err(e) => {
+            eprintln!("{} failed: {}", method, e);
+            1
+        }
+    }
+}
+
+/// Turns a response into its generated type and back, so the result lacks whatever the type
+/// doesn't keep.
+type RoundTrip = fn(Value) -> serde_json::Result<Value>;
+
+fn round_trip<T>(response: Value) -> serde_json::Result<Value>
+where
+    T: serde::de::DeserializeOwned + serde::Serialize,
+{
+    serde_json::from_value::<T>(response).and_then(serde_json::to_value)
+}
+
+/// The read-only methods `drift` can check, with their generated response types.
+const READ_ONLY: &[(&str, RoundTrip)] = &[
+    ("auth.test", round_trip::<sync::auth::TestResponse>),
+    (
+        "bookmarks.list",
+        round_trip::<sync::bookmarks::ListResponse>,
+    ),
+    ("bots.info", round_trip::<sync::bots::InfoResponse>),
+    (
+        "chat.scheduledMessages.list",
+        round_trip::<sync::chat_scheduled_messages::ListResponse>,
+    ),
+    (
+        "conversations.history",
+        round_trip::<sync::conversations::HistoryResponse>,
+    ),
+    (
+        "conversations.info",
+        round_trip::<sync::conversations::InfoResponse>,
+    ),
+    (
+        "conversations.list",
+        round_trip::<sync::conversations::ListResponse>,
+    ),
+    (
+        "conversations.members",
+        round_trip::<sync::conversations::MembersResponse>,
+    ),
+    (
+        "conversations.replies",
+        round_trip::<sync::conversations::RepliesResponse>,
+    ),
+    ("dnd.info", round_trip::<sync::dnd::InfoResponse>),
+    ("emoji.list", round_trip::<sync::emoji::ListResponse>),
+    ("files.list", round_trip::<sync::files::ListResponse>),
+    ("pins.list", round_trip::<sync::pins::ListResponse>),
+    (
+        "reminders.list",
+        round_trip::<sync::reminders::ListResponse>,
+    ),
+    ("stars.list", round_trip::<sync::stars::ListResponse>),
+    ("team.info", round_trip::<sync::team::InfoResponse>),
+    (
+        "usergroups.list",
+        round_trip::<sync::usergroups::ListResponse>,
+    ),
+    ("users.info", round_trip::<sync::users::InfoResponse>),
+    ("users.list", round_trip::<sync::users::ListResponse>),
+    (
+        "users.profile.get",
+        round_trip::<sync::users_profile::GetResponse>,
+    ),
+];
+
+/// Something in a response the generated types don't keep.
+#[derive(Clone, Debug, Eq, Ord, PartialEq, PartialOrd)]
+enum Drift {
+    /// A field the type doesn't have, by its path such as `members[].profile.pronouns`.
+    UnknownField(String),
+    /// A message subtype the `Message` enum doesn't have.
+    UnknownSubtype(String),
+    /// Items of a list the type dropped, such as blocks of an unknown type.
+    DroppedItems(String),
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Drift::UnknownField(ref path) => write!(f, "unknown field {}", path),
+            Drift::UnknownSubtype(ref subtype) => write!(f, "unknown message subtype {}", subtype),
+            Drift::DroppedItems(ref path) => write!(f, "items dropped from {}", path),
+        }
+    }
+}
+
+/// A method to call, with its parameters.
+type Call<'a> = (&'static str, BTreeMap<&'a str, &'a str>);
+
+/// Reads `<method> [--<param> <value>]...` groups, defaulting to every method `drift` can check
+/// which has no required parameters.
+fn parse_calls(args: &[String]) -> Result<Vec<Call<'_>>, String> {
+    if args.is_empty() {
+        return Ok(READ_ONLY
+            .iter()
+            .filter_map(|&(name, _)| MethodInfo::find(name))
+            .filter(|method| method.params.iter().all(|p| !p.required))
+            .map(|method| (method.name, BTreeMap::new()))
+            .collect());
+    }
+    let mut calls = Vec::new();
+    let mut start = 0;
+    while start < args.len() {
+        let method = find(&args[start])?;
+        if !READ_ONLY.iter().any(|&(name, _)| name == method.name) {
+            return Err(format!("drift can't check {}", method.name));
+        }
+        // The method's flags run up to the next argument which is neither a flag nor a value.
+        let mut end = start + 1;
+        while end < args.len() && args[end].starts_with("--") {
+            end += if args[end].contains('=') { 1 } else { 2 };
+        }
+        let end = end.min(args.len());
+        calls.push((method.name, parse_params(method, &args[start + 1..end])?));
+        start = end;
+    }
+    Ok(calls)
+}
+
+fn drift(calls: &[(&str, BTreeMap<&str, &str>)]) -> i32 {
+    let token = match env::var("SLACK_API_TOKEN") {
+        Ok(token) => token,
+        Err(_) => return fail("SLACK_API_TOKEN must be set"),
+    };
+    let sender = match default_client() {
+        Ok(sender) => sender,
+        Err(e) => return fail(&format!("Could not create a client: {}", e)),
+    };
+    let client = SlackClient::new(sender, token);
+    let mut code = 0;
+    for &(method, ref params) in calls {
+        let response = match client.call_method::<_, Value>(method, params) {
+            Ok(response) => response,
+            Err(e) => {
+                eprintln!("{} failed: {}", method, e);
+                code = 1;
+                continue;
+            }
+        };
+        let round_trip = READ_ONLY
+            .iter()
+            .find(|&&(name, _)| name == method)
+            .map(|&(_, round_trip)| round_trip)
+            .expect("drift only calls the methods it can check");
+        match find_drift(&response, round_trip) {
+            Ok(drift) if drift.is_empty() => println!("{}: no drift", method),
+            Ok(drift) => {
+                code = 1;
+                for (drift, count) in drift {
+                    println!("{}: {} ({} times)", method, drift, count);
+                }
+            }
+            Err(e) => {
+                println!("{}: the response doesn't deserialize: {}", method, e);
+                code = 1;
+            }
+        }
+    }
+    code
+}
+
+/// What the generated type loses of a response, with how often.
+fn find_drift(
+    response: &Value,
+    round_trip: RoundTrip,
+) -> serde_json::Result<BTreeMap<Drift, usize>> {
+    let typed = round_trip(response.clone())?;
+    let mut drift = BTreeMap::new();
+    compare("", response, &typed, &mut drift);
+    Ok(drift)
+}
+
+fn compare(path: &str, original: &Value, typed: &Value, drift: &mut BTreeMap<Drift, usize>) {
+    match (original, typed) {
+        (Value::Object(original), Value::Object(typed)) => {
+            if original.get("type").and_then(Value::as_str) == Some("message") {
+                if let Some(subtype) = original.get("subtype").and_then(Value::as_str) {
+                    let value = Value::Object(original.clone());
+                    if let Ok(Message::Other(_)) = serde_json::from_value(value) {
+                        *drift
+                            .entry(Drift::UnknownSubtype(subtype.to_owned()))
+                            .or_default() += 1;
+                        // The whole message is kept as JSON, so none of its fields are missed.
+                        return;
+                    }
+                }
+            }
+            for (key, value) in original.iter().filter(|(_, value)| !value.is_null()) {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match typed.get(key) {
+                    Some(typed) => compare(&path, value, typed, drift),
+                    None => *drift.entry(Drift::UnknownField(path)).or_default() += 1,
+                }
+            }
+        }
+        (Value::Array(original), Value::Array(typed)) => {
+            if original.len() != typed.len() {
+                let dropped = original.len().saturating_sub(typed.len());
+                *drift
+                    .entry(Drift::DroppedItems(path.to_owned()))
+                    .or_default() += dropped;
+                return;
+            }
+            let path = format!("{}[]", path);
+            for (original, typed) in original.iter().zip(typed) {
+                compare(&path, original, typed, drift);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Whether the method's function is compiled in.
+fn is_available(method: &MethodInfo) -> bool {
+    !method.unstable || cfg!(feature = "unstable-api")
+}
+
+fn find(name: &str) -> Result<&'static MethodInfo, String> {
+    match MethodInfo::find(name) {
+        Some(method) if is_available(method) => Ok(method),
+        Some(_) => Err(format!(
+            "{} is undocumented, and only available with the unstable-api feature",
+            name
+        )),
+        None => Err(format!(
+            "Unknown method {}. Run `slack-api methods` to list them.",
+            name
+        )),
+    }
+}
+
+/// Reads `--name value` and `--name=value` flags into parameters, checking they're the method's
+/// and that none of its required parameters are missing. Dashes in names are read as underscores.
+fn parse_params<'a>(
+    method: &MethodInfo,
+    args: &'a [String],
+) -> Result<BTreeMap<&'static str, &'a str>, String> {
+    let mut params = BTreeMap::new();
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let flag = arg
+            .strip_prefix("--")
+            .ok_or_else(|| format!("Expected a --<param> flag but got {}", arg))?;
+        let (name, value) = match flag.find('=') {
+            Some(i) => (&flag[..i], &flag[i + 1..]),
+            None => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("Missing a value for --{}", flag))?;
+                (flag, &value[..])
+            }
+        };
+        let param = method.param(&name.replace('-', "_")).ok_or_else(|| {
+            format!(
+                "{} has no parameter {}. Run `slack-api help {}` to list them.",
+                method.name, name, method.name
+            )
+        })?;
+        params.insert(param.name, value);
+    }
+    let missing = method
+        .params
+        .iter()
+        .filter(|p| p.required && !params.contains_key(p.name))
+        .map(|p| format!("--{}", p.name))
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        Ok(params)
+    } else {
+        Err(format!(
+            "Missing {} for {}",
+            missing.join(", "),
+            method.name
+        ))
+    }
+}
+
+fn describe(method: &MethodInfo) {
+    println!("{}\n\n{}", method.name, method.description);
+    if !method.params.is_empty() {
+        println!("\nParameters:");
+    }
+    for param in method.params {
+        let required = if param.required { " (required)" } else { "" };
+        println!("    --{}{}", param.name, required);
+        if !param.description.is_empty() {
+            println!("        {}", param.description);
+        }
+    }
+    println!("\nhttps://api.slack.com/methods/{}", method.name);
+}
+
+/// The first line of a description.
+fn summary(description: &str) -> &str {
+    description.lines().next().unwrap_or_default()
+}
+
+fn fail(message: &str) -> i32 {
+    eprintln!("{}", message);
+    2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&a| a.to_owned()).collect()
+    }
+
+    #[test]
+    fn test_parse_params() {
+        let method = MethodInfo::find("chat.postMessage").unwrap();
+        let args = args(&["--channel", "C1", "--text=hi", "--thread-ts", "1.000000"]);
+        let params = parse_params(method, &args).unwrap();
+        assert_eq!(params["channel"], "C1");
+        assert_eq!(params["text"], "hi");
+        assert_eq!(params["thread_ts"], "1.000000");
+
+        let missing = parse_params(method, &args[..2]).unwrap_err();
+        assert_eq!(missing, "Missing --text for chat.postMessage");
+        assert!(parse_params(method, &self::args(&["--chanel", "C1"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_calls() {
+        let args = args(&["users.list", "conversations.history", "--channel", "C1"]);
+        let calls = parse_calls(&args).unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0], ("users.list", BTreeMap::new()));
+        assert_eq!(calls[1].1["channel"], "C1");
+        assert!(parse_calls(&self::args(&["chat.postMessage"])).is_err());
+        assert!(parse_calls(&[])
+            .unwrap()
+            .iter()
+            .all(|&(name, _)| name != "conversations.history"));
+    }
+
+    #[test]
+    fn test_find_drift() {
+        let response = serde_json::json!({"ok": true, "messages": [
+            {"type": "message", "user": "U1", "text": "hi", "ts": "1.000000",
+             "pronouns": "they/them", "blocks": [{"type": "future_block"}]},
+            {"type": "message", "subtype": "huddle_thread", "ts": "2.000000", "room": {}},
+        ], "has_more": false, "unheard_of": 0});
+        let round_trip = round_trip::<sync::conversations::HistoryResponse>;
+        let drift = find_drift(&response, round_trip).unwrap();
+        assert_eq!(
+            drift.into_iter().collect::<Vec<_>>(),
+            vec![
+                (Drift::UnknownField("messages[].pronouns".to_owned()), 1),
+                (Drift::UnknownField("unheard_of".to_owned()), 1),
+                (Drift::UnknownSubtype("huddle_thread".to_owned()), 1),
+                (Drift::DroppedItems("messages[].blocks".to_owned()), 1),
+            ]
+        );
+    }
+}
@@ -0,0 +1,639 @@
+//! [Block Kit](https://api.slack.com/block-kit) layout blocks and legacy attachments, for
+//! building messages.
+//!
+//! Only the commonly used blocks and elements are typed, along with rich text, which Slack sends
+//! in messages.
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Block {
+    Section(SectionBlock),
+    Divider(DividerBlock),
+    Header(HeaderBlock),
+    Image(ImageBlock),
+    Context(ContextBlock),
+    Actions(ActionsBlock),
+    RichText(RichTextBlock),
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SectionBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+    /// Shown in two columns; up to 10 fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<Text>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub accessory: Option<BlockElement>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DividerBlock {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HeaderBlock {
+    /// Must be plain text.
+    pub text: Text,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImageBlock {
+    pub image_url: String,
+    pub alt_text: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ContextBlock {
+    pub elements: Vec<ContextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ActionsBlock {
+    pub elements: Vec<BlockElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+/// A text object.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Text {
+    #[serde(rename = "plain_text")]
+    Plain {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        emoji: Option<bool>,
+    },
+    #[serde(rename = "mrkdwn")]
+    Markdown {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        verbatim: Option<bool>,
+    },
+}
+
+impl Text {
+    pub fn plain<S: Into<String>>(text: S) -> Self {
+        Text::Plain {
+            text: text.into(),
+            emoji: None,
+        }
+    }
+
+    pub fn markdown<S: Into<String>>(text: S) -> Self {
+        Text::Markdown {
+            text: text.into(),
+            verbatim: None,
+        }
+    }
+}
+
+/// An interactive or image element within a section or actions block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BlockElement {
+    Button(ButtonElement),
+    Image(ImageElement),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ButtonElement {
+    /// Must be plain text.
+    pub text: Text,
+    pub action_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    /// `"primary"` or `"danger"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub style: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImageElement {
+    pub image_url: String,
+    pub alt_text: String,
+}
+
+/// An element of a context block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ContextElement {
+    Text(Text),
+    Image(ContextImage),
+}
+
+/// An image within a context block.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "image")]
+pub struct ContextImage {
+    pub image_url: String,
+    pub alt_text: String,
+}
+
+/// The view shown in a user's App Home tab, holding up to 100 blocks.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "home")]
+pub struct HomeView {
+    pub blocks: Vec<Block>,
+    /// Up to 3000 characters passed back to the app with interactions on the view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+}
+
+/// A modal opened with `views.open` or `views.push`, holding up to 100 blocks.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename = "modal")]
+pub struct ModalView {
+    /// Plain text of up to 24 characters.
+    pub title: Text,
+    pub blocks: Vec<Block>,
+    /// The submit button, which is required when the modal has input blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submit: Option<Text>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub close: Option<Text>,
+    /// Up to 3000 characters passed back to the app with interactions on the view.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_metadata: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callback_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    /// Sends a `view_closed` interaction when the user closes the modal.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notify_on_close: Option<bool>,
+}
+
+impl ModalView {
+    pub fn new<S: Into<String>>(title: S, blocks: Vec<Block>) -> Self {
+        ModalView {
+            title: Text::plain(title),
+            blocks,
+            submit: None,
+            close: None,
+            private_metadata: None,
+            callback_id: None,
+            external_id: None,
+            notify_on_close: None,
+        }
+    }
+}
+
+/// Formatted text, the way Slack's clients compose messages.
+///
+/// Slack sends a message's rich text alongside its plain `text`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RichTextBlock {
+    pub elements: Vec<RichTextObject>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_id: Option<String>,
+}
+
+/// A paragraph-level part of a rich text block.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum RichTextObject {
+    #[serde(rename = "rich_text_section")]
+    Section(RichTextSection),
+    #[serde(rename = "rich_text_list")]
+    List(RichTextList),
+    #[serde(rename = "rich_text_quote")]
+    Quote(RichTextQuote),
+    #[serde(rename = "rich_text_preformatted")]
+    Preformatted(RichTextPreformatted),
+}
+
+/// A run of inline elements, such as a paragraph or a list item.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RichTextSection {
+    pub elements: Vec<RichTextElement>,
+}
+
+impl RichTextSection {
+    pub fn new(elements: Vec<RichTextElement>) -> Self {
+        RichTextSection { elements }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RichTextList {
+    pub style: RichTextListStyle,
+    /// The list's items.
+    #[serde(with = "list_items")]
+    pub elements: Vec<RichTextSection>,
+    /// How deeply the list is nested, from 0.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indent: Option<u32>,
+    /// The number of items before the first one of an ordered list, continuing an earlier list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border: Option<u32>,
+}
+
+/// (De)serializes list items, which are sections tagged with their type.
+mod list_items {
+    use super::RichTextSection;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    #[derive(Serialize)]
+    #[serde(tag = "type")]
+    enum ItemRef<'a> {
+        #[serde(rename = "rich_text_section")]
+        Section(&'a RichTextSection),
+    }
+
+    #[derive(Deserialize)]
+    #[serde(tag = "type")]
+    enum Item {
+        #[serde(rename = "rich_text_section")]
+        Section(RichTextSection),
+    }
+
+    pub fn serialize<S: Serializer>(
+        items: &[RichTextSection],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(items.iter().map(ItemRef::Section))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<RichTextSection>, D::Error> {
+        let items = Vec::<Item>::deserialize(deserializer)?;
+        Ok(items.into_iter().map(|Item::Section(s)| s).collect())
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RichTextListStyle {
+    Bullet,
+    Ordered,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RichTextQuote {
+    pub elements: Vec<RichTextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border: Option<u32>,
+}
+
+/// A code block.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RichTextPreformatted {
+    pub elements: Vec<RichTextElement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub border: Option<u32>,
+}
+
+/// An inline element of rich text.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum RichTextElement {
+    Text {
+        text: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Link {
+        url: String,
+        /// Shown instead of the URL.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        text: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    User {
+        user_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Usergroup {
+        usergroup_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Channel {
+        channel_id: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        style: Option<RichTextStyle>,
+    },
+    Emoji {
+        /// The emoji's name, without colons.
+        name: String,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        unicode: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        skin_tone: Option<u8>,
+    },
+    /// An `@here`, `@channel` or `@everyone` mention.
+    Broadcast {
+        /// `"here"`, `"channel"` or `"everyone"`.
+        range: String,
+    },
+    /// A date formatted for the reader's time zone.
+    Date {
+        timestamp: i64,
+        /// A template such as `"{date_short} at {time}"`.
+        format: String,
+        /// Shown by clients that can't format the date.
+        #[serde(skip_serializing_if = "Option::is_none")]
+        fallback: Option<String>,
+    },
+    Color {
+        /// A hex color code.
+        value: String,
+    },
+}
+
+impl RichTextElement {
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        RichTextElement::Text {
+            text: text.into(),
+            style: None,
+        }
+    }
+
+    pub fn styled<S: Into<String>>(text: S, style: RichTextStyle) -> Self {
+        RichTextElement::Text {
+            text: text.into(),
+            style: Some(style),
+        }
+    }
+
+    pub fn link<S: Into<String>>(url: S) -> Self {
+        RichTextElement::Link {
+            url: url.into(),
+            text: None,
+            style: None,
+        }
+    }
+
+    pub fn user<S: Into<String>>(user_id: S) -> Self {
+        RichTextElement::User {
+            user_id: user_id.into(),
+            style: None,
+        }
+    }
+
+    pub fn channel<S: Into<String>>(channel_id: S) -> Self {
+        RichTextElement::Channel {
+            channel_id: channel_id.into(),
+            style: None,
+        }
+    }
+
+    pub fn emoji<S: Into<String>>(name: S) -> Self {
+        RichTextElement::Emoji {
+            name: name.into(),
+            unicode: None,
+            skin_tone: None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+pub struct RichTextStyle {
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub bold: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub italic: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub strike: bool,
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub code: bool,
+}
+
+fn is_false(b: &bool) -> bool {
+    !*b
+}
+
+/// Deserializes a message's blocks, leaving out those of types this library doesn't type.
+pub(crate) fn known_blocks<'de, D>(deserializer: D) -> Result<Option<Vec<Block>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    let blocks = Option::<Vec<serde_json::Value>>::deserialize(deserializer)?;
+    Ok(blocks.map(|blocks| {
+        blocks
+            .into_iter()
+            .filter_map(|block| serde_json::from_value(block).ok())
+            .collect()
+    }))
+}
+
+/// A legacy message attachment.
+///
+/// Slack recommends using blocks instead, but attachments are still the only way to add a colored
+/// bar alongside a message.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Attachment {
+    /// A plain text summary for clients that can't display attachments.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<String>,
+    /// `"good"`, `"warning"`, `"danger"`, or a hex color code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pretext: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author_icon: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fields: Option<Vec<AttachmentField>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thumb_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub footer_icon: Option<String>,
+    /// Names of the fields to format as mrkdwn, e.g. `"text"` or `"pretext"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mrkdwn_in: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<Block>>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct AttachmentField {
+    pub title: String,
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub short: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_serialize() {
+        let blocks = vec![
+            Block::Section(SectionBlock {
+                text: Some(Text::markdown("*Deploy* finished")),
+                accessory: Some(BlockElement::Button(ButtonElement {
+                    text: Text::plain("View"),
+                    action_id: "view".into(),
+                    url: None,
+                    value: Some("42".into()),
+                    style: None,
+                })),
+                ..Default::default()
+            }),
+            Block::Divider(DividerBlock::default()),
+            Block::Context(ContextBlock {
+                elements: vec![
+                    ContextElement::Text(Text::plain("by ci")),
+                    ContextElement::Image(ContextImage {
+                        image_url: "https://example.com/a.png".into(),
+                        alt_text: "ci".into(),
+                    }),
+                ],
+                block_id: None,
+            }),
+        ];
+        let json = serde_json::to_value(&blocks).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([
+                {
+                    "type": "section",
+                    "text": {"type": "mrkdwn", "text": "*Deploy* finished"},
+                    "accessory": {
+                        "type": "button",
+                        "text": {"type": "plain_text", "text": "View"},
+                        "action_id": "view",
+                        "value": "42"
+                    }
+                },
+                {"type": "divider"},
+                {
+                    "type": "context",
+                    "elements": [
+                        {"type": "plain_text", "text": "by ci"},
+                        {"type": "image", "image_url": "https://example.com/a.png", "alt_text": "ci"}
+                    ]
+                }
+            ])
+        );
+        assert_eq!(serde_json::from_value::<Vec<Block>>(json).unwrap(), blocks);
+    }
+
+    #[test]
+    fn test_rich_text_round_trip() {
+        let json = serde_json::json!({
+            "type": "rich_text",
+            "elements": [
+                {
+                    "type": "rich_text_section",
+                    "elements": [
+                        {"type": "text", "text": "Ship it ", "style": {"bold": true}},
+                        {"type": "user", "user_id": "U1"},
+                        {"type": "emoji", "name": "rocket", "unicode": "1f680"}
+                    ]
+                },
+                {
+                    "type": "rich_text_list",
+                    "style": "ordered",
+                    "elements": [
+                        {"type": "rich_text_section", "elements": [{"type": "link", "url": "https://example.com"}]}
+                    ],
+                    "indent": 1
+                },
+                {"type": "rich_text_quote", "elements": [{"type": "channel", "channel_id": "C1"}]},
+                {"type": "rich_text_preformatted", "elements": [{"type": "text", "text": "cargo test"}], "border": 0}
+            ]
+        });
+        let block = Block::RichText(RichTextBlock {
+            elements: vec![
+                RichTextObject::Section(RichTextSection::new(vec![
+                    RichTextElement::styled(
+                        "Ship it ",
+                        RichTextStyle {
+                            bold: true,
+                            ..Default::default()
+                        },
+                    ),
+                    RichTextElement::user("U1"),
+                    RichTextElement::Emoji {
+                        name: "rocket".into(),
+                        unicode: Some("1f680".into()),
+                        skin_tone: None,
+                    },
+                ])),
+                RichTextObject::List(RichTextList {
+                    style: RichTextListStyle::Ordered,
+                    elements: vec![RichTextSection::new(vec![RichTextElement::link(
+                        "https://example.com",
+                    )])],
+                    indent: Some(1),
+                    offset: None,
+                    border: None,
+                }),
+                RichTextObject::Quote(RichTextQuote {
+                    elements: vec![RichTextElement::channel("C1")],
+                    border: None,
+                }),
+                RichTextObject::Preformatted(RichTextPreformatted {
+                    elements: vec![RichTextElement::text("cargo test")],
+                    border: Some(0),
+                }),
+            ],
+            block_id: None,
+        });
+        assert_eq!(serde_json::to_value(&block).unwrap(), json);
+        assert_eq!(serde_json::from_value::<Block>(json).unwrap(), block);
+    }
+
+    #[test]
+    fn test_message_blocks_skip_unknown_types() {
+        let message: crate::Message = serde_json::from_str(
+            r#"{"type": "message", "text": "hi", "ts": "1.000000", "blocks": [
+                {"type": "rich_text", "elements": [
+                    {"type": "rich_text_section", "elements": [{"type": "text", "text": "hi"}]}
+                ]},
+                {"type": "video", "title": {"type": "plain_text", "text": "demo"}}
+            ]}"#,
+        )
+        .unwrap();
+        match message {
+            crate::Message::Standard(m) => {
+                assert!(matches!(m.blocks.as_deref(), Some([Block::RichText(_)])))
+            }
+            _ => panic!("expected a standard message"),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+//! Borrowed views of the largest list responses, for tools that process huge workspaces.
+//!
+//! The types in [`crate`] own every string they contain, so a `users.list` or
+//! `conversations.history` page from a big workspace allocates tens of thousands of `String`s.
+//! The types here hold only the commonly used fields and borrow their strings from the response
+//! body, copying only those that contain JSON escapes.
+//!
+//! Fetch the body with `SlackClient::call_method_raw` and parse it with `serde_json::from_str`:
+//! the page borrows from the body, so keep the body alive while using it.
+
+use serde::{Deserialize, Deserializer};
+use std::borrow::Cow;
+
+fn borrowed_str<'de: 'a, 'a, D>(deserializer: D) -> Result<Option<Cow<'a, str>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // `Option<Cow<str>>` always copies, so borrow through a bare `Cow` instead.
+    #[derive(Deserialize)]
+    struct Borrowed<'a>(#[serde(borrow)] Cow<'a, str>);
+
+    Ok(Option::<Borrowed<'a>>::deserialize(deserializer)?.map(|b| b.0))
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ResponseMetadataRef<'a> {
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub next_cursor: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UserRef<'a> {
+    pub deleted: Option<bool>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub id: Option<Cow<'a, str>>,
+    pub is_bot: Option<bool>,
+    pub is_restricted: Option<bool>,
+    pub is_ultra_restricted: Option<bool>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub name: Option<Cow<'a, str>>,
+    #[serde(borrow)]
+    pub profile: Option<UserProfileRef<'a>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub real_name: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub team_id: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub tz: Option<Cow<'a, str>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct UserProfileRef<'a> {
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub display_name: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub email: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub real_name: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub status_emoji: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub status_text: Option<Cow<'a, str>>,
+}
+
+/// A message from `conversations.history` or `conversations.replies`.
+///
+/// `ts` and `thread_ts` are kept as Slack sends them, ready to pass back as parameters.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageRef<'a> {
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub bot_id: Option<Cow<'a, str>>,
+    pub reply_count: Option<u32>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub subtype: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub text: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub thread_ts: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub ts: Option<Cow<'a, str>>,
+    #[serde(default, borrow, deserialize_with = "borrowed_str")]
+    pub user: Option<Cow<'a, str>>,
+}
+
+/// A page of `users.list`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct UsersListPage<'a> {
+    #[serde(default, borrow)]
+    pub members: Vec<UserRef<'a>>,
+    #[serde(borrow)]
+    pub response_metadata: Option<ResponseMetadataRef<'a>>,
+}
+
+/// A page of `conversations.history` or `conversations.replies`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct HistoryPage<'a> {
+    #[serde(default)]
+    pub has_more: bool,
+    #[serde(default, borrow)]
+    pub messages: Vec<MessageRef<'a>>,
+    #[serde(borrow)]
+    pub response_metadata: Option<ResponseMetadataRef<'a>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_page_borrows_unescaped_strings() {
+        let body = r#"{"ok": true, "has_more": true, "messages": [
+            {"type": "message", "user": "U1", "text": "say \"hi\"", "ts": "1.000100"}
+        ], "response_metadata": {"next_cursor": "abc"}}"#;
+        let page: HistoryPage<'_> = serde_json::from_str(body).unwrap();
+        let message = &page.messages[0];
+        assert!(matches!(message.user, Some(Cow::Borrowed("U1"))));
+        assert!(matches!(message.ts, Some(Cow::Borrowed("1.000100"))));
+        assert_eq!(message.text.as_deref(), Some("say \"hi\""));
+        assert!(message.thread_ts.is_none());
+        let cursor = page.response_metadata.unwrap().next_cursor;
+        assert!(matches!(cursor, Some(Cow::Borrowed("abc"))));
+    }
+}
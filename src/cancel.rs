@@ -0,0 +1,126 @@
+use std::collections::HashMap;
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::Waker;
+#[cfg(feature = "async")]
+use std::task::{Context, Poll};
+
+/// Cancels the Slack calls made through a `Bounded` sender, for example when the request that
+/// triggered them is abandoned.
+///
+/// Clones share their state, so cancelling any clone cancels them all.
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    cancelled: AtomicBool,
+    wakers: Mutex<Wakers>,
+}
+
+/// The wakers of the futures waiting on a token, keyed by future so that each has at most one.
+#[derive(Debug, Default)]
+struct Wakers {
+    #[cfg(feature = "async")]
+    next_key: u64,
+    by_key: HashMap<u64, Waker>,
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        CancelToken::default()
+    }
+
+    /// Cancels calls in progress and fails any later ones.
+    pub fn cancel(&self) {
+        self.inner.cancelled.store(true, Ordering::SeqCst);
+        for (_, waker) in self.inner.wakers.lock().unwrap().by_key.drain() {
+            waker.wake();
+        }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.inner.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Resolves once the token is cancelled.
+    #[cfg(feature = "async")]
+    pub(crate) fn cancelled(&self) -> Cancelled<'_> {
+        Cancelled {
+            token: self,
+            key: None,
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+pub(crate) struct Cancelled<'a> {
+    token: &'a CancelToken,
+    /// The key of the future's waker once it's been polled.
+    key: Option<u64>,
+}
+
+#[cfg(feature = "async")]
+impl Future for Cancelled<'_> {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.token.is_cancelled() {
+            return Poll::Ready(());
+        }
+        {
+            let mut wakers = self.token.inner.wakers.lock().unwrap();
+            let key = *self.key.get_or_insert_with(|| {
+                wakers.next_key += 1;
+                wakers.next_key
+            });
+            match wakers.by_key.get_mut(&key) {
+                Some(waker) if waker.will_wake(cx.waker()) => {}
+                Some(waker) => *waker = cx.waker().clone(),
+                None => {
+                    wakers.by_key.insert(key, cx.waker().clone());
+                }
+            }
+        }
+        // Cancelling between the first check and registering the waker would otherwise be missed.
+        if self.token.is_cancelled() {
+            Poll::Ready(())
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+impl Drop for Cancelled<'_> {
+    fn drop(&mut self) {
+        if let Some(key) = self.key {
+            self.token.inner.wakers.lock().unwrap().by_key.remove(&key);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "async")]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dropped_futures_unregister_their_wakers() {
+        let token = CancelToken::new();
+        let mut cx = Context::from_waker(futures_util::task::noop_waker_ref());
+        for _ in 0..3 {
+            let mut cancelled = Box::pin(token.cancelled());
+            assert!(cancelled.as_mut().poll(&mut cx).is_pending());
+            assert!(cancelled.as_mut().poll(&mut cx).is_pending());
+            assert_eq!(token.inner.wakers.lock().unwrap().by_key.len(), 1);
+        }
+        assert!(token.inner.wakers.lock().unwrap().by_key.is_empty());
+    }
+}
@@ -0,0 +1,216 @@
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Wraps a sender to stop calling Slack during a sustained outage.
+///
+/// After `failure_threshold` consecutive failed calls the circuit opens and calls fail straight
+/// away with [`CircuitError::Open`]. Once `reset_timeout` has passed a single probe call is let
+/// through: if it succeeds the circuit closes again, otherwise it stays open for another
+/// `reset_timeout`. A call fails when the wrapped sender returns an error, such as a timeout, or
+/// reports a 5xx status from `send_with_status`. The crate's other wrappers pass the status on,
+/// so the breaker can sit outside them.
+///
+/// Clones share their state, so one breaker can guard every task talking to Slack.
+#[derive(Clone, Debug)]
+pub struct CircuitBreaker<R> {
+    pub(crate) sender: R,
+    failure_threshold: u32,
+    reset_timeout: Duration,
+    state: Arc<Mutex<State>>,
+}
+
+/// Where a [`CircuitBreaker`] is in its cycle.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CircuitState {
+    /// Calls go through.
+    Closed,
+    /// Calls fail without being sent.
+    Open,
+    /// A probe call is deciding whether to close the circuit.
+    HalfOpen,
+}
+
+#[derive(Debug)]
+enum State {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    HalfOpen,
+}
+
+impl<R> CircuitBreaker<R> {
+    /// Wraps `sender`, opening after 5 consecutive failures for 30 seconds.
+    pub fn new(sender: R) -> Self {
+        CircuitBreaker {
+            sender,
+            failure_threshold: 5,
+            reset_timeout: Duration::from_secs(30),
+            state: Arc::new(Mutex::new(State::Closed { failures: 0 })),
+        }
+    }
+
+    pub fn failure_threshold(mut self, failures: u32) -> Self {
+        self.failure_threshold = failures.max(1);
+        self
+    }
+
+    pub fn reset_timeout(mut self, timeout: Duration) -> Self {
+        self.reset_timeout = timeout;
+        self
+    }
+
+    pub fn state(&self) -> CircuitState {
+        match *self.state.lock().unwrap() {
+            State::Closed { .. } => CircuitState::Closed,
+            State::Open { until } if Instant::now() >= until => CircuitState::HalfOpen,
+            State::Open { .. } => CircuitState::Open,
+            State::HalfOpen => CircuitState::HalfOpen,
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.sender
+    }
+
+    /// Lets a call through unless the circuit is open or another call is probing it.
+    pub(crate) fn permit(&self) -> Option<Permit<'_>> {
+        let mut state = self.state.lock().unwrap();
+        match *state {
+            State::Closed { .. } => {}
+            State::Open { until } if Instant::now() >= until => *state = State::HalfOpen,
+            State::Open { .. } | State::HalfOpen => return None,
+        }
+        Some(Permit {
+            failure_threshold: self.failure_threshold,
+            reset_timeout: self.reset_timeout,
+            state: &self.state,
+            recorded: false,
+        })
+    }
+}
+
+/// A call let through the circuit; dropping it without recording an outcome counts as a failure,
+/// so an abandoned probe doesn't leave the circuit half-open.
+pub(crate) struct Permit<'a> {
+    failure_threshold: u32,
+    reset_timeout: Duration,
+    state: &'a Mutex<State>,
+    recorded: bool,
+}
+
+impl Permit<'_> {
+    /// Records the outcome of a call sent with `send_with_status`, which failed if the sender
+    /// returned an error or Slack answered with a 5xx status.
+    pub(crate) fn record<T, E>(mut self, result: &Result<(Option<u16>, T), E>) {
+        self.recorded = true;
+        self.settle(matches!(*result, Ok((status, _)) if !matches!(status, Some(500..=599))));
+    }
+
+    fn settle(&self, succeeded: bool) {
+        let mut state = self.state.lock().unwrap();
+        if succeeded {
+            *state = State::Closed { failures: 0 };
+            return;
+        }
+        let failures = match *state {
+            State::Closed { failures } => failures + 1,
+            State::Open { .. } | State::HalfOpen => self.failure_threshold,
+        };
+        *state = if failures >= self.failure_threshold {
+            State::Open {
+                until: Instant::now() + self.reset_timeout,
+            }
+        } else {
+            State::Closed { failures }
+        };
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        if !self.recorded {
+            self.settle(false);
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum CircuitError<E> {
+    /// The circuit is open, so the call wasn't sent
+    Open,
+    /// The wrapped sender failed
+    Sender(E),
+}
+
+impl<E: Error> fmt::Display for CircuitError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CircuitError::Open => write!(f, "circuit open: Slack has been failing"),
+            CircuitError::Sender(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for CircuitError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CircuitError::Sender(ref inner) => Some(inner),
+            CircuitError::Open => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(breaker: &CircuitBreaker<()>, succeed: bool) -> bool {
+        match breaker.permit() {
+            Some(permit) => {
+                permit.record(&if succeed { Ok((None, ())) } else { Err(()) });
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[test]
+    fn test_circuit_opens_and_probes() {
+        let breaker = CircuitBreaker::new(())
+            .failure_threshold(2)
+            .reset_timeout(Duration::from_millis(20));
+        assert!(call(&breaker, false));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+        assert!(call(&breaker, false));
+        assert_eq!(breaker.state(), CircuitState::Open);
+        assert!(!call(&breaker, true));
+
+        std::thread::sleep(Duration::from_millis(30));
+        let probe = breaker.permit().expect("a probe is let through");
+        assert!(breaker.permit().is_none(), "only one probe at a time");
+        drop(probe);
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        std::thread::sleep(Duration::from_millis(30));
+        assert!(call(&breaker, true));
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_server_errors_are_failures() {
+        let breaker = CircuitBreaker::new(()).failure_threshold(2);
+        for status in [Some(503), Some(500)] {
+            let permit = breaker.permit().unwrap();
+            permit.record(&Ok::<_, ()>((status, "<html>Service Unavailable</html>")));
+        }
+        assert_eq!(breaker.state(), CircuitState::Open);
+
+        let breaker = CircuitBreaker::new(()).failure_threshold(1);
+        for status in [Some(200), Some(429), None] {
+            let permit = breaker.permit().unwrap();
+            permit.record(&Ok::<_, ()>((status, r#"{"ok": false}"#)));
+        }
+        assert_eq!(breaker.state(), CircuitState::Closed);
+    }
+}
@@ -0,0 +1,83 @@
+use std::ops::{BitOr, BitOrAssign};
+
+/// A set of conversation types to list with `conversations.list`, combined with `|`.
+///
+/// The default, empty set lists what Slack lists when no types are given: public channels.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct ConversationTypes(u8);
+
+impl ConversationTypes {
+    pub const PUBLIC_CHANNEL: ConversationTypes = ConversationTypes(1);
+    pub const PRIVATE_CHANNEL: ConversationTypes = ConversationTypes(1 << 1);
+    /// Multi-person direct messages.
+    pub const MPIM: ConversationTypes = ConversationTypes(1 << 2);
+    /// Direct messages.
+    pub const IM: ConversationTypes = ConversationTypes(1 << 3);
+
+    const NAMES: [(ConversationTypes, &'static str); 4] = [
+        (ConversationTypes::PUBLIC_CHANNEL, "public_channel"),
+        (ConversationTypes::PRIVATE_CHANNEL, "private_channel"),
+        (ConversationTypes::MPIM, "mpim"),
+        (ConversationTypes::IM, "im"),
+    ];
+
+    /// Every conversation type.
+    pub fn all() -> ConversationTypes {
+        ConversationTypes(0b1111)
+    }
+
+    pub fn contains(self, other: ConversationTypes) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn to_param_value(self) -> String {
+        if self.is_empty() {
+            return "public_channel".to_owned();
+        }
+        ConversationTypes::NAMES
+            .iter()
+            .filter(|&&(ty, _)| self.contains(ty))
+            .map(|&(_, name)| name)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl BitOr for ConversationTypes {
+    type Output = ConversationTypes;
+
+    fn bitor(self, other: ConversationTypes) -> ConversationTypes {
+        ConversationTypes(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for ConversationTypes {
+    fn bitor_assign(&mut self, other: ConversationTypes) {
+        self.0 |= other.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn conversation_types_param_value() {
+        assert_eq!(
+            ConversationTypes::default().to_param_value(),
+            "public_channel"
+        );
+        assert_eq!(
+            (ConversationTypes::IM | ConversationTypes::PRIVATE_CHANNEL).to_param_value(),
+            "private_channel,im"
+        );
+        assert_eq!(
+            ConversationTypes::all().to_param_value(),
+            "public_channel,private_channel,mpim,im"
+        );
+    }
+}
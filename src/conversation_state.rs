@@ -0,0 +1,277 @@
+//! Keeps the state of conversations which span several messages or modals, such as a wizard asking
+//! one question at a time, for each user in each channel or thread.
+//!
+//! States expire after a timeout, so a user who wanders off mid-way starts again next time rather
+//! than finding the conversation where they left it days ago.
+//!
+//! ```
+//! use serde_derive::{Deserialize, Serialize};
+//! use slack_api::conversation_state::{ConversationKey, ConversationState, MemoryStateStore};
+//!
+//! #[derive(Deserialize, Serialize)]
+//! enum Signup {
+//!     AskedName,
+//!     AskedTeam { name: String },
+//! }
+//!
+//! let states = ConversationState::new(MemoryStateStore::new());
+//! let key = ConversationKey::new("U1", "D1");
+//! states.set(&key, &Signup::AskedName).unwrap();
+//! match states.get(&key).unwrap() {
+//!     Some(Signup::AskedName) => {
+//!         states.set(&key, &Signup::AskedTeam { name: "Ann".to_owned() }).unwrap()
+//!     }
+//!     _ => unreachable!(),
+//! }
+//! assert!(states.finish(&key).unwrap().is_some());
+//! ```
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::marker::PhantomData;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::events::Event;
+use crate::Message;
+
+/// How long a conversation's state is kept after it was last set, by default.
+pub const DEFAULT_STATE_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// Identifies a conversation: a user in a channel, or in one of its threads.
+#[derive(Clone, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
+pub struct ConversationKey {
+    pub user: String,
+    pub channel: String,
+    /// The thread the conversation is in, if it's kept to one.
+    pub thread_ts: Option<String>,
+}
+
+impl ConversationKey {
+    pub fn new<U: Into<String>, C: Into<String>>(user: U, channel: C) -> Self {
+        ConversationKey {
+            user: user.into(),
+            channel: channel.into(),
+            thread_ts: None,
+        }
+    }
+
+    /// Keeps the conversation to a thread.
+    pub fn in_thread<T: ToString>(mut self, thread_ts: T) -> Self {
+        self.thread_ts = Some(thread_ts.to_string());
+        self
+    }
+
+    /// The conversation a message or mention belongs to, in its thread if it was posted in one.
+    pub fn from_event(event: &Event) -> Option<Self> {
+        let (user, channel, thread_ts) = match *event {
+            Event::Message(ref message) => match **message {
+                Message::Standard(ref m) => (&m.user, &m.channel, &m.thread_ts),
+                _ => return None,
+            },
+            Event::AppMention(ref m) => (&m.user, &m.channel, &m.thread_ts),
+            _ => return None,
+        };
+        let key = ConversationKey::new(user.as_deref()?, channel.as_deref()?);
+        Some(match *thread_ts {
+            Some(ts) => key.in_thread(ts),
+            None => key,
+        })
+    }
+}
+
+/// A conversation's state as it's stored.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct StoredState {
+    pub state: Value,
+    /// When the state expires, in microseconds since the Unix epoch.
+    pub expires_at: u64,
+}
+
+/// Where a [`ConversationState`] keeps the states of conversations.
+pub trait StateStore {
+    type Error: Error + Send + Sync + 'static;
+
+    fn get(&self, key: &ConversationKey) -> Result<Option<StoredState>, Self::Error>;
+
+    /// Stores `state`, replacing any state the conversation had.
+    fn put(&self, key: &ConversationKey, state: &StoredState) -> Result<(), Self::Error>;
+
+    /// Removes the conversation's state, if it has one.
+    fn remove(&self, key: &ConversationKey) -> Result<(), Self::Error>;
+}
+
+/// Keeps states in memory. They're lost when the process exits, which is often fine for
+/// conversations expiring after minutes anyway.
+#[derive(Debug, Default)]
+pub struct MemoryStateStore {
+    states: Mutex<HashMap<ConversationKey, StoredState>>,
+}
+
+impl MemoryStateStore {
+    pub fn new() -> Self {
+        MemoryStateStore::default()
+    }
+}
+
+impl StateStore for MemoryStateStore {
+    type Error = io::Error;
+
+    fn get(&self, key: &ConversationKey) -> Result<Option<StoredState>, Self::Error> {
+        Ok(self.states.lock().unwrap().get(key).cloned())
+    }
+
+    fn put(&self, key: &ConversationKey, state: &StoredState) -> Result<(), Self::Error> {
+        let mut states = self.states.lock().unwrap();
+        // Expired states are otherwise only removed when their conversation is looked up.
+        let now = now_micros();
+        states.retain(|_, state| state.expires_at > now);
+        states.insert(key.clone(), state.clone());
+        Ok(())
+    }
+
+    fn remove(&self, key: &ConversationKey) -> Result<(), Self::Error> {
+        self.states.lock().unwrap().remove(key);
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub enum StateError<E: Error> {
+    /// The store failed.
+    Store(E),
+    /// The state couldn't be converted to or from JSON, such as a state stored by an older version
+    /// of the app.
+    Json(serde_json::Error),
+}
+
+impl<E: Error> fmt::Display for StateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            StateError::Store(ref e) => write!(f, "Storing the conversation state failed: {}", e),
+            StateError::Json(ref e) => write!(f, "Invalid conversation state: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for StateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            StateError::Store(ref e) => Some(e),
+            StateError::Json(ref e) => Some(e),
+        }
+    }
+}
+
+/// The states of conversations, of type `S`, kept in a [`StateStore`] until they time out.
+pub struct ConversationState<S, St> {
+    store: St,
+    timeout: Duration,
+    state: PhantomData<fn() -> S>,
+}
+
+impl<S, St> ConversationState<S, St>
+where
+    S: Serialize + DeserializeOwned,
+    St: StateStore,
+{
+    pub fn new(store: St) -> Self {
+        ConversationState {
+            store,
+            timeout: DEFAULT_STATE_TIMEOUT,
+            state: PhantomData,
+        }
+    }
+
+    /// How long a state is kept after it was last set. Defaults to [`DEFAULT_STATE_TIMEOUT`].
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn store(&self) -> &St {
+        &self.store
+    }
+
+    /// The conversation's state, unless it has none or it has timed out.
+    pub fn get(&self, key: &ConversationKey) -> Result<Option<S>, StateError<St::Error>> {
+        let stored = match self.store.get(key).map_err(StateError::Store)? {
+            Some(stored) => stored,
+            None => return Ok(None),
+        };
+        if stored.expires_at <= now_micros() {
+            self.store.remove(key).map_err(StateError::Store)?;
+            return Ok(None);
+        }
+        serde_json::from_value(stored.state)
+            .map(Some)
+            .map_err(StateError::Json)
+    }
+
+    /// Sets the conversation's state, starting its timeout again.
+    pub fn set(&self, key: &ConversationKey, state: &S) -> Result<(), StateError<St::Error>> {
+        let stored = StoredState {
+            state: serde_json::to_value(state).map_err(StateError::Json)?,
+            expires_at: now_micros().saturating_add(self.timeout.as_micros() as u64),
+        };
+        self.store.put(key, &stored).map_err(StateError::Store)
+    }
+
+    /// Ends the conversation, returning the state it was in unless it had timed out.
+    pub fn finish(&self, key: &ConversationKey) -> Result<Option<S>, StateError<St::Error>> {
+        let state = self.get(key)?;
+        self.store.remove(key).map_err(StateError::Store)?;
+        Ok(state)
+    }
+}
+
+fn now_micros() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_micros() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_states_are_per_conversation_and_expire() {
+        let states = ConversationState::<u32, _>::new(MemoryStateStore::new());
+        let dm = ConversationKey::new("U1", "D1");
+        let thread = ConversationKey::new("U1", "C1").in_thread("1.000000");
+        states.set(&dm, &1).unwrap();
+        states.set(&thread, &2).unwrap();
+        assert_eq!(states.get(&dm).unwrap(), Some(1));
+        assert_eq!(states.finish(&thread).unwrap(), Some(2));
+        assert_eq!(states.get(&thread).unwrap(), None);
+        assert!(states
+            .get(&ConversationKey::new("U1", "C1"))
+            .unwrap()
+            .is_none());
+
+        let states = states.timeout(Duration::from_secs(0));
+        states.set(&dm, &3).unwrap();
+        assert_eq!(states.get(&dm).unwrap(), None);
+        assert!(states.store().states.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_key_from_event() {
+        let event: Event = serde_json::from_str(
+            r#"{"type": "message", "user": "U1", "channel": "C1", "text": "yes",
+                "ts": "2.000000", "thread_ts": "1.000000"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            ConversationKey::from_event(&event),
+            Some(ConversationKey::new("U1", "C1").in_thread("1.000000"))
+        );
+    }
+}
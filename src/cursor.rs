@@ -0,0 +1,45 @@
+use serde::Deserialize;
+
+/// An opaque position in a paginated list, from a response's `response_metadata.next_cursor`.
+///
+/// Pass it back as the `cursor` of the next request to the same method with the same token; a
+/// cursor means nothing to any other method. Slack marks the last page with an empty cursor,
+/// which is read as `None`, so a `Some` cursor always has another page behind it.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Cursor(String);
+
+impl Cursor {
+    /// Resumes from a cursor saved with [`as_str`](#method.as_str).
+    pub fn from_raw<S: Into<String>>(cursor: S) -> Self {
+        Cursor(cursor.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Reads a `next_cursor`, treating Slack's empty end-of-list cursor as `None`.
+pub(crate) fn next_cursor<'de, D>(deserializer: D) -> Result<Option<Cursor>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Ok(Option::<Cursor>::deserialize(deserializer)?.filter(|cursor| !cursor.0.is_empty()))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ResponseMetadata;
+
+    #[test]
+    fn test_empty_cursor_ends_the_list() {
+        let last: ResponseMetadata = serde_json::from_str(r#"{"next_cursor": ""}"#).unwrap();
+        assert!(last.next_cursor.is_none());
+        let next: ResponseMetadata =
+            serde_json::from_str(r#"{"next_cursor": "dXNlcg=="}"#).unwrap();
+        assert_eq!(next.next_cursor.unwrap().as_str(), "dXNlcg==");
+        let missing: ResponseMetadata = serde_json::from_str("{}").unwrap();
+        assert!(missing.next_cursor.is_none());
+    }
+}
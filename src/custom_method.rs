@@ -0,0 +1,147 @@
+//! Support for calling methods the crate doesn't wrap, with typed requests and responses.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug)]
+pub enum CallMethodError<E: Error> {
+    /// The request didn't serialize to a JSON object of parameters
+    InvalidRequest(serde_json::error::Error),
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error, given as Slack's error code
+    Slack(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for CallMethodError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            CallMethodError::InvalidRequest(ref e) => write!(f, "invalid request: {}", e),
+            CallMethodError::MalformedResponse(_, ref e) => write!(f, "{}", e),
+            CallMethodError::Slack(ref s) => write!(f, "{}", s),
+            CallMethodError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for CallMethodError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CallMethodError::InvalidRequest(ref e) => Some(e),
+            CallMethodError::MalformedResponse(_, ref e) => Some(e),
+            CallMethodError::Client(ref inner) => Some(inner),
+            CallMethodError::Slack(_) => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Status {
+    #[serde(default)]
+    ok: bool,
+    error: Option<String>,
+}
+
+/// Flattens a request's fields into query parameters the way the generated functions send them:
+/// `None` fields are left out, booleans become `1`/`0` and nested values are sent as JSON.
+pub(crate) fn to_params<T>(request: &T) -> Result<Vec<(String, String)>, serde_json::Error>
+where
+    T: Serialize + ?Sized,
+{
+    let fields = match serde_json::to_value(request)? {
+        Value::Object(fields) => fields,
+        Value::Null => return Ok(Vec::new()),
+        other => {
+            return Err(serde::ser::Error::custom(format!(
+                "expected a struct or map of parameters, got {}",
+                other
+            )))
+        }
+    };
+    Ok(fields
+        .into_iter()
+        .filter_map(|(name, value)| {
+            let value = match value {
+                Value::Null => return None,
+                Value::Bool(b) => (if b { "1" } else { "0" }).to_owned(),
+                Value::String(s) => s,
+                Value::Number(n) => n.to_string(),
+                nested => nested.to_string(),
+            };
+            Some((name, value))
+        })
+        .collect())
+}
+
+/// Checks a response's `ok` flag, passing the body through if it's set.
+pub(crate) fn check_ok<E: Error>(result: String) -> Result<String, CallMethodError<E>> {
+    match serde_json::from_str::<Status>(&result) {
+        Ok(Status { ok: true, .. }) => Ok(result),
+        Ok(Status { error, .. }) => Err(CallMethodError::Slack(error.unwrap_or_default())),
+        Err(e) => Err(CallMethodError::MalformedResponse(result, e)),
+    }
+}
+
+/// Checks a response's `ok` flag before deserializing it as `T`.
+pub(crate) fn parse_response<T, E>(body: &[u8]) -> Result<T, CallMethodError<E>>
+where
+    T: DeserializeOwned,
+    E: Error,
+{
+    let malformed =
+        |e| CallMethodError::MalformedResponse(String::from_utf8_lossy(body).into_owned(), e);
+    match serde_json::from_slice::<Status>(body) {
+        Ok(Status { ok: true, .. }) => serde_json::from_slice::<T>(body).map_err(malformed),
+        Ok(Status { error, .. }) => Err(CallMethodError::Slack(error.unwrap_or_default())),
+        Err(e) => Err(malformed(e)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Request<'a> {
+        channel: &'a str,
+        limit: Option<u32>,
+        cursor: Option<&'a str>,
+        inclusive: bool,
+        tags: &'a [&'a str],
+    }
+
+    #[test]
+    fn test_to_params_flattens_fields() {
+        let mut params = to_params(&Request {
+            channel: "C1",
+            limit: Some(10),
+            cursor: None,
+            inclusive: true,
+            tags: &["a", "b"],
+        })
+        .unwrap();
+        params.sort();
+        assert_eq!(
+            params,
+            vec![
+                ("channel".to_owned(), "C1".to_owned()),
+                ("inclusive".to_owned(), "1".to_owned()),
+                ("limit".to_owned(), "10".to_owned()),
+                ("tags".to_owned(), r#"["a","b"]"#.to_owned()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_response_checks_ok() {
+        match parse_response::<Value, std::io::Error>(br#"{"ok": false, "error": "nope"}"#) {
+            Err(CallMethodError::Slack(error)) => assert_eq!(error, "nope"),
+            other => panic!("expected a Slack error but got {:?}", other),
+        }
+    }
+}
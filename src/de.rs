@@ -0,0 +1,121 @@
+//! Lenient deserializers for fields Slack doesn't always send as the same JSON type. The generated
+//! types use them for the fields listed in the codegen, and they can be used with
+//! `#[serde(deserialize_with = "...")]` on an app's own types.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de::{DeserializeOwned, Deserializer, Error};
+use serde::Deserialize;
+use serde_json::Value;
+
+/// Reads a number sent either as a JSON number or as a string holding one, such as the counts and
+/// times Slack sometimes quotes.
+pub fn string_or_number<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + DeserializeOwned,
+    T::Err: Display,
+{
+    match Value::deserialize(deserializer)? {
+        Value::String(s) => s.trim().parse().map_err(D::Error::custom),
+        value => T::deserialize(value).map_err(D::Error::custom),
+    }
+}
+
+/// The same for optional fields, reading `null` and an empty string as `None`. Pair it with
+/// `#[serde(default)]` so a missing field is `None` too.
+pub fn optional_string_or_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr + DeserializeOwned,
+    T::Err: Display,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(None),
+        Value::String(ref s) if s.trim().is_empty() => Ok(None),
+        value => string_or_number(value).map(Some).map_err(D::Error::custom),
+    }
+}
+
+/// Reads a flag sent as a JSON bool, as `0` or `1`, or as a string holding any of those, such as
+/// the role flags of users in some admin responses. Pair it with `#[serde(default)]` so a missing
+/// flag is `false`.
+pub fn lenient_bool<'de, D>(deserializer: D) -> Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    optional_lenient_bool(deserializer).map(Option::unwrap_or_default)
+}
+
+/// The same for optional flags, reading `null` and an empty string as `None`. Pair it with
+/// `#[serde(default)]` so a missing flag is `None` too.
+pub fn optional_lenient_bool<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = Value::deserialize(deserializer)?;
+    let flag = match value {
+        Value::Null => return Ok(None),
+        Value::Bool(b) => Some(b),
+        Value::Number(ref n) => match n.as_u64() {
+            Some(0) => Some(false),
+            Some(1) => Some(true),
+            _ => None,
+        },
+        Value::String(ref s) => match s.trim() {
+            "" => return Ok(None),
+            "true" | "1" => Some(true),
+            "false" | "0" => Some(false),
+            _ => None,
+        },
+        _ => None,
+    };
+    flag.map(Some)
+        .ok_or_else(|| D::Error::custom(format!("expected a flag but got: {}", value)))
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_string_or_number() {
+        let paging: crate::Paging =
+            serde_json::from_str(r#"{"count": "20", "total": 41, "page": null, "pages": ""}"#)
+                .unwrap();
+        assert_eq!(paging.count, Some(20));
+        assert_eq!(paging.total, Some(41));
+        assert_eq!(paging.page, None);
+        assert_eq!(paging.pages, None);
+
+        let paging: crate::Paging = serde_json::from_str("{}").unwrap();
+        assert_eq!(paging.count, None);
+        assert!(serde_json::from_str::<crate::Paging>(r#"{"count": "twenty"}"#).is_err());
+    }
+
+    #[test]
+    fn test_lenient_bool() {
+        let user: crate::User = serde_json::from_str(
+            r#"{"is_admin": 1, "is_owner": "false", "is_primary_owner": "1",
+                "is_restricted": false}"#,
+        )
+        .unwrap();
+        assert_eq!(user.is_admin, Some(true));
+        assert_eq!(user.is_owner, Some(false));
+        assert_eq!(user.is_primary_owner, Some(true));
+        assert_eq!(user.is_restricted, Some(false));
+        assert_eq!(user.is_ultra_restricted, None);
+        assert!(serde_json::from_str::<crate::User>(r#"{"is_admin": 2}"#).is_err());
+
+        #[derive(Deserialize)]
+        struct Flag {
+            #[serde(default, deserialize_with = "super::lenient_bool")]
+            on: bool,
+        }
+        assert!(
+            serde_json::from_str::<Flag>(r#"{"on": "true"}"#)
+                .unwrap()
+                .on
+        );
+        assert!(!serde_json::from_str::<Flag>("{}").unwrap().on);
+    }
+}
@@ -0,0 +1,386 @@
+//! Events delivered by the Events API and the Real Time Messaging API.
+//!
+//! Only the most commonly handled events are typed; anything else is kept as
+//! [`Event::Other`] so that new event types from Slack don't fail to deserialize.
+
+use serde_json::Value;
+
+use crate::{Identity, Message};
+
+#[derive(Clone, Debug)]
+pub enum Event {
+    Message(Box<crate::Message>),
+    AppMention(AppMention),
+    AppHomeOpened(AppHomeOpened),
+    AppUninstalled(AppUninstalled),
+    MemberJoinedChannel(MemberJoinedChannel),
+    MemberLeftChannel(MemberLeftChannel),
+    PresenceChange(PresenceChange),
+    ReactionAdded(ReactionEvent),
+    ReactionRemoved(ReactionEvent),
+    TokensRevoked(TokensRevoked),
+    /// An event of a type that isn't typed by this library.
+    Other(Value),
+}
+
+impl Event {
+    /// The `type` of the event, e.g. `"message"`.
+    pub fn event_type(&self) -> Option<&str> {
+        match *self {
+            Event::Message(_) => Some("message"),
+            Event::AppMention(_) => Some("app_mention"),
+            Event::AppHomeOpened(_) => Some("app_home_opened"),
+            Event::AppUninstalled(_) => Some("app_uninstalled"),
+            Event::MemberJoinedChannel(_) => Some("member_joined_channel"),
+            Event::MemberLeftChannel(_) => Some("member_left_channel"),
+            Event::PresenceChange(_) => Some("presence_change"),
+            Event::ReactionAdded(_) => Some("reaction_added"),
+            Event::ReactionRemoved(_) => Some("reaction_removed"),
+            Event::TokensRevoked(_) => Some("tokens_revoked"),
+            Event::Other(ref value) => value.get("type").and_then(Value::as_str),
+        }
+    }
+
+    /// The user who caused the event, such as a message's author or the user who reacted. For an
+    /// edited message, the author of the message.
+    pub fn user(&self) -> Option<&str> {
+        match *self {
+            Event::Message(ref message) => message_author(message).0,
+            Event::AppMention(AppMention { ref user, .. })
+            | Event::AppHomeOpened(AppHomeOpened { ref user, .. })
+            | Event::MemberJoinedChannel(MemberJoinedChannel { ref user, .. })
+            | Event::MemberLeftChannel(MemberLeftChannel { ref user, .. })
+            | Event::PresenceChange(PresenceChange { ref user, .. })
+            | Event::ReactionAdded(ReactionEvent { ref user, .. })
+            | Event::ReactionRemoved(ReactionEvent { ref user, .. }) => user.as_deref(),
+            Event::AppUninstalled(_) | Event::TokensRevoked(_) => None,
+            Event::Other(ref value) => value.get("user").and_then(Value::as_str),
+        }
+    }
+
+    /// The bot which posted a message, for messages from bots and apps.
+    pub fn bot_id(&self) -> Option<&str> {
+        match *self {
+            Event::Message(ref message) => message_author(message).1,
+            Event::Other(ref value) => value.get("bot_id").and_then(Value::as_str),
+            _ => None,
+        }
+    }
+}
+
+/// The user and bot which posted a message, or which posted the message an edit changed.
+pub(crate) fn message_author(message: &Message) -> (Option<&str>, Option<&str>) {
+    let (user, bot_id) = match *message {
+        Message::Standard(ref m) => (&m.user, &m.bot_id),
+        Message::BotMessage(ref m) => (&None, &m.bot_id),
+        Message::FileShare(ref m) => (&m.user, &m.bot_id),
+        Message::ThreadBroadcast(ref m) => (&m.user, &m.bot_id),
+        Message::MeMessage(ref m) => (&m.user, &None),
+        Message::ReplyBroadcast(ref m) => (&m.user, &None),
+        Message::MessageChanged(ref m) => match m.message {
+            Some(ref m) => (&m.user, &m.bot_id),
+            None => (&None, &None),
+        },
+        Message::Other(ref value) => {
+            return (
+                value.get("user").and_then(Value::as_str),
+                value.get("bot_id").and_then(Value::as_str),
+            )
+        }
+        _ => (&None, &None),
+    };
+    (user.as_deref(), bot_id.as_deref())
+}
+
+/// Picks out the events a bot caused itself, such as its own messages, so that a bot replying to
+/// messages doesn't reply to its replies. It can also pick out the messages of every bot, to keep
+/// two bots from replying to each other.
+///
+/// Use [`is_ignored`](#method.is_ignored) to filter a stream of events, or wrap a handler in
+/// [`SkipOwnEvents`].
+#[derive(Clone, Debug, Default)]
+pub struct SelfFilter {
+    user_id: Option<String>,
+    bot_id: Option<String>,
+    ignore_bots: bool,
+}
+
+impl SelfFilter {
+    /// Ignores the events of the bot a token belongs to, as looked up by `SlackClient::whoami`.
+    pub fn new(identity: &Identity) -> Self {
+        SelfFilter {
+            user_id: Some(identity.user_id.clone()).filter(|id| !id.is_empty()),
+            bot_id: identity.bot_id.clone(),
+            ignore_bots: false,
+        }
+    }
+
+    /// Ignores the messages of every bot and app, not just this one.
+    pub fn ignore_bots(mut self) -> Self {
+        self.ignore_bots = true;
+        self
+    }
+
+    /// Whether the event was caused by this bot, or by any bot if they're all ignored.
+    pub fn is_ignored(&self, event: &Event) -> bool {
+        let bot_id = event.bot_id();
+        if self.ignore_bots && bot_id.is_some() {
+            return true;
+        }
+        let is_self =
+            |own: &Option<String>, id: Option<&str>| own.is_some() && own.as_deref() == id;
+        is_self(&self.user_id, event.user()) || is_self(&self.bot_id, bot_id)
+    }
+}
+
+/// Wraps an RTM or Events API handler, passing it only the events a [`SelfFilter`] doesn't ignore.
+pub struct SkipOwnEvents<H> {
+    filter: SelfFilter,
+    handler: H,
+}
+
+impl<H> SkipOwnEvents<H> {
+    pub fn new(filter: SelfFilter, handler: H) -> Self {
+        SkipOwnEvents { filter, handler }
+    }
+
+    pub fn filter(&self) -> &SelfFilter {
+        &self.filter
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+}
+
+#[cfg(feature = "realtime")]
+#[async_trait::async_trait]
+impl<H: crate::realtime::RtmHandler> crate::realtime::RtmHandler for SkipOwnEvents<H> {
+    async fn event(&self, event: Event) {
+        if !self.filter.is_ignored(&event) {
+            self.handler.event(event).await
+        }
+    }
+}
+
+#[cfg(feature = "server")]
+#[async_trait::async_trait]
+impl<H: crate::server::Handler> crate::server::Handler for SkipOwnEvents<H> {
+    async fn event(&self, callback: crate::server::EventCallback) {
+        if !self.filter.is_ignored(&callback.event) {
+            self.handler.event(callback).await
+        }
+    }
+
+    async fn slash_command(&self, command: crate::server::SlashCommand) -> crate::server::Ack {
+        self.handler.slash_command(command).await
+    }
+
+    async fn interaction(&self, payload: crate::server::InteractionPayload) -> crate::server::Ack {
+        self.handler.interaction(payload).await
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for Event {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        use ::serde::de::Error as SerdeError;
+
+        fn parse<T, E>(value: Value, f: fn(T) -> Event) -> Result<Event, E>
+        where
+            T: ::serde::de::DeserializeOwned,
+            E: SerdeError,
+        {
+            ::serde_json::from_value::<T>(value)
+                .map(f)
+                .map_err(E::custom)
+        }
+
+        let value = Value::deserialize(deserializer)?;
+        let ty = value.get("type").and_then(Value::as_str).map(str::to_owned);
+        match ty.as_deref() {
+            Some("message") => parse(value, |m| Event::Message(Box::new(m))),
+            Some("app_mention") => parse(value, Event::AppMention),
+            Some("app_home_opened") => parse(value, Event::AppHomeOpened),
+            Some("app_uninstalled") => parse(value, Event::AppUninstalled),
+            Some("member_joined_channel") => parse(value, Event::MemberJoinedChannel),
+            Some("member_left_channel") => parse(value, Event::MemberLeftChannel),
+            Some("presence_change") => parse(value, Event::PresenceChange),
+            Some("reaction_added") => parse(value, Event::ReactionAdded),
+            Some("reaction_removed") => parse(value, Event::ReactionRemoved),
+            Some("tokens_revoked") => parse(value, Event::TokensRevoked),
+            Some(_) => Ok(Event::Other(value)),
+            None => Err(D::Error::missing_field("type")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AppMention {
+    pub channel: Option<String>,
+    pub event_ts: Option<crate::Timestamp>,
+    pub team: Option<String>,
+    pub text: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
+    pub ts: Option<crate::Timestamp>,
+    pub user: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AppHomeOpened {
+    pub channel: Option<String>,
+    pub event_ts: Option<crate::Timestamp>,
+    pub tab: Option<String>,
+    pub user: Option<String>,
+    pub view: Option<Value>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct AppUninstalled {
+    pub event_ts: Option<crate::Timestamp>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MemberJoinedChannel {
+    pub channel: Option<String>,
+    pub channel_type: Option<String>,
+    pub event_ts: Option<crate::Timestamp>,
+    pub inviter: Option<String>,
+    pub team: Option<String>,
+    pub user: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MemberLeftChannel {
+    pub channel: Option<String>,
+    pub channel_type: Option<String>,
+    pub event_ts: Option<crate::Timestamp>,
+    pub team: Option<String>,
+    pub user: Option<String>,
+}
+
+/// A change in presence of one or more users, sent over RTM for users subscribed to with
+/// `presence_sub`.
+///
+/// Slack batches changes for several users with the same presence into one event, setting `users`
+/// instead of `user`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct PresenceChange {
+    pub presence: String,
+    pub user: Option<String>,
+    pub users: Option<Vec<String>>,
+}
+
+impl PresenceChange {
+    /// All of the users whose presence changed.
+    pub fn users(&self) -> impl Iterator<Item = &str> {
+        self.user
+            .iter()
+            .chain(self.users.iter().flatten())
+            .map(String::as_str)
+    }
+}
+
+/// A `reaction_added` or `reaction_removed` event.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReactionEvent {
+    pub event_ts: Option<crate::Timestamp>,
+    pub item: Option<ReactionEventItem>,
+    pub item_user: Option<String>,
+    pub reaction: Option<String>,
+    pub user: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct ReactionEventItem {
+    pub channel: Option<String>,
+    pub file: Option<String>,
+    pub file_comment: Option<String>,
+    pub ts: Option<crate::Timestamp>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TokensRevoked {
+    pub event_ts: Option<crate::Timestamp>,
+    pub tokens: Option<TokensRevokedTokens>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct TokensRevokedTokens {
+    pub bot: Option<Vec<String>>,
+    pub oauth: Option<Vec<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_event_message_deserialize() {
+        let event: Event = serde_json::from_str(
+            r#"{"type": "message", "channel": "C1", "user": "U1", "text": "hi", "ts": "1.000001"}"#,
+        )
+        .unwrap();
+        match event {
+            Event::Message(ref m) => match **m {
+                crate::Message::Standard(ref m) => assert_eq!(m.text.as_deref(), Some("hi")),
+                ref m => panic!("expected a standard message but got {:?}", m),
+            },
+            e => panic!("expected Event::Message but got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_event_presence_change_batched() {
+        let event: Event = serde_json::from_str(
+            r#"{"type": "presence_change", "users": ["U1", "U2"], "presence": "away"}"#,
+        )
+        .unwrap();
+        match event {
+            Event::PresenceChange(ref p) => {
+                assert_eq!(p.users().collect::<Vec<_>>(), vec!["U1", "U2"]);
+                assert_eq!(p.presence, "away");
+            }
+            e => panic!("expected Event::PresenceChange but got {:?}", e),
+        }
+    }
+
+    #[test]
+    fn test_self_filter() {
+        let identity = Identity {
+            user_id: "U1".to_owned(),
+            bot_id: Some("B1".to_owned()),
+            ..Default::default()
+        };
+        let events: Vec<Event> = serde_json::from_str(
+            r#"[
+                {"type": "message", "user": "U1", "bot_id": "B1", "text": "pong", "ts": "1.0"},
+                {"type": "message", "subtype": "message_changed", "ts": "2.0",
+                 "message": {"user": "U1", "text": "pong!", "ts": "1.0"}},
+                {"type": "reaction_added", "user": "U1", "reaction": "eyes"},
+                {"type": "message", "subtype": "bot_message", "bot_id": "B2", "text": "ping",
+                 "ts": "3.0"},
+                {"type": "message", "user": "U2", "text": "ping", "ts": "4.0"}
+            ]"#,
+        )
+        .unwrap();
+        let kept = |filter: &SelfFilter| events.iter().filter(|e| !filter.is_ignored(e)).count();
+
+        let filter = SelfFilter::new(&identity);
+        assert_eq!(kept(&filter), 2);
+        assert_eq!(kept(&filter.ignore_bots()), 1);
+        assert_eq!(kept(&SelfFilter::default()), 5);
+    }
+
+    #[test]
+    fn test_event_unknown_type_deserialize() {
+        let event: Event =
+            serde_json::from_str(r#"{"type": "team_join", "user": {"id": "U1"}}"#).unwrap();
+        assert_eq!(event.event_type(), Some("team_join"));
+        assert!(matches!(event, Event::Other(_)));
+    }
+}
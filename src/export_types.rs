@@ -0,0 +1,356 @@
+//! Types shared by the helpers which archive a workspace.
+
+use std::collections::BTreeSet;
+use std::error::Error;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::rate_limit::RetryPolicy;
+use crate::{CallMethodError, Timestamp};
+
+/// How long to wait between requests by default, keeping within the rate limit of around 50
+/// requests per minute of `conversations.history` and the other methods an export calls.
+pub const DEFAULT_EXPORT_INTERVAL: Duration = Duration::from_millis(1200);
+
+/// The directory, within an export, holding a file of messages for each channel.
+pub const MESSAGES_DIR: &str = "messages";
+
+const CHECKPOINT_FILE: &str = "checkpoint.json";
+
+#[derive(Clone, Debug)]
+pub struct ExportRequest<'a> {
+    /// Directory to write the export to, created if it doesn't exist. Exporting into the
+    /// directory of an interrupted export carries on where it stopped.
+    pub dir: &'a Path,
+    /// Types of conversations to export, as for `conversations.list`. Defaults to public channels.
+    pub types: Option<&'a str>,
+    /// Only export messages posted after this time.
+    pub oldest: Option<Timestamp>,
+    /// How long to wait between requests. Defaults to [`DEFAULT_EXPORT_INTERVAL`].
+    pub interval: Option<Duration>,
+    /// How rate limited requests are retried. Defaults to [`RetryPolicy::new()`].
+    pub retry_policy: Option<RetryPolicy>,
+    /// Pass true to write a Parquet file next to each JSON Lines file.
+    #[cfg(feature = "parquet")]
+    pub parquet: bool,
+}
+
+impl<'a> ExportRequest<'a> {
+    /// Exports public channels into `dir`.
+    pub fn new(dir: &'a Path) -> Self {
+        ExportRequest {
+            dir,
+            types: None,
+            oldest: None,
+            interval: None,
+            retry_policy: None,
+            #[cfg(feature = "parquet")]
+            parquet: false,
+        }
+    }
+}
+
+/// How many records an export wrote. Parts finished by an earlier, interrupted export aren't
+/// counted again.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ExportSummary {
+    pub users: usize,
+    pub conversations: usize,
+    /// Messages, including thread replies.
+    pub messages: usize,
+    pub files: usize,
+    pub emoji: usize,
+    /// Channels whose history couldn't be read because the caller isn't a member. Exporting again
+    /// after joining them picks them up.
+    pub skipped_channels: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum ExportError<E: Error> {
+    /// Calling a method failed.
+    Slack {
+        method: &'static str,
+        error: CallMethodError<E>,
+    },
+    /// Reading or writing the export directory failed.
+    Io(io::Error),
+    /// Writing a Parquet file failed.
+    #[cfg(feature = "parquet")]
+    Parquet(parquet_::errors::ParquetError),
+}
+
+impl<E: Error> From<io::Error> for ExportError<E> {
+    fn from(e: io::Error) -> Self {
+        ExportError::Io(e)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl<E: Error> From<parquet_::errors::ParquetError> for ExportError<E> {
+    fn from(e: parquet_::errors::ParquetError) -> Self {
+        ExportError::Parquet(e)
+    }
+}
+
+impl<E: Error> fmt::Display for ExportError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ExportError::Slack { method, ref error } => write!(f, "{} failed: {}", method, error),
+            ExportError::Io(ref e) => write!(f, "Writing the export failed: {}", e),
+            #[cfg(feature = "parquet")]
+            ExportError::Parquet(ref e) => write!(f, "Writing Parquet failed: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ExportError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ExportError::Slack { ref error, .. } => Some(error),
+            ExportError::Io(ref e) => Some(e),
+            #[cfg(feature = "parquet")]
+            ExportError::Parquet(ref e) => Some(e),
+        }
+    }
+}
+
+/// The parameters of the paginated methods an export calls.
+#[derive(Clone, Copy, Default, Serialize)]
+pub(crate) struct PageRequest<'a> {
+    pub channel: Option<&'a str>,
+    pub ts: Option<&'a str>,
+    pub types: Option<&'a str>,
+    pub oldest: Option<&'a str>,
+    pub exclude_archived: Option<bool>,
+    pub cursor: Option<&'a str>,
+    pub limit: Option<u32>,
+    pub page: Option<u32>,
+    pub count: Option<u32>,
+}
+
+/// A file of an export, with the fields copied into columns of its Parquet counterpart.
+pub(crate) struct Part {
+    pub name: &'static str,
+    pub columns: &'static [&'static str],
+}
+
+pub(crate) const USERS: Part = Part {
+    name: "users",
+    columns: &["id", "name", "real_name"],
+};
+
+pub(crate) const CONVERSATIONS: Part = Part {
+    name: "conversations",
+    columns: &["id", "name"],
+};
+
+pub(crate) const EMOJI: Part = Part {
+    name: "emoji",
+    columns: &["name", "url"],
+};
+
+pub(crate) const FILES: Part = Part {
+    name: "files",
+    columns: &["id", "name", "user", "created"],
+};
+
+pub(crate) const MESSAGE_COLUMNS: &[&str] = &["ts", "thread_ts", "user", "text"];
+
+/// Records which parts of an export are finished, so an interrupted export can carry on.
+#[derive(Default, Deserialize, Serialize)]
+pub(crate) struct Checkpoint {
+    parts: BTreeSet<String>,
+    channels: BTreeSet<String>,
+}
+
+impl Checkpoint {
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        match fs::read(dir.join(CHECKPOINT_FILE)) {
+            Ok(bytes) => Ok(serde_json::from_slice(&bytes)?),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(Checkpoint::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn is_done(&self, part: &Part) -> bool {
+        self.parts.contains(part.name)
+    }
+
+    pub fn is_channel_done(&self, channel: &str) -> bool {
+        self.channels.contains(channel)
+    }
+
+    pub fn done(&mut self, dir: &Path, part: &Part) -> io::Result<()> {
+        self.parts.insert(part.name.to_owned());
+        self.save(dir)
+    }
+
+    pub fn channel_done(&mut self, dir: &Path, channel: &str) -> io::Result<()> {
+        self.channels.insert(channel.to_owned());
+        self.save(dir)
+    }
+
+    /// Replaces the checkpoint file whole, so an export killed mid-write can still resume.
+    fn save(&self, dir: &Path) -> io::Result<()> {
+        let tmp = dir.join(format!("{}.tmp", CHECKPOINT_FILE));
+        fs::write(&tmp, serde_json::to_vec(self)?)?;
+        fs::rename(tmp, dir.join(CHECKPOINT_FILE))
+    }
+}
+
+/// Writes records to a JSON Lines file of an export, one JSON object per line.
+pub(crate) struct JsonLines {
+    path: PathBuf,
+    columns: &'static [&'static str],
+    writer: BufWriter<File>,
+    count: usize,
+}
+
+impl JsonLines {
+    /// Creates `name.jsonl` in `dir`, replacing what an interrupted export left there.
+    pub fn create(dir: &Path, name: &str, columns: &'static [&'static str]) -> io::Result<Self> {
+        let path = dir.join(format!("{}.jsonl", name));
+        Ok(JsonLines {
+            writer: BufWriter::new(File::create(&path)?),
+            path,
+            columns,
+            count: 0,
+        })
+    }
+
+    pub fn write(&mut self, record: &Value) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, record)?;
+        self.writer.write_all(b"\n")?;
+        self.count += 1;
+        Ok(())
+    }
+
+    /// Flushes the file, writes its Parquet counterpart if asked to, and returns how many records
+    /// it holds.
+    pub fn finish<E: Error>(
+        mut self,
+        request: &ExportRequest<'_>,
+    ) -> Result<usize, ExportError<E>> {
+        self.writer.flush()?;
+        #[cfg(feature = "parquet")]
+        {
+            if request.parquet {
+                write_parquet(&self.path, self.columns)?;
+            }
+        }
+        // Only needed to write Parquet.
+        #[cfg(not(feature = "parquet"))]
+        let _ = (request, &self.path, self.columns);
+        Ok(self.count)
+    }
+}
+
+/// Reads the `id` of each record in an export's JSON Lines file.
+pub(crate) fn read_ids(dir: &Path, part: &Part) -> io::Result<Vec<String>> {
+    let file = File::open(dir.join(format!("{}.jsonl", part.name)))?;
+    let mut ids = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let record: Value = serde_json::from_str(&line?)?;
+        if let Some(id) = record["id"].as_str() {
+            ids.push(id.to_owned());
+        }
+    }
+    Ok(ids)
+}
+
+/// Takes the array of records under `key` out of a page of results.
+pub(crate) fn take_records(page: &mut Value, key: &str) -> Vec<Value> {
+    match page[key].take() {
+        Value::Array(records) => records,
+        _ => Vec::new(),
+    }
+}
+
+/// The page's `next_cursor`, treating Slack's empty end-of-list cursor as `None`.
+pub(crate) fn next_cursor(page: &Value) -> Option<String> {
+    page["response_metadata"]["next_cursor"]
+        .as_str()
+        .filter(|cursor| !cursor.is_empty())
+        .map(str::to_owned)
+}
+
+/// The `ts` of a message which has replies, to fetch them with `conversations.replies`.
+pub(crate) fn thread_ts(message: &Value) -> Option<&str> {
+    let ts = message["ts"].as_str()?;
+    let has_replies = message["reply_count"].as_u64().is_some_and(|n| n > 0)
+        && message["thread_ts"].as_str() == Some(ts);
+    if has_replies {
+        Some(ts)
+    } else {
+        None
+    }
+}
+
+pub(crate) fn is_not_in_channel<E: Error>(error: &ExportError<E>) -> bool {
+    matches!(
+        *error,
+        ExportError::Slack {
+            error: CallMethodError::Slack(ref e),
+            ..
+        } if e == "not_in_channel"
+    )
+}
+
+/// The number of rows written to each row group of a Parquet file.
+#[cfg(feature = "parquet")]
+const PARQUET_BATCH_ROWS: usize = 10_000;
+
+/// Writes a JSON Lines file of an export as Parquet next to it. Each of `columns` holds that field
+/// of the records, as a string, and the `json` column holds the whole record.
+#[cfg(feature = "parquet")]
+fn write_parquet(jsonl: &Path, columns: &[&str]) -> Result<(), parquet_::errors::ParquetError> {
+    use arrow_array::{ArrayRef, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use parquet_::arrow::ArrowWriter;
+    use parquet_::errors::ParquetError;
+    use std::sync::Arc;
+
+    let fields = columns
+        .iter()
+        .map(|&name| Field::new(name, DataType::Utf8, true))
+        .chain(std::iter::once(Field::new("json", DataType::Utf8, false)))
+        .collect::<Vec<_>>();
+    let schema = Arc::new(Schema::new(fields));
+    let mut writer = ArrowWriter::try_new(
+        File::create(jsonl.with_extension("parquet"))?,
+        schema.clone(),
+        None,
+    )?;
+    let mut lines = BufReader::new(File::open(jsonl)?).lines().peekable();
+    while lines.peek().is_some() {
+        let batch = lines
+            .by_ref()
+            .take(PARQUET_BATCH_ROWS)
+            .collect::<io::Result<Vec<_>>>()?;
+        let records = batch
+            .iter()
+            .map(|line| serde_json::from_str::<Value>(line))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| ParquetError::External(Box::new(e)))?;
+        let mut arrays = columns
+            .iter()
+            .map(|&name| {
+                let values = records.iter().map(|record| match record[name] {
+                    Value::Null => None,
+                    Value::String(ref s) => Some(s.clone()),
+                    ref other => Some(other.to_string()),
+                });
+                Arc::new(values.collect::<StringArray>()) as ArrayRef
+            })
+            .collect::<Vec<_>>();
+        arrays.push(Arc::new(StringArray::from(batch)));
+        writer.write(&RecordBatch::try_new(schema.clone(), arrays)?)?;
+    }
+    writer.close()?;
+    Ok(())
+}
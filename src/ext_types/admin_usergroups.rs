@@ -0,0 +1,41 @@
+use std::error::Error;
+use std::fmt;
+
+pub use super::usergroups::MembershipChange;
+use crate::mod_types::admin_usergroups_types::{
+    AddChannelsError, ListChannelsError, RemoveChannelsError,
+};
+
+#[derive(Debug)]
+pub enum SyncDefaultChannelsError<E: Error> {
+    /// Fetching the current default channels failed.
+    List(ListChannelsError<E>),
+    /// Adding the missing channels failed.
+    Add(AddChannelsError<E>),
+    /// Removing the extra channels failed, after any missing ones were added.
+    Remove(RemoveChannelsError<E>),
+}
+
+impl<E: Error> fmt::Display for SyncDefaultChannelsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SyncDefaultChannelsError::List(ref e) => {
+                write!(f, "Fetching the default channels failed: {}", e)
+            }
+            SyncDefaultChannelsError::Add(ref e) => write!(f, "Adding channels failed: {}", e),
+            SyncDefaultChannelsError::Remove(ref e) => {
+                write!(f, "Removing channels failed: {}", e)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for SyncDefaultChannelsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SyncDefaultChannelsError::List(ref e) => Some(e),
+            SyncDefaultChannelsError::Add(ref e) => Some(e),
+            SyncDefaultChannelsError::Remove(ref e) => Some(e),
+        }
+    }
+}
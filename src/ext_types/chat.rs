@@ -0,0 +1,140 @@
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::error::Error;
+use std::fmt::{self, Write};
+use std::hash::{BuildHasher, Hash, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
+
+use crate::mod_types::chat_types::{
+    PostEphemeralError, PostEphemeralResponse, PostMessageError, PostMessageResponse,
+};
+use crate::mod_types::conversations_types::OpenError;
+
+/// A random version 4 UUID for the `client_msg_id` of `chat.postMessage`, the way Slack's clients
+/// generate them.
+///
+/// Keep it with the message until Slack accepts it and reuse it for retries, so the message can
+/// be recognized if a retry posts it twice.
+pub fn new_client_msg_id() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let random = || {
+        let mut hasher = RandomState::new().build_hasher();
+        COUNTER.fetch_add(1, Ordering::Relaxed).hash(&mut hasher);
+        SystemTime::now().hash(&mut hasher);
+        hasher.finish()
+    };
+    let (high, low) = (random(), random());
+    format!(
+        "{:08x}-{:04x}-{:04x}-{:04x}-{:012x}",
+        high >> 32,
+        (high >> 16) & 0xffff,
+        (high & 0x0fff) | 0x4000,
+        ((low >> 48) & 0x3fff) | 0x8000,
+        low & 0xffff_ffff_ffff
+    )
+}
+
+/// Where `post_ephemeral_or_dm` delivered a message.
+#[derive(Debug)]
+pub enum EphemeralDelivery {
+    /// The message was shown to the user in the channel.
+    Ephemeral(PostEphemeralResponse),
+    /// The user wasn't in the channel, so the message was sent to them as a direct message.
+    Direct(Box<PostMessageResponse>),
+}
+
+#[derive(Debug)]
+pub enum PostEphemeralOrDmError<E: Error> {
+    /// Posting the ephemeral message failed for a reason other than the user not being in the
+    /// channel.
+    Ephemeral(PostEphemeralError<E>),
+    /// Opening the direct message with the user failed.
+    Open(OpenError<E>),
+    /// Posting the direct message failed.
+    Direct(PostMessageError<E>),
+}
+
+impl<E: Error> fmt::Display for PostEphemeralOrDmError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PostEphemeralOrDmError::Ephemeral(ref e) => {
+                write!(f, "Posting the ephemeral message failed: {}", e)
+            }
+            PostEphemeralOrDmError::Open(ref e) => {
+                write!(f, "Opening the direct message failed: {}", e)
+            }
+            PostEphemeralOrDmError::Direct(ref e) => {
+                write!(f, "Posting the direct message failed: {}", e)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for PostEphemeralOrDmError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            PostEphemeralOrDmError::Ephemeral(ref e) => Some(e),
+            PostEphemeralOrDmError::Open(ref e) => Some(e),
+            PostEphemeralOrDmError::Direct(ref e) => Some(e),
+        }
+    }
+}
+
+/// Encodes parameters as an `application/x-www-form-urlencoded` body, for sending them in a POST
+/// rather than the query string.
+pub(crate) fn form_body<I, K, V>(params: I) -> String
+where
+    I: IntoIterator,
+    K: AsRef<str>,
+    V: AsRef<str>,
+    I::Item: Borrow<(K, V)>,
+{
+    fn encode(s: &str, body: &mut String) {
+        for &b in s.as_bytes() {
+            match b {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'*' => {
+                    body.push(b as char)
+                }
+                b' ' => body.push('+'),
+                _ => write!(body, "%{:02X}", b).unwrap(),
+            }
+        }
+    }
+
+    let mut body = String::new();
+    for param in params {
+        let (ref key, ref value) = *param.borrow();
+        if !body.is_empty() {
+            body.push('&');
+        }
+        encode(key.as_ref(), &mut body);
+        body.push('=');
+        encode(value.as_ref(), &mut body);
+    }
+    body
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_client_msg_id() {
+        let id = new_client_msg_id();
+        let groups: Vec<_> = id.split('-').map(str::len).collect();
+        assert_eq!(groups, [8, 4, 4, 4, 12]);
+        assert_eq!(id.as_bytes()[14], b'4');
+        assert!(matches!(id.as_bytes()[19], b'8' | b'9' | b'a' | b'b'));
+        assert_ne!(id, new_client_msg_id());
+    }
+
+    #[test]
+    fn test_form_body() {
+        let body = form_body(&[("token", "xoxb-1"), ("text", "a&b=c+d ünï 🚀")][..]);
+        assert_eq!(
+            body,
+            "token=xoxb-1&text=a%26b%3Dc%2Bd+%C3%BCn%C3%AF+%F0%9F%9A%80"
+        );
+    }
+}
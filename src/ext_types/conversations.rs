@@ -0,0 +1,110 @@
+use std::collections::HashSet;
+use std::error::Error;
+
+use crate::mod_types::conversations_types::{InviteError, KickError};
+use crate::UserId;
+
+/// The most users `conversations.invite` takes in one call.
+pub(crate) const MAX_INVITE_USERS: usize = 1000;
+
+/// The outcome of `invite_many` or `kick_many`.
+#[derive(Debug)]
+pub struct MembershipReport<E> {
+    /// Users invited or removed.
+    pub succeeded: Vec<UserId>,
+    /// Users Slack refused, each with its error, e.g. `AlreadyInChannel`.
+    pub failed: Vec<(UserId, E)>,
+    /// The error that stopped the batch because it wasn't about one user, e.g. `ChannelNotFound`
+    /// or a client error.
+    pub aborted: Option<E>,
+    /// Users not attempted because the batch stopped.
+    pub skipped: Vec<UserId>,
+}
+
+impl<E> MembershipReport<E> {
+    /// Whether every user was invited or removed.
+    pub fn is_complete(&self) -> bool {
+        self.failed.is_empty() && self.aborted.is_none() && self.skipped.is_empty()
+    }
+
+    /// Records the outcome of a call for `users`, stopping the batch on an error that isn't about
+    /// the one user in the call.
+    pub(crate) fn record(
+        &mut self,
+        users: &[UserId],
+        result: Result<(), E>,
+        user_error: fn(&E) -> bool,
+    ) {
+        match result {
+            Ok(()) => self.succeeded.extend_from_slice(users),
+            Err(e) if users.len() == 1 && user_error(&e) => self.failed.push((users[0].clone(), e)),
+            Err(e) => {
+                self.aborted = Some(e);
+                self.skip(users);
+            }
+        }
+    }
+
+    pub(crate) fn skip(&mut self, users: &[UserId]) {
+        self.skipped.extend_from_slice(users);
+    }
+}
+
+impl<E> Default for MembershipReport<E> {
+    fn default() -> Self {
+        MembershipReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+            aborted: None,
+            skipped: Vec::new(),
+        }
+    }
+}
+
+/// The changes that would bring a channel's members in line with the members it should have.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct MembershipDiff {
+    /// Users who should be members but aren't, sorted.
+    pub to_invite: Vec<UserId>,
+    /// Users who are members but shouldn't be, sorted.
+    pub to_kick: Vec<UserId>,
+}
+
+impl MembershipDiff {
+    /// Compares the `current` members of a channel, such as from `members_all`, with the
+    /// `desired` ones.
+    pub fn between(current: &HashSet<UserId>, desired: &HashSet<UserId>) -> Self {
+        let mut to_invite: Vec<_> = desired.difference(current).cloned().collect();
+        let mut to_kick: Vec<_> = current.difference(desired).cloned().collect();
+        to_invite.sort();
+        to_kick.sort();
+        MembershipDiff { to_invite, to_kick }
+    }
+
+    /// Whether the channel already has the members it should.
+    pub fn is_empty(&self) -> bool {
+        self.to_invite.is_empty() && self.to_kick.is_empty()
+    }
+}
+
+/// Whether an invite failed because of one of the invited users rather than the channel or the
+/// caller.
+pub(crate) fn is_user_invite_error<E: Error>(error: &InviteError<E>) -> bool {
+    matches!(
+        error,
+        InviteError::AlreadyInChannel
+            | InviteError::CantInvite
+            | InviteError::CantInviteSelf
+            | InviteError::UraMaxChannels
+            | InviteError::UserIsRestricted
+            | InviteError::UserNotFound
+    )
+}
+
+/// Whether a kick failed because of the removed user rather than the channel or the caller.
+pub(crate) fn is_user_kick_error<E: Error>(error: &KickError<E>) -> bool {
+    matches!(
+        error,
+        KickError::CantKickSelf | KickError::NotInChannel | KickError::UserNotFound
+    )
+}
@@ -0,0 +1,129 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::mod_types::chat_types::{
+    PostMessageError, PostMessageRequest, PostMessageResponse, ScheduleMessageError,
+    ScheduleMessageRequest, ScheduleMessageResponse,
+};
+use crate::mod_types::dnd_types::{InfoError, InfoResponse};
+use crate::Timestamp;
+
+/// When a user's Do Not Disturb ends, if it's on at `now`, whether they snoozed notifications
+/// or are within their Do Not Disturb schedule.
+pub fn dnd_ends_at(info: &InfoResponse, now: Timestamp) -> Option<Timestamp> {
+    let snooze = info
+        .snooze_endtime
+        .filter(|&end| info.snooze_enabled == Some(true) && end > now);
+    let scheduled = match (info.next_dnd_start_ts, info.next_dnd_end_ts) {
+        (Some(start), Some(end)) if info.dnd_enabled == Some(true) && start <= now && now < end => {
+            Some(end)
+        }
+        _ => None,
+    };
+    snooze.max(scheduled)
+}
+
+/// What `send_respecting_dnd` does with a message for a user in Do Not Disturb.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DndPolicy<'a> {
+    /// Schedules the message for when Do Not Disturb ends.
+    Delay,
+    /// Posts the message to this channel instead, such as a team channel someone else watches.
+    Fallback(&'a str),
+}
+
+/// How `send_respecting_dnd` delivered a message.
+#[derive(Debug)]
+pub enum Delivery {
+    /// The user wasn't in Do Not Disturb, so the message was sent.
+    Sent(PostMessageResponse),
+    /// The message was scheduled for when Do Not Disturb ends.
+    Scheduled(ScheduleMessageResponse),
+    /// The message was posted to the fallback channel.
+    FellBack(PostMessageResponse),
+}
+
+#[derive(Debug)]
+pub enum SendRespectingDndError<E: Error> {
+    /// Looking up the user's Do Not Disturb status failed.
+    Info(InfoError<E>),
+    /// Posting the message, or posting it to the fallback channel, failed.
+    Post(PostMessageError<E>),
+    /// Scheduling the message failed.
+    Schedule(ScheduleMessageError<E>),
+}
+
+impl<E: Error> fmt::Display for SendRespectingDndError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SendRespectingDndError::Info(ref e) => {
+                write!(f, "Looking up Do Not Disturb failed: {}", e)
+            }
+            SendRespectingDndError::Post(ref e) => write!(f, "Posting the message failed: {}", e),
+            SendRespectingDndError::Schedule(ref e) => {
+                write!(f, "Scheduling the message failed: {}", e)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for SendRespectingDndError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SendRespectingDndError::Info(ref e) => Some(e),
+            SendRespectingDndError::Post(ref e) => Some(e),
+            SendRespectingDndError::Schedule(ref e) => Some(e),
+        }
+    }
+}
+
+/// `message` scheduled for `post_at`. `chat.scheduleMessage` doesn't take `username`,
+/// `icon_url`, `icon_emoji` or `mrkdwn`, so they're dropped.
+pub(crate) fn schedule_request<'a>(
+    message: &PostMessageRequest<'a>,
+    channel: &'a str,
+    post_at: Timestamp,
+) -> ScheduleMessageRequest<'a> {
+    ScheduleMessageRequest {
+        channel,
+        // Rounded up, since Slack refuses a time in the past.
+        post_at: post_at.to_micros().div_ceil(1_000_000),
+        text: Some(message.text).filter(|text| !text.is_empty()),
+        as_user: message.as_user,
+        attachments: message.attachments,
+        blocks: message.blocks,
+        link_names: message.link_names,
+        metadata: message.metadata,
+        parse: message.parse,
+        reply_broadcast: message.reply_broadcast,
+        thread_ts: message.thread_ts,
+        unfurl_links: message.unfurl_links,
+        unfurl_media: message.unfurl_media,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dnd_ends_at() {
+        let info = |json| serde_json::from_str::<InfoResponse>(json).unwrap();
+        let now = Timestamp::from(1_000u64);
+        let snoozed = info(
+            r#"{"ok": true, "dnd_enabled": true, "next_dnd_start_ts": 2000,
+                "next_dnd_end_ts": 3000, "snooze_enabled": true, "snooze_endtime": 1500}"#,
+        );
+        assert_eq!(dnd_ends_at(&snoozed, now), Some(Timestamp::from(1_500u64)));
+        let in_schedule = info(
+            r#"{"ok": true, "dnd_enabled": true, "next_dnd_start_ts": 900,
+                "next_dnd_end_ts": 3000, "snooze_enabled": false}"#,
+        );
+        assert_eq!(
+            dnd_ends_at(&in_schedule, now),
+            Some(Timestamp::from(3_000u64))
+        );
+        assert_eq!(dnd_ends_at(&snoozed, Timestamp::from(1_500u64)), None);
+        assert_eq!(dnd_ends_at(&in_schedule, Timestamp::from(3_000u64)), None);
+    }
+}
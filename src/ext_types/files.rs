@@ -0,0 +1,175 @@
+use std::error::Error;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+
+use crate::{File, Timestamp};
+
+/// How many bytes of a request body are read and sent at a time when uploading, bounding the
+/// memory an upload takes whatever the size of the file.
+pub const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
+
+/// How much of a request body has been sent, as reported while uploading.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UploadProgress {
+    /// The bytes handed to the connection so far.
+    pub sent: u64,
+    /// The length of the whole body.
+    pub total: u64,
+}
+
+impl UploadProgress {
+    /// The share of the body sent, from 0 to 1.
+    pub fn fraction(&self) -> f64 {
+        if self.total == 0 {
+            1.0
+        } else {
+            self.sent as f64 / self.total as f64
+        }
+    }
+}
+
+/// A file to upload with `files::upload_external`, which gets an upload URL with
+/// `files.getUploadURLExternal`, sends the file to it and completes the upload with
+/// `files.completeUploadExternal`.
+#[derive(Clone, Default, Debug)]
+pub struct UploadExternalRequest<'a> {
+    /// The name of the file.
+    pub filename: &'a str,
+    /// The size of the file in bytes. Exactly this much is read from the file's reader.
+    pub length: u64,
+    /// The title of the file, which defaults to its name.
+    pub title: Option<&'a str>,
+    /// Alt text describing an image.
+    pub alt_txt: Option<&'a str>,
+    /// The syntax highlighting of a snippet, such as `"python"`.
+    pub snippet_type: Option<&'a str>,
+    /// Shares the file to this channel once it's uploaded. Otherwise it's only visible to the
+    /// uploader until shared.
+    pub channel_id: Option<&'a str>,
+    /// The text of the message sharing the file.
+    pub initial_comment: Option<&'a str>,
+    /// Shares the file as a reply in this thread of `channel_id`.
+    pub thread_ts: Option<Timestamp>,
+}
+
+#[derive(Debug)]
+pub enum UploadExternalError<E: Error> {
+    /// `files.getUploadURLExternal` failed, with Slack's error code.
+    GetUploadUrl(String),
+    /// `files.completeUploadExternal` failed, with Slack's error code.
+    CompleteUpload(String),
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The client had an error sending a request, or the file to its upload URL
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for UploadExternalError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            UploadExternalError::GetUploadUrl(ref e) => {
+                write!(f, "Getting the upload URL failed: {}", e)
+            }
+            UploadExternalError::CompleteUpload(ref e) => {
+                write!(f, "Completing the upload failed: {}", e)
+            }
+            UploadExternalError::MalformedResponse(_, ref e) => {
+                write!(f, "Uploading the file returned a malformed response: {}", e)
+            }
+            UploadExternalError::Client(ref inner) => {
+                write!(f, "Uploading the file failed: {}", inner)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for UploadExternalError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UploadExternalError::MalformedResponse(_, ref e) => Some(e),
+            UploadExternalError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Deserialize)]
+struct Status {
+    ok: bool,
+    error: Option<String>,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct UploadUrl {
+    pub upload_url: String,
+    pub file_id: String,
+}
+
+#[derive(Deserialize)]
+struct Completed {
+    files: Vec<File>,
+}
+
+/// Parses the response of one of the upload methods, turning Slack's error code into an error
+/// with `failed`.
+fn parse<T, E>(
+    body: String,
+    failed: fn(String) -> UploadExternalError<E>,
+) -> Result<T, UploadExternalError<E>>
+where
+    T: DeserializeOwned,
+    E: Error,
+{
+    let status = match serde_json::from_str::<Status>(&body) {
+        Ok(status) => status,
+        Err(e) => return Err(UploadExternalError::MalformedResponse(body, e)),
+    };
+    if !status.ok {
+        return Err(failed(status.error.unwrap_or_default()));
+    }
+    serde_json::from_str(&body).map_err(|e| UploadExternalError::MalformedResponse(body, e))
+}
+
+pub(crate) fn parse_upload_url<E: Error>(
+    body: String,
+) -> Result<UploadUrl, UploadExternalError<E>> {
+    parse(body, UploadExternalError::GetUploadUrl)
+}
+
+pub(crate) fn parse_completed<E: Error>(body: String) -> Result<Vec<File>, UploadExternalError<E>> {
+    parse(body, UploadExternalError::CompleteUpload).map(|c: Completed| c.files)
+}
+
+/// The `files` parameter of `files.completeUploadExternal` for the uploaded file.
+pub(crate) fn completed_files(file_id: &str, request: &UploadExternalRequest<'_>) -> String {
+    crate::to_json_param(&serde_json::json!([{
+        "id": file_id,
+        "title": request.title.unwrap_or(request.filename),
+    }]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_upload_responses() {
+        let url = parse_upload_url::<std::io::Error>(
+            r#"{"ok": true, "upload_url": "https://files.slack.com/upload/v1/abc", "file_id": "F1"}"#
+                .into(),
+        )
+        .unwrap();
+        assert_eq!(url.file_id, "F1");
+        match parse_completed::<std::io::Error>(
+            r#"{"ok": false, "error": "invalid_channel"}"#.into(),
+        ) {
+            Err(UploadExternalError::CompleteUpload(e)) => assert_eq!(e, "invalid_channel"),
+            other => panic!("expected a failed upload but got {:?}", other),
+        }
+        assert!(matches!(
+            parse_completed::<std::io::Error>(r#"{"ok": true}"#.into()),
+            Err(UploadExternalError::MalformedResponse(..))
+        ));
+    }
+}
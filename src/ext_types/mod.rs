@@ -0,0 +1,11 @@
+//! Hand-written types and helpers shared by the async and sync `ext` modules.
+
+pub mod admin_usergroups;
+pub mod chat;
+pub mod conversations;
+pub mod dnd;
+pub mod files;
+pub mod reactions;
+pub mod usergroups;
+pub mod users;
+pub mod views;
@@ -0,0 +1,153 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+
+use crate::mod_types::reactions_types::{AddError, RemoveError};
+use crate::{Message, Reaction, Timestamp};
+
+/// The item to react to.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ReactionTarget<'a> {
+    Message {
+        channel: &'a str,
+        timestamp: Timestamp,
+    },
+    File(&'a str),
+    FileComment(&'a str),
+}
+
+/// The item's fields in the order of the add and remove request fields: `file`, `file_comment`,
+/// `channel` and `timestamp`.
+type TargetFields<'a> = (
+    Option<&'a str>,
+    Option<&'a str>,
+    Option<&'a str>,
+    Option<Timestamp>,
+);
+
+impl<'a> ReactionTarget<'a> {
+    pub(crate) fn fields(self) -> TargetFields<'a> {
+        match self {
+            ReactionTarget::Message { channel, timestamp } => {
+                (None, None, Some(channel), Some(timestamp))
+            }
+            ReactionTarget::File(file) => (Some(file), None, None, None),
+            ReactionTarget::FileComment(comment) => (None, Some(comment), None, None),
+        }
+    }
+}
+
+/// Whether `toggle` added or removed the reaction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Toggled {
+    Added,
+    Removed,
+}
+
+#[derive(Debug)]
+pub enum ToggleError<E: Error> {
+    Add(AddError<E>),
+    Remove(RemoveError<E>),
+}
+
+impl<E: Error> fmt::Display for ToggleError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ToggleError::Add(ref e) => write!(f, "Adding the reaction failed: {}", e),
+            ToggleError::Remove(ref e) => write!(f, "Removing the reaction failed: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ToggleError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ToggleError::Add(ref e) => Some(e),
+            ToggleError::Remove(ref e) => Some(e),
+        }
+    }
+}
+
+/// The reactions of one kind across a set of messages.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ReactionSummary {
+    /// The total number of reactions, as counted by Slack.
+    pub count: u32,
+    /// The users who reacted, each listed once.
+    ///
+    /// Slack lists only some of the users for popular reactions unless `full` is requested.
+    pub users: Vec<String>,
+}
+
+/// Merges the reactions on a set of messages by name, e.g. to tally votes.
+///
+/// Reactions with a skin tone and aliases such as `+1` are counted under their canonical name.
+pub fn summarize(messages: &[Message]) -> HashMap<String, ReactionSummary> {
+    let mut summaries = HashMap::<String, ReactionSummary>::new();
+    let reactions = messages
+        .iter()
+        .filter_map(message_reactions)
+        .flatten()
+        .filter_map(|r| r.name.as_ref().map(|name| (canonical_name(name), r)));
+    for (name, reaction) in reactions {
+        let summary = summaries.entry(name.to_owned()).or_default();
+        summary.count += reaction.count.unwrap_or(0).max(0) as u32;
+        for user in reaction.users.iter().flatten() {
+            if !summary.users.contains(user) {
+                summary.users.push(user.clone());
+            }
+        }
+    }
+    summaries
+}
+
+/// The name a reaction is known by, ignoring skin tones and aliases.
+pub fn canonical_name(name: &str) -> &str {
+    match name.split("::").next().unwrap_or(name) {
+        "+1" => "thumbsup",
+        "-1" => "thumbsdown",
+        name => name,
+    }
+}
+
+fn message_reactions(message: &Message) -> Option<&Vec<Reaction>> {
+    match *message {
+        Message::Standard(ref m) => m.reactions.as_ref(),
+        Message::BotMessage(ref m) => m.reactions.as_ref(),
+        Message::MeMessage(ref m) => m.reactions.as_ref(),
+        Message::FileShare(ref m) => m.reactions.as_ref(),
+        Message::ThreadBroadcast(ref m) => m.reactions.as_ref(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_merges_aliases_and_users() {
+        let messages: Vec<Message> = serde_json::from_str(
+            r#"[
+                {"type": "message", "text": "a", "ts": "1.000000", "reactions": [
+                    {"name": "+1", "count": 2, "users": ["U1", "U2"]},
+                    {"name": "tada", "count": 1, "users": ["U1"]}
+                ]},
+                {"type": "message", "text": "b", "ts": "2.000000", "reactions": [
+                    {"name": "thumbsup::skin-tone-3", "count": 1, "users": ["U1"]}
+                ]}
+            ]"#,
+        )
+        .unwrap();
+        let summaries = summarize(&messages);
+        assert_eq!(
+            summaries["thumbsup"],
+            ReactionSummary {
+                count: 3,
+                users: vec!["U1".to_owned(), "U2".to_owned()],
+            }
+        );
+        assert_eq!(summaries["tada"].count, 1);
+        assert_eq!(summaries.len(), 2);
+    }
+}
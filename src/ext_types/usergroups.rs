@@ -0,0 +1,78 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::mod_types::usergroups_users_types::{ListError, UpdateError};
+
+/// The users `sync_members` added to and removed from a User Group.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MembershipChange {
+    pub added: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl MembershipChange {
+    /// Compares a group's `current` members with the `desired` ones, keeping the order of each.
+    pub fn diff(current: &[String], desired: &[&str]) -> Self {
+        let mut added = Vec::<String>::new();
+        for &user in desired {
+            if !current.iter().any(|u| u == user) && !added.iter().any(|u| u == user) {
+                added.push(user.to_owned());
+            }
+        }
+        let removed = current
+            .iter()
+            .filter(|u| !desired.contains(&&u[..]))
+            .cloned()
+            .collect();
+        MembershipChange { added, removed }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+#[derive(Debug)]
+pub enum SyncMembersError<E: Error> {
+    /// Fetching the current members failed.
+    List(ListError<E>),
+    /// Updating the members failed.
+    Update(UpdateError<E>),
+    /// The desired members were empty. Slack doesn't allow a User Group without members; disable
+    /// the group instead.
+    NoMembers,
+}
+
+impl<E: Error> fmt::Display for SyncMembersError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SyncMembersError::List(ref e) => write!(f, "Fetching the members failed: {}", e),
+            SyncMembersError::Update(ref e) => write!(f, "Updating the members failed: {}", e),
+            SyncMembersError::NoMembers => write!(f, "A User Group can't be left without members"),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for SyncMembersError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SyncMembersError::List(ref e) => Some(e),
+            SyncMembersError::Update(ref e) => Some(e),
+            SyncMembersError::NoMembers => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_members() {
+        let current = vec!["U1".to_owned(), "U2".to_owned(), "U3".to_owned()];
+        let change = MembershipChange::diff(&current, &["U3", "U4", "U1", "U4"]);
+        assert_eq!(change.added, vec!["U4"]);
+        assert_eq!(change.removed, vec!["U2"]);
+        assert!(MembershipChange::diff(&current, &["U2", "U1", "U3"]).is_empty());
+    }
+}
@@ -0,0 +1,102 @@
+use crate::User;
+
+/// Whether a user is an active, full member of the workspace: not deactivated, not a guest and
+/// not a bot.
+pub fn is_active_member(user: &User) -> bool {
+    let flag = |f: Option<bool>| f.unwrap_or(false);
+    !(flag(user.deleted)
+        || flag(user.is_restricted)
+        || flag(user.is_ultra_restricted)
+        || flag(user.is_bot)
+        || user.id.as_deref() == Some("USLACKBOT"))
+}
+
+/// The square of a profile photo Slack keeps, sent to `users.setPhoto` as `crop_x`, `crop_y` and
+/// `crop_w`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PhotoCrop {
+    pub x: u32,
+    pub y: u32,
+    /// The width, and height, of the square.
+    pub w: u32,
+}
+
+impl PhotoCrop {
+    /// The largest square in the middle of a `width` by `height` image.
+    pub fn centered(width: u32, height: u32) -> Self {
+        let w = width.min(height);
+        PhotoCrop {
+            x: (width - w) / 2,
+            y: (height - w) / 2,
+            w,
+        }
+    }
+
+    /// The largest square at the top of a `width` by `height` image, centered horizontally, which
+    /// keeps the face in most portraits.
+    pub fn top(width: u32, height: u32) -> Self {
+        PhotoCrop {
+            y: 0,
+            ..PhotoCrop::centered(width, height)
+        }
+    }
+
+    /// The crop as `users.setPhoto` parameters.
+    pub fn to_params(&self) -> [(&'static str, String); 3] {
+        [
+            ("crop_x", self.x.to_string()),
+            ("crop_y", self.y.to_string()),
+            ("crop_w", self.w.to_string()),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_photo_crop() {
+        assert_eq!(
+            PhotoCrop::centered(1200, 800),
+            PhotoCrop {
+                x: 200,
+                y: 0,
+                w: 800
+            }
+        );
+        assert_eq!(
+            PhotoCrop::centered(600, 1000),
+            PhotoCrop {
+                x: 0,
+                y: 200,
+                w: 600
+            }
+        );
+        assert_eq!(PhotoCrop::top(600, 1000), PhotoCrop { x: 0, y: 0, w: 600 });
+        assert_eq!(
+            PhotoCrop::centered(1200, 800).to_params()[0],
+            ("crop_x", "200".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_is_active_member() {
+        let users: Vec<User> = serde_json::from_str(
+            r#"[
+                {"id": "U1", "name": "alice"},
+                {"id": "U2", "deleted": true},
+                {"id": "U3", "is_restricted": true},
+                {"id": "B1", "is_bot": true},
+                {"id": "USLACKBOT", "is_bot": false}
+            ]"#,
+        )
+        .unwrap();
+        let active = users
+            .iter()
+            .filter(|u| is_active_member(u))
+            .filter_map(|u| u.id.as_deref())
+            .collect::<Vec<_>>();
+        assert_eq!(active, vec!["U1"]);
+    }
+}
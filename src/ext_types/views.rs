@@ -0,0 +1,136 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Mutex;
+
+use crate::blocks::{ActionsBlock, Block, BlockElement, ButtonElement, HomeView, Text};
+
+/// The most blocks a Home tab holds.
+pub const MAX_HOME_BLOCKS: usize = 100;
+
+/// The `action_id` of the button `home_page` adds to go back a page. Its value is the page to show.
+pub const PREVIOUS_PAGE_ACTION: &str = "home_previous_page";
+
+/// The `action_id` of the button `home_page` adds to go on a page. Its value is the page to show.
+pub const NEXT_PAGE_ACTION: &str = "home_next_page";
+
+/// Remembers the view last published to each user's Home tab, so `publish_home` can skip
+/// publishing it again.
+///
+/// Share one cache between everything publishing Home tabs for the app. The cache only lives in
+/// memory, so after a restart each view is published once more.
+#[derive(Debug, Default)]
+pub struct HomeTabCache {
+    hashes: Mutex<HashMap<String, u64>>,
+}
+
+impl HomeTabCache {
+    pub fn new() -> Self {
+        HomeTabCache::default()
+    }
+
+    /// Forgets the view published to a user, so the next one is published even if it's the same.
+    pub fn forget(&self, user_id: &str) {
+        self.hashes.lock().unwrap().remove(user_id);
+    }
+
+    pub(crate) fn is_current(&self, user_id: &str, hash: u64) -> bool {
+        self.hashes.lock().unwrap().get(user_id) == Some(&hash)
+    }
+
+    pub(crate) fn remember(&self, user_id: &str, hash: u64) {
+        self.hashes.lock().unwrap().insert(user_id.to_owned(), hash);
+    }
+}
+
+pub(crate) fn view_hash(view: &HomeView) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    crate::to_json_param(view).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Whether `publish_home` published the view.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HomePublished {
+    Published,
+    /// The user's Home tab already shows the view.
+    Unchanged,
+}
+
+/// The blocks of one page of a Home tab too long to show at once, counting pages from 0.
+///
+/// Content that fits in a Home tab is returned as is. Longer content is split into pages with
+/// Previous and Next buttons at the bottom, whose `action_id`s are [`PREVIOUS_PAGE_ACTION`] and
+/// [`NEXT_PAGE_ACTION`] and whose values are the page to publish next. Pages past the end show
+/// the last page.
+pub fn home_page(blocks: &[Block], page: usize) -> Vec<Block> {
+    if blocks.len() <= MAX_HOME_BLOCKS {
+        return blocks.to_vec();
+    }
+    let per_page = MAX_HOME_BLOCKS - 1;
+    let pages = blocks.len().div_ceil(per_page);
+    let page = page.min(pages - 1);
+    let start = page * per_page;
+    let mut shown = blocks[start..blocks.len().min(start + per_page)].to_vec();
+
+    let button = |label: &str, action_id: &str, page: usize| {
+        BlockElement::Button(ButtonElement {
+            text: Text::plain(label),
+            action_id: action_id.to_owned(),
+            url: None,
+            value: Some(page.to_string()),
+            style: None,
+        })
+    };
+    let mut elements = Vec::new();
+    if page > 0 {
+        elements.push(button("Previous", PREVIOUS_PAGE_ACTION, page - 1));
+    }
+    if page + 1 < pages {
+        elements.push(button("Next", NEXT_PAGE_ACTION, page + 1));
+    }
+    shown.push(Block::Actions(ActionsBlock {
+        elements,
+        block_id: None,
+    }));
+    shown
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::blocks::DividerBlock;
+
+    fn nav_values(blocks: &[Block]) -> Vec<(String, String)> {
+        match blocks.last() {
+            Some(Block::Actions(actions)) => actions
+                .elements
+                .iter()
+                .filter_map(|e| match e {
+                    BlockElement::Button(b) => Some((b.action_id.clone(), b.value.clone()?)),
+                    _ => None,
+                })
+                .collect(),
+            _ => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_home_page() {
+        let blocks = vec![Block::Divider(DividerBlock::default()); 250];
+        assert_eq!(home_page(&blocks[..100], 0).len(), 100);
+
+        let first = home_page(&blocks, 0);
+        assert_eq!(first.len(), MAX_HOME_BLOCKS);
+        assert_eq!(
+            nav_values(&first),
+            vec![(NEXT_PAGE_ACTION.to_owned(), "1".to_owned())]
+        );
+        let last = home_page(&blocks, 5);
+        assert_eq!(last.len(), 250 - 2 * 99 + 1);
+        assert_eq!(
+            nav_values(&last),
+            vec![(PREVIOUS_PAGE_ACTION.to_owned(), "1".to_owned())]
+        );
+    }
+}
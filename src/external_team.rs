@@ -0,0 +1,79 @@
+/// An external organization connected to the workspace through Slack Connect, from
+/// `team.externalTeams.list`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ExternalOrganization {
+    /// The workspaces of this organization which are connected.
+    pub connected_workspaces: Option<Vec<ConnectedWorkspace>>,
+    pub connection_status: Option<ConnectionStatus>,
+    pub im_channel_count: Option<u32>,
+    #[serde(default)]
+    pub is_sponsored: bool,
+    pub last_active_timestamp: Option<crate::Timestamp>,
+    pub mpim_channel_count: Option<u32>,
+    pub private_channel_count: Option<u32>,
+    pub public_channel_count: Option<u32>,
+    pub public_url: Option<String>,
+    pub team_domain: Option<String>,
+    pub team_id: Option<String>,
+    pub team_name: Option<String>,
+}
+
+impl ExternalOrganization {
+    /// The number of channels of every kind shared with the organization.
+    pub fn channel_count(&self) -> u32 {
+        [
+            self.im_channel_count,
+            self.mpim_channel_count,
+            self.private_channel_count,
+            self.public_channel_count,
+        ]
+        .iter()
+        .flatten()
+        .sum()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ConnectedWorkspace {
+    pub workspace_id: Option<String>,
+    pub workspace_name: Option<String>,
+}
+
+/// Whether an external organization is still connected.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConnectionStatus {
+    #[serde(alias = "connected")]
+    Connected,
+    #[serde(alias = "disconnected")]
+    Disconnected,
+    #[serde(alias = "blocked")]
+    Blocked,
+    #[serde(alias = "in_review")]
+    InReview,
+    /// A status Slack added after this version of the crate.
+    #[serde(other)]
+    Unknown,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_external_organization() {
+        let org: ExternalOrganization = serde_json::from_str(
+            r#"{"team_id": "T2", "team_name": "Partner", "connection_status": "CONNECTED",
+                "last_active_timestamp": 1696012245, "public_channel_count": 2,
+                "private_channel_count": 1, "im_channel_count": 3,
+                "connected_workspaces": [{"workspace_id": "T1", "workspace_name": "Ours"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(org.connection_status, Some(ConnectionStatus::Connected));
+        assert_eq!(org.channel_count(), 6);
+        assert_eq!(
+            org.connected_workspaces.unwrap()[0].workspace_id.as_deref(),
+            Some("T1")
+        );
+    }
+}
@@ -0,0 +1,75 @@
+use std::ops::{BitOr, BitOrAssign};
+
+/// A set of file types to filter `files.list` by, combined with `|`.
+///
+/// The default, empty set doesn't filter the list.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct FileTypes(u8);
+
+impl FileTypes {
+    /// Posts.
+    pub const SPACES: FileTypes = FileTypes(1);
+    pub const SNIPPETS: FileTypes = FileTypes(1 << 1);
+    pub const IMAGES: FileTypes = FileTypes(1 << 2);
+    /// Google docs.
+    pub const GDOCS: FileTypes = FileTypes(1 << 3);
+    pub const ZIPS: FileTypes = FileTypes(1 << 4);
+    pub const PDFS: FileTypes = FileTypes(1 << 5);
+
+    const NAMES: [(FileTypes, &'static str); 6] = [
+        (FileTypes::SPACES, "spaces"),
+        (FileTypes::SNIPPETS, "snippets"),
+        (FileTypes::IMAGES, "images"),
+        (FileTypes::GDOCS, "gdocs"),
+        (FileTypes::ZIPS, "zips"),
+        (FileTypes::PDFS, "pdfs"),
+    ];
+
+    pub fn contains(self, other: FileTypes) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn to_param_value(self) -> String {
+        if self.is_empty() {
+            return "all".to_owned();
+        }
+        FileTypes::NAMES
+            .iter()
+            .filter(|&&(ty, _)| self.contains(ty))
+            .map(|&(_, name)| name)
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+}
+
+impl BitOr for FileTypes {
+    type Output = FileTypes;
+
+    fn bitor(self, other: FileTypes) -> FileTypes {
+        FileTypes(self.0 | other.0)
+    }
+}
+
+impl BitOrAssign for FileTypes {
+    fn bitor_assign(&mut self, other: FileTypes) {
+        self.0 |= other.0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_types_param_value() {
+        assert_eq!(FileTypes::default().to_param_value(), "all");
+        assert_eq!(
+            (FileTypes::PDFS | FileTypes::SPACES).to_param_value(),
+            "spaces,pdfs"
+        );
+    }
+}
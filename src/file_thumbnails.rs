@@ -0,0 +1,185 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use serde::de::{Deserialize, Deserializer, IgnoredAny, MapAccess, Visitor};
+use serde::ser::{Serialize, SerializeMap, Serializer};
+use serde_json::Value;
+
+/// A file's thumbnails, collected from the `thumb_*` fields Slack sends for each size.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FileThumbnails {
+    /// Image thumbnails by the size of their longest side, in pixels.
+    pub sizes: BTreeMap<u32, Thumbnail>,
+    /// A still from a video.
+    pub video: Option<String>,
+    /// The first page of a PDF.
+    pub pdf: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Thumbnail {
+    pub url: Option<String>,
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    /// An animated version of a GIF's thumbnail.
+    pub gif: Option<String>,
+}
+
+impl FileThumbnails {
+    pub fn is_empty(&self) -> bool {
+        self.sizes.is_empty() && self.video.is_none() && self.pdf.is_none()
+    }
+
+    /// The smallest thumbnail at least `size` pixels on its longest side, or the largest one if
+    /// none is that big.
+    pub fn at_least(&self, size: u32) -> Option<&Thumbnail> {
+        self.sizes
+            .range(size..)
+            .next()
+            .or_else(|| self.sizes.iter().next_back())
+            .map(|(_, thumbnail)| thumbnail)
+    }
+
+    fn add(&mut self, key: &str, value: Value) {
+        let mut parts = key.splitn(2, '_');
+        let (size, attribute) = (parts.next().unwrap_or(""), parts.next());
+        let string = || value.as_str().map(str::to_owned);
+        match (size, attribute) {
+            ("video", None) => self.video = string(),
+            ("pdf", None) => self.pdf = string(),
+            (size, attribute) => {
+                let size = match size.parse() {
+                    Ok(size) => size,
+                    Err(_) => return,
+                };
+                let thumbnail = self.sizes.entry(size).or_default();
+                let number = || value.as_u64().map(|n| n as u32);
+                match attribute {
+                    None => thumbnail.url = string(),
+                    Some("w") => thumbnail.width = number(),
+                    Some("h") => thumbnail.height = number(),
+                    Some("gif") => thumbnail.gif = string(),
+                    Some(_) => {}
+                }
+            }
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for FileThumbnails {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ThumbnailsVisitor;
+
+        impl<'de> Visitor<'de> for ThumbnailsVisitor {
+            type Value = FileThumbnails;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a file's thumb_* fields")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<FileThumbnails, A::Error> {
+                let mut thumbnails = FileThumbnails::default();
+                while let Some(key) = map.next_key::<String>()? {
+                    match key.strip_prefix("thumb_") {
+                        Some(thumb) => thumbnails.add(thumb, map.next_value()?),
+                        None => {
+                            map.next_value::<IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(thumbnails)
+            }
+        }
+
+        deserializer.deserialize_map(ThumbnailsVisitor)
+    }
+}
+
+/// Written back as the `thumb_*` fields they were read from.
+impl Serialize for FileThumbnails {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        for (size, thumbnail) in &self.sizes {
+            if let Some(ref url) = thumbnail.url {
+                map.serialize_entry(&format!("thumb_{}", size), url)?;
+            }
+            if let Some(width) = thumbnail.width {
+                map.serialize_entry(&format!("thumb_{}_w", size), &width)?;
+            }
+            if let Some(height) = thumbnail.height {
+                map.serialize_entry(&format!("thumb_{}_h", size), &height)?;
+            }
+            if let Some(ref gif) = thumbnail.gif {
+                map.serialize_entry(&format!("thumb_{}_gif", size), gif)?;
+            }
+        }
+        if let Some(ref video) = self.video {
+            map.serialize_entry("thumb_video", video)?;
+        }
+        if let Some(ref pdf) = self.pdf {
+            map.serialize_entry("thumb_pdf", pdf)?;
+        }
+        map.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_thumbnails() {
+        let file: crate::File = serde_json::from_str(
+            r#"{
+                "id": "F1",
+                "size": 3000000000,
+                "thumb_64": "https://example.com/64.png",
+                "thumb_360": "https://example.com/360.png",
+                "thumb_360_w": 360,
+                "thumb_360_h": 240,
+                "thumb_360_gif": "https://example.com/360.gif",
+                "thumb_tiny": "AwAe",
+                "thumb_video": "https://example.com/video.png",
+                "transcription": {"status": "complete", "preview": {"content": "Hi", "has_more": false}}
+            }"#,
+        )
+        .unwrap();
+        let thumbnails = &file.thumbnails;
+        assert_eq!(thumbnails.sizes.keys().collect::<Vec<_>>(), vec![&64, &360]);
+        assert_eq!(
+            thumbnails.at_least(100),
+            Some(&Thumbnail {
+                url: Some("https://example.com/360.png".into()),
+                width: Some(360),
+                height: Some(240),
+                gif: Some("https://example.com/360.gif".into()),
+            })
+        );
+        assert_eq!(thumbnails.at_least(1024), thumbnails.sizes.get(&360));
+        assert_eq!(
+            thumbnails.video.as_deref(),
+            Some("https://example.com/video.png")
+        );
+        assert_eq!(file.size, Some(3_000_000_000));
+        assert_eq!(
+            file.transcription.and_then(|t| t.status).as_deref(),
+            Some("complete")
+        );
+    }
+
+    #[test]
+    fn test_file_round_trips() {
+        let file: crate::File = serde_json::from_str(
+            r#"{"id": "F1", "timestamp": 1614215551, "thumb_64": "https://example.com/64.png",
+                "thumb_64_w": 64, "thumb_pdf": "https://example.com/pdf.png"}"#,
+        )
+        .unwrap();
+        let json = serde_json::to_value(&file).unwrap();
+        assert_eq!(json["thumb_64_w"], 64);
+        assert_eq!(json["timestamp"], "1614215551.000000");
+        assert_eq!(serde_json::from_value::<crate::File>(json).unwrap(), file);
+    }
+}
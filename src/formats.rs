@@ -0,0 +1,129 @@
+//! The types of fields whose schema gives a `format`, which the codegen maps to a richer type than
+//! the string or number Slack sends.
+
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::Serializer;
+use serde_json::Value;
+
+/// A time sent as seconds since the Unix epoch: a `chrono::DateTime<Utc>` with the `chrono`
+/// feature, or the seconds without it.
+#[cfg(feature = "chrono")]
+pub type UnixTime = chrono::DateTime<chrono::Utc>;
+
+/// A time sent as seconds since the Unix epoch: a `chrono::DateTime<Utc>` with the `chrono`
+/// feature, or the seconds without it.
+#[cfg(not(feature = "chrono"))]
+pub type UnixTime = i64;
+
+/// A URL: a `url::Url` with the `url` feature, or the string without it.
+#[cfg(feature = "url")]
+pub type Url = url::Url;
+
+/// A URL: a `url::Url` with the `url` feature, or the string without it.
+#[cfg(not(feature = "url"))]
+pub type Url = String;
+
+#[cfg(feature = "chrono")]
+fn from_secs(secs: i64) -> Option<UnixTime> {
+    chrono::DateTime::from_timestamp(secs, 0)
+}
+
+#[cfg(not(feature = "chrono"))]
+fn from_secs(secs: i64) -> Option<UnixTime> {
+    Some(secs)
+}
+
+#[cfg(feature = "chrono")]
+fn to_secs(time: &UnixTime) -> i64 {
+    time.timestamp()
+}
+
+#[cfg(not(feature = "chrono"))]
+fn to_secs(time: &UnixTime) -> i64 {
+    *time
+}
+
+/// Reads a [`UnixTime`] from seconds sent as a number or a string, dropping any fraction, and
+/// writes it back as a number of seconds, for fields marked
+/// `#[serde(with = "slack_api::formats::unix_ts")]`.
+pub mod unix_ts {
+    use super::*;
+
+    pub fn serialize<S: Serializer>(time: &UnixTime, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(to_secs(time))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<UnixTime, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        let secs = match value {
+            Value::Number(ref n) => n.as_i64().or_else(|| n.as_f64().map(|f| f as i64)),
+            Value::String(ref s) => s
+                .parse::<i64>()
+                .ok()
+                .or_else(|| s.parse::<f64>().ok().map(|f| f as i64)),
+            _ => None,
+        };
+        secs.and_then(from_secs).ok_or_else(|| {
+            D::Error::custom(format!(
+                "expected seconds since the epoch but got: {}",
+                value
+            ))
+        })
+    }
+
+    /// The same for optional fields, reading `null` as `None`.
+    pub mod option {
+        use super::*;
+
+        pub fn serialize<S: Serializer>(
+            time: &Option<UnixTime>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            match *time {
+                Some(ref time) => super::serialize(time, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Option<UnixTime>, D::Error> {
+            match Value::deserialize(deserializer)? {
+                Value::Null => Ok(None),
+                value => super::deserialize(value)
+                    .map(Some)
+                    .map_err(D::Error::custom),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Deserialize, PartialEq, Serialize)]
+    struct Event {
+        #[serde(with = "unix_ts")]
+        created: UnixTime,
+        #[serde(default, with = "unix_ts::option")]
+        updated: Option<UnixTime>,
+    }
+
+    #[test]
+    fn test_unix_ts() {
+        let event: Event = serde_json::from_str(r#"{"created": "1614215551"}"#).unwrap();
+        assert_eq!(to_secs(&event.created), 1614215551);
+        assert_eq!(event.updated, None);
+
+        let event: Event =
+            serde_json::from_str(r#"{"created": 1614215551.25, "updated": 1614215560}"#).unwrap();
+        assert_eq!(event.updated.as_ref().map(to_secs), Some(1614215560));
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"created":1614215551,"updated":1614215560}"#
+        );
+
+        assert!(serde_json::from_str::<Event>(r#"{"created": "yesterday"}"#).is_err());
+    }
+}
@@ -0,0 +1,27 @@
+/// Who can run a custom function, set with `admin.functions.permissions.set`.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FunctionVisibility {
+    Everyone,
+    /// Only the collaborators of the function's app.
+    AppCollaborators,
+    /// Only the users, channels, workspaces or organizations listed alongside it.
+    NamedEntities,
+    NoOne,
+    /// A visibility Slack added after this version of the crate. It can't be sent back, and is
+    /// sent as `no_one`.
+    #[serde(other)]
+    Unknown,
+}
+
+impl FunctionVisibility {
+    pub fn to_param_value(self) -> String {
+        match self {
+            FunctionVisibility::Everyone => "everyone",
+            FunctionVisibility::AppCollaborators => "app_collaborators",
+            FunctionVisibility::NamedEntities => "named_entities",
+            FunctionVisibility::NoOne | FunctionVisibility::Unknown => "no_one",
+        }
+        .to_owned()
+    }
+}
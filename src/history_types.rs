@@ -0,0 +1,98 @@
+//! Types shared by the helpers which walk a channel's history.
+
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use crate::mod_types::chat_types::DeleteError;
+use crate::mod_types::conversations_types::{HistoryError, InfoError};
+use crate::rate_limit::RetryPolicy;
+use crate::{Message, Timestamp};
+
+/// How long to wait between deletions by default, keeping within `chat.delete`'s rate limit of
+/// around 50 requests per minute.
+pub const DEFAULT_DELETE_INTERVAL: Duration = Duration::from_millis(1200);
+
+#[derive(Clone, Default, Debug)]
+pub struct DeleteRangeRequest<'a> {
+    /// Channel to delete bot messages from.
+    pub channel: &'a str,
+    /// Only delete messages posted after this time.
+    pub oldest: Option<Timestamp>,
+    /// Only delete messages posted before this time.
+    pub latest: Option<Timestamp>,
+    /// Pass true to delete the messages as the authed user.
+    pub as_user: Option<bool>,
+    /// How long to wait between deletions. Defaults to [`DEFAULT_DELETE_INTERVAL`].
+    pub interval: Option<Duration>,
+    /// How rate limited calls are retried. Defaults to [`RetryPolicy::new()`].
+    pub retry_policy: Option<RetryPolicy>,
+}
+
+#[derive(Debug)]
+pub enum DeleteRangeError<E: Error> {
+    /// Fetching the channel's history failed.
+    History(HistoryError<E>),
+    /// Deleting a message failed. Messages before it in the range have already been deleted.
+    Delete {
+        deleted: usize,
+        error: DeleteError<E>,
+    },
+}
+
+impl<E: Error> fmt::Display for DeleteRangeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            DeleteRangeError::History(ref e) => write!(f, "Fetching history failed: {}", e),
+            DeleteRangeError::Delete { deleted, ref error } => write!(
+                f,
+                "Deleting a message failed after deleting {}: {}",
+                deleted, error
+            ),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for DeleteRangeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            DeleteRangeError::History(ref e) => Some(e),
+            DeleteRangeError::Delete { ref error, .. } => Some(error),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum UnreadCountError<E: Error> {
+    /// Fetching the channel's read cursor failed.
+    Info(InfoError<E>),
+    /// Fetching the channel's history failed.
+    History(HistoryError<E>),
+}
+
+impl<E: Error> fmt::Display for UnreadCountError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            UnreadCountError::Info(ref e) => write!(f, "Fetching the read cursor failed: {}", e),
+            UnreadCountError::History(ref e) => write!(f, "Fetching history failed: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for UnreadCountError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UnreadCountError::Info(ref e) => Some(e),
+            UnreadCountError::History(ref e) => Some(e),
+        }
+    }
+}
+
+/// The timestamp of a message if it was posted by a bot.
+pub(crate) fn bot_message_ts(message: &Message) -> Option<Timestamp> {
+    match *message {
+        Message::BotMessage(ref m) => m.ts,
+        Message::Standard(ref m) if m.bot_id.is_some() => m.ts,
+        _ => None,
+    }
+}
@@ -0,0 +1,33 @@
+//! Who a client's token belongs to, as reported by `auth.test`.
+
+use crate::mod_types::auth_types::TestResponse;
+
+/// The user, team and bot a token belongs to. Bots need their own user ID to tell the events
+/// they caused apart from everyone else's.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Identity {
+    /// The token's user: the bot user for a bot token.
+    pub user_id: String,
+    pub user: Option<String>,
+    pub team_id: String,
+    pub team: Option<String>,
+    pub enterprise_id: Option<String>,
+    /// Set for bot tokens.
+    pub bot_id: Option<String>,
+    /// The workspace's URL.
+    pub url: Option<String>,
+}
+
+impl From<TestResponse> for Identity {
+    fn from(response: TestResponse) -> Self {
+        Identity {
+            user_id: response.user_id.unwrap_or_default(),
+            user: response.user,
+            team_id: response.team_id.unwrap_or_default(),
+            team: response.team,
+            enterprise_id: response.enterprise_id,
+            bot_id: response.bot_id,
+            url: response.url,
+        }
+    }
+}
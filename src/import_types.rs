@@ -0,0 +1,343 @@
+//! Types shared by the helpers which replay an export into a workspace.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::export_types::MESSAGES_DIR;
+use crate::rate_limit::RetryPolicy;
+use crate::{CallMethodError, Timestamp};
+
+/// How long to wait between requests by default, keeping within `chat.postMessage`'s limit of
+/// around one message per second in a channel.
+pub const DEFAULT_IMPORT_INTERVAL: Duration = Duration::from_secs(1);
+
+const LOG_FILE: &str = "imported.jsonl";
+
+/// Subtypes of messages which are posted again. Others, such as channel joins, are made by Slack
+/// itself rather than posted.
+const REPLAYED_SUBTYPES: &[&str] = &[
+    "bot_message",
+    "file_share",
+    "me_message",
+    "thread_broadcast",
+];
+
+#[derive(Clone, Debug)]
+pub struct ImportRequest<'a> {
+    /// Directory of the export to replay. A log of what has been imported is kept there, so
+    /// importing again after an error carries on where it stopped.
+    pub dir: &'a Path,
+    /// How long to wait between requests. Defaults to [`DEFAULT_IMPORT_INTERVAL`].
+    pub interval: Option<Duration>,
+    /// How rate limited requests are retried. Defaults to [`RetryPolicy::new()`].
+    pub retry_policy: Option<RetryPolicy>,
+}
+
+impl<'a> ImportRequest<'a> {
+    /// Imports the export in `dir`.
+    pub fn new(dir: &'a Path) -> Self {
+        ImportRequest {
+            dir,
+            interval: None,
+            retry_policy: None,
+        }
+    }
+}
+
+/// What an import did. Parts done by an earlier, interrupted import aren't counted again.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ImportSummary {
+    pub channels_created: usize,
+    pub messages: usize,
+    /// Messages which weren't posted, such as channel joins or messages with nothing but a file.
+    pub skipped_messages: usize,
+    /// Direct messages, which can't be recreated, and channels whose history wasn't exported.
+    pub skipped_channels: Vec<String>,
+}
+
+#[derive(Debug)]
+pub enum ImportError<E: Error> {
+    /// Calling a method failed.
+    Slack {
+        method: &'static str,
+        error: CallMethodError<E>,
+    },
+    /// Reading the export or writing the import log failed.
+    Io(io::Error),
+}
+
+impl<E: Error> From<io::Error> for ImportError<E> {
+    fn from(e: io::Error) -> Self {
+        ImportError::Io(e)
+    }
+}
+
+impl<E: Error> fmt::Display for ImportError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ImportError::Slack { method, ref error } => write!(f, "{} failed: {}", method, error),
+            ImportError::Io(ref e) => write!(f, "Reading the export failed: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ImportError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ImportError::Slack { ref error, .. } => Some(error),
+            ImportError::Io(ref e) => Some(e),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub(crate) struct CreateRequest<'a> {
+    pub name: &'a str,
+    pub is_private: bool,
+}
+
+#[derive(Serialize)]
+pub(crate) struct JoinRequest<'a> {
+    pub channel: &'a str,
+}
+
+/// A message posted again under the name and icon of its original author.
+#[derive(Serialize)]
+pub(crate) struct PostRequest<'a> {
+    pub channel: &'a str,
+    pub text: &'a str,
+    pub blocks: Option<&'a Value>,
+    pub attachments: Option<&'a Value>,
+    pub username: Option<&'a str>,
+    pub icon_url: Option<&'a str>,
+    pub thread_ts: Option<&'a str>,
+    pub unfurl_links: bool,
+    pub unfurl_media: bool,
+}
+
+/// A conversation of an export.
+#[derive(Deserialize)]
+pub(crate) struct ExportedChannel {
+    pub id: String,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub is_private: bool,
+    #[serde(default)]
+    pub is_im: bool,
+    #[serde(default)]
+    pub is_mpim: bool,
+}
+
+/// A channel of the workspace being imported into.
+pub(crate) struct TargetChannel {
+    pub id: String,
+    pub is_private: bool,
+}
+
+/// How the author of a message is shown when it's posted again.
+pub(crate) struct Author {
+    pub name: String,
+    pub icon_url: Option<String>,
+}
+
+/// Records the channels an import created or found and the messages it posted, so an interrupted
+/// import doesn't post anything twice.
+pub(crate) struct ImportLog {
+    file: File,
+    channels: HashMap<String, String>,
+    messages: HashMap<(String, String), String>,
+}
+
+#[derive(Deserialize, Serialize)]
+struct LogEntry {
+    channel: String,
+    new_channel: Option<String>,
+    ts: Option<String>,
+    new_ts: Option<String>,
+}
+
+impl ImportLog {
+    pub fn open(dir: &Path) -> io::Result<Self> {
+        let path = dir.join(LOG_FILE);
+        let mut channels = HashMap::new();
+        let mut messages = HashMap::new();
+        if path.exists() {
+            for line in BufReader::new(File::open(&path)?).lines() {
+                let entry: LogEntry = serde_json::from_str(&line?)?;
+                match entry {
+                    LogEntry {
+                        channel,
+                        new_channel: Some(new_channel),
+                        ..
+                    } => {
+                        channels.insert(channel, new_channel);
+                    }
+                    LogEntry {
+                        channel,
+                        ts: Some(ts),
+                        new_ts: Some(new_ts),
+                        ..
+                    } => {
+                        messages.insert((channel, ts), new_ts);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let file = OpenOptions::new().create(true).append(true).open(path)?;
+        Ok(ImportLog {
+            file,
+            channels,
+            messages,
+        })
+    }
+
+    /// The channel an exported channel was imported into.
+    pub fn channel(&self, channel: &str) -> Option<&str> {
+        self.channels.get(channel).map(String::as_str)
+    }
+
+    /// The `ts` an exported message was posted again with.
+    pub fn message(&self, channel: &str, ts: &str) -> Option<&str> {
+        self.messages
+            .get(&(channel.to_owned(), ts.to_owned()))
+            .map(String::as_str)
+    }
+
+    pub fn record_channel(&mut self, channel: &str, new_channel: &str) -> io::Result<()> {
+        self.append(&LogEntry {
+            channel: channel.to_owned(),
+            new_channel: Some(new_channel.to_owned()),
+            ts: None,
+            new_ts: None,
+        })?;
+        self.channels
+            .insert(channel.to_owned(), new_channel.to_owned());
+        Ok(())
+    }
+
+    pub fn record_message(&mut self, channel: &str, ts: &str, new_ts: &str) -> io::Result<()> {
+        self.append(&LogEntry {
+            channel: channel.to_owned(),
+            new_channel: None,
+            ts: Some(ts.to_owned()),
+            new_ts: Some(new_ts.to_owned()),
+        })?;
+        self.messages
+            .insert((channel.to_owned(), ts.to_owned()), new_ts.to_owned());
+        Ok(())
+    }
+
+    /// Writes an entry as a single line, so an import killed mid-write loses at most that entry.
+    fn append(&mut self, entry: &LogEntry) -> io::Result<()> {
+        let mut line = serde_json::to_vec(entry)?;
+        line.push(b'\n');
+        self.file.write_all(&line)
+    }
+}
+
+/// Reads the conversations of an export.
+pub(crate) fn read_channels(dir: &Path) -> io::Result<Vec<ExportedChannel>> {
+    let file = File::open(dir.join("conversations.jsonl"))?;
+    BufReader::new(file)
+        .lines()
+        .map(|line| Ok(serde_json::from_str(&line?)?))
+        .collect()
+}
+
+/// Reads how each user of an export is shown as an author. An export without users shows
+/// messages as posted by the importing app.
+pub(crate) fn read_authors(dir: &Path) -> io::Result<HashMap<String, Author>> {
+    let file = match File::open(dir.join("users.jsonl")) {
+        Ok(file) => file,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(e) => return Err(e),
+    };
+    let mut authors = HashMap::new();
+    for line in BufReader::new(file).lines() {
+        let user: Value = serde_json::from_str(&line?)?;
+        let profile = &user["profile"];
+        let name = [
+            &profile["display_name"],
+            &profile["real_name"],
+            &user["name"],
+        ]
+        .iter()
+        .filter_map(|name| name.as_str())
+        .find(|name| !name.is_empty());
+        if let (Some(id), Some(name)) = (user["id"].as_str(), name) {
+            let author = Author {
+                name: name.to_owned(),
+                icon_url: profile["image_48"].as_str().map(str::to_owned),
+            };
+            authors.insert(id.to_owned(), author);
+        }
+    }
+    Ok(authors)
+}
+
+/// Reads a channel's exported messages in the order to post them again: oldest first, with the
+/// replies to a thread straight after the message starting it. `None` if the channel's history
+/// wasn't exported.
+pub(crate) fn read_messages(dir: &Path, channel: &str) -> io::Result<Option<Vec<Value>>> {
+    let path = dir.join(MESSAGES_DIR).join(format!("{}.jsonl", channel));
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(ref e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    let mut messages = BufReader::new(file)
+        .lines()
+        .map(|line| Ok(serde_json::from_str::<Value>(&line?)?))
+        .collect::<io::Result<Vec<_>>>()?;
+    messages.sort_by_key(|message| {
+        let ts = parse_ts(&message["ts"]);
+        let thread_ts = parse_ts(&message["thread_ts"]).or(ts);
+        (thread_ts, ts != thread_ts, ts)
+    });
+    Ok(Some(messages))
+}
+
+fn parse_ts(value: &Value) -> Option<Timestamp> {
+    Timestamp::deserialize(value).ok()
+}
+
+/// Whether a message is one to post again, rather than one made by Slack or one with nothing to
+/// post.
+pub(crate) fn is_replayable(message: &Value) -> bool {
+    let posted = match message["subtype"].as_str() {
+        None => true,
+        Some(subtype) => REPLAYED_SUBTYPES.contains(&subtype),
+    };
+    let has_content = message["text"].as_str().is_some_and(|t| !t.is_empty())
+        || message["blocks"].is_array()
+        || message["attachments"].is_array();
+    posted && has_content
+}
+
+/// The name and icon to post a message under, from its author's profile or, for messages from
+/// bots, the name and icon they were posted with.
+pub(crate) fn author_of<'a>(
+    message: &'a Value,
+    authors: &'a HashMap<String, Author>,
+) -> (Option<&'a str>, Option<&'a str>) {
+    if let Some(author) = message["user"].as_str().and_then(|id| authors.get(id)) {
+        return (Some(&author.name), author.icon_url.as_deref());
+    }
+    let bot = &message["bot_profile"];
+    let name = message["username"]
+        .as_str()
+        .or_else(|| bot["name"].as_str());
+    let icon_url = message["icons"]["image_48"]
+        .as_str()
+        .or_else(|| bot["icons"]["image_48"].as_str());
+    (name, icon_url)
+}
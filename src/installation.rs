@@ -0,0 +1,185 @@
+//! Types describing an app's installation into a workspace or organization.
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// The credentials and identifiers recorded when an app is installed into a workspace, or into
+/// an Enterprise Grid organization as a whole.
+#[derive(Clone, Debug, Default, Deserialize, Serialize, PartialEq)]
+pub struct Installation {
+    pub enterprise_id: Option<String>,
+    pub team_id: Option<String>,
+    /// Whether the app was installed organization-wide, in which case `team_id` is not set.
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    pub bot_token: Option<String>,
+    pub bot_id: Option<String>,
+    pub bot_user_id: Option<String>,
+    /// The user who installed the app.
+    pub user_id: Option<String>,
+    pub user_token: Option<String>,
+}
+
+/// Identifies the workspace (and/or organization) an incoming request or event belongs to.
+#[derive(Clone, Copy, Default, Debug, PartialEq)]
+pub struct InstallationQuery<'a> {
+    pub enterprise_id: Option<&'a str>,
+    pub team_id: Option<&'a str>,
+}
+
+type InstallationKey = (Option<String>, Option<String>);
+
+/// An `InstallationStore` which keeps installations in memory.
+///
+/// Useful for tests and single-process apps; anything longer lived should persist installations
+/// elsewhere.
+#[derive(Debug, Default)]
+pub struct MemoryInstallationStore {
+    installations: RwLock<HashMap<InstallationKey, Installation>>,
+}
+
+impl MemoryInstallationStore {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Finds the installation for a query.
+    ///
+    /// An installation into the specific workspace is preferred, whether or not it was stored
+    /// with the query's enterprise; failing that, an organization-wide installation for the
+    /// query's enterprise is used.
+    pub fn get(&self, query: &InstallationQuery<'_>) -> Option<Installation> {
+        let installations = self.installations.read().unwrap_or_else(|e| e.into_inner());
+        let exact = (
+            query.enterprise_id.map(str::to_owned),
+            query.team_id.map(str::to_owned),
+        );
+        installations
+            .get(&exact)
+            .or_else(|| {
+                query
+                    .team_id
+                    .and_then(|t| installations.get(&(None, Some(t.to_owned()))))
+            })
+            .or_else(|| {
+                query
+                    .enterprise_id
+                    .and_then(|e| installations.get(&(Some(e.to_owned()), None)))
+            })
+            .cloned()
+    }
+
+    /// Stores an installation, replacing any previous installation for the same workspace.
+    pub fn insert(&self, installation: Installation) {
+        let team_id = if installation.is_enterprise_install {
+            None
+        } else {
+            installation.team_id.clone()
+        };
+        let key = (installation.enterprise_id.clone(), team_id);
+        self.installations
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, installation);
+    }
+
+    /// Removes the installation stored for exactly this workspace (or organization).
+    pub fn remove(&self, query: &InstallationQuery<'_>) -> Option<Installation> {
+        let key = (
+            query.enterprise_id.map(str::to_owned),
+            query.team_id.map(str::to_owned),
+        );
+        self.installations
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .remove(&key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn installation(
+        enterprise_id: Option<&str>,
+        team_id: Option<&str>,
+        token: &str,
+    ) -> Installation {
+        Installation {
+            enterprise_id: enterprise_id.map(str::to_owned),
+            team_id: team_id.map(str::to_owned),
+            is_enterprise_install: team_id.is_none(),
+            bot_token: Some(token.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_memory_store_prefers_workspace_installation() {
+        let store = MemoryInstallationStore::new();
+        store.insert(installation(Some("E1"), None, "xoxb-org"));
+        store.insert(installation(Some("E1"), Some("T1"), "xoxb-team"));
+
+        let query = InstallationQuery {
+            enterprise_id: Some("E1"),
+            team_id: Some("T1"),
+        };
+        assert_eq!(
+            store.get(&query).unwrap().bot_token.as_deref(),
+            Some("xoxb-team")
+        );
+
+        let query = InstallationQuery {
+            enterprise_id: Some("E1"),
+            team_id: Some("T2"),
+        };
+        assert_eq!(
+            store.get(&query).unwrap().bot_token.as_deref(),
+            Some("xoxb-org")
+        );
+    }
+
+    #[test]
+    fn test_memory_store_falls_back_from_team_to_enterprise() {
+        let store = MemoryInstallationStore::new();
+        store.insert(installation(Some("E1"), None, "xoxb-org"));
+        store.insert(installation(None, Some("T1"), "xoxb-team"));
+
+        let query = InstallationQuery {
+            enterprise_id: Some("E1"),
+            team_id: Some("T1"),
+        };
+        assert_eq!(
+            store.get(&query).unwrap().bot_token.as_deref(),
+            Some("xoxb-team")
+        );
+
+        let query = InstallationQuery {
+            enterprise_id: Some("E1"),
+            team_id: Some("T2"),
+        };
+        assert_eq!(
+            store.get(&query).unwrap().bot_token.as_deref(),
+            Some("xoxb-org")
+        );
+    }
+
+    #[test]
+    fn test_memory_store_missing_installation() {
+        let store = MemoryInstallationStore::new();
+        store.insert(installation(None, Some("T1"), "xoxb-team"));
+
+        let query = InstallationQuery {
+            team_id: Some("T2"),
+            ..Default::default()
+        };
+        assert!(store.get(&query).is_none());
+
+        let query = InstallationQuery {
+            team_id: Some("T1"),
+            ..Default::default()
+        };
+        assert!(store.remove(&query).is_some());
+        assert!(store.get(&query).is_none());
+    }
+}
@@ -18,14 +18,117 @@
 #[macro_use]
 extern crate serde_derive;
 
+mod ext_types;
 mod mod_types;
 
+mod analytics_types;
+pub mod blocks;
+pub mod borrowed;
+pub mod conversation_state;
+pub mod de;
+pub mod events;
+#[cfg(any(feature = "async", feature = "sync"))]
+mod export_types;
+pub mod formats;
+mod history_types;
+#[cfg(any(feature = "async", feature = "sync"))]
+mod import_types;
+pub mod links;
+pub mod metadata;
+pub mod methods;
+#[cfg(feature = "oidc")]
+mod oidc_types;
+mod onboarding_types;
+mod outbox_types;
+mod presence_types;
+mod provisioning_types;
+mod scheduler_types;
+pub mod storage;
+mod threads_types;
+
+mod admin_role;
+pub use crate::admin_role::*;
+
+mod assistant;
+pub use crate::assistant::*;
+
+mod auth_policy;
+pub use crate::auth_policy::*;
+
+mod cancel;
+pub use crate::cancel::CancelToken;
+
+#[cfg(any(feature = "async", feature = "sync"))]
+mod circuit_breaker;
+
+#[cfg(any(feature = "async", feature = "sync"))]
+mod lossy;
+
+#[cfg(any(feature = "async", feature = "sync"))]
+mod rate_limit;
+
+#[cfg(any(feature = "async", feature = "sync"))]
+mod response_buffer;
+
+mod cursor;
+pub use crate::cursor::Cursor;
+
+mod custom_method;
+pub use crate::custom_method::CallMethodError;
+
+mod slack_error;
+pub use crate::slack_error::SlackError;
+
+#[cfg(feature = "log")]
+mod logged;
+
+mod conversation_filter;
+pub use crate::conversation_filter::*;
+
+mod external_team;
+pub use crate::external_team::*;
+
+mod file_filter;
+pub use crate::file_filter::*;
+
+mod file_thumbnails;
+pub use crate::file_thumbnails::*;
+
+pub use crate::formats::{UnixTime, Url};
+
+mod function_visibility;
+pub use crate::function_visibility::*;
+
+mod identity;
+pub use crate::identity::Identity;
+
+mod installation;
+pub use crate::installation::*;
+
+mod locale;
+pub use crate::locale::*;
+
+mod method_info;
+pub use crate::method_info::*;
+
+mod pagination;
+pub use crate::pagination::*;
+
+mod slack_list;
+pub use crate::slack_list::*;
+
 mod timestamp;
 pub use crate::timestamp::*;
 
+mod trigger_id;
+pub use crate::trigger_id::*;
+
 mod types;
 pub use crate::types::*;
 
+mod user_id;
+pub use crate::user_id::UserId;
+
 #[cfg(feature = "async")]
 mod async_impl;
 
@@ -35,10 +138,28 @@ pub use async_impl::*;
 #[cfg(feature = "sync")]
 pub mod sync;
 
+#[cfg(feature = "server")]
+pub mod server;
+
+#[cfg(feature = "realtime")]
+pub mod realtime;
+
+#[cfg(feature = "router")]
+pub mod router;
+
+/// Sent as the `User-Agent` of the default clients, ahead of any app-specific suffix.
+#[cfg(any(feature = "reqwest", feature = "reqwest_blocking"))]
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
 fn get_slack_url_for_method(method: &str) -> String {
     format!("https://slack.com/api/{}", method)
 }
 
+/// Serializes a typed parameter such as `blocks` into the JSON string Slack expects.
+fn to_json_param<T: serde::Serialize + ?Sized>(value: &T) -> String {
+    serde_json::to_string(value).expect("typed parameters always serialize to JSON")
+}
+
 fn optional_struct_or_empty_array<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
 where
     T: serde::Deserialize<'de>,
@@ -87,6 +208,16 @@ where
     deserializer.deserialize_any(StructOrEmptyArray(PhantomData))
 }
 
+/// Reads a number of seconds, such as a session's `duration`, as a `Duration`.
+fn optional_duration_secs<'de, D>(deserializer: D) -> Result<Option<std::time::Duration>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+
+    Ok(Option::<u64>::deserialize(deserializer)?.map(std::time::Duration::from_secs))
+}
+
 #[cfg(test)]
 mod tests {
     use super::UserProfile;
@@ -124,6 +255,45 @@ mod tests {
         assert!(user_profile.fields.is_none());
     }
 
+    #[test]
+    fn test_error_display_names_method() {
+        type Error = crate::mod_types::chat_types::PostMessageError<std::io::Error>;
+        assert_eq!(
+            Error::from("channel_not_found").to_string(),
+            "chat.postMessage failed: channel_not_found: Value passed for channel was invalid."
+        );
+        assert_eq!(
+            Error::from("not_a_real_error").to_string(),
+            "chat.postMessage failed: not_a_real_error"
+        );
+    }
+
+    // The errors compose with `anyhow` and the like, which need them to be `Send + Sync + 'static`.
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_errors_are_send_sync() {
+        fn assert_send_sync<T: std::error::Error + Send + Sync + 'static>() {}
+        type E = std::io::Error;
+        assert_send_sync::<crate::CallMethodError<E>>();
+        assert_send_sync::<crate::SlackError<E>>();
+        assert_send_sync::<crate::chat::PostMessageError<E>>();
+        assert_send_sync::<crate::scheduler::ScheduleInUserTzError<E>>();
+        assert_send_sync::<crate::history::DeleteRangeError<E>>();
+        assert_send_sync::<crate::provisioning::EnsureChannelError<E>>();
+        assert_send_sync::<crate::export::ExportError<E>>();
+        assert_send_sync::<crate::import::ImportError<E>>();
+        assert_send_sync::<crate::installations::RouteError<E>>();
+        assert_send_sync::<crate::requests::BoundedError<E>>();
+        assert_send_sync::<crate::requests::CircuitError<E>>();
+        #[cfg(feature = "reqwest")]
+        assert_send_sync::<crate::requests::Error>();
+    }
+
+    #[test]
+    fn test_empty_json_param_clears() {
+        assert_eq!(super::to_json_param::<[crate::blocks::Block]>(&[]), "[]");
+    }
+
     #[test]
     fn test_timestamp_to_param_value() {
         assert_eq!(
@@ -155,4 +325,121 @@ mod tests {
             m => panic!("expected Message::Standard but got {:?}", m),
         };
     }
+
+    #[test]
+    fn test_message_subtypes() {
+        let messages: Vec<crate::Message> = serde_json::from_str(
+            r#"[
+                {"type": "message", "subtype": "tombstone", "text": "This message was deleted.",
+                 "user": "USLACKBOT", "hidden": true, "ts": "1.000000", "thread_ts": "1.000000",
+                 "reply_count": 2},
+                {"type": "message", "subtype": "ekm_access_denied", "text": "Your admins have revoked access.",
+                 "user": "UREVOKEDU", "ts": "2.000000"},
+                {"type": "message", "subtype": "huddle_thread", "ts": "3.000000"}
+            ]"#,
+        )
+        .unwrap();
+        assert!(
+            matches!(messages[0], crate::Message::Tombstone(ref m) if m.reply_count == Some(2))
+        );
+        assert!(matches!(messages[1], crate::Message::EkmAccessDenied(_)));
+        assert!(
+            matches!(messages[2], crate::Message::Other(ref v) if v["subtype"] == "huddle_thread")
+        );
+    }
+
+    #[test]
+    fn test_bot_message_profile() {
+        let message: crate::Message = serde_json::from_str(
+            r#"{"type": "message", "subtype": "bot_message", "text": "Deployed", "ts": "1.000000",
+                "bot_id": "B1", "app_id": "A1", "bot_profile": {
+                    "id": "B1", "app_id": "A1", "name": "deploybot", "team_id": "T1",
+                    "icons": {"image_36": "https://example.com/36.png"}
+                }}"#,
+        )
+        .unwrap();
+        match message {
+            crate::Message::BotMessage(m) => {
+                let profile = m.bot_profile.unwrap();
+                assert_eq!(m.app_id.as_deref(), Some("A1"));
+                assert_eq!(profile.name.as_deref(), Some("deploybot"));
+                assert_eq!(
+                    profile.icons.and_then(|i| i.image_36).as_deref(),
+                    Some("https://example.com/36.png")
+                );
+            }
+            m => panic!("expected Message::BotMessage but got {:?}", m),
+        }
+    }
+
+    #[test]
+    fn test_chat_update_response_message() {
+        let response: crate::mod_types::chat_types::UpdateResponse = serde_json::from_str(
+            r#"{"ok": true, "channel": "C1", "ts": "1.000000", "text": "edited",
+                "message": {"type": "message", "user": "U1", "text": "edited",
+                    "blocks": [{"type": "divider"}]},
+                "warning": "missing_charset",
+                "response_metadata": {"warnings": ["missing_charset"]}}"#,
+        )
+        .unwrap();
+        assert_eq!(response.warning.as_deref(), Some("missing_charset"));
+        assert_eq!(
+            response.response_metadata.and_then(|m| m.warnings),
+            Some(vec!["missing_charset".to_owned()])
+        );
+        match response.message {
+            Some(crate::Message::Standard(m)) => assert_eq!(m.blocks.map(|b| b.len()), Some(1)),
+            m => panic!("expected Message::Standard but got {:?}", m),
+        }
+    }
+
+    #[test]
+    fn test_session_settings_duration() {
+        let response: crate::mod_types::admin_users_session_types::GetSettingsResponse =
+            serde_json::from_str(
+                r#"{"ok": true, "no_settings_applied": ["U2"], "session_settings": [
+                    {"user_id": "U1", "desktop_app_browser_quit": true, "duration": 86400}
+                ]}"#,
+            )
+            .unwrap();
+        let settings = &response.session_settings.unwrap()[0];
+        assert!(settings.desktop_app_browser_quit);
+        assert_eq!(
+            settings.duration,
+            Some(std::time::Duration::from_secs(86400))
+        );
+    }
+
+    #[test]
+    fn test_workflow_permissions() {
+        let response: crate::mod_types::admin_workflows_permissions_types::LookupResponse =
+            serde_json::from_str(
+                r#"{"ok": true, "permissions": {"Wf1": {"complete": true, "who_can_run": {
+                    "permission_type": "named_entities", "user_ids": ["U1"]}}}}"#,
+            )
+            .unwrap();
+        let who_can_run = response.permissions.unwrap()["Wf1"]
+            .who_can_run
+            .clone()
+            .unwrap();
+        assert_eq!(
+            who_can_run.permission_type,
+            Some(crate::FunctionVisibility::NamedEntities)
+        );
+        assert_eq!(who_can_run.user_ids.unwrap(), ["U1"]);
+    }
+
+    #[test]
+    fn test_conversation_canvas_id() {
+        let response: crate::mod_types::conversations_types::InfoResponse = serde_json::from_str(
+            r#"{"ok": true, "channel": {"id": "C1", "properties": {
+                "canvas": {"file_id": "F1", "is_empty": false, "quip_thread_id": "Q1"},
+                "tabs": [{"id": "files", "label": "", "type": "files"}]}}}"#,
+        )
+        .unwrap();
+        let channel = response.channel.unwrap();
+        assert_eq!(channel.canvas_id(), Some("F1"));
+        let tabs = channel.properties.unwrap().tabs.unwrap();
+        assert_eq!(tabs[0].r#type.as_deref(), Some("files"));
+    }
 }
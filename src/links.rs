@@ -0,0 +1,117 @@
+//! Links into Slack, for dashboards and emails which point people at a channel, message or app.
+//!
+//! `slack://` links open the desktop or mobile app directly. `https://app.slack.com` links open
+//! Slack in the browser, which offers to switch to the app.
+
+use crate::Timestamp;
+
+/// A tab of an app's App Home, for [`app`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AppTab {
+    Home,
+    Messages,
+    About,
+}
+
+impl AppTab {
+    fn as_str(self) -> &'static str {
+        match self {
+            AppTab::Home => "home",
+            AppTab::Messages => "messages",
+            AppTab::About => "about",
+        }
+    }
+}
+
+/// Opens `channel` in the Slack app, such as `slack://channel?team=T1&id=C1`.
+pub fn channel(team: &str, channel: &str) -> String {
+    format!("slack://channel?team={}&id={}", team, channel)
+}
+
+/// Opens a direct message with `user` in the Slack app.
+pub fn user(team: &str, user: &str) -> String {
+    format!("slack://user?team={}&id={}", team, user)
+}
+
+/// Opens `file` in the Slack app.
+pub fn file(team: &str, file: &str) -> String {
+    format!("slack://file?team={}&id={}", team, file)
+}
+
+/// Opens an app's App Home in the Slack app, on `tab` or the app's default tab.
+pub fn app(team: &str, app_id: &str, tab: Option<AppTab>) -> String {
+    let mut link = format!("slack://app?team={}&id={}", team, app_id);
+    if let Some(tab) = tab {
+        link.push_str("&tab=");
+        link.push_str(tab.as_str());
+    }
+    link
+}
+
+/// Opens `channel` in Slack in the browser, such as `https://app.slack.com/client/T1/C1`.
+pub fn client_channel(team: &str, channel: &str) -> String {
+    format!("https://app.slack.com/client/{}/{}", team, channel)
+}
+
+/// Opens the thread under `thread_ts` in Slack in the browser, beside its channel.
+pub fn client_thread(team: &str, channel: &str, thread_ts: Timestamp) -> String {
+    format!(
+        "https://app.slack.com/client/{team}/{channel}/thread/{channel}-{ts}",
+        team = team,
+        channel = channel,
+        ts = thread_ts
+    )
+}
+
+/// The permalink of the message at `ts`, the way `chat.getPermalink` builds it, without a call
+/// to Slack. `domain` is the workspace's subdomain, such as `acme` for `acme.slack.com`.
+///
+/// Pass the `thread_ts` of a reply to open it in its thread.
+pub fn message_permalink(
+    domain: &str,
+    channel: &str,
+    ts: Timestamp,
+    thread_ts: Option<Timestamp>,
+) -> String {
+    let micros = ts.to_micros();
+    let mut link = format!(
+        "https://{}.slack.com/archives/{}/p{}{:06}",
+        domain,
+        channel,
+        micros / 1_000_000,
+        micros % 1_000_000
+    );
+    if let Some(thread_ts) = thread_ts {
+        link.push_str(&format!("?thread_ts={}&cid={}", thread_ts, channel));
+    }
+    link
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_links() {
+        assert_eq!(channel("T1", "C1"), "slack://channel?team=T1&id=C1");
+        assert_eq!(
+            app("T1", "A1", Some(AppTab::Home)),
+            "slack://app?team=T1&id=A1&tab=home"
+        );
+        let thread_ts = Timestamp::from((1712345678, 0.25));
+        assert_eq!(
+            client_thread("T1", "C1", thread_ts),
+            "https://app.slack.com/client/T1/C1/thread/C1-1712345678.250000"
+        );
+        let ts = Timestamp::from((1712345680, 0.5));
+        assert_eq!(
+            message_permalink("acme", "C1", ts, None),
+            "https://acme.slack.com/archives/C1/p1712345680500000"
+        );
+        assert_eq!(
+            message_permalink("acme", "C1", ts, Some(thread_ts)),
+            "https://acme.slack.com/archives/C1/p1712345680500000\
+             ?thread_ts=1712345678.250000&cid=C1"
+        );
+    }
+}
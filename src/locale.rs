@@ -0,0 +1,49 @@
+/// A BCP-47 language tag such as `en-US`, as returned for users and conversations when
+/// `include_locale` is requested.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct Locale(String);
+
+impl Locale {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// The primary language subtag, e.g. `en` for `en-US`.
+    pub fn language(&self) -> &str {
+        self.0.split('-').next().unwrap_or(&self.0)
+    }
+
+    /// The region subtag, e.g. `US` for `en-US`, if there is one.
+    pub fn region(&self) -> Option<&str> {
+        self.0
+            .split('-')
+            .skip(1)
+            .find(|s| s.len() == 2 || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit())))
+    }
+}
+
+impl std::fmt::Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<&str> for Locale {
+    fn from(s: &str) -> Self {
+        Locale(s.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locale_subtags() {
+        let locale: Locale = serde_json::from_str(r#""zh-Hant-TW""#).unwrap();
+        assert_eq!(locale.language(), "zh");
+        assert_eq!(locale.region(), Some("TW"));
+        assert_eq!(Locale::from("ja").region(), None);
+    }
+}
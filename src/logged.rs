@@ -0,0 +1,196 @@
+use std::borrow::Borrow;
+
+/// Parameters whose values are never logged.
+const SECRET_PARAMS: &[&str] = &[
+    "token",
+    "client_secret",
+    "signing_secret",
+    "refresh_token",
+    "code",
+];
+
+/// Prefixes of Slack tokens, redacted wherever they appear in a logged value or response.
+const TOKEN_PREFIXES: &[&str] = &[
+    "xoxa-", "xoxb-", "xoxe-", "xoxp-", "xoxr-", "xoxs-", "xapp-",
+];
+
+const REDACTED: &str = "[REDACTED]";
+
+/// Wraps a sender to log each call at `debug` level: the method, its parameters and the start of
+/// the response, or the error.
+///
+/// Credentials are redacted before anything is logged: the values of `token`, `client_secret`,
+/// `signing_secret`, `refresh_token` and `code` parameters, including in URLs quoted by errors,
+/// and anything that looks like a Slack token in other parameters, the response or the error.
+/// Senders don't see HTTP headers, so cookies and signatures never reach the log.
+#[derive(Clone, Debug)]
+pub struct Logged<R> {
+    pub(crate) sender: R,
+    max_response_len: usize,
+}
+
+impl<R> Logged<R> {
+    /// Wraps `sender`, logging up to 512 bytes of each response.
+    pub fn new(sender: R) -> Self {
+        Logged {
+            sender,
+            max_response_len: 512,
+        }
+    }
+
+    pub fn max_response_len(mut self, len: usize) -> Self {
+        self.max_response_len = len;
+        self
+    }
+
+    pub fn into_inner(self) -> R {
+        self.sender
+    }
+
+    pub(crate) fn log_request<I, K, V>(&self, method_url: &str, params: I) -> Vec<(String, String)>
+    where
+        I: IntoIterator,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+    {
+        let params = params
+            .into_iter()
+            .map(|p| {
+                let (k, v) = p.borrow();
+                (k.as_ref().to_owned(), v.as_ref().to_owned())
+            })
+            .collect::<Vec<_>>();
+        if log::log_enabled!(target: "slack_api", log::Level::Debug) {
+            let method = method_url.rsplit('/').next().unwrap_or(method_url);
+            let logged = params
+                .iter()
+                .map(|(k, v)| format!("{}={}", k, redact_param(k, v)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            log::debug!(target: "slack_api", "calling {} {}", method, logged);
+        }
+        params
+    }
+
+    pub(crate) fn log_response<B, E>(&self, result: &Result<(Option<u16>, B), E>)
+    where
+        B: AsRef<[u8]>,
+        E: std::fmt::Display,
+    {
+        match result {
+            Ok((_, body)) => log::debug!(
+                target: "slack_api",
+                "response {}",
+                truncate(
+                    &redact_tokens(&String::from_utf8_lossy(body.as_ref())),
+                    self.max_response_len
+                )
+            ),
+            Err(e) => log::debug!(
+                target: "slack_api",
+                "error {}",
+                redact_tokens(&redact_query_params(&e.to_string()))
+            ),
+        }
+    }
+}
+
+fn redact_param(name: &str, value: &str) -> String {
+    if SECRET_PARAMS.contains(&name) {
+        REDACTED.to_owned()
+    } else {
+        redact_tokens(value)
+    }
+}
+
+/// Replaces the values of secret parameters in the query strings of URLs in `text`, such as the
+/// URL a client error quotes.
+fn redact_query_params(text: &str) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find(['?', '&']) {
+        let (before, after) = rest.split_at(start + 1);
+        redacted.push_str(before);
+        rest = after;
+        let secret = SECRET_PARAMS.iter().find(|name| {
+            rest.strip_prefix(**name)
+                .is_some_and(|value| value.starts_with('='))
+        });
+        if let Some(name) = secret {
+            let value = &rest[name.len() + 1..];
+            let len = value
+                .find(|c: char| c == '&' || c == '#' || c == ')' || c == '"' || c.is_whitespace())
+                .unwrap_or(value.len());
+            redacted.push_str(name);
+            redacted.push('=');
+            redacted.push_str(REDACTED);
+            rest = &value[len..];
+        }
+    }
+    redacted.push_str(rest);
+    redacted
+}
+
+/// Replaces the secret part of every Slack token in `text`, keeping its prefix.
+fn redact_tokens(text: &str) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some((start, prefix)) = TOKEN_PREFIXES
+        .iter()
+        .filter_map(|prefix| rest.find(prefix).map(|start| (start, prefix)))
+        .min()
+    {
+        let secret = start + prefix.len();
+        redacted.push_str(&rest[..secret]);
+        redacted.push_str(REDACTED);
+        let len = rest[secret..]
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-'))
+            .unwrap_or(rest.len() - secret);
+        rest = &rest[secret + len..];
+    }
+    redacted.push_str(rest);
+    redacted
+}
+
+fn truncate(text: &str, max_len: usize) -> &str {
+    if text.len() <= max_len {
+        return text;
+    }
+    let mut end = max_len;
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redacts_secrets() {
+        assert_eq!(redact_param("token", "anything"), REDACTED);
+        assert_eq!(redact_param("channel", "C123"), "C123");
+        assert_eq!(
+            redact_tokens(r#"{"access_token": "xoxp-123-abc", "bot": {"t": "xoxb-9"}}"#),
+            r#"{"access_token": "xoxp-[REDACTED]", "bot": {"t": "xoxb-[REDACTED]"}}"#
+        );
+        assert_eq!(truncate("héllo", 2), "h");
+    }
+
+    #[test]
+    fn test_redacts_secret_params_in_errors() {
+        let error = "error sending request for url (https://slack.com/api/oauth.v2.access?\
+                     client_id=1.2&client_secret=5f6e&code=77-88&refresh_token=xoxe-1-abc)";
+        assert_eq!(
+            redact_tokens(&redact_query_params(error)),
+            "error sending request for url (https://slack.com/api/oauth.v2.access?\
+             client_id=1.2&client_secret=[REDACTED]&code=[REDACTED]&refresh_token=[REDACTED])"
+        );
+        assert_eq!(
+            redact_query_params("https://slack.com/api/x?codename=a&channel=C1"),
+            "https://slack.com/api/x?codename=a&channel=C1"
+        );
+    }
+}
@@ -0,0 +1,238 @@
+//! Skipping the items of a list response which don't deserialize, instead of failing the call.
+
+use std::fmt;
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+/// An item of a list response which [`Lossy`] skipped because it didn't deserialize.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DeserializeIssue {
+    /// The Web API method called, such as `conversations.history`.
+    pub method: String,
+    /// The field of the response holding the list, such as `messages`.
+    pub field: &'static str,
+    /// The position of the item in the list, before any items were skipped.
+    pub index: usize,
+    /// Why the item didn't deserialize.
+    pub error: String,
+    /// The item as Slack sent it.
+    pub item: Value,
+}
+
+/// Wraps a sender so that an item of a list which doesn't deserialize, such as one malformed
+/// message in a page of `conversations.history`, is skipped and reported to a callback, rather
+/// than failing the whole call with `MalformedResponse`.
+///
+/// This suits long-running exporters, which would rather lose an item than stop. It covers the
+/// lists of the methods returning typed lists, such as `users.list`, `conversations.list` and the
+/// history and replies methods. Anything else wrong with a response still fails the call.
+#[derive(Clone)]
+pub struct Lossy<R> {
+    pub(crate) sender: R,
+    on_issue: Arc<dyn Fn(&DeserializeIssue) + Send + Sync>,
+}
+
+impl<R> Lossy<R> {
+    /// Wraps `sender`, passing every skipped item to `on_issue`.
+    pub fn new<F>(sender: R, on_issue: F) -> Self
+    where
+        F: Fn(&DeserializeIssue) + Send + Sync + 'static,
+    {
+        Lossy {
+            sender,
+            on_issue: Arc::new(on_issue),
+        }
+    }
+
+    pub fn into_inner(self) -> R {
+        self.sender
+    }
+
+    /// Removes the items of the response's lists which don't deserialize, reporting each.
+    pub(crate) fn skip_invalid(&self, method_url: &str, body: String) -> String {
+        let method = method_url.rsplit('/').next().unwrap_or(method_url);
+        let mut fields = LIST_FIELDS
+            .iter()
+            .filter(|&&(m, _, _)| m == method)
+            .peekable();
+        if fields.peek().is_none() {
+            return body;
+        }
+        let mut response = match serde_json::from_str::<Value>(&body) {
+            Ok(Value::Object(response)) => response,
+            _ => return body,
+        };
+        let mut skipped = false;
+        for &(_, field, check) in fields {
+            let items = match response.get_mut(field) {
+                Some(Value::Array(items)) => items,
+                _ => continue,
+            };
+            let mut index = 0;
+            items.retain(|item| {
+                let result = check(item);
+                if let Err(ref e) = result {
+                    (self.on_issue)(&DeserializeIssue {
+                        method: method.to_owned(),
+                        field,
+                        index,
+                        error: e.to_string(),
+                        item: item.clone(),
+                    });
+                }
+                index += 1;
+                result.is_ok()
+            });
+            skipped |= index != items.len();
+        }
+        if skipped {
+            Value::Object(response).to_string()
+        } else {
+            body
+        }
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for Lossy<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Lossy")
+            .field("sender", &self.sender)
+            .finish()
+    }
+}
+
+fn check<T: DeserializeOwned>(item: &Value) -> Result<(), serde_json::Error> {
+    T::deserialize(item).map(drop)
+}
+
+type Check = fn(&Value) -> Result<(), serde_json::Error>;
+
+/// The lists of typed items in responses, by method.
+const LIST_FIELDS: &[(&str, &str, Check)] = &[
+    (
+        "admin.auth.policy.getEntities",
+        "entities",
+        check::<crate::AuthPolicyEntity>,
+    ),
+    (
+        "admin.conversations.ekm.listOriginalConnectedChannelInfo",
+        "channels",
+        check::<crate::OriginalConnectedChannel>,
+    ),
+    (
+        "admin.functions.list",
+        "functions",
+        check::<crate::SlackFunction>,
+    ),
+    (
+        "admin.roles.listAssignments",
+        "role_assignments",
+        check::<crate::RoleAssignment>,
+    ),
+    (
+        "admin.usergroups.listChannels",
+        "channels",
+        check::<crate::Conversation>,
+    ),
+    (
+        "admin.users.session.getSettings",
+        "session_settings",
+        check::<crate::SessionSettings>,
+    ),
+    (
+        "admin.users.session.list",
+        "active_sessions",
+        check::<crate::UserSession>,
+    ),
+    (
+        "admin.workflows.search",
+        "workflows",
+        check::<crate::Workflow>,
+    ),
+    ("bookmarks.list", "bookmarks", check::<crate::Bookmark>),
+    ("channels.history", "messages", check::<crate::Message>),
+    ("channels.list", "channels", check::<crate::Channel>),
+    ("channels.replies", "messages", check::<crate::Message>),
+    (
+        "chat.scheduledMessages.list",
+        "scheduled_messages",
+        check::<crate::ScheduledMessage>,
+    ),
+    ("conversations.history", "messages", check::<crate::Message>),
+    (
+        "conversations.list",
+        "channels",
+        check::<crate::Conversation>,
+    ),
+    ("conversations.replies", "messages", check::<crate::Message>),
+    ("files.info", "comments", check::<crate::FileComment>),
+    ("files.list", "files", check::<crate::File>),
+    ("groups.history", "messages", check::<crate::Message>),
+    ("groups.list", "groups", check::<crate::Group>),
+    ("groups.replies", "messages", check::<crate::Message>),
+    ("im.history", "messages", check::<crate::Message>),
+    ("im.list", "ims", check::<crate::Im>),
+    ("im.replies", "messages", check::<crate::Message>),
+    ("mpim.history", "messages", check::<crate::Message>),
+    ("mpim.list", "groups", check::<crate::Mpim>),
+    ("mpim.replies", "messages", check::<crate::Message>),
+    ("reminders.list", "reminders", check::<crate::Reminder>),
+    ("rtm.start", "bots", check::<crate::Bot>),
+    ("rtm.start", "channels", check::<crate::Channel>),
+    ("rtm.start", "groups", check::<crate::Group>),
+    ("rtm.start", "ims", check::<crate::Im>),
+    ("rtm.start", "mpims", check::<crate::Mpim>),
+    ("rtm.start", "users", check::<crate::User>),
+    (
+        "slackLists.items.info",
+        "subtasks",
+        check::<crate::ListItem>,
+    ),
+    ("slackLists.items.list", "items", check::<crate::ListItem>),
+    (
+        "team.externalTeams.list",
+        "organizations",
+        check::<crate::ExternalOrganization>,
+    ),
+    ("usergroups.list", "usergroups", check::<crate::Usergroup>),
+    ("users.list", "members", check::<crate::User>),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_skip_invalid_items() {
+        let issues = Arc::new(Mutex::new(Vec::new()));
+        let lossy = {
+            let issues = issues.clone();
+            Lossy::new((), move |issue: &DeserializeIssue| {
+                issues.lock().unwrap().push(issue.clone())
+            })
+        };
+        let body = serde_json::json!({"ok": true, "messages": [
+            {"type": "message", "user": "U1", "text": "hi", "ts": "1.000000"},
+            {"type": "message", "subtype": "bot_message", "ts": ["not", "a", "ts"]},
+            {"type": "message", "user": "U2", "text": "hello", "ts": "2.000000"},
+        ]})
+        .to_string();
+        let body = lossy.skip_invalid("https://slack.com/api/conversations.history", body);
+        let page: crate::mod_types::conversations_types::HistoryResponse =
+            serde_json::from_str(&body).unwrap();
+        assert_eq!(page.messages.unwrap().len(), 2);
+
+        let issues = issues.lock().unwrap();
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].method, "conversations.history");
+        assert_eq!(issues[0].field, "messages");
+        assert_eq!(issues[0].index, 1);
+
+        let body = r#"{"ok": true, "messages": [{"ts": []}]}"#.to_owned();
+        assert_eq!(lossy.skip_invalid("chat.postMessage", body.clone()), body);
+    }
+}
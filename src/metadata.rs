@@ -0,0 +1,12 @@
+//! [Message metadata](https://api.slack.com/metadata), structured data attached to a message for
+//! other apps to read.
+
+use serde_json::Value;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MessageMetadata {
+    /// The name of the event, e.g. `"task_created"`.
+    pub event_type: String,
+    /// Any JSON object describing the event.
+    pub event_payload: Value,
+}
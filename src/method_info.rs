@@ -0,0 +1,52 @@
+//! Descriptions of the methods the crate wraps, for tools which call methods by name.
+
+use crate::methods::METHODS;
+
+/// A Web API method, as described by the schemas the crate is generated from.
+#[derive(Clone, Copy, Debug)]
+pub struct MethodInfo {
+    /// The method's name, such as `chat.postMessage`.
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Whether the method is undocumented, and only wrapped with the `unstable-api` feature.
+    pub unstable: bool,
+    /// The method's parameters, leaving out `token`.
+    pub params: &'static [ParamInfo],
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct ParamInfo {
+    pub name: &'static str,
+    pub required: bool,
+    pub description: &'static str,
+}
+
+impl MethodInfo {
+    /// Looks up a method in [`METHODS`](crate::methods::METHODS) by name.
+    pub fn find(name: &str) -> Option<&'static MethodInfo> {
+        METHODS
+            .binary_search_by_key(&name, |method| method.name)
+            .ok()
+            .map(|i| &METHODS[i])
+    }
+
+    /// Looks up one of the method's parameters by name.
+    pub fn param(&self, name: &str) -> Option<&'static ParamInfo> {
+        self.params.iter().find(|param| param.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_method() {
+        assert!(METHODS.windows(2).all(|w| w[0].name < w[1].name));
+        let post = MethodInfo::find("chat.postMessage").unwrap();
+        assert!(post.param("channel").unwrap().required);
+        assert!(!post.param("thread_ts").unwrap().required);
+        assert!(post.param("token").is_none());
+        assert!(MethodInfo::find("chat.post").is_none());
+    }
+}
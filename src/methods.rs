@@ -0,0 +1,3843 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Every Web API method the crate wraps, with its parameters.
+
+use crate::method_info::{MethodInfo, ParamInfo};
+
+/// Every method, sorted by name.
+pub static METHODS: &[MethodInfo] = &[
+    MethodInfo {
+        name: "admin.auth.policy.assignEntities",
+        description: "Assigns entities to an authentication policy.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "entity_ids",
+                required: true,
+                description: "The IDs of the entities, at most 50.",
+            },
+            ParamInfo {
+                name: "entity_type",
+                required: true,
+                description: "The type of the entities.",
+            },
+            ParamInfo {
+                name: "policy_name",
+                required: true,
+                description: "The authentication policy.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.auth.policy.getEntities",
+        description: "Lists the entities assigned to an authentication policy.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "policy_name",
+                required: true,
+                description: "The authentication policy.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.",
+            },
+            ParamInfo {
+                name: "entity_type",
+                required: false,
+                description: "Only list entities of this type.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of entities to return, up to 1000. Defaults to 100.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.auth.policy.removeEntities",
+        description: "Removes entities from an authentication policy.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "entity_ids",
+                required: true,
+                description: "The IDs of the entities, at most 50.",
+            },
+            ParamInfo {
+                name: "entity_type",
+                required: true,
+                description: "The type of the entities.",
+            },
+            ParamInfo {
+                name: "policy_name",
+                required: true,
+                description: "The authentication policy.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.conversations.ekm.listOriginalConnectedChannelInfo",
+        description: "Lists the original connected channel information of channels shared by Slack Connect before EKM was enabled.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_ids",
+                required: false,
+                description: "Only list these channels.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of channels to return, up to 1000. Defaults to 100.",
+            },
+            ParamInfo {
+                name: "team_ids",
+                required: false,
+                description: "Only list the channels of these workspaces.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.conversations.restrictAccess.addGroup",
+        description: "Adds an IDP group to the allowlist of a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_id",
+                required: true,
+                description: "The private channel.",
+            },
+            ParamInfo {
+                name: "group_id",
+                required: true,
+                description: "The IDP group to allow.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: false,
+                description: "The workspace of the channel. Required for channels that aren't shared across the organization.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.conversations.restrictAccess.listGroups",
+        description: "Lists the IDP groups allowed into a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_id",
+                required: true,
+                description: "The private channel.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: false,
+                description: "The workspace of the channel. Required for channels that aren't shared across the organization.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.conversations.restrictAccess.removeGroup",
+        description: "Removes an IDP group from the allowlist of a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_id",
+                required: true,
+                description: "The private channel.",
+            },
+            ParamInfo {
+                name: "group_id",
+                required: true,
+                description: "The IDP group to remove.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: true,
+                description: "The workspace of the channel.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.functions.list",
+        description: "Lists the functions of apps.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "app_ids",
+                required: true,
+                description: "The apps whose functions are listed.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of functions to return, up to 1000. Defaults to 100.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: false,
+                description: "The workspace the apps are installed in.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.functions.permissions.lookup",
+        description: "Looks up who can run functions, keyed by function ID.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "function_ids",
+                required: true,
+                description: "The functions, at most 20.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.functions.permissions.set",
+        description: "Sets who can run a function.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "function_id",
+                required: true,
+                description: "The function.",
+            },
+            ParamInfo {
+                name: "visibility",
+                required: true,
+                description: "Who can run the function.",
+            },
+            ParamInfo {
+                name: "user_ids",
+                required: false,
+                description: "The users who can run the function when its visibility is `named_entities`.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.roles.addAssignments",
+        description: "Assigns users to a role, scoped to the given entities.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "entity_ids",
+                required: true,
+                description: "The organization, workspaces or channels the role is scoped to.",
+            },
+            ParamInfo {
+                name: "role_id",
+                required: true,
+                description: "The role.",
+            },
+            ParamInfo {
+                name: "user_ids",
+                required: true,
+                description: "The users, at most 100.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.roles.listAssignments",
+        description: "Lists the assignments of roles.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.",
+            },
+            ParamInfo {
+                name: "entity_ids",
+                required: false,
+                description: "Only list assignments scoped to these entities.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of assignments to return, up to 200. Defaults to 100.",
+            },
+            ParamInfo {
+                name: "role_ids",
+                required: false,
+                description: "Only list assignments of these roles.",
+            },
+            ParamInfo {
+                name: "sort_dir",
+                required: false,
+                description: "`asc`, or `desc` by default, by when the role was assigned.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.roles.removeAssignments",
+        description: "Removes users from a role, scoped to the given entities.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "entity_ids",
+                required: true,
+                description: "The organization, workspaces or channels the role is scoped to.",
+            },
+            ParamInfo {
+                name: "role_id",
+                required: true,
+                description: "The role.",
+            },
+            ParamInfo {
+                name: "user_ids",
+                required: true,
+                description: "The users, at most 100.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.usergroups.addChannels",
+        description: "Adds channels to the default channels of a user group, which its members join automatically.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_ids",
+                required: true,
+                description: "The channels to add, at most 100.",
+            },
+            ParamInfo {
+                name: "usergroup_id",
+                required: true,
+                description: "The user group.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: false,
+                description: "The workspace of the channels, when the user group is in more than one.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.usergroups.addTeams",
+        description: "Makes an organization-wide user group available in more workspaces.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "team_ids",
+                required: true,
+                description: "The workspaces to add the user group to.",
+            },
+            ParamInfo {
+                name: "usergroup_id",
+                required: true,
+                description: "The user group.",
+            },
+            ParamInfo {
+                name: "auto_provision",
+                required: false,
+                description: "Add the user group's members to the workspaces that they aren't in yet.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.usergroups.listChannels",
+        description: "Lists the default channels of a user group.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "usergroup_id",
+                required: true,
+                description: "The user group.",
+            },
+            ParamInfo {
+                name: "include_num_members",
+                required: false,
+                description: "Include the number of members of each channel.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: false,
+                description: "The workspace of the user group, when it's in more than one.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.usergroups.removeChannels",
+        description: "Removes channels from the default channels of a user group.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_ids",
+                required: true,
+                description: "The channels to remove, at most 100.",
+            },
+            ParamInfo {
+                name: "usergroup_id",
+                required: true,
+                description: "The user group.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.users.assign",
+        description: "Adds a member of the organization to a workspace.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "team_id",
+                required: true,
+                description: "The workspace to add the user to.",
+            },
+            ParamInfo {
+                name: "user_id",
+                required: true,
+                description: "The user to add.",
+            },
+            ParamInfo {
+                name: "channel_ids",
+                required: false,
+                description: "Channels of the workspace to add the user to.",
+            },
+            ParamInfo {
+                name: "is_restricted",
+                required: false,
+                description: "Add the user as a multi-channel guest.",
+            },
+            ParamInfo {
+                name: "is_ultra_restricted",
+                required: false,
+                description: "Add the user as a single-channel guest.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.users.invite",
+        description: "Invites a user to a workspace by email.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_ids",
+                required: true,
+                description: "Channels of the workspace the user joins when they accept.",
+            },
+            ParamInfo {
+                name: "email",
+                required: true,
+                description: "The email address of the user to invite.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: true,
+                description: "The workspace to invite the user to.",
+            },
+            ParamInfo {
+                name: "custom_message",
+                required: false,
+                description: "A message sent along with the invitation.",
+            },
+            ParamInfo {
+                name: "email_password_policy_enabled",
+                required: false,
+                description: "Let the user sign in with an email and password instead of SSO.",
+            },
+            ParamInfo {
+                name: "guest_expiration_ts",
+                required: false,
+                description: "When a guest's account is deactivated.",
+            },
+            ParamInfo {
+                name: "is_restricted",
+                required: false,
+                description: "Invite the user as a multi-channel guest.",
+            },
+            ParamInfo {
+                name: "is_ultra_restricted",
+                required: false,
+                description: "Invite the user as a single-channel guest.",
+            },
+            ParamInfo {
+                name: "real_name",
+                required: false,
+                description: "The user's full name.",
+            },
+            ParamInfo {
+                name: "resend",
+                required: false,
+                description: "Send the invitation again if the user has already been invited.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.users.session.clearSettings",
+        description: "Clears the session settings of the given users, so the organization's defaults apply.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user_ids",
+                required: true,
+                description: "Comma separated list of user IDs, at most 1000.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.users.session.getSettings",
+        description: "Gets the session settings of the given users, if they have any.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user_ids",
+                required: true,
+                description: "Comma separated list of user IDs, at most 1000.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.users.session.invalidate",
+        description: "Revokes a single session of a user, signing them out of that device.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "session_id",
+                required: true,
+                description: "The ID of the session, as listed by admin.users.session.list.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: true,
+                description: "The workspace the session belongs to.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.users.session.list",
+        description: "Lists the active user sessions of an organization.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of sessions to return. Defaults to 1000.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: false,
+                description: "Only list sessions in this workspace. Required with `user_id`.",
+            },
+            ParamInfo {
+                name: "user_id",
+                required: false,
+                description: "Only list this user's sessions. Required with `team_id`.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.users.session.reset",
+        description: "Wipes all of a user's valid sessions across every device.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                required: true,
+                description: "The user whose sessions are reset.",
+            },
+            ParamInfo {
+                name: "mobile_only",
+                required: false,
+                description: "Only reset the user's mobile sessions.",
+            },
+            ParamInfo {
+                name: "web_only",
+                required: false,
+                description: "Only reset the user's web sessions.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.users.session.setSettings",
+        description: "Sets how long the given users' sessions last before they must sign in again.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user_ids",
+                required: true,
+                description: "Comma separated list of user IDs, at most 1000.",
+            },
+            ParamInfo {
+                name: "desktop_app_browser_quit",
+                required: false,
+                description: "Sign users out of the desktop app when they quit it.",
+            },
+            ParamInfo {
+                name: "duration",
+                required: false,
+                description: "How long a session lasts, sent in whole seconds.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.workflows.collaborators.add",
+        description: "Adds collaborators to workflows.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "collaborator_ids",
+                required: true,
+                description: "The users to add, at most 50.",
+            },
+            ParamInfo {
+                name: "workflow_ids",
+                required: true,
+                description: "The workflows, at most 50.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.workflows.collaborators.remove",
+        description: "Removes collaborators from workflows.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "collaborator_ids",
+                required: true,
+                description: "The users to remove, at most 50.",
+            },
+            ParamInfo {
+                name: "workflow_ids",
+                required: true,
+                description: "The workflows, at most 50.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.workflows.permissions.lookup",
+        description: "Looks up who can run workflows, keyed by workflow ID.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "workflow_ids",
+                required: true,
+                description: "The workflows, at most 50.",
+            },
+            ParamInfo {
+                name: "max_workflow_triggers",
+                required: false,
+                description: "How many of each workflow's triggers to consider, up to 100.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.workflows.search",
+        description: "Searches the workflows of the organization.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "app_id",
+                required: false,
+                description: "Only find workflows of this app.",
+            },
+            ParamInfo {
+                name: "collaborator_ids",
+                required: false,
+                description: "Only find workflows with these collaborators.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of workflows to return, up to 50. Defaults to 50.",
+            },
+            ParamInfo {
+                name: "no_collaborators",
+                required: false,
+                description: "Only find workflows without collaborators.",
+            },
+            ParamInfo {
+                name: "num_trigger_ids",
+                required: false,
+                description: "How many of each workflow's triggers to include, up to 10.",
+            },
+            ParamInfo {
+                name: "query",
+                required: false,
+                description: "Text to match against the workflows' titles.",
+            },
+            ParamInfo {
+                name: "sort",
+                required: false,
+                description: "The field to sort by, `premium_runs` by default.",
+            },
+            ParamInfo {
+                name: "sort_dir",
+                required: false,
+                description: "`asc` or `desc`, the default.",
+            },
+            ParamInfo {
+                name: "source",
+                required: false,
+                description: "Only find workflows from `code` or the `workflow_builder`.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "admin.workflows.unpublish",
+        description: "Unpublishes workflows, stopping them from being run.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "workflow_ids",
+                required: true,
+                description: "The workflows, at most 50.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "api.test",
+        description: "Checks API calling code.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "error",
+                required: false,
+                description: "Error response to return",
+            },
+            ParamInfo {
+                name: "foo",
+                required: false,
+                description: "example property to return",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "assistant.threads.setStatus",
+        description: "Shows a status, such as \"is thinking...\", in an assistant thread while a reply is prepared.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_id",
+                required: true,
+                description: "The assistant thread's channel.",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: true,
+                description: "The timestamp of the assistant thread's parent message.",
+            },
+            ParamInfo {
+                name: "status",
+                required: true,
+                description: "The status to show. An empty status clears it, as does posting a reply.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "assistant.threads.setSuggestedPrompts",
+        description: "Sets the prompts suggested in an assistant thread.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_id",
+                required: true,
+                description: "The assistant thread's channel.",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: true,
+                description: "The timestamp of the assistant thread's parent message.",
+            },
+            ParamInfo {
+                name: "prompts",
+                required: true,
+                description: "The prompts, at most 4.",
+            },
+            ParamInfo {
+                name: "title",
+                required: false,
+                description: "A title shown above the prompts.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "assistant.threads.setTitle",
+        description: "Sets the title of an assistant thread, shown in the user's history of threads.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_id",
+                required: true,
+                description: "The assistant thread's channel.",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: true,
+                description: "The timestamp of the assistant thread's parent message.",
+            },
+            ParamInfo {
+                name: "title",
+                required: true,
+                description: "The title.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "auth.revoke",
+        description: "Revokes a token.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "test",
+                required: false,
+                description: "Setting this parameter to 1 triggers a testing mode where the specified token will not actually be revoked.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "auth.test",
+        description: "Checks authentication & identity.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "bookmarks.add",
+        description: "Add bookmark to a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_id",
+                required: true,
+                description: "Channel to add bookmark in.",
+            },
+            ParamInfo {
+                name: "title",
+                required: true,
+                description: "Title for the bookmark.",
+            },
+            ParamInfo {
+                name: "type",
+                required: true,
+                description: "Type of the bookmark i.e link.",
+            },
+            ParamInfo {
+                name: "emoji",
+                required: false,
+                description: "Emoji tag to apply to the link.",
+            },
+            ParamInfo {
+                name: "entity_id",
+                required: false,
+                description: "ID of the entity being bookmarked. Only applies to message and file types.",
+            },
+            ParamInfo {
+                name: "link",
+                required: false,
+                description: "Link to bookmark.",
+            },
+            ParamInfo {
+                name: "parent_id",
+                required: false,
+                description: "Id of this bookmark's parent.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "bookmarks.list",
+        description: "List bookmark for the channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel_id",
+                required: true,
+                description: "Channel to list bookmarks in.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "bots.info",
+        description: "Gets information about a bot user.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "bot",
+                required: false,
+                description: "Bot user to get info on",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.archive",
+        description: "Archives a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to archive",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.create",
+        description: "Creates a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "Name of channel to create",
+            },
+            ParamInfo {
+                name: "validate",
+                required: false,
+                description: "Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.history",
+        description: "Fetches history of messages and events from a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to fetch history for.",
+            },
+            ParamInfo {
+                name: "latest",
+                required: false,
+                description: "End of time range of messages to include in results.",
+            },
+            ParamInfo {
+                name: "oldest",
+                required: false,
+                description: "Start of time range of messages to include in results.",
+            },
+            ParamInfo {
+                name: "inclusive",
+                required: false,
+                description: "Include messages with latest or oldest timestamp in results.",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of messages to return, between 1 and 1000.",
+            },
+            ParamInfo {
+                name: "unreads",
+                required: false,
+                description: "Include unread_count_display in the output?",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.info",
+        description: "Gets information about a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to get info on",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.invite",
+        description: "Invites a user to a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to invite user to.",
+            },
+            ParamInfo {
+                name: "user",
+                required: true,
+                description: "User to invite to channel.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.join",
+        description: "Joins a channel, creating it if needed.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "Name of channel to join",
+            },
+            ParamInfo {
+                name: "validate",
+                required: false,
+                description: "Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.kick",
+        description: "Removes a user from a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to remove user from.",
+            },
+            ParamInfo {
+                name: "user",
+                required: true,
+                description: "User to remove from channel.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.leave",
+        description: "Leaves a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to leave",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.list",
+        description: "Lists all channels in a Slack team.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "exclude_archived",
+                required: false,
+                description: "Exclude archived channels from the list",
+            },
+            ParamInfo {
+                name: "exclude_members",
+                required: false,
+                description: "Exclude the members collection from each channel",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.mark",
+        description: "Sets the read cursor in a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to set reading cursor in.",
+            },
+            ParamInfo {
+                name: "ts",
+                required: true,
+                description: "Timestamp of the most recently seen message.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.rename",
+        description: "Renames a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to rename",
+            },
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "New name for channel.",
+            },
+            ParamInfo {
+                name: "validate",
+                required: false,
+                description: "Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.replies",
+        description: "Retrieve a thread of messages posted to a channel",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to fetch thread from",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: true,
+                description: "Unique identifier of a thread's parent message",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.setPurpose",
+        description: "Sets the purpose for a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to set the purpose of",
+            },
+            ParamInfo {
+                name: "purpose",
+                required: true,
+                description: "The new purpose",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.setTopic",
+        description: "Sets the topic for a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to set the topic of",
+            },
+            ParamInfo {
+                name: "topic",
+                required: true,
+                description: "The new topic",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "channels.unarchive",
+        description: "Unarchives a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to unarchive",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "chat.delete",
+        description: "Deletes a message.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "ts",
+                required: true,
+                description: "Timestamp of the message to be deleted.",
+            },
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel containing the message to be deleted.",
+            },
+            ParamInfo {
+                name: "as_user",
+                required: false,
+                description: "Pass true to delete the message as the authed user. Bot users in this context are considered authed users.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "chat.deleteScheduledMessage",
+        description: "Deletes a pending scheduled message from the queue.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "The channel the scheduled_message is posting to",
+            },
+            ParamInfo {
+                name: "scheduled_message_id",
+                required: true,
+                description: "scheduled_message_id returned from call to chat.scheduleMessage",
+            },
+            ParamInfo {
+                name: "as_user",
+                required: false,
+                description: "Pass true to delete the message as the authed user with chat:write:user scope. Bot users in this context are considered authed users. If unused or false, the message will be deleted with chat:write:bot scope.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "chat.meMessage",
+        description: "Share a me message into a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to send message to. Can be a public channel, private group or IM channel. Can be an encoded ID, or a name.",
+            },
+            ParamInfo {
+                name: "text",
+                required: true,
+                description: "Text of the message to send.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "chat.postEphemeral",
+        description: "Sends an ephemeral message to a user in a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name.",
+            },
+            ParamInfo {
+                name: "text",
+                required: true,
+                description: "Text of the message to send. When blocks are set, text is only used as the notification fallback.",
+            },
+            ParamInfo {
+                name: "user",
+                required: true,
+                description: "id of the user who will receive the ephemeral message. The user should be in the channel specified by the channel argument.",
+            },
+            ParamInfo {
+                name: "as_user",
+                required: false,
+                description: "Pass true to post the message as the authed user. Defaults to true if the chat:write:bot scope is not included. Otherwise, defaults to false.",
+            },
+            ParamInfo {
+                name: "attachments",
+                required: false,
+                description: "Structured message attachments.",
+            },
+            ParamInfo {
+                name: "blocks",
+                required: false,
+                description: "Structured message layout blocks.",
+            },
+            ParamInfo {
+                name: "icon_emoji",
+                required: false,
+                description: "Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored.",
+            },
+            ParamInfo {
+                name: "icon_url",
+                required: false,
+                description: "URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored.",
+            },
+            ParamInfo {
+                name: "link_names",
+                required: false,
+                description: "Find and link channel names and usernames.",
+            },
+            ParamInfo {
+                name: "parse",
+                required: false,
+                description: "Change how messages are treated. Defaults to none.",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: false,
+                description: "Provide another message's ts value to post this message in a thread. Avoid using a reply's ts value; use its parent's value instead. Ephemeral messages in threads are only shown if there is already an active thread.",
+            },
+            ParamInfo {
+                name: "username",
+                required: false,
+                description: "Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "chat.postMessage",
+        description: "Sends a message to a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See below for more details.",
+            },
+            ParamInfo {
+                name: "text",
+                required: true,
+                description: "Text of the message to send. See below for an explanation of formatting. This field is usually required, unless you're providing only attachments instead.",
+            },
+            ParamInfo {
+                name: "parse",
+                required: false,
+                description: "Change how messages are treated. Defaults to none. See below.",
+            },
+            ParamInfo {
+                name: "link_names",
+                required: false,
+                description: "Find and link channel names and usernames.",
+            },
+            ParamInfo {
+                name: "attachments",
+                required: false,
+                description: "Structured message attachments.",
+            },
+            ParamInfo {
+                name: "blocks",
+                required: false,
+                description: "Structured message layout blocks. When set, text is only used as the notification fallback.",
+            },
+            ParamInfo {
+                name: "unfurl_links",
+                required: false,
+                description: "Pass true to enable unfurling of primarily text-based content.",
+            },
+            ParamInfo {
+                name: "unfurl_media",
+                required: false,
+                description: "Pass false to disable unfurling of media content.",
+            },
+            ParamInfo {
+                name: "username",
+                required: false,
+                description: "Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.",
+            },
+            ParamInfo {
+                name: "as_user",
+                required: false,
+                description: "Pass true to post the message as the authed user, instead of as a bot. Defaults to false. See authorship below.",
+            },
+            ParamInfo {
+                name: "icon_url",
+                required: false,
+                description: "URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.",
+            },
+            ParamInfo {
+                name: "icon_emoji",
+                required: false,
+                description: "Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored. See authorship below.",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: false,
+                description: "Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.",
+            },
+            ParamInfo {
+                name: "reply_broadcast",
+                required: false,
+                description: "Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.",
+            },
+            ParamInfo {
+                name: "mrkdwn",
+                required: false,
+                description: "Pass false to disable Slack markup parsing of text. Defaults to true.",
+            },
+            ParamInfo {
+                name: "metadata",
+                required: false,
+                description: "Metadata to attach to the message, for other apps to read.",
+            },
+            ParamInfo {
+                name: "client_msg_id",
+                required: false,
+                description: "An ID of the caller's choosing, such as one from `new_client_msg_id`, which Slack echoes in the message and its events so redelivered ones can be recognized.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "chat.scheduleMessage",
+        description: "Schedules a message to be sent to a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel, private group, or DM channel to send message to. Can be an encoded ID, or a name.",
+            },
+            ParamInfo {
+                name: "post_at",
+                required: true,
+                description: "Unix EPOCH timestamp of time in future to send the message.",
+            },
+            ParamInfo {
+                name: "text",
+                required: false,
+                description: "Text of the message to send. When blocks are set, text is only used as the notification fallback.",
+            },
+            ParamInfo {
+                name: "as_user",
+                required: false,
+                description: "Pass true to post the message as the authed user, instead of as a bot.",
+            },
+            ParamInfo {
+                name: "attachments",
+                required: false,
+                description: "Structured message attachments.",
+            },
+            ParamInfo {
+                name: "blocks",
+                required: false,
+                description: "Structured message layout blocks.",
+            },
+            ParamInfo {
+                name: "link_names",
+                required: false,
+                description: "Find and link channel names and usernames.",
+            },
+            ParamInfo {
+                name: "metadata",
+                required: false,
+                description: "Metadata to attach to the message, for other apps to read.",
+            },
+            ParamInfo {
+                name: "parse",
+                required: false,
+                description: "Change how messages are treated. Defaults to none.",
+            },
+            ParamInfo {
+                name: "reply_broadcast",
+                required: false,
+                description: "Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: false,
+                description: "Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.",
+            },
+            ParamInfo {
+                name: "unfurl_links",
+                required: false,
+                description: "Pass true to enable unfurling of primarily text-based content.",
+            },
+            ParamInfo {
+                name: "unfurl_media",
+                required: false,
+                description: "Pass false to disable unfurling of media content.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "chat.scheduledMessages.list",
+        description: "Returns a list of scheduled messages.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: false,
+                description: "The channel of the scheduled messages",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.",
+            },
+            ParamInfo {
+                name: "latest",
+                required: false,
+                description: "A UNIX timestamp of the latest value in the time range",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "Maximum number of original entries to return.",
+            },
+            ParamInfo {
+                name: "oldest",
+                required: false,
+                description: "A UNIX timestamp of the oldest value in the time range",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: false,
+                description: "encoded team id to list channels in, required if org token is used",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "chat.unfurl",
+        description: "Unfurl a URL that a user posted",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel ID of the message",
+            },
+            ParamInfo {
+                name: "ts",
+                required: true,
+                description: "Timestamp of the message to add unfurl behavior to",
+            },
+            ParamInfo {
+                name: "unfurls",
+                required: true,
+                description: "JSON mapping a set of URLs from the message to their unfurl attachments",
+            },
+            ParamInfo {
+                name: "user_auth_required",
+                required: false,
+                description: "Set to true or 1 to indicate the user must install your Slack app to trigger unfurls for this domain",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "chat.update",
+        description: "Updates a message.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "ts",
+                required: true,
+                description: "Timestamp of the message to be updated.",
+            },
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel containing the message to be updated.",
+            },
+            ParamInfo {
+                name: "text",
+                required: true,
+                description: "New text for the message, using the default formatting rules.",
+            },
+            ParamInfo {
+                name: "attachments",
+                required: false,
+                description: "Structured message attachments. Pass an empty slice to remove the message's attachments.",
+            },
+            ParamInfo {
+                name: "blocks",
+                required: false,
+                description: "Structured message layout blocks. Pass an empty slice to remove the message's blocks.",
+            },
+            ParamInfo {
+                name: "parse",
+                required: false,
+                description: "Change how messages are treated. Defaults to client, unlike chat.postMessage. See below.",
+            },
+            ParamInfo {
+                name: "link_names",
+                required: false,
+                description: "Find and link channel names and usernames. Defaults to none. This parameter should be used in conjunction with parse. To set link_names to 1, specify a parse mode of full.",
+            },
+            ParamInfo {
+                name: "as_user",
+                required: false,
+                description: "Pass true to update the message as the authed user. Bot users in this context are considered authed users.",
+            },
+            ParamInfo {
+                name: "file_ids",
+                required: false,
+                description: "A comma separated list of file IDs to share in the message in place of its text.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.create",
+        description: "Initiates a public or private channel-based conversation.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "Name of the public or private channel to create.",
+            },
+            ParamInfo {
+                name: "is_private",
+                required: false,
+                description: "Create a private channel instead of a public one.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: false,
+                description: "Encoded team id to create the channel in, required if org token is used.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.history",
+        description: "Fetches a conversation's history of messages and events.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Conversation ID to fetch history for.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first \"page\" of the collection. See pagination for more detail.",
+            },
+            ParamInfo {
+                name: "inclusive",
+                required: false,
+                description: "Include messages with latest or oldest timestamp in results only when either timestamp is specified.",
+            },
+            ParamInfo {
+                name: "latest",
+                required: false,
+                description: "End of time range of messages to include in results. Default is the current time.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the history hasn't been reached.",
+            },
+            ParamInfo {
+                name: "oldest",
+                required: false,
+                description: "Start of time range of messages to include in results.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.info",
+        description: "Retrieve information about a conversation.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Conversation ID to learn more about",
+            },
+            ParamInfo {
+                name: "include_locale",
+                required: false,
+                description: "Set this to true to receive the locale for this conversation. Defaults to false",
+            },
+            ParamInfo {
+                name: "include_num_members",
+                required: false,
+                description: "Set to true to include the member count for the specified conversation. Defaults to false",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.invite",
+        description: "Invites users to a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "The ID of the public or private channel to invite user(s) to.",
+            },
+            ParamInfo {
+                name: "users",
+                required: true,
+                description: "A comma separated list of user IDs. Up to 1000 users may be listed.",
+            },
+            ParamInfo {
+                name: "force",
+                required: false,
+                description: "When set to true and multiple user IDs are provided, continue inviting the valid ones while disregarding invalid IDs.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.kick",
+        description: "Removes a user from a conversation.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "ID of conversation to remove user from.",
+            },
+            ParamInfo {
+                name: "user",
+                required: true,
+                description: "User ID to be removed.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.list",
+        description: "Lists all channels in a Slack team.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first \"page\" of the collection. See pagination for more detail.",
+            },
+            ParamInfo {
+                name: "exclude_archived",
+                required: false,
+                description: "Set to true to exclude archived channels from the list.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.",
+            },
+            ParamInfo {
+                name: "team_id",
+                required: false,
+                description: "Encoded team id to list channels in, required if token belongs to org-wide app.",
+            },
+            ParamInfo {
+                name: "types",
+                required: false,
+                description: "The types of conversations to list. Defaults to public channels.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.mark",
+        description: "Sets the read cursor in a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel or conversation to set the read cursor for.",
+            },
+            ParamInfo {
+                name: "ts",
+                required: true,
+                description: "Unique identifier of message you want marked as most recently seen in this conversation.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.members",
+        description: "Retrieve members of a conversation.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "ID of the conversation to retrieve members for",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first \"page\" of the collection. See pagination for more detail.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.open",
+        description: "Opens or resumes a direct message or multi-person direct message.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: false,
+                description: "Resume a conversation by supplying an im or mpim's ID. Or provide the users field instead.",
+            },
+            ParamInfo {
+                name: "return_im",
+                required: false,
+                description: "Boolean, indicates you want the full IM channel definition in the response.",
+            },
+            ParamInfo {
+                name: "users",
+                required: false,
+                description: "Comma separated lists of users. If only one user is included, this creates a 1:1 DM. The ordering of the users is preserved whenever a multi-person direct message is returned. Supply a channel when not supplying users.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.replies",
+        description: "Retrieve a thread of messages posted to a conversation",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Conversation ID to fetch thread from.",
+            },
+            ParamInfo {
+                name: "ts",
+                required: true,
+                description: "Unique identifier of either a thread's parent message or a message in the thread.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first \"page\" of the collection. See pagination for more detail.",
+            },
+            ParamInfo {
+                name: "inclusive",
+                required: false,
+                description: "Include messages with latest or oldest timestamp in results only when either timestamp is specified.",
+            },
+            ParamInfo {
+                name: "latest",
+                required: false,
+                description: "End of time range of messages to include in results. Default is the current time.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the history hasn't been reached.",
+            },
+            ParamInfo {
+                name: "oldest",
+                required: false,
+                description: "Start of time range of messages to include in results.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.setPurpose",
+        description: "Sets the purpose for a conversation.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Conversation to set the purpose of.",
+            },
+            ParamInfo {
+                name: "purpose",
+                required: true,
+                description: "A new, specialer purpose.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "conversations.setTopic",
+        description: "Sets the topic for a conversation.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Conversation to set the topic of.",
+            },
+            ParamInfo {
+                name: "topic",
+                required: true,
+                description: "The new topic string. Does not support formatting or linkification.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "dialog.open",
+        description: "Open a dialog with a user",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "dialog",
+                required: true,
+                description: "The dialog definition. This must be a JSON-encoded string.",
+            },
+            ParamInfo {
+                name: "trigger_id",
+                required: true,
+                description: "Exchange a trigger to post to the user.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "dnd.endDnd",
+        description: "Ends the current user's Do Not Disturb session immediately.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "dnd.endSnooze",
+        description: "Ends the current user's snooze mode immediately.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "dnd.info",
+        description: "Retrieves a user's current Do Not Disturb status.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user",
+                required: false,
+                description: "User to fetch status for (defaults to current user)",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "dnd.setSnooze",
+        description: "Turns on Do Not Disturb mode for the current user, or changes its duration.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "num_minutes",
+                required: true,
+                description: "Number of minutes, from now, to snooze until.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "dnd.teamInfo",
+        description: "Retrieves the Do Not Disturb status for users on a team.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "users",
+                required: false,
+                description: "Comma-separated list of users to fetch Do Not Disturb status for",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "emoji.list",
+        description: "Lists custom emoji for a team.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "files.comments.add",
+        description: "Add a comment to an existing file.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: true,
+                description: "File to add a comment to.",
+            },
+            ParamInfo {
+                name: "comment",
+                required: true,
+                description: "Text of the comment to add.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "files.comments.delete",
+        description: "Deletes an existing comment on a file.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: true,
+                description: "File to delete a comment from.",
+            },
+            ParamInfo {
+                name: "id",
+                required: true,
+                description: "The comment to delete.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "files.comments.edit",
+        description: "Edit an existing file comment.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: true,
+                description: "File containing the comment to edit.",
+            },
+            ParamInfo {
+                name: "id",
+                required: true,
+                description: "The comment to edit.",
+            },
+            ParamInfo {
+                name: "comment",
+                required: true,
+                description: "Text of the comment to edit.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "files.delete",
+        description: "Deletes a file.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: true,
+                description: "ID of file to delete.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "files.info",
+        description: "Gets information about a team file.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: true,
+                description: "Specify a file by providing its ID.",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of items to return per page.",
+            },
+            ParamInfo {
+                name: "page",
+                required: false,
+                description: "Page number of results to return.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Parameter for pagination. Set cursor equal to the next_cursor attribute returned by the previous request's response_metadata. This parameter is optional, but pagination is mandatory: the default value simply fetches the first \"page\" of the collection of comments.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "files.list",
+        description: "Lists & filters team files.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user",
+                required: false,
+                description: "Filter files created by a single user.",
+            },
+            ParamInfo {
+                name: "channel",
+                required: false,
+                description: "Filter files appearing in a specific channel, indicated by its ID.",
+            },
+            ParamInfo {
+                name: "ts_from",
+                required: false,
+                description: "Filter files created after this timestamp (inclusive).",
+            },
+            ParamInfo {
+                name: "ts_to",
+                required: false,
+                description: "Filter files created before this timestamp (inclusive).",
+            },
+            ParamInfo {
+                name: "types",
+                required: false,
+                description: "Filter files by type. The default value of all types does not filter the list.",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of items to return per page.",
+            },
+            ParamInfo {
+                name: "page",
+                required: false,
+                description: "Page number of results to return.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "files.revokePublicURL",
+        description: "Revokes public/external sharing access for a file",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: true,
+                description: "File to revoke",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "files.sharedPublicURL",
+        description: "Enables a file for public/external sharing.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: true,
+                description: "File to share",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.archive",
+        description: "Archives a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to archive",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.close",
+        description: "Closes a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to close.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.create",
+        description: "Creates a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "Name of private channel to create",
+            },
+            ParamInfo {
+                name: "validate",
+                required: false,
+                description: "Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.createChild",
+        description: "Clones and archives a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to clone and archive.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.history",
+        description: "Fetches history of messages and events from a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to fetch history for.",
+            },
+            ParamInfo {
+                name: "latest",
+                required: false,
+                description: "End of time range of messages to include in results.",
+            },
+            ParamInfo {
+                name: "oldest",
+                required: false,
+                description: "Start of time range of messages to include in results.",
+            },
+            ParamInfo {
+                name: "inclusive",
+                required: false,
+                description: "Include messages with latest or oldest timestamp in results.",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of messages to return, between 1 and 1000.",
+            },
+            ParamInfo {
+                name: "unreads",
+                required: false,
+                description: "Include unread_count_display in the output?",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.info",
+        description: "Gets information about a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to get info on",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.invite",
+        description: "Invites a user to a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to invite user to.",
+            },
+            ParamInfo {
+                name: "user",
+                required: true,
+                description: "User to invite.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.kick",
+        description: "Removes a user from a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to remove user from.",
+            },
+            ParamInfo {
+                name: "user",
+                required: true,
+                description: "User to remove from private channel.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.leave",
+        description: "Leaves a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to leave",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.list",
+        description: "Lists private channels that the calling user has access to.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "exclude_archived",
+                required: false,
+                description: "Don't return archived private channels.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.mark",
+        description: "Sets the read cursor in a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to set reading cursor in.",
+            },
+            ParamInfo {
+                name: "ts",
+                required: true,
+                description: "Timestamp of the most recently seen message.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.open",
+        description: "Opens a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to open.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.rename",
+        description: "Renames a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to rename",
+            },
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "New name for private channel.",
+            },
+            ParamInfo {
+                name: "validate",
+                required: false,
+                description: "Whether to return errors on invalid channel name instead of modifying it to meet the specified criteria.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.replies",
+        description: "Retrieve a thread of messages posted to a private channel",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to fetch thread from",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: true,
+                description: "Unique identifier of a thread's parent message",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.setPurpose",
+        description: "Sets the purpose for a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to set the purpose of",
+            },
+            ParamInfo {
+                name: "purpose",
+                required: true,
+                description: "The new purpose",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.setTopic",
+        description: "Sets the topic for a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to set the topic of",
+            },
+            ParamInfo {
+                name: "topic",
+                required: true,
+                description: "The new topic",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "groups.unarchive",
+        description: "Unarchives a private channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Private channel to unarchive",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "im.close",
+        description: "Close a direct message channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Direct message channel to close.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "im.history",
+        description: "Fetches history of messages and events from direct message channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Direct message channel to fetch history for.",
+            },
+            ParamInfo {
+                name: "latest",
+                required: false,
+                description: "End of time range of messages to include in results.",
+            },
+            ParamInfo {
+                name: "oldest",
+                required: false,
+                description: "Start of time range of messages to include in results.",
+            },
+            ParamInfo {
+                name: "inclusive",
+                required: false,
+                description: "Include messages with latest or oldest timestamp in results.",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of messages to return, between 1 and 1000.",
+            },
+            ParamInfo {
+                name: "unreads",
+                required: false,
+                description: "Include unread_count_display in the output?",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "im.list",
+        description: "Lists direct message channels for the calling user.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first \"page\" of the collection. See pagination for more detail.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "im.mark",
+        description: "Sets the read cursor in a direct message channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Direct message channel to set reading cursor in.",
+            },
+            ParamInfo {
+                name: "ts",
+                required: true,
+                description: "Timestamp of the most recently seen message.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "im.open",
+        description: "Opens a direct message channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user",
+                required: true,
+                description: "User to open a direct message channel with.",
+            },
+            ParamInfo {
+                name: "return_im",
+                required: false,
+                description: "Boolean, indicates you want the full IM channel definition in the response.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "im.replies",
+        description: "Retrieve a thread of messages posted to a direct message conversation",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Direct message channel to fetch thread from",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: true,
+                description: "Unique identifier of a thread's parent message",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "mpim.close",
+        description: "Closes a multiparty direct message channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "MPIM to close.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "mpim.history",
+        description: "Fetches history of messages and events from a multiparty direct message.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Multiparty direct message to fetch history for.",
+            },
+            ParamInfo {
+                name: "latest",
+                required: false,
+                description: "End of time range of messages to include in results.",
+            },
+            ParamInfo {
+                name: "oldest",
+                required: false,
+                description: "Start of time range of messages to include in results.",
+            },
+            ParamInfo {
+                name: "inclusive",
+                required: false,
+                description: "Include messages with latest or oldest timestamp in results.",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of messages to return, between 1 and 1000.",
+            },
+            ParamInfo {
+                name: "unreads",
+                required: false,
+                description: "Include unread_count_display in the output?",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "mpim.list",
+        description: "Lists multiparty direct message channels for the calling user.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "mpim.mark",
+        description: "Sets the read cursor in a multiparty direct message channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "multiparty direct message channel to set reading cursor in.",
+            },
+            ParamInfo {
+                name: "ts",
+                required: true,
+                description: "Timestamp of the most recently seen message.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "mpim.open",
+        description: "This method opens a multiparty direct message.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "users",
+                required: true,
+                description: "Comma separated lists of users.  The ordering of the users is preserved whenever a MPIM group is returned.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "mpim.replies",
+        description: "Retrieve a thread of messages posted to a direct message conversation from a multiparty direct message.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Multiparty direct message channel to fetch thread from.",
+            },
+            ParamInfo {
+                name: "thread_ts",
+                required: true,
+                description: "Unique identifier of a thread's parent message.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "oauth.access",
+        description: "Exchanges a temporary OAuth code for an API token.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "client_id",
+                required: true,
+                description: "Issued when you created your application.",
+            },
+            ParamInfo {
+                name: "client_secret",
+                required: true,
+                description: "Issued when you created your application.",
+            },
+            ParamInfo {
+                name: "code",
+                required: true,
+                description: "The code param returned via the OAuth callback.",
+            },
+            ParamInfo {
+                name: "redirect_uri",
+                required: false,
+                description: "This must match the originally submitted URI (if one was sent).",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "pins.add",
+        description: "Pins an item to a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to pin the item in.",
+            },
+            ParamInfo {
+                name: "file",
+                required: false,
+                description: "File to pin.",
+            },
+            ParamInfo {
+                name: "file_comment",
+                required: false,
+                description: "File comment to pin.",
+            },
+            ParamInfo {
+                name: "timestamp",
+                required: false,
+                description: "Timestamp of the message to pin.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "pins.list",
+        description: "Lists items pinned to a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel to get pinned items for.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "pins.remove",
+        description: "Un-pins an item from a channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "channel",
+                required: true,
+                description: "Channel where the item is pinned to.",
+            },
+            ParamInfo {
+                name: "file",
+                required: false,
+                description: "File to un-pin.",
+            },
+            ParamInfo {
+                name: "file_comment",
+                required: false,
+                description: "File comment to un-pin.",
+            },
+            ParamInfo {
+                name: "timestamp",
+                required: false,
+                description: "Timestamp of the message to un-pin.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "reactions.add",
+        description: "Adds a reaction to an item.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "Reaction (emoji) name.",
+            },
+            ParamInfo {
+                name: "file",
+                required: false,
+                description: "File to add reaction to.",
+            },
+            ParamInfo {
+                name: "file_comment",
+                required: false,
+                description: "File comment to add reaction to.",
+            },
+            ParamInfo {
+                name: "channel",
+                required: false,
+                description: "Channel where the message to add reaction to was posted.",
+            },
+            ParamInfo {
+                name: "timestamp",
+                required: false,
+                description: "Timestamp of the message to add reaction to.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "reactions.get",
+        description: "Gets reactions for an item.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: false,
+                description: "File to get reactions for.",
+            },
+            ParamInfo {
+                name: "file_comment",
+                required: false,
+                description: "File comment to get reactions for.",
+            },
+            ParamInfo {
+                name: "channel",
+                required: false,
+                description: "Channel where the message to get reactions for was posted.",
+            },
+            ParamInfo {
+                name: "timestamp",
+                required: false,
+                description: "Timestamp of the message to get reactions for.",
+            },
+            ParamInfo {
+                name: "full",
+                required: false,
+                description: "If true always return the complete reaction list.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "reactions.list",
+        description: "Lists reactions made by a user.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user",
+                required: false,
+                description: "Show reactions made by this user. Defaults to the authed user.",
+            },
+            ParamInfo {
+                name: "full",
+                required: false,
+                description: "If true always return the complete reaction list.",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of items to return per page.",
+            },
+            ParamInfo {
+                name: "page",
+                required: false,
+                description: "Page number of results to return.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Parameter for pagination. Set cursor equal to the next_cursor attribute returned by the previous request's response_metadata. This parameter is optional, but pagination is mandatory: the default value simply fetches the first \"page\" of the collection.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "reactions.remove",
+        description: "Removes a reaction from an item.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "Reaction (emoji) name.",
+            },
+            ParamInfo {
+                name: "file",
+                required: false,
+                description: "File to remove reaction from.",
+            },
+            ParamInfo {
+                name: "file_comment",
+                required: false,
+                description: "File comment to remove reaction from.",
+            },
+            ParamInfo {
+                name: "channel",
+                required: false,
+                description: "Channel where the message to remove reaction from was posted.",
+            },
+            ParamInfo {
+                name: "timestamp",
+                required: false,
+                description: "Timestamp of the message to remove reaction from.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "reminders.add",
+        description: "Creates a reminder.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "text",
+                required: true,
+                description: "The content of the reminder",
+            },
+            ParamInfo {
+                name: "time",
+                required: true,
+                description: "When this reminder should happen: the Unix timestamp (up to five years from now), the number of seconds until the reminder (if within 24 hours), or a natural language description (Ex. \"in 15 minutes,\" or \"every Thursday\")",
+            },
+            ParamInfo {
+                name: "user",
+                required: false,
+                description: "The user who will receive the reminder. If no user is specified, the reminder will go to user who created it.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "reminders.complete",
+        description: "Marks a reminder as complete.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "reminder",
+                required: true,
+                description: "The ID of the reminder to be marked as complete",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "reminders.delete",
+        description: "Deletes a reminder.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "reminder",
+                required: true,
+                description: "The ID of the reminder",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "reminders.info",
+        description: "Gets information about a reminder.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "reminder",
+                required: true,
+                description: "The ID of the reminder",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "reminders.list",
+        description: "Lists all reminders created by or for a given user.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "rtm.connect",
+        description: "Starts a Real Time Messaging session.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "rtm.start",
+        description: "Starts a Real Time Messaging session.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "no_unreads",
+                required: false,
+                description: "Skip unread counts for each channel (improves performance).",
+            },
+            ParamInfo {
+                name: "mpim_aware",
+                required: false,
+                description: "Returns MPIMs to the client in the API response.",
+            },
+            ParamInfo {
+                name: "no_latest",
+                required: false,
+                description: "Exclude latest timestamps for channels, groups, mpims, and ims. Automatically sets no_unreads to 1",
+            },
+            ParamInfo {
+                name: "batch_presence_aware",
+                required: false,
+                description: "Only deliver presence events when requested by subscription. See [presence subscriptions](/docs/presence-and-status#subscriptions).",
+            },
+            ParamInfo {
+                name: "include_locale",
+                required: false,
+                description: "Set this to `true` to receive the locale for users and channels. Defaults to `false`",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "search.all",
+        description: "Searches for messages and files matching a query.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "query",
+                required: true,
+                description: "Search query. May contains booleans, etc.",
+            },
+            ParamInfo {
+                name: "sort",
+                required: false,
+                description: "Return matches sorted by either score or timestamp.",
+            },
+            ParamInfo {
+                name: "sort_dir",
+                required: false,
+                description: "Change sort direction to ascending (asc) or descending (desc).",
+            },
+            ParamInfo {
+                name: "highlight",
+                required: false,
+                description: "Pass a value of true to enable query highlight markers (see below).",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of items to return per page.",
+            },
+            ParamInfo {
+                name: "page",
+                required: false,
+                description: "Page number of results to return.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "search.files",
+        description: "Searches for files matching a query.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "query",
+                required: true,
+                description: "Search query. May contain booleans, etc.",
+            },
+            ParamInfo {
+                name: "sort",
+                required: false,
+                description: "Return matches sorted by either score or timestamp.",
+            },
+            ParamInfo {
+                name: "sort_dir",
+                required: false,
+                description: "Change sort direction to ascending (asc) or descending (desc).",
+            },
+            ParamInfo {
+                name: "highlight",
+                required: false,
+                description: "Pass a value of true to enable query highlight markers (see below).",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of items to return per page.",
+            },
+            ParamInfo {
+                name: "page",
+                required: false,
+                description: "Page number of results to return.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "search.messages",
+        description: "Searches for messages matching a query.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "query",
+                required: true,
+                description: "Search query. May contains booleans, etc.",
+            },
+            ParamInfo {
+                name: "sort",
+                required: false,
+                description: "Return matches sorted by either score or timestamp.",
+            },
+            ParamInfo {
+                name: "sort_dir",
+                required: false,
+                description: "Change sort direction to ascending (asc) or descending (desc).",
+            },
+            ParamInfo {
+                name: "highlight",
+                required: false,
+                description: "Pass a value of true to enable query highlight markers (see below).",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of items to return per page.",
+            },
+            ParamInfo {
+                name: "page",
+                required: false,
+                description: "Page number of results to return.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "slackLists.create",
+        description: "Creates a list.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "The name of the list.",
+            },
+            ParamInfo {
+                name: "copy_from_list_id",
+                required: false,
+                description: "A list to copy the schema of.",
+            },
+            ParamInfo {
+                name: "description_blocks",
+                required: false,
+                description: "The description of the list, as rich text blocks.",
+            },
+            ParamInfo {
+                name: "include_copied_list_records",
+                required: false,
+                description: "Whether to copy the items of `copy_from_list_id` too.",
+            },
+            ParamInfo {
+                name: "schema",
+                required: false,
+                description: "The columns of the list. One of them must be a primary text column.",
+            },
+            ParamInfo {
+                name: "todo_mode",
+                required: false,
+                description: "Whether to add the completed, assignee and due date columns of a to-do list.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "slackLists.items.create",
+        description: "Adds an item to a list.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "list_id",
+                required: true,
+                description: "The list.",
+            },
+            ParamInfo {
+                name: "duplicated_item_id",
+                required: false,
+                description: "An item to copy the fields of.",
+            },
+            ParamInfo {
+                name: "initial_fields",
+                required: false,
+                description: "The fields of the new item.",
+            },
+            ParamInfo {
+                name: "parent_item_id",
+                required: false,
+                description: "The item to add the new item to as a subtask.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "slackLists.items.delete",
+        description: "Removes an item from a list.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "list_id",
+                required: true,
+                description: "The list.",
+            },
+            ParamInfo {
+                name: "id",
+                required: true,
+                description: "The item.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "slackLists.items.deleteMultiple",
+        description: "Removes several items from a list.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "list_id",
+                required: true,
+                description: "The list.",
+            },
+            ParamInfo {
+                name: "ids",
+                required: true,
+                description: "The items.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "slackLists.items.info",
+        description: "Gets an item of a list, with its subtasks.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "list_id",
+                required: true,
+                description: "The list.",
+            },
+            ParamInfo {
+                name: "id",
+                required: true,
+                description: "The item.",
+            },
+            ParamInfo {
+                name: "include_is_subscribed",
+                required: false,
+                description: "Whether to say if the calling user is subscribed to the item.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "slackLists.items.list",
+        description: "Lists the items of a list.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "list_id",
+                required: true,
+                description: "The list.",
+            },
+            ParamInfo {
+                name: "archived",
+                required: false,
+                description: "Whether to list archived items instead.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return, up to 1000. Defaults to 100.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "slackLists.items.update",
+        description: "Sets fields of items of a list.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "list_id",
+                required: true,
+                description: "The list.",
+            },
+            ParamInfo {
+                name: "cells",
+                required: true,
+                description: "The fields to set, each naming its item and column.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "slackLists.update",
+        description: "Updates the name, description or to-do mode of a list.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "id",
+                required: true,
+                description: "The list.",
+            },
+            ParamInfo {
+                name: "description_blocks",
+                required: false,
+                description: "The new description, as rich text blocks.",
+            },
+            ParamInfo {
+                name: "name",
+                required: false,
+                description: "The new name.",
+            },
+            ParamInfo {
+                name: "todo_mode",
+                required: false,
+                description: "Whether the list is a to-do list.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "stars.add",
+        description: "Adds a star to an item.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: false,
+                description: "File to add star to.",
+            },
+            ParamInfo {
+                name: "file_comment",
+                required: false,
+                description: "File comment to add star to.",
+            },
+            ParamInfo {
+                name: "channel",
+                required: false,
+                description: "Channel to add star to, or channel where the message to add star to was posted (used with timestamp).",
+            },
+            ParamInfo {
+                name: "timestamp",
+                required: false,
+                description: "Timestamp of the message to add star to.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "stars.list",
+        description: "Lists stars for a user.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of items to return per page.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Parameter for pagination. Set `cursor` equal to the `next_cursor` attribute returned by the previous request's `response_metadata`. This parameter is optional, but pagination is mandatory: the default value simply fetches the first \"page\" of the collection. See pagination for more details.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.",
+            },
+            ParamInfo {
+                name: "page",
+                required: false,
+                description: "Page number of results to return.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "stars.remove",
+        description: "Removes a star from an item.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "file",
+                required: false,
+                description: "File to remove star from.",
+            },
+            ParamInfo {
+                name: "file_comment",
+                required: false,
+                description: "File comment to remove star from.",
+            },
+            ParamInfo {
+                name: "channel",
+                required: false,
+                description: "Channel to remove star from, or channel where the message to remove star from was posted (used with timestamp).",
+            },
+            ParamInfo {
+                name: "timestamp",
+                required: false,
+                description: "Timestamp of the message to remove star from.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "team.accessLogs",
+        description: "Gets the access logs for the current team.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of items to return per page.",
+            },
+            ParamInfo {
+                name: "page",
+                required: false,
+                description: "Page number of results to return.",
+            },
+            ParamInfo {
+                name: "before",
+                required: false,
+                description: "End of time range of logs to include in results (inclusive).",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "team.billableInfo",
+        description: "Gets billable users information for the current team.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user",
+                required: false,
+                description: "A user to retrieve the billable information for. Defaults to all users.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "team.externalTeams.disconnect",
+        description: "Disconnects an external organization, removing it from every shared channel.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "target_team",
+                required: true,
+                description: "The external organization to disconnect.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "team.externalTeams.list",
+        description: "Lists the external organizations connected to the workspace.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "connection_status_filter",
+                required: false,
+                description: "Only list organizations with this status: `CONNECTED`, `DISCONNECTED`, `BLOCKED` or `IN_REVIEW`.",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of organizations to return, up to 1000. Defaults to 20.",
+            },
+            ParamInfo {
+                name: "slack_connect_pref_filter",
+                required: false,
+                description: "Only list organizations with these Slack Connect preferences.",
+            },
+            ParamInfo {
+                name: "sort_direction",
+                required: false,
+                description: "`asc` by default, or `desc`.",
+            },
+            ParamInfo {
+                name: "sort_field",
+                required: false,
+                description: "`team_name` by default, `last_active_timestamp` or `connection_status`.",
+            },
+            ParamInfo {
+                name: "workspace_filter",
+                required: false,
+                description: "Only list organizations connected to these workspaces of an Enterprise Grid organization.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "team.info",
+        description: "Gets information about the current team.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "team.integrationLogs",
+        description: "Gets the integration logs for the current team.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "service_id",
+                required: false,
+                description: "Filter logs to this service. Defaults to all logs.",
+            },
+            ParamInfo {
+                name: "app_id",
+                required: false,
+                description: "Filter logs to this Slack app. Defaults to all logs.",
+            },
+            ParamInfo {
+                name: "user",
+                required: false,
+                description: "Filter logs generated by this user’s actions. Defaults to all logs.",
+            },
+            ParamInfo {
+                name: "change_type",
+                required: false,
+                description: "Filter logs with this change type. Defaults to all logs.",
+            },
+            ParamInfo {
+                name: "count",
+                required: false,
+                description: "Number of items to return per page.",
+            },
+            ParamInfo {
+                name: "page",
+                required: false,
+                description: "Page number of results to return.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "team.preferences.list",
+        description: "Retrieve a list of a workspace's team preferences.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "team.profile.get",
+        description: "Retrieve a team's profile.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "visibility",
+                required: false,
+                description: "Filter by visibility.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "usergroups.create",
+        description: "Create a User Group",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "name",
+                required: true,
+                description: "A name for the User Group. Must be unique among User Groups.",
+            },
+            ParamInfo {
+                name: "handle",
+                required: false,
+                description: "A mention handle. Must be unique among channels, users and User Groups.",
+            },
+            ParamInfo {
+                name: "description",
+                required: false,
+                description: "A short description of the User Group.",
+            },
+            ParamInfo {
+                name: "channels",
+                required: false,
+                description: "A comma separated string of encoded channel IDs for which the User Group uses as a default.",
+            },
+            ParamInfo {
+                name: "include_count",
+                required: false,
+                description: "Include the number of users in each User Group.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "usergroups.disable",
+        description: "Disable an existing User Group",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "usergroup",
+                required: true,
+                description: "The encoded ID of the User Group to disable.",
+            },
+            ParamInfo {
+                name: "include_count",
+                required: false,
+                description: "Include the number of users in the User Group.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "usergroups.enable",
+        description: "Enable a User Group",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "usergroup",
+                required: true,
+                description: "The encoded ID of the User Group to enable.",
+            },
+            ParamInfo {
+                name: "include_count",
+                required: false,
+                description: "Include the number of users in the User Group.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "usergroups.list",
+        description: "List all User Groups for a team",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "include_disabled",
+                required: false,
+                description: "Include disabled User Groups.",
+            },
+            ParamInfo {
+                name: "include_count",
+                required: false,
+                description: "Include the number of users in each User Group.",
+            },
+            ParamInfo {
+                name: "include_users",
+                required: false,
+                description: "Include the list of users for each User Group.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "usergroups.update",
+        description: "Update an existing User Group",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "usergroup",
+                required: true,
+                description: "The encoded ID of the User Group to update.",
+            },
+            ParamInfo {
+                name: "name",
+                required: false,
+                description: "A name for the User Group. Must be unique among User Groups.",
+            },
+            ParamInfo {
+                name: "handle",
+                required: false,
+                description: "A mention handle. Must be unique among channels, users and User Groups.",
+            },
+            ParamInfo {
+                name: "description",
+                required: false,
+                description: "A short description of the User Group.",
+            },
+            ParamInfo {
+                name: "channels",
+                required: false,
+                description: "A comma separated string of encoded channel IDs for which the User Group uses as a default.",
+            },
+            ParamInfo {
+                name: "include_count",
+                required: false,
+                description: "Include the number of users in the User Group.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "usergroups.users.list",
+        description: "List all users in a User Group",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "usergroup",
+                required: true,
+                description: "The encoded ID of the User Group to update.",
+            },
+            ParamInfo {
+                name: "include_disabled",
+                required: false,
+                description: "Allow results that involve disabled User Groups.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "usergroups.users.update",
+        description: "Update the list of users for a User Group",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "usergroup",
+                required: true,
+                description: "The encoded ID of the User Group to update.",
+            },
+            ParamInfo {
+                name: "users",
+                required: true,
+                description: "A comma separated string of encoded user IDs that represent the entire list of users for the User Group.",
+            },
+            ParamInfo {
+                name: "include_count",
+                required: false,
+                description: "Include the number of users in the User Group.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "users.admin.invite",
+        description: "Invites a user to the workspace by email. Not available on Enterprise Grid, which uses admin.users.invite instead.",
+        unstable: true,
+        params: &[
+            ParamInfo {
+                name: "email",
+                required: true,
+                description: "Email address of the person to invite.",
+            },
+            ParamInfo {
+                name: "channels",
+                required: false,
+                description: "Comma separated list of channel IDs the user will automatically join.",
+            },
+            ParamInfo {
+                name: "first_name",
+                required: false,
+                description: "First name of the invitee.",
+            },
+            ParamInfo {
+                name: "last_name",
+                required: false,
+                description: "Last name of the invitee.",
+            },
+            ParamInfo {
+                name: "restricted",
+                required: false,
+                description: "Invite the user as a multi-channel guest.",
+            },
+            ParamInfo {
+                name: "ultra_restricted",
+                required: false,
+                description: "Invite the user as a single-channel guest. Requires exactly one channel.",
+            },
+            ParamInfo {
+                name: "resend",
+                required: false,
+                description: "Resend the invitation if the user has already been invited.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "users.deletePhoto",
+        description: "Delete the user profile photo",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "users.getPresence",
+        description: "Gets user presence information.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user",
+                required: true,
+                description: "User to get presence info on. Defaults to the authed user.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "users.identity",
+        description: "Get a user's identity.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "users.info",
+        description: "Gets information about a user.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user",
+                required: true,
+                description: "User to get info on",
+            },
+            ParamInfo {
+                name: "include_locale",
+                required: false,
+                description: "Set this to true to receive the locale for this user. Defaults to false",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "users.list",
+        description: "Lists all users in a Slack team.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "presence",
+                required: false,
+                description: "Whether to include presence data in the output",
+            },
+            ParamInfo {
+                name: "cursor",
+                required: false,
+                description: "Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first \"page\" of the collection. See pagination for more detail.",
+            },
+            ParamInfo {
+                name: "limit",
+                required: false,
+                description: "The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.",
+            },
+            ParamInfo {
+                name: "include_locale",
+                required: false,
+                description: "Set this to true to receive the locale for users. Defaults to false",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "users.lookupByEmail",
+        description: "Find a user with an email address.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "email",
+                required: true,
+                description: "An email address belonging to a user in the workspace",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "users.profile.get",
+        description: "Retrieves a user's profile information.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user",
+                required: false,
+                description: "User to retrieve profile info for",
+            },
+            ParamInfo {
+                name: "include_labels",
+                required: false,
+                description: "Include labels for each ID in custom profile fields",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "users.profile.set",
+        description: "Set the profile information for a user.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user",
+                required: false,
+                description: "ID of user to change. This argument may only be specified by team admins on paid teams.",
+            },
+            ParamInfo {
+                name: "profile",
+                required: false,
+                description: "Collection of key:value pairs presented as a URL-encoded JSON hash.",
+            },
+            ParamInfo {
+                name: "name",
+                required: false,
+                description: "Name of a single key to set. Usable only if profile is not passed.",
+            },
+            ParamInfo {
+                name: "value",
+                required: false,
+                description: "Value to set a single key to. Usable only if profile is not passed.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "users.setActive",
+        description: "Marks a user as active.",
+        unstable: false,
+        params: &[],
+    },
+    MethodInfo {
+        name: "users.setPresence",
+        description: "Manually sets user presence.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "presence",
+                required: true,
+                description: "Either auto or away",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "views.open",
+        description: "Open a view for a user.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "trigger_id",
+                required: true,
+                description: "Exchange a trigger to post to the user.",
+            },
+            ParamInfo {
+                name: "view",
+                required: true,
+                description: "A view payload.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "views.publish",
+        description: "Publish a static view for a User.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "user_id",
+                required: true,
+                description: "id of the user you want publish a view to.",
+            },
+            ParamInfo {
+                name: "view",
+                required: true,
+                description: "A view payload.",
+            },
+            ParamInfo {
+                name: "hash",
+                required: false,
+                description: "A string that represents view state to protect against possible race conditions.",
+            },
+        ],
+    },
+    MethodInfo {
+        name: "views.push",
+        description: "Push a view onto the stack of a root view.",
+        unstable: false,
+        params: &[
+            ParamInfo {
+                name: "trigger_id",
+                required: true,
+                description: "Exchange a trigger to post to the user.",
+            },
+            ParamInfo {
+                name: "view",
+                required: true,
+                description: "A view payload.",
+            },
+        ],
+    },
+];
@@ -0,0 +1,596 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Assign users to the authentication policies of an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct AssignEntitiesRequest<'a> {
+    /// The IDs of the entities, at most 50.
+    pub entity_ids: &'a [&'a str],
+    /// The type of the entities.
+    pub entity_type: crate::AuthPolicyEntityType,
+    /// The authentication policy.
+    pub policy_name: crate::AuthPolicyName,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AssignEntitiesResponse {
+    pub entity_total_count: Option<i32>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<AssignEntitiesResponse>
+    for Result<AssignEntitiesResponse, AssignEntitiesError<E>>
+{
+    fn from(response: AssignEntitiesResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AssignEntitiesError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// One of the entities wasn't found.
+    EntityNotFound,
+    /// One of the entity IDs was invalid.
+    InvalidEntityId,
+    /// The entity type was invalid.
+    InvalidEntityType,
+    /// The policy name was invalid.
+    InvalidPolicyName,
+    /// More entities were given than the method accepts at once.
+    TooManyEntities,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AssignEntitiesError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => AssignEntitiesError::FeatureNotEnabled,
+            "not_an_admin" => AssignEntitiesError::NotAnAdmin,
+            "not_an_enterprise" => AssignEntitiesError::NotAnEnterprise,
+            "missing_scope" => AssignEntitiesError::MissingScope,
+            "ratelimited" => AssignEntitiesError::Ratelimited,
+            "entity_not_found" => AssignEntitiesError::EntityNotFound,
+            "invalid_entity_id" => AssignEntitiesError::InvalidEntityId,
+            "invalid_entity_type" => AssignEntitiesError::InvalidEntityType,
+            "invalid_policy_name" => AssignEntitiesError::InvalidPolicyName,
+            "too_many_entities" => AssignEntitiesError::TooManyEntities,
+            "not_authed" => AssignEntitiesError::NotAuthed,
+            "invalid_auth" => AssignEntitiesError::InvalidAuth,
+            "account_inactive" => AssignEntitiesError::AccountInactive,
+            "invalid_arg_name" => AssignEntitiesError::InvalidArgName,
+            "invalid_array_arg" => AssignEntitiesError::InvalidArrayArg,
+            "invalid_charset" => AssignEntitiesError::InvalidCharset,
+            "invalid_form_data" => AssignEntitiesError::InvalidFormData,
+            "invalid_post_type" => AssignEntitiesError::InvalidPostType,
+            "missing_post_type" => AssignEntitiesError::MissingPostType,
+            "team_added_to_org" => AssignEntitiesError::TeamAddedToOrg,
+            "request_timeout" => AssignEntitiesError::RequestTimeout,
+            _ => AssignEntitiesError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AssignEntitiesError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        AssignEntitiesError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+AssignEntitiesError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+AssignEntitiesError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+AssignEntitiesError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+AssignEntitiesError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+AssignEntitiesError::EntityNotFound => "entity_not_found: One of the entities wasn't found.",
+AssignEntitiesError::InvalidEntityId => "invalid_entity_id: One of the entity IDs was invalid.",
+AssignEntitiesError::InvalidEntityType => "invalid_entity_type: The entity type was invalid.",
+AssignEntitiesError::InvalidPolicyName => "invalid_policy_name: The policy name was invalid.",
+AssignEntitiesError::TooManyEntities => "too_many_entities: More entities were given than the method accepts at once.",
+AssignEntitiesError::NotAuthed => "not_authed: No authentication token provided.",
+AssignEntitiesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+AssignEntitiesError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+AssignEntitiesError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+AssignEntitiesError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+AssignEntitiesError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+AssignEntitiesError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+AssignEntitiesError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+AssignEntitiesError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+AssignEntitiesError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+AssignEntitiesError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        AssignEntitiesError::MalformedResponse(_, ref e) => return write!(f, "admin.auth.policy.assignEntities returned a malformed response: {}", e),
+                        AssignEntitiesError::Unknown(ref s) => return write!(f, "admin.auth.policy.assignEntities failed: {}", s),
+                        AssignEntitiesError::Client(ref inner) => return write!(f, "admin.auth.policy.assignEntities failed: {}", inner),
+                    };
+        write!(f, "admin.auth.policy.assignEntities failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AssignEntitiesError<E>> for crate::SlackError<E> {
+    fn from(e: AssignEntitiesError<E>) -> Self {
+        let method = "admin.auth.policy.assignEntities";
+        let error = match e {
+            AssignEntitiesError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            AssignEntitiesError::NotAnAdmin => "not_an_admin".to_owned(),
+            AssignEntitiesError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            AssignEntitiesError::MissingScope => "missing_scope".to_owned(),
+            AssignEntitiesError::Ratelimited => "ratelimited".to_owned(),
+            AssignEntitiesError::EntityNotFound => "entity_not_found".to_owned(),
+            AssignEntitiesError::InvalidEntityId => "invalid_entity_id".to_owned(),
+            AssignEntitiesError::InvalidEntityType => "invalid_entity_type".to_owned(),
+            AssignEntitiesError::InvalidPolicyName => "invalid_policy_name".to_owned(),
+            AssignEntitiesError::TooManyEntities => "too_many_entities".to_owned(),
+            AssignEntitiesError::NotAuthed => "not_authed".to_owned(),
+            AssignEntitiesError::InvalidAuth => "invalid_auth".to_owned(),
+            AssignEntitiesError::AccountInactive => "account_inactive".to_owned(),
+            AssignEntitiesError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AssignEntitiesError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AssignEntitiesError::InvalidCharset => "invalid_charset".to_owned(),
+            AssignEntitiesError::InvalidFormData => "invalid_form_data".to_owned(),
+            AssignEntitiesError::InvalidPostType => "invalid_post_type".to_owned(),
+            AssignEntitiesError::MissingPostType => "missing_post_type".to_owned(),
+            AssignEntitiesError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AssignEntitiesError::RequestTimeout => "request_timeout".to_owned(),
+            AssignEntitiesError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AssignEntitiesError::Unknown(s) => s,
+            AssignEntitiesError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for AssignEntitiesError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AssignEntitiesError::MalformedResponse(_, ref e) => Some(e),
+            AssignEntitiesError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct GetEntitiesRequest<'a> {
+    /// The authentication policy.
+    pub policy_name: crate::AuthPolicyName,
+    /// Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// Only list entities of this type.
+    pub entity_type: Option<crate::AuthPolicyEntityType>,
+    /// The maximum number of entities to return, up to 1000. Defaults to 100.
+    pub limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GetEntitiesResponse {
+    pub entities: Option<Vec<crate::AuthPolicyEntity>>,
+    pub entity_total_count: Option<i32>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for GetEntitiesResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<GetEntitiesResponse> for Result<GetEntitiesResponse, GetEntitiesError<E>> {
+    fn from(response: GetEntitiesResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum GetEntitiesError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The cursor was invalid or expired.
+    InvalidCursor,
+    /// The entity type was invalid.
+    InvalidEntityType,
+    /// The policy name was invalid.
+    InvalidPolicyName,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for GetEntitiesError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => GetEntitiesError::FeatureNotEnabled,
+            "not_an_admin" => GetEntitiesError::NotAnAdmin,
+            "not_an_enterprise" => GetEntitiesError::NotAnEnterprise,
+            "missing_scope" => GetEntitiesError::MissingScope,
+            "ratelimited" => GetEntitiesError::Ratelimited,
+            "invalid_cursor" => GetEntitiesError::InvalidCursor,
+            "invalid_entity_type" => GetEntitiesError::InvalidEntityType,
+            "invalid_policy_name" => GetEntitiesError::InvalidPolicyName,
+            "not_authed" => GetEntitiesError::NotAuthed,
+            "invalid_auth" => GetEntitiesError::InvalidAuth,
+            "account_inactive" => GetEntitiesError::AccountInactive,
+            "invalid_arg_name" => GetEntitiesError::InvalidArgName,
+            "invalid_array_arg" => GetEntitiesError::InvalidArrayArg,
+            "invalid_charset" => GetEntitiesError::InvalidCharset,
+            "invalid_form_data" => GetEntitiesError::InvalidFormData,
+            "invalid_post_type" => GetEntitiesError::InvalidPostType,
+            "missing_post_type" => GetEntitiesError::MissingPostType,
+            "team_added_to_org" => GetEntitiesError::TeamAddedToOrg,
+            "request_timeout" => GetEntitiesError::RequestTimeout,
+            _ => GetEntitiesError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for GetEntitiesError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        GetEntitiesError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+GetEntitiesError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+GetEntitiesError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+GetEntitiesError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+GetEntitiesError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+GetEntitiesError::InvalidCursor => "invalid_cursor: The cursor was invalid or expired.",
+GetEntitiesError::InvalidEntityType => "invalid_entity_type: The entity type was invalid.",
+GetEntitiesError::InvalidPolicyName => "invalid_policy_name: The policy name was invalid.",
+GetEntitiesError::NotAuthed => "not_authed: No authentication token provided.",
+GetEntitiesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+GetEntitiesError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+GetEntitiesError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+GetEntitiesError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+GetEntitiesError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+GetEntitiesError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+GetEntitiesError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+GetEntitiesError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+GetEntitiesError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+GetEntitiesError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        GetEntitiesError::MalformedResponse(_, ref e) => return write!(f, "admin.auth.policy.getEntities returned a malformed response: {}", e),
+                        GetEntitiesError::Unknown(ref s) => return write!(f, "admin.auth.policy.getEntities failed: {}", s),
+                        GetEntitiesError::Client(ref inner) => return write!(f, "admin.auth.policy.getEntities failed: {}", inner),
+                    };
+        write!(f, "admin.auth.policy.getEntities failed: {}", d)
+    }
+}
+
+impl<E: Error> From<GetEntitiesError<E>> for crate::SlackError<E> {
+    fn from(e: GetEntitiesError<E>) -> Self {
+        let method = "admin.auth.policy.getEntities";
+        let error = match e {
+            GetEntitiesError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            GetEntitiesError::NotAnAdmin => "not_an_admin".to_owned(),
+            GetEntitiesError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            GetEntitiesError::MissingScope => "missing_scope".to_owned(),
+            GetEntitiesError::Ratelimited => "ratelimited".to_owned(),
+            GetEntitiesError::InvalidCursor => "invalid_cursor".to_owned(),
+            GetEntitiesError::InvalidEntityType => "invalid_entity_type".to_owned(),
+            GetEntitiesError::InvalidPolicyName => "invalid_policy_name".to_owned(),
+            GetEntitiesError::NotAuthed => "not_authed".to_owned(),
+            GetEntitiesError::InvalidAuth => "invalid_auth".to_owned(),
+            GetEntitiesError::AccountInactive => "account_inactive".to_owned(),
+            GetEntitiesError::InvalidArgName => "invalid_arg_name".to_owned(),
+            GetEntitiesError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            GetEntitiesError::InvalidCharset => "invalid_charset".to_owned(),
+            GetEntitiesError::InvalidFormData => "invalid_form_data".to_owned(),
+            GetEntitiesError::InvalidPostType => "invalid_post_type".to_owned(),
+            GetEntitiesError::MissingPostType => "missing_post_type".to_owned(),
+            GetEntitiesError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            GetEntitiesError::RequestTimeout => "request_timeout".to_owned(),
+            GetEntitiesError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            GetEntitiesError::Unknown(s) => s,
+            GetEntitiesError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for GetEntitiesError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            GetEntitiesError::MalformedResponse(_, ref e) => Some(e),
+            GetEntitiesError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RemoveEntitiesRequest<'a> {
+    /// The IDs of the entities, at most 50.
+    pub entity_ids: &'a [&'a str],
+    /// The type of the entities.
+    pub entity_type: crate::AuthPolicyEntityType,
+    /// The authentication policy.
+    pub policy_name: crate::AuthPolicyName,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RemoveEntitiesResponse {
+    pub entity_total_count: Option<i32>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<RemoveEntitiesResponse>
+    for Result<RemoveEntitiesResponse, RemoveEntitiesError<E>>
+{
+    fn from(response: RemoveEntitiesResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveEntitiesError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// One of the entities wasn't found.
+    EntityNotFound,
+    /// One of the entity IDs was invalid.
+    InvalidEntityId,
+    /// The entity type was invalid.
+    InvalidEntityType,
+    /// The policy name was invalid.
+    InvalidPolicyName,
+    /// More entities were given than the method accepts at once.
+    TooManyEntities,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveEntitiesError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => RemoveEntitiesError::FeatureNotEnabled,
+            "not_an_admin" => RemoveEntitiesError::NotAnAdmin,
+            "not_an_enterprise" => RemoveEntitiesError::NotAnEnterprise,
+            "missing_scope" => RemoveEntitiesError::MissingScope,
+            "ratelimited" => RemoveEntitiesError::Ratelimited,
+            "entity_not_found" => RemoveEntitiesError::EntityNotFound,
+            "invalid_entity_id" => RemoveEntitiesError::InvalidEntityId,
+            "invalid_entity_type" => RemoveEntitiesError::InvalidEntityType,
+            "invalid_policy_name" => RemoveEntitiesError::InvalidPolicyName,
+            "too_many_entities" => RemoveEntitiesError::TooManyEntities,
+            "not_authed" => RemoveEntitiesError::NotAuthed,
+            "invalid_auth" => RemoveEntitiesError::InvalidAuth,
+            "account_inactive" => RemoveEntitiesError::AccountInactive,
+            "invalid_arg_name" => RemoveEntitiesError::InvalidArgName,
+            "invalid_array_arg" => RemoveEntitiesError::InvalidArrayArg,
+            "invalid_charset" => RemoveEntitiesError::InvalidCharset,
+            "invalid_form_data" => RemoveEntitiesError::InvalidFormData,
+            "invalid_post_type" => RemoveEntitiesError::InvalidPostType,
+            "missing_post_type" => RemoveEntitiesError::MissingPostType,
+            "team_added_to_org" => RemoveEntitiesError::TeamAddedToOrg,
+            "request_timeout" => RemoveEntitiesError::RequestTimeout,
+            _ => RemoveEntitiesError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveEntitiesError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        RemoveEntitiesError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+RemoveEntitiesError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+RemoveEntitiesError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+RemoveEntitiesError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+RemoveEntitiesError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+RemoveEntitiesError::EntityNotFound => "entity_not_found: One of the entities wasn't found.",
+RemoveEntitiesError::InvalidEntityId => "invalid_entity_id: One of the entity IDs was invalid.",
+RemoveEntitiesError::InvalidEntityType => "invalid_entity_type: The entity type was invalid.",
+RemoveEntitiesError::InvalidPolicyName => "invalid_policy_name: The policy name was invalid.",
+RemoveEntitiesError::TooManyEntities => "too_many_entities: More entities were given than the method accepts at once.",
+RemoveEntitiesError::NotAuthed => "not_authed: No authentication token provided.",
+RemoveEntitiesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+RemoveEntitiesError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+RemoveEntitiesError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+RemoveEntitiesError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+RemoveEntitiesError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+RemoveEntitiesError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+RemoveEntitiesError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+RemoveEntitiesError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+RemoveEntitiesError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+RemoveEntitiesError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        RemoveEntitiesError::MalformedResponse(_, ref e) => return write!(f, "admin.auth.policy.removeEntities returned a malformed response: {}", e),
+                        RemoveEntitiesError::Unknown(ref s) => return write!(f, "admin.auth.policy.removeEntities failed: {}", s),
+                        RemoveEntitiesError::Client(ref inner) => return write!(f, "admin.auth.policy.removeEntities failed: {}", inner),
+                    };
+        write!(f, "admin.auth.policy.removeEntities failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RemoveEntitiesError<E>> for crate::SlackError<E> {
+    fn from(e: RemoveEntitiesError<E>) -> Self {
+        let method = "admin.auth.policy.removeEntities";
+        let error = match e {
+            RemoveEntitiesError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            RemoveEntitiesError::NotAnAdmin => "not_an_admin".to_owned(),
+            RemoveEntitiesError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            RemoveEntitiesError::MissingScope => "missing_scope".to_owned(),
+            RemoveEntitiesError::Ratelimited => "ratelimited".to_owned(),
+            RemoveEntitiesError::EntityNotFound => "entity_not_found".to_owned(),
+            RemoveEntitiesError::InvalidEntityId => "invalid_entity_id".to_owned(),
+            RemoveEntitiesError::InvalidEntityType => "invalid_entity_type".to_owned(),
+            RemoveEntitiesError::InvalidPolicyName => "invalid_policy_name".to_owned(),
+            RemoveEntitiesError::TooManyEntities => "too_many_entities".to_owned(),
+            RemoveEntitiesError::NotAuthed => "not_authed".to_owned(),
+            RemoveEntitiesError::InvalidAuth => "invalid_auth".to_owned(),
+            RemoveEntitiesError::AccountInactive => "account_inactive".to_owned(),
+            RemoveEntitiesError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RemoveEntitiesError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RemoveEntitiesError::InvalidCharset => "invalid_charset".to_owned(),
+            RemoveEntitiesError::InvalidFormData => "invalid_form_data".to_owned(),
+            RemoveEntitiesError::InvalidPostType => "invalid_post_type".to_owned(),
+            RemoveEntitiesError::MissingPostType => "missing_post_type".to_owned(),
+            RemoveEntitiesError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RemoveEntitiesError::RequestTimeout => "request_timeout".to_owned(),
+            RemoveEntitiesError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RemoveEntitiesError::Unknown(s) => s,
+            RemoveEntitiesError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for RemoveEntitiesError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RemoveEntitiesError::MalformedResponse(_, ref e) => Some(e),
+            RemoveEntitiesError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
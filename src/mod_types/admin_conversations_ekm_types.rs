@@ -0,0 +1,224 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Look up the channels of an Enterprise Grid organization using Enterprise Key Management.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct ListOriginalConnectedChannelInfoRequest<'a> {
+    /// Only list these channels.
+    pub channel_ids: Option<&'a [&'a str]>,
+    /// Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// The maximum number of channels to return, up to 1000. Defaults to 100.
+    pub limit: Option<u32>,
+    /// Only list the channels of these workspaces.
+    pub team_ids: Option<&'a [&'a str]>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListOriginalConnectedChannelInfoResponse {
+    pub channels: Option<Vec<crate::OriginalConnectedChannel>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for ListOriginalConnectedChannelInfoResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<ListOriginalConnectedChannelInfoResponse>
+    for Result<ListOriginalConnectedChannelInfoResponse, ListOriginalConnectedChannelInfoError<E>>
+{
+    fn from(response: ListOriginalConnectedChannelInfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListOriginalConnectedChannelInfoError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The cursor was invalid or expired.
+    InvalidCursor,
+    /// The organization doesn't use Enterprise Key Management.
+    EkmNotEnabled,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListOriginalConnectedChannelInfoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => ListOriginalConnectedChannelInfoError::FeatureNotEnabled,
+            "not_an_admin" => ListOriginalConnectedChannelInfoError::NotAnAdmin,
+            "not_an_enterprise" => ListOriginalConnectedChannelInfoError::NotAnEnterprise,
+            "missing_scope" => ListOriginalConnectedChannelInfoError::MissingScope,
+            "ratelimited" => ListOriginalConnectedChannelInfoError::Ratelimited,
+            "invalid_cursor" => ListOriginalConnectedChannelInfoError::InvalidCursor,
+            "ekm_not_enabled" => ListOriginalConnectedChannelInfoError::EkmNotEnabled,
+            "not_authed" => ListOriginalConnectedChannelInfoError::NotAuthed,
+            "invalid_auth" => ListOriginalConnectedChannelInfoError::InvalidAuth,
+            "account_inactive" => ListOriginalConnectedChannelInfoError::AccountInactive,
+            "invalid_arg_name" => ListOriginalConnectedChannelInfoError::InvalidArgName,
+            "invalid_array_arg" => ListOriginalConnectedChannelInfoError::InvalidArrayArg,
+            "invalid_charset" => ListOriginalConnectedChannelInfoError::InvalidCharset,
+            "invalid_form_data" => ListOriginalConnectedChannelInfoError::InvalidFormData,
+            "invalid_post_type" => ListOriginalConnectedChannelInfoError::InvalidPostType,
+            "missing_post_type" => ListOriginalConnectedChannelInfoError::MissingPostType,
+            "team_added_to_org" => ListOriginalConnectedChannelInfoError::TeamAddedToOrg,
+            "request_timeout" => ListOriginalConnectedChannelInfoError::RequestTimeout,
+            _ => ListOriginalConnectedChannelInfoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListOriginalConnectedChannelInfoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ListOriginalConnectedChannelInfoError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+ListOriginalConnectedChannelInfoError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+ListOriginalConnectedChannelInfoError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+ListOriginalConnectedChannelInfoError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ListOriginalConnectedChannelInfoError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ListOriginalConnectedChannelInfoError::InvalidCursor => "invalid_cursor: The cursor was invalid or expired.",
+ListOriginalConnectedChannelInfoError::EkmNotEnabled => "ekm_not_enabled: The organization doesn't use Enterprise Key Management.",
+ListOriginalConnectedChannelInfoError::NotAuthed => "not_authed: No authentication token provided.",
+ListOriginalConnectedChannelInfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ListOriginalConnectedChannelInfoError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ListOriginalConnectedChannelInfoError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ListOriginalConnectedChannelInfoError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ListOriginalConnectedChannelInfoError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ListOriginalConnectedChannelInfoError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ListOriginalConnectedChannelInfoError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ListOriginalConnectedChannelInfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ListOriginalConnectedChannelInfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ListOriginalConnectedChannelInfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ListOriginalConnectedChannelInfoError::MalformedResponse(_, ref e) => return write!(f, "admin.conversations.ekm.listOriginalConnectedChannelInfo returned a malformed response: {}", e),
+                        ListOriginalConnectedChannelInfoError::Unknown(ref s) => return write!(f, "admin.conversations.ekm.listOriginalConnectedChannelInfo failed: {}", s),
+                        ListOriginalConnectedChannelInfoError::Client(ref inner) => return write!(f, "admin.conversations.ekm.listOriginalConnectedChannelInfo failed: {}", inner),
+                    };
+        write!(
+            f,
+            "admin.conversations.ekm.listOriginalConnectedChannelInfo failed: {}",
+            d
+        )
+    }
+}
+
+impl<E: Error> From<ListOriginalConnectedChannelInfoError<E>> for crate::SlackError<E> {
+    fn from(e: ListOriginalConnectedChannelInfoError<E>) -> Self {
+        let method = "admin.conversations.ekm.listOriginalConnectedChannelInfo";
+        let error = match e {
+            ListOriginalConnectedChannelInfoError::FeatureNotEnabled => {
+                "feature_not_enabled".to_owned()
+            }
+            ListOriginalConnectedChannelInfoError::NotAnAdmin => "not_an_admin".to_owned(),
+            ListOriginalConnectedChannelInfoError::NotAnEnterprise => {
+                "not_an_enterprise".to_owned()
+            }
+            ListOriginalConnectedChannelInfoError::MissingScope => "missing_scope".to_owned(),
+            ListOriginalConnectedChannelInfoError::Ratelimited => "ratelimited".to_owned(),
+            ListOriginalConnectedChannelInfoError::InvalidCursor => "invalid_cursor".to_owned(),
+            ListOriginalConnectedChannelInfoError::EkmNotEnabled => "ekm_not_enabled".to_owned(),
+            ListOriginalConnectedChannelInfoError::NotAuthed => "not_authed".to_owned(),
+            ListOriginalConnectedChannelInfoError::InvalidAuth => "invalid_auth".to_owned(),
+            ListOriginalConnectedChannelInfoError::AccountInactive => "account_inactive".to_owned(),
+            ListOriginalConnectedChannelInfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListOriginalConnectedChannelInfoError::InvalidArrayArg => {
+                "invalid_array_arg".to_owned()
+            }
+            ListOriginalConnectedChannelInfoError::InvalidCharset => "invalid_charset".to_owned(),
+            ListOriginalConnectedChannelInfoError::InvalidFormData => {
+                "invalid_form_data".to_owned()
+            }
+            ListOriginalConnectedChannelInfoError::InvalidPostType => {
+                "invalid_post_type".to_owned()
+            }
+            ListOriginalConnectedChannelInfoError::MissingPostType => {
+                "missing_post_type".to_owned()
+            }
+            ListOriginalConnectedChannelInfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListOriginalConnectedChannelInfoError::RequestTimeout => "request_timeout".to_owned(),
+            ListOriginalConnectedChannelInfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListOriginalConnectedChannelInfoError::Unknown(s) => s,
+            ListOriginalConnectedChannelInfoError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ListOriginalConnectedChannelInfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ListOriginalConnectedChannelInfoError::MalformedResponse(_, ref e) => Some(e),
+            ListOriginalConnectedChannelInfoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
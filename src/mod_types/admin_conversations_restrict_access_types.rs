@@ -0,0 +1,586 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Limit who can join the private channels of an Enterprise Grid organization to members of allowlisted IDP groups.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct AddGroupRequest<'a> {
+    /// The private channel.
+    pub channel_id: &'a str,
+    /// The IDP group to allow.
+    pub group_id: &'a str,
+    /// The workspace of the channel. Required for channels that aren't shared across the organization.
+    pub team_id: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AddGroupResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<AddGroupResponse> for Result<AddGroupResponse, AddGroupError<E>> {
+    fn from(response: AddGroupResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddGroupError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The channel wasn't found.
+    ChannelNotFound,
+    /// The channel isn't a private channel.
+    InvalidChannelType,
+    /// The IDP group wasn't found.
+    GroupNotFound,
+    /// The admin isn't allowed to manage access to this channel.
+    RestrictedAction,
+    /// The channel already allows the maximum number of groups.
+    TooManyGroups,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddGroupError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => AddGroupError::FeatureNotEnabled,
+            "not_an_admin" => AddGroupError::NotAnAdmin,
+            "not_an_enterprise" => AddGroupError::NotAnEnterprise,
+            "missing_scope" => AddGroupError::MissingScope,
+            "ratelimited" => AddGroupError::Ratelimited,
+            "channel_not_found" => AddGroupError::ChannelNotFound,
+            "invalid_channel_type" => AddGroupError::InvalidChannelType,
+            "group_not_found" => AddGroupError::GroupNotFound,
+            "restricted_action" => AddGroupError::RestrictedAction,
+            "too_many_groups" => AddGroupError::TooManyGroups,
+            "not_authed" => AddGroupError::NotAuthed,
+            "invalid_auth" => AddGroupError::InvalidAuth,
+            "account_inactive" => AddGroupError::AccountInactive,
+            "invalid_arg_name" => AddGroupError::InvalidArgName,
+            "invalid_array_arg" => AddGroupError::InvalidArrayArg,
+            "invalid_charset" => AddGroupError::InvalidCharset,
+            "invalid_form_data" => AddGroupError::InvalidFormData,
+            "invalid_post_type" => AddGroupError::InvalidPostType,
+            "missing_post_type" => AddGroupError::MissingPostType,
+            "team_added_to_org" => AddGroupError::TeamAddedToOrg,
+            "request_timeout" => AddGroupError::RequestTimeout,
+            _ => AddGroupError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AddGroupError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        AddGroupError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+AddGroupError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+AddGroupError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+AddGroupError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+AddGroupError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+AddGroupError::ChannelNotFound => "channel_not_found: The channel wasn't found.",
+AddGroupError::InvalidChannelType => "invalid_channel_type: The channel isn't a private channel.",
+AddGroupError::GroupNotFound => "group_not_found: The IDP group wasn't found.",
+AddGroupError::RestrictedAction => "restricted_action: The admin isn't allowed to manage access to this channel.",
+AddGroupError::TooManyGroups => "too_many_groups: The channel already allows the maximum number of groups.",
+AddGroupError::NotAuthed => "not_authed: No authentication token provided.",
+AddGroupError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+AddGroupError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+AddGroupError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+AddGroupError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+AddGroupError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+AddGroupError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+AddGroupError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+AddGroupError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+AddGroupError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+AddGroupError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        AddGroupError::MalformedResponse(_, ref e) => return write!(f, "admin.conversations.restrictAccess.addGroup returned a malformed response: {}", e),
+                        AddGroupError::Unknown(ref s) => return write!(f, "admin.conversations.restrictAccess.addGroup failed: {}", s),
+                        AddGroupError::Client(ref inner) => return write!(f, "admin.conversations.restrictAccess.addGroup failed: {}", inner),
+                    };
+        write!(
+            f,
+            "admin.conversations.restrictAccess.addGroup failed: {}",
+            d
+        )
+    }
+}
+
+impl<E: Error> From<AddGroupError<E>> for crate::SlackError<E> {
+    fn from(e: AddGroupError<E>) -> Self {
+        let method = "admin.conversations.restrictAccess.addGroup";
+        let error = match e {
+            AddGroupError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            AddGroupError::NotAnAdmin => "not_an_admin".to_owned(),
+            AddGroupError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            AddGroupError::MissingScope => "missing_scope".to_owned(),
+            AddGroupError::Ratelimited => "ratelimited".to_owned(),
+            AddGroupError::ChannelNotFound => "channel_not_found".to_owned(),
+            AddGroupError::InvalidChannelType => "invalid_channel_type".to_owned(),
+            AddGroupError::GroupNotFound => "group_not_found".to_owned(),
+            AddGroupError::RestrictedAction => "restricted_action".to_owned(),
+            AddGroupError::TooManyGroups => "too_many_groups".to_owned(),
+            AddGroupError::NotAuthed => "not_authed".to_owned(),
+            AddGroupError::InvalidAuth => "invalid_auth".to_owned(),
+            AddGroupError::AccountInactive => "account_inactive".to_owned(),
+            AddGroupError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddGroupError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddGroupError::InvalidCharset => "invalid_charset".to_owned(),
+            AddGroupError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddGroupError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddGroupError::MissingPostType => "missing_post_type".to_owned(),
+            AddGroupError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddGroupError::RequestTimeout => "request_timeout".to_owned(),
+            AddGroupError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddGroupError::Unknown(s) => s,
+            AddGroupError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for AddGroupError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AddGroupError::MalformedResponse(_, ref e) => Some(e),
+            AddGroupError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListGroupsRequest<'a> {
+    /// The private channel.
+    pub channel_id: &'a str,
+    /// The workspace of the channel. Required for channels that aren't shared across the organization.
+    pub team_id: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListGroupsResponse {
+    error: Option<String>,
+    pub group_ids: Option<Vec<String>>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<ListGroupsResponse> for Result<ListGroupsResponse, ListGroupsError<E>> {
+    fn from(response: ListGroupsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListGroupsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The channel wasn't found.
+    ChannelNotFound,
+    /// The channel isn't a private channel.
+    InvalidChannelType,
+    /// The IDP group wasn't found.
+    GroupNotFound,
+    /// The admin isn't allowed to manage access to this channel.
+    RestrictedAction,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListGroupsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => ListGroupsError::FeatureNotEnabled,
+            "not_an_admin" => ListGroupsError::NotAnAdmin,
+            "not_an_enterprise" => ListGroupsError::NotAnEnterprise,
+            "missing_scope" => ListGroupsError::MissingScope,
+            "ratelimited" => ListGroupsError::Ratelimited,
+            "channel_not_found" => ListGroupsError::ChannelNotFound,
+            "invalid_channel_type" => ListGroupsError::InvalidChannelType,
+            "group_not_found" => ListGroupsError::GroupNotFound,
+            "restricted_action" => ListGroupsError::RestrictedAction,
+            "not_authed" => ListGroupsError::NotAuthed,
+            "invalid_auth" => ListGroupsError::InvalidAuth,
+            "account_inactive" => ListGroupsError::AccountInactive,
+            "invalid_arg_name" => ListGroupsError::InvalidArgName,
+            "invalid_array_arg" => ListGroupsError::InvalidArrayArg,
+            "invalid_charset" => ListGroupsError::InvalidCharset,
+            "invalid_form_data" => ListGroupsError::InvalidFormData,
+            "invalid_post_type" => ListGroupsError::InvalidPostType,
+            "missing_post_type" => ListGroupsError::MissingPostType,
+            "team_added_to_org" => ListGroupsError::TeamAddedToOrg,
+            "request_timeout" => ListGroupsError::RequestTimeout,
+            _ => ListGroupsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListGroupsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ListGroupsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+ListGroupsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+ListGroupsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+ListGroupsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ListGroupsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ListGroupsError::ChannelNotFound => "channel_not_found: The channel wasn't found.",
+ListGroupsError::InvalidChannelType => "invalid_channel_type: The channel isn't a private channel.",
+ListGroupsError::GroupNotFound => "group_not_found: The IDP group wasn't found.",
+ListGroupsError::RestrictedAction => "restricted_action: The admin isn't allowed to manage access to this channel.",
+ListGroupsError::NotAuthed => "not_authed: No authentication token provided.",
+ListGroupsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ListGroupsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ListGroupsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ListGroupsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ListGroupsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ListGroupsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ListGroupsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ListGroupsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ListGroupsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ListGroupsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ListGroupsError::MalformedResponse(_, ref e) => return write!(f, "admin.conversations.restrictAccess.listGroups returned a malformed response: {}", e),
+                        ListGroupsError::Unknown(ref s) => return write!(f, "admin.conversations.restrictAccess.listGroups failed: {}", s),
+                        ListGroupsError::Client(ref inner) => return write!(f, "admin.conversations.restrictAccess.listGroups failed: {}", inner),
+                    };
+        write!(
+            f,
+            "admin.conversations.restrictAccess.listGroups failed: {}",
+            d
+        )
+    }
+}
+
+impl<E: Error> From<ListGroupsError<E>> for crate::SlackError<E> {
+    fn from(e: ListGroupsError<E>) -> Self {
+        let method = "admin.conversations.restrictAccess.listGroups";
+        let error = match e {
+            ListGroupsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            ListGroupsError::NotAnAdmin => "not_an_admin".to_owned(),
+            ListGroupsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            ListGroupsError::MissingScope => "missing_scope".to_owned(),
+            ListGroupsError::Ratelimited => "ratelimited".to_owned(),
+            ListGroupsError::ChannelNotFound => "channel_not_found".to_owned(),
+            ListGroupsError::InvalidChannelType => "invalid_channel_type".to_owned(),
+            ListGroupsError::GroupNotFound => "group_not_found".to_owned(),
+            ListGroupsError::RestrictedAction => "restricted_action".to_owned(),
+            ListGroupsError::NotAuthed => "not_authed".to_owned(),
+            ListGroupsError::InvalidAuth => "invalid_auth".to_owned(),
+            ListGroupsError::AccountInactive => "account_inactive".to_owned(),
+            ListGroupsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListGroupsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListGroupsError::InvalidCharset => "invalid_charset".to_owned(),
+            ListGroupsError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListGroupsError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListGroupsError::MissingPostType => "missing_post_type".to_owned(),
+            ListGroupsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListGroupsError::RequestTimeout => "request_timeout".to_owned(),
+            ListGroupsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListGroupsError::Unknown(s) => s,
+            ListGroupsError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ListGroupsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ListGroupsError::MalformedResponse(_, ref e) => Some(e),
+            ListGroupsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RemoveGroupRequest<'a> {
+    /// The private channel.
+    pub channel_id: &'a str,
+    /// The IDP group to remove.
+    pub group_id: &'a str,
+    /// The workspace of the channel.
+    pub team_id: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RemoveGroupResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<RemoveGroupResponse> for Result<RemoveGroupResponse, RemoveGroupError<E>> {
+    fn from(response: RemoveGroupResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveGroupError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The channel wasn't found.
+    ChannelNotFound,
+    /// The channel isn't a private channel.
+    InvalidChannelType,
+    /// The IDP group wasn't found.
+    GroupNotFound,
+    /// The admin isn't allowed to manage access to this channel.
+    RestrictedAction,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveGroupError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => RemoveGroupError::FeatureNotEnabled,
+            "not_an_admin" => RemoveGroupError::NotAnAdmin,
+            "not_an_enterprise" => RemoveGroupError::NotAnEnterprise,
+            "missing_scope" => RemoveGroupError::MissingScope,
+            "ratelimited" => RemoveGroupError::Ratelimited,
+            "channel_not_found" => RemoveGroupError::ChannelNotFound,
+            "invalid_channel_type" => RemoveGroupError::InvalidChannelType,
+            "group_not_found" => RemoveGroupError::GroupNotFound,
+            "restricted_action" => RemoveGroupError::RestrictedAction,
+            "not_authed" => RemoveGroupError::NotAuthed,
+            "invalid_auth" => RemoveGroupError::InvalidAuth,
+            "account_inactive" => RemoveGroupError::AccountInactive,
+            "invalid_arg_name" => RemoveGroupError::InvalidArgName,
+            "invalid_array_arg" => RemoveGroupError::InvalidArrayArg,
+            "invalid_charset" => RemoveGroupError::InvalidCharset,
+            "invalid_form_data" => RemoveGroupError::InvalidFormData,
+            "invalid_post_type" => RemoveGroupError::InvalidPostType,
+            "missing_post_type" => RemoveGroupError::MissingPostType,
+            "team_added_to_org" => RemoveGroupError::TeamAddedToOrg,
+            "request_timeout" => RemoveGroupError::RequestTimeout,
+            _ => RemoveGroupError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveGroupError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        RemoveGroupError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+RemoveGroupError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+RemoveGroupError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+RemoveGroupError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+RemoveGroupError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+RemoveGroupError::ChannelNotFound => "channel_not_found: The channel wasn't found.",
+RemoveGroupError::InvalidChannelType => "invalid_channel_type: The channel isn't a private channel.",
+RemoveGroupError::GroupNotFound => "group_not_found: The IDP group wasn't found.",
+RemoveGroupError::RestrictedAction => "restricted_action: The admin isn't allowed to manage access to this channel.",
+RemoveGroupError::NotAuthed => "not_authed: No authentication token provided.",
+RemoveGroupError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+RemoveGroupError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+RemoveGroupError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+RemoveGroupError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+RemoveGroupError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+RemoveGroupError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+RemoveGroupError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+RemoveGroupError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+RemoveGroupError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+RemoveGroupError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        RemoveGroupError::MalformedResponse(_, ref e) => return write!(f, "admin.conversations.restrictAccess.removeGroup returned a malformed response: {}", e),
+                        RemoveGroupError::Unknown(ref s) => return write!(f, "admin.conversations.restrictAccess.removeGroup failed: {}", s),
+                        RemoveGroupError::Client(ref inner) => return write!(f, "admin.conversations.restrictAccess.removeGroup failed: {}", inner),
+                    };
+        write!(
+            f,
+            "admin.conversations.restrictAccess.removeGroup failed: {}",
+            d
+        )
+    }
+}
+
+impl<E: Error> From<RemoveGroupError<E>> for crate::SlackError<E> {
+    fn from(e: RemoveGroupError<E>) -> Self {
+        let method = "admin.conversations.restrictAccess.removeGroup";
+        let error = match e {
+            RemoveGroupError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            RemoveGroupError::NotAnAdmin => "not_an_admin".to_owned(),
+            RemoveGroupError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            RemoveGroupError::MissingScope => "missing_scope".to_owned(),
+            RemoveGroupError::Ratelimited => "ratelimited".to_owned(),
+            RemoveGroupError::ChannelNotFound => "channel_not_found".to_owned(),
+            RemoveGroupError::InvalidChannelType => "invalid_channel_type".to_owned(),
+            RemoveGroupError::GroupNotFound => "group_not_found".to_owned(),
+            RemoveGroupError::RestrictedAction => "restricted_action".to_owned(),
+            RemoveGroupError::NotAuthed => "not_authed".to_owned(),
+            RemoveGroupError::InvalidAuth => "invalid_auth".to_owned(),
+            RemoveGroupError::AccountInactive => "account_inactive".to_owned(),
+            RemoveGroupError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RemoveGroupError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RemoveGroupError::InvalidCharset => "invalid_charset".to_owned(),
+            RemoveGroupError::InvalidFormData => "invalid_form_data".to_owned(),
+            RemoveGroupError::InvalidPostType => "invalid_post_type".to_owned(),
+            RemoveGroupError::MissingPostType => "missing_post_type".to_owned(),
+            RemoveGroupError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RemoveGroupError::RequestTimeout => "request_timeout".to_owned(),
+            RemoveGroupError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RemoveGroupError::Unknown(s) => s,
+            RemoveGroupError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for RemoveGroupError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RemoveGroupError::MalformedResponse(_, ref e) => Some(e),
+            RemoveGroupError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
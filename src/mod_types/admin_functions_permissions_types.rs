@@ -0,0 +1,369 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage who can run the custom functions of an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct LookupRequest<'a> {
+    /// The functions, at most 20.
+    pub function_ids: &'a [&'a str],
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LookupResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub permissions: Option<HashMap<String, crate::FunctionPermission>>,
+}
+
+impl<E: Error> From<LookupResponse> for Result<LookupResponse, LookupError<E>> {
+    fn from(response: LookupResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum LookupError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The function wasn't found.
+    FunctionNotFound,
+    /// One of the function IDs was invalid.
+    InvalidFunctionId,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for LookupError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => LookupError::FeatureNotEnabled,
+            "not_an_admin" => LookupError::NotAnAdmin,
+            "not_an_enterprise" => LookupError::NotAnEnterprise,
+            "missing_scope" => LookupError::MissingScope,
+            "ratelimited" => LookupError::Ratelimited,
+            "function_not_found" => LookupError::FunctionNotFound,
+            "invalid_function_id" => LookupError::InvalidFunctionId,
+            "not_authed" => LookupError::NotAuthed,
+            "invalid_auth" => LookupError::InvalidAuth,
+            "account_inactive" => LookupError::AccountInactive,
+            "invalid_arg_name" => LookupError::InvalidArgName,
+            "invalid_array_arg" => LookupError::InvalidArrayArg,
+            "invalid_charset" => LookupError::InvalidCharset,
+            "invalid_form_data" => LookupError::InvalidFormData,
+            "invalid_post_type" => LookupError::InvalidPostType,
+            "missing_post_type" => LookupError::MissingPostType,
+            "team_added_to_org" => LookupError::TeamAddedToOrg,
+            "request_timeout" => LookupError::RequestTimeout,
+            _ => LookupError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for LookupError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        LookupError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+LookupError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+LookupError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+LookupError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+LookupError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+LookupError::FunctionNotFound => "function_not_found: The function wasn't found.",
+LookupError::InvalidFunctionId => "invalid_function_id: One of the function IDs was invalid.",
+LookupError::NotAuthed => "not_authed: No authentication token provided.",
+LookupError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+LookupError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+LookupError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+LookupError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+LookupError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+LookupError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+LookupError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+LookupError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+LookupError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+LookupError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        LookupError::MalformedResponse(_, ref e) => return write!(f, "admin.functions.permissions.lookup returned a malformed response: {}", e),
+                        LookupError::Unknown(ref s) => return write!(f, "admin.functions.permissions.lookup failed: {}", s),
+                        LookupError::Client(ref inner) => return write!(f, "admin.functions.permissions.lookup failed: {}", inner),
+                    };
+        write!(f, "admin.functions.permissions.lookup failed: {}", d)
+    }
+}
+
+impl<E: Error> From<LookupError<E>> for crate::SlackError<E> {
+    fn from(e: LookupError<E>) -> Self {
+        let method = "admin.functions.permissions.lookup";
+        let error = match e {
+            LookupError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            LookupError::NotAnAdmin => "not_an_admin".to_owned(),
+            LookupError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            LookupError::MissingScope => "missing_scope".to_owned(),
+            LookupError::Ratelimited => "ratelimited".to_owned(),
+            LookupError::FunctionNotFound => "function_not_found".to_owned(),
+            LookupError::InvalidFunctionId => "invalid_function_id".to_owned(),
+            LookupError::NotAuthed => "not_authed".to_owned(),
+            LookupError::InvalidAuth => "invalid_auth".to_owned(),
+            LookupError::AccountInactive => "account_inactive".to_owned(),
+            LookupError::InvalidArgName => "invalid_arg_name".to_owned(),
+            LookupError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            LookupError::InvalidCharset => "invalid_charset".to_owned(),
+            LookupError::InvalidFormData => "invalid_form_data".to_owned(),
+            LookupError::InvalidPostType => "invalid_post_type".to_owned(),
+            LookupError::MissingPostType => "missing_post_type".to_owned(),
+            LookupError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            LookupError::RequestTimeout => "request_timeout".to_owned(),
+            LookupError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            LookupError::Unknown(s) => s,
+            LookupError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for LookupError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            LookupError::MalformedResponse(_, ref e) => Some(e),
+            LookupError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SetRequest<'a> {
+    /// The function.
+    pub function_id: &'a str,
+    /// Who can run the function.
+    pub visibility: crate::FunctionVisibility,
+    /// The users who can run the function when its visibility is `named_entities`.
+    pub user_ids: Option<&'a [&'a str]>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SetResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<SetResponse> for Result<SetResponse, SetError<E>> {
+    fn from(response: SetResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The function wasn't found.
+    FunctionNotFound,
+    /// One of the function IDs was invalid.
+    InvalidFunctionId,
+    /// One of the user IDs was invalid.
+    InvalidUserId,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => SetError::FeatureNotEnabled,
+            "not_an_admin" => SetError::NotAnAdmin,
+            "not_an_enterprise" => SetError::NotAnEnterprise,
+            "missing_scope" => SetError::MissingScope,
+            "ratelimited" => SetError::Ratelimited,
+            "function_not_found" => SetError::FunctionNotFound,
+            "invalid_function_id" => SetError::InvalidFunctionId,
+            "invalid_user_id" => SetError::InvalidUserId,
+            "not_authed" => SetError::NotAuthed,
+            "invalid_auth" => SetError::InvalidAuth,
+            "account_inactive" => SetError::AccountInactive,
+            "invalid_arg_name" => SetError::InvalidArgName,
+            "invalid_array_arg" => SetError::InvalidArrayArg,
+            "invalid_charset" => SetError::InvalidCharset,
+            "invalid_form_data" => SetError::InvalidFormData,
+            "invalid_post_type" => SetError::InvalidPostType,
+            "missing_post_type" => SetError::MissingPostType,
+            "team_added_to_org" => SetError::TeamAddedToOrg,
+            "request_timeout" => SetError::RequestTimeout,
+            _ => SetError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        SetError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+SetError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+SetError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+SetError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+SetError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+SetError::FunctionNotFound => "function_not_found: The function wasn't found.",
+SetError::InvalidFunctionId => "invalid_function_id: One of the function IDs was invalid.",
+SetError::InvalidUserId => "invalid_user_id: One of the user IDs was invalid.",
+SetError::NotAuthed => "not_authed: No authentication token provided.",
+SetError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+SetError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+SetError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+SetError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+SetError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+SetError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+SetError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+SetError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+SetError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+SetError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        SetError::MalformedResponse(_, ref e) => return write!(f, "admin.functions.permissions.set returned a malformed response: {}", e),
+                        SetError::Unknown(ref s) => return write!(f, "admin.functions.permissions.set failed: {}", s),
+                        SetError::Client(ref inner) => return write!(f, "admin.functions.permissions.set failed: {}", inner),
+                    };
+        write!(f, "admin.functions.permissions.set failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetError<E>> for crate::SlackError<E> {
+    fn from(e: SetError<E>) -> Self {
+        let method = "admin.functions.permissions.set";
+        let error = match e {
+            SetError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            SetError::NotAnAdmin => "not_an_admin".to_owned(),
+            SetError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            SetError::MissingScope => "missing_scope".to_owned(),
+            SetError::Ratelimited => "ratelimited".to_owned(),
+            SetError::FunctionNotFound => "function_not_found".to_owned(),
+            SetError::InvalidFunctionId => "invalid_function_id".to_owned(),
+            SetError::InvalidUserId => "invalid_user_id".to_owned(),
+            SetError::NotAuthed => "not_authed".to_owned(),
+            SetError::InvalidAuth => "invalid_auth".to_owned(),
+            SetError::AccountInactive => "account_inactive".to_owned(),
+            SetError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetError::InvalidCharset => "invalid_charset".to_owned(),
+            SetError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetError::MissingPostType => "missing_post_type".to_owned(),
+            SetError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetError::RequestTimeout => "request_timeout".to_owned(),
+            SetError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetError::Unknown(s) => s,
+            SetError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for SetError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SetError::MalformedResponse(_, ref e) => Some(e),
+            SetError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
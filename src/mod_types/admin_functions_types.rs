@@ -0,0 +1,204 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Govern the custom functions of the apps in an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// The apps whose functions are listed.
+    pub app_ids: &'a [&'a str],
+    /// Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// The maximum number of functions to return, up to 1000. Defaults to 100.
+    pub limit: Option<u32>,
+    /// The workspace the apps are installed in.
+    pub team_id: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListResponse {
+    error: Option<String>,
+    pub functions: Option<Vec<crate::SlackFunction>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for ListResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The cursor was invalid or expired.
+    InvalidCursor,
+    /// One of the apps wasn't found.
+    AppNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => ListError::FeatureNotEnabled,
+            "not_an_admin" => ListError::NotAnAdmin,
+            "not_an_enterprise" => ListError::NotAnEnterprise,
+            "missing_scope" => ListError::MissingScope,
+            "ratelimited" => ListError::Ratelimited,
+            "invalid_cursor" => ListError::InvalidCursor,
+            "app_not_found" => ListError::AppNotFound,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ListError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+ListError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+ListError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ListError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ListError::InvalidCursor => "invalid_cursor: The cursor was invalid or expired.",
+ListError::AppNotFound => "app_not_found: One of the apps wasn't found.",
+ListError::NotAuthed => "not_authed: No authentication token provided.",
+ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "admin.functions.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "admin.functions.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "admin.functions.list failed: {}", inner),
+                    };
+        write!(f, "admin.functions.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "admin.functions.list";
+        let error = match e {
+            ListError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            ListError::NotAnAdmin => "not_an_admin".to_owned(),
+            ListError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            ListError::MissingScope => "missing_scope".to_owned(),
+            ListError::Ratelimited => "ratelimited".to_owned(),
+            ListError::InvalidCursor => "invalid_cursor".to_owned(),
+            ListError::AppNotFound => "app_not_found".to_owned(),
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ListError::MalformedResponse(_, ref e) => Some(e),
+            ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
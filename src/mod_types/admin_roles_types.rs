@@ -0,0 +1,593 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Assign the admin roles of an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub struct AddAssignmentsRequest<'a> {
+    /// The organization, workspaces or channels the role is scoped to.
+    pub entity_ids: &'a [crate::RoleEntity],
+    /// The role.
+    pub role_id: &'a crate::RoleId,
+    /// The users, at most 100.
+    pub user_ids: &'a [&'a str],
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AddAssignmentsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub rejected_entities: Option<Vec<String>>,
+    pub rejected_users: Option<Vec<String>>,
+}
+
+impl<E: Error> From<AddAssignmentsResponse>
+    for Result<AddAssignmentsResponse, AddAssignmentsError<E>>
+{
+    fn from(response: AddAssignmentsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddAssignmentsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// One of the entity IDs was invalid.
+    InvalidEntityId,
+    /// The role ID was invalid.
+    InvalidRoleId,
+    /// One of the user IDs was invalid.
+    InvalidUserId,
+    /// One of the users wasn't found.
+    UserNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddAssignmentsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => AddAssignmentsError::FeatureNotEnabled,
+            "not_an_admin" => AddAssignmentsError::NotAnAdmin,
+            "not_an_enterprise" => AddAssignmentsError::NotAnEnterprise,
+            "missing_scope" => AddAssignmentsError::MissingScope,
+            "ratelimited" => AddAssignmentsError::Ratelimited,
+            "invalid_entity_id" => AddAssignmentsError::InvalidEntityId,
+            "invalid_role_id" => AddAssignmentsError::InvalidRoleId,
+            "invalid_user_id" => AddAssignmentsError::InvalidUserId,
+            "user_not_found" => AddAssignmentsError::UserNotFound,
+            "not_authed" => AddAssignmentsError::NotAuthed,
+            "invalid_auth" => AddAssignmentsError::InvalidAuth,
+            "account_inactive" => AddAssignmentsError::AccountInactive,
+            "invalid_arg_name" => AddAssignmentsError::InvalidArgName,
+            "invalid_array_arg" => AddAssignmentsError::InvalidArrayArg,
+            "invalid_charset" => AddAssignmentsError::InvalidCharset,
+            "invalid_form_data" => AddAssignmentsError::InvalidFormData,
+            "invalid_post_type" => AddAssignmentsError::InvalidPostType,
+            "missing_post_type" => AddAssignmentsError::MissingPostType,
+            "team_added_to_org" => AddAssignmentsError::TeamAddedToOrg,
+            "request_timeout" => AddAssignmentsError::RequestTimeout,
+            _ => AddAssignmentsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AddAssignmentsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        AddAssignmentsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+AddAssignmentsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+AddAssignmentsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+AddAssignmentsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+AddAssignmentsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+AddAssignmentsError::InvalidEntityId => "invalid_entity_id: One of the entity IDs was invalid.",
+AddAssignmentsError::InvalidRoleId => "invalid_role_id: The role ID was invalid.",
+AddAssignmentsError::InvalidUserId => "invalid_user_id: One of the user IDs was invalid.",
+AddAssignmentsError::UserNotFound => "user_not_found: One of the users wasn't found.",
+AddAssignmentsError::NotAuthed => "not_authed: No authentication token provided.",
+AddAssignmentsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+AddAssignmentsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+AddAssignmentsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+AddAssignmentsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+AddAssignmentsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+AddAssignmentsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+AddAssignmentsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+AddAssignmentsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+AddAssignmentsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+AddAssignmentsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        AddAssignmentsError::MalformedResponse(_, ref e) => return write!(f, "admin.roles.addAssignments returned a malformed response: {}", e),
+                        AddAssignmentsError::Unknown(ref s) => return write!(f, "admin.roles.addAssignments failed: {}", s),
+                        AddAssignmentsError::Client(ref inner) => return write!(f, "admin.roles.addAssignments failed: {}", inner),
+                    };
+        write!(f, "admin.roles.addAssignments failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AddAssignmentsError<E>> for crate::SlackError<E> {
+    fn from(e: AddAssignmentsError<E>) -> Self {
+        let method = "admin.roles.addAssignments";
+        let error = match e {
+            AddAssignmentsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            AddAssignmentsError::NotAnAdmin => "not_an_admin".to_owned(),
+            AddAssignmentsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            AddAssignmentsError::MissingScope => "missing_scope".to_owned(),
+            AddAssignmentsError::Ratelimited => "ratelimited".to_owned(),
+            AddAssignmentsError::InvalidEntityId => "invalid_entity_id".to_owned(),
+            AddAssignmentsError::InvalidRoleId => "invalid_role_id".to_owned(),
+            AddAssignmentsError::InvalidUserId => "invalid_user_id".to_owned(),
+            AddAssignmentsError::UserNotFound => "user_not_found".to_owned(),
+            AddAssignmentsError::NotAuthed => "not_authed".to_owned(),
+            AddAssignmentsError::InvalidAuth => "invalid_auth".to_owned(),
+            AddAssignmentsError::AccountInactive => "account_inactive".to_owned(),
+            AddAssignmentsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddAssignmentsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddAssignmentsError::InvalidCharset => "invalid_charset".to_owned(),
+            AddAssignmentsError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddAssignmentsError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddAssignmentsError::MissingPostType => "missing_post_type".to_owned(),
+            AddAssignmentsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddAssignmentsError::RequestTimeout => "request_timeout".to_owned(),
+            AddAssignmentsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddAssignmentsError::Unknown(s) => s,
+            AddAssignmentsError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for AddAssignmentsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AddAssignmentsError::MalformedResponse(_, ref e) => Some(e),
+            AddAssignmentsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListAssignmentsRequest<'a> {
+    /// Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// Only list assignments scoped to these entities.
+    pub entity_ids: Option<&'a [crate::RoleEntity]>,
+    /// The maximum number of assignments to return, up to 200. Defaults to 100.
+    pub limit: Option<u32>,
+    /// Only list assignments of these roles.
+    pub role_ids: Option<&'a [crate::RoleId]>,
+    /// `asc`, or `desc` by default, by when the role was assigned.
+    pub sort_dir: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListAssignmentsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub role_assignments: Option<Vec<crate::RoleAssignment>>,
+}
+
+impl crate::HasPagination for ListAssignmentsResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<ListAssignmentsResponse>
+    for Result<ListAssignmentsResponse, ListAssignmentsError<E>>
+{
+    fn from(response: ListAssignmentsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListAssignmentsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The cursor was invalid or expired.
+    InvalidCursor,
+    /// One of the entity IDs was invalid.
+    InvalidEntityId,
+    /// One of the role IDs was invalid.
+    InvalidRoleId,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListAssignmentsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => ListAssignmentsError::FeatureNotEnabled,
+            "not_an_admin" => ListAssignmentsError::NotAnAdmin,
+            "not_an_enterprise" => ListAssignmentsError::NotAnEnterprise,
+            "missing_scope" => ListAssignmentsError::MissingScope,
+            "ratelimited" => ListAssignmentsError::Ratelimited,
+            "invalid_cursor" => ListAssignmentsError::InvalidCursor,
+            "invalid_entity_id" => ListAssignmentsError::InvalidEntityId,
+            "invalid_role_id" => ListAssignmentsError::InvalidRoleId,
+            "not_authed" => ListAssignmentsError::NotAuthed,
+            "invalid_auth" => ListAssignmentsError::InvalidAuth,
+            "account_inactive" => ListAssignmentsError::AccountInactive,
+            "invalid_arg_name" => ListAssignmentsError::InvalidArgName,
+            "invalid_array_arg" => ListAssignmentsError::InvalidArrayArg,
+            "invalid_charset" => ListAssignmentsError::InvalidCharset,
+            "invalid_form_data" => ListAssignmentsError::InvalidFormData,
+            "invalid_post_type" => ListAssignmentsError::InvalidPostType,
+            "missing_post_type" => ListAssignmentsError::MissingPostType,
+            "team_added_to_org" => ListAssignmentsError::TeamAddedToOrg,
+            "request_timeout" => ListAssignmentsError::RequestTimeout,
+            _ => ListAssignmentsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListAssignmentsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ListAssignmentsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+ListAssignmentsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+ListAssignmentsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+ListAssignmentsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ListAssignmentsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ListAssignmentsError::InvalidCursor => "invalid_cursor: The cursor was invalid or expired.",
+ListAssignmentsError::InvalidEntityId => "invalid_entity_id: One of the entity IDs was invalid.",
+ListAssignmentsError::InvalidRoleId => "invalid_role_id: One of the role IDs was invalid.",
+ListAssignmentsError::NotAuthed => "not_authed: No authentication token provided.",
+ListAssignmentsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ListAssignmentsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ListAssignmentsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ListAssignmentsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ListAssignmentsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ListAssignmentsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ListAssignmentsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ListAssignmentsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ListAssignmentsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ListAssignmentsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ListAssignmentsError::MalformedResponse(_, ref e) => return write!(f, "admin.roles.listAssignments returned a malformed response: {}", e),
+                        ListAssignmentsError::Unknown(ref s) => return write!(f, "admin.roles.listAssignments failed: {}", s),
+                        ListAssignmentsError::Client(ref inner) => return write!(f, "admin.roles.listAssignments failed: {}", inner),
+                    };
+        write!(f, "admin.roles.listAssignments failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListAssignmentsError<E>> for crate::SlackError<E> {
+    fn from(e: ListAssignmentsError<E>) -> Self {
+        let method = "admin.roles.listAssignments";
+        let error = match e {
+            ListAssignmentsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            ListAssignmentsError::NotAnAdmin => "not_an_admin".to_owned(),
+            ListAssignmentsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            ListAssignmentsError::MissingScope => "missing_scope".to_owned(),
+            ListAssignmentsError::Ratelimited => "ratelimited".to_owned(),
+            ListAssignmentsError::InvalidCursor => "invalid_cursor".to_owned(),
+            ListAssignmentsError::InvalidEntityId => "invalid_entity_id".to_owned(),
+            ListAssignmentsError::InvalidRoleId => "invalid_role_id".to_owned(),
+            ListAssignmentsError::NotAuthed => "not_authed".to_owned(),
+            ListAssignmentsError::InvalidAuth => "invalid_auth".to_owned(),
+            ListAssignmentsError::AccountInactive => "account_inactive".to_owned(),
+            ListAssignmentsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListAssignmentsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListAssignmentsError::InvalidCharset => "invalid_charset".to_owned(),
+            ListAssignmentsError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListAssignmentsError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListAssignmentsError::MissingPostType => "missing_post_type".to_owned(),
+            ListAssignmentsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListAssignmentsError::RequestTimeout => "request_timeout".to_owned(),
+            ListAssignmentsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListAssignmentsError::Unknown(s) => s,
+            ListAssignmentsError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ListAssignmentsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ListAssignmentsError::MalformedResponse(_, ref e) => Some(e),
+            ListAssignmentsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct RemoveAssignmentsRequest<'a> {
+    /// The organization, workspaces or channels the role is scoped to.
+    pub entity_ids: &'a [crate::RoleEntity],
+    /// The role.
+    pub role_id: &'a crate::RoleId,
+    /// The users, at most 100.
+    pub user_ids: &'a [&'a str],
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RemoveAssignmentsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub rejected_entities: Option<Vec<String>>,
+    pub rejected_users: Option<Vec<String>>,
+}
+
+impl<E: Error> From<RemoveAssignmentsResponse>
+    for Result<RemoveAssignmentsResponse, RemoveAssignmentsError<E>>
+{
+    fn from(response: RemoveAssignmentsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveAssignmentsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// One of the entity IDs was invalid.
+    InvalidEntityId,
+    /// The role ID was invalid.
+    InvalidRoleId,
+    /// One of the user IDs was invalid.
+    InvalidUserId,
+    /// One of the users wasn't found.
+    UserNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveAssignmentsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => RemoveAssignmentsError::FeatureNotEnabled,
+            "not_an_admin" => RemoveAssignmentsError::NotAnAdmin,
+            "not_an_enterprise" => RemoveAssignmentsError::NotAnEnterprise,
+            "missing_scope" => RemoveAssignmentsError::MissingScope,
+            "ratelimited" => RemoveAssignmentsError::Ratelimited,
+            "invalid_entity_id" => RemoveAssignmentsError::InvalidEntityId,
+            "invalid_role_id" => RemoveAssignmentsError::InvalidRoleId,
+            "invalid_user_id" => RemoveAssignmentsError::InvalidUserId,
+            "user_not_found" => RemoveAssignmentsError::UserNotFound,
+            "not_authed" => RemoveAssignmentsError::NotAuthed,
+            "invalid_auth" => RemoveAssignmentsError::InvalidAuth,
+            "account_inactive" => RemoveAssignmentsError::AccountInactive,
+            "invalid_arg_name" => RemoveAssignmentsError::InvalidArgName,
+            "invalid_array_arg" => RemoveAssignmentsError::InvalidArrayArg,
+            "invalid_charset" => RemoveAssignmentsError::InvalidCharset,
+            "invalid_form_data" => RemoveAssignmentsError::InvalidFormData,
+            "invalid_post_type" => RemoveAssignmentsError::InvalidPostType,
+            "missing_post_type" => RemoveAssignmentsError::MissingPostType,
+            "team_added_to_org" => RemoveAssignmentsError::TeamAddedToOrg,
+            "request_timeout" => RemoveAssignmentsError::RequestTimeout,
+            _ => RemoveAssignmentsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveAssignmentsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        RemoveAssignmentsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+RemoveAssignmentsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+RemoveAssignmentsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+RemoveAssignmentsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+RemoveAssignmentsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+RemoveAssignmentsError::InvalidEntityId => "invalid_entity_id: One of the entity IDs was invalid.",
+RemoveAssignmentsError::InvalidRoleId => "invalid_role_id: The role ID was invalid.",
+RemoveAssignmentsError::InvalidUserId => "invalid_user_id: One of the user IDs was invalid.",
+RemoveAssignmentsError::UserNotFound => "user_not_found: One of the users wasn't found.",
+RemoveAssignmentsError::NotAuthed => "not_authed: No authentication token provided.",
+RemoveAssignmentsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+RemoveAssignmentsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+RemoveAssignmentsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+RemoveAssignmentsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+RemoveAssignmentsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+RemoveAssignmentsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+RemoveAssignmentsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+RemoveAssignmentsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+RemoveAssignmentsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+RemoveAssignmentsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        RemoveAssignmentsError::MalformedResponse(_, ref e) => return write!(f, "admin.roles.removeAssignments returned a malformed response: {}", e),
+                        RemoveAssignmentsError::Unknown(ref s) => return write!(f, "admin.roles.removeAssignments failed: {}", s),
+                        RemoveAssignmentsError::Client(ref inner) => return write!(f, "admin.roles.removeAssignments failed: {}", inner),
+                    };
+        write!(f, "admin.roles.removeAssignments failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RemoveAssignmentsError<E>> for crate::SlackError<E> {
+    fn from(e: RemoveAssignmentsError<E>) -> Self {
+        let method = "admin.roles.removeAssignments";
+        let error = match e {
+            RemoveAssignmentsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            RemoveAssignmentsError::NotAnAdmin => "not_an_admin".to_owned(),
+            RemoveAssignmentsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            RemoveAssignmentsError::MissingScope => "missing_scope".to_owned(),
+            RemoveAssignmentsError::Ratelimited => "ratelimited".to_owned(),
+            RemoveAssignmentsError::InvalidEntityId => "invalid_entity_id".to_owned(),
+            RemoveAssignmentsError::InvalidRoleId => "invalid_role_id".to_owned(),
+            RemoveAssignmentsError::InvalidUserId => "invalid_user_id".to_owned(),
+            RemoveAssignmentsError::UserNotFound => "user_not_found".to_owned(),
+            RemoveAssignmentsError::NotAuthed => "not_authed".to_owned(),
+            RemoveAssignmentsError::InvalidAuth => "invalid_auth".to_owned(),
+            RemoveAssignmentsError::AccountInactive => "account_inactive".to_owned(),
+            RemoveAssignmentsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RemoveAssignmentsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RemoveAssignmentsError::InvalidCharset => "invalid_charset".to_owned(),
+            RemoveAssignmentsError::InvalidFormData => "invalid_form_data".to_owned(),
+            RemoveAssignmentsError::InvalidPostType => "invalid_post_type".to_owned(),
+            RemoveAssignmentsError::MissingPostType => "missing_post_type".to_owned(),
+            RemoveAssignmentsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RemoveAssignmentsError::RequestTimeout => "request_timeout".to_owned(),
+            RemoveAssignmentsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RemoveAssignmentsError::Unknown(s) => s,
+            RemoveAssignmentsError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for RemoveAssignmentsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RemoveAssignmentsError::MalformedResponse(_, ref e) => Some(e),
+            RemoveAssignmentsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,740 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage the default channels and workspaces of the user groups of an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct AddChannelsRequest<'a> {
+    /// The channels to add, at most 100.
+    pub channel_ids: &'a [&'a str],
+    /// The user group.
+    pub usergroup_id: &'a str,
+    /// The workspace of the channels, when the user group is in more than one.
+    pub team_id: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AddChannelsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<AddChannelsResponse> for Result<AddChannelsResponse, AddChannelsError<E>> {
+    fn from(response: AddChannelsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddChannelsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The user group wasn't found.
+    InvalidUsergroup,
+    /// The user group isn't available in the given workspace.
+    UsergroupNotLinkedToTeam,
+    /// One of the channels wasn't found.
+    ChannelNotFound,
+    /// The user group would have more than 100 default channels.
+    TooManyChannels,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddChannelsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => AddChannelsError::FeatureNotEnabled,
+            "not_an_admin" => AddChannelsError::NotAnAdmin,
+            "not_an_enterprise" => AddChannelsError::NotAnEnterprise,
+            "missing_scope" => AddChannelsError::MissingScope,
+            "ratelimited" => AddChannelsError::Ratelimited,
+            "invalid_usergroup" => AddChannelsError::InvalidUsergroup,
+            "usergroup_not_linked_to_team" => AddChannelsError::UsergroupNotLinkedToTeam,
+            "channel_not_found" => AddChannelsError::ChannelNotFound,
+            "too_many_channels" => AddChannelsError::TooManyChannels,
+            "not_authed" => AddChannelsError::NotAuthed,
+            "invalid_auth" => AddChannelsError::InvalidAuth,
+            "account_inactive" => AddChannelsError::AccountInactive,
+            "invalid_arg_name" => AddChannelsError::InvalidArgName,
+            "invalid_array_arg" => AddChannelsError::InvalidArrayArg,
+            "invalid_charset" => AddChannelsError::InvalidCharset,
+            "invalid_form_data" => AddChannelsError::InvalidFormData,
+            "invalid_post_type" => AddChannelsError::InvalidPostType,
+            "missing_post_type" => AddChannelsError::MissingPostType,
+            "team_added_to_org" => AddChannelsError::TeamAddedToOrg,
+            "request_timeout" => AddChannelsError::RequestTimeout,
+            _ => AddChannelsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AddChannelsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        AddChannelsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+AddChannelsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+AddChannelsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+AddChannelsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+AddChannelsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+AddChannelsError::InvalidUsergroup => "invalid_usergroup: The user group wasn't found.",
+AddChannelsError::UsergroupNotLinkedToTeam => "usergroup_not_linked_to_team: The user group isn't available in the given workspace.",
+AddChannelsError::ChannelNotFound => "channel_not_found: One of the channels wasn't found.",
+AddChannelsError::TooManyChannels => "too_many_channels: The user group would have more than 100 default channels.",
+AddChannelsError::NotAuthed => "not_authed: No authentication token provided.",
+AddChannelsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+AddChannelsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+AddChannelsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+AddChannelsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+AddChannelsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+AddChannelsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+AddChannelsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+AddChannelsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+AddChannelsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+AddChannelsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        AddChannelsError::MalformedResponse(_, ref e) => return write!(f, "admin.usergroups.addChannels returned a malformed response: {}", e),
+                        AddChannelsError::Unknown(ref s) => return write!(f, "admin.usergroups.addChannels failed: {}", s),
+                        AddChannelsError::Client(ref inner) => return write!(f, "admin.usergroups.addChannels failed: {}", inner),
+                    };
+        write!(f, "admin.usergroups.addChannels failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AddChannelsError<E>> for crate::SlackError<E> {
+    fn from(e: AddChannelsError<E>) -> Self {
+        let method = "admin.usergroups.addChannels";
+        let error = match e {
+            AddChannelsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            AddChannelsError::NotAnAdmin => "not_an_admin".to_owned(),
+            AddChannelsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            AddChannelsError::MissingScope => "missing_scope".to_owned(),
+            AddChannelsError::Ratelimited => "ratelimited".to_owned(),
+            AddChannelsError::InvalidUsergroup => "invalid_usergroup".to_owned(),
+            AddChannelsError::UsergroupNotLinkedToTeam => "usergroup_not_linked_to_team".to_owned(),
+            AddChannelsError::ChannelNotFound => "channel_not_found".to_owned(),
+            AddChannelsError::TooManyChannels => "too_many_channels".to_owned(),
+            AddChannelsError::NotAuthed => "not_authed".to_owned(),
+            AddChannelsError::InvalidAuth => "invalid_auth".to_owned(),
+            AddChannelsError::AccountInactive => "account_inactive".to_owned(),
+            AddChannelsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddChannelsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddChannelsError::InvalidCharset => "invalid_charset".to_owned(),
+            AddChannelsError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddChannelsError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddChannelsError::MissingPostType => "missing_post_type".to_owned(),
+            AddChannelsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddChannelsError::RequestTimeout => "request_timeout".to_owned(),
+            AddChannelsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddChannelsError::Unknown(s) => s,
+            AddChannelsError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for AddChannelsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AddChannelsError::MalformedResponse(_, ref e) => Some(e),
+            AddChannelsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct AddTeamsRequest<'a> {
+    /// The workspaces to add the user group to.
+    pub team_ids: &'a [&'a str],
+    /// The user group.
+    pub usergroup_id: &'a str,
+    /// Add the user group's members to the workspaces that they aren't in yet.
+    pub auto_provision: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AddTeamsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<AddTeamsResponse> for Result<AddTeamsResponse, AddTeamsError<E>> {
+    fn from(response: AddTeamsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddTeamsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The user group wasn't found.
+    InvalidUsergroup,
+    /// The user group isn't available in the given workspace.
+    UsergroupNotLinkedToTeam,
+    /// One of the workspaces wasn't found.
+    TeamNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddTeamsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => AddTeamsError::FeatureNotEnabled,
+            "not_an_admin" => AddTeamsError::NotAnAdmin,
+            "not_an_enterprise" => AddTeamsError::NotAnEnterprise,
+            "missing_scope" => AddTeamsError::MissingScope,
+            "ratelimited" => AddTeamsError::Ratelimited,
+            "invalid_usergroup" => AddTeamsError::InvalidUsergroup,
+            "usergroup_not_linked_to_team" => AddTeamsError::UsergroupNotLinkedToTeam,
+            "team_not_found" => AddTeamsError::TeamNotFound,
+            "not_authed" => AddTeamsError::NotAuthed,
+            "invalid_auth" => AddTeamsError::InvalidAuth,
+            "account_inactive" => AddTeamsError::AccountInactive,
+            "invalid_arg_name" => AddTeamsError::InvalidArgName,
+            "invalid_array_arg" => AddTeamsError::InvalidArrayArg,
+            "invalid_charset" => AddTeamsError::InvalidCharset,
+            "invalid_form_data" => AddTeamsError::InvalidFormData,
+            "invalid_post_type" => AddTeamsError::InvalidPostType,
+            "missing_post_type" => AddTeamsError::MissingPostType,
+            "team_added_to_org" => AddTeamsError::TeamAddedToOrg,
+            "request_timeout" => AddTeamsError::RequestTimeout,
+            _ => AddTeamsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AddTeamsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        AddTeamsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+AddTeamsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+AddTeamsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+AddTeamsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+AddTeamsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+AddTeamsError::InvalidUsergroup => "invalid_usergroup: The user group wasn't found.",
+AddTeamsError::UsergroupNotLinkedToTeam => "usergroup_not_linked_to_team: The user group isn't available in the given workspace.",
+AddTeamsError::TeamNotFound => "team_not_found: One of the workspaces wasn't found.",
+AddTeamsError::NotAuthed => "not_authed: No authentication token provided.",
+AddTeamsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+AddTeamsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+AddTeamsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+AddTeamsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+AddTeamsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+AddTeamsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+AddTeamsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+AddTeamsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+AddTeamsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+AddTeamsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        AddTeamsError::MalformedResponse(_, ref e) => return write!(f, "admin.usergroups.addTeams returned a malformed response: {}", e),
+                        AddTeamsError::Unknown(ref s) => return write!(f, "admin.usergroups.addTeams failed: {}", s),
+                        AddTeamsError::Client(ref inner) => return write!(f, "admin.usergroups.addTeams failed: {}", inner),
+                    };
+        write!(f, "admin.usergroups.addTeams failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AddTeamsError<E>> for crate::SlackError<E> {
+    fn from(e: AddTeamsError<E>) -> Self {
+        let method = "admin.usergroups.addTeams";
+        let error = match e {
+            AddTeamsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            AddTeamsError::NotAnAdmin => "not_an_admin".to_owned(),
+            AddTeamsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            AddTeamsError::MissingScope => "missing_scope".to_owned(),
+            AddTeamsError::Ratelimited => "ratelimited".to_owned(),
+            AddTeamsError::InvalidUsergroup => "invalid_usergroup".to_owned(),
+            AddTeamsError::UsergroupNotLinkedToTeam => "usergroup_not_linked_to_team".to_owned(),
+            AddTeamsError::TeamNotFound => "team_not_found".to_owned(),
+            AddTeamsError::NotAuthed => "not_authed".to_owned(),
+            AddTeamsError::InvalidAuth => "invalid_auth".to_owned(),
+            AddTeamsError::AccountInactive => "account_inactive".to_owned(),
+            AddTeamsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddTeamsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddTeamsError::InvalidCharset => "invalid_charset".to_owned(),
+            AddTeamsError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddTeamsError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddTeamsError::MissingPostType => "missing_post_type".to_owned(),
+            AddTeamsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddTeamsError::RequestTimeout => "request_timeout".to_owned(),
+            AddTeamsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddTeamsError::Unknown(s) => s,
+            AddTeamsError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for AddTeamsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AddTeamsError::MalformedResponse(_, ref e) => Some(e),
+            AddTeamsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListChannelsRequest<'a> {
+    /// The user group.
+    pub usergroup_id: &'a str,
+    /// Include the number of members of each channel.
+    pub include_num_members: Option<bool>,
+    /// The workspace of the user group, when it's in more than one.
+    pub team_id: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListChannelsResponse {
+    pub channels: Option<Vec<crate::Conversation>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<ListChannelsResponse> for Result<ListChannelsResponse, ListChannelsError<E>> {
+    fn from(response: ListChannelsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListChannelsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The user group wasn't found.
+    InvalidUsergroup,
+    /// The user group isn't available in the given workspace.
+    UsergroupNotLinkedToTeam,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListChannelsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => ListChannelsError::FeatureNotEnabled,
+            "not_an_admin" => ListChannelsError::NotAnAdmin,
+            "not_an_enterprise" => ListChannelsError::NotAnEnterprise,
+            "missing_scope" => ListChannelsError::MissingScope,
+            "ratelimited" => ListChannelsError::Ratelimited,
+            "invalid_usergroup" => ListChannelsError::InvalidUsergroup,
+            "usergroup_not_linked_to_team" => ListChannelsError::UsergroupNotLinkedToTeam,
+            "not_authed" => ListChannelsError::NotAuthed,
+            "invalid_auth" => ListChannelsError::InvalidAuth,
+            "account_inactive" => ListChannelsError::AccountInactive,
+            "invalid_arg_name" => ListChannelsError::InvalidArgName,
+            "invalid_array_arg" => ListChannelsError::InvalidArrayArg,
+            "invalid_charset" => ListChannelsError::InvalidCharset,
+            "invalid_form_data" => ListChannelsError::InvalidFormData,
+            "invalid_post_type" => ListChannelsError::InvalidPostType,
+            "missing_post_type" => ListChannelsError::MissingPostType,
+            "team_added_to_org" => ListChannelsError::TeamAddedToOrg,
+            "request_timeout" => ListChannelsError::RequestTimeout,
+            _ => ListChannelsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListChannelsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ListChannelsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+ListChannelsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+ListChannelsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+ListChannelsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ListChannelsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ListChannelsError::InvalidUsergroup => "invalid_usergroup: The user group wasn't found.",
+ListChannelsError::UsergroupNotLinkedToTeam => "usergroup_not_linked_to_team: The user group isn't available in the given workspace.",
+ListChannelsError::NotAuthed => "not_authed: No authentication token provided.",
+ListChannelsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ListChannelsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ListChannelsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ListChannelsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ListChannelsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ListChannelsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ListChannelsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ListChannelsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ListChannelsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ListChannelsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ListChannelsError::MalformedResponse(_, ref e) => return write!(f, "admin.usergroups.listChannels returned a malformed response: {}", e),
+                        ListChannelsError::Unknown(ref s) => return write!(f, "admin.usergroups.listChannels failed: {}", s),
+                        ListChannelsError::Client(ref inner) => return write!(f, "admin.usergroups.listChannels failed: {}", inner),
+                    };
+        write!(f, "admin.usergroups.listChannels failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListChannelsError<E>> for crate::SlackError<E> {
+    fn from(e: ListChannelsError<E>) -> Self {
+        let method = "admin.usergroups.listChannels";
+        let error = match e {
+            ListChannelsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            ListChannelsError::NotAnAdmin => "not_an_admin".to_owned(),
+            ListChannelsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            ListChannelsError::MissingScope => "missing_scope".to_owned(),
+            ListChannelsError::Ratelimited => "ratelimited".to_owned(),
+            ListChannelsError::InvalidUsergroup => "invalid_usergroup".to_owned(),
+            ListChannelsError::UsergroupNotLinkedToTeam => {
+                "usergroup_not_linked_to_team".to_owned()
+            }
+            ListChannelsError::NotAuthed => "not_authed".to_owned(),
+            ListChannelsError::InvalidAuth => "invalid_auth".to_owned(),
+            ListChannelsError::AccountInactive => "account_inactive".to_owned(),
+            ListChannelsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListChannelsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListChannelsError::InvalidCharset => "invalid_charset".to_owned(),
+            ListChannelsError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListChannelsError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListChannelsError::MissingPostType => "missing_post_type".to_owned(),
+            ListChannelsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListChannelsError::RequestTimeout => "request_timeout".to_owned(),
+            ListChannelsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListChannelsError::Unknown(s) => s,
+            ListChannelsError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ListChannelsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ListChannelsError::MalformedResponse(_, ref e) => Some(e),
+            ListChannelsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RemoveChannelsRequest<'a> {
+    /// The channels to remove, at most 100.
+    pub channel_ids: &'a [&'a str],
+    /// The user group.
+    pub usergroup_id: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RemoveChannelsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<RemoveChannelsResponse>
+    for Result<RemoveChannelsResponse, RemoveChannelsError<E>>
+{
+    fn from(response: RemoveChannelsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveChannelsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The user group wasn't found.
+    InvalidUsergroup,
+    /// The user group isn't available in the given workspace.
+    UsergroupNotLinkedToTeam,
+    /// One of the channels wasn't found.
+    ChannelNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveChannelsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => RemoveChannelsError::FeatureNotEnabled,
+            "not_an_admin" => RemoveChannelsError::NotAnAdmin,
+            "not_an_enterprise" => RemoveChannelsError::NotAnEnterprise,
+            "missing_scope" => RemoveChannelsError::MissingScope,
+            "ratelimited" => RemoveChannelsError::Ratelimited,
+            "invalid_usergroup" => RemoveChannelsError::InvalidUsergroup,
+            "usergroup_not_linked_to_team" => RemoveChannelsError::UsergroupNotLinkedToTeam,
+            "channel_not_found" => RemoveChannelsError::ChannelNotFound,
+            "not_authed" => RemoveChannelsError::NotAuthed,
+            "invalid_auth" => RemoveChannelsError::InvalidAuth,
+            "account_inactive" => RemoveChannelsError::AccountInactive,
+            "invalid_arg_name" => RemoveChannelsError::InvalidArgName,
+            "invalid_array_arg" => RemoveChannelsError::InvalidArrayArg,
+            "invalid_charset" => RemoveChannelsError::InvalidCharset,
+            "invalid_form_data" => RemoveChannelsError::InvalidFormData,
+            "invalid_post_type" => RemoveChannelsError::InvalidPostType,
+            "missing_post_type" => RemoveChannelsError::MissingPostType,
+            "team_added_to_org" => RemoveChannelsError::TeamAddedToOrg,
+            "request_timeout" => RemoveChannelsError::RequestTimeout,
+            _ => RemoveChannelsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveChannelsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        RemoveChannelsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+RemoveChannelsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+RemoveChannelsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+RemoveChannelsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+RemoveChannelsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+RemoveChannelsError::InvalidUsergroup => "invalid_usergroup: The user group wasn't found.",
+RemoveChannelsError::UsergroupNotLinkedToTeam => "usergroup_not_linked_to_team: The user group isn't available in the given workspace.",
+RemoveChannelsError::ChannelNotFound => "channel_not_found: One of the channels wasn't found.",
+RemoveChannelsError::NotAuthed => "not_authed: No authentication token provided.",
+RemoveChannelsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+RemoveChannelsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+RemoveChannelsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+RemoveChannelsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+RemoveChannelsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+RemoveChannelsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+RemoveChannelsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+RemoveChannelsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+RemoveChannelsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+RemoveChannelsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        RemoveChannelsError::MalformedResponse(_, ref e) => return write!(f, "admin.usergroups.removeChannels returned a malformed response: {}", e),
+                        RemoveChannelsError::Unknown(ref s) => return write!(f, "admin.usergroups.removeChannels failed: {}", s),
+                        RemoveChannelsError::Client(ref inner) => return write!(f, "admin.usergroups.removeChannels failed: {}", inner),
+                    };
+        write!(f, "admin.usergroups.removeChannels failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RemoveChannelsError<E>> for crate::SlackError<E> {
+    fn from(e: RemoveChannelsError<E>) -> Self {
+        let method = "admin.usergroups.removeChannels";
+        let error = match e {
+            RemoveChannelsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            RemoveChannelsError::NotAnAdmin => "not_an_admin".to_owned(),
+            RemoveChannelsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            RemoveChannelsError::MissingScope => "missing_scope".to_owned(),
+            RemoveChannelsError::Ratelimited => "ratelimited".to_owned(),
+            RemoveChannelsError::InvalidUsergroup => "invalid_usergroup".to_owned(),
+            RemoveChannelsError::UsergroupNotLinkedToTeam => {
+                "usergroup_not_linked_to_team".to_owned()
+            }
+            RemoveChannelsError::ChannelNotFound => "channel_not_found".to_owned(),
+            RemoveChannelsError::NotAuthed => "not_authed".to_owned(),
+            RemoveChannelsError::InvalidAuth => "invalid_auth".to_owned(),
+            RemoveChannelsError::AccountInactive => "account_inactive".to_owned(),
+            RemoveChannelsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RemoveChannelsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RemoveChannelsError::InvalidCharset => "invalid_charset".to_owned(),
+            RemoveChannelsError::InvalidFormData => "invalid_form_data".to_owned(),
+            RemoveChannelsError::InvalidPostType => "invalid_post_type".to_owned(),
+            RemoveChannelsError::MissingPostType => "missing_post_type".to_owned(),
+            RemoveChannelsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RemoveChannelsError::RequestTimeout => "request_timeout".to_owned(),
+            RemoveChannelsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RemoveChannelsError::Unknown(s) => s,
+            RemoveChannelsError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for RemoveChannelsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RemoveChannelsError::MalformedResponse(_, ref e) => Some(e),
+            RemoveChannelsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
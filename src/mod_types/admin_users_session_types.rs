@@ -0,0 +1,1045 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage the sessions of users in an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// The maximum number of sessions to return. Defaults to 1000.
+    pub limit: Option<u32>,
+    /// Only list sessions in this workspace. Required with `user_id`.
+    pub team_id: Option<&'a str>,
+    /// Only list this user's sessions. Required with `team_id`.
+    pub user_id: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListResponse {
+    pub active_sessions: Option<Vec<crate::UserSession>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for ListResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The cursor was invalid or expired.
+    InvalidCursor,
+    /// The user wasn't found.
+    UserNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => ListError::FeatureNotEnabled,
+            "not_an_admin" => ListError::NotAnAdmin,
+            "not_an_enterprise" => ListError::NotAnEnterprise,
+            "missing_scope" => ListError::MissingScope,
+            "ratelimited" => ListError::Ratelimited,
+            "invalid_cursor" => ListError::InvalidCursor,
+            "user_not_found" => ListError::UserNotFound,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ListError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+ListError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+ListError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ListError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ListError::InvalidCursor => "invalid_cursor: The cursor was invalid or expired.",
+ListError::UserNotFound => "user_not_found: The user wasn't found.",
+ListError::NotAuthed => "not_authed: No authentication token provided.",
+ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "admin.users.session.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "admin.users.session.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "admin.users.session.list failed: {}", inner),
+                    };
+        write!(f, "admin.users.session.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "admin.users.session.list";
+        let error = match e {
+            ListError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            ListError::NotAnAdmin => "not_an_admin".to_owned(),
+            ListError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            ListError::MissingScope => "missing_scope".to_owned(),
+            ListError::Ratelimited => "ratelimited".to_owned(),
+            ListError::InvalidCursor => "invalid_cursor".to_owned(),
+            ListError::UserNotFound => "user_not_found".to_owned(),
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ListError::MalformedResponse(_, ref e) => Some(e),
+            ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InvalidateRequest<'a> {
+    /// The ID of the session, as listed by admin.users.session.list.
+    pub session_id: u64,
+    /// The workspace the session belongs to.
+    pub team_id: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InvalidateResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<InvalidateResponse> for Result<InvalidateResponse, InvalidateError<E>> {
+    fn from(response: InvalidateResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InvalidateError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The session wasn't found.
+    InvalidSession,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InvalidateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => InvalidateError::FeatureNotEnabled,
+            "not_an_admin" => InvalidateError::NotAnAdmin,
+            "not_an_enterprise" => InvalidateError::NotAnEnterprise,
+            "missing_scope" => InvalidateError::MissingScope,
+            "ratelimited" => InvalidateError::Ratelimited,
+            "invalid_session" => InvalidateError::InvalidSession,
+            "not_authed" => InvalidateError::NotAuthed,
+            "invalid_auth" => InvalidateError::InvalidAuth,
+            "account_inactive" => InvalidateError::AccountInactive,
+            "invalid_arg_name" => InvalidateError::InvalidArgName,
+            "invalid_array_arg" => InvalidateError::InvalidArrayArg,
+            "invalid_charset" => InvalidateError::InvalidCharset,
+            "invalid_form_data" => InvalidateError::InvalidFormData,
+            "invalid_post_type" => InvalidateError::InvalidPostType,
+            "missing_post_type" => InvalidateError::MissingPostType,
+            "team_added_to_org" => InvalidateError::TeamAddedToOrg,
+            "request_timeout" => InvalidateError::RequestTimeout,
+            _ => InvalidateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for InvalidateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        InvalidateError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+InvalidateError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+InvalidateError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+InvalidateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+InvalidateError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+InvalidateError::InvalidSession => "invalid_session: The session wasn't found.",
+InvalidateError::NotAuthed => "not_authed: No authentication token provided.",
+InvalidateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+InvalidateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+InvalidateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+InvalidateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+InvalidateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+InvalidateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+InvalidateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+InvalidateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+InvalidateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+InvalidateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        InvalidateError::MalformedResponse(_, ref e) => return write!(f, "admin.users.session.invalidate returned a malformed response: {}", e),
+                        InvalidateError::Unknown(ref s) => return write!(f, "admin.users.session.invalidate failed: {}", s),
+                        InvalidateError::Client(ref inner) => return write!(f, "admin.users.session.invalidate failed: {}", inner),
+                    };
+        write!(f, "admin.users.session.invalidate failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InvalidateError<E>> for crate::SlackError<E> {
+    fn from(e: InvalidateError<E>) -> Self {
+        let method = "admin.users.session.invalidate";
+        let error = match e {
+            InvalidateError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            InvalidateError::NotAnAdmin => "not_an_admin".to_owned(),
+            InvalidateError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            InvalidateError::MissingScope => "missing_scope".to_owned(),
+            InvalidateError::Ratelimited => "ratelimited".to_owned(),
+            InvalidateError::InvalidSession => "invalid_session".to_owned(),
+            InvalidateError::NotAuthed => "not_authed".to_owned(),
+            InvalidateError::InvalidAuth => "invalid_auth".to_owned(),
+            InvalidateError::AccountInactive => "account_inactive".to_owned(),
+            InvalidateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InvalidateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InvalidateError::InvalidCharset => "invalid_charset".to_owned(),
+            InvalidateError::InvalidFormData => "invalid_form_data".to_owned(),
+            InvalidateError::InvalidPostType => "invalid_post_type".to_owned(),
+            InvalidateError::MissingPostType => "missing_post_type".to_owned(),
+            InvalidateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InvalidateError::RequestTimeout => "request_timeout".to_owned(),
+            InvalidateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InvalidateError::Unknown(s) => s,
+            InvalidateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for InvalidateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            InvalidateError::MalformedResponse(_, ref e) => Some(e),
+            InvalidateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ResetRequest<'a> {
+    /// The user whose sessions are reset.
+    pub user_id: &'a str,
+    /// Only reset the user's mobile sessions.
+    pub mobile_only: Option<bool>,
+    /// Only reset the user's web sessions.
+    pub web_only: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ResetResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<ResetResponse> for Result<ResetResponse, ResetError<E>> {
+    fn from(response: ResetResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ResetError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The user wasn't found.
+    UserNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ResetError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => ResetError::FeatureNotEnabled,
+            "not_an_admin" => ResetError::NotAnAdmin,
+            "not_an_enterprise" => ResetError::NotAnEnterprise,
+            "missing_scope" => ResetError::MissingScope,
+            "ratelimited" => ResetError::Ratelimited,
+            "user_not_found" => ResetError::UserNotFound,
+            "not_authed" => ResetError::NotAuthed,
+            "invalid_auth" => ResetError::InvalidAuth,
+            "account_inactive" => ResetError::AccountInactive,
+            "invalid_arg_name" => ResetError::InvalidArgName,
+            "invalid_array_arg" => ResetError::InvalidArrayArg,
+            "invalid_charset" => ResetError::InvalidCharset,
+            "invalid_form_data" => ResetError::InvalidFormData,
+            "invalid_post_type" => ResetError::InvalidPostType,
+            "missing_post_type" => ResetError::MissingPostType,
+            "team_added_to_org" => ResetError::TeamAddedToOrg,
+            "request_timeout" => ResetError::RequestTimeout,
+            _ => ResetError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ResetError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ResetError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+ResetError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+ResetError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+ResetError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ResetError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ResetError::UserNotFound => "user_not_found: The user wasn't found.",
+ResetError::NotAuthed => "not_authed: No authentication token provided.",
+ResetError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ResetError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ResetError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ResetError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ResetError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ResetError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ResetError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ResetError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ResetError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ResetError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ResetError::MalformedResponse(_, ref e) => return write!(f, "admin.users.session.reset returned a malformed response: {}", e),
+                        ResetError::Unknown(ref s) => return write!(f, "admin.users.session.reset failed: {}", s),
+                        ResetError::Client(ref inner) => return write!(f, "admin.users.session.reset failed: {}", inner),
+                    };
+        write!(f, "admin.users.session.reset failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ResetError<E>> for crate::SlackError<E> {
+    fn from(e: ResetError<E>) -> Self {
+        let method = "admin.users.session.reset";
+        let error = match e {
+            ResetError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            ResetError::NotAnAdmin => "not_an_admin".to_owned(),
+            ResetError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            ResetError::MissingScope => "missing_scope".to_owned(),
+            ResetError::Ratelimited => "ratelimited".to_owned(),
+            ResetError::UserNotFound => "user_not_found".to_owned(),
+            ResetError::NotAuthed => "not_authed".to_owned(),
+            ResetError::InvalidAuth => "invalid_auth".to_owned(),
+            ResetError::AccountInactive => "account_inactive".to_owned(),
+            ResetError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ResetError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ResetError::InvalidCharset => "invalid_charset".to_owned(),
+            ResetError::InvalidFormData => "invalid_form_data".to_owned(),
+            ResetError::InvalidPostType => "invalid_post_type".to_owned(),
+            ResetError::MissingPostType => "missing_post_type".to_owned(),
+            ResetError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ResetError::RequestTimeout => "request_timeout".to_owned(),
+            ResetError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ResetError::Unknown(s) => s,
+            ResetError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ResetError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ResetError::MalformedResponse(_, ref e) => Some(e),
+            ResetError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetSettingsRequest<'a> {
+    /// Comma separated list of user IDs, at most 1000.
+    pub user_ids: &'a str,
+    /// Sign users out of the desktop app when they quit it.
+    pub desktop_app_browser_quit: Option<bool>,
+    /// How long a session lasts, sent in whole seconds.
+    pub duration: Option<std::time::Duration>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SetSettingsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<SetSettingsResponse> for Result<SetSettingsResponse, SetSettingsError<E>> {
+    fn from(response: SetSettingsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetSettingsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The duration was outside the range Slack allows.
+    InvalidDuration,
+    /// A user ID was invalid.
+    InvalidUserIds,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetSettingsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => SetSettingsError::FeatureNotEnabled,
+            "not_an_admin" => SetSettingsError::NotAnAdmin,
+            "not_an_enterprise" => SetSettingsError::NotAnEnterprise,
+            "missing_scope" => SetSettingsError::MissingScope,
+            "ratelimited" => SetSettingsError::Ratelimited,
+            "invalid_duration" => SetSettingsError::InvalidDuration,
+            "invalid_user_ids" => SetSettingsError::InvalidUserIds,
+            "not_authed" => SetSettingsError::NotAuthed,
+            "invalid_auth" => SetSettingsError::InvalidAuth,
+            "account_inactive" => SetSettingsError::AccountInactive,
+            "invalid_arg_name" => SetSettingsError::InvalidArgName,
+            "invalid_array_arg" => SetSettingsError::InvalidArrayArg,
+            "invalid_charset" => SetSettingsError::InvalidCharset,
+            "invalid_form_data" => SetSettingsError::InvalidFormData,
+            "invalid_post_type" => SetSettingsError::InvalidPostType,
+            "missing_post_type" => SetSettingsError::MissingPostType,
+            "team_added_to_org" => SetSettingsError::TeamAddedToOrg,
+            "request_timeout" => SetSettingsError::RequestTimeout,
+            _ => SetSettingsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetSettingsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        SetSettingsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+SetSettingsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+SetSettingsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+SetSettingsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+SetSettingsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+SetSettingsError::InvalidDuration => "invalid_duration: The duration was outside the range Slack allows.",
+SetSettingsError::InvalidUserIds => "invalid_user_ids: A user ID was invalid.",
+SetSettingsError::NotAuthed => "not_authed: No authentication token provided.",
+SetSettingsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+SetSettingsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+SetSettingsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+SetSettingsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+SetSettingsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+SetSettingsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+SetSettingsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+SetSettingsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+SetSettingsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+SetSettingsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        SetSettingsError::MalformedResponse(_, ref e) => return write!(f, "admin.users.session.setSettings returned a malformed response: {}", e),
+                        SetSettingsError::Unknown(ref s) => return write!(f, "admin.users.session.setSettings failed: {}", s),
+                        SetSettingsError::Client(ref inner) => return write!(f, "admin.users.session.setSettings failed: {}", inner),
+                    };
+        write!(f, "admin.users.session.setSettings failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetSettingsError<E>> for crate::SlackError<E> {
+    fn from(e: SetSettingsError<E>) -> Self {
+        let method = "admin.users.session.setSettings";
+        let error = match e {
+            SetSettingsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            SetSettingsError::NotAnAdmin => "not_an_admin".to_owned(),
+            SetSettingsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            SetSettingsError::MissingScope => "missing_scope".to_owned(),
+            SetSettingsError::Ratelimited => "ratelimited".to_owned(),
+            SetSettingsError::InvalidDuration => "invalid_duration".to_owned(),
+            SetSettingsError::InvalidUserIds => "invalid_user_ids".to_owned(),
+            SetSettingsError::NotAuthed => "not_authed".to_owned(),
+            SetSettingsError::InvalidAuth => "invalid_auth".to_owned(),
+            SetSettingsError::AccountInactive => "account_inactive".to_owned(),
+            SetSettingsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetSettingsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetSettingsError::InvalidCharset => "invalid_charset".to_owned(),
+            SetSettingsError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetSettingsError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetSettingsError::MissingPostType => "missing_post_type".to_owned(),
+            SetSettingsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetSettingsError::RequestTimeout => "request_timeout".to_owned(),
+            SetSettingsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetSettingsError::Unknown(s) => s,
+            SetSettingsError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for SetSettingsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SetSettingsError::MalformedResponse(_, ref e) => Some(e),
+            SetSettingsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct GetSettingsRequest<'a> {
+    /// Comma separated list of user IDs, at most 1000.
+    pub user_ids: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct GetSettingsResponse {
+    error: Option<String>,
+    pub no_settings_applied: Option<Vec<String>>,
+    #[serde(default)]
+    ok: bool,
+    pub session_settings: Option<Vec<crate::SessionSettings>>,
+}
+
+impl<E: Error> From<GetSettingsResponse> for Result<GetSettingsResponse, GetSettingsError<E>> {
+    fn from(response: GetSettingsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum GetSettingsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// A user ID was invalid.
+    InvalidUserIds,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for GetSettingsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => GetSettingsError::FeatureNotEnabled,
+            "not_an_admin" => GetSettingsError::NotAnAdmin,
+            "not_an_enterprise" => GetSettingsError::NotAnEnterprise,
+            "missing_scope" => GetSettingsError::MissingScope,
+            "ratelimited" => GetSettingsError::Ratelimited,
+            "invalid_user_ids" => GetSettingsError::InvalidUserIds,
+            "not_authed" => GetSettingsError::NotAuthed,
+            "invalid_auth" => GetSettingsError::InvalidAuth,
+            "account_inactive" => GetSettingsError::AccountInactive,
+            "invalid_arg_name" => GetSettingsError::InvalidArgName,
+            "invalid_array_arg" => GetSettingsError::InvalidArrayArg,
+            "invalid_charset" => GetSettingsError::InvalidCharset,
+            "invalid_form_data" => GetSettingsError::InvalidFormData,
+            "invalid_post_type" => GetSettingsError::InvalidPostType,
+            "missing_post_type" => GetSettingsError::MissingPostType,
+            "team_added_to_org" => GetSettingsError::TeamAddedToOrg,
+            "request_timeout" => GetSettingsError::RequestTimeout,
+            _ => GetSettingsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for GetSettingsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        GetSettingsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+GetSettingsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+GetSettingsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+GetSettingsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+GetSettingsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+GetSettingsError::InvalidUserIds => "invalid_user_ids: A user ID was invalid.",
+GetSettingsError::NotAuthed => "not_authed: No authentication token provided.",
+GetSettingsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+GetSettingsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+GetSettingsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+GetSettingsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+GetSettingsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+GetSettingsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+GetSettingsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+GetSettingsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+GetSettingsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+GetSettingsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        GetSettingsError::MalformedResponse(_, ref e) => return write!(f, "admin.users.session.getSettings returned a malformed response: {}", e),
+                        GetSettingsError::Unknown(ref s) => return write!(f, "admin.users.session.getSettings failed: {}", s),
+                        GetSettingsError::Client(ref inner) => return write!(f, "admin.users.session.getSettings failed: {}", inner),
+                    };
+        write!(f, "admin.users.session.getSettings failed: {}", d)
+    }
+}
+
+impl<E: Error> From<GetSettingsError<E>> for crate::SlackError<E> {
+    fn from(e: GetSettingsError<E>) -> Self {
+        let method = "admin.users.session.getSettings";
+        let error = match e {
+            GetSettingsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            GetSettingsError::NotAnAdmin => "not_an_admin".to_owned(),
+            GetSettingsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            GetSettingsError::MissingScope => "missing_scope".to_owned(),
+            GetSettingsError::Ratelimited => "ratelimited".to_owned(),
+            GetSettingsError::InvalidUserIds => "invalid_user_ids".to_owned(),
+            GetSettingsError::NotAuthed => "not_authed".to_owned(),
+            GetSettingsError::InvalidAuth => "invalid_auth".to_owned(),
+            GetSettingsError::AccountInactive => "account_inactive".to_owned(),
+            GetSettingsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            GetSettingsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            GetSettingsError::InvalidCharset => "invalid_charset".to_owned(),
+            GetSettingsError::InvalidFormData => "invalid_form_data".to_owned(),
+            GetSettingsError::InvalidPostType => "invalid_post_type".to_owned(),
+            GetSettingsError::MissingPostType => "missing_post_type".to_owned(),
+            GetSettingsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            GetSettingsError::RequestTimeout => "request_timeout".to_owned(),
+            GetSettingsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            GetSettingsError::Unknown(s) => s,
+            GetSettingsError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for GetSettingsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            GetSettingsError::MalformedResponse(_, ref e) => Some(e),
+            GetSettingsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ClearSettingsRequest<'a> {
+    /// Comma separated list of user IDs, at most 1000.
+    pub user_ids: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ClearSettingsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<ClearSettingsResponse>
+    for Result<ClearSettingsResponse, ClearSettingsError<E>>
+{
+    fn from(response: ClearSettingsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ClearSettingsError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// A user ID was invalid.
+    InvalidUserIds,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ClearSettingsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => ClearSettingsError::FeatureNotEnabled,
+            "not_an_admin" => ClearSettingsError::NotAnAdmin,
+            "not_an_enterprise" => ClearSettingsError::NotAnEnterprise,
+            "missing_scope" => ClearSettingsError::MissingScope,
+            "ratelimited" => ClearSettingsError::Ratelimited,
+            "invalid_user_ids" => ClearSettingsError::InvalidUserIds,
+            "not_authed" => ClearSettingsError::NotAuthed,
+            "invalid_auth" => ClearSettingsError::InvalidAuth,
+            "account_inactive" => ClearSettingsError::AccountInactive,
+            "invalid_arg_name" => ClearSettingsError::InvalidArgName,
+            "invalid_array_arg" => ClearSettingsError::InvalidArrayArg,
+            "invalid_charset" => ClearSettingsError::InvalidCharset,
+            "invalid_form_data" => ClearSettingsError::InvalidFormData,
+            "invalid_post_type" => ClearSettingsError::InvalidPostType,
+            "missing_post_type" => ClearSettingsError::MissingPostType,
+            "team_added_to_org" => ClearSettingsError::TeamAddedToOrg,
+            "request_timeout" => ClearSettingsError::RequestTimeout,
+            _ => ClearSettingsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ClearSettingsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ClearSettingsError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+ClearSettingsError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+ClearSettingsError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+ClearSettingsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ClearSettingsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ClearSettingsError::InvalidUserIds => "invalid_user_ids: A user ID was invalid.",
+ClearSettingsError::NotAuthed => "not_authed: No authentication token provided.",
+ClearSettingsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ClearSettingsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ClearSettingsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ClearSettingsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ClearSettingsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ClearSettingsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ClearSettingsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ClearSettingsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ClearSettingsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ClearSettingsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ClearSettingsError::MalformedResponse(_, ref e) => return write!(f, "admin.users.session.clearSettings returned a malformed response: {}", e),
+                        ClearSettingsError::Unknown(ref s) => return write!(f, "admin.users.session.clearSettings failed: {}", s),
+                        ClearSettingsError::Client(ref inner) => return write!(f, "admin.users.session.clearSettings failed: {}", inner),
+                    };
+        write!(f, "admin.users.session.clearSettings failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ClearSettingsError<E>> for crate::SlackError<E> {
+    fn from(e: ClearSettingsError<E>) -> Self {
+        let method = "admin.users.session.clearSettings";
+        let error = match e {
+            ClearSettingsError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            ClearSettingsError::NotAnAdmin => "not_an_admin".to_owned(),
+            ClearSettingsError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            ClearSettingsError::MissingScope => "missing_scope".to_owned(),
+            ClearSettingsError::Ratelimited => "ratelimited".to_owned(),
+            ClearSettingsError::InvalidUserIds => "invalid_user_ids".to_owned(),
+            ClearSettingsError::NotAuthed => "not_authed".to_owned(),
+            ClearSettingsError::InvalidAuth => "invalid_auth".to_owned(),
+            ClearSettingsError::AccountInactive => "account_inactive".to_owned(),
+            ClearSettingsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ClearSettingsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ClearSettingsError::InvalidCharset => "invalid_charset".to_owned(),
+            ClearSettingsError::InvalidFormData => "invalid_form_data".to_owned(),
+            ClearSettingsError::InvalidPostType => "invalid_post_type".to_owned(),
+            ClearSettingsError::MissingPostType => "missing_post_type".to_owned(),
+            ClearSettingsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ClearSettingsError::RequestTimeout => "request_timeout".to_owned(),
+            ClearSettingsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ClearSettingsError::Unknown(s) => s,
+            ClearSettingsError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ClearSettingsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ClearSettingsError::MalformedResponse(_, ref e) => Some(e),
+            ClearSettingsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,400 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Invite users to and assign users to the workspaces of an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct AssignRequest<'a> {
+    /// The workspace to add the user to.
+    pub team_id: &'a str,
+    /// The user to add.
+    pub user_id: &'a str,
+    /// Channels of the workspace to add the user to.
+    pub channel_ids: Option<&'a [&'a str]>,
+    /// Add the user as a multi-channel guest.
+    pub is_restricted: Option<bool>,
+    /// Add the user as a single-channel guest.
+    pub is_ultra_restricted: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AssignResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<AssignResponse> for Result<AssignResponse, AssignError<E>> {
+    fn from(response: AssignResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AssignError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The user is already a member of the workspace.
+    UserAlreadyTeamMember,
+    /// The user wasn't found.
+    UserNotFound,
+    /// One of the channels wasn't found.
+    ChannelNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AssignError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => AssignError::FeatureNotEnabled,
+            "not_an_admin" => AssignError::NotAnAdmin,
+            "not_an_enterprise" => AssignError::NotAnEnterprise,
+            "missing_scope" => AssignError::MissingScope,
+            "ratelimited" => AssignError::Ratelimited,
+            "user_already_team_member" => AssignError::UserAlreadyTeamMember,
+            "user_not_found" => AssignError::UserNotFound,
+            "channel_not_found" => AssignError::ChannelNotFound,
+            "not_authed" => AssignError::NotAuthed,
+            "invalid_auth" => AssignError::InvalidAuth,
+            "account_inactive" => AssignError::AccountInactive,
+            "invalid_arg_name" => AssignError::InvalidArgName,
+            "invalid_array_arg" => AssignError::InvalidArrayArg,
+            "invalid_charset" => AssignError::InvalidCharset,
+            "invalid_form_data" => AssignError::InvalidFormData,
+            "invalid_post_type" => AssignError::InvalidPostType,
+            "missing_post_type" => AssignError::MissingPostType,
+            "team_added_to_org" => AssignError::TeamAddedToOrg,
+            "request_timeout" => AssignError::RequestTimeout,
+            _ => AssignError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AssignError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        AssignError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+AssignError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+AssignError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+AssignError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+AssignError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+AssignError::UserAlreadyTeamMember => "user_already_team_member: The user is already a member of the workspace.",
+AssignError::UserNotFound => "user_not_found: The user wasn't found.",
+AssignError::ChannelNotFound => "channel_not_found: One of the channels wasn't found.",
+AssignError::NotAuthed => "not_authed: No authentication token provided.",
+AssignError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+AssignError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+AssignError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+AssignError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+AssignError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+AssignError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+AssignError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+AssignError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+AssignError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+AssignError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        AssignError::MalformedResponse(_, ref e) => return write!(f, "admin.users.assign returned a malformed response: {}", e),
+                        AssignError::Unknown(ref s) => return write!(f, "admin.users.assign failed: {}", s),
+                        AssignError::Client(ref inner) => return write!(f, "admin.users.assign failed: {}", inner),
+                    };
+        write!(f, "admin.users.assign failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AssignError<E>> for crate::SlackError<E> {
+    fn from(e: AssignError<E>) -> Self {
+        let method = "admin.users.assign";
+        let error = match e {
+            AssignError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            AssignError::NotAnAdmin => "not_an_admin".to_owned(),
+            AssignError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            AssignError::MissingScope => "missing_scope".to_owned(),
+            AssignError::Ratelimited => "ratelimited".to_owned(),
+            AssignError::UserAlreadyTeamMember => "user_already_team_member".to_owned(),
+            AssignError::UserNotFound => "user_not_found".to_owned(),
+            AssignError::ChannelNotFound => "channel_not_found".to_owned(),
+            AssignError::NotAuthed => "not_authed".to_owned(),
+            AssignError::InvalidAuth => "invalid_auth".to_owned(),
+            AssignError::AccountInactive => "account_inactive".to_owned(),
+            AssignError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AssignError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AssignError::InvalidCharset => "invalid_charset".to_owned(),
+            AssignError::InvalidFormData => "invalid_form_data".to_owned(),
+            AssignError::InvalidPostType => "invalid_post_type".to_owned(),
+            AssignError::MissingPostType => "missing_post_type".to_owned(),
+            AssignError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AssignError::RequestTimeout => "request_timeout".to_owned(),
+            AssignError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AssignError::Unknown(s) => s,
+            AssignError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for AssignError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AssignError::MalformedResponse(_, ref e) => Some(e),
+            AssignError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InviteRequest<'a> {
+    /// Channels of the workspace the user joins when they accept.
+    pub channel_ids: &'a [&'a str],
+    /// The email address of the user to invite.
+    pub email: &'a str,
+    /// The workspace to invite the user to.
+    pub team_id: &'a str,
+    /// A message sent along with the invitation.
+    pub custom_message: Option<&'a str>,
+    /// Let the user sign in with an email and password instead of SSO.
+    pub email_password_policy_enabled: Option<bool>,
+    /// When a guest's account is deactivated.
+    pub guest_expiration_ts: Option<crate::Timestamp>,
+    /// Invite the user as a multi-channel guest.
+    pub is_restricted: Option<bool>,
+    /// Invite the user as a single-channel guest.
+    pub is_ultra_restricted: Option<bool>,
+    /// The user's full name.
+    pub real_name: Option<&'a str>,
+    /// Send the invitation again if the user has already been invited.
+    pub resend: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InviteResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<InviteResponse> for Result<InviteResponse, InviteError<E>> {
+    fn from(response: InviteResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InviteError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The user is already a member of the workspace.
+    AlreadyInTeam,
+    /// The user has already been invited and `resend` wasn't set.
+    AlreadyInTeamInvitedUser,
+    /// The email address is invalid.
+    InvalidEmail,
+    /// One of the channels wasn't found.
+    ChannelNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InviteError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => InviteError::FeatureNotEnabled,
+            "not_an_admin" => InviteError::NotAnAdmin,
+            "not_an_enterprise" => InviteError::NotAnEnterprise,
+            "missing_scope" => InviteError::MissingScope,
+            "ratelimited" => InviteError::Ratelimited,
+            "already_in_team" => InviteError::AlreadyInTeam,
+            "already_in_team_invited_user" => InviteError::AlreadyInTeamInvitedUser,
+            "invalid_email" => InviteError::InvalidEmail,
+            "channel_not_found" => InviteError::ChannelNotFound,
+            "not_authed" => InviteError::NotAuthed,
+            "invalid_auth" => InviteError::InvalidAuth,
+            "account_inactive" => InviteError::AccountInactive,
+            "invalid_arg_name" => InviteError::InvalidArgName,
+            "invalid_array_arg" => InviteError::InvalidArrayArg,
+            "invalid_charset" => InviteError::InvalidCharset,
+            "invalid_form_data" => InviteError::InvalidFormData,
+            "invalid_post_type" => InviteError::InvalidPostType,
+            "missing_post_type" => InviteError::MissingPostType,
+            "team_added_to_org" => InviteError::TeamAddedToOrg,
+            "request_timeout" => InviteError::RequestTimeout,
+            _ => InviteError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for InviteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        InviteError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+InviteError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+InviteError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+InviteError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+InviteError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+InviteError::AlreadyInTeam => "already_in_team: The user is already a member of the workspace.",
+InviteError::AlreadyInTeamInvitedUser => "already_in_team_invited_user: The user has already been invited and `resend` wasn't set.",
+InviteError::InvalidEmail => "invalid_email: The email address is invalid.",
+InviteError::ChannelNotFound => "channel_not_found: One of the channels wasn't found.",
+InviteError::NotAuthed => "not_authed: No authentication token provided.",
+InviteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+InviteError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+InviteError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+InviteError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+InviteError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+InviteError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+InviteError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+InviteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+InviteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+InviteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        InviteError::MalformedResponse(_, ref e) => return write!(f, "admin.users.invite returned a malformed response: {}", e),
+                        InviteError::Unknown(ref s) => return write!(f, "admin.users.invite failed: {}", s),
+                        InviteError::Client(ref inner) => return write!(f, "admin.users.invite failed: {}", inner),
+                    };
+        write!(f, "admin.users.invite failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InviteError<E>> for crate::SlackError<E> {
+    fn from(e: InviteError<E>) -> Self {
+        let method = "admin.users.invite";
+        let error = match e {
+            InviteError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            InviteError::NotAnAdmin => "not_an_admin".to_owned(),
+            InviteError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            InviteError::MissingScope => "missing_scope".to_owned(),
+            InviteError::Ratelimited => "ratelimited".to_owned(),
+            InviteError::AlreadyInTeam => "already_in_team".to_owned(),
+            InviteError::AlreadyInTeamInvitedUser => "already_in_team_invited_user".to_owned(),
+            InviteError::InvalidEmail => "invalid_email".to_owned(),
+            InviteError::ChannelNotFound => "channel_not_found".to_owned(),
+            InviteError::NotAuthed => "not_authed".to_owned(),
+            InviteError::InvalidAuth => "invalid_auth".to_owned(),
+            InviteError::AccountInactive => "account_inactive".to_owned(),
+            InviteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InviteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InviteError::InvalidCharset => "invalid_charset".to_owned(),
+            InviteError::InvalidFormData => "invalid_form_data".to_owned(),
+            InviteError::InvalidPostType => "invalid_post_type".to_owned(),
+            InviteError::MissingPostType => "missing_post_type".to_owned(),
+            InviteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InviteError::RequestTimeout => "request_timeout".to_owned(),
+            InviteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InviteError::Unknown(s) => s,
+            InviteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for InviteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            InviteError::MalformedResponse(_, ref e) => Some(e),
+            InviteError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
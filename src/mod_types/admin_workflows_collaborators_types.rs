@@ -0,0 +1,385 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage who can edit the workflows of an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct AddRequest<'a> {
+    /// The users to add, at most 50.
+    pub collaborator_ids: &'a [&'a str],
+    /// The workflows, at most 50.
+    pub workflow_ids: &'a [&'a str],
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AddResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// One of the workflow IDs was invalid.
+    InvalidWorkflowId,
+    /// One of the workflows wasn't found.
+    WorkflowNotFound,
+    /// One of the collaborator IDs was invalid.
+    InvalidCollaboratorId,
+    /// A workflow would have more collaborators than allowed.
+    TooManyCollaborators,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => AddError::FeatureNotEnabled,
+            "not_an_admin" => AddError::NotAnAdmin,
+            "not_an_enterprise" => AddError::NotAnEnterprise,
+            "missing_scope" => AddError::MissingScope,
+            "ratelimited" => AddError::Ratelimited,
+            "invalid_workflow_id" => AddError::InvalidWorkflowId,
+            "workflow_not_found" => AddError::WorkflowNotFound,
+            "invalid_collaborator_id" => AddError::InvalidCollaboratorId,
+            "too_many_collaborators" => AddError::TooManyCollaborators,
+            "not_authed" => AddError::NotAuthed,
+            "invalid_auth" => AddError::InvalidAuth,
+            "account_inactive" => AddError::AccountInactive,
+            "invalid_arg_name" => AddError::InvalidArgName,
+            "invalid_array_arg" => AddError::InvalidArrayArg,
+            "invalid_charset" => AddError::InvalidCharset,
+            "invalid_form_data" => AddError::InvalidFormData,
+            "invalid_post_type" => AddError::InvalidPostType,
+            "missing_post_type" => AddError::MissingPostType,
+            "team_added_to_org" => AddError::TeamAddedToOrg,
+            "request_timeout" => AddError::RequestTimeout,
+            _ => AddError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AddError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        AddError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+AddError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+AddError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+AddError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+AddError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+AddError::InvalidWorkflowId => "invalid_workflow_id: One of the workflow IDs was invalid.",
+AddError::WorkflowNotFound => "workflow_not_found: One of the workflows wasn't found.",
+AddError::InvalidCollaboratorId => "invalid_collaborator_id: One of the collaborator IDs was invalid.",
+AddError::TooManyCollaborators => "too_many_collaborators: A workflow would have more collaborators than allowed.",
+AddError::NotAuthed => "not_authed: No authentication token provided.",
+AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+AddError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+AddError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+AddError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+AddError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+AddError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+AddError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        AddError::MalformedResponse(_, ref e) => return write!(f, "admin.workflows.collaborators.add returned a malformed response: {}", e),
+                        AddError::Unknown(ref s) => return write!(f, "admin.workflows.collaborators.add failed: {}", s),
+                        AddError::Client(ref inner) => return write!(f, "admin.workflows.collaborators.add failed: {}", inner),
+                    };
+        write!(f, "admin.workflows.collaborators.add failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(e: AddError<E>) -> Self {
+        let method = "admin.workflows.collaborators.add";
+        let error = match e {
+            AddError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            AddError::NotAnAdmin => "not_an_admin".to_owned(),
+            AddError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            AddError::MissingScope => "missing_scope".to_owned(),
+            AddError::Ratelimited => "ratelimited".to_owned(),
+            AddError::InvalidWorkflowId => "invalid_workflow_id".to_owned(),
+            AddError::WorkflowNotFound => "workflow_not_found".to_owned(),
+            AddError::InvalidCollaboratorId => "invalid_collaborator_id".to_owned(),
+            AddError::TooManyCollaborators => "too_many_collaborators".to_owned(),
+            AddError::NotAuthed => "not_authed".to_owned(),
+            AddError::InvalidAuth => "invalid_auth".to_owned(),
+            AddError::AccountInactive => "account_inactive".to_owned(),
+            AddError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddError::InvalidCharset => "invalid_charset".to_owned(),
+            AddError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddError::MissingPostType => "missing_post_type".to_owned(),
+            AddError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddError::RequestTimeout => "request_timeout".to_owned(),
+            AddError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddError::Unknown(s) => s,
+            AddError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for AddError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AddError::MalformedResponse(_, ref e) => Some(e),
+            AddError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RemoveRequest<'a> {
+    /// The users to remove, at most 50.
+    pub collaborator_ids: &'a [&'a str],
+    /// The workflows, at most 50.
+    pub workflow_ids: &'a [&'a str],
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RemoveResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<RemoveResponse> for Result<RemoveResponse, RemoveError<E>> {
+    fn from(response: RemoveResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RemoveError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// One of the workflow IDs was invalid.
+    InvalidWorkflowId,
+    /// One of the workflows wasn't found.
+    WorkflowNotFound,
+    /// One of the collaborator IDs was invalid.
+    InvalidCollaboratorId,
+    /// A workflow would be left without collaborators.
+    CannotRemoveLastCollaborator,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RemoveError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => RemoveError::FeatureNotEnabled,
+            "not_an_admin" => RemoveError::NotAnAdmin,
+            "not_an_enterprise" => RemoveError::NotAnEnterprise,
+            "missing_scope" => RemoveError::MissingScope,
+            "ratelimited" => RemoveError::Ratelimited,
+            "invalid_workflow_id" => RemoveError::InvalidWorkflowId,
+            "workflow_not_found" => RemoveError::WorkflowNotFound,
+            "invalid_collaborator_id" => RemoveError::InvalidCollaboratorId,
+            "cannot_remove_last_collaborator" => RemoveError::CannotRemoveLastCollaborator,
+            "not_authed" => RemoveError::NotAuthed,
+            "invalid_auth" => RemoveError::InvalidAuth,
+            "account_inactive" => RemoveError::AccountInactive,
+            "invalid_arg_name" => RemoveError::InvalidArgName,
+            "invalid_array_arg" => RemoveError::InvalidArrayArg,
+            "invalid_charset" => RemoveError::InvalidCharset,
+            "invalid_form_data" => RemoveError::InvalidFormData,
+            "invalid_post_type" => RemoveError::InvalidPostType,
+            "missing_post_type" => RemoveError::MissingPostType,
+            "team_added_to_org" => RemoveError::TeamAddedToOrg,
+            "request_timeout" => RemoveError::RequestTimeout,
+            _ => RemoveError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for RemoveError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        RemoveError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+RemoveError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+RemoveError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+RemoveError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+RemoveError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+RemoveError::InvalidWorkflowId => "invalid_workflow_id: One of the workflow IDs was invalid.",
+RemoveError::WorkflowNotFound => "workflow_not_found: One of the workflows wasn't found.",
+RemoveError::InvalidCollaboratorId => "invalid_collaborator_id: One of the collaborator IDs was invalid.",
+RemoveError::CannotRemoveLastCollaborator => "cannot_remove_last_collaborator: A workflow would be left without collaborators.",
+RemoveError::NotAuthed => "not_authed: No authentication token provided.",
+RemoveError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+RemoveError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+RemoveError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+RemoveError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+RemoveError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+RemoveError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+RemoveError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+RemoveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+RemoveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+RemoveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        RemoveError::MalformedResponse(_, ref e) => return write!(f, "admin.workflows.collaborators.remove returned a malformed response: {}", e),
+                        RemoveError::Unknown(ref s) => return write!(f, "admin.workflows.collaborators.remove failed: {}", s),
+                        RemoveError::Client(ref inner) => return write!(f, "admin.workflows.collaborators.remove failed: {}", inner),
+                    };
+        write!(f, "admin.workflows.collaborators.remove failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RemoveError<E>> for crate::SlackError<E> {
+    fn from(e: RemoveError<E>) -> Self {
+        let method = "admin.workflows.collaborators.remove";
+        let error = match e {
+            RemoveError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            RemoveError::NotAnAdmin => "not_an_admin".to_owned(),
+            RemoveError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            RemoveError::MissingScope => "missing_scope".to_owned(),
+            RemoveError::Ratelimited => "ratelimited".to_owned(),
+            RemoveError::InvalidWorkflowId => "invalid_workflow_id".to_owned(),
+            RemoveError::WorkflowNotFound => "workflow_not_found".to_owned(),
+            RemoveError::InvalidCollaboratorId => "invalid_collaborator_id".to_owned(),
+            RemoveError::CannotRemoveLastCollaborator => {
+                "cannot_remove_last_collaborator".to_owned()
+            }
+            RemoveError::NotAuthed => "not_authed".to_owned(),
+            RemoveError::InvalidAuth => "invalid_auth".to_owned(),
+            RemoveError::AccountInactive => "account_inactive".to_owned(),
+            RemoveError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RemoveError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RemoveError::InvalidCharset => "invalid_charset".to_owned(),
+            RemoveError::InvalidFormData => "invalid_form_data".to_owned(),
+            RemoveError::InvalidPostType => "invalid_post_type".to_owned(),
+            RemoveError::MissingPostType => "missing_post_type".to_owned(),
+            RemoveError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RemoveError::RequestTimeout => "request_timeout".to_owned(),
+            RemoveError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RemoveError::Unknown(s) => s,
+            RemoveError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for RemoveError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RemoveError::MalformedResponse(_, ref e) => Some(e),
+            RemoveError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
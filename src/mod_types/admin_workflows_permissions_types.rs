@@ -0,0 +1,193 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Look up who can run the workflows of an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct LookupRequest<'a> {
+    /// The workflows, at most 50.
+    pub workflow_ids: &'a [&'a str],
+    /// How many of each workflow's triggers to consider, up to 100.
+    pub max_workflow_triggers: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LookupResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub permissions: Option<HashMap<String, crate::WorkflowPermission>>,
+}
+
+impl<E: Error> From<LookupResponse> for Result<LookupResponse, LookupError<E>> {
+    fn from(response: LookupResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum LookupError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// One of the workflow IDs was invalid.
+    InvalidWorkflowId,
+    /// One of the workflows wasn't found.
+    WorkflowNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for LookupError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => LookupError::FeatureNotEnabled,
+            "not_an_admin" => LookupError::NotAnAdmin,
+            "not_an_enterprise" => LookupError::NotAnEnterprise,
+            "missing_scope" => LookupError::MissingScope,
+            "ratelimited" => LookupError::Ratelimited,
+            "invalid_workflow_id" => LookupError::InvalidWorkflowId,
+            "workflow_not_found" => LookupError::WorkflowNotFound,
+            "not_authed" => LookupError::NotAuthed,
+            "invalid_auth" => LookupError::InvalidAuth,
+            "account_inactive" => LookupError::AccountInactive,
+            "invalid_arg_name" => LookupError::InvalidArgName,
+            "invalid_array_arg" => LookupError::InvalidArrayArg,
+            "invalid_charset" => LookupError::InvalidCharset,
+            "invalid_form_data" => LookupError::InvalidFormData,
+            "invalid_post_type" => LookupError::InvalidPostType,
+            "missing_post_type" => LookupError::MissingPostType,
+            "team_added_to_org" => LookupError::TeamAddedToOrg,
+            "request_timeout" => LookupError::RequestTimeout,
+            _ => LookupError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for LookupError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        LookupError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+LookupError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+LookupError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+LookupError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+LookupError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+LookupError::InvalidWorkflowId => "invalid_workflow_id: One of the workflow IDs was invalid.",
+LookupError::WorkflowNotFound => "workflow_not_found: One of the workflows wasn't found.",
+LookupError::NotAuthed => "not_authed: No authentication token provided.",
+LookupError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+LookupError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+LookupError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+LookupError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+LookupError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+LookupError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+LookupError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+LookupError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+LookupError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+LookupError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        LookupError::MalformedResponse(_, ref e) => return write!(f, "admin.workflows.permissions.lookup returned a malformed response: {}", e),
+                        LookupError::Unknown(ref s) => return write!(f, "admin.workflows.permissions.lookup failed: {}", s),
+                        LookupError::Client(ref inner) => return write!(f, "admin.workflows.permissions.lookup failed: {}", inner),
+                    };
+        write!(f, "admin.workflows.permissions.lookup failed: {}", d)
+    }
+}
+
+impl<E: Error> From<LookupError<E>> for crate::SlackError<E> {
+    fn from(e: LookupError<E>) -> Self {
+        let method = "admin.workflows.permissions.lookup";
+        let error = match e {
+            LookupError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            LookupError::NotAnAdmin => "not_an_admin".to_owned(),
+            LookupError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            LookupError::MissingScope => "missing_scope".to_owned(),
+            LookupError::Ratelimited => "ratelimited".to_owned(),
+            LookupError::InvalidWorkflowId => "invalid_workflow_id".to_owned(),
+            LookupError::WorkflowNotFound => "workflow_not_found".to_owned(),
+            LookupError::NotAuthed => "not_authed".to_owned(),
+            LookupError::InvalidAuth => "invalid_auth".to_owned(),
+            LookupError::AccountInactive => "account_inactive".to_owned(),
+            LookupError::InvalidArgName => "invalid_arg_name".to_owned(),
+            LookupError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            LookupError::InvalidCharset => "invalid_charset".to_owned(),
+            LookupError::InvalidFormData => "invalid_form_data".to_owned(),
+            LookupError::InvalidPostType => "invalid_post_type".to_owned(),
+            LookupError::MissingPostType => "missing_post_type".to_owned(),
+            LookupError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            LookupError::RequestTimeout => "request_timeout".to_owned(),
+            LookupError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            LookupError::Unknown(s) => s,
+            LookupError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for LookupError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            LookupError::MalformedResponse(_, ref e) => Some(e),
+            LookupError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Govern the workflows of an Enterprise Grid organization.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct SearchRequest<'a> {
+    /// Only find workflows of this app.
+    pub app_id: Option<&'a str>,
+    /// Only find workflows with these collaborators.
+    pub collaborator_ids: Option<&'a [&'a str]>,
+    /// Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// The maximum number of workflows to return, up to 50. Defaults to 50.
+    pub limit: Option<u32>,
+    /// Only find workflows without collaborators.
+    pub no_collaborators: Option<bool>,
+    /// How many of each workflow's triggers to include, up to 10.
+    pub num_trigger_ids: Option<u32>,
+    /// Text to match against the workflows' titles.
+    pub query: Option<&'a str>,
+    /// The field to sort by, `premium_runs` by default.
+    pub sort: Option<&'a str>,
+    /// `asc` or `desc`, the default.
+    pub sort_dir: Option<&'a str>,
+    /// Only find workflows from `code` or the `workflow_builder`.
+    pub source: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SearchResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+    pub total_found: Option<i32>,
+    pub workflows: Option<Vec<crate::Workflow>>,
+}
+
+impl crate::HasPagination for SearchResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<SearchResponse> for Result<SearchResponse, SearchError<E>> {
+    fn from(response: SearchResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SearchError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The cursor was invalid or expired.
+    InvalidCursor,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SearchError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => SearchError::FeatureNotEnabled,
+            "not_an_admin" => SearchError::NotAnAdmin,
+            "not_an_enterprise" => SearchError::NotAnEnterprise,
+            "missing_scope" => SearchError::MissingScope,
+            "ratelimited" => SearchError::Ratelimited,
+            "invalid_cursor" => SearchError::InvalidCursor,
+            "not_authed" => SearchError::NotAuthed,
+            "invalid_auth" => SearchError::InvalidAuth,
+            "account_inactive" => SearchError::AccountInactive,
+            "invalid_arg_name" => SearchError::InvalidArgName,
+            "invalid_array_arg" => SearchError::InvalidArrayArg,
+            "invalid_charset" => SearchError::InvalidCharset,
+            "invalid_form_data" => SearchError::InvalidFormData,
+            "invalid_post_type" => SearchError::InvalidPostType,
+            "missing_post_type" => SearchError::MissingPostType,
+            "team_added_to_org" => SearchError::TeamAddedToOrg,
+            "request_timeout" => SearchError::RequestTimeout,
+            _ => SearchError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SearchError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        SearchError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+SearchError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+SearchError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+SearchError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+SearchError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+SearchError::InvalidCursor => "invalid_cursor: The cursor was invalid or expired.",
+SearchError::NotAuthed => "not_authed: No authentication token provided.",
+SearchError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+SearchError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+SearchError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+SearchError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+SearchError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+SearchError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+SearchError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+SearchError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+SearchError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+SearchError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        SearchError::MalformedResponse(_, ref e) => return write!(f, "admin.workflows.search returned a malformed response: {}", e),
+                        SearchError::Unknown(ref s) => return write!(f, "admin.workflows.search failed: {}", s),
+                        SearchError::Client(ref inner) => return write!(f, "admin.workflows.search failed: {}", inner),
+                    };
+        write!(f, "admin.workflows.search failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SearchError<E>> for crate::SlackError<E> {
+    fn from(e: SearchError<E>) -> Self {
+        let method = "admin.workflows.search";
+        let error = match e {
+            SearchError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            SearchError::NotAnAdmin => "not_an_admin".to_owned(),
+            SearchError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            SearchError::MissingScope => "missing_scope".to_owned(),
+            SearchError::Ratelimited => "ratelimited".to_owned(),
+            SearchError::InvalidCursor => "invalid_cursor".to_owned(),
+            SearchError::NotAuthed => "not_authed".to_owned(),
+            SearchError::InvalidAuth => "invalid_auth".to_owned(),
+            SearchError::AccountInactive => "account_inactive".to_owned(),
+            SearchError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SearchError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SearchError::InvalidCharset => "invalid_charset".to_owned(),
+            SearchError::InvalidFormData => "invalid_form_data".to_owned(),
+            SearchError::InvalidPostType => "invalid_post_type".to_owned(),
+            SearchError::MissingPostType => "missing_post_type".to_owned(),
+            SearchError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SearchError::RequestTimeout => "request_timeout".to_owned(),
+            SearchError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SearchError::Unknown(s) => s,
+            SearchError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for SearchError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SearchError::MalformedResponse(_, ref e) => Some(e),
+            SearchError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UnpublishRequest<'a> {
+    /// The workflows, at most 50.
+    pub workflow_ids: &'a [&'a str],
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UnpublishResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<UnpublishResponse> for Result<UnpublishResponse, UnpublishError<E>> {
+    fn from(response: UnpublishResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UnpublishError<E: Error> {
+    /// The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.
+    FeatureNotEnabled,
+    /// The token doesn't belong to an admin or owner.
+    NotAnAdmin,
+    /// The token isn't for an Enterprise Grid organization.
+    NotAnEnterprise,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// One of the workflow IDs was invalid.
+    InvalidWorkflowId,
+    /// One of the workflows wasn't found.
+    WorkflowNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UnpublishError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "feature_not_enabled" => UnpublishError::FeatureNotEnabled,
+            "not_an_admin" => UnpublishError::NotAnAdmin,
+            "not_an_enterprise" => UnpublishError::NotAnEnterprise,
+            "missing_scope" => UnpublishError::MissingScope,
+            "ratelimited" => UnpublishError::Ratelimited,
+            "invalid_workflow_id" => UnpublishError::InvalidWorkflowId,
+            "workflow_not_found" => UnpublishError::WorkflowNotFound,
+            "not_authed" => UnpublishError::NotAuthed,
+            "invalid_auth" => UnpublishError::InvalidAuth,
+            "account_inactive" => UnpublishError::AccountInactive,
+            "invalid_arg_name" => UnpublishError::InvalidArgName,
+            "invalid_array_arg" => UnpublishError::InvalidArrayArg,
+            "invalid_charset" => UnpublishError::InvalidCharset,
+            "invalid_form_data" => UnpublishError::InvalidFormData,
+            "invalid_post_type" => UnpublishError::InvalidPostType,
+            "missing_post_type" => UnpublishError::MissingPostType,
+            "team_added_to_org" => UnpublishError::TeamAddedToOrg,
+            "request_timeout" => UnpublishError::RequestTimeout,
+            _ => UnpublishError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for UnpublishError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        UnpublishError::FeatureNotEnabled => "feature_not_enabled: The workspace isn't on Enterprise Grid or the feature isn't enabled for the organization.",
+UnpublishError::NotAnAdmin => "not_an_admin: The token doesn't belong to an admin or owner.",
+UnpublishError::NotAnEnterprise => "not_an_enterprise: The token isn't for an Enterprise Grid organization.",
+UnpublishError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+UnpublishError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+UnpublishError::InvalidWorkflowId => "invalid_workflow_id: One of the workflow IDs was invalid.",
+UnpublishError::WorkflowNotFound => "workflow_not_found: One of the workflows wasn't found.",
+UnpublishError::NotAuthed => "not_authed: No authentication token provided.",
+UnpublishError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+UnpublishError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+UnpublishError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+UnpublishError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+UnpublishError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+UnpublishError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+UnpublishError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+UnpublishError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+UnpublishError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+UnpublishError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        UnpublishError::MalformedResponse(_, ref e) => return write!(f, "admin.workflows.unpublish returned a malformed response: {}", e),
+                        UnpublishError::Unknown(ref s) => return write!(f, "admin.workflows.unpublish failed: {}", s),
+                        UnpublishError::Client(ref inner) => return write!(f, "admin.workflows.unpublish failed: {}", inner),
+                    };
+        write!(f, "admin.workflows.unpublish failed: {}", d)
+    }
+}
+
+impl<E: Error> From<UnpublishError<E>> for crate::SlackError<E> {
+    fn from(e: UnpublishError<E>) -> Self {
+        let method = "admin.workflows.unpublish";
+        let error = match e {
+            UnpublishError::FeatureNotEnabled => "feature_not_enabled".to_owned(),
+            UnpublishError::NotAnAdmin => "not_an_admin".to_owned(),
+            UnpublishError::NotAnEnterprise => "not_an_enterprise".to_owned(),
+            UnpublishError::MissingScope => "missing_scope".to_owned(),
+            UnpublishError::Ratelimited => "ratelimited".to_owned(),
+            UnpublishError::InvalidWorkflowId => "invalid_workflow_id".to_owned(),
+            UnpublishError::WorkflowNotFound => "workflow_not_found".to_owned(),
+            UnpublishError::NotAuthed => "not_authed".to_owned(),
+            UnpublishError::InvalidAuth => "invalid_auth".to_owned(),
+            UnpublishError::AccountInactive => "account_inactive".to_owned(),
+            UnpublishError::InvalidArgName => "invalid_arg_name".to_owned(),
+            UnpublishError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            UnpublishError::InvalidCharset => "invalid_charset".to_owned(),
+            UnpublishError::InvalidFormData => "invalid_form_data".to_owned(),
+            UnpublishError::InvalidPostType => "invalid_post_type".to_owned(),
+            UnpublishError::MissingPostType => "missing_post_type".to_owned(),
+            UnpublishError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            UnpublishError::RequestTimeout => "request_timeout".to_owned(),
+            UnpublishError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            UnpublishError::Unknown(s) => s,
+            UnpublishError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for UnpublishError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UnpublishError::MalformedResponse(_, ref e) => Some(e),
+            UnpublishError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
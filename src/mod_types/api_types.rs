@@ -26,7 +26,7 @@ pub struct TestRequest<'a> {
     pub foo: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TestResponse {
     pub args: Option<HashMap<String, String>>,
     error: Option<String>,
@@ -34,12 +34,12 @@ pub struct TestResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<TestResponse, TestError<E>>> for TestResponse {
-    fn into(self) -> Result<TestResponse, TestError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<TestResponse> for Result<TestResponse, TestError<E>> {
+    fn from(response: TestResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -96,11 +96,37 @@ TestError::InvalidPostType => "invalid_post_type: The method was called via a PO
 TestError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 TestError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 TestError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        TestError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        TestError::Unknown(ref s) => return write!(f, "{}", s),
-                        TestError::Client(ref inner) => return write!(f, "{}", inner),
+                        TestError::MalformedResponse(_, ref e) => return write!(f, "api.test returned a malformed response: {}", e),
+                        TestError::Unknown(ref s) => return write!(f, "api.test failed: {}", s),
+                        TestError::Client(ref inner) => return write!(f, "api.test failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "api.test failed: {}", d)
+    }
+}
+
+impl<E: Error> From<TestError<E>> for crate::SlackError<E> {
+    fn from(e: TestError<E>) -> Self {
+        let method = "api.test";
+        let error = match e {
+            TestError::InvalidArgName => "invalid_arg_name".to_owned(),
+            TestError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            TestError::InvalidCharset => "invalid_charset".to_owned(),
+            TestError::InvalidFormData => "invalid_form_data".to_owned(),
+            TestError::InvalidPostType => "invalid_post_type".to_owned(),
+            TestError::MissingPostType => "missing_post_type".to_owned(),
+            TestError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            TestError::RequestTimeout => "request_timeout".to_owned(),
+            TestError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            TestError::Unknown(s) => s,
+            TestError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
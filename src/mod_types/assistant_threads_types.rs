@@ -0,0 +1,521 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage the threads of an app's AI assistant.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct SetStatusRequest<'a> {
+    /// The assistant thread's channel.
+    pub channel_id: &'a str,
+    /// The timestamp of the assistant thread's parent message.
+    pub thread_ts: crate::Timestamp,
+    /// The status to show. An empty status clears it, as does posting a reply.
+    pub status: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SetStatusResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<SetStatusResponse> for Result<SetStatusResponse, SetStatusError<E>> {
+    fn from(response: SetStatusResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetStatusError<E: Error> {
+    /// The channel wasn't found.
+    ChannelNotFound,
+    /// The thread wasn't an assistant thread.
+    InvalidThreadTs,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetStatusError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => SetStatusError::ChannelNotFound,
+            "invalid_thread_ts" => SetStatusError::InvalidThreadTs,
+            "not_allowed_token_type" => SetStatusError::NotAllowedTokenType,
+            "missing_scope" => SetStatusError::MissingScope,
+            "ratelimited" => SetStatusError::Ratelimited,
+            "not_authed" => SetStatusError::NotAuthed,
+            "invalid_auth" => SetStatusError::InvalidAuth,
+            "account_inactive" => SetStatusError::AccountInactive,
+            "invalid_arg_name" => SetStatusError::InvalidArgName,
+            "invalid_array_arg" => SetStatusError::InvalidArrayArg,
+            "invalid_charset" => SetStatusError::InvalidCharset,
+            "invalid_form_data" => SetStatusError::InvalidFormData,
+            "invalid_post_type" => SetStatusError::InvalidPostType,
+            "missing_post_type" => SetStatusError::MissingPostType,
+            "team_added_to_org" => SetStatusError::TeamAddedToOrg,
+            "request_timeout" => SetStatusError::RequestTimeout,
+            _ => SetStatusError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetStatusError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        SetStatusError::ChannelNotFound => "channel_not_found: The channel wasn't found.",
+SetStatusError::InvalidThreadTs => "invalid_thread_ts: The thread wasn't an assistant thread.",
+SetStatusError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+SetStatusError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+SetStatusError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+SetStatusError::NotAuthed => "not_authed: No authentication token provided.",
+SetStatusError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+SetStatusError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+SetStatusError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+SetStatusError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+SetStatusError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+SetStatusError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+SetStatusError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+SetStatusError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+SetStatusError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+SetStatusError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        SetStatusError::MalformedResponse(_, ref e) => return write!(f, "assistant.threads.setStatus returned a malformed response: {}", e),
+                        SetStatusError::Unknown(ref s) => return write!(f, "assistant.threads.setStatus failed: {}", s),
+                        SetStatusError::Client(ref inner) => return write!(f, "assistant.threads.setStatus failed: {}", inner),
+                    };
+        write!(f, "assistant.threads.setStatus failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetStatusError<E>> for crate::SlackError<E> {
+    fn from(e: SetStatusError<E>) -> Self {
+        let method = "assistant.threads.setStatus";
+        let error = match e {
+            SetStatusError::ChannelNotFound => "channel_not_found".to_owned(),
+            SetStatusError::InvalidThreadTs => "invalid_thread_ts".to_owned(),
+            SetStatusError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            SetStatusError::MissingScope => "missing_scope".to_owned(),
+            SetStatusError::Ratelimited => "ratelimited".to_owned(),
+            SetStatusError::NotAuthed => "not_authed".to_owned(),
+            SetStatusError::InvalidAuth => "invalid_auth".to_owned(),
+            SetStatusError::AccountInactive => "account_inactive".to_owned(),
+            SetStatusError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetStatusError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetStatusError::InvalidCharset => "invalid_charset".to_owned(),
+            SetStatusError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetStatusError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetStatusError::MissingPostType => "missing_post_type".to_owned(),
+            SetStatusError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetStatusError::RequestTimeout => "request_timeout".to_owned(),
+            SetStatusError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetStatusError::Unknown(s) => s,
+            SetStatusError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for SetStatusError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SetStatusError::MalformedResponse(_, ref e) => Some(e),
+            SetStatusError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetSuggestedPromptsRequest<'a> {
+    /// The assistant thread's channel.
+    pub channel_id: &'a str,
+    /// The timestamp of the assistant thread's parent message.
+    pub thread_ts: crate::Timestamp,
+    /// The prompts, at most 4.
+    pub prompts: &'a [crate::SuggestedPrompt],
+    /// A title shown above the prompts.
+    pub title: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SetSuggestedPromptsResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<SetSuggestedPromptsResponse>
+    for Result<SetSuggestedPromptsResponse, SetSuggestedPromptsError<E>>
+{
+    fn from(response: SetSuggestedPromptsResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetSuggestedPromptsError<E: Error> {
+    /// The channel wasn't found.
+    ChannelNotFound,
+    /// The thread wasn't an assistant thread.
+    InvalidThreadTs,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The prompts were empty, too many or malformed.
+    InvalidPrompts,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetSuggestedPromptsError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => SetSuggestedPromptsError::ChannelNotFound,
+            "invalid_thread_ts" => SetSuggestedPromptsError::InvalidThreadTs,
+            "not_allowed_token_type" => SetSuggestedPromptsError::NotAllowedTokenType,
+            "missing_scope" => SetSuggestedPromptsError::MissingScope,
+            "ratelimited" => SetSuggestedPromptsError::Ratelimited,
+            "invalid_prompts" => SetSuggestedPromptsError::InvalidPrompts,
+            "not_authed" => SetSuggestedPromptsError::NotAuthed,
+            "invalid_auth" => SetSuggestedPromptsError::InvalidAuth,
+            "account_inactive" => SetSuggestedPromptsError::AccountInactive,
+            "invalid_arg_name" => SetSuggestedPromptsError::InvalidArgName,
+            "invalid_array_arg" => SetSuggestedPromptsError::InvalidArrayArg,
+            "invalid_charset" => SetSuggestedPromptsError::InvalidCharset,
+            "invalid_form_data" => SetSuggestedPromptsError::InvalidFormData,
+            "invalid_post_type" => SetSuggestedPromptsError::InvalidPostType,
+            "missing_post_type" => SetSuggestedPromptsError::MissingPostType,
+            "team_added_to_org" => SetSuggestedPromptsError::TeamAddedToOrg,
+            "request_timeout" => SetSuggestedPromptsError::RequestTimeout,
+            _ => SetSuggestedPromptsError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetSuggestedPromptsError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        SetSuggestedPromptsError::ChannelNotFound => "channel_not_found: The channel wasn't found.",
+SetSuggestedPromptsError::InvalidThreadTs => "invalid_thread_ts: The thread wasn't an assistant thread.",
+SetSuggestedPromptsError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+SetSuggestedPromptsError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+SetSuggestedPromptsError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+SetSuggestedPromptsError::InvalidPrompts => "invalid_prompts: The prompts were empty, too many or malformed.",
+SetSuggestedPromptsError::NotAuthed => "not_authed: No authentication token provided.",
+SetSuggestedPromptsError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+SetSuggestedPromptsError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+SetSuggestedPromptsError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+SetSuggestedPromptsError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+SetSuggestedPromptsError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+SetSuggestedPromptsError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+SetSuggestedPromptsError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+SetSuggestedPromptsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+SetSuggestedPromptsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+SetSuggestedPromptsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        SetSuggestedPromptsError::MalformedResponse(_, ref e) => return write!(f, "assistant.threads.setSuggestedPrompts returned a malformed response: {}", e),
+                        SetSuggestedPromptsError::Unknown(ref s) => return write!(f, "assistant.threads.setSuggestedPrompts failed: {}", s),
+                        SetSuggestedPromptsError::Client(ref inner) => return write!(f, "assistant.threads.setSuggestedPrompts failed: {}", inner),
+                    };
+        write!(f, "assistant.threads.setSuggestedPrompts failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetSuggestedPromptsError<E>> for crate::SlackError<E> {
+    fn from(e: SetSuggestedPromptsError<E>) -> Self {
+        let method = "assistant.threads.setSuggestedPrompts";
+        let error = match e {
+            SetSuggestedPromptsError::ChannelNotFound => "channel_not_found".to_owned(),
+            SetSuggestedPromptsError::InvalidThreadTs => "invalid_thread_ts".to_owned(),
+            SetSuggestedPromptsError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            SetSuggestedPromptsError::MissingScope => "missing_scope".to_owned(),
+            SetSuggestedPromptsError::Ratelimited => "ratelimited".to_owned(),
+            SetSuggestedPromptsError::InvalidPrompts => "invalid_prompts".to_owned(),
+            SetSuggestedPromptsError::NotAuthed => "not_authed".to_owned(),
+            SetSuggestedPromptsError::InvalidAuth => "invalid_auth".to_owned(),
+            SetSuggestedPromptsError::AccountInactive => "account_inactive".to_owned(),
+            SetSuggestedPromptsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetSuggestedPromptsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetSuggestedPromptsError::InvalidCharset => "invalid_charset".to_owned(),
+            SetSuggestedPromptsError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetSuggestedPromptsError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetSuggestedPromptsError::MissingPostType => "missing_post_type".to_owned(),
+            SetSuggestedPromptsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetSuggestedPromptsError::RequestTimeout => "request_timeout".to_owned(),
+            SetSuggestedPromptsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetSuggestedPromptsError::Unknown(s) => s,
+            SetSuggestedPromptsError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for SetSuggestedPromptsError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SetSuggestedPromptsError::MalformedResponse(_, ref e) => Some(e),
+            SetSuggestedPromptsError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetTitleRequest<'a> {
+    /// The assistant thread's channel.
+    pub channel_id: &'a str,
+    /// The timestamp of the assistant thread's parent message.
+    pub thread_ts: crate::Timestamp,
+    /// The title.
+    pub title: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SetTitleResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<SetTitleResponse> for Result<SetTitleResponse, SetTitleError<E>> {
+    fn from(response: SetTitleResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetTitleError<E: Error> {
+    /// The channel wasn't found.
+    ChannelNotFound,
+    /// The thread wasn't an assistant thread.
+    InvalidThreadTs,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetTitleError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => SetTitleError::ChannelNotFound,
+            "invalid_thread_ts" => SetTitleError::InvalidThreadTs,
+            "not_allowed_token_type" => SetTitleError::NotAllowedTokenType,
+            "missing_scope" => SetTitleError::MissingScope,
+            "ratelimited" => SetTitleError::Ratelimited,
+            "not_authed" => SetTitleError::NotAuthed,
+            "invalid_auth" => SetTitleError::InvalidAuth,
+            "account_inactive" => SetTitleError::AccountInactive,
+            "invalid_arg_name" => SetTitleError::InvalidArgName,
+            "invalid_array_arg" => SetTitleError::InvalidArrayArg,
+            "invalid_charset" => SetTitleError::InvalidCharset,
+            "invalid_form_data" => SetTitleError::InvalidFormData,
+            "invalid_post_type" => SetTitleError::InvalidPostType,
+            "missing_post_type" => SetTitleError::MissingPostType,
+            "team_added_to_org" => SetTitleError::TeamAddedToOrg,
+            "request_timeout" => SetTitleError::RequestTimeout,
+            _ => SetTitleError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetTitleError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        SetTitleError::ChannelNotFound => "channel_not_found: The channel wasn't found.",
+SetTitleError::InvalidThreadTs => "invalid_thread_ts: The thread wasn't an assistant thread.",
+SetTitleError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+SetTitleError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+SetTitleError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+SetTitleError::NotAuthed => "not_authed: No authentication token provided.",
+SetTitleError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+SetTitleError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+SetTitleError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+SetTitleError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+SetTitleError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+SetTitleError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+SetTitleError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+SetTitleError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+SetTitleError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+SetTitleError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        SetTitleError::MalformedResponse(_, ref e) => return write!(f, "assistant.threads.setTitle returned a malformed response: {}", e),
+                        SetTitleError::Unknown(ref s) => return write!(f, "assistant.threads.setTitle failed: {}", s),
+                        SetTitleError::Client(ref inner) => return write!(f, "assistant.threads.setTitle failed: {}", inner),
+                    };
+        write!(f, "assistant.threads.setTitle failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetTitleError<E>> for crate::SlackError<E> {
+    fn from(e: SetTitleError<E>) -> Self {
+        let method = "assistant.threads.setTitle";
+        let error = match e {
+            SetTitleError::ChannelNotFound => "channel_not_found".to_owned(),
+            SetTitleError::InvalidThreadTs => "invalid_thread_ts".to_owned(),
+            SetTitleError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            SetTitleError::MissingScope => "missing_scope".to_owned(),
+            SetTitleError::Ratelimited => "ratelimited".to_owned(),
+            SetTitleError::NotAuthed => "not_authed".to_owned(),
+            SetTitleError::InvalidAuth => "invalid_auth".to_owned(),
+            SetTitleError::AccountInactive => "account_inactive".to_owned(),
+            SetTitleError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetTitleError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetTitleError::InvalidCharset => "invalid_charset".to_owned(),
+            SetTitleError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetTitleError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetTitleError::MissingPostType => "missing_post_type".to_owned(),
+            SetTitleError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetTitleError::RequestTimeout => "request_timeout".to_owned(),
+            SetTitleError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetTitleError::Unknown(s) => s,
+            SetTitleError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for SetTitleError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SetTitleError::MalformedResponse(_, ref e) => Some(e),
+            SetTitleError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
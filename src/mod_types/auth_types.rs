@@ -24,7 +24,7 @@ pub struct RevokeRequest {
     pub test: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RevokeResponse {
     error: Option<String>,
     #[serde(default)]
@@ -32,12 +32,12 @@ pub struct RevokeResponse {
     pub revoked: Option<bool>,
 }
 
-impl<E: Error> Into<Result<RevokeResponse, RevokeError<E>>> for RevokeResponse {
-    fn into(self) -> Result<RevokeResponse, RevokeError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RevokeResponse> for Result<RevokeResponse, RevokeError<E>> {
+    fn from(response: RevokeResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -106,11 +106,40 @@ RevokeError::InvalidPostType => "invalid_post_type: The method was called via a
 RevokeError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 RevokeError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 RevokeError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        RevokeError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        RevokeError::Unknown(ref s) => return write!(f, "{}", s),
-                        RevokeError::Client(ref inner) => return write!(f, "{}", inner),
+                        RevokeError::MalformedResponse(_, ref e) => return write!(f, "auth.revoke returned a malformed response: {}", e),
+                        RevokeError::Unknown(ref s) => return write!(f, "auth.revoke failed: {}", s),
+                        RevokeError::Client(ref inner) => return write!(f, "auth.revoke failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "auth.revoke failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RevokeError<E>> for crate::SlackError<E> {
+    fn from(e: RevokeError<E>) -> Self {
+        let method = "auth.revoke";
+        let error = match e {
+            RevokeError::NotAuthed => "not_authed".to_owned(),
+            RevokeError::InvalidAuth => "invalid_auth".to_owned(),
+            RevokeError::AccountInactive => "account_inactive".to_owned(),
+            RevokeError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RevokeError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RevokeError::InvalidCharset => "invalid_charset".to_owned(),
+            RevokeError::InvalidFormData => "invalid_form_data".to_owned(),
+            RevokeError::InvalidPostType => "invalid_post_type".to_owned(),
+            RevokeError::MissingPostType => "missing_post_type".to_owned(),
+            RevokeError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RevokeError::RequestTimeout => "request_timeout".to_owned(),
+            RevokeError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RevokeError::Unknown(s) => s,
+            RevokeError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -124,11 +153,13 @@ impl<E: Error + 'static> Error for RevokeError<E> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TestResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub bot_id: Option<String>,
+    pub enterprise_id: Option<String>,
     pub team: Option<String>,
     pub team_id: Option<String>,
     pub url: Option<String>,
@@ -136,12 +167,12 @@ pub struct TestResponse {
     pub user_id: Option<String>,
 }
 
-impl<E: Error> Into<Result<TestResponse, TestError<E>>> for TestResponse {
-    fn into(self) -> Result<TestResponse, TestError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<TestResponse> for Result<TestResponse, TestError<E>> {
+    fn from(response: TestResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -210,11 +241,40 @@ TestError::InvalidPostType => "invalid_post_type: The method was called via a PO
 TestError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 TestError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 TestError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        TestError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        TestError::Unknown(ref s) => return write!(f, "{}", s),
-                        TestError::Client(ref inner) => return write!(f, "{}", inner),
+                        TestError::MalformedResponse(_, ref e) => return write!(f, "auth.test returned a malformed response: {}", e),
+                        TestError::Unknown(ref s) => return write!(f, "auth.test failed: {}", s),
+                        TestError::Client(ref inner) => return write!(f, "auth.test failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "auth.test failed: {}", d)
+    }
+}
+
+impl<E: Error> From<TestError<E>> for crate::SlackError<E> {
+    fn from(e: TestError<E>) -> Self {
+        let method = "auth.test";
+        let error = match e {
+            TestError::NotAuthed => "not_authed".to_owned(),
+            TestError::InvalidAuth => "invalid_auth".to_owned(),
+            TestError::AccountInactive => "account_inactive".to_owned(),
+            TestError::InvalidArgName => "invalid_arg_name".to_owned(),
+            TestError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            TestError::InvalidCharset => "invalid_charset".to_owned(),
+            TestError::InvalidFormData => "invalid_form_data".to_owned(),
+            TestError::InvalidPostType => "invalid_post_type".to_owned(),
+            TestError::MissingPostType => "missing_post_type".to_owned(),
+            TestError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            TestError::RequestTimeout => "request_timeout".to_owned(),
+            TestError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            TestError::Unknown(s) => s,
+            TestError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -0,0 +1,348 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage the bookmarks in a channel's bookmark bar.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct AddRequest<'a> {
+    /// Channel to add bookmark in.
+    pub channel_id: &'a str,
+    /// Title for the bookmark.
+    pub title: &'a str,
+    /// Type of the bookmark i.e link.
+    pub r#type: &'a str,
+    /// Emoji tag to apply to the link.
+    pub emoji: Option<&'a str>,
+    /// ID of the entity being bookmarked. Only applies to message and file types.
+    pub entity_id: Option<&'a str>,
+    /// Link to bookmark.
+    pub link: Option<&'a str>,
+    /// Id of this bookmark's parent.
+    pub parent_id: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct AddResponse {
+    pub bookmark: Option<crate::Bookmark>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum AddError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// Value passed for type was invalid.
+    InvalidBookmarkType,
+    /// Value passed for link was invalid.
+    InvalidLink,
+    /// Authenticated user is not in the channel.
+    NotInChannel,
+    /// A team preference prevents the authenticated user from adding bookmarks.
+    RestrictedAction,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for AddError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => AddError::ChannelNotFound,
+            "invalid_bookmark_type" => AddError::InvalidBookmarkType,
+            "invalid_link" => AddError::InvalidLink,
+            "not_in_channel" => AddError::NotInChannel,
+            "restricted_action" => AddError::RestrictedAction,
+            "missing_scope" => AddError::MissingScope,
+            "not_authed" => AddError::NotAuthed,
+            "invalid_auth" => AddError::InvalidAuth,
+            "account_inactive" => AddError::AccountInactive,
+            "invalid_arg_name" => AddError::InvalidArgName,
+            "invalid_array_arg" => AddError::InvalidArrayArg,
+            "invalid_charset" => AddError::InvalidCharset,
+            "invalid_form_data" => AddError::InvalidFormData,
+            "invalid_post_type" => AddError::InvalidPostType,
+            "missing_post_type" => AddError::MissingPostType,
+            "team_added_to_org" => AddError::TeamAddedToOrg,
+            "request_timeout" => AddError::RequestTimeout,
+            _ => AddError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for AddError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        AddError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+AddError::InvalidBookmarkType => "invalid_bookmark_type: Value passed for type was invalid.",
+AddError::InvalidLink => "invalid_link: Value passed for link was invalid.",
+AddError::NotInChannel => "not_in_channel: Authenticated user is not in the channel.",
+AddError::RestrictedAction => "restricted_action: A team preference prevents the authenticated user from adding bookmarks.",
+AddError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+AddError::NotAuthed => "not_authed: No authentication token provided.",
+AddError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+AddError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+AddError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+AddError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+AddError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+AddError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+AddError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        AddError::MalformedResponse(_, ref e) => return write!(f, "bookmarks.add returned a malformed response: {}", e),
+                        AddError::Unknown(ref s) => return write!(f, "bookmarks.add failed: {}", s),
+                        AddError::Client(ref inner) => return write!(f, "bookmarks.add failed: {}", inner),
+                    };
+        write!(f, "bookmarks.add failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(e: AddError<E>) -> Self {
+        let method = "bookmarks.add";
+        let error = match e {
+            AddError::ChannelNotFound => "channel_not_found".to_owned(),
+            AddError::InvalidBookmarkType => "invalid_bookmark_type".to_owned(),
+            AddError::InvalidLink => "invalid_link".to_owned(),
+            AddError::NotInChannel => "not_in_channel".to_owned(),
+            AddError::RestrictedAction => "restricted_action".to_owned(),
+            AddError::MissingScope => "missing_scope".to_owned(),
+            AddError::NotAuthed => "not_authed".to_owned(),
+            AddError::InvalidAuth => "invalid_auth".to_owned(),
+            AddError::AccountInactive => "account_inactive".to_owned(),
+            AddError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddError::InvalidCharset => "invalid_charset".to_owned(),
+            AddError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddError::MissingPostType => "missing_post_type".to_owned(),
+            AddError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddError::RequestTimeout => "request_timeout".to_owned(),
+            AddError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddError::Unknown(s) => s,
+            AddError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for AddError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            AddError::MalformedResponse(_, ref e) => Some(e),
+            AddError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Channel to list bookmarks in.
+    pub channel_id: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListResponse {
+    pub bookmarks: Option<Vec<crate::Bookmark>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// Value passed for channel_id was invalid.
+    ChannelNotFound,
+    /// Authenticated user is not in the channel.
+    NotInChannel,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => ListError::ChannelNotFound,
+            "not_in_channel" => ListError::NotInChannel,
+            "missing_scope" => ListError::MissingScope,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ListError::ChannelNotFound => "channel_not_found: Value passed for channel_id was invalid.",
+ListError::NotInChannel => "not_in_channel: Authenticated user is not in the channel.",
+ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ListError::NotAuthed => "not_authed: No authentication token provided.",
+ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "bookmarks.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "bookmarks.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "bookmarks.list failed: {}", inner),
+                    };
+        write!(f, "bookmarks.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "bookmarks.list";
+        let error = match e {
+            ListError::ChannelNotFound => "channel_not_found".to_owned(),
+            ListError::NotInChannel => "not_in_channel".to_owned(),
+            ListError::MissingScope => "missing_scope".to_owned(),
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ListError::MalformedResponse(_, ref e) => Some(e),
+            ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -24,7 +24,7 @@ pub struct InfoRequest<'a> {
     pub bot: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InfoResponse {
     pub bot: Option<InfoResponseBot>,
     error: Option<String>,
@@ -32,7 +32,7 @@ pub struct InfoResponse {
     ok: bool,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InfoResponseBot {
     pub app_id: Option<String>,
     pub deleted: Option<bool>,
@@ -41,19 +41,19 @@ pub struct InfoResponseBot {
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InfoResponseBotIcons {
     pub image_36: Option<String>,
     pub image_48: Option<String>,
     pub image_72: Option<String>,
 }
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -130,11 +130,42 @@ InfoError::InvalidPostType => "invalid_post_type: The method was called via a PO
 InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        InfoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        InfoError::Unknown(ref s) => return write!(f, "{}", s),
-                        InfoError::Client(ref inner) => return write!(f, "{}", inner),
+                        InfoError::MalformedResponse(_, ref e) => return write!(f, "bots.info returned a malformed response: {}", e),
+                        InfoError::Unknown(ref s) => return write!(f, "bots.info failed: {}", s),
+                        InfoError::Client(ref inner) => return write!(f, "bots.info failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "bots.info failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(e: InfoError<E>) -> Self {
+        let method = "bots.info";
+        let error = match e {
+            InfoError::BotNotFound => "bot_not_found".to_owned(),
+            InfoError::NotAuthed => "not_authed".to_owned(),
+            InfoError::InvalidAuth => "invalid_auth".to_owned(),
+            InfoError::AccountInactive => "account_inactive".to_owned(),
+            InfoError::UserIsBot => "user_is_bot".to_owned(),
+            InfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InfoError::InvalidCharset => "invalid_charset".to_owned(),
+            InfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            InfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            InfoError::MissingPostType => "missing_post_type".to_owned(),
+            InfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InfoError::RequestTimeout => "request_timeout".to_owned(),
+            InfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InfoError::Unknown(s) => s,
+            InfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
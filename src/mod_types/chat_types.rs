@@ -30,21 +30,29 @@ pub struct DeleteRequest<'a> {
     pub as_user: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct DeleteResponse {
     pub channel: Option<String>,
     error: Option<String>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub ts: Option<crate::Timestamp>,
+    pub warning: Option<String>,
 }
 
-impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
-    fn into(self) -> Result<DeleteResponse, DeleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl crate::HasPagination for DeleteResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<DeleteResponse> for Result<DeleteResponse, DeleteError<E>> {
+    fn from(response: DeleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -129,11 +137,46 @@ DeleteError::InvalidPostType => "invalid_post_type: The method was called via a
 DeleteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 DeleteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 DeleteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        DeleteError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        DeleteError::Unknown(ref s) => return write!(f, "{}", s),
-                        DeleteError::Client(ref inner) => return write!(f, "{}", inner),
+                        DeleteError::MalformedResponse(_, ref e) => return write!(f, "chat.delete returned a malformed response: {}", e),
+                        DeleteError::Unknown(ref s) => return write!(f, "chat.delete failed: {}", s),
+                        DeleteError::Client(ref inner) => return write!(f, "chat.delete failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "chat.delete failed: {}", d)
+    }
+}
+
+impl<E: Error> From<DeleteError<E>> for crate::SlackError<E> {
+    fn from(e: DeleteError<E>) -> Self {
+        let method = "chat.delete";
+        let error = match e {
+            DeleteError::MessageNotFound => "message_not_found".to_owned(),
+            DeleteError::ChannelNotFound => "channel_not_found".to_owned(),
+            DeleteError::CantDeleteMessage => "cant_delete_message".to_owned(),
+            DeleteError::ComplianceExportsPreventDeletion => {
+                "compliance_exports_prevent_deletion".to_owned()
+            }
+            DeleteError::NotAuthed => "not_authed".to_owned(),
+            DeleteError::InvalidAuth => "invalid_auth".to_owned(),
+            DeleteError::AccountInactive => "account_inactive".to_owned(),
+            DeleteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            DeleteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            DeleteError::InvalidCharset => "invalid_charset".to_owned(),
+            DeleteError::InvalidFormData => "invalid_form_data".to_owned(),
+            DeleteError::InvalidPostType => "invalid_post_type".to_owned(),
+            DeleteError::MissingPostType => "missing_post_type".to_owned(),
+            DeleteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            DeleteError::RequestTimeout => "request_timeout".to_owned(),
+            DeleteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            DeleteError::Unknown(s) => s,
+            DeleteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -147,6 +190,177 @@ impl<E: Error + 'static> Error for DeleteError<E> {
     }
 }
 
+#[derive(Clone, Default, Debug)]
+pub struct DeleteScheduledMessageRequest<'a> {
+    /// The channel the scheduled_message is posting to
+    pub channel: &'a str,
+    /// scheduled_message_id returned from call to chat.scheduleMessage
+    pub scheduled_message_id: &'a str,
+    /// Pass true to delete the message as the authed user with chat:write:user scope. Bot users in this context are considered authed users. If unused or false, the message will be deleted with chat:write:bot scope.
+    pub as_user: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DeleteScheduledMessageResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<DeleteScheduledMessageResponse>
+    for Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<E>>
+{
+    fn from(response: DeleteScheduledMessageResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum DeleteScheduledMessageError<E: Error> {
+    /// The scheduled_message_id passed is either invalid, already posted or already deleted.
+    InvalidScheduledMessageId,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The token passed is invalid.
+    BadToken,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for DeleteScheduledMessageError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_scheduled_message_id" => {
+                DeleteScheduledMessageError::InvalidScheduledMessageId
+            }
+            "channel_not_found" => DeleteScheduledMessageError::ChannelNotFound,
+            "bad_token" => DeleteScheduledMessageError::BadToken,
+            "missing_scope" => DeleteScheduledMessageError::MissingScope,
+            "ratelimited" => DeleteScheduledMessageError::Ratelimited,
+            "not_authed" => DeleteScheduledMessageError::NotAuthed,
+            "invalid_auth" => DeleteScheduledMessageError::InvalidAuth,
+            "account_inactive" => DeleteScheduledMessageError::AccountInactive,
+            "invalid_arg_name" => DeleteScheduledMessageError::InvalidArgName,
+            "invalid_array_arg" => DeleteScheduledMessageError::InvalidArrayArg,
+            "invalid_charset" => DeleteScheduledMessageError::InvalidCharset,
+            "invalid_form_data" => DeleteScheduledMessageError::InvalidFormData,
+            "invalid_post_type" => DeleteScheduledMessageError::InvalidPostType,
+            "missing_post_type" => DeleteScheduledMessageError::MissingPostType,
+            "team_added_to_org" => DeleteScheduledMessageError::TeamAddedToOrg,
+            "request_timeout" => DeleteScheduledMessageError::RequestTimeout,
+            _ => DeleteScheduledMessageError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for DeleteScheduledMessageError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        DeleteScheduledMessageError::InvalidScheduledMessageId => "invalid_scheduled_message_id: The scheduled_message_id passed is either invalid, already posted or already deleted.",
+DeleteScheduledMessageError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+DeleteScheduledMessageError::BadToken => "bad_token: The token passed is invalid.",
+DeleteScheduledMessageError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+DeleteScheduledMessageError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+DeleteScheduledMessageError::NotAuthed => "not_authed: No authentication token provided.",
+DeleteScheduledMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+DeleteScheduledMessageError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+DeleteScheduledMessageError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+DeleteScheduledMessageError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+DeleteScheduledMessageError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+DeleteScheduledMessageError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+DeleteScheduledMessageError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+DeleteScheduledMessageError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+DeleteScheduledMessageError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+DeleteScheduledMessageError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        DeleteScheduledMessageError::MalformedResponse(_, ref e) => return write!(f, "chat.deleteScheduledMessage returned a malformed response: {}", e),
+                        DeleteScheduledMessageError::Unknown(ref s) => return write!(f, "chat.deleteScheduledMessage failed: {}", s),
+                        DeleteScheduledMessageError::Client(ref inner) => return write!(f, "chat.deleteScheduledMessage failed: {}", inner),
+                    };
+        write!(f, "chat.deleteScheduledMessage failed: {}", d)
+    }
+}
+
+impl<E: Error> From<DeleteScheduledMessageError<E>> for crate::SlackError<E> {
+    fn from(e: DeleteScheduledMessageError<E>) -> Self {
+        let method = "chat.deleteScheduledMessage";
+        let error = match e {
+            DeleteScheduledMessageError::InvalidScheduledMessageId => {
+                "invalid_scheduled_message_id".to_owned()
+            }
+            DeleteScheduledMessageError::ChannelNotFound => "channel_not_found".to_owned(),
+            DeleteScheduledMessageError::BadToken => "bad_token".to_owned(),
+            DeleteScheduledMessageError::MissingScope => "missing_scope".to_owned(),
+            DeleteScheduledMessageError::Ratelimited => "ratelimited".to_owned(),
+            DeleteScheduledMessageError::NotAuthed => "not_authed".to_owned(),
+            DeleteScheduledMessageError::InvalidAuth => "invalid_auth".to_owned(),
+            DeleteScheduledMessageError::AccountInactive => "account_inactive".to_owned(),
+            DeleteScheduledMessageError::InvalidArgName => "invalid_arg_name".to_owned(),
+            DeleteScheduledMessageError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            DeleteScheduledMessageError::InvalidCharset => "invalid_charset".to_owned(),
+            DeleteScheduledMessageError::InvalidFormData => "invalid_form_data".to_owned(),
+            DeleteScheduledMessageError::InvalidPostType => "invalid_post_type".to_owned(),
+            DeleteScheduledMessageError::MissingPostType => "missing_post_type".to_owned(),
+            DeleteScheduledMessageError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            DeleteScheduledMessageError::RequestTimeout => "request_timeout".to_owned(),
+            DeleteScheduledMessageError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            DeleteScheduledMessageError::Unknown(s) => s,
+            DeleteScheduledMessageError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for DeleteScheduledMessageError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            DeleteScheduledMessageError::MalformedResponse(_, ref e) => Some(e),
+            DeleteScheduledMessageError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct MeMessageRequest<'a> {
     /// Channel to send message to. Can be a public channel, private group or IM channel. Can be an encoded ID, or a name.
@@ -155,7 +369,7 @@ pub struct MeMessageRequest<'a> {
     pub text: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MeMessageResponse {
     pub channel: Option<String>,
     error: Option<String>,
@@ -164,12 +378,12 @@ pub struct MeMessageResponse {
     pub ts: Option<crate::Timestamp>,
 }
 
-impl<E: Error> Into<Result<MeMessageResponse, MeMessageError<E>>> for MeMessageResponse {
-    fn into(self) -> Result<MeMessageResponse, MeMessageError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<MeMessageResponse> for Result<MeMessageResponse, MeMessageError<E>> {
+    fn from(response: MeMessageResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -262,11 +476,46 @@ MeMessageError::InvalidPostType => "invalid_post_type: The method was called via
 MeMessageError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 MeMessageError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 MeMessageError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        MeMessageError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        MeMessageError::Unknown(ref s) => return write!(f, "{}", s),
-                        MeMessageError::Client(ref inner) => return write!(f, "{}", inner),
+                        MeMessageError::MalformedResponse(_, ref e) => return write!(f, "chat.meMessage returned a malformed response: {}", e),
+                        MeMessageError::Unknown(ref s) => return write!(f, "chat.meMessage failed: {}", s),
+                        MeMessageError::Client(ref inner) => return write!(f, "chat.meMessage failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "chat.meMessage failed: {}", d)
+    }
+}
+
+impl<E: Error> From<MeMessageError<E>> for crate::SlackError<E> {
+    fn from(e: MeMessageError<E>) -> Self {
+        let method = "chat.meMessage";
+        let error = match e {
+            MeMessageError::ChannelNotFound => "channel_not_found".to_owned(),
+            MeMessageError::NotInChannel => "not_in_channel".to_owned(),
+            MeMessageError::IsArchived => "is_archived".to_owned(),
+            MeMessageError::MsgTooLong => "msg_too_long".to_owned(),
+            MeMessageError::NoText => "no_text".to_owned(),
+            MeMessageError::RateLimited => "rate_limited".to_owned(),
+            MeMessageError::NotAuthed => "not_authed".to_owned(),
+            MeMessageError::InvalidAuth => "invalid_auth".to_owned(),
+            MeMessageError::AccountInactive => "account_inactive".to_owned(),
+            MeMessageError::InvalidArgName => "invalid_arg_name".to_owned(),
+            MeMessageError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            MeMessageError::InvalidCharset => "invalid_charset".to_owned(),
+            MeMessageError::InvalidFormData => "invalid_form_data".to_owned(),
+            MeMessageError::InvalidPostType => "invalid_post_type".to_owned(),
+            MeMessageError::MissingPostType => "missing_post_type".to_owned(),
+            MeMessageError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            MeMessageError::RequestTimeout => "request_timeout".to_owned(),
+            MeMessageError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            MeMessageError::Unknown(s) => s,
+            MeMessageError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -280,6 +529,217 @@ impl<E: Error + 'static> Error for MeMessageError<E> {
     }
 }
 
+#[derive(Clone, Default, Debug)]
+pub struct PostEphemeralRequest<'a> {
+    /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name.
+    pub channel: &'a str,
+    /// Text of the message to send. When blocks are set, text is only used as the notification fallback.
+    pub text: &'a str,
+    /// id of the user who will receive the ephemeral message. The user should be in the channel specified by the channel argument.
+    pub user: &'a str,
+    /// Pass true to post the message as the authed user. Defaults to true if the chat:write:bot scope is not included. Otherwise, defaults to false.
+    pub as_user: Option<bool>,
+    /// Structured message attachments.
+    pub attachments: Option<&'a [crate::blocks::Attachment]>,
+    /// Structured message layout blocks.
+    pub blocks: Option<&'a [crate::blocks::Block]>,
+    /// Emoji to use as the icon for this message. Overrides icon_url. Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub icon_emoji: Option<&'a str>,
+    /// URL to an image to use as the icon for this message. Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub icon_url: Option<&'a str>,
+    /// Find and link channel names and usernames.
+    pub link_names: Option<bool>,
+    /// Change how messages are treated. Defaults to none.
+    pub parse: Option<&'a str>,
+    /// Provide another message's ts value to post this message in a thread. Avoid using a reply's ts value; use its parent's value instead. Ephemeral messages in threads are only shown if there is already an active thread.
+    pub thread_ts: Option<crate::Timestamp>,
+    /// Set your bot's user name. Must be used in conjunction with as_user set to false, otherwise ignored.
+    pub username: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PostEphemeralResponse {
+    error: Option<String>,
+    pub message_ts: Option<crate::Timestamp>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<PostEphemeralResponse>
+    for Result<PostEphemeralResponse, PostEphemeralError<E>>
+{
+    fn from(response: PostEphemeralResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PostEphemeralError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has been archived.
+    IsArchived,
+    /// Message text is too long
+    MsgTooLong,
+    /// No message text provided
+    NoText,
+    /// A workspace preference prevents the authenticated user from posting.
+    RestrictedAction,
+    /// Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.
+    TooManyAttachments,
+    /// Intended recipient is not in the specified channel.
+    UserNotInChannel,
+    /// The blocks are invalid.
+    InvalidBlocks,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PostEphemeralError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => PostEphemeralError::ChannelNotFound,
+            "is_archived" => PostEphemeralError::IsArchived,
+            "msg_too_long" => PostEphemeralError::MsgTooLong,
+            "no_text" => PostEphemeralError::NoText,
+            "restricted_action" => PostEphemeralError::RestrictedAction,
+            "too_many_attachments" => PostEphemeralError::TooManyAttachments,
+            "user_not_in_channel" => PostEphemeralError::UserNotInChannel,
+            "invalid_blocks" => PostEphemeralError::InvalidBlocks,
+            "missing_scope" => PostEphemeralError::MissingScope,
+            "ratelimited" => PostEphemeralError::Ratelimited,
+            "not_authed" => PostEphemeralError::NotAuthed,
+            "invalid_auth" => PostEphemeralError::InvalidAuth,
+            "account_inactive" => PostEphemeralError::AccountInactive,
+            "invalid_arg_name" => PostEphemeralError::InvalidArgName,
+            "invalid_array_arg" => PostEphemeralError::InvalidArrayArg,
+            "invalid_charset" => PostEphemeralError::InvalidCharset,
+            "invalid_form_data" => PostEphemeralError::InvalidFormData,
+            "invalid_post_type" => PostEphemeralError::InvalidPostType,
+            "missing_post_type" => PostEphemeralError::MissingPostType,
+            "team_added_to_org" => PostEphemeralError::TeamAddedToOrg,
+            "request_timeout" => PostEphemeralError::RequestTimeout,
+            _ => PostEphemeralError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for PostEphemeralError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        PostEphemeralError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+PostEphemeralError::IsArchived => "is_archived: Channel has been archived.",
+PostEphemeralError::MsgTooLong => "msg_too_long: Message text is too long",
+PostEphemeralError::NoText => "no_text: No message text provided",
+PostEphemeralError::RestrictedAction => "restricted_action: A workspace preference prevents the authenticated user from posting.",
+PostEphemeralError::TooManyAttachments => "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.",
+PostEphemeralError::UserNotInChannel => "user_not_in_channel: Intended recipient is not in the specified channel.",
+PostEphemeralError::InvalidBlocks => "invalid_blocks: The blocks are invalid.",
+PostEphemeralError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+PostEphemeralError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+PostEphemeralError::NotAuthed => "not_authed: No authentication token provided.",
+PostEphemeralError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+PostEphemeralError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+PostEphemeralError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+PostEphemeralError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+PostEphemeralError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+PostEphemeralError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+PostEphemeralError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+PostEphemeralError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+PostEphemeralError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+PostEphemeralError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        PostEphemeralError::MalformedResponse(_, ref e) => return write!(f, "chat.postEphemeral returned a malformed response: {}", e),
+                        PostEphemeralError::Unknown(ref s) => return write!(f, "chat.postEphemeral failed: {}", s),
+                        PostEphemeralError::Client(ref inner) => return write!(f, "chat.postEphemeral failed: {}", inner),
+                    };
+        write!(f, "chat.postEphemeral failed: {}", d)
+    }
+}
+
+impl<E: Error> From<PostEphemeralError<E>> for crate::SlackError<E> {
+    fn from(e: PostEphemeralError<E>) -> Self {
+        let method = "chat.postEphemeral";
+        let error = match e {
+            PostEphemeralError::ChannelNotFound => "channel_not_found".to_owned(),
+            PostEphemeralError::IsArchived => "is_archived".to_owned(),
+            PostEphemeralError::MsgTooLong => "msg_too_long".to_owned(),
+            PostEphemeralError::NoText => "no_text".to_owned(),
+            PostEphemeralError::RestrictedAction => "restricted_action".to_owned(),
+            PostEphemeralError::TooManyAttachments => "too_many_attachments".to_owned(),
+            PostEphemeralError::UserNotInChannel => "user_not_in_channel".to_owned(),
+            PostEphemeralError::InvalidBlocks => "invalid_blocks".to_owned(),
+            PostEphemeralError::MissingScope => "missing_scope".to_owned(),
+            PostEphemeralError::Ratelimited => "ratelimited".to_owned(),
+            PostEphemeralError::NotAuthed => "not_authed".to_owned(),
+            PostEphemeralError::InvalidAuth => "invalid_auth".to_owned(),
+            PostEphemeralError::AccountInactive => "account_inactive".to_owned(),
+            PostEphemeralError::InvalidArgName => "invalid_arg_name".to_owned(),
+            PostEphemeralError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            PostEphemeralError::InvalidCharset => "invalid_charset".to_owned(),
+            PostEphemeralError::InvalidFormData => "invalid_form_data".to_owned(),
+            PostEphemeralError::InvalidPostType => "invalid_post_type".to_owned(),
+            PostEphemeralError::MissingPostType => "missing_post_type".to_owned(),
+            PostEphemeralError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            PostEphemeralError::RequestTimeout => "request_timeout".to_owned(),
+            PostEphemeralError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            PostEphemeralError::Unknown(s) => s,
+            PostEphemeralError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for PostEphemeralError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            PostEphemeralError::MalformedResponse(_, ref e) => Some(e),
+            PostEphemeralError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct PostMessageRequest<'a> {
     /// Channel, private group, or IM channel to send message to. Can be an encoded ID, or a name. See below for more details.
@@ -291,7 +751,9 @@ pub struct PostMessageRequest<'a> {
     /// Find and link channel names and usernames.
     pub link_names: Option<bool>,
     /// Structured message attachments.
-    pub attachments: Option<&'a str>,
+    pub attachments: Option<&'a [crate::blocks::Attachment]>,
+    /// Structured message layout blocks. When set, text is only used as the notification fallback.
+    pub blocks: Option<&'a [crate::blocks::Block]>,
     /// Pass true to enable unfurling of primarily text-based content.
     pub unfurl_links: Option<bool>,
     /// Pass false to disable unfurling of media content.
@@ -308,9 +770,15 @@ pub struct PostMessageRequest<'a> {
     pub thread_ts: Option<crate::Timestamp>,
     /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
     pub reply_broadcast: Option<bool>,
+    /// Pass false to disable Slack markup parsing of text. Defaults to true.
+    pub mrkdwn: Option<bool>,
+    /// Metadata to attach to the message, for other apps to read.
+    pub metadata: Option<&'a crate::metadata::MessageMetadata>,
+    /// An ID of the caller's choosing, such as one from `new_client_msg_id`, which Slack echoes in the message and its events so redelivered ones can be recognized.
+    pub client_msg_id: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct PostMessageResponse {
     pub channel: Option<String>,
     error: Option<String>,
@@ -320,12 +788,12 @@ pub struct PostMessageResponse {
     pub ts: Option<crate::Timestamp>,
 }
 
-impl<E: Error> Into<Result<PostMessageResponse, PostMessageError<E>>> for PostMessageResponse {
-    fn into(self) -> Result<PostMessageResponse, PostMessageError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<PostMessageResponse> for Result<PostMessageResponse, PostMessageError<E>> {
+    fn from(response: PostMessageResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -422,11 +890,47 @@ PostMessageError::InvalidPostType => "invalid_post_type: The method was called v
 PostMessageError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 PostMessageError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 PostMessageError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        PostMessageError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        PostMessageError::Unknown(ref s) => return write!(f, "{}", s),
-                        PostMessageError::Client(ref inner) => return write!(f, "{}", inner),
+                        PostMessageError::MalformedResponse(_, ref e) => return write!(f, "chat.postMessage returned a malformed response: {}", e),
+                        PostMessageError::Unknown(ref s) => return write!(f, "chat.postMessage failed: {}", s),
+                        PostMessageError::Client(ref inner) => return write!(f, "chat.postMessage failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "chat.postMessage failed: {}", d)
+    }
+}
+
+impl<E: Error> From<PostMessageError<E>> for crate::SlackError<E> {
+    fn from(e: PostMessageError<E>) -> Self {
+        let method = "chat.postMessage";
+        let error = match e {
+            PostMessageError::ChannelNotFound => "channel_not_found".to_owned(),
+            PostMessageError::NotInChannel => "not_in_channel".to_owned(),
+            PostMessageError::IsArchived => "is_archived".to_owned(),
+            PostMessageError::MsgTooLong => "msg_too_long".to_owned(),
+            PostMessageError::NoText => "no_text".to_owned(),
+            PostMessageError::TooManyAttachments => "too_many_attachments".to_owned(),
+            PostMessageError::RateLimited => "rate_limited".to_owned(),
+            PostMessageError::NotAuthed => "not_authed".to_owned(),
+            PostMessageError::InvalidAuth => "invalid_auth".to_owned(),
+            PostMessageError::AccountInactive => "account_inactive".to_owned(),
+            PostMessageError::InvalidArgName => "invalid_arg_name".to_owned(),
+            PostMessageError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            PostMessageError::InvalidCharset => "invalid_charset".to_owned(),
+            PostMessageError::InvalidFormData => "invalid_form_data".to_owned(),
+            PostMessageError::InvalidPostType => "invalid_post_type".to_owned(),
+            PostMessageError::MissingPostType => "missing_post_type".to_owned(),
+            PostMessageError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            PostMessageError::RequestTimeout => "request_timeout".to_owned(),
+            PostMessageError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            PostMessageError::Unknown(s) => s,
+            PostMessageError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -440,6 +944,237 @@ impl<E: Error + 'static> Error for PostMessageError<E> {
     }
 }
 
+#[derive(Clone, Default, Debug)]
+pub struct ScheduleMessageRequest<'a> {
+    /// Channel, private group, or DM channel to send message to. Can be an encoded ID, or a name.
+    pub channel: &'a str,
+    /// Unix EPOCH timestamp of time in future to send the message.
+    pub post_at: u64,
+    /// Text of the message to send. When blocks are set, text is only used as the notification fallback.
+    pub text: Option<&'a str>,
+    /// Pass true to post the message as the authed user, instead of as a bot.
+    pub as_user: Option<bool>,
+    /// Structured message attachments.
+    pub attachments: Option<&'a [crate::blocks::Attachment]>,
+    /// Structured message layout blocks.
+    pub blocks: Option<&'a [crate::blocks::Block]>,
+    /// Find and link channel names and usernames.
+    pub link_names: Option<bool>,
+    /// Metadata to attach to the message, for other apps to read.
+    pub metadata: Option<&'a crate::metadata::MessageMetadata>,
+    /// Change how messages are treated. Defaults to none.
+    pub parse: Option<&'a str>,
+    /// Used in conjunction with thread_ts and indicates whether reply should be made visible to everyone in the channel or conversation. Defaults to false.
+    pub reply_broadcast: Option<bool>,
+    /// Provide another message's ts value to make this message a reply. Avoid using a reply's ts value; use its parent instead.
+    pub thread_ts: Option<crate::Timestamp>,
+    /// Pass true to enable unfurling of primarily text-based content.
+    pub unfurl_links: Option<bool>,
+    /// Pass false to disable unfurling of media content.
+    pub unfurl_media: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ScheduleMessageResponse {
+    pub channel: Option<String>,
+    error: Option<String>,
+    pub message: Option<crate::Message>,
+    #[serde(default)]
+    ok: bool,
+    pub post_at: Option<i32>,
+    pub scheduled_message_id: Option<String>,
+}
+
+impl<E: Error> From<ScheduleMessageResponse>
+    for Result<ScheduleMessageResponse, ScheduleMessageError<E>>
+{
+    fn from(response: ScheduleMessageResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ScheduleMessageError<E: Error> {
+    /// post_at was invalid, such as not a number.
+    InvalidTime,
+    /// post_at is in the past.
+    TimeInPast,
+    /// post_at is more than 120 days in the future.
+    TimeTooFar,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Cannot post user messages to a channel they are not in.
+    NotInChannel,
+    /// Channel has been archived.
+    IsArchived,
+    /// Message text is too long
+    MsgTooLong,
+    /// No message text provided
+    NoText,
+    /// A workspace preference prevents the authenticated user from posting.
+    RestrictedAction,
+    /// Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.
+    TooManyAttachments,
+    /// The blocks are invalid.
+    InvalidBlocks,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ScheduleMessageError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_time" => ScheduleMessageError::InvalidTime,
+            "time_in_past" => ScheduleMessageError::TimeInPast,
+            "time_too_far" => ScheduleMessageError::TimeTooFar,
+            "channel_not_found" => ScheduleMessageError::ChannelNotFound,
+            "not_in_channel" => ScheduleMessageError::NotInChannel,
+            "is_archived" => ScheduleMessageError::IsArchived,
+            "msg_too_long" => ScheduleMessageError::MsgTooLong,
+            "no_text" => ScheduleMessageError::NoText,
+            "restricted_action" => ScheduleMessageError::RestrictedAction,
+            "too_many_attachments" => ScheduleMessageError::TooManyAttachments,
+            "invalid_blocks" => ScheduleMessageError::InvalidBlocks,
+            "missing_scope" => ScheduleMessageError::MissingScope,
+            "ratelimited" => ScheduleMessageError::Ratelimited,
+            "not_authed" => ScheduleMessageError::NotAuthed,
+            "invalid_auth" => ScheduleMessageError::InvalidAuth,
+            "account_inactive" => ScheduleMessageError::AccountInactive,
+            "invalid_arg_name" => ScheduleMessageError::InvalidArgName,
+            "invalid_array_arg" => ScheduleMessageError::InvalidArrayArg,
+            "invalid_charset" => ScheduleMessageError::InvalidCharset,
+            "invalid_form_data" => ScheduleMessageError::InvalidFormData,
+            "invalid_post_type" => ScheduleMessageError::InvalidPostType,
+            "missing_post_type" => ScheduleMessageError::MissingPostType,
+            "team_added_to_org" => ScheduleMessageError::TeamAddedToOrg,
+            "request_timeout" => ScheduleMessageError::RequestTimeout,
+            _ => ScheduleMessageError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ScheduleMessageError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ScheduleMessageError::InvalidTime => "invalid_time: post_at was invalid, such as not a number.",
+ScheduleMessageError::TimeInPast => "time_in_past: post_at is in the past.",
+ScheduleMessageError::TimeTooFar => "time_too_far: post_at is more than 120 days in the future.",
+ScheduleMessageError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+ScheduleMessageError::NotInChannel => "not_in_channel: Cannot post user messages to a channel they are not in.",
+ScheduleMessageError::IsArchived => "is_archived: Channel has been archived.",
+ScheduleMessageError::MsgTooLong => "msg_too_long: Message text is too long",
+ScheduleMessageError::NoText => "no_text: No message text provided",
+ScheduleMessageError::RestrictedAction => "restricted_action: A workspace preference prevents the authenticated user from posting.",
+ScheduleMessageError::TooManyAttachments => "too_many_attachments: Too many attachments were provided with this message. A maximum of 100 attachments are allowed on a message.",
+ScheduleMessageError::InvalidBlocks => "invalid_blocks: The blocks are invalid.",
+ScheduleMessageError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ScheduleMessageError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ScheduleMessageError::NotAuthed => "not_authed: No authentication token provided.",
+ScheduleMessageError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ScheduleMessageError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ScheduleMessageError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ScheduleMessageError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ScheduleMessageError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ScheduleMessageError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ScheduleMessageError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ScheduleMessageError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ScheduleMessageError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ScheduleMessageError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ScheduleMessageError::MalformedResponse(_, ref e) => return write!(f, "chat.scheduleMessage returned a malformed response: {}", e),
+                        ScheduleMessageError::Unknown(ref s) => return write!(f, "chat.scheduleMessage failed: {}", s),
+                        ScheduleMessageError::Client(ref inner) => return write!(f, "chat.scheduleMessage failed: {}", inner),
+                    };
+        write!(f, "chat.scheduleMessage failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ScheduleMessageError<E>> for crate::SlackError<E> {
+    fn from(e: ScheduleMessageError<E>) -> Self {
+        let method = "chat.scheduleMessage";
+        let error = match e {
+            ScheduleMessageError::InvalidTime => "invalid_time".to_owned(),
+            ScheduleMessageError::TimeInPast => "time_in_past".to_owned(),
+            ScheduleMessageError::TimeTooFar => "time_too_far".to_owned(),
+            ScheduleMessageError::ChannelNotFound => "channel_not_found".to_owned(),
+            ScheduleMessageError::NotInChannel => "not_in_channel".to_owned(),
+            ScheduleMessageError::IsArchived => "is_archived".to_owned(),
+            ScheduleMessageError::MsgTooLong => "msg_too_long".to_owned(),
+            ScheduleMessageError::NoText => "no_text".to_owned(),
+            ScheduleMessageError::RestrictedAction => "restricted_action".to_owned(),
+            ScheduleMessageError::TooManyAttachments => "too_many_attachments".to_owned(),
+            ScheduleMessageError::InvalidBlocks => "invalid_blocks".to_owned(),
+            ScheduleMessageError::MissingScope => "missing_scope".to_owned(),
+            ScheduleMessageError::Ratelimited => "ratelimited".to_owned(),
+            ScheduleMessageError::NotAuthed => "not_authed".to_owned(),
+            ScheduleMessageError::InvalidAuth => "invalid_auth".to_owned(),
+            ScheduleMessageError::AccountInactive => "account_inactive".to_owned(),
+            ScheduleMessageError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ScheduleMessageError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ScheduleMessageError::InvalidCharset => "invalid_charset".to_owned(),
+            ScheduleMessageError::InvalidFormData => "invalid_form_data".to_owned(),
+            ScheduleMessageError::InvalidPostType => "invalid_post_type".to_owned(),
+            ScheduleMessageError::MissingPostType => "missing_post_type".to_owned(),
+            ScheduleMessageError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ScheduleMessageError::RequestTimeout => "request_timeout".to_owned(),
+            ScheduleMessageError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ScheduleMessageError::Unknown(s) => s,
+            ScheduleMessageError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ScheduleMessageError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ScheduleMessageError::MalformedResponse(_, ref e) => Some(e),
+            ScheduleMessageError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Default, Debug)]
 pub struct UnfurlRequest<'a> {
     /// Channel ID of the message
@@ -452,19 +1187,19 @@ pub struct UnfurlRequest<'a> {
     pub user_auth_required: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UnfurlResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<UnfurlResponse, UnfurlError<E>>> for UnfurlResponse {
-    fn into(self) -> Result<UnfurlResponse, UnfurlError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<UnfurlResponse> for Result<UnfurlResponse, UnfurlError<E>> {
+    fn from(response: UnfurlResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -537,11 +1272,41 @@ UnfurlError::InvalidPostType => "invalid_post_type: The method was called via a
 UnfurlError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 UnfurlError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 UnfurlError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        UnfurlError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        UnfurlError::Unknown(ref s) => return write!(f, "{}", s),
-                        UnfurlError::Client(ref inner) => return write!(f, "{}", inner),
+                        UnfurlError::MalformedResponse(_, ref e) => return write!(f, "chat.unfurl returned a malformed response: {}", e),
+                        UnfurlError::Unknown(ref s) => return write!(f, "chat.unfurl failed: {}", s),
+                        UnfurlError::Client(ref inner) => return write!(f, "chat.unfurl failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "chat.unfurl failed: {}", d)
+    }
+}
+
+impl<E: Error> From<UnfurlError<E>> for crate::SlackError<E> {
+    fn from(e: UnfurlError<E>) -> Self {
+        let method = "chat.unfurl";
+        let error = match e {
+            UnfurlError::NotAuthed => "not_authed".to_owned(),
+            UnfurlError::InvalidAuth => "invalid_auth".to_owned(),
+            UnfurlError::AccountInactive => "account_inactive".to_owned(),
+            UnfurlError::UserIsBot => "user_is_bot".to_owned(),
+            UnfurlError::InvalidArgName => "invalid_arg_name".to_owned(),
+            UnfurlError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            UnfurlError::InvalidCharset => "invalid_charset".to_owned(),
+            UnfurlError::InvalidFormData => "invalid_form_data".to_owned(),
+            UnfurlError::InvalidPostType => "invalid_post_type".to_owned(),
+            UnfurlError::MissingPostType => "missing_post_type".to_owned(),
+            UnfurlError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            UnfurlError::RequestTimeout => "request_timeout".to_owned(),
+            UnfurlError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            UnfurlError::Unknown(s) => s,
+            UnfurlError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -563,32 +1328,46 @@ pub struct UpdateRequest<'a> {
     pub channel: &'a str,
     /// New text for the message, using the default formatting rules.
     pub text: &'a str,
-    /// Structured message attachments.
-    pub attachments: Option<&'a str>,
+    /// Structured message attachments. Pass an empty slice to remove the message's attachments.
+    pub attachments: Option<&'a [crate::blocks::Attachment]>,
+    /// Structured message layout blocks. Pass an empty slice to remove the message's blocks.
+    pub blocks: Option<&'a [crate::blocks::Block]>,
     /// Change how messages are treated. Defaults to client, unlike chat.postMessage. See below.
     pub parse: Option<&'a str>,
     /// Find and link channel names and usernames. Defaults to none. This parameter should be used in conjunction with parse. To set link_names to 1, specify a parse mode of full.
     pub link_names: Option<bool>,
     /// Pass true to update the message as the authed user. Bot users in this context are considered authed users.
     pub as_user: Option<bool>,
+    /// A comma separated list of file IDs to share in the message in place of its text.
+    pub file_ids: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UpdateResponse {
     pub channel: Option<String>,
     error: Option<String>,
+    /// The message as Slack rendered it after the update.
+    pub message: Option<crate::Message>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
+    pub warning: Option<String>,
+}
+
+impl crate::HasPagination for UpdateResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
 }
 
-impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
-    fn into(self) -> Result<UpdateResponse, UpdateError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<UpdateResponse> for Result<UpdateResponse, UpdateError<E>> {
+    fn from(response: UpdateResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -685,11 +1464,47 @@ UpdateError::InvalidPostType => "invalid_post_type: The method was called via a
 UpdateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 UpdateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 UpdateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        UpdateError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        UpdateError::Unknown(ref s) => return write!(f, "{}", s),
-                        UpdateError::Client(ref inner) => return write!(f, "{}", inner),
+                        UpdateError::MalformedResponse(_, ref e) => return write!(f, "chat.update returned a malformed response: {}", e),
+                        UpdateError::Unknown(ref s) => return write!(f, "chat.update failed: {}", s),
+                        UpdateError::Client(ref inner) => return write!(f, "chat.update failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "chat.update failed: {}", d)
+    }
+}
+
+impl<E: Error> From<UpdateError<E>> for crate::SlackError<E> {
+    fn from(e: UpdateError<E>) -> Self {
+        let method = "chat.update";
+        let error = match e {
+            UpdateError::MessageNotFound => "message_not_found".to_owned(),
+            UpdateError::CantUpdateMessage => "cant_update_message".to_owned(),
+            UpdateError::ChannelNotFound => "channel_not_found".to_owned(),
+            UpdateError::EditWindowClosed => "edit_window_closed".to_owned(),
+            UpdateError::MsgTooLong => "msg_too_long".to_owned(),
+            UpdateError::TooManyAttachments => "too_many_attachments".to_owned(),
+            UpdateError::NoText => "no_text".to_owned(),
+            UpdateError::NotAuthed => "not_authed".to_owned(),
+            UpdateError::InvalidAuth => "invalid_auth".to_owned(),
+            UpdateError::AccountInactive => "account_inactive".to_owned(),
+            UpdateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            UpdateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            UpdateError::InvalidCharset => "invalid_charset".to_owned(),
+            UpdateError::InvalidFormData => "invalid_form_data".to_owned(),
+            UpdateError::InvalidPostType => "invalid_post_type".to_owned(),
+            UpdateError::MissingPostType => "missing_post_type".to_owned(),
+            UpdateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            UpdateError::RequestTimeout => "request_timeout".to_owned(),
+            UpdateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            UpdateError::Unknown(s) => s,
+            UpdateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
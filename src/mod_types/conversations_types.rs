@@ -0,0 +1,2131 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage conversations of all types: public and private channels, direct messages and multi-person direct messages.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct CreateRequest<'a> {
+    /// Name of the public or private channel to create.
+    pub name: &'a str,
+    /// Create a private channel instead of a public one.
+    pub is_private: Option<bool>,
+    /// Encoded team id to create the channel in, required if org token is used.
+    pub team_id: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CreateResponse {
+    pub channel: Option<crate::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<CreateResponse> for Result<CreateResponse, CreateError<E>> {
+    fn from(response: CreateResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CreateError<E: Error> {
+    /// A channel cannot be created with the given name.
+    NameTaken,
+    /// Value passed for name was invalid.
+    InvalidName,
+    /// Value passed for name exceeded max length.
+    InvalidNameMaxlength,
+    /// Value passed for name contained only punctuation.
+    InvalidNamePunctuation,
+    /// Value passed for name was empty.
+    InvalidNameRequired,
+    /// Value passed for name contained unallowed special characters or upper case characters.
+    InvalidNameSpecials,
+    /// Value passed for name was empty.
+    NoChannel,
+    /// A team preference prevents the authenticated user from creating channels.
+    RestrictedAction,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CreateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "name_taken" => CreateError::NameTaken,
+            "invalid_name" => CreateError::InvalidName,
+            "invalid_name_maxlength" => CreateError::InvalidNameMaxlength,
+            "invalid_name_punctuation" => CreateError::InvalidNamePunctuation,
+            "invalid_name_required" => CreateError::InvalidNameRequired,
+            "invalid_name_specials" => CreateError::InvalidNameSpecials,
+            "no_channel" => CreateError::NoChannel,
+            "restricted_action" => CreateError::RestrictedAction,
+            "missing_scope" => CreateError::MissingScope,
+            "not_authed" => CreateError::NotAuthed,
+            "invalid_auth" => CreateError::InvalidAuth,
+            "account_inactive" => CreateError::AccountInactive,
+            "invalid_arg_name" => CreateError::InvalidArgName,
+            "invalid_array_arg" => CreateError::InvalidArrayArg,
+            "invalid_charset" => CreateError::InvalidCharset,
+            "invalid_form_data" => CreateError::InvalidFormData,
+            "invalid_post_type" => CreateError::InvalidPostType,
+            "missing_post_type" => CreateError::MissingPostType,
+            "team_added_to_org" => CreateError::TeamAddedToOrg,
+            "request_timeout" => CreateError::RequestTimeout,
+            _ => CreateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for CreateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        CreateError::NameTaken => "name_taken: A channel cannot be created with the given name.",
+CreateError::InvalidName => "invalid_name: Value passed for name was invalid.",
+CreateError::InvalidNameMaxlength => "invalid_name_maxlength: Value passed for name exceeded max length.",
+CreateError::InvalidNamePunctuation => "invalid_name_punctuation: Value passed for name contained only punctuation.",
+CreateError::InvalidNameRequired => "invalid_name_required: Value passed for name was empty.",
+CreateError::InvalidNameSpecials => "invalid_name_specials: Value passed for name contained unallowed special characters or upper case characters.",
+CreateError::NoChannel => "no_channel: Value passed for name was empty.",
+CreateError::RestrictedAction => "restricted_action: A team preference prevents the authenticated user from creating channels.",
+CreateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+CreateError::NotAuthed => "not_authed: No authentication token provided.",
+CreateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+CreateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+CreateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+CreateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+CreateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+CreateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+CreateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+CreateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+CreateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+CreateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        CreateError::MalformedResponse(_, ref e) => return write!(f, "conversations.create returned a malformed response: {}", e),
+                        CreateError::Unknown(ref s) => return write!(f, "conversations.create failed: {}", s),
+                        CreateError::Client(ref inner) => return write!(f, "conversations.create failed: {}", inner),
+                    };
+        write!(f, "conversations.create failed: {}", d)
+    }
+}
+
+impl<E: Error> From<CreateError<E>> for crate::SlackError<E> {
+    fn from(e: CreateError<E>) -> Self {
+        let method = "conversations.create";
+        let error = match e {
+            CreateError::NameTaken => "name_taken".to_owned(),
+            CreateError::InvalidName => "invalid_name".to_owned(),
+            CreateError::InvalidNameMaxlength => "invalid_name_maxlength".to_owned(),
+            CreateError::InvalidNamePunctuation => "invalid_name_punctuation".to_owned(),
+            CreateError::InvalidNameRequired => "invalid_name_required".to_owned(),
+            CreateError::InvalidNameSpecials => "invalid_name_specials".to_owned(),
+            CreateError::NoChannel => "no_channel".to_owned(),
+            CreateError::RestrictedAction => "restricted_action".to_owned(),
+            CreateError::MissingScope => "missing_scope".to_owned(),
+            CreateError::NotAuthed => "not_authed".to_owned(),
+            CreateError::InvalidAuth => "invalid_auth".to_owned(),
+            CreateError::AccountInactive => "account_inactive".to_owned(),
+            CreateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            CreateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            CreateError::InvalidCharset => "invalid_charset".to_owned(),
+            CreateError::InvalidFormData => "invalid_form_data".to_owned(),
+            CreateError::InvalidPostType => "invalid_post_type".to_owned(),
+            CreateError::MissingPostType => "missing_post_type".to_owned(),
+            CreateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            CreateError::RequestTimeout => "request_timeout".to_owned(),
+            CreateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            CreateError::Unknown(s) => s,
+            CreateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for CreateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CreateError::MalformedResponse(_, ref e) => Some(e),
+            CreateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct HistoryRequest<'a> {
+    /// Conversation ID to fetch history for.
+    pub channel: &'a str,
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub inclusive: Option<bool>,
+    /// End of time range of messages to include in results. Default is the current time.
+    pub latest: Option<crate::Timestamp>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the history hasn't been reached.
+    pub limit: Option<u32>,
+    /// Start of time range of messages to include in results.
+    pub oldest: Option<crate::Timestamp>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct HistoryResponse {
+    error: Option<String>,
+    pub has_more: Option<bool>,
+    pub messages: Option<Vec<crate::Message>>,
+    #[serde(default)]
+    ok: bool,
+    pub pin_count: Option<i32>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for HistoryResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<HistoryResponse> for Result<HistoryResponse, HistoryError<E>> {
+    fn from(response: HistoryResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum HistoryError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// Value passed for latest was invalid
+    InvalidTsLatest,
+    /// Value passed for oldest was invalid
+    InvalidTsOldest,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The bot or user is not a member of the channel.
+    NotInChannel,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for HistoryError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => HistoryError::ChannelNotFound,
+            "invalid_cursor" => HistoryError::InvalidCursor,
+            "invalid_ts_latest" => HistoryError::InvalidTsLatest,
+            "invalid_ts_oldest" => HistoryError::InvalidTsOldest,
+            "missing_scope" => HistoryError::MissingScope,
+            "not_in_channel" => HistoryError::NotInChannel,
+            "not_authed" => HistoryError::NotAuthed,
+            "invalid_auth" => HistoryError::InvalidAuth,
+            "account_inactive" => HistoryError::AccountInactive,
+            "invalid_arg_name" => HistoryError::InvalidArgName,
+            "invalid_array_arg" => HistoryError::InvalidArrayArg,
+            "invalid_charset" => HistoryError::InvalidCharset,
+            "invalid_form_data" => HistoryError::InvalidFormData,
+            "invalid_post_type" => HistoryError::InvalidPostType,
+            "missing_post_type" => HistoryError::MissingPostType,
+            "team_added_to_org" => HistoryError::TeamAddedToOrg,
+            "request_timeout" => HistoryError::RequestTimeout,
+            _ => HistoryError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for HistoryError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        HistoryError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+HistoryError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+HistoryError::InvalidTsLatest => "invalid_ts_latest: Value passed for latest was invalid",
+HistoryError::InvalidTsOldest => "invalid_ts_oldest: Value passed for oldest was invalid",
+HistoryError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+HistoryError::NotInChannel => "not_in_channel: The bot or user is not a member of the channel.",
+HistoryError::NotAuthed => "not_authed: No authentication token provided.",
+HistoryError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+HistoryError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+HistoryError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+HistoryError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+HistoryError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+HistoryError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+HistoryError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+HistoryError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+HistoryError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+HistoryError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        HistoryError::MalformedResponse(_, ref e) => return write!(f, "conversations.history returned a malformed response: {}", e),
+                        HistoryError::Unknown(ref s) => return write!(f, "conversations.history failed: {}", s),
+                        HistoryError::Client(ref inner) => return write!(f, "conversations.history failed: {}", inner),
+                    };
+        write!(f, "conversations.history failed: {}", d)
+    }
+}
+
+impl<E: Error> From<HistoryError<E>> for crate::SlackError<E> {
+    fn from(e: HistoryError<E>) -> Self {
+        let method = "conversations.history";
+        let error = match e {
+            HistoryError::ChannelNotFound => "channel_not_found".to_owned(),
+            HistoryError::InvalidCursor => "invalid_cursor".to_owned(),
+            HistoryError::InvalidTsLatest => "invalid_ts_latest".to_owned(),
+            HistoryError::InvalidTsOldest => "invalid_ts_oldest".to_owned(),
+            HistoryError::MissingScope => "missing_scope".to_owned(),
+            HistoryError::NotInChannel => "not_in_channel".to_owned(),
+            HistoryError::NotAuthed => "not_authed".to_owned(),
+            HistoryError::InvalidAuth => "invalid_auth".to_owned(),
+            HistoryError::AccountInactive => "account_inactive".to_owned(),
+            HistoryError::InvalidArgName => "invalid_arg_name".to_owned(),
+            HistoryError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            HistoryError::InvalidCharset => "invalid_charset".to_owned(),
+            HistoryError::InvalidFormData => "invalid_form_data".to_owned(),
+            HistoryError::InvalidPostType => "invalid_post_type".to_owned(),
+            HistoryError::MissingPostType => "missing_post_type".to_owned(),
+            HistoryError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            HistoryError::RequestTimeout => "request_timeout".to_owned(),
+            HistoryError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            HistoryError::Unknown(s) => s,
+            HistoryError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for HistoryError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            HistoryError::MalformedResponse(_, ref e) => Some(e),
+            HistoryError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct RepliesRequest<'a> {
+    /// Conversation ID to fetch thread from.
+    pub channel: &'a str,
+    /// Unique identifier of either a thread's parent message or a message in the thread.
+    pub ts: crate::Timestamp,
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// Include messages with latest or oldest timestamp in results only when either timestamp is specified.
+    pub inclusive: Option<bool>,
+    /// End of time range of messages to include in results. Default is the current time.
+    pub latest: Option<crate::Timestamp>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the history hasn't been reached.
+    pub limit: Option<u32>,
+    /// Start of time range of messages to include in results.
+    pub oldest: Option<crate::Timestamp>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct RepliesResponse {
+    error: Option<String>,
+    pub has_more: Option<bool>,
+    pub messages: Option<Vec<crate::Message>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for RepliesResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<RepliesResponse> for Result<RepliesResponse, RepliesError<E>> {
+    fn from(response: RepliesResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum RepliesError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// Value passed for latest was invalid
+    InvalidTsLatest,
+    /// Value passed for oldest was invalid
+    InvalidTsOldest,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The bot or user is not a member of the channel.
+    NotInChannel,
+    /// Value for ts was missing or invalid.
+    ThreadNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for RepliesError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => RepliesError::ChannelNotFound,
+            "invalid_cursor" => RepliesError::InvalidCursor,
+            "invalid_ts_latest" => RepliesError::InvalidTsLatest,
+            "invalid_ts_oldest" => RepliesError::InvalidTsOldest,
+            "missing_scope" => RepliesError::MissingScope,
+            "not_in_channel" => RepliesError::NotInChannel,
+            "thread_not_found" => RepliesError::ThreadNotFound,
+            "not_authed" => RepliesError::NotAuthed,
+            "invalid_auth" => RepliesError::InvalidAuth,
+            "account_inactive" => RepliesError::AccountInactive,
+            "invalid_arg_name" => RepliesError::InvalidArgName,
+            "invalid_array_arg" => RepliesError::InvalidArrayArg,
+            "invalid_charset" => RepliesError::InvalidCharset,
+            "invalid_form_data" => RepliesError::InvalidFormData,
+            "invalid_post_type" => RepliesError::InvalidPostType,
+            "missing_post_type" => RepliesError::MissingPostType,
+            "team_added_to_org" => RepliesError::TeamAddedToOrg,
+            "request_timeout" => RepliesError::RequestTimeout,
+            _ => RepliesError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for RepliesError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        RepliesError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+RepliesError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+RepliesError::InvalidTsLatest => "invalid_ts_latest: Value passed for latest was invalid",
+RepliesError::InvalidTsOldest => "invalid_ts_oldest: Value passed for oldest was invalid",
+RepliesError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+RepliesError::NotInChannel => "not_in_channel: The bot or user is not a member of the channel.",
+RepliesError::ThreadNotFound => "thread_not_found: Value for ts was missing or invalid.",
+RepliesError::NotAuthed => "not_authed: No authentication token provided.",
+RepliesError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+RepliesError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+RepliesError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+RepliesError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+RepliesError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+RepliesError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+RepliesError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+RepliesError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+RepliesError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+RepliesError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        RepliesError::MalformedResponse(_, ref e) => return write!(f, "conversations.replies returned a malformed response: {}", e),
+                        RepliesError::Unknown(ref s) => return write!(f, "conversations.replies failed: {}", s),
+                        RepliesError::Client(ref inner) => return write!(f, "conversations.replies failed: {}", inner),
+                    };
+        write!(f, "conversations.replies failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RepliesError<E>> for crate::SlackError<E> {
+    fn from(e: RepliesError<E>) -> Self {
+        let method = "conversations.replies";
+        let error = match e {
+            RepliesError::ChannelNotFound => "channel_not_found".to_owned(),
+            RepliesError::InvalidCursor => "invalid_cursor".to_owned(),
+            RepliesError::InvalidTsLatest => "invalid_ts_latest".to_owned(),
+            RepliesError::InvalidTsOldest => "invalid_ts_oldest".to_owned(),
+            RepliesError::MissingScope => "missing_scope".to_owned(),
+            RepliesError::NotInChannel => "not_in_channel".to_owned(),
+            RepliesError::ThreadNotFound => "thread_not_found".to_owned(),
+            RepliesError::NotAuthed => "not_authed".to_owned(),
+            RepliesError::InvalidAuth => "invalid_auth".to_owned(),
+            RepliesError::AccountInactive => "account_inactive".to_owned(),
+            RepliesError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RepliesError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RepliesError::InvalidCharset => "invalid_charset".to_owned(),
+            RepliesError::InvalidFormData => "invalid_form_data".to_owned(),
+            RepliesError::InvalidPostType => "invalid_post_type".to_owned(),
+            RepliesError::MissingPostType => "missing_post_type".to_owned(),
+            RepliesError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RepliesError::RequestTimeout => "request_timeout".to_owned(),
+            RepliesError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RepliesError::Unknown(s) => s,
+            RepliesError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for RepliesError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RepliesError::MalformedResponse(_, ref e) => Some(e),
+            RepliesError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InviteRequest<'a> {
+    /// The ID of the public or private channel to invite user(s) to.
+    pub channel: &'a str,
+    /// A comma separated list of user IDs. Up to 1000 users may be listed.
+    pub users: &'a str,
+    /// When set to true and multiple user IDs are provided, continue inviting the valid ones while disregarding invalid IDs.
+    pub force: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InviteResponse {
+    pub channel: Option<crate::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<InviteResponse> for Result<InviteResponse, InviteError<E>> {
+    fn from(response: InviteResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InviteError<E: Error> {
+    /// Invited user is already in the channel.
+    AlreadyInChannel,
+    /// User cannot be invited to this channel.
+    CantInvite,
+    /// Authenticated user cannot invite themselves to a channel.
+    CantInviteSelf,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has been archived.
+    IsArchived,
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// No value was passed for users.
+    NoUser,
+    /// The authenticated user is not in the channel.
+    NotInChannel,
+    /// Too many users were passed in users.
+    TooManyUsers,
+    /// The invited user is a single channel guest already in a channel.
+    UraMaxChannels,
+    /// The invited user cannot be invited to this channel.
+    UserIsRestricted,
+    /// Value passed for users was invalid.
+    UserNotFound,
+    /// A team preference prevents the authenticated user from inviting users to this channel.
+    RestrictedAction,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InviteError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "already_in_channel" => InviteError::AlreadyInChannel,
+            "cant_invite" => InviteError::CantInvite,
+            "cant_invite_self" => InviteError::CantInviteSelf,
+            "channel_not_found" => InviteError::ChannelNotFound,
+            "is_archived" => InviteError::IsArchived,
+            "method_not_supported_for_channel_type" => {
+                InviteError::MethodNotSupportedForChannelType
+            }
+            "no_user" => InviteError::NoUser,
+            "not_in_channel" => InviteError::NotInChannel,
+            "too_many_users" => InviteError::TooManyUsers,
+            "ura_max_channels" => InviteError::UraMaxChannels,
+            "user_is_restricted" => InviteError::UserIsRestricted,
+            "user_not_found" => InviteError::UserNotFound,
+            "restricted_action" => InviteError::RestrictedAction,
+            "missing_scope" => InviteError::MissingScope,
+            "not_authed" => InviteError::NotAuthed,
+            "invalid_auth" => InviteError::InvalidAuth,
+            "account_inactive" => InviteError::AccountInactive,
+            "invalid_arg_name" => InviteError::InvalidArgName,
+            "invalid_array_arg" => InviteError::InvalidArrayArg,
+            "invalid_charset" => InviteError::InvalidCharset,
+            "invalid_form_data" => InviteError::InvalidFormData,
+            "invalid_post_type" => InviteError::InvalidPostType,
+            "missing_post_type" => InviteError::MissingPostType,
+            "team_added_to_org" => InviteError::TeamAddedToOrg,
+            "request_timeout" => InviteError::RequestTimeout,
+            _ => InviteError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for InviteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        InviteError::AlreadyInChannel => "already_in_channel: Invited user is already in the channel.",
+InviteError::CantInvite => "cant_invite: User cannot be invited to this channel.",
+InviteError::CantInviteSelf => "cant_invite_self: Authenticated user cannot invite themselves to a channel.",
+InviteError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+InviteError::IsArchived => "is_archived: Channel has been archived.",
+InviteError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+InviteError::NoUser => "no_user: No value was passed for users.",
+InviteError::NotInChannel => "not_in_channel: The authenticated user is not in the channel.",
+InviteError::TooManyUsers => "too_many_users: Too many users were passed in users.",
+InviteError::UraMaxChannels => "ura_max_channels: The invited user is a single channel guest already in a channel.",
+InviteError::UserIsRestricted => "user_is_restricted: The invited user cannot be invited to this channel.",
+InviteError::UserNotFound => "user_not_found: Value passed for users was invalid.",
+InviteError::RestrictedAction => "restricted_action: A team preference prevents the authenticated user from inviting users to this channel.",
+InviteError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+InviteError::NotAuthed => "not_authed: No authentication token provided.",
+InviteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+InviteError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+InviteError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+InviteError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+InviteError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+InviteError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+InviteError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+InviteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+InviteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+InviteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        InviteError::MalformedResponse(_, ref e) => return write!(f, "conversations.invite returned a malformed response: {}", e),
+                        InviteError::Unknown(ref s) => return write!(f, "conversations.invite failed: {}", s),
+                        InviteError::Client(ref inner) => return write!(f, "conversations.invite failed: {}", inner),
+                    };
+        write!(f, "conversations.invite failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InviteError<E>> for crate::SlackError<E> {
+    fn from(e: InviteError<E>) -> Self {
+        let method = "conversations.invite";
+        let error = match e {
+            InviteError::AlreadyInChannel => "already_in_channel".to_owned(),
+            InviteError::CantInvite => "cant_invite".to_owned(),
+            InviteError::CantInviteSelf => "cant_invite_self".to_owned(),
+            InviteError::ChannelNotFound => "channel_not_found".to_owned(),
+            InviteError::IsArchived => "is_archived".to_owned(),
+            InviteError::MethodNotSupportedForChannelType => {
+                "method_not_supported_for_channel_type".to_owned()
+            }
+            InviteError::NoUser => "no_user".to_owned(),
+            InviteError::NotInChannel => "not_in_channel".to_owned(),
+            InviteError::TooManyUsers => "too_many_users".to_owned(),
+            InviteError::UraMaxChannels => "ura_max_channels".to_owned(),
+            InviteError::UserIsRestricted => "user_is_restricted".to_owned(),
+            InviteError::UserNotFound => "user_not_found".to_owned(),
+            InviteError::RestrictedAction => "restricted_action".to_owned(),
+            InviteError::MissingScope => "missing_scope".to_owned(),
+            InviteError::NotAuthed => "not_authed".to_owned(),
+            InviteError::InvalidAuth => "invalid_auth".to_owned(),
+            InviteError::AccountInactive => "account_inactive".to_owned(),
+            InviteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InviteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InviteError::InvalidCharset => "invalid_charset".to_owned(),
+            InviteError::InvalidFormData => "invalid_form_data".to_owned(),
+            InviteError::InvalidPostType => "invalid_post_type".to_owned(),
+            InviteError::MissingPostType => "missing_post_type".to_owned(),
+            InviteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InviteError::RequestTimeout => "request_timeout".to_owned(),
+            InviteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InviteError::Unknown(s) => s,
+            InviteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for InviteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            InviteError::MalformedResponse(_, ref e) => Some(e),
+            InviteError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct KickRequest<'a> {
+    /// ID of conversation to remove user from.
+    pub channel: &'a str,
+    /// User ID to be removed.
+    pub user: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct KickResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<KickResponse> for Result<KickResponse, KickError<E>> {
+    fn from(response: KickResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum KickError<E: Error> {
+    /// User cannot be removed from #general.
+    CantKickFromGeneral,
+    /// Authenticated user can't kick themselves from a channel.
+    CantKickSelf,
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// This type of conversation cannot be used with this method.
+    MethodNotSupportedForChannelType,
+    /// User was not in the channel.
+    NotInChannel,
+    /// Value passed for user was invalid.
+    UserNotFound,
+    /// A team preference prevents the authenticated user from removing users from this channel.
+    RestrictedAction,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for KickError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "cant_kick_from_general" => KickError::CantKickFromGeneral,
+            "cant_kick_self" => KickError::CantKickSelf,
+            "channel_not_found" => KickError::ChannelNotFound,
+            "method_not_supported_for_channel_type" => KickError::MethodNotSupportedForChannelType,
+            "not_in_channel" => KickError::NotInChannel,
+            "user_not_found" => KickError::UserNotFound,
+            "restricted_action" => KickError::RestrictedAction,
+            "missing_scope" => KickError::MissingScope,
+            "not_authed" => KickError::NotAuthed,
+            "invalid_auth" => KickError::InvalidAuth,
+            "account_inactive" => KickError::AccountInactive,
+            "invalid_arg_name" => KickError::InvalidArgName,
+            "invalid_array_arg" => KickError::InvalidArrayArg,
+            "invalid_charset" => KickError::InvalidCharset,
+            "invalid_form_data" => KickError::InvalidFormData,
+            "invalid_post_type" => KickError::InvalidPostType,
+            "missing_post_type" => KickError::MissingPostType,
+            "team_added_to_org" => KickError::TeamAddedToOrg,
+            "request_timeout" => KickError::RequestTimeout,
+            _ => KickError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for KickError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        KickError::CantKickFromGeneral => "cant_kick_from_general: User cannot be removed from #general.",
+KickError::CantKickSelf => "cant_kick_self: Authenticated user can't kick themselves from a channel.",
+KickError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+KickError::MethodNotSupportedForChannelType => "method_not_supported_for_channel_type: This type of conversation cannot be used with this method.",
+KickError::NotInChannel => "not_in_channel: User was not in the channel.",
+KickError::UserNotFound => "user_not_found: Value passed for user was invalid.",
+KickError::RestrictedAction => "restricted_action: A team preference prevents the authenticated user from removing users from this channel.",
+KickError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+KickError::NotAuthed => "not_authed: No authentication token provided.",
+KickError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+KickError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+KickError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+KickError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+KickError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+KickError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+KickError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+KickError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+KickError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+KickError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        KickError::MalformedResponse(_, ref e) => return write!(f, "conversations.kick returned a malformed response: {}", e),
+                        KickError::Unknown(ref s) => return write!(f, "conversations.kick failed: {}", s),
+                        KickError::Client(ref inner) => return write!(f, "conversations.kick failed: {}", inner),
+                    };
+        write!(f, "conversations.kick failed: {}", d)
+    }
+}
+
+impl<E: Error> From<KickError<E>> for crate::SlackError<E> {
+    fn from(e: KickError<E>) -> Self {
+        let method = "conversations.kick";
+        let error = match e {
+            KickError::CantKickFromGeneral => "cant_kick_from_general".to_owned(),
+            KickError::CantKickSelf => "cant_kick_self".to_owned(),
+            KickError::ChannelNotFound => "channel_not_found".to_owned(),
+            KickError::MethodNotSupportedForChannelType => {
+                "method_not_supported_for_channel_type".to_owned()
+            }
+            KickError::NotInChannel => "not_in_channel".to_owned(),
+            KickError::UserNotFound => "user_not_found".to_owned(),
+            KickError::RestrictedAction => "restricted_action".to_owned(),
+            KickError::MissingScope => "missing_scope".to_owned(),
+            KickError::NotAuthed => "not_authed".to_owned(),
+            KickError::InvalidAuth => "invalid_auth".to_owned(),
+            KickError::AccountInactive => "account_inactive".to_owned(),
+            KickError::InvalidArgName => "invalid_arg_name".to_owned(),
+            KickError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            KickError::InvalidCharset => "invalid_charset".to_owned(),
+            KickError::InvalidFormData => "invalid_form_data".to_owned(),
+            KickError::InvalidPostType => "invalid_post_type".to_owned(),
+            KickError::MissingPostType => "missing_post_type".to_owned(),
+            KickError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            KickError::RequestTimeout => "request_timeout".to_owned(),
+            KickError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            KickError::Unknown(s) => s,
+            KickError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for KickError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            KickError::MalformedResponse(_, ref e) => Some(e),
+            KickError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// Set to true to exclude archived channels from the list.
+    pub exclude_archived: Option<bool>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached. Must be an integer no larger than 1000.
+    pub limit: Option<u32>,
+    /// Encoded team id to list channels in, required if token belongs to org-wide app.
+    pub team_id: Option<&'a str>,
+    /// The types of conversations to list. Defaults to public channels.
+    pub types: Option<crate::ConversationTypes>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListResponse {
+    pub channels: Option<Vec<crate::Conversation>>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for ListResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// Value passed for limit is not valid.
+    InvalidLimit,
+    /// Value passed for type could not be used based on the method's capabilities or the permission scopes granted to the used token.
+    InvalidTypes,
+    /// A required argument is missing.
+    MissingArgument,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "invalid_cursor" => ListError::InvalidCursor,
+            "invalid_limit" => ListError::InvalidLimit,
+            "invalid_types" => ListError::InvalidTypes,
+            "missing_argument" => ListError::MissingArgument,
+            "missing_scope" => ListError::MissingScope,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ListError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+ListError::InvalidLimit => "invalid_limit: Value passed for limit is not valid.",
+ListError::InvalidTypes => "invalid_types: Value passed for type could not be used based on the method's capabilities or the permission scopes granted to the used token.",
+ListError::MissingArgument => "missing_argument: A required argument is missing.",
+ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ListError::NotAuthed => "not_authed: No authentication token provided.",
+ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "conversations.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "conversations.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "conversations.list failed: {}", inner),
+                    };
+        write!(f, "conversations.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "conversations.list";
+        let error = match e {
+            ListError::InvalidCursor => "invalid_cursor".to_owned(),
+            ListError::InvalidLimit => "invalid_limit".to_owned(),
+            ListError::InvalidTypes => "invalid_types".to_owned(),
+            ListError::MissingArgument => "missing_argument".to_owned(),
+            ListError::MissingScope => "missing_scope".to_owned(),
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ListError::MalformedResponse(_, ref e) => Some(e),
+            ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct MembersRequest<'a> {
+    /// ID of the conversation to retrieve members for
+    pub channel: &'a str,
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MembersResponse {
+    error: Option<String>,
+    pub members: Option<Vec<String>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for MembersResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<MembersResponse> for Result<MembersResponse, MembersError<E>> {
+    fn from(response: MembersResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum MembersError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Failed to fetch members for the conversation.
+    FetchMembersFailed,
+    /// Value passed for cursor was not valid or is no longer valid.
+    InvalidCursor,
+    /// Value passed for limit is not valid.
+    InvalidLimit,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for MembersError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => MembersError::ChannelNotFound,
+            "fetch_members_failed" => MembersError::FetchMembersFailed,
+            "invalid_cursor" => MembersError::InvalidCursor,
+            "invalid_limit" => MembersError::InvalidLimit,
+            "missing_scope" => MembersError::MissingScope,
+            "not_authed" => MembersError::NotAuthed,
+            "invalid_auth" => MembersError::InvalidAuth,
+            "account_inactive" => MembersError::AccountInactive,
+            "invalid_arg_name" => MembersError::InvalidArgName,
+            "invalid_array_arg" => MembersError::InvalidArrayArg,
+            "invalid_charset" => MembersError::InvalidCharset,
+            "invalid_form_data" => MembersError::InvalidFormData,
+            "invalid_post_type" => MembersError::InvalidPostType,
+            "missing_post_type" => MembersError::MissingPostType,
+            "team_added_to_org" => MembersError::TeamAddedToOrg,
+            "request_timeout" => MembersError::RequestTimeout,
+            _ => MembersError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for MembersError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        MembersError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+MembersError::FetchMembersFailed => "fetch_members_failed: Failed to fetch members for the conversation.",
+MembersError::InvalidCursor => "invalid_cursor: Value passed for cursor was not valid or is no longer valid.",
+MembersError::InvalidLimit => "invalid_limit: Value passed for limit is not valid.",
+MembersError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+MembersError::NotAuthed => "not_authed: No authentication token provided.",
+MembersError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+MembersError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+MembersError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+MembersError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+MembersError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+MembersError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+MembersError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+MembersError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+MembersError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+MembersError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        MembersError::MalformedResponse(_, ref e) => return write!(f, "conversations.members returned a malformed response: {}", e),
+                        MembersError::Unknown(ref s) => return write!(f, "conversations.members failed: {}", s),
+                        MembersError::Client(ref inner) => return write!(f, "conversations.members failed: {}", inner),
+                    };
+        write!(f, "conversations.members failed: {}", d)
+    }
+}
+
+impl<E: Error> From<MembersError<E>> for crate::SlackError<E> {
+    fn from(e: MembersError<E>) -> Self {
+        let method = "conversations.members";
+        let error = match e {
+            MembersError::ChannelNotFound => "channel_not_found".to_owned(),
+            MembersError::FetchMembersFailed => "fetch_members_failed".to_owned(),
+            MembersError::InvalidCursor => "invalid_cursor".to_owned(),
+            MembersError::InvalidLimit => "invalid_limit".to_owned(),
+            MembersError::MissingScope => "missing_scope".to_owned(),
+            MembersError::NotAuthed => "not_authed".to_owned(),
+            MembersError::InvalidAuth => "invalid_auth".to_owned(),
+            MembersError::AccountInactive => "account_inactive".to_owned(),
+            MembersError::InvalidArgName => "invalid_arg_name".to_owned(),
+            MembersError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            MembersError::InvalidCharset => "invalid_charset".to_owned(),
+            MembersError::InvalidFormData => "invalid_form_data".to_owned(),
+            MembersError::InvalidPostType => "invalid_post_type".to_owned(),
+            MembersError::MissingPostType => "missing_post_type".to_owned(),
+            MembersError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            MembersError::RequestTimeout => "request_timeout".to_owned(),
+            MembersError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            MembersError::Unknown(s) => s,
+            MembersError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for MembersError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            MembersError::MalformedResponse(_, ref e) => Some(e),
+            MembersError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct OpenRequest<'a> {
+    /// Resume a conversation by supplying an im or mpim's ID. Or provide the users field instead.
+    pub channel: Option<&'a str>,
+    /// Boolean, indicates you want the full IM channel definition in the response.
+    pub return_im: Option<bool>,
+    /// Comma separated lists of users. If only one user is included, this creates a 1:1 DM. The ordering of the users is preserved whenever a multi-person direct message is returned. Supply a channel when not supplying users.
+    pub users: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OpenResponse {
+    pub already_open: Option<bool>,
+    pub channel: Option<crate::Conversation>,
+    error: Option<String>,
+    pub no_op: Option<bool>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<OpenResponse> for Result<OpenResponse, OpenError<E>> {
+    fn from(response: OpenResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum OpenError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Needs at least 2 users to open
+    NotEnoughUsers,
+    /// Needs at most 8 users to open
+    TooManyUsers,
+    /// Missing users in request
+    UsersListNotSupplied,
+    /// Value passed for user was invalid.
+    UserNotFound,
+    /// The user has been disabled.
+    UserDisabled,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for OpenError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => OpenError::ChannelNotFound,
+            "not_enough_users" => OpenError::NotEnoughUsers,
+            "too_many_users" => OpenError::TooManyUsers,
+            "users_list_not_supplied" => OpenError::UsersListNotSupplied,
+            "user_not_found" => OpenError::UserNotFound,
+            "user_disabled" => OpenError::UserDisabled,
+            "missing_scope" => OpenError::MissingScope,
+            "not_authed" => OpenError::NotAuthed,
+            "invalid_auth" => OpenError::InvalidAuth,
+            "account_inactive" => OpenError::AccountInactive,
+            "invalid_arg_name" => OpenError::InvalidArgName,
+            "invalid_array_arg" => OpenError::InvalidArrayArg,
+            "invalid_charset" => OpenError::InvalidCharset,
+            "invalid_form_data" => OpenError::InvalidFormData,
+            "invalid_post_type" => OpenError::InvalidPostType,
+            "missing_post_type" => OpenError::MissingPostType,
+            "team_added_to_org" => OpenError::TeamAddedToOrg,
+            "request_timeout" => OpenError::RequestTimeout,
+            _ => OpenError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for OpenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        OpenError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+OpenError::NotEnoughUsers => "not_enough_users: Needs at least 2 users to open",
+OpenError::TooManyUsers => "too_many_users: Needs at most 8 users to open",
+OpenError::UsersListNotSupplied => "users_list_not_supplied: Missing users in request",
+OpenError::UserNotFound => "user_not_found: Value passed for user was invalid.",
+OpenError::UserDisabled => "user_disabled: The user has been disabled.",
+OpenError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+OpenError::NotAuthed => "not_authed: No authentication token provided.",
+OpenError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+OpenError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+OpenError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+OpenError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+OpenError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+OpenError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+OpenError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+OpenError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+OpenError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+OpenError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        OpenError::MalformedResponse(_, ref e) => return write!(f, "conversations.open returned a malformed response: {}", e),
+                        OpenError::Unknown(ref s) => return write!(f, "conversations.open failed: {}", s),
+                        OpenError::Client(ref inner) => return write!(f, "conversations.open failed: {}", inner),
+                    };
+        write!(f, "conversations.open failed: {}", d)
+    }
+}
+
+impl<E: Error> From<OpenError<E>> for crate::SlackError<E> {
+    fn from(e: OpenError<E>) -> Self {
+        let method = "conversations.open";
+        let error = match e {
+            OpenError::ChannelNotFound => "channel_not_found".to_owned(),
+            OpenError::NotEnoughUsers => "not_enough_users".to_owned(),
+            OpenError::TooManyUsers => "too_many_users".to_owned(),
+            OpenError::UsersListNotSupplied => "users_list_not_supplied".to_owned(),
+            OpenError::UserNotFound => "user_not_found".to_owned(),
+            OpenError::UserDisabled => "user_disabled".to_owned(),
+            OpenError::MissingScope => "missing_scope".to_owned(),
+            OpenError::NotAuthed => "not_authed".to_owned(),
+            OpenError::InvalidAuth => "invalid_auth".to_owned(),
+            OpenError::AccountInactive => "account_inactive".to_owned(),
+            OpenError::InvalidArgName => "invalid_arg_name".to_owned(),
+            OpenError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            OpenError::InvalidCharset => "invalid_charset".to_owned(),
+            OpenError::InvalidFormData => "invalid_form_data".to_owned(),
+            OpenError::InvalidPostType => "invalid_post_type".to_owned(),
+            OpenError::MissingPostType => "missing_post_type".to_owned(),
+            OpenError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            OpenError::RequestTimeout => "request_timeout".to_owned(),
+            OpenError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            OpenError::Unknown(s) => s,
+            OpenError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for OpenError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            OpenError::MalformedResponse(_, ref e) => Some(e),
+            OpenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequest<'a> {
+    /// Conversation ID to learn more about
+    pub channel: &'a str,
+    /// Set this to true to receive the locale for this conversation. Defaults to false
+    pub include_locale: Option<bool>,
+    /// Set to true to include the member count for the specified conversation. Defaults to false
+    pub include_num_members: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InfoResponse {
+    pub channel: Option<crate::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InfoError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InfoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => InfoError::ChannelNotFound,
+            "missing_scope" => InfoError::MissingScope,
+            "not_authed" => InfoError::NotAuthed,
+            "invalid_auth" => InfoError::InvalidAuth,
+            "account_inactive" => InfoError::AccountInactive,
+            "invalid_arg_name" => InfoError::InvalidArgName,
+            "invalid_array_arg" => InfoError::InvalidArrayArg,
+            "invalid_charset" => InfoError::InvalidCharset,
+            "invalid_form_data" => InfoError::InvalidFormData,
+            "invalid_post_type" => InfoError::InvalidPostType,
+            "missing_post_type" => InfoError::MissingPostType,
+            "team_added_to_org" => InfoError::TeamAddedToOrg,
+            "request_timeout" => InfoError::RequestTimeout,
+            _ => InfoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for InfoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        InfoError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+InfoError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+InfoError::NotAuthed => "not_authed: No authentication token provided.",
+InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+InfoError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+InfoError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+InfoError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+InfoError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+InfoError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+InfoError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        InfoError::MalformedResponse(_, ref e) => return write!(f, "conversations.info returned a malformed response: {}", e),
+                        InfoError::Unknown(ref s) => return write!(f, "conversations.info failed: {}", s),
+                        InfoError::Client(ref inner) => return write!(f, "conversations.info failed: {}", inner),
+                    };
+        write!(f, "conversations.info failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(e: InfoError<E>) -> Self {
+        let method = "conversations.info";
+        let error = match e {
+            InfoError::ChannelNotFound => "channel_not_found".to_owned(),
+            InfoError::MissingScope => "missing_scope".to_owned(),
+            InfoError::NotAuthed => "not_authed".to_owned(),
+            InfoError::InvalidAuth => "invalid_auth".to_owned(),
+            InfoError::AccountInactive => "account_inactive".to_owned(),
+            InfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InfoError::InvalidCharset => "invalid_charset".to_owned(),
+            InfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            InfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            InfoError::MissingPostType => "missing_post_type".to_owned(),
+            InfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InfoError::RequestTimeout => "request_timeout".to_owned(),
+            InfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InfoError::Unknown(s) => s,
+            InfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for InfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            InfoError::MalformedResponse(_, ref e) => Some(e),
+            InfoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct MarkRequest<'a> {
+    /// Channel or conversation to set the read cursor for.
+    pub channel: &'a str,
+    /// Unique identifier of message you want marked as most recently seen in this conversation.
+    pub ts: crate::Timestamp,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MarkResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<MarkResponse> for Result<MarkResponse, MarkError<E>> {
+    fn from(response: MarkResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum MarkError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Value passed for timestamp was invalid.
+    InvalidTimestamp,
+    /// Caller is not a member of the channel.
+    NotInChannel,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for MarkError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => MarkError::ChannelNotFound,
+            "invalid_timestamp" => MarkError::InvalidTimestamp,
+            "not_in_channel" => MarkError::NotInChannel,
+            "missing_scope" => MarkError::MissingScope,
+            "not_authed" => MarkError::NotAuthed,
+            "invalid_auth" => MarkError::InvalidAuth,
+            "account_inactive" => MarkError::AccountInactive,
+            "invalid_arg_name" => MarkError::InvalidArgName,
+            "invalid_array_arg" => MarkError::InvalidArrayArg,
+            "invalid_charset" => MarkError::InvalidCharset,
+            "invalid_form_data" => MarkError::InvalidFormData,
+            "invalid_post_type" => MarkError::InvalidPostType,
+            "missing_post_type" => MarkError::MissingPostType,
+            "team_added_to_org" => MarkError::TeamAddedToOrg,
+            "request_timeout" => MarkError::RequestTimeout,
+            _ => MarkError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for MarkError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        MarkError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+MarkError::InvalidTimestamp => "invalid_timestamp: Value passed for timestamp was invalid.",
+MarkError::NotInChannel => "not_in_channel: Caller is not a member of the channel.",
+MarkError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+MarkError::NotAuthed => "not_authed: No authentication token provided.",
+MarkError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+MarkError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+MarkError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+MarkError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+MarkError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+MarkError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+MarkError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+MarkError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+MarkError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+MarkError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        MarkError::MalformedResponse(_, ref e) => return write!(f, "conversations.mark returned a malformed response: {}", e),
+                        MarkError::Unknown(ref s) => return write!(f, "conversations.mark failed: {}", s),
+                        MarkError::Client(ref inner) => return write!(f, "conversations.mark failed: {}", inner),
+                    };
+        write!(f, "conversations.mark failed: {}", d)
+    }
+}
+
+impl<E: Error> From<MarkError<E>> for crate::SlackError<E> {
+    fn from(e: MarkError<E>) -> Self {
+        let method = "conversations.mark";
+        let error = match e {
+            MarkError::ChannelNotFound => "channel_not_found".to_owned(),
+            MarkError::InvalidTimestamp => "invalid_timestamp".to_owned(),
+            MarkError::NotInChannel => "not_in_channel".to_owned(),
+            MarkError::MissingScope => "missing_scope".to_owned(),
+            MarkError::NotAuthed => "not_authed".to_owned(),
+            MarkError::InvalidAuth => "invalid_auth".to_owned(),
+            MarkError::AccountInactive => "account_inactive".to_owned(),
+            MarkError::InvalidArgName => "invalid_arg_name".to_owned(),
+            MarkError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            MarkError::InvalidCharset => "invalid_charset".to_owned(),
+            MarkError::InvalidFormData => "invalid_form_data".to_owned(),
+            MarkError::InvalidPostType => "invalid_post_type".to_owned(),
+            MarkError::MissingPostType => "missing_post_type".to_owned(),
+            MarkError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            MarkError::RequestTimeout => "request_timeout".to_owned(),
+            MarkError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            MarkError::Unknown(s) => s,
+            MarkError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for MarkError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            MarkError::MalformedResponse(_, ref e) => Some(e),
+            MarkError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetPurposeRequest<'a> {
+    /// Conversation to set the purpose of.
+    pub channel: &'a str,
+    /// A new, specialer purpose.
+    pub purpose: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SetPurposeResponse {
+    pub channel: Option<crate::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<SetPurposeResponse> for Result<SetPurposeResponse, SetPurposeError<E>> {
+    fn from(response: SetPurposeResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetPurposeError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has been archived.
+    IsArchived,
+    /// Authenticated user is not in the channel.
+    NotInChannel,
+    /// Purpose was longer than 250 characters.
+    TooLong,
+    /// Setting the purpose is a restricted action.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetPurposeError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => SetPurposeError::ChannelNotFound,
+            "is_archived" => SetPurposeError::IsArchived,
+            "not_in_channel" => SetPurposeError::NotInChannel,
+            "too_long" => SetPurposeError::TooLong,
+            "user_is_restricted" => SetPurposeError::UserIsRestricted,
+            "missing_scope" => SetPurposeError::MissingScope,
+            "not_authed" => SetPurposeError::NotAuthed,
+            "invalid_auth" => SetPurposeError::InvalidAuth,
+            "account_inactive" => SetPurposeError::AccountInactive,
+            "invalid_arg_name" => SetPurposeError::InvalidArgName,
+            "invalid_array_arg" => SetPurposeError::InvalidArrayArg,
+            "invalid_charset" => SetPurposeError::InvalidCharset,
+            "invalid_form_data" => SetPurposeError::InvalidFormData,
+            "invalid_post_type" => SetPurposeError::InvalidPostType,
+            "missing_post_type" => SetPurposeError::MissingPostType,
+            "team_added_to_org" => SetPurposeError::TeamAddedToOrg,
+            "request_timeout" => SetPurposeError::RequestTimeout,
+            _ => SetPurposeError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetPurposeError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        SetPurposeError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+SetPurposeError::IsArchived => "is_archived: Channel has been archived.",
+SetPurposeError::NotInChannel => "not_in_channel: Authenticated user is not in the channel.",
+SetPurposeError::TooLong => "too_long: Purpose was longer than 250 characters.",
+SetPurposeError::UserIsRestricted => "user_is_restricted: Setting the purpose is a restricted action.",
+SetPurposeError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+SetPurposeError::NotAuthed => "not_authed: No authentication token provided.",
+SetPurposeError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+SetPurposeError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+SetPurposeError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+SetPurposeError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+SetPurposeError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+SetPurposeError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+SetPurposeError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+SetPurposeError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+SetPurposeError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+SetPurposeError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        SetPurposeError::MalformedResponse(_, ref e) => return write!(f, "conversations.setPurpose returned a malformed response: {}", e),
+                        SetPurposeError::Unknown(ref s) => return write!(f, "conversations.setPurpose failed: {}", s),
+                        SetPurposeError::Client(ref inner) => return write!(f, "conversations.setPurpose failed: {}", inner),
+                    };
+        write!(f, "conversations.setPurpose failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetPurposeError<E>> for crate::SlackError<E> {
+    fn from(e: SetPurposeError<E>) -> Self {
+        let method = "conversations.setPurpose";
+        let error = match e {
+            SetPurposeError::ChannelNotFound => "channel_not_found".to_owned(),
+            SetPurposeError::IsArchived => "is_archived".to_owned(),
+            SetPurposeError::NotInChannel => "not_in_channel".to_owned(),
+            SetPurposeError::TooLong => "too_long".to_owned(),
+            SetPurposeError::UserIsRestricted => "user_is_restricted".to_owned(),
+            SetPurposeError::MissingScope => "missing_scope".to_owned(),
+            SetPurposeError::NotAuthed => "not_authed".to_owned(),
+            SetPurposeError::InvalidAuth => "invalid_auth".to_owned(),
+            SetPurposeError::AccountInactive => "account_inactive".to_owned(),
+            SetPurposeError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetPurposeError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetPurposeError::InvalidCharset => "invalid_charset".to_owned(),
+            SetPurposeError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetPurposeError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetPurposeError::MissingPostType => "missing_post_type".to_owned(),
+            SetPurposeError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetPurposeError::RequestTimeout => "request_timeout".to_owned(),
+            SetPurposeError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetPurposeError::Unknown(s) => s,
+            SetPurposeError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for SetPurposeError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SetPurposeError::MalformedResponse(_, ref e) => Some(e),
+            SetPurposeError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct SetTopicRequest<'a> {
+    /// Conversation to set the topic of.
+    pub channel: &'a str,
+    /// The new topic string. Does not support formatting or linkification.
+    pub topic: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SetTopicResponse {
+    pub channel: Option<crate::Conversation>,
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<SetTopicResponse> for Result<SetTopicResponse, SetTopicError<E>> {
+    fn from(response: SetTopicResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum SetTopicError<E: Error> {
+    /// Value passed for channel was invalid.
+    ChannelNotFound,
+    /// Channel has been archived.
+    IsArchived,
+    /// Authenticated user is not in the channel.
+    NotInChannel,
+    /// Topic was longer than 250 characters.
+    TooLong,
+    /// Setting the topic is a restricted action.
+    UserIsRestricted,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for SetTopicError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "channel_not_found" => SetTopicError::ChannelNotFound,
+            "is_archived" => SetTopicError::IsArchived,
+            "not_in_channel" => SetTopicError::NotInChannel,
+            "too_long" => SetTopicError::TooLong,
+            "user_is_restricted" => SetTopicError::UserIsRestricted,
+            "missing_scope" => SetTopicError::MissingScope,
+            "not_authed" => SetTopicError::NotAuthed,
+            "invalid_auth" => SetTopicError::InvalidAuth,
+            "account_inactive" => SetTopicError::AccountInactive,
+            "invalid_arg_name" => SetTopicError::InvalidArgName,
+            "invalid_array_arg" => SetTopicError::InvalidArrayArg,
+            "invalid_charset" => SetTopicError::InvalidCharset,
+            "invalid_form_data" => SetTopicError::InvalidFormData,
+            "invalid_post_type" => SetTopicError::InvalidPostType,
+            "missing_post_type" => SetTopicError::MissingPostType,
+            "team_added_to_org" => SetTopicError::TeamAddedToOrg,
+            "request_timeout" => SetTopicError::RequestTimeout,
+            _ => SetTopicError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SetTopicError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        SetTopicError::ChannelNotFound => "channel_not_found: Value passed for channel was invalid.",
+SetTopicError::IsArchived => "is_archived: Channel has been archived.",
+SetTopicError::NotInChannel => "not_in_channel: Authenticated user is not in the channel.",
+SetTopicError::TooLong => "too_long: Topic was longer than 250 characters.",
+SetTopicError::UserIsRestricted => "user_is_restricted: Setting the topic is a restricted action.",
+SetTopicError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+SetTopicError::NotAuthed => "not_authed: No authentication token provided.",
+SetTopicError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+SetTopicError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+SetTopicError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+SetTopicError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+SetTopicError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+SetTopicError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+SetTopicError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+SetTopicError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+SetTopicError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+SetTopicError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        SetTopicError::MalformedResponse(_, ref e) => return write!(f, "conversations.setTopic returned a malformed response: {}", e),
+                        SetTopicError::Unknown(ref s) => return write!(f, "conversations.setTopic failed: {}", s),
+                        SetTopicError::Client(ref inner) => return write!(f, "conversations.setTopic failed: {}", inner),
+                    };
+        write!(f, "conversations.setTopic failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetTopicError<E>> for crate::SlackError<E> {
+    fn from(e: SetTopicError<E>) -> Self {
+        let method = "conversations.setTopic";
+        let error = match e {
+            SetTopicError::ChannelNotFound => "channel_not_found".to_owned(),
+            SetTopicError::IsArchived => "is_archived".to_owned(),
+            SetTopicError::NotInChannel => "not_in_channel".to_owned(),
+            SetTopicError::TooLong => "too_long".to_owned(),
+            SetTopicError::UserIsRestricted => "user_is_restricted".to_owned(),
+            SetTopicError::MissingScope => "missing_scope".to_owned(),
+            SetTopicError::NotAuthed => "not_authed".to_owned(),
+            SetTopicError::InvalidAuth => "invalid_auth".to_owned(),
+            SetTopicError::AccountInactive => "account_inactive".to_owned(),
+            SetTopicError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetTopicError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetTopicError::InvalidCharset => "invalid_charset".to_owned(),
+            SetTopicError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetTopicError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetTopicError::MissingPostType => "missing_post_type".to_owned(),
+            SetTopicError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetTopicError::RequestTimeout => "request_timeout".to_owned(),
+            SetTopicError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetTopicError::Unknown(s) => s,
+            SetTopicError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for SetTopicError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SetTopicError::MalformedResponse(_, ref e) => Some(e),
+            SetTopicError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
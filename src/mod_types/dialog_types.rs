@@ -0,0 +1,192 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Open legacy dialogs, which modals have superseded.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub struct OpenRequest<'a> {
+    /// The dialog definition. This must be a JSON-encoded string.
+    pub dialog: &'a str,
+    /// Exchange a trigger to post to the user.
+    pub trigger_id: &'a crate::TriggerId,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OpenResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<OpenResponse> for Result<OpenResponse, OpenError<E>> {
+    fn from(response: OpenResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum OpenError<E: Error> {
+    /// The trigger_id has expired, 3 seconds after Slack sent it.
+    ExpiredTriggerId,
+    /// The trigger_id is invalid, or was already used.
+    InvalidTrigger,
+    /// No trigger_id was given.
+    MissingTrigger,
+    /// The dialog definition failed validation.
+    ValidationErrors,
+    /// No dialog was given.
+    MissingDialog,
+    /// The app has no interactive components Request URL.
+    AppMissingActionUrl,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for OpenError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "expired_trigger_id" => OpenError::ExpiredTriggerId,
+            "invalid_trigger" => OpenError::InvalidTrigger,
+            "missing_trigger" => OpenError::MissingTrigger,
+            "validation_errors" => OpenError::ValidationErrors,
+            "missing_dialog" => OpenError::MissingDialog,
+            "app_missing_action_url" => OpenError::AppMissingActionUrl,
+            "missing_scope" => OpenError::MissingScope,
+            "not_authed" => OpenError::NotAuthed,
+            "invalid_auth" => OpenError::InvalidAuth,
+            "account_inactive" => OpenError::AccountInactive,
+            "invalid_arg_name" => OpenError::InvalidArgName,
+            "invalid_array_arg" => OpenError::InvalidArrayArg,
+            "invalid_charset" => OpenError::InvalidCharset,
+            "invalid_form_data" => OpenError::InvalidFormData,
+            "invalid_post_type" => OpenError::InvalidPostType,
+            "missing_post_type" => OpenError::MissingPostType,
+            "team_added_to_org" => OpenError::TeamAddedToOrg,
+            "request_timeout" => OpenError::RequestTimeout,
+            _ => OpenError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for OpenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        OpenError::ExpiredTriggerId => "expired_trigger_id: The trigger_id has expired, 3 seconds after Slack sent it.",
+OpenError::InvalidTrigger => "invalid_trigger: The trigger_id is invalid, or was already used.",
+OpenError::MissingTrigger => "missing_trigger: No trigger_id was given.",
+OpenError::ValidationErrors => "validation_errors: The dialog definition failed validation.",
+OpenError::MissingDialog => "missing_dialog: No dialog was given.",
+OpenError::AppMissingActionUrl => "app_missing_action_url: The app has no interactive components Request URL.",
+OpenError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+OpenError::NotAuthed => "not_authed: No authentication token provided.",
+OpenError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+OpenError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+OpenError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+OpenError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+OpenError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+OpenError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+OpenError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+OpenError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+OpenError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+OpenError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        OpenError::MalformedResponse(_, ref e) => return write!(f, "dialog.open returned a malformed response: {}", e),
+                        OpenError::Unknown(ref s) => return write!(f, "dialog.open failed: {}", s),
+                        OpenError::Client(ref inner) => return write!(f, "dialog.open failed: {}", inner),
+                    };
+        write!(f, "dialog.open failed: {}", d)
+    }
+}
+
+impl<E: Error> From<OpenError<E>> for crate::SlackError<E> {
+    fn from(e: OpenError<E>) -> Self {
+        let method = "dialog.open";
+        let error = match e {
+            OpenError::ExpiredTriggerId => "expired_trigger_id".to_owned(),
+            OpenError::InvalidTrigger => "invalid_trigger".to_owned(),
+            OpenError::MissingTrigger => "missing_trigger".to_owned(),
+            OpenError::ValidationErrors => "validation_errors".to_owned(),
+            OpenError::MissingDialog => "missing_dialog".to_owned(),
+            OpenError::AppMissingActionUrl => "app_missing_action_url".to_owned(),
+            OpenError::MissingScope => "missing_scope".to_owned(),
+            OpenError::NotAuthed => "not_authed".to_owned(),
+            OpenError::InvalidAuth => "invalid_auth".to_owned(),
+            OpenError::AccountInactive => "account_inactive".to_owned(),
+            OpenError::InvalidArgName => "invalid_arg_name".to_owned(),
+            OpenError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            OpenError::InvalidCharset => "invalid_charset".to_owned(),
+            OpenError::InvalidFormData => "invalid_form_data".to_owned(),
+            OpenError::InvalidPostType => "invalid_post_type".to_owned(),
+            OpenError::MissingPostType => "missing_post_type".to_owned(),
+            OpenError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            OpenError::RequestTimeout => "request_timeout".to_owned(),
+            OpenError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            OpenError::Unknown(s) => s,
+            OpenError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for OpenError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            OpenError::MalformedResponse(_, ref e) => Some(e),
+            OpenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -20,19 +20,19 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct EndDndResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<EndDndResponse, EndDndError<E>>> for EndDndResponse {
-    fn into(self) -> Result<EndDndResponse, EndDndError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<EndDndResponse> for Result<EndDndResponse, EndDndError<E>> {
+    fn from(response: EndDndResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -109,11 +109,42 @@ EndDndError::InvalidPostType => "invalid_post_type: The method was called via a
 EndDndError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 EndDndError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 EndDndError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        EndDndError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        EndDndError::Unknown(ref s) => return write!(f, "{}", s),
-                        EndDndError::Client(ref inner) => return write!(f, "{}", inner),
+                        EndDndError::MalformedResponse(_, ref e) => return write!(f, "dnd.endDnd returned a malformed response: {}", e),
+                        EndDndError::Unknown(ref s) => return write!(f, "dnd.endDnd failed: {}", s),
+                        EndDndError::Client(ref inner) => return write!(f, "dnd.endDnd failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "dnd.endDnd failed: {}", d)
+    }
+}
+
+impl<E: Error> From<EndDndError<E>> for crate::SlackError<E> {
+    fn from(e: EndDndError<E>) -> Self {
+        let method = "dnd.endDnd";
+        let error = match e {
+            EndDndError::UnknownError => "unknown_error".to_owned(),
+            EndDndError::NotAuthed => "not_authed".to_owned(),
+            EndDndError::InvalidAuth => "invalid_auth".to_owned(),
+            EndDndError::AccountInactive => "account_inactive".to_owned(),
+            EndDndError::UserIsBot => "user_is_bot".to_owned(),
+            EndDndError::InvalidArgName => "invalid_arg_name".to_owned(),
+            EndDndError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            EndDndError::InvalidCharset => "invalid_charset".to_owned(),
+            EndDndError::InvalidFormData => "invalid_form_data".to_owned(),
+            EndDndError::InvalidPostType => "invalid_post_type".to_owned(),
+            EndDndError::MissingPostType => "missing_post_type".to_owned(),
+            EndDndError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            EndDndError::RequestTimeout => "request_timeout".to_owned(),
+            EndDndError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            EndDndError::Unknown(s) => s,
+            EndDndError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -127,7 +158,7 @@ impl<E: Error + 'static> Error for EndDndError<E> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct EndSnoozeResponse {
     pub dnd_enabled: Option<bool>,
     error: Option<String>,
@@ -138,12 +169,12 @@ pub struct EndSnoozeResponse {
     pub snooze_enabled: Option<bool>,
 }
 
-impl<E: Error> Into<Result<EndSnoozeResponse, EndSnoozeError<E>>> for EndSnoozeResponse {
-    fn into(self) -> Result<EndSnoozeResponse, EndSnoozeError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<EndSnoozeResponse> for Result<EndSnoozeResponse, EndSnoozeError<E>> {
+    fn from(response: EndSnoozeResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -224,11 +255,43 @@ EndSnoozeError::InvalidPostType => "invalid_post_type: The method was called via
 EndSnoozeError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 EndSnoozeError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 EndSnoozeError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        EndSnoozeError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        EndSnoozeError::Unknown(ref s) => return write!(f, "{}", s),
-                        EndSnoozeError::Client(ref inner) => return write!(f, "{}", inner),
+                        EndSnoozeError::MalformedResponse(_, ref e) => return write!(f, "dnd.endSnooze returned a malformed response: {}", e),
+                        EndSnoozeError::Unknown(ref s) => return write!(f, "dnd.endSnooze failed: {}", s),
+                        EndSnoozeError::Client(ref inner) => return write!(f, "dnd.endSnooze failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "dnd.endSnooze failed: {}", d)
+    }
+}
+
+impl<E: Error> From<EndSnoozeError<E>> for crate::SlackError<E> {
+    fn from(e: EndSnoozeError<E>) -> Self {
+        let method = "dnd.endSnooze";
+        let error = match e {
+            EndSnoozeError::SnoozeNotActive => "snooze_not_active".to_owned(),
+            EndSnoozeError::SnoozeEndFailed => "snooze_end_failed".to_owned(),
+            EndSnoozeError::NotAuthed => "not_authed".to_owned(),
+            EndSnoozeError::InvalidAuth => "invalid_auth".to_owned(),
+            EndSnoozeError::AccountInactive => "account_inactive".to_owned(),
+            EndSnoozeError::UserIsBot => "user_is_bot".to_owned(),
+            EndSnoozeError::InvalidArgName => "invalid_arg_name".to_owned(),
+            EndSnoozeError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            EndSnoozeError::InvalidCharset => "invalid_charset".to_owned(),
+            EndSnoozeError::InvalidFormData => "invalid_form_data".to_owned(),
+            EndSnoozeError::InvalidPostType => "invalid_post_type".to_owned(),
+            EndSnoozeError::MissingPostType => "missing_post_type".to_owned(),
+            EndSnoozeError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            EndSnoozeError::RequestTimeout => "request_timeout".to_owned(),
+            EndSnoozeError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            EndSnoozeError::Unknown(s) => s,
+            EndSnoozeError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -248,7 +311,7 @@ pub struct InfoRequest<'a> {
     pub user: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InfoResponse {
     pub dnd_enabled: Option<bool>,
     error: Option<String>,
@@ -261,12 +324,12 @@ pub struct InfoResponse {
     pub snooze_remaining: Option<f32>,
 }
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -339,11 +402,41 @@ InfoError::InvalidPostType => "invalid_post_type: The method was called via a PO
 InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        InfoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        InfoError::Unknown(ref s) => return write!(f, "{}", s),
-                        InfoError::Client(ref inner) => return write!(f, "{}", inner),
+                        InfoError::MalformedResponse(_, ref e) => return write!(f, "dnd.info returned a malformed response: {}", e),
+                        InfoError::Unknown(ref s) => return write!(f, "dnd.info failed: {}", s),
+                        InfoError::Client(ref inner) => return write!(f, "dnd.info failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "dnd.info failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(e: InfoError<E>) -> Self {
+        let method = "dnd.info";
+        let error = match e {
+            InfoError::UserNotFound => "user_not_found".to_owned(),
+            InfoError::NotAuthed => "not_authed".to_owned(),
+            InfoError::InvalidAuth => "invalid_auth".to_owned(),
+            InfoError::AccountInactive => "account_inactive".to_owned(),
+            InfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InfoError::InvalidCharset => "invalid_charset".to_owned(),
+            InfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            InfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            InfoError::MissingPostType => "missing_post_type".to_owned(),
+            InfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InfoError::RequestTimeout => "request_timeout".to_owned(),
+            InfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InfoError::Unknown(s) => s,
+            InfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -363,7 +456,7 @@ pub struct SetSnoozeRequest {
     pub num_minutes: u32,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SetSnoozeResponse {
     error: Option<String>,
     #[serde(default)]
@@ -373,12 +466,12 @@ pub struct SetSnoozeResponse {
     pub snooze_remaining: Option<f32>,
 }
 
-impl<E: Error> Into<Result<SetSnoozeResponse, SetSnoozeError<E>>> for SetSnoozeResponse {
-    fn into(self) -> Result<SetSnoozeResponse, SetSnoozeError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetSnoozeResponse> for Result<SetSnoozeResponse, SetSnoozeError<E>> {
+    fn from(response: SetSnoozeResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -459,11 +552,43 @@ SetSnoozeError::InvalidPostType => "invalid_post_type: The method was called via
 SetSnoozeError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 SetSnoozeError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 SetSnoozeError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        SetSnoozeError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        SetSnoozeError::Unknown(ref s) => return write!(f, "{}", s),
-                        SetSnoozeError::Client(ref inner) => return write!(f, "{}", inner),
+                        SetSnoozeError::MalformedResponse(_, ref e) => return write!(f, "dnd.setSnooze returned a malformed response: {}", e),
+                        SetSnoozeError::Unknown(ref s) => return write!(f, "dnd.setSnooze failed: {}", s),
+                        SetSnoozeError::Client(ref inner) => return write!(f, "dnd.setSnooze failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "dnd.setSnooze failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetSnoozeError<E>> for crate::SlackError<E> {
+    fn from(e: SetSnoozeError<E>) -> Self {
+        let method = "dnd.setSnooze";
+        let error = match e {
+            SetSnoozeError::MissingDuration => "missing_duration".to_owned(),
+            SetSnoozeError::SnoozeFailed => "snooze_failed".to_owned(),
+            SetSnoozeError::NotAuthed => "not_authed".to_owned(),
+            SetSnoozeError::InvalidAuth => "invalid_auth".to_owned(),
+            SetSnoozeError::AccountInactive => "account_inactive".to_owned(),
+            SetSnoozeError::UserIsBot => "user_is_bot".to_owned(),
+            SetSnoozeError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetSnoozeError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetSnoozeError::InvalidCharset => "invalid_charset".to_owned(),
+            SetSnoozeError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetSnoozeError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetSnoozeError::MissingPostType => "missing_post_type".to_owned(),
+            SetSnoozeError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetSnoozeError::RequestTimeout => "request_timeout".to_owned(),
+            SetSnoozeError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetSnoozeError::Unknown(s) => s,
+            SetSnoozeError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -483,7 +608,7 @@ pub struct TeamInfoRequest<'a> {
     pub users: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TeamInfoResponse {
     error: Option<String>,
     #[serde(default)]
@@ -491,12 +616,12 @@ pub struct TeamInfoResponse {
     pub users: Option<HashMap<String, bool>>,
 }
 
-impl<E: Error> Into<Result<TeamInfoResponse, TeamInfoError<E>>> for TeamInfoResponse {
-    fn into(self) -> Result<TeamInfoResponse, TeamInfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<TeamInfoResponse> for Result<TeamInfoResponse, TeamInfoError<E>> {
+    fn from(response: TeamInfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -565,11 +690,40 @@ TeamInfoError::InvalidPostType => "invalid_post_type: The method was called via
 TeamInfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 TeamInfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 TeamInfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        TeamInfoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        TeamInfoError::Unknown(ref s) => return write!(f, "{}", s),
-                        TeamInfoError::Client(ref inner) => return write!(f, "{}", inner),
+                        TeamInfoError::MalformedResponse(_, ref e) => return write!(f, "dnd.teamInfo returned a malformed response: {}", e),
+                        TeamInfoError::Unknown(ref s) => return write!(f, "dnd.teamInfo failed: {}", s),
+                        TeamInfoError::Client(ref inner) => return write!(f, "dnd.teamInfo failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "dnd.teamInfo failed: {}", d)
+    }
+}
+
+impl<E: Error> From<TeamInfoError<E>> for crate::SlackError<E> {
+    fn from(e: TeamInfoError<E>) -> Self {
+        let method = "dnd.teamInfo";
+        let error = match e {
+            TeamInfoError::NotAuthed => "not_authed".to_owned(),
+            TeamInfoError::InvalidAuth => "invalid_auth".to_owned(),
+            TeamInfoError::AccountInactive => "account_inactive".to_owned(),
+            TeamInfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            TeamInfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            TeamInfoError::InvalidCharset => "invalid_charset".to_owned(),
+            TeamInfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            TeamInfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            TeamInfoError::MissingPostType => "missing_post_type".to_owned(),
+            TeamInfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            TeamInfoError::RequestTimeout => "request_timeout".to_owned(),
+            TeamInfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            TeamInfoError::Unknown(s) => s,
+            TeamInfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
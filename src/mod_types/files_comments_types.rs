@@ -26,7 +26,7 @@ pub struct AddRequest<'a> {
     pub comment: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AddResponse {
     pub comment: Option<crate::FileComment>,
     error: Option<String>,
@@ -34,12 +34,12 @@ pub struct AddResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
-    fn into(self) -> Result<AddResponse, AddError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -120,11 +120,43 @@ AddError::InvalidPostType => "invalid_post_type: The method was called via a POS
 AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        AddError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        AddError::Unknown(ref s) => return write!(f, "{}", s),
-                        AddError::Client(ref inner) => return write!(f, "{}", inner),
+                        AddError::MalformedResponse(_, ref e) => return write!(f, "files.comments.add returned a malformed response: {}", e),
+                        AddError::Unknown(ref s) => return write!(f, "files.comments.add failed: {}", s),
+                        AddError::Client(ref inner) => return write!(f, "files.comments.add failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "files.comments.add failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(e: AddError<E>) -> Self {
+        let method = "files.comments.add";
+        let error = match e {
+            AddError::FileNotFound => "file_not_found".to_owned(),
+            AddError::FileDeleted => "file_deleted".to_owned(),
+            AddError::NoComment => "no_comment".to_owned(),
+            AddError::NotAuthed => "not_authed".to_owned(),
+            AddError::InvalidAuth => "invalid_auth".to_owned(),
+            AddError::AccountInactive => "account_inactive".to_owned(),
+            AddError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddError::InvalidCharset => "invalid_charset".to_owned(),
+            AddError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddError::MissingPostType => "missing_post_type".to_owned(),
+            AddError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddError::RequestTimeout => "request_timeout".to_owned(),
+            AddError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddError::Unknown(s) => s,
+            AddError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -146,19 +178,19 @@ pub struct DeleteRequest<'a> {
     pub id: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct DeleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
-    fn into(self) -> Result<DeleteResponse, DeleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DeleteResponse> for Result<DeleteResponse, DeleteError<E>> {
+    fn from(response: DeleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -239,11 +271,43 @@ DeleteError::InvalidPostType => "invalid_post_type: The method was called via a
 DeleteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 DeleteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 DeleteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        DeleteError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        DeleteError::Unknown(ref s) => return write!(f, "{}", s),
-                        DeleteError::Client(ref inner) => return write!(f, "{}", inner),
+                        DeleteError::MalformedResponse(_, ref e) => return write!(f, "files.comments.delete returned a malformed response: {}", e),
+                        DeleteError::Unknown(ref s) => return write!(f, "files.comments.delete failed: {}", s),
+                        DeleteError::Client(ref inner) => return write!(f, "files.comments.delete failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "files.comments.delete failed: {}", d)
+    }
+}
+
+impl<E: Error> From<DeleteError<E>> for crate::SlackError<E> {
+    fn from(e: DeleteError<E>) -> Self {
+        let method = "files.comments.delete";
+        let error = match e {
+            DeleteError::FileNotFound => "file_not_found".to_owned(),
+            DeleteError::FileDeleted => "file_deleted".to_owned(),
+            DeleteError::CantDelete => "cant_delete".to_owned(),
+            DeleteError::NotAuthed => "not_authed".to_owned(),
+            DeleteError::InvalidAuth => "invalid_auth".to_owned(),
+            DeleteError::AccountInactive => "account_inactive".to_owned(),
+            DeleteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            DeleteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            DeleteError::InvalidCharset => "invalid_charset".to_owned(),
+            DeleteError::InvalidFormData => "invalid_form_data".to_owned(),
+            DeleteError::InvalidPostType => "invalid_post_type".to_owned(),
+            DeleteError::MissingPostType => "missing_post_type".to_owned(),
+            DeleteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            DeleteError::RequestTimeout => "request_timeout".to_owned(),
+            DeleteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            DeleteError::Unknown(s) => s,
+            DeleteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -267,7 +331,7 @@ pub struct EditRequest<'a> {
     pub comment: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct EditResponse {
     pub comment: Option<crate::FileComment>,
     error: Option<String>,
@@ -275,12 +339,12 @@ pub struct EditResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<EditResponse, EditError<E>>> for EditResponse {
-    fn into(self) -> Result<EditResponse, EditError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<EditResponse> for Result<EditResponse, EditError<E>> {
+    fn from(response: EditResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -369,11 +433,45 @@ EditError::InvalidPostType => "invalid_post_type: The method was called via a PO
 EditError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 EditError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 EditError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        EditError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        EditError::Unknown(ref s) => return write!(f, "{}", s),
-                        EditError::Client(ref inner) => return write!(f, "{}", inner),
+                        EditError::MalformedResponse(_, ref e) => return write!(f, "files.comments.edit returned a malformed response: {}", e),
+                        EditError::Unknown(ref s) => return write!(f, "files.comments.edit failed: {}", s),
+                        EditError::Client(ref inner) => return write!(f, "files.comments.edit failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "files.comments.edit failed: {}", d)
+    }
+}
+
+impl<E: Error> From<EditError<E>> for crate::SlackError<E> {
+    fn from(e: EditError<E>) -> Self {
+        let method = "files.comments.edit";
+        let error = match e {
+            EditError::FileNotFound => "file_not_found".to_owned(),
+            EditError::FileDeleted => "file_deleted".to_owned(),
+            EditError::NoComment => "no_comment".to_owned(),
+            EditError::EditWindowClosed => "edit_window_closed".to_owned(),
+            EditError::CantEdit => "cant_edit".to_owned(),
+            EditError::NotAuthed => "not_authed".to_owned(),
+            EditError::InvalidAuth => "invalid_auth".to_owned(),
+            EditError::AccountInactive => "account_inactive".to_owned(),
+            EditError::InvalidArgName => "invalid_arg_name".to_owned(),
+            EditError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            EditError::InvalidCharset => "invalid_charset".to_owned(),
+            EditError::InvalidFormData => "invalid_form_data".to_owned(),
+            EditError::InvalidPostType => "invalid_post_type".to_owned(),
+            EditError::MissingPostType => "missing_post_type".to_owned(),
+            EditError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            EditError::RequestTimeout => "request_timeout".to_owned(),
+            EditError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            EditError::Unknown(s) => s,
+            EditError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
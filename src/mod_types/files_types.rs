@@ -26,19 +26,19 @@ pub struct DeleteRequest<'a> {
     pub file: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct DeleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
-    fn into(self) -> Result<DeleteResponse, DeleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DeleteResponse> for Result<DeleteResponse, DeleteError<E>> {
+    fn from(response: DeleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -119,11 +119,43 @@ DeleteError::InvalidPostType => "invalid_post_type: The method was called via a
 DeleteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 DeleteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 DeleteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        DeleteError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        DeleteError::Unknown(ref s) => return write!(f, "{}", s),
-                        DeleteError::Client(ref inner) => return write!(f, "{}", inner),
+                        DeleteError::MalformedResponse(_, ref e) => return write!(f, "files.delete returned a malformed response: {}", e),
+                        DeleteError::Unknown(ref s) => return write!(f, "files.delete failed: {}", s),
+                        DeleteError::Client(ref inner) => return write!(f, "files.delete failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "files.delete failed: {}", d)
+    }
+}
+
+impl<E: Error> From<DeleteError<E>> for crate::SlackError<E> {
+    fn from(e: DeleteError<E>) -> Self {
+        let method = "files.delete";
+        let error = match e {
+            DeleteError::FileNotFound => "file_not_found".to_owned(),
+            DeleteError::FileDeleted => "file_deleted".to_owned(),
+            DeleteError::CantDeleteFile => "cant_delete_file".to_owned(),
+            DeleteError::NotAuthed => "not_authed".to_owned(),
+            DeleteError::InvalidAuth => "invalid_auth".to_owned(),
+            DeleteError::AccountInactive => "account_inactive".to_owned(),
+            DeleteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            DeleteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            DeleteError::InvalidCharset => "invalid_charset".to_owned(),
+            DeleteError::InvalidFormData => "invalid_form_data".to_owned(),
+            DeleteError::InvalidPostType => "invalid_post_type".to_owned(),
+            DeleteError::MissingPostType => "missing_post_type".to_owned(),
+            DeleteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            DeleteError::RequestTimeout => "request_timeout".to_owned(),
+            DeleteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            DeleteError::Unknown(s) => s,
+            DeleteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -145,9 +177,13 @@ pub struct InfoRequest<'a> {
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
+    /// Parameter for pagination. Set cursor equal to the next_cursor attribute returned by the previous request's response_metadata. This parameter is optional, but pagination is mandatory: the default value simply fetches the first "page" of the collection of comments.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InfoResponse {
     pub comments: Option<Vec<crate::FileComment>>,
     error: Option<String>,
@@ -155,14 +191,27 @@ pub struct InfoResponse {
     #[serde(default)]
     ok: bool,
     pub paging: Option<crate::Paging>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPaging for InfoResponse {
+    fn paging(&self) -> Option<&crate::Paging> {
+        self.paging.as_ref()
+    }
 }
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl crate::HasPagination for InfoResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -239,11 +288,42 @@ InfoError::InvalidPostType => "invalid_post_type: The method was called via a PO
 InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        InfoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        InfoError::Unknown(ref s) => return write!(f, "{}", s),
-                        InfoError::Client(ref inner) => return write!(f, "{}", inner),
+                        InfoError::MalformedResponse(_, ref e) => return write!(f, "files.info returned a malformed response: {}", e),
+                        InfoError::Unknown(ref s) => return write!(f, "files.info failed: {}", s),
+                        InfoError::Client(ref inner) => return write!(f, "files.info failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "files.info failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(e: InfoError<E>) -> Self {
+        let method = "files.info";
+        let error = match e {
+            InfoError::FileNotFound => "file_not_found".to_owned(),
+            InfoError::FileDeleted => "file_deleted".to_owned(),
+            InfoError::NotAuthed => "not_authed".to_owned(),
+            InfoError::InvalidAuth => "invalid_auth".to_owned(),
+            InfoError::AccountInactive => "account_inactive".to_owned(),
+            InfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InfoError::InvalidCharset => "invalid_charset".to_owned(),
+            InfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            InfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            InfoError::MissingPostType => "missing_post_type".to_owned(),
+            InfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InfoError::RequestTimeout => "request_timeout".to_owned(),
+            InfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InfoError::Unknown(s) => s,
+            InfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -264,30 +344,18 @@ pub struct ListRequest<'a> {
     /// Filter files appearing in a specific channel, indicated by its ID.
     pub channel: Option<&'a str>,
     /// Filter files created after this timestamp (inclusive).
-    pub ts_from: Option<u32>,
+    pub ts_from: Option<crate::Timestamp>,
     /// Filter files created before this timestamp (inclusive).
-    pub ts_to: Option<u32>,
-    /// Filter files by type:
-    ///
-    ///
-    /// all - All files
-    /// spaces - Posts
-    /// snippets - Snippets
-    /// images - Image files
-    /// gdocs - Google docs
-    /// zips - Zip files
-    /// pdfs - PDF files
-    ///
-    ///
-    /// You can pass multiple values in the types argument, like types=spaces,snippets.The default value is all, which does not filter the list.
-    pub types: Option<&'a str>,
+    pub ts_to: Option<crate::Timestamp>,
+    /// Filter files by type. The default value of all types does not filter the list.
+    pub types: Option<crate::FileTypes>,
     /// Number of items to return per page.
     pub count: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponse {
     error: Option<String>,
     pub files: Option<Vec<crate::File>>,
@@ -296,12 +364,18 @@ pub struct ListResponse {
     pub paging: Option<crate::Paging>,
 }
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl crate::HasPaging for ListResponse {
+    fn paging(&self) -> Option<&crate::Paging> {
+        self.paging.as_ref()
+    }
+}
+
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -382,11 +456,43 @@ ListError::InvalidPostType => "invalid_post_type: The method was called via a PO
 ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ListError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ListError::Unknown(ref s) => return write!(f, "{}", s),
-                        ListError::Client(ref inner) => return write!(f, "{}", inner),
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "files.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "files.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "files.list failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "files.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "files.list";
+        let error = match e {
+            ListError::UserNotFound => "user_not_found".to_owned(),
+            ListError::UnknownType => "unknown_type".to_owned(),
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::UserIsBot => "user_is_bot".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -406,7 +512,7 @@ pub struct RevokePublicURLRequest<'a> {
     pub file: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RevokePublicURLResponse {
     error: Option<String>,
     pub file: Option<crate::File>,
@@ -414,14 +520,14 @@ pub struct RevokePublicURLResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<RevokePublicURLResponse, RevokePublicURLError<E>>>
-    for RevokePublicURLResponse
+impl<E: Error> From<RevokePublicURLResponse>
+    for Result<RevokePublicURLResponse, RevokePublicURLError<E>>
 {
-    fn into(self) -> Result<RevokePublicURLResponse, RevokePublicURLError<E>> {
-        if self.ok {
-            Ok(self)
+    fn from(response: RevokePublicURLResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -502,11 +608,45 @@ RevokePublicURLError::InvalidPostType => "invalid_post_type: The method was call
 RevokePublicURLError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 RevokePublicURLError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 RevokePublicURLError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        RevokePublicURLError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        RevokePublicURLError::Unknown(ref s) => return write!(f, "{}", s),
-                        RevokePublicURLError::Client(ref inner) => return write!(f, "{}", inner),
+                        RevokePublicURLError::MalformedResponse(_, ref e) => return write!(f, "files.revokePublicURL returned a malformed response: {}", e),
+                        RevokePublicURLError::Unknown(ref s) => return write!(f, "files.revokePublicURL failed: {}", s),
+                        RevokePublicURLError::Client(ref inner) => return write!(f, "files.revokePublicURL failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "files.revokePublicURL failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RevokePublicURLError<E>> for crate::SlackError<E> {
+    fn from(e: RevokePublicURLError<E>) -> Self {
+        let method = "files.revokePublicURL";
+        let error = match e {
+            RevokePublicURLError::FileNotFound => "file_not_found".to_owned(),
+            RevokePublicURLError::NotAuthed => "not_authed".to_owned(),
+            RevokePublicURLError::InvalidAuth => "invalid_auth".to_owned(),
+            RevokePublicURLError::AccountInactive => "account_inactive".to_owned(),
+            RevokePublicURLError::UserIsBot => "user_is_bot".to_owned(),
+            RevokePublicURLError::UserIsRestricted => "user_is_restricted".to_owned(),
+            RevokePublicURLError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RevokePublicURLError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RevokePublicURLError::InvalidCharset => "invalid_charset".to_owned(),
+            RevokePublicURLError::InvalidFormData => "invalid_form_data".to_owned(),
+            RevokePublicURLError::InvalidPostType => "invalid_post_type".to_owned(),
+            RevokePublicURLError::MissingPostType => "missing_post_type".to_owned(),
+            RevokePublicURLError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RevokePublicURLError::RequestTimeout => "request_timeout".to_owned(),
+            RevokePublicURLError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RevokePublicURLError::Unknown(s) => s,
+            RevokePublicURLError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -526,7 +666,7 @@ pub struct SharedPublicURLRequest<'a> {
     pub file: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SharedPublicURLResponse {
     error: Option<String>,
     pub file: Option<crate::File>,
@@ -534,14 +674,14 @@ pub struct SharedPublicURLResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<SharedPublicURLResponse, SharedPublicURLError<E>>>
-    for SharedPublicURLResponse
+impl<E: Error> From<SharedPublicURLResponse>
+    for Result<SharedPublicURLResponse, SharedPublicURLError<E>>
 {
-    fn into(self) -> Result<SharedPublicURLResponse, SharedPublicURLError<E>> {
-        if self.ok {
-            Ok(self)
+    fn from(response: SharedPublicURLResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -626,11 +766,46 @@ SharedPublicURLError::InvalidPostType => "invalid_post_type: The method was call
 SharedPublicURLError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 SharedPublicURLError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 SharedPublicURLError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        SharedPublicURLError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        SharedPublicURLError::Unknown(ref s) => return write!(f, "{}", s),
-                        SharedPublicURLError::Client(ref inner) => return write!(f, "{}", inner),
+                        SharedPublicURLError::MalformedResponse(_, ref e) => return write!(f, "files.sharedPublicURL returned a malformed response: {}", e),
+                        SharedPublicURLError::Unknown(ref s) => return write!(f, "files.sharedPublicURL failed: {}", s),
+                        SharedPublicURLError::Client(ref inner) => return write!(f, "files.sharedPublicURL failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "files.sharedPublicURL failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SharedPublicURLError<E>> for crate::SlackError<E> {
+    fn from(e: SharedPublicURLError<E>) -> Self {
+        let method = "files.sharedPublicURL";
+        let error = match e {
+            SharedPublicURLError::FileNotFound => "file_not_found".to_owned(),
+            SharedPublicURLError::NotAllowed => "not_allowed".to_owned(),
+            SharedPublicURLError::NotAuthed => "not_authed".to_owned(),
+            SharedPublicURLError::InvalidAuth => "invalid_auth".to_owned(),
+            SharedPublicURLError::AccountInactive => "account_inactive".to_owned(),
+            SharedPublicURLError::UserIsBot => "user_is_bot".to_owned(),
+            SharedPublicURLError::UserIsRestricted => "user_is_restricted".to_owned(),
+            SharedPublicURLError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SharedPublicURLError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SharedPublicURLError::InvalidCharset => "invalid_charset".to_owned(),
+            SharedPublicURLError::InvalidFormData => "invalid_form_data".to_owned(),
+            SharedPublicURLError::InvalidPostType => "invalid_post_type".to_owned(),
+            SharedPublicURLError::MissingPostType => "missing_post_type".to_owned(),
+            SharedPublicURLError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SharedPublicURLError::RequestTimeout => "request_timeout".to_owned(),
+            SharedPublicURLError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SharedPublicURLError::Unknown(s) => s,
+            SharedPublicURLError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -26,19 +26,19 @@ pub struct ArchiveRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ArchiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<ArchiveResponse, ArchiveError<E>>> for ArchiveResponse {
-    fn into(self) -> Result<ArchiveResponse, ArchiveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ArchiveResponse> for Result<ArchiveResponse, ArchiveError<E>> {
+    fn from(response: ArchiveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -131,11 +131,46 @@ ArchiveError::InvalidPostType => "invalid_post_type: The method was called via a
 ArchiveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ArchiveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ArchiveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ArchiveError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ArchiveError::Unknown(ref s) => return write!(f, "{}", s),
-                        ArchiveError::Client(ref inner) => return write!(f, "{}", inner),
+                        ArchiveError::MalformedResponse(_, ref e) => return write!(f, "groups.archive returned a malformed response: {}", e),
+                        ArchiveError::Unknown(ref s) => return write!(f, "groups.archive failed: {}", s),
+                        ArchiveError::Client(ref inner) => return write!(f, "groups.archive failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.archive failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ArchiveError<E>> for crate::SlackError<E> {
+    fn from(e: ArchiveError<E>) -> Self {
+        let method = "groups.archive";
+        let error = match e {
+            ArchiveError::ChannelNotFound => "channel_not_found".to_owned(),
+            ArchiveError::AlreadyArchived => "already_archived".to_owned(),
+            ArchiveError::GroupContainsOthers => "group_contains_others".to_owned(),
+            ArchiveError::RestrictedAction => "restricted_action".to_owned(),
+            ArchiveError::NotAuthed => "not_authed".to_owned(),
+            ArchiveError::InvalidAuth => "invalid_auth".to_owned(),
+            ArchiveError::AccountInactive => "account_inactive".to_owned(),
+            ArchiveError::UserIsBot => "user_is_bot".to_owned(),
+            ArchiveError::UserIsUltraRestricted => "user_is_ultra_restricted".to_owned(),
+            ArchiveError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ArchiveError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ArchiveError::InvalidCharset => "invalid_charset".to_owned(),
+            ArchiveError::InvalidFormData => "invalid_form_data".to_owned(),
+            ArchiveError::InvalidPostType => "invalid_post_type".to_owned(),
+            ArchiveError::MissingPostType => "missing_post_type".to_owned(),
+            ArchiveError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ArchiveError::RequestTimeout => "request_timeout".to_owned(),
+            ArchiveError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ArchiveError::Unknown(s) => s,
+            ArchiveError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -155,19 +190,19 @@ pub struct CloseRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CloseResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<CloseResponse, CloseError<E>>> for CloseResponse {
-    fn into(self) -> Result<CloseResponse, CloseError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CloseResponse> for Result<CloseResponse, CloseError<E>> {
+    fn from(response: CloseResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -240,11 +275,41 @@ CloseError::InvalidPostType => "invalid_post_type: The method was called via a P
 CloseError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 CloseError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 CloseError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        CloseError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        CloseError::Unknown(ref s) => return write!(f, "{}", s),
-                        CloseError::Client(ref inner) => return write!(f, "{}", inner),
+                        CloseError::MalformedResponse(_, ref e) => return write!(f, "groups.close returned a malformed response: {}", e),
+                        CloseError::Unknown(ref s) => return write!(f, "groups.close failed: {}", s),
+                        CloseError::Client(ref inner) => return write!(f, "groups.close failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.close failed: {}", d)
+    }
+}
+
+impl<E: Error> From<CloseError<E>> for crate::SlackError<E> {
+    fn from(e: CloseError<E>) -> Self {
+        let method = "groups.close";
+        let error = match e {
+            CloseError::ChannelNotFound => "channel_not_found".to_owned(),
+            CloseError::NotAuthed => "not_authed".to_owned(),
+            CloseError::InvalidAuth => "invalid_auth".to_owned(),
+            CloseError::AccountInactive => "account_inactive".to_owned(),
+            CloseError::InvalidArgName => "invalid_arg_name".to_owned(),
+            CloseError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            CloseError::InvalidCharset => "invalid_charset".to_owned(),
+            CloseError::InvalidFormData => "invalid_form_data".to_owned(),
+            CloseError::InvalidPostType => "invalid_post_type".to_owned(),
+            CloseError::MissingPostType => "missing_post_type".to_owned(),
+            CloseError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            CloseError::RequestTimeout => "request_timeout".to_owned(),
+            CloseError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            CloseError::Unknown(s) => s,
+            CloseError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -266,7 +331,7 @@ pub struct CreateRequest<'a> {
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CreateResponse {
     error: Option<String>,
     pub group: Option<crate::Group>,
@@ -274,12 +339,12 @@ pub struct CreateResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
-    fn into(self) -> Result<CreateResponse, CreateError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CreateResponse> for Result<CreateResponse, CreateError<E>> {
+    fn from(response: CreateResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -388,11 +453,50 @@ CreateError::InvalidPostType => "invalid_post_type: The method was called via a
 CreateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 CreateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 CreateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        CreateError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        CreateError::Unknown(ref s) => return write!(f, "{}", s),
-                        CreateError::Client(ref inner) => return write!(f, "{}", inner),
+                        CreateError::MalformedResponse(_, ref e) => return write!(f, "groups.create returned a malformed response: {}", e),
+                        CreateError::Unknown(ref s) => return write!(f, "groups.create failed: {}", s),
+                        CreateError::Client(ref inner) => return write!(f, "groups.create failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.create failed: {}", d)
+    }
+}
+
+impl<E: Error> From<CreateError<E>> for crate::SlackError<E> {
+    fn from(e: CreateError<E>) -> Self {
+        let method = "groups.create";
+        let error = match e {
+            CreateError::NoChannel => "no_channel".to_owned(),
+            CreateError::RestrictedAction => "restricted_action".to_owned(),
+            CreateError::NameTaken => "name_taken".to_owned(),
+            CreateError::InvalidNameRequired => "invalid_name_required".to_owned(),
+            CreateError::InvalidNamePunctuation => "invalid_name_punctuation".to_owned(),
+            CreateError::InvalidNameMaxlength => "invalid_name_maxlength".to_owned(),
+            CreateError::InvalidNameSpecials => "invalid_name_specials".to_owned(),
+            CreateError::InvalidName => "invalid_name".to_owned(),
+            CreateError::NotAuthed => "not_authed".to_owned(),
+            CreateError::InvalidAuth => "invalid_auth".to_owned(),
+            CreateError::AccountInactive => "account_inactive".to_owned(),
+            CreateError::UserIsBot => "user_is_bot".to_owned(),
+            CreateError::UserIsUltraRestricted => "user_is_ultra_restricted".to_owned(),
+            CreateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            CreateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            CreateError::InvalidCharset => "invalid_charset".to_owned(),
+            CreateError::InvalidFormData => "invalid_form_data".to_owned(),
+            CreateError::InvalidPostType => "invalid_post_type".to_owned(),
+            CreateError::MissingPostType => "missing_post_type".to_owned(),
+            CreateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            CreateError::RequestTimeout => "request_timeout".to_owned(),
+            CreateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            CreateError::Unknown(s) => s,
+            CreateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -412,7 +516,7 @@ pub struct CreateChildRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CreateChildResponse {
     error: Option<String>,
     pub group: Option<crate::Group>,
@@ -420,12 +524,12 @@ pub struct CreateChildResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<CreateChildResponse, CreateChildError<E>>> for CreateChildResponse {
-    fn into(self) -> Result<CreateChildResponse, CreateChildError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CreateChildResponse> for Result<CreateChildResponse, CreateChildError<E>> {
+    fn from(response: CreateChildResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -514,11 +618,45 @@ CreateChildError::InvalidPostType => "invalid_post_type: The method was called v
 CreateChildError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 CreateChildError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 CreateChildError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        CreateChildError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        CreateChildError::Unknown(ref s) => return write!(f, "{}", s),
-                        CreateChildError::Client(ref inner) => return write!(f, "{}", inner),
+                        CreateChildError::MalformedResponse(_, ref e) => return write!(f, "groups.createChild returned a malformed response: {}", e),
+                        CreateChildError::Unknown(ref s) => return write!(f, "groups.createChild failed: {}", s),
+                        CreateChildError::Client(ref inner) => return write!(f, "groups.createChild failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.createChild failed: {}", d)
+    }
+}
+
+impl<E: Error> From<CreateChildError<E>> for crate::SlackError<E> {
+    fn from(e: CreateChildError<E>) -> Self {
+        let method = "groups.createChild";
+        let error = match e {
+            CreateChildError::ChannelNotFound => "channel_not_found".to_owned(),
+            CreateChildError::AlreadyArchived => "already_archived".to_owned(),
+            CreateChildError::RestrictedAction => "restricted_action".to_owned(),
+            CreateChildError::NotAuthed => "not_authed".to_owned(),
+            CreateChildError::InvalidAuth => "invalid_auth".to_owned(),
+            CreateChildError::AccountInactive => "account_inactive".to_owned(),
+            CreateChildError::UserIsBot => "user_is_bot".to_owned(),
+            CreateChildError::UserIsUltraRestricted => "user_is_ultra_restricted".to_owned(),
+            CreateChildError::InvalidArgName => "invalid_arg_name".to_owned(),
+            CreateChildError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            CreateChildError::InvalidCharset => "invalid_charset".to_owned(),
+            CreateChildError::InvalidFormData => "invalid_form_data".to_owned(),
+            CreateChildError::InvalidPostType => "invalid_post_type".to_owned(),
+            CreateChildError::MissingPostType => "missing_post_type".to_owned(),
+            CreateChildError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            CreateChildError::RequestTimeout => "request_timeout".to_owned(),
+            CreateChildError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            CreateChildError::Unknown(s) => s,
+            CreateChildError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -548,7 +686,7 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct HistoryResponse {
     error: Option<String>,
     pub has_more: Option<bool>,
@@ -558,12 +696,12 @@ pub struct HistoryResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<HistoryResponse, HistoryError<E>>> for HistoryResponse {
-    fn into(self) -> Result<HistoryResponse, HistoryError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<HistoryResponse> for Result<HistoryResponse, HistoryError<E>> {
+    fn from(response: HistoryResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -644,11 +782,43 @@ HistoryError::InvalidPostType => "invalid_post_type: The method was called via a
 HistoryError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 HistoryError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 HistoryError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        HistoryError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        HistoryError::Unknown(ref s) => return write!(f, "{}", s),
-                        HistoryError::Client(ref inner) => return write!(f, "{}", inner),
+                        HistoryError::MalformedResponse(_, ref e) => return write!(f, "groups.history returned a malformed response: {}", e),
+                        HistoryError::Unknown(ref s) => return write!(f, "groups.history failed: {}", s),
+                        HistoryError::Client(ref inner) => return write!(f, "groups.history failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.history failed: {}", d)
+    }
+}
+
+impl<E: Error> From<HistoryError<E>> for crate::SlackError<E> {
+    fn from(e: HistoryError<E>) -> Self {
+        let method = "groups.history";
+        let error = match e {
+            HistoryError::ChannelNotFound => "channel_not_found".to_owned(),
+            HistoryError::InvalidTsLatest => "invalid_ts_latest".to_owned(),
+            HistoryError::InvalidTsOldest => "invalid_ts_oldest".to_owned(),
+            HistoryError::NotAuthed => "not_authed".to_owned(),
+            HistoryError::InvalidAuth => "invalid_auth".to_owned(),
+            HistoryError::AccountInactive => "account_inactive".to_owned(),
+            HistoryError::InvalidArgName => "invalid_arg_name".to_owned(),
+            HistoryError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            HistoryError::InvalidCharset => "invalid_charset".to_owned(),
+            HistoryError::InvalidFormData => "invalid_form_data".to_owned(),
+            HistoryError::InvalidPostType => "invalid_post_type".to_owned(),
+            HistoryError::MissingPostType => "missing_post_type".to_owned(),
+            HistoryError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            HistoryError::RequestTimeout => "request_timeout".to_owned(),
+            HistoryError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            HistoryError::Unknown(s) => s,
+            HistoryError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -668,7 +838,7 @@ pub struct InfoRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InfoResponse {
     error: Option<String>,
     pub group: Option<crate::Group>,
@@ -676,12 +846,12 @@ pub struct InfoResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -754,11 +924,41 @@ InfoError::InvalidPostType => "invalid_post_type: The method was called via a PO
 InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        InfoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        InfoError::Unknown(ref s) => return write!(f, "{}", s),
-                        InfoError::Client(ref inner) => return write!(f, "{}", inner),
+                        InfoError::MalformedResponse(_, ref e) => return write!(f, "groups.info returned a malformed response: {}", e),
+                        InfoError::Unknown(ref s) => return write!(f, "groups.info failed: {}", s),
+                        InfoError::Client(ref inner) => return write!(f, "groups.info failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.info failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(e: InfoError<E>) -> Self {
+        let method = "groups.info";
+        let error = match e {
+            InfoError::ChannelNotFound => "channel_not_found".to_owned(),
+            InfoError::NotAuthed => "not_authed".to_owned(),
+            InfoError::InvalidAuth => "invalid_auth".to_owned(),
+            InfoError::AccountInactive => "account_inactive".to_owned(),
+            InfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InfoError::InvalidCharset => "invalid_charset".to_owned(),
+            InfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            InfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            InfoError::MissingPostType => "missing_post_type".to_owned(),
+            InfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InfoError::RequestTimeout => "request_timeout".to_owned(),
+            InfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InfoError::Unknown(s) => s,
+            InfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -780,7 +980,7 @@ pub struct InviteRequest<'a> {
     pub user: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InviteResponse {
     error: Option<String>,
     pub group: Option<crate::Group>,
@@ -788,12 +988,12 @@ pub struct InviteResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<InviteResponse, InviteError<E>>> for InviteResponse {
-    fn into(self) -> Result<InviteResponse, InviteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InviteResponse> for Result<InviteResponse, InviteError<E>> {
+    fn from(response: InviteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -894,11 +1094,48 @@ InviteError::InvalidPostType => "invalid_post_type: The method was called via a
 InviteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 InviteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 InviteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        InviteError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        InviteError::Unknown(ref s) => return write!(f, "{}", s),
-                        InviteError::Client(ref inner) => return write!(f, "{}", inner),
+                        InviteError::MalformedResponse(_, ref e) => return write!(f, "groups.invite returned a malformed response: {}", e),
+                        InviteError::Unknown(ref s) => return write!(f, "groups.invite failed: {}", s),
+                        InviteError::Client(ref inner) => return write!(f, "groups.invite failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.invite failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InviteError<E>> for crate::SlackError<E> {
+    fn from(e: InviteError<E>) -> Self {
+        let method = "groups.invite";
+        let error = match e {
+            InviteError::ChannelNotFound => "channel_not_found".to_owned(),
+            InviteError::UserNotFound => "user_not_found".to_owned(),
+            InviteError::CantInviteSelf => "cant_invite_self".to_owned(),
+            InviteError::IsArchived => "is_archived".to_owned(),
+            InviteError::CantInvite => "cant_invite".to_owned(),
+            InviteError::UraMaxChannels => "ura_max_channels".to_owned(),
+            InviteError::NotAuthed => "not_authed".to_owned(),
+            InviteError::InvalidAuth => "invalid_auth".to_owned(),
+            InviteError::AccountInactive => "account_inactive".to_owned(),
+            InviteError::UserIsBot => "user_is_bot".to_owned(),
+            InviteError::UserIsUltraRestricted => "user_is_ultra_restricted".to_owned(),
+            InviteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InviteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InviteError::InvalidCharset => "invalid_charset".to_owned(),
+            InviteError::InvalidFormData => "invalid_form_data".to_owned(),
+            InviteError::InvalidPostType => "invalid_post_type".to_owned(),
+            InviteError::MissingPostType => "missing_post_type".to_owned(),
+            InviteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InviteError::RequestTimeout => "request_timeout".to_owned(),
+            InviteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InviteError::Unknown(s) => s,
+            InviteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -920,19 +1157,19 @@ pub struct KickRequest<'a> {
     pub user: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct KickResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<KickResponse, KickError<E>>> for KickResponse {
-    fn into(self) -> Result<KickResponse, KickError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<KickResponse> for Result<KickResponse, KickError<E>> {
+    fn from(response: KickResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -1029,11 +1266,47 @@ KickError::InvalidPostType => "invalid_post_type: The method was called via a PO
 KickError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 KickError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 KickError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        KickError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        KickError::Unknown(ref s) => return write!(f, "{}", s),
-                        KickError::Client(ref inner) => return write!(f, "{}", inner),
+                        KickError::MalformedResponse(_, ref e) => return write!(f, "groups.kick returned a malformed response: {}", e),
+                        KickError::Unknown(ref s) => return write!(f, "groups.kick failed: {}", s),
+                        KickError::Client(ref inner) => return write!(f, "groups.kick failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.kick failed: {}", d)
+    }
+}
+
+impl<E: Error> From<KickError<E>> for crate::SlackError<E> {
+    fn from(e: KickError<E>) -> Self {
+        let method = "groups.kick";
+        let error = match e {
+            KickError::ChannelNotFound => "channel_not_found".to_owned(),
+            KickError::UserNotFound => "user_not_found".to_owned(),
+            KickError::CantKickSelf => "cant_kick_self".to_owned(),
+            KickError::NotInGroup => "not_in_group".to_owned(),
+            KickError::RestrictedAction => "restricted_action".to_owned(),
+            KickError::NotAuthed => "not_authed".to_owned(),
+            KickError::InvalidAuth => "invalid_auth".to_owned(),
+            KickError::AccountInactive => "account_inactive".to_owned(),
+            KickError::UserIsBot => "user_is_bot".to_owned(),
+            KickError::UserIsRestricted => "user_is_restricted".to_owned(),
+            KickError::InvalidArgName => "invalid_arg_name".to_owned(),
+            KickError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            KickError::InvalidCharset => "invalid_charset".to_owned(),
+            KickError::InvalidFormData => "invalid_form_data".to_owned(),
+            KickError::InvalidPostType => "invalid_post_type".to_owned(),
+            KickError::MissingPostType => "missing_post_type".to_owned(),
+            KickError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            KickError::RequestTimeout => "request_timeout".to_owned(),
+            KickError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            KickError::Unknown(s) => s,
+            KickError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -1053,19 +1326,19 @@ pub struct LeaveRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct LeaveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<LeaveResponse, LeaveError<E>>> for LeaveResponse {
-    fn into(self) -> Result<LeaveResponse, LeaveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<LeaveResponse> for Result<LeaveResponse, LeaveError<E>> {
+    fn from(response: LeaveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -1150,11 +1423,44 @@ LeaveError::InvalidPostType => "invalid_post_type: The method was called via a P
 LeaveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 LeaveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 LeaveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        LeaveError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        LeaveError::Unknown(ref s) => return write!(f, "{}", s),
-                        LeaveError::Client(ref inner) => return write!(f, "{}", inner),
+                        LeaveError::MalformedResponse(_, ref e) => return write!(f, "groups.leave returned a malformed response: {}", e),
+                        LeaveError::Unknown(ref s) => return write!(f, "groups.leave failed: {}", s),
+                        LeaveError::Client(ref inner) => return write!(f, "groups.leave failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.leave failed: {}", d)
+    }
+}
+
+impl<E: Error> From<LeaveError<E>> for crate::SlackError<E> {
+    fn from(e: LeaveError<E>) -> Self {
+        let method = "groups.leave";
+        let error = match e {
+            LeaveError::ChannelNotFound => "channel_not_found".to_owned(),
+            LeaveError::IsArchived => "is_archived".to_owned(),
+            LeaveError::NotAuthed => "not_authed".to_owned(),
+            LeaveError::InvalidAuth => "invalid_auth".to_owned(),
+            LeaveError::AccountInactive => "account_inactive".to_owned(),
+            LeaveError::UserIsBot => "user_is_bot".to_owned(),
+            LeaveError::UserIsUltraRestricted => "user_is_ultra_restricted".to_owned(),
+            LeaveError::InvalidArgName => "invalid_arg_name".to_owned(),
+            LeaveError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            LeaveError::InvalidCharset => "invalid_charset".to_owned(),
+            LeaveError::InvalidFormData => "invalid_form_data".to_owned(),
+            LeaveError::InvalidPostType => "invalid_post_type".to_owned(),
+            LeaveError::MissingPostType => "missing_post_type".to_owned(),
+            LeaveError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            LeaveError::RequestTimeout => "request_timeout".to_owned(),
+            LeaveError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            LeaveError::Unknown(s) => s,
+            LeaveError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -1174,7 +1480,7 @@ pub struct ListRequest {
     pub exclude_archived: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponse {
     error: Option<String>,
     pub groups: Option<Vec<crate::Group>>,
@@ -1182,12 +1488,12 @@ pub struct ListResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -1256,11 +1562,40 @@ ListError::InvalidPostType => "invalid_post_type: The method was called via a PO
 ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ListError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ListError::Unknown(ref s) => return write!(f, "{}", s),
-                        ListError::Client(ref inner) => return write!(f, "{}", inner),
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "groups.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "groups.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "groups.list failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "groups.list";
+        let error = match e {
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -1282,19 +1617,19 @@ pub struct MarkRequest<'a> {
     pub ts: crate::Timestamp,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MarkResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<MarkResponse, MarkError<E>>> for MarkResponse {
-    fn into(self) -> Result<MarkResponse, MarkError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<MarkResponse> for Result<MarkResponse, MarkError<E>> {
+    fn from(response: MarkResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -1371,11 +1706,42 @@ MarkError::InvalidPostType => "invalid_post_type: The method was called via a PO
 MarkError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 MarkError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 MarkError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        MarkError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        MarkError::Unknown(ref s) => return write!(f, "{}", s),
-                        MarkError::Client(ref inner) => return write!(f, "{}", inner),
+                        MarkError::MalformedResponse(_, ref e) => return write!(f, "groups.mark returned a malformed response: {}", e),
+                        MarkError::Unknown(ref s) => return write!(f, "groups.mark failed: {}", s),
+                        MarkError::Client(ref inner) => return write!(f, "groups.mark failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.mark failed: {}", d)
+    }
+}
+
+impl<E: Error> From<MarkError<E>> for crate::SlackError<E> {
+    fn from(e: MarkError<E>) -> Self {
+        let method = "groups.mark";
+        let error = match e {
+            MarkError::ChannelNotFound => "channel_not_found".to_owned(),
+            MarkError::InvalidTimestamp => "invalid_timestamp".to_owned(),
+            MarkError::NotAuthed => "not_authed".to_owned(),
+            MarkError::InvalidAuth => "invalid_auth".to_owned(),
+            MarkError::AccountInactive => "account_inactive".to_owned(),
+            MarkError::InvalidArgName => "invalid_arg_name".to_owned(),
+            MarkError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            MarkError::InvalidCharset => "invalid_charset".to_owned(),
+            MarkError::InvalidFormData => "invalid_form_data".to_owned(),
+            MarkError::InvalidPostType => "invalid_post_type".to_owned(),
+            MarkError::MissingPostType => "missing_post_type".to_owned(),
+            MarkError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            MarkError::RequestTimeout => "request_timeout".to_owned(),
+            MarkError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            MarkError::Unknown(s) => s,
+            MarkError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -1395,19 +1761,19 @@ pub struct OpenRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct OpenResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
-    fn into(self) -> Result<OpenResponse, OpenError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<OpenResponse> for Result<OpenResponse, OpenError<E>> {
+    fn from(response: OpenResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -1480,11 +1846,41 @@ OpenError::InvalidPostType => "invalid_post_type: The method was called via a PO
 OpenError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 OpenError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 OpenError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        OpenError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        OpenError::Unknown(ref s) => return write!(f, "{}", s),
-                        OpenError::Client(ref inner) => return write!(f, "{}", inner),
+                        OpenError::MalformedResponse(_, ref e) => return write!(f, "groups.open returned a malformed response: {}", e),
+                        OpenError::Unknown(ref s) => return write!(f, "groups.open failed: {}", s),
+                        OpenError::Client(ref inner) => return write!(f, "groups.open failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.open failed: {}", d)
+    }
+}
+
+impl<E: Error> From<OpenError<E>> for crate::SlackError<E> {
+    fn from(e: OpenError<E>) -> Self {
+        let method = "groups.open";
+        let error = match e {
+            OpenError::ChannelNotFound => "channel_not_found".to_owned(),
+            OpenError::NotAuthed => "not_authed".to_owned(),
+            OpenError::InvalidAuth => "invalid_auth".to_owned(),
+            OpenError::AccountInactive => "account_inactive".to_owned(),
+            OpenError::InvalidArgName => "invalid_arg_name".to_owned(),
+            OpenError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            OpenError::InvalidCharset => "invalid_charset".to_owned(),
+            OpenError::InvalidFormData => "invalid_form_data".to_owned(),
+            OpenError::InvalidPostType => "invalid_post_type".to_owned(),
+            OpenError::MissingPostType => "missing_post_type".to_owned(),
+            OpenError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            OpenError::RequestTimeout => "request_timeout".to_owned(),
+            OpenError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            OpenError::Unknown(s) => s,
+            OpenError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -1508,7 +1904,7 @@ pub struct RenameRequest<'a> {
     pub validate: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RenameResponse {
     pub channel: Option<RenameResponseChannel>,
     error: Option<String>,
@@ -1516,7 +1912,7 @@ pub struct RenameResponse {
     ok: bool,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RenameResponseChannel {
     pub created: Option<f32>,
     pub id: Option<String>,
@@ -1524,12 +1920,12 @@ pub struct RenameResponseChannel {
     pub name: Option<String>,
 }
 
-impl<E: Error> Into<Result<RenameResponse, RenameError<E>>> for RenameResponse {
-    fn into(self) -> Result<RenameResponse, RenameError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RenameResponse> for Result<RenameResponse, RenameError<E>> {
+    fn from(response: RenameResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -1634,11 +2030,49 @@ RenameError::InvalidPostType => "invalid_post_type: The method was called via a
 RenameError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 RenameError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 RenameError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        RenameError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        RenameError::Unknown(ref s) => return write!(f, "{}", s),
-                        RenameError::Client(ref inner) => return write!(f, "{}", inner),
+                        RenameError::MalformedResponse(_, ref e) => return write!(f, "groups.rename returned a malformed response: {}", e),
+                        RenameError::Unknown(ref s) => return write!(f, "groups.rename failed: {}", s),
+                        RenameError::Client(ref inner) => return write!(f, "groups.rename failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.rename failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RenameError<E>> for crate::SlackError<E> {
+    fn from(e: RenameError<E>) -> Self {
+        let method = "groups.rename";
+        let error = match e {
+            RenameError::ChannelNotFound => "channel_not_found".to_owned(),
+            RenameError::InvalidName => "invalid_name".to_owned(),
+            RenameError::NameTaken => "name_taken".to_owned(),
+            RenameError::InvalidNameRequired => "invalid_name_required".to_owned(),
+            RenameError::InvalidNamePunctuation => "invalid_name_punctuation".to_owned(),
+            RenameError::InvalidNameMaxlength => "invalid_name_maxlength".to_owned(),
+            RenameError::InvalidNameSpecials => "invalid_name_specials".to_owned(),
+            RenameError::NotAuthed => "not_authed".to_owned(),
+            RenameError::InvalidAuth => "invalid_auth".to_owned(),
+            RenameError::AccountInactive => "account_inactive".to_owned(),
+            RenameError::UserIsBot => "user_is_bot".to_owned(),
+            RenameError::UserIsRestricted => "user_is_restricted".to_owned(),
+            RenameError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RenameError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RenameError::InvalidCharset => "invalid_charset".to_owned(),
+            RenameError::InvalidFormData => "invalid_form_data".to_owned(),
+            RenameError::InvalidPostType => "invalid_post_type".to_owned(),
+            RenameError::MissingPostType => "missing_post_type".to_owned(),
+            RenameError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RenameError::RequestTimeout => "request_timeout".to_owned(),
+            RenameError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RenameError::Unknown(s) => s,
+            RenameError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -1660,7 +2094,7 @@ pub struct RepliesRequest<'a> {
     pub thread_ts: crate::Timestamp,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RepliesResponse {
     error: Option<String>,
     pub messages: Option<Vec<crate::Message>>,
@@ -1669,12 +2103,12 @@ pub struct RepliesResponse {
     pub thread_info: Option<crate::ThreadInfo>,
 }
 
-impl<E: Error> Into<Result<RepliesResponse, RepliesError<E>>> for RepliesResponse {
-    fn into(self) -> Result<RepliesResponse, RepliesError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RepliesResponse> for Result<RepliesResponse, RepliesError<E>> {
+    fn from(response: RepliesResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -1755,11 +2189,43 @@ RepliesError::InvalidPostType => "invalid_post_type: The method was called via a
 RepliesError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 RepliesError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 RepliesError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        RepliesError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        RepliesError::Unknown(ref s) => return write!(f, "{}", s),
-                        RepliesError::Client(ref inner) => return write!(f, "{}", inner),
+                        RepliesError::MalformedResponse(_, ref e) => return write!(f, "groups.replies returned a malformed response: {}", e),
+                        RepliesError::Unknown(ref s) => return write!(f, "groups.replies failed: {}", s),
+                        RepliesError::Client(ref inner) => return write!(f, "groups.replies failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.replies failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RepliesError<E>> for crate::SlackError<E> {
+    fn from(e: RepliesError<E>) -> Self {
+        let method = "groups.replies";
+        let error = match e {
+            RepliesError::ChannelNotFound => "channel_not_found".to_owned(),
+            RepliesError::ThreadNotFound => "thread_not_found".to_owned(),
+            RepliesError::NotAuthed => "not_authed".to_owned(),
+            RepliesError::InvalidAuth => "invalid_auth".to_owned(),
+            RepliesError::AccountInactive => "account_inactive".to_owned(),
+            RepliesError::UserIsBot => "user_is_bot".to_owned(),
+            RepliesError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RepliesError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RepliesError::InvalidCharset => "invalid_charset".to_owned(),
+            RepliesError::InvalidFormData => "invalid_form_data".to_owned(),
+            RepliesError::InvalidPostType => "invalid_post_type".to_owned(),
+            RepliesError::MissingPostType => "missing_post_type".to_owned(),
+            RepliesError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RepliesError::RequestTimeout => "request_timeout".to_owned(),
+            RepliesError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RepliesError::Unknown(s) => s,
+            RepliesError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -1781,7 +2247,7 @@ pub struct SetPurposeRequest<'a> {
     pub purpose: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SetPurposeResponse {
     error: Option<String>,
     #[serde(default)]
@@ -1789,12 +2255,12 @@ pub struct SetPurposeResponse {
     pub purpose: Option<String>,
 }
 
-impl<E: Error> Into<Result<SetPurposeResponse, SetPurposeError<E>>> for SetPurposeResponse {
-    fn into(self) -> Result<SetPurposeResponse, SetPurposeError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetPurposeResponse> for Result<SetPurposeResponse, SetPurposeError<E>> {
+    fn from(response: SetPurposeResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -1879,11 +2345,44 @@ SetPurposeError::InvalidPostType => "invalid_post_type: The method was called vi
 SetPurposeError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 SetPurposeError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 SetPurposeError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        SetPurposeError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        SetPurposeError::Unknown(ref s) => return write!(f, "{}", s),
-                        SetPurposeError::Client(ref inner) => return write!(f, "{}", inner),
+                        SetPurposeError::MalformedResponse(_, ref e) => return write!(f, "groups.setPurpose returned a malformed response: {}", e),
+                        SetPurposeError::Unknown(ref s) => return write!(f, "groups.setPurpose failed: {}", s),
+                        SetPurposeError::Client(ref inner) => return write!(f, "groups.setPurpose failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.setPurpose failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetPurposeError<E>> for crate::SlackError<E> {
+    fn from(e: SetPurposeError<E>) -> Self {
+        let method = "groups.setPurpose";
+        let error = match e {
+            SetPurposeError::ChannelNotFound => "channel_not_found".to_owned(),
+            SetPurposeError::IsArchived => "is_archived".to_owned(),
+            SetPurposeError::TooLong => "too_long".to_owned(),
+            SetPurposeError::UserIsRestricted => "user_is_restricted".to_owned(),
+            SetPurposeError::NotAuthed => "not_authed".to_owned(),
+            SetPurposeError::InvalidAuth => "invalid_auth".to_owned(),
+            SetPurposeError::AccountInactive => "account_inactive".to_owned(),
+            SetPurposeError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetPurposeError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetPurposeError::InvalidCharset => "invalid_charset".to_owned(),
+            SetPurposeError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetPurposeError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetPurposeError::MissingPostType => "missing_post_type".to_owned(),
+            SetPurposeError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetPurposeError::RequestTimeout => "request_timeout".to_owned(),
+            SetPurposeError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetPurposeError::Unknown(s) => s,
+            SetPurposeError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -1905,7 +2404,7 @@ pub struct SetTopicRequest<'a> {
     pub topic: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SetTopicResponse {
     error: Option<String>,
     #[serde(default)]
@@ -1913,12 +2412,12 @@ pub struct SetTopicResponse {
     pub topic: Option<String>,
 }
 
-impl<E: Error> Into<Result<SetTopicResponse, SetTopicError<E>>> for SetTopicResponse {
-    fn into(self) -> Result<SetTopicResponse, SetTopicError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetTopicResponse> for Result<SetTopicResponse, SetTopicError<E>> {
+    fn from(response: SetTopicResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -2003,11 +2502,44 @@ SetTopicError::InvalidPostType => "invalid_post_type: The method was called via
 SetTopicError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 SetTopicError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 SetTopicError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        SetTopicError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        SetTopicError::Unknown(ref s) => return write!(f, "{}", s),
-                        SetTopicError::Client(ref inner) => return write!(f, "{}", inner),
+                        SetTopicError::MalformedResponse(_, ref e) => return write!(f, "groups.setTopic returned a malformed response: {}", e),
+                        SetTopicError::Unknown(ref s) => return write!(f, "groups.setTopic failed: {}", s),
+                        SetTopicError::Client(ref inner) => return write!(f, "groups.setTopic failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.setTopic failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetTopicError<E>> for crate::SlackError<E> {
+    fn from(e: SetTopicError<E>) -> Self {
+        let method = "groups.setTopic";
+        let error = match e {
+            SetTopicError::ChannelNotFound => "channel_not_found".to_owned(),
+            SetTopicError::IsArchived => "is_archived".to_owned(),
+            SetTopicError::TooLong => "too_long".to_owned(),
+            SetTopicError::UserIsRestricted => "user_is_restricted".to_owned(),
+            SetTopicError::NotAuthed => "not_authed".to_owned(),
+            SetTopicError::InvalidAuth => "invalid_auth".to_owned(),
+            SetTopicError::AccountInactive => "account_inactive".to_owned(),
+            SetTopicError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetTopicError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetTopicError::InvalidCharset => "invalid_charset".to_owned(),
+            SetTopicError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetTopicError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetTopicError::MissingPostType => "missing_post_type".to_owned(),
+            SetTopicError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetTopicError::RequestTimeout => "request_timeout".to_owned(),
+            SetTopicError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetTopicError::Unknown(s) => s,
+            SetTopicError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -2027,19 +2559,19 @@ pub struct UnarchiveRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UnarchiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<UnarchiveResponse, UnarchiveError<E>>> for UnarchiveResponse {
-    fn into(self) -> Result<UnarchiveResponse, UnarchiveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<UnarchiveResponse> for Result<UnarchiveResponse, UnarchiveError<E>> {
+    fn from(response: UnarchiveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -2124,11 +2656,44 @@ UnarchiveError::InvalidPostType => "invalid_post_type: The method was called via
 UnarchiveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 UnarchiveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 UnarchiveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        UnarchiveError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        UnarchiveError::Unknown(ref s) => return write!(f, "{}", s),
-                        UnarchiveError::Client(ref inner) => return write!(f, "{}", inner),
+                        UnarchiveError::MalformedResponse(_, ref e) => return write!(f, "groups.unarchive returned a malformed response: {}", e),
+                        UnarchiveError::Unknown(ref s) => return write!(f, "groups.unarchive failed: {}", s),
+                        UnarchiveError::Client(ref inner) => return write!(f, "groups.unarchive failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "groups.unarchive failed: {}", d)
+    }
+}
+
+impl<E: Error> From<UnarchiveError<E>> for crate::SlackError<E> {
+    fn from(e: UnarchiveError<E>) -> Self {
+        let method = "groups.unarchive";
+        let error = match e {
+            UnarchiveError::ChannelNotFound => "channel_not_found".to_owned(),
+            UnarchiveError::NotArchived => "not_archived".to_owned(),
+            UnarchiveError::NotAuthed => "not_authed".to_owned(),
+            UnarchiveError::InvalidAuth => "invalid_auth".to_owned(),
+            UnarchiveError::AccountInactive => "account_inactive".to_owned(),
+            UnarchiveError::UserIsBot => "user_is_bot".to_owned(),
+            UnarchiveError::UserIsRestricted => "user_is_restricted".to_owned(),
+            UnarchiveError::InvalidArgName => "invalid_arg_name".to_owned(),
+            UnarchiveError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            UnarchiveError::InvalidCharset => "invalid_charset".to_owned(),
+            UnarchiveError::InvalidFormData => "invalid_form_data".to_owned(),
+            UnarchiveError::InvalidPostType => "invalid_post_type".to_owned(),
+            UnarchiveError::MissingPostType => "missing_post_type".to_owned(),
+            UnarchiveError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            UnarchiveError::RequestTimeout => "request_timeout".to_owned(),
+            UnarchiveError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            UnarchiveError::Unknown(s) => s,
+            UnarchiveError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
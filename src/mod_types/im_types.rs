@@ -26,19 +26,19 @@ pub struct CloseRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CloseResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<CloseResponse, CloseError<E>>> for CloseResponse {
-    fn into(self) -> Result<CloseResponse, CloseError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CloseResponse> for Result<CloseResponse, CloseError<E>> {
+    fn from(response: CloseResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -115,11 +115,42 @@ CloseError::InvalidPostType => "invalid_post_type: The method was called via a P
 CloseError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 CloseError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 CloseError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        CloseError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        CloseError::Unknown(ref s) => return write!(f, "{}", s),
-                        CloseError::Client(ref inner) => return write!(f, "{}", inner),
+                        CloseError::MalformedResponse(_, ref e) => return write!(f, "im.close returned a malformed response: {}", e),
+                        CloseError::Unknown(ref s) => return write!(f, "im.close failed: {}", s),
+                        CloseError::Client(ref inner) => return write!(f, "im.close failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "im.close failed: {}", d)
+    }
+}
+
+impl<E: Error> From<CloseError<E>> for crate::SlackError<E> {
+    fn from(e: CloseError<E>) -> Self {
+        let method = "im.close";
+        let error = match e {
+            CloseError::ChannelNotFound => "channel_not_found".to_owned(),
+            CloseError::UserDoesNotOwnChannel => "user_does_not_own_channel".to_owned(),
+            CloseError::NotAuthed => "not_authed".to_owned(),
+            CloseError::InvalidAuth => "invalid_auth".to_owned(),
+            CloseError::AccountInactive => "account_inactive".to_owned(),
+            CloseError::InvalidArgName => "invalid_arg_name".to_owned(),
+            CloseError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            CloseError::InvalidCharset => "invalid_charset".to_owned(),
+            CloseError::InvalidFormData => "invalid_form_data".to_owned(),
+            CloseError::InvalidPostType => "invalid_post_type".to_owned(),
+            CloseError::MissingPostType => "missing_post_type".to_owned(),
+            CloseError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            CloseError::RequestTimeout => "request_timeout".to_owned(),
+            CloseError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            CloseError::Unknown(s) => s,
+            CloseError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -149,7 +180,7 @@ pub struct HistoryRequest<'a> {
     pub unreads: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct HistoryResponse {
     error: Option<String>,
     pub has_more: Option<bool>,
@@ -159,12 +190,12 @@ pub struct HistoryResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<HistoryResponse, HistoryError<E>>> for HistoryResponse {
-    fn into(self) -> Result<HistoryResponse, HistoryError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<HistoryResponse> for Result<HistoryResponse, HistoryError<E>> {
+    fn from(response: HistoryResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -245,11 +276,43 @@ HistoryError::InvalidPostType => "invalid_post_type: The method was called via a
 HistoryError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 HistoryError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 HistoryError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        HistoryError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        HistoryError::Unknown(ref s) => return write!(f, "{}", s),
-                        HistoryError::Client(ref inner) => return write!(f, "{}", inner),
+                        HistoryError::MalformedResponse(_, ref e) => return write!(f, "im.history returned a malformed response: {}", e),
+                        HistoryError::Unknown(ref s) => return write!(f, "im.history failed: {}", s),
+                        HistoryError::Client(ref inner) => return write!(f, "im.history failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "im.history failed: {}", d)
+    }
+}
+
+impl<E: Error> From<HistoryError<E>> for crate::SlackError<E> {
+    fn from(e: HistoryError<E>) -> Self {
+        let method = "im.history";
+        let error = match e {
+            HistoryError::ChannelNotFound => "channel_not_found".to_owned(),
+            HistoryError::InvalidTsLatest => "invalid_ts_latest".to_owned(),
+            HistoryError::InvalidTsOldest => "invalid_ts_oldest".to_owned(),
+            HistoryError::NotAuthed => "not_authed".to_owned(),
+            HistoryError::InvalidAuth => "invalid_auth".to_owned(),
+            HistoryError::AccountInactive => "account_inactive".to_owned(),
+            HistoryError::InvalidArgName => "invalid_arg_name".to_owned(),
+            HistoryError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            HistoryError::InvalidCharset => "invalid_charset".to_owned(),
+            HistoryError::InvalidFormData => "invalid_form_data".to_owned(),
+            HistoryError::InvalidPostType => "invalid_post_type".to_owned(),
+            HistoryError::MissingPostType => "missing_post_type".to_owned(),
+            HistoryError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            HistoryError::RequestTimeout => "request_timeout".to_owned(),
+            HistoryError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            HistoryError::Unknown(s) => s,
+            HistoryError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -266,12 +329,12 @@ impl<E: Error + 'static> Error for HistoryError<E> {
 #[derive(Clone, Default, Debug)]
 pub struct ListRequest<'a> {
     /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See pagination for more detail.
-    pub cursor: Option<&'a str>,
+    pub cursor: Option<&'a crate::Cursor>,
     /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
     pub limit: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponse {
     error: Option<String>,
     pub ims: Option<Vec<crate::Im>>,
@@ -279,12 +342,12 @@ pub struct ListResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -353,11 +416,40 @@ ListError::InvalidPostType => "invalid_post_type: The method was called via a PO
 ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ListError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ListError::Unknown(ref s) => return write!(f, "{}", s),
-                        ListError::Client(ref inner) => return write!(f, "{}", inner),
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "im.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "im.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "im.list failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "im.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "im.list";
+        let error = match e {
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -379,19 +471,19 @@ pub struct MarkRequest<'a> {
     pub ts: crate::Timestamp,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MarkResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<MarkResponse, MarkError<E>>> for MarkResponse {
-    fn into(self) -> Result<MarkResponse, MarkError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<MarkResponse> for Result<MarkResponse, MarkError<E>> {
+    fn from(response: MarkResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -472,11 +564,43 @@ MarkError::InvalidPostType => "invalid_post_type: The method was called via a PO
 MarkError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 MarkError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 MarkError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        MarkError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        MarkError::Unknown(ref s) => return write!(f, "{}", s),
-                        MarkError::Client(ref inner) => return write!(f, "{}", inner),
+                        MarkError::MalformedResponse(_, ref e) => return write!(f, "im.mark returned a malformed response: {}", e),
+                        MarkError::Unknown(ref s) => return write!(f, "im.mark failed: {}", s),
+                        MarkError::Client(ref inner) => return write!(f, "im.mark failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "im.mark failed: {}", d)
+    }
+}
+
+impl<E: Error> From<MarkError<E>> for crate::SlackError<E> {
+    fn from(e: MarkError<E>) -> Self {
+        let method = "im.mark";
+        let error = match e {
+            MarkError::ChannelNotFound => "channel_not_found".to_owned(),
+            MarkError::InvalidTimestamp => "invalid_timestamp".to_owned(),
+            MarkError::NotInChannel => "not_in_channel".to_owned(),
+            MarkError::NotAuthed => "not_authed".to_owned(),
+            MarkError::InvalidAuth => "invalid_auth".to_owned(),
+            MarkError::AccountInactive => "account_inactive".to_owned(),
+            MarkError::InvalidArgName => "invalid_arg_name".to_owned(),
+            MarkError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            MarkError::InvalidCharset => "invalid_charset".to_owned(),
+            MarkError::InvalidFormData => "invalid_form_data".to_owned(),
+            MarkError::InvalidPostType => "invalid_post_type".to_owned(),
+            MarkError::MissingPostType => "missing_post_type".to_owned(),
+            MarkError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            MarkError::RequestTimeout => "request_timeout".to_owned(),
+            MarkError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            MarkError::Unknown(s) => s,
+            MarkError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -498,7 +622,7 @@ pub struct OpenRequest<'a> {
     pub return_im: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct OpenResponse {
     pub channel: Option<crate::Im>,
     error: Option<String>,
@@ -506,12 +630,12 @@ pub struct OpenResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<OpenResponse, OpenError<E>>> for OpenResponse {
-    fn into(self) -> Result<OpenResponse, OpenError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<OpenResponse> for Result<OpenResponse, OpenError<E>> {
+    fn from(response: OpenResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -592,11 +716,43 @@ OpenError::InvalidPostType => "invalid_post_type: The method was called via a PO
 OpenError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 OpenError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 OpenError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        OpenError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        OpenError::Unknown(ref s) => return write!(f, "{}", s),
-                        OpenError::Client(ref inner) => return write!(f, "{}", inner),
+                        OpenError::MalformedResponse(_, ref e) => return write!(f, "im.open returned a malformed response: {}", e),
+                        OpenError::Unknown(ref s) => return write!(f, "im.open failed: {}", s),
+                        OpenError::Client(ref inner) => return write!(f, "im.open failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "im.open failed: {}", d)
+    }
+}
+
+impl<E: Error> From<OpenError<E>> for crate::SlackError<E> {
+    fn from(e: OpenError<E>) -> Self {
+        let method = "im.open";
+        let error = match e {
+            OpenError::UserNotFound => "user_not_found".to_owned(),
+            OpenError::UserNotVisible => "user_not_visible".to_owned(),
+            OpenError::UserDisabled => "user_disabled".to_owned(),
+            OpenError::NotAuthed => "not_authed".to_owned(),
+            OpenError::InvalidAuth => "invalid_auth".to_owned(),
+            OpenError::AccountInactive => "account_inactive".to_owned(),
+            OpenError::InvalidArgName => "invalid_arg_name".to_owned(),
+            OpenError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            OpenError::InvalidCharset => "invalid_charset".to_owned(),
+            OpenError::InvalidFormData => "invalid_form_data".to_owned(),
+            OpenError::InvalidPostType => "invalid_post_type".to_owned(),
+            OpenError::MissingPostType => "missing_post_type".to_owned(),
+            OpenError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            OpenError::RequestTimeout => "request_timeout".to_owned(),
+            OpenError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            OpenError::Unknown(s) => s,
+            OpenError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -618,7 +774,7 @@ pub struct RepliesRequest<'a> {
     pub thread_ts: crate::Timestamp,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RepliesResponse {
     error: Option<String>,
     pub messages: Option<Vec<crate::Message>>,
@@ -627,12 +783,12 @@ pub struct RepliesResponse {
     pub thread_info: Option<crate::ThreadInfo>,
 }
 
-impl<E: Error> Into<Result<RepliesResponse, RepliesError<E>>> for RepliesResponse {
-    fn into(self) -> Result<RepliesResponse, RepliesError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RepliesResponse> for Result<RepliesResponse, RepliesError<E>> {
+    fn from(response: RepliesResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -713,11 +869,43 @@ RepliesError::InvalidPostType => "invalid_post_type: The method was called via a
 RepliesError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 RepliesError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 RepliesError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        RepliesError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        RepliesError::Unknown(ref s) => return write!(f, "{}", s),
-                        RepliesError::Client(ref inner) => return write!(f, "{}", inner),
+                        RepliesError::MalformedResponse(_, ref e) => return write!(f, "im.replies returned a malformed response: {}", e),
+                        RepliesError::Unknown(ref s) => return write!(f, "im.replies failed: {}", s),
+                        RepliesError::Client(ref inner) => return write!(f, "im.replies failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "im.replies failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RepliesError<E>> for crate::SlackError<E> {
+    fn from(e: RepliesError<E>) -> Self {
+        let method = "im.replies";
+        let error = match e {
+            RepliesError::ChannelNotFound => "channel_not_found".to_owned(),
+            RepliesError::ThreadNotFound => "thread_not_found".to_owned(),
+            RepliesError::NotAuthed => "not_authed".to_owned(),
+            RepliesError::InvalidAuth => "invalid_auth".to_owned(),
+            RepliesError::AccountInactive => "account_inactive".to_owned(),
+            RepliesError::UserIsBot => "user_is_bot".to_owned(),
+            RepliesError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RepliesError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RepliesError::InvalidCharset => "invalid_charset".to_owned(),
+            RepliesError::InvalidFormData => "invalid_form_data".to_owned(),
+            RepliesError::InvalidPostType => "invalid_post_type".to_owned(),
+            RepliesError::MissingPostType => "missing_post_type".to_owned(),
+            RepliesError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RepliesError::RequestTimeout => "request_timeout".to_owned(),
+            RepliesError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RepliesError::Unknown(s) => s,
+            RepliesError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -1,8 +1,25 @@
+pub mod admin_auth_policy_types;
+pub mod admin_conversations_ekm_types;
+pub mod admin_conversations_restrict_access_types;
+pub mod admin_functions_types;
+pub mod admin_functions_permissions_types;
+pub mod admin_roles_types;
+pub mod admin_usergroups_types;
+pub mod admin_users_types;
+pub mod admin_users_session_types;
+pub mod admin_workflows_types;
+pub mod admin_workflows_collaborators_types;
+pub mod admin_workflows_permissions_types;
 pub mod api_types;
+pub mod assistant_threads_types;
 pub mod auth_types;
+pub mod bookmarks_types;
 pub mod bots_types;
 pub mod channels_types;
 pub mod chat_types;
+pub mod chat_scheduled_messages_types;
+pub mod conversations_types;
+pub mod dialog_types;
 pub mod dnd_types;
 pub mod emoji_types;
 pub mod files_comments_types;
@@ -16,10 +33,17 @@ pub mod reactions_types;
 pub mod reminders_types;
 pub mod rtm_types;
 pub mod search_types;
+pub mod slack_lists_types;
+pub mod slack_lists_items_types;
 pub mod stars_types;
 pub mod team_types;
+pub mod team_external_teams_types;
+pub mod team_preferences_types;
 pub mod team_profile_types;
 pub mod usergroups_types;
 pub mod usergroups_users_types;
 pub mod users_types;
-pub mod users_profile_types;
\ No newline at end of file
+#[cfg(feature = "unstable-api")]
+pub mod users_admin_types;
+pub mod users_profile_types;
+pub mod views_types;
\ No newline at end of file
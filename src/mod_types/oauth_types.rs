@@ -30,7 +30,7 @@ pub struct AccessRequest<'a> {
     pub redirect_uri: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AccessResponse {
     pub access_token: Option<String>,
     pub scope: Option<String>,
@@ -105,11 +105,41 @@ AccessError::InvalidPostType => "invalid_post_type: The method was called via a
 AccessError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 AccessError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 AccessError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        AccessError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        AccessError::Unknown(ref s) => return write!(f, "{}", s),
-                        AccessError::Client(ref inner) => return write!(f, "{}", inner),
+                        AccessError::MalformedResponse(_, ref e) => return write!(f, "oauth.access returned a malformed response: {}", e),
+                        AccessError::Unknown(ref s) => return write!(f, "oauth.access failed: {}", s),
+                        AccessError::Client(ref inner) => return write!(f, "oauth.access failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "oauth.access failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AccessError<E>> for crate::SlackError<E> {
+    fn from(e: AccessError<E>) -> Self {
+        let method = "oauth.access";
+        let error = match e {
+            AccessError::InvalidClientId => "invalid_client_id".to_owned(),
+            AccessError::BadClientSecret => "bad_client_secret".to_owned(),
+            AccessError::InvalidCode => "invalid_code".to_owned(),
+            AccessError::BadRedirectUri => "bad_redirect_uri".to_owned(),
+            AccessError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AccessError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AccessError::InvalidCharset => "invalid_charset".to_owned(),
+            AccessError::InvalidFormData => "invalid_form_data".to_owned(),
+            AccessError::InvalidPostType => "invalid_post_type".to_owned(),
+            AccessError::MissingPostType => "missing_post_type".to_owned(),
+            AccessError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AccessError::RequestTimeout => "request_timeout".to_owned(),
+            AccessError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AccessError::Unknown(s) => s,
+            AccessError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
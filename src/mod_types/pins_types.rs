@@ -30,19 +30,19 @@ pub struct AddRequest<'a> {
     pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
-    fn into(self) -> Result<AddResponse, AddError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -147,11 +147,49 @@ AddError::InvalidPostType => "invalid_post_type: The method was called via a POS
 AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        AddError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        AddError::Unknown(ref s) => return write!(f, "{}", s),
-                        AddError::Client(ref inner) => return write!(f, "{}", inner),
+                        AddError::MalformedResponse(_, ref e) => return write!(f, "pins.add returned a malformed response: {}", e),
+                        AddError::Unknown(ref s) => return write!(f, "pins.add failed: {}", s),
+                        AddError::Client(ref inner) => return write!(f, "pins.add failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "pins.add failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(e: AddError<E>) -> Self {
+        let method = "pins.add";
+        let error = match e {
+            AddError::BadTimestamp => "bad_timestamp".to_owned(),
+            AddError::FileNotFound => "file_not_found".to_owned(),
+            AddError::FileCommentNotFound => "file_comment_not_found".to_owned(),
+            AddError::MessageNotFound => "message_not_found".to_owned(),
+            AddError::ChannelNotFound => "channel_not_found".to_owned(),
+            AddError::NoItemSpecified => "no_item_specified".to_owned(),
+            AddError::AlreadyPinned => "already_pinned".to_owned(),
+            AddError::PermissionDenied => "permission_denied".to_owned(),
+            AddError::FileNotShared => "file_not_shared".to_owned(),
+            AddError::NotAuthed => "not_authed".to_owned(),
+            AddError::InvalidAuth => "invalid_auth".to_owned(),
+            AddError::AccountInactive => "account_inactive".to_owned(),
+            AddError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddError::InvalidCharset => "invalid_charset".to_owned(),
+            AddError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddError::MissingPostType => "missing_post_type".to_owned(),
+            AddError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddError::RequestTimeout => "request_timeout".to_owned(),
+            AddError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddError::Unknown(s) => s,
+            AddError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -171,7 +209,7 @@ pub struct ListRequest<'a> {
     pub channel: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponse {
     error: Option<String>,
     pub items: Option<Vec<ListResponseItem>>,
@@ -179,7 +217,8 @@ pub struct ListResponse {
     ok: bool,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum ListResponseItem {
     Message(ListResponseItemMessage),
     File(ListResponseItemFile),
@@ -201,14 +240,14 @@ impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
                 match ty {
                     "message" => ::serde_json::from_value::<ListResponseItemMessage>(value.clone())
                         .map(ListResponseItem::Message)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "file" => ::serde_json::from_value::<ListResponseItemFile>(value.clone())
                         .map(ListResponseItem::File)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "file_comment" => {
                         ::serde_json::from_value::<ListResponseItemFileComment>(value.clone())
                             .map(ListResponseItem::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
                 }
@@ -224,41 +263,38 @@ impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponseItemFile {
     pub created: Option<f32>,
     pub created_by: Option<String>,
     pub file: crate::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponseItemFileComment {
     pub comment: crate::FileComment,
     pub created: Option<f32>,
     pub created_by: Option<String>,
     pub file: crate::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponseItemMessage {
     pub channel: String,
     pub created: Option<f32>,
     pub created_by: Option<String>,
     pub message: crate::Message,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub r#type: String,
 }
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -331,11 +367,41 @@ ListError::InvalidPostType => "invalid_post_type: The method was called via a PO
 ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ListError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ListError::Unknown(ref s) => return write!(f, "{}", s),
-                        ListError::Client(ref inner) => return write!(f, "{}", inner),
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "pins.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "pins.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "pins.list failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "pins.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "pins.list";
+        let error = match e {
+            ListError::ChannelNotFound => "channel_not_found".to_owned(),
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -361,19 +427,19 @@ pub struct RemoveRequest<'a> {
     pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RemoveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
-    fn into(self) -> Result<RemoveResponse, RemoveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RemoveResponse> for Result<RemoveResponse, RemoveError<E>> {
+    fn from(response: RemoveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -470,11 +536,47 @@ RemoveError::InvalidPostType => "invalid_post_type: The method was called via a
 RemoveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 RemoveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 RemoveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        RemoveError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        RemoveError::Unknown(ref s) => return write!(f, "{}", s),
-                        RemoveError::Client(ref inner) => return write!(f, "{}", inner),
+                        RemoveError::MalformedResponse(_, ref e) => return write!(f, "pins.remove returned a malformed response: {}", e),
+                        RemoveError::Unknown(ref s) => return write!(f, "pins.remove failed: {}", s),
+                        RemoveError::Client(ref inner) => return write!(f, "pins.remove failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "pins.remove failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RemoveError<E>> for crate::SlackError<E> {
+    fn from(e: RemoveError<E>) -> Self {
+        let method = "pins.remove";
+        let error = match e {
+            RemoveError::BadTimestamp => "bad_timestamp".to_owned(),
+            RemoveError::FileNotFound => "file_not_found".to_owned(),
+            RemoveError::FileCommentNotFound => "file_comment_not_found".to_owned(),
+            RemoveError::MessageNotFound => "message_not_found".to_owned(),
+            RemoveError::NoItemSpecified => "no_item_specified".to_owned(),
+            RemoveError::NotPinned => "not_pinned".to_owned(),
+            RemoveError::PermissionDenied => "permission_denied".to_owned(),
+            RemoveError::NotAuthed => "not_authed".to_owned(),
+            RemoveError::InvalidAuth => "invalid_auth".to_owned(),
+            RemoveError::AccountInactive => "account_inactive".to_owned(),
+            RemoveError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RemoveError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RemoveError::InvalidCharset => "invalid_charset".to_owned(),
+            RemoveError::InvalidFormData => "invalid_form_data".to_owned(),
+            RemoveError::InvalidPostType => "invalid_post_type".to_owned(),
+            RemoveError::MissingPostType => "missing_post_type".to_owned(),
+            RemoveError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RemoveError::RequestTimeout => "request_timeout".to_owned(),
+            RemoveError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RemoveError::Unknown(s) => s,
+            RemoveError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
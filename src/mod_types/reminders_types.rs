@@ -28,7 +28,7 @@ pub struct AddRequest<'a> {
     pub user: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
@@ -36,12 +36,12 @@ pub struct AddResponse {
     pub reminder: Option<crate::Reminder>,
 }
 
-impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
-    fn into(self) -> Result<AddResponse, AddError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -138,11 +138,47 @@ AddError::InvalidPostType => "invalid_post_type: The method was called via a POS
 AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        AddError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        AddError::Unknown(ref s) => return write!(f, "{}", s),
-                        AddError::Client(ref inner) => return write!(f, "{}", inner),
+                        AddError::MalformedResponse(_, ref e) => return write!(f, "reminders.add returned a malformed response: {}", e),
+                        AddError::Unknown(ref s) => return write!(f, "reminders.add failed: {}", s),
+                        AddError::Client(ref inner) => return write!(f, "reminders.add failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "reminders.add failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(e: AddError<E>) -> Self {
+        let method = "reminders.add";
+        let error = match e {
+            AddError::CannotParse => "cannot_parse".to_owned(),
+            AddError::UserNotFound => "user_not_found".to_owned(),
+            AddError::CannotAddBot => "cannot_add_bot".to_owned(),
+            AddError::CannotAddSlackbot => "cannot_add_slackbot".to_owned(),
+            AddError::CannotAddOthers => "cannot_add_others".to_owned(),
+            AddError::CannotAddOthersRecurring => "cannot_add_others_recurring".to_owned(),
+            AddError::NotAuthed => "not_authed".to_owned(),
+            AddError::InvalidAuth => "invalid_auth".to_owned(),
+            AddError::AccountInactive => "account_inactive".to_owned(),
+            AddError::UserIsBot => "user_is_bot".to_owned(),
+            AddError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddError::InvalidCharset => "invalid_charset".to_owned(),
+            AddError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddError::MissingPostType => "missing_post_type".to_owned(),
+            AddError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddError::RequestTimeout => "request_timeout".to_owned(),
+            AddError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddError::Unknown(s) => s,
+            AddError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -162,19 +198,19 @@ pub struct CompleteRequest<'a> {
     pub reminder: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CompleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<CompleteResponse, CompleteError<E>>> for CompleteResponse {
-    fn into(self) -> Result<CompleteResponse, CompleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CompleteResponse> for Result<CompleteResponse, CompleteError<E>> {
+    fn from(response: CompleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -259,11 +295,44 @@ CompleteError::InvalidPostType => "invalid_post_type: The method was called via
 CompleteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 CompleteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 CompleteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        CompleteError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        CompleteError::Unknown(ref s) => return write!(f, "{}", s),
-                        CompleteError::Client(ref inner) => return write!(f, "{}", inner),
+                        CompleteError::MalformedResponse(_, ref e) => return write!(f, "reminders.complete returned a malformed response: {}", e),
+                        CompleteError::Unknown(ref s) => return write!(f, "reminders.complete failed: {}", s),
+                        CompleteError::Client(ref inner) => return write!(f, "reminders.complete failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "reminders.complete failed: {}", d)
+    }
+}
+
+impl<E: Error> From<CompleteError<E>> for crate::SlackError<E> {
+    fn from(e: CompleteError<E>) -> Self {
+        let method = "reminders.complete";
+        let error = match e {
+            CompleteError::NotFound => "not_found".to_owned(),
+            CompleteError::CannotCompleteRecurring => "cannot_complete_recurring".to_owned(),
+            CompleteError::CannotCompleteOthers => "cannot_complete_others".to_owned(),
+            CompleteError::NotAuthed => "not_authed".to_owned(),
+            CompleteError::InvalidAuth => "invalid_auth".to_owned(),
+            CompleteError::AccountInactive => "account_inactive".to_owned(),
+            CompleteError::UserIsBot => "user_is_bot".to_owned(),
+            CompleteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            CompleteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            CompleteError::InvalidCharset => "invalid_charset".to_owned(),
+            CompleteError::InvalidFormData => "invalid_form_data".to_owned(),
+            CompleteError::InvalidPostType => "invalid_post_type".to_owned(),
+            CompleteError::MissingPostType => "missing_post_type".to_owned(),
+            CompleteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            CompleteError::RequestTimeout => "request_timeout".to_owned(),
+            CompleteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            CompleteError::Unknown(s) => s,
+            CompleteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -283,19 +352,19 @@ pub struct DeleteRequest<'a> {
     pub reminder: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct DeleteResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<DeleteResponse, DeleteError<E>>> for DeleteResponse {
-    fn into(self) -> Result<DeleteResponse, DeleteError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DeleteResponse> for Result<DeleteResponse, DeleteError<E>> {
+    fn from(response: DeleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -372,11 +441,42 @@ DeleteError::InvalidPostType => "invalid_post_type: The method was called via a
 DeleteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 DeleteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 DeleteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        DeleteError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        DeleteError::Unknown(ref s) => return write!(f, "{}", s),
-                        DeleteError::Client(ref inner) => return write!(f, "{}", inner),
+                        DeleteError::MalformedResponse(_, ref e) => return write!(f, "reminders.delete returned a malformed response: {}", e),
+                        DeleteError::Unknown(ref s) => return write!(f, "reminders.delete failed: {}", s),
+                        DeleteError::Client(ref inner) => return write!(f, "reminders.delete failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "reminders.delete failed: {}", d)
+    }
+}
+
+impl<E: Error> From<DeleteError<E>> for crate::SlackError<E> {
+    fn from(e: DeleteError<E>) -> Self {
+        let method = "reminders.delete";
+        let error = match e {
+            DeleteError::NotFound => "not_found".to_owned(),
+            DeleteError::NotAuthed => "not_authed".to_owned(),
+            DeleteError::InvalidAuth => "invalid_auth".to_owned(),
+            DeleteError::AccountInactive => "account_inactive".to_owned(),
+            DeleteError::UserIsBot => "user_is_bot".to_owned(),
+            DeleteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            DeleteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            DeleteError::InvalidCharset => "invalid_charset".to_owned(),
+            DeleteError::InvalidFormData => "invalid_form_data".to_owned(),
+            DeleteError::InvalidPostType => "invalid_post_type".to_owned(),
+            DeleteError::MissingPostType => "missing_post_type".to_owned(),
+            DeleteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            DeleteError::RequestTimeout => "request_timeout".to_owned(),
+            DeleteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            DeleteError::Unknown(s) => s,
+            DeleteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -396,7 +496,7 @@ pub struct InfoRequest<'a> {
     pub reminder: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InfoResponse {
     error: Option<String>,
     #[serde(default)]
@@ -404,12 +504,12 @@ pub struct InfoResponse {
     pub reminder: Option<crate::Reminder>,
 }
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -486,11 +586,42 @@ InfoError::InvalidPostType => "invalid_post_type: The method was called via a PO
 InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        InfoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        InfoError::Unknown(ref s) => return write!(f, "{}", s),
-                        InfoError::Client(ref inner) => return write!(f, "{}", inner),
+                        InfoError::MalformedResponse(_, ref e) => return write!(f, "reminders.info returned a malformed response: {}", e),
+                        InfoError::Unknown(ref s) => return write!(f, "reminders.info failed: {}", s),
+                        InfoError::Client(ref inner) => return write!(f, "reminders.info failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "reminders.info failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(e: InfoError<E>) -> Self {
+        let method = "reminders.info";
+        let error = match e {
+            InfoError::NotFound => "not_found".to_owned(),
+            InfoError::NotAuthed => "not_authed".to_owned(),
+            InfoError::InvalidAuth => "invalid_auth".to_owned(),
+            InfoError::AccountInactive => "account_inactive".to_owned(),
+            InfoError::UserIsBot => "user_is_bot".to_owned(),
+            InfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InfoError::InvalidCharset => "invalid_charset".to_owned(),
+            InfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            InfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            InfoError::MissingPostType => "missing_post_type".to_owned(),
+            InfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InfoError::RequestTimeout => "request_timeout".to_owned(),
+            InfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InfoError::Unknown(s) => s,
+            InfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -504,7 +635,7 @@ impl<E: Error + 'static> Error for InfoError<E> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponse {
     error: Option<String>,
     #[serde(default)]
@@ -512,12 +643,12 @@ pub struct ListResponse {
     pub reminders: Option<Vec<crate::Reminder>>,
 }
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -590,11 +721,41 @@ ListError::InvalidPostType => "invalid_post_type: The method was called via a PO
 ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ListError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ListError::Unknown(ref s) => return write!(f, "{}", s),
-                        ListError::Client(ref inner) => return write!(f, "{}", inner),
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "reminders.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "reminders.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "reminders.list failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "reminders.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "reminders.list";
+        let error = match e {
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::UserIsBot => "user_is_bot".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
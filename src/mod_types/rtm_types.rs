@@ -18,7 +18,7 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ConnectResponse {
     error: Option<String>,
     #[serde(default)]
@@ -29,13 +29,13 @@ pub struct ConnectResponse {
     pub url: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ConnectResponseSelf {
     pub id: Option<String>,
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ConnectResponseTeam {
     pub domain: Option<String>,
     pub enterprise_id: Option<String>,
@@ -44,12 +44,12 @@ pub struct ConnectResponseTeam {
     pub name: Option<String>,
 }
 
-impl<E: Error> Into<Result<ConnectResponse, ConnectError<E>>> for ConnectResponse {
-    fn into(self) -> Result<ConnectResponse, ConnectError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ConnectResponse> for Result<ConnectResponse, ConnectError<E>> {
+    fn from(response: ConnectResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -118,11 +118,40 @@ ConnectError::InvalidPostType => "invalid_post_type: The method was called via a
 ConnectError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ConnectError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ConnectError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ConnectError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ConnectError::Unknown(ref s) => return write!(f, "{}", s),
-                        ConnectError::Client(ref inner) => return write!(f, "{}", inner),
+                        ConnectError::MalformedResponse(_, ref e) => return write!(f, "rtm.connect returned a malformed response: {}", e),
+                        ConnectError::Unknown(ref s) => return write!(f, "rtm.connect failed: {}", s),
+                        ConnectError::Client(ref inner) => return write!(f, "rtm.connect failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "rtm.connect failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ConnectError<E>> for crate::SlackError<E> {
+    fn from(e: ConnectError<E>) -> Self {
+        let method = "rtm.connect";
+        let error = match e {
+            ConnectError::NotAuthed => "not_authed".to_owned(),
+            ConnectError::InvalidAuth => "invalid_auth".to_owned(),
+            ConnectError::AccountInactive => "account_inactive".to_owned(),
+            ConnectError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ConnectError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ConnectError::InvalidCharset => "invalid_charset".to_owned(),
+            ConnectError::InvalidFormData => "invalid_form_data".to_owned(),
+            ConnectError::InvalidPostType => "invalid_post_type".to_owned(),
+            ConnectError::MissingPostType => "missing_post_type".to_owned(),
+            ConnectError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ConnectError::RequestTimeout => "request_timeout".to_owned(),
+            ConnectError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ConnectError::Unknown(s) => s,
+            ConnectError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -150,7 +179,7 @@ pub struct StartRequest {
     pub include_locale: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct StartResponse {
     pub bots: Option<Vec<crate::Bot>>,
     pub channels: Option<Vec<crate::Channel>>,
@@ -167,12 +196,12 @@ pub struct StartResponse {
     pub users: Option<Vec<crate::User>>,
 }
 
-impl<E: Error> Into<Result<StartResponse, StartError<E>>> for StartResponse {
-    fn into(self) -> Result<StartResponse, StartError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<StartResponse> for Result<StartResponse, StartError<E>> {
+    fn from(response: StartResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -245,11 +274,41 @@ StartError::InvalidPostType => "invalid_post_type: The method was called via a P
 StartError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 StartError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 StartError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        StartError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        StartError::Unknown(ref s) => return write!(f, "{}", s),
-                        StartError::Client(ref inner) => return write!(f, "{}", inner),
+                        StartError::MalformedResponse(_, ref e) => return write!(f, "rtm.start returned a malformed response: {}", e),
+                        StartError::Unknown(ref s) => return write!(f, "rtm.start failed: {}", s),
+                        StartError::Client(ref inner) => return write!(f, "rtm.start failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "rtm.start failed: {}", d)
+    }
+}
+
+impl<E: Error> From<StartError<E>> for crate::SlackError<E> {
+    fn from(e: StartError<E>) -> Self {
+        let method = "rtm.start";
+        let error = match e {
+            StartError::MigrationInProgress => "migration_in_progress".to_owned(),
+            StartError::NotAuthed => "not_authed".to_owned(),
+            StartError::InvalidAuth => "invalid_auth".to_owned(),
+            StartError::AccountInactive => "account_inactive".to_owned(),
+            StartError::InvalidArgName => "invalid_arg_name".to_owned(),
+            StartError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            StartError::InvalidCharset => "invalid_charset".to_owned(),
+            StartError::InvalidFormData => "invalid_form_data".to_owned(),
+            StartError::InvalidPostType => "invalid_post_type".to_owned(),
+            StartError::MissingPostType => "missing_post_type".to_owned(),
+            StartError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            StartError::RequestTimeout => "request_timeout".to_owned(),
+            StartError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            StartError::Unknown(s) => s,
+            StartError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -36,7 +36,7 @@ pub struct AllRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AllResponse {
     error: Option<String>,
     pub files: Option<AllResponseFiles>,
@@ -46,24 +46,36 @@ pub struct AllResponse {
     pub query: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AllResponseFiles {
     pub matches: Vec<crate::File>,
     pub paging: crate::Paging,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::HasPaging for AllResponseFiles {
+    fn paging(&self) -> Option<&crate::Paging> {
+        Some(&self.paging)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AllResponseMessages {
     pub matches: Vec<crate::Message>,
     pub paging: crate::Paging,
 }
 
-impl<E: Error> Into<Result<AllResponse, AllError<E>>> for AllResponse {
-    fn into(self) -> Result<AllResponse, AllError<E>> {
-        if self.ok {
-            Ok(self)
+impl crate::HasPaging for AllResponseMessages {
+    fn paging(&self) -> Option<&crate::Paging> {
+        Some(&self.paging)
+    }
+}
+
+impl<E: Error> From<AllResponse> for Result<AllResponse, AllError<E>> {
+    fn from(response: AllResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -136,11 +148,41 @@ AllError::InvalidPostType => "invalid_post_type: The method was called via a POS
 AllError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 AllError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 AllError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        AllError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        AllError::Unknown(ref s) => return write!(f, "{}", s),
-                        AllError::Client(ref inner) => return write!(f, "{}", inner),
+                        AllError::MalformedResponse(_, ref e) => return write!(f, "search.all returned a malformed response: {}", e),
+                        AllError::Unknown(ref s) => return write!(f, "search.all failed: {}", s),
+                        AllError::Client(ref inner) => return write!(f, "search.all failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "search.all failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AllError<E>> for crate::SlackError<E> {
+    fn from(e: AllError<E>) -> Self {
+        let method = "search.all";
+        let error = match e {
+            AllError::NotAuthed => "not_authed".to_owned(),
+            AllError::InvalidAuth => "invalid_auth".to_owned(),
+            AllError::AccountInactive => "account_inactive".to_owned(),
+            AllError::UserIsBot => "user_is_bot".to_owned(),
+            AllError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AllError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AllError::InvalidCharset => "invalid_charset".to_owned(),
+            AllError::InvalidFormData => "invalid_form_data".to_owned(),
+            AllError::InvalidPostType => "invalid_post_type".to_owned(),
+            AllError::MissingPostType => "missing_post_type".to_owned(),
+            AllError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AllError::RequestTimeout => "request_timeout".to_owned(),
+            AllError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AllError::Unknown(s) => s,
+            AllError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -170,7 +212,7 @@ pub struct FilesRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct FilesResponse {
     error: Option<String>,
     pub files: Option<FilesResponseFiles>,
@@ -179,19 +221,25 @@ pub struct FilesResponse {
     pub query: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct FilesResponseFiles {
     pub matches: Option<Vec<crate::File>>,
     pub paging: Option<crate::Paging>,
     pub total: Option<i32>,
 }
 
-impl<E: Error> Into<Result<FilesResponse, FilesError<E>>> for FilesResponse {
-    fn into(self) -> Result<FilesResponse, FilesError<E>> {
-        if self.ok {
-            Ok(self)
+impl crate::HasPaging for FilesResponseFiles {
+    fn paging(&self) -> Option<&crate::Paging> {
+        self.paging.as_ref()
+    }
+}
+
+impl<E: Error> From<FilesResponse> for Result<FilesResponse, FilesError<E>> {
+    fn from(response: FilesResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -264,11 +312,41 @@ FilesError::InvalidPostType => "invalid_post_type: The method was called via a P
 FilesError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 FilesError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 FilesError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        FilesError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        FilesError::Unknown(ref s) => return write!(f, "{}", s),
-                        FilesError::Client(ref inner) => return write!(f, "{}", inner),
+                        FilesError::MalformedResponse(_, ref e) => return write!(f, "search.files returned a malformed response: {}", e),
+                        FilesError::Unknown(ref s) => return write!(f, "search.files failed: {}", s),
+                        FilesError::Client(ref inner) => return write!(f, "search.files failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "search.files failed: {}", d)
+    }
+}
+
+impl<E: Error> From<FilesError<E>> for crate::SlackError<E> {
+    fn from(e: FilesError<E>) -> Self {
+        let method = "search.files";
+        let error = match e {
+            FilesError::NotAuthed => "not_authed".to_owned(),
+            FilesError::InvalidAuth => "invalid_auth".to_owned(),
+            FilesError::AccountInactive => "account_inactive".to_owned(),
+            FilesError::UserIsBot => "user_is_bot".to_owned(),
+            FilesError::InvalidArgName => "invalid_arg_name".to_owned(),
+            FilesError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            FilesError::InvalidCharset => "invalid_charset".to_owned(),
+            FilesError::InvalidFormData => "invalid_form_data".to_owned(),
+            FilesError::InvalidPostType => "invalid_post_type".to_owned(),
+            FilesError::MissingPostType => "missing_post_type".to_owned(),
+            FilesError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            FilesError::RequestTimeout => "request_timeout".to_owned(),
+            FilesError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            FilesError::Unknown(s) => s,
+            FilesError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -298,7 +376,7 @@ pub struct MessagesRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessagesResponse {
     error: Option<String>,
     pub messages: Option<MessagesResponseMessages>,
@@ -307,19 +385,25 @@ pub struct MessagesResponse {
     pub query: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessagesResponseMessages {
     pub matches: Option<Vec<crate::Message>>,
     pub paging: Option<crate::Paging>,
     pub total: Option<i32>,
 }
 
-impl<E: Error> Into<Result<MessagesResponse, MessagesError<E>>> for MessagesResponse {
-    fn into(self) -> Result<MessagesResponse, MessagesError<E>> {
-        if self.ok {
-            Ok(self)
+impl crate::HasPaging for MessagesResponseMessages {
+    fn paging(&self) -> Option<&crate::Paging> {
+        self.paging.as_ref()
+    }
+}
+
+impl<E: Error> From<MessagesResponse> for Result<MessagesResponse, MessagesError<E>> {
+    fn from(response: MessagesResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -392,11 +476,41 @@ MessagesError::InvalidPostType => "invalid_post_type: The method was called via
 MessagesError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 MessagesError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 MessagesError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        MessagesError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        MessagesError::Unknown(ref s) => return write!(f, "{}", s),
-                        MessagesError::Client(ref inner) => return write!(f, "{}", inner),
+                        MessagesError::MalformedResponse(_, ref e) => return write!(f, "search.messages returned a malformed response: {}", e),
+                        MessagesError::Unknown(ref s) => return write!(f, "search.messages failed: {}", s),
+                        MessagesError::Client(ref inner) => return write!(f, "search.messages failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "search.messages failed: {}", d)
+    }
+}
+
+impl<E: Error> From<MessagesError<E>> for crate::SlackError<E> {
+    fn from(e: MessagesError<E>) -> Self {
+        let method = "search.messages";
+        let error = match e {
+            MessagesError::NotAuthed => "not_authed".to_owned(),
+            MessagesError::InvalidAuth => "invalid_auth".to_owned(),
+            MessagesError::AccountInactive => "account_inactive".to_owned(),
+            MessagesError::UserIsBot => "user_is_bot".to_owned(),
+            MessagesError::InvalidArgName => "invalid_arg_name".to_owned(),
+            MessagesError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            MessagesError::InvalidCharset => "invalid_charset".to_owned(),
+            MessagesError::InvalidFormData => "invalid_form_data".to_owned(),
+            MessagesError::InvalidPostType => "invalid_post_type".to_owned(),
+            MessagesError::MissingPostType => "missing_post_type".to_owned(),
+            MessagesError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            MessagesError::RequestTimeout => "request_timeout".to_owned(),
+            MessagesError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            MessagesError::Unknown(s) => s,
+            MessagesError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
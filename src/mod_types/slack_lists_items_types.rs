@@ -0,0 +1,1017 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Add, update and remove the items of a list.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct CreateRequest<'a> {
+    /// The list.
+    pub list_id: &'a str,
+    /// An item to copy the fields of.
+    pub duplicated_item_id: Option<&'a str>,
+    /// The fields of the new item.
+    pub initial_fields: Option<&'a [crate::ListField]>,
+    /// The item to add the new item to as a subtask.
+    pub parent_item_id: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CreateResponse {
+    error: Option<String>,
+    pub item: Option<crate::ListItem>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<CreateResponse> for Result<CreateResponse, CreateError<E>> {
+    fn from(response: CreateResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CreateError<E: Error> {
+    /// The list wasn't found.
+    ListNotFound,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// A field's value didn't match the type of its column.
+    InvalidField,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CreateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "list_not_found" => CreateError::ListNotFound,
+            "not_allowed_token_type" => CreateError::NotAllowedTokenType,
+            "missing_scope" => CreateError::MissingScope,
+            "ratelimited" => CreateError::Ratelimited,
+            "invalid_field" => CreateError::InvalidField,
+            "not_authed" => CreateError::NotAuthed,
+            "invalid_auth" => CreateError::InvalidAuth,
+            "account_inactive" => CreateError::AccountInactive,
+            "invalid_arg_name" => CreateError::InvalidArgName,
+            "invalid_array_arg" => CreateError::InvalidArrayArg,
+            "invalid_charset" => CreateError::InvalidCharset,
+            "invalid_form_data" => CreateError::InvalidFormData,
+            "invalid_post_type" => CreateError::InvalidPostType,
+            "missing_post_type" => CreateError::MissingPostType,
+            "team_added_to_org" => CreateError::TeamAddedToOrg,
+            "request_timeout" => CreateError::RequestTimeout,
+            _ => CreateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for CreateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        CreateError::ListNotFound => "list_not_found: The list wasn't found.",
+CreateError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+CreateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+CreateError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+CreateError::InvalidField => "invalid_field: A field's value didn't match the type of its column.",
+CreateError::NotAuthed => "not_authed: No authentication token provided.",
+CreateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+CreateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+CreateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+CreateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+CreateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+CreateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+CreateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+CreateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+CreateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+CreateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        CreateError::MalformedResponse(_, ref e) => return write!(f, "slackLists.items.create returned a malformed response: {}", e),
+                        CreateError::Unknown(ref s) => return write!(f, "slackLists.items.create failed: {}", s),
+                        CreateError::Client(ref inner) => return write!(f, "slackLists.items.create failed: {}", inner),
+                    };
+        write!(f, "slackLists.items.create failed: {}", d)
+    }
+}
+
+impl<E: Error> From<CreateError<E>> for crate::SlackError<E> {
+    fn from(e: CreateError<E>) -> Self {
+        let method = "slackLists.items.create";
+        let error = match e {
+            CreateError::ListNotFound => "list_not_found".to_owned(),
+            CreateError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            CreateError::MissingScope => "missing_scope".to_owned(),
+            CreateError::Ratelimited => "ratelimited".to_owned(),
+            CreateError::InvalidField => "invalid_field".to_owned(),
+            CreateError::NotAuthed => "not_authed".to_owned(),
+            CreateError::InvalidAuth => "invalid_auth".to_owned(),
+            CreateError::AccountInactive => "account_inactive".to_owned(),
+            CreateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            CreateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            CreateError::InvalidCharset => "invalid_charset".to_owned(),
+            CreateError::InvalidFormData => "invalid_form_data".to_owned(),
+            CreateError::InvalidPostType => "invalid_post_type".to_owned(),
+            CreateError::MissingPostType => "missing_post_type".to_owned(),
+            CreateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            CreateError::RequestTimeout => "request_timeout".to_owned(),
+            CreateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            CreateError::Unknown(s) => s,
+            CreateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for CreateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CreateError::MalformedResponse(_, ref e) => Some(e),
+            CreateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct DeleteRequest<'a> {
+    /// The list.
+    pub list_id: &'a str,
+    /// The item.
+    pub id: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DeleteResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<DeleteResponse> for Result<DeleteResponse, DeleteError<E>> {
+    fn from(response: DeleteResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum DeleteError<E: Error> {
+    /// The list wasn't found.
+    ListNotFound,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The item wasn't found.
+    ItemNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for DeleteError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "list_not_found" => DeleteError::ListNotFound,
+            "not_allowed_token_type" => DeleteError::NotAllowedTokenType,
+            "missing_scope" => DeleteError::MissingScope,
+            "ratelimited" => DeleteError::Ratelimited,
+            "item_not_found" => DeleteError::ItemNotFound,
+            "not_authed" => DeleteError::NotAuthed,
+            "invalid_auth" => DeleteError::InvalidAuth,
+            "account_inactive" => DeleteError::AccountInactive,
+            "invalid_arg_name" => DeleteError::InvalidArgName,
+            "invalid_array_arg" => DeleteError::InvalidArrayArg,
+            "invalid_charset" => DeleteError::InvalidCharset,
+            "invalid_form_data" => DeleteError::InvalidFormData,
+            "invalid_post_type" => DeleteError::InvalidPostType,
+            "missing_post_type" => DeleteError::MissingPostType,
+            "team_added_to_org" => DeleteError::TeamAddedToOrg,
+            "request_timeout" => DeleteError::RequestTimeout,
+            _ => DeleteError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for DeleteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        DeleteError::ListNotFound => "list_not_found: The list wasn't found.",
+DeleteError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+DeleteError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+DeleteError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+DeleteError::ItemNotFound => "item_not_found: The item wasn't found.",
+DeleteError::NotAuthed => "not_authed: No authentication token provided.",
+DeleteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+DeleteError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+DeleteError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+DeleteError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+DeleteError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+DeleteError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+DeleteError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+DeleteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+DeleteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+DeleteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        DeleteError::MalformedResponse(_, ref e) => return write!(f, "slackLists.items.delete returned a malformed response: {}", e),
+                        DeleteError::Unknown(ref s) => return write!(f, "slackLists.items.delete failed: {}", s),
+                        DeleteError::Client(ref inner) => return write!(f, "slackLists.items.delete failed: {}", inner),
+                    };
+        write!(f, "slackLists.items.delete failed: {}", d)
+    }
+}
+
+impl<E: Error> From<DeleteError<E>> for crate::SlackError<E> {
+    fn from(e: DeleteError<E>) -> Self {
+        let method = "slackLists.items.delete";
+        let error = match e {
+            DeleteError::ListNotFound => "list_not_found".to_owned(),
+            DeleteError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            DeleteError::MissingScope => "missing_scope".to_owned(),
+            DeleteError::Ratelimited => "ratelimited".to_owned(),
+            DeleteError::ItemNotFound => "item_not_found".to_owned(),
+            DeleteError::NotAuthed => "not_authed".to_owned(),
+            DeleteError::InvalidAuth => "invalid_auth".to_owned(),
+            DeleteError::AccountInactive => "account_inactive".to_owned(),
+            DeleteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            DeleteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            DeleteError::InvalidCharset => "invalid_charset".to_owned(),
+            DeleteError::InvalidFormData => "invalid_form_data".to_owned(),
+            DeleteError::InvalidPostType => "invalid_post_type".to_owned(),
+            DeleteError::MissingPostType => "missing_post_type".to_owned(),
+            DeleteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            DeleteError::RequestTimeout => "request_timeout".to_owned(),
+            DeleteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            DeleteError::Unknown(s) => s,
+            DeleteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for DeleteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            DeleteError::MalformedResponse(_, ref e) => Some(e),
+            DeleteError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct DeleteMultipleRequest<'a> {
+    /// The list.
+    pub list_id: &'a str,
+    /// The items.
+    pub ids: &'a [&'a str],
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct DeleteMultipleResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<DeleteMultipleResponse>
+    for Result<DeleteMultipleResponse, DeleteMultipleError<E>>
+{
+    fn from(response: DeleteMultipleResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum DeleteMultipleError<E: Error> {
+    /// The list wasn't found.
+    ListNotFound,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The item wasn't found.
+    ItemNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for DeleteMultipleError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "list_not_found" => DeleteMultipleError::ListNotFound,
+            "not_allowed_token_type" => DeleteMultipleError::NotAllowedTokenType,
+            "missing_scope" => DeleteMultipleError::MissingScope,
+            "ratelimited" => DeleteMultipleError::Ratelimited,
+            "item_not_found" => DeleteMultipleError::ItemNotFound,
+            "not_authed" => DeleteMultipleError::NotAuthed,
+            "invalid_auth" => DeleteMultipleError::InvalidAuth,
+            "account_inactive" => DeleteMultipleError::AccountInactive,
+            "invalid_arg_name" => DeleteMultipleError::InvalidArgName,
+            "invalid_array_arg" => DeleteMultipleError::InvalidArrayArg,
+            "invalid_charset" => DeleteMultipleError::InvalidCharset,
+            "invalid_form_data" => DeleteMultipleError::InvalidFormData,
+            "invalid_post_type" => DeleteMultipleError::InvalidPostType,
+            "missing_post_type" => DeleteMultipleError::MissingPostType,
+            "team_added_to_org" => DeleteMultipleError::TeamAddedToOrg,
+            "request_timeout" => DeleteMultipleError::RequestTimeout,
+            _ => DeleteMultipleError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for DeleteMultipleError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        DeleteMultipleError::ListNotFound => "list_not_found: The list wasn't found.",
+DeleteMultipleError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+DeleteMultipleError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+DeleteMultipleError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+DeleteMultipleError::ItemNotFound => "item_not_found: The item wasn't found.",
+DeleteMultipleError::NotAuthed => "not_authed: No authentication token provided.",
+DeleteMultipleError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+DeleteMultipleError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+DeleteMultipleError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+DeleteMultipleError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+DeleteMultipleError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+DeleteMultipleError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+DeleteMultipleError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+DeleteMultipleError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+DeleteMultipleError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+DeleteMultipleError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        DeleteMultipleError::MalformedResponse(_, ref e) => return write!(f, "slackLists.items.deleteMultiple returned a malformed response: {}", e),
+                        DeleteMultipleError::Unknown(ref s) => return write!(f, "slackLists.items.deleteMultiple failed: {}", s),
+                        DeleteMultipleError::Client(ref inner) => return write!(f, "slackLists.items.deleteMultiple failed: {}", inner),
+                    };
+        write!(f, "slackLists.items.deleteMultiple failed: {}", d)
+    }
+}
+
+impl<E: Error> From<DeleteMultipleError<E>> for crate::SlackError<E> {
+    fn from(e: DeleteMultipleError<E>) -> Self {
+        let method = "slackLists.items.deleteMultiple";
+        let error = match e {
+            DeleteMultipleError::ListNotFound => "list_not_found".to_owned(),
+            DeleteMultipleError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            DeleteMultipleError::MissingScope => "missing_scope".to_owned(),
+            DeleteMultipleError::Ratelimited => "ratelimited".to_owned(),
+            DeleteMultipleError::ItemNotFound => "item_not_found".to_owned(),
+            DeleteMultipleError::NotAuthed => "not_authed".to_owned(),
+            DeleteMultipleError::InvalidAuth => "invalid_auth".to_owned(),
+            DeleteMultipleError::AccountInactive => "account_inactive".to_owned(),
+            DeleteMultipleError::InvalidArgName => "invalid_arg_name".to_owned(),
+            DeleteMultipleError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            DeleteMultipleError::InvalidCharset => "invalid_charset".to_owned(),
+            DeleteMultipleError::InvalidFormData => "invalid_form_data".to_owned(),
+            DeleteMultipleError::InvalidPostType => "invalid_post_type".to_owned(),
+            DeleteMultipleError::MissingPostType => "missing_post_type".to_owned(),
+            DeleteMultipleError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            DeleteMultipleError::RequestTimeout => "request_timeout".to_owned(),
+            DeleteMultipleError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            DeleteMultipleError::Unknown(s) => s,
+            DeleteMultipleError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for DeleteMultipleError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            DeleteMultipleError::MalformedResponse(_, ref e) => Some(e),
+            DeleteMultipleError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct InfoRequest<'a> {
+    /// The list.
+    pub list_id: &'a str,
+    /// The item.
+    pub id: &'a str,
+    /// Whether to say if the calling user is subscribed to the item.
+    pub include_is_subscribed: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InfoResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub record: Option<crate::ListItem>,
+    pub subtasks: Option<Vec<crate::ListItem>>,
+}
+
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InfoError<E: Error> {
+    /// The list wasn't found.
+    ListNotFound,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The item wasn't found.
+    ItemNotFound,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InfoError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "list_not_found" => InfoError::ListNotFound,
+            "not_allowed_token_type" => InfoError::NotAllowedTokenType,
+            "missing_scope" => InfoError::MissingScope,
+            "ratelimited" => InfoError::Ratelimited,
+            "item_not_found" => InfoError::ItemNotFound,
+            "not_authed" => InfoError::NotAuthed,
+            "invalid_auth" => InfoError::InvalidAuth,
+            "account_inactive" => InfoError::AccountInactive,
+            "invalid_arg_name" => InfoError::InvalidArgName,
+            "invalid_array_arg" => InfoError::InvalidArrayArg,
+            "invalid_charset" => InfoError::InvalidCharset,
+            "invalid_form_data" => InfoError::InvalidFormData,
+            "invalid_post_type" => InfoError::InvalidPostType,
+            "missing_post_type" => InfoError::MissingPostType,
+            "team_added_to_org" => InfoError::TeamAddedToOrg,
+            "request_timeout" => InfoError::RequestTimeout,
+            _ => InfoError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for InfoError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        InfoError::ListNotFound => "list_not_found: The list wasn't found.",
+InfoError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+InfoError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+InfoError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+InfoError::ItemNotFound => "item_not_found: The item wasn't found.",
+InfoError::NotAuthed => "not_authed: No authentication token provided.",
+InfoError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+InfoError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+InfoError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+InfoError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+InfoError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+InfoError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+InfoError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        InfoError::MalformedResponse(_, ref e) => return write!(f, "slackLists.items.info returned a malformed response: {}", e),
+                        InfoError::Unknown(ref s) => return write!(f, "slackLists.items.info failed: {}", s),
+                        InfoError::Client(ref inner) => return write!(f, "slackLists.items.info failed: {}", inner),
+                    };
+        write!(f, "slackLists.items.info failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(e: InfoError<E>) -> Self {
+        let method = "slackLists.items.info";
+        let error = match e {
+            InfoError::ListNotFound => "list_not_found".to_owned(),
+            InfoError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            InfoError::MissingScope => "missing_scope".to_owned(),
+            InfoError::Ratelimited => "ratelimited".to_owned(),
+            InfoError::ItemNotFound => "item_not_found".to_owned(),
+            InfoError::NotAuthed => "not_authed".to_owned(),
+            InfoError::InvalidAuth => "invalid_auth".to_owned(),
+            InfoError::AccountInactive => "account_inactive".to_owned(),
+            InfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InfoError::InvalidCharset => "invalid_charset".to_owned(),
+            InfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            InfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            InfoError::MissingPostType => "missing_post_type".to_owned(),
+            InfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InfoError::RequestTimeout => "request_timeout".to_owned(),
+            InfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InfoError::Unknown(s) => s,
+            InfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for InfoError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            InfoError::MalformedResponse(_, ref e) => Some(e),
+            InfoError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct ListRequest<'a> {
+    /// The list.
+    pub list_id: &'a str,
+    /// Whether to list archived items instead.
+    pub archived: Option<bool>,
+    /// Set `cursor` to the `next_cursor` of a previous call's `response_metadata` to fetch the next page.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// The maximum number of items to return, up to 1000. Defaults to 100.
+    pub limit: Option<u32>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListResponse {
+    error: Option<String>,
+    pub items: Option<Vec<crate::ListItem>>,
+    #[serde(default)]
+    ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for ListResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum ListError<E: Error> {
+    /// The list wasn't found.
+    ListNotFound,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The cursor was invalid or expired.
+    InvalidCursor,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for ListError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "list_not_found" => ListError::ListNotFound,
+            "not_allowed_token_type" => ListError::NotAllowedTokenType,
+            "missing_scope" => ListError::MissingScope,
+            "ratelimited" => ListError::Ratelimited,
+            "invalid_cursor" => ListError::InvalidCursor,
+            "not_authed" => ListError::NotAuthed,
+            "invalid_auth" => ListError::InvalidAuth,
+            "account_inactive" => ListError::AccountInactive,
+            "invalid_arg_name" => ListError::InvalidArgName,
+            "invalid_array_arg" => ListError::InvalidArrayArg,
+            "invalid_charset" => ListError::InvalidCharset,
+            "invalid_form_data" => ListError::InvalidFormData,
+            "invalid_post_type" => ListError::InvalidPostType,
+            "missing_post_type" => ListError::MissingPostType,
+            "team_added_to_org" => ListError::TeamAddedToOrg,
+            "request_timeout" => ListError::RequestTimeout,
+            _ => ListError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for ListError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        ListError::ListNotFound => "list_not_found: The list wasn't found.",
+ListError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+ListError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+ListError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+ListError::InvalidCursor => "invalid_cursor: The cursor was invalid or expired.",
+ListError::NotAuthed => "not_authed: No authentication token provided.",
+ListError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+ListError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+ListError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+ListError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+ListError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+ListError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+ListError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "slackLists.items.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "slackLists.items.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "slackLists.items.list failed: {}", inner),
+                    };
+        write!(f, "slackLists.items.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "slackLists.items.list";
+        let error = match e {
+            ListError::ListNotFound => "list_not_found".to_owned(),
+            ListError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            ListError::MissingScope => "missing_scope".to_owned(),
+            ListError::Ratelimited => "ratelimited".to_owned(),
+            ListError::InvalidCursor => "invalid_cursor".to_owned(),
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for ListError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ListError::MalformedResponse(_, ref e) => Some(e),
+            ListError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UpdateRequest<'a> {
+    /// The list.
+    pub list_id: &'a str,
+    /// The fields to set, each naming its item and column.
+    pub cells: &'a [crate::ListCell],
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UpdateResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<UpdateResponse> for Result<UpdateResponse, UpdateError<E>> {
+    fn from(response: UpdateResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UpdateError<E: Error> {
+    /// The list wasn't found.
+    ListNotFound,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The item wasn't found.
+    ItemNotFound,
+    /// A field's value didn't match the type of its column.
+    InvalidField,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UpdateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "list_not_found" => UpdateError::ListNotFound,
+            "not_allowed_token_type" => UpdateError::NotAllowedTokenType,
+            "missing_scope" => UpdateError::MissingScope,
+            "ratelimited" => UpdateError::Ratelimited,
+            "item_not_found" => UpdateError::ItemNotFound,
+            "invalid_field" => UpdateError::InvalidField,
+            "not_authed" => UpdateError::NotAuthed,
+            "invalid_auth" => UpdateError::InvalidAuth,
+            "account_inactive" => UpdateError::AccountInactive,
+            "invalid_arg_name" => UpdateError::InvalidArgName,
+            "invalid_array_arg" => UpdateError::InvalidArrayArg,
+            "invalid_charset" => UpdateError::InvalidCharset,
+            "invalid_form_data" => UpdateError::InvalidFormData,
+            "invalid_post_type" => UpdateError::InvalidPostType,
+            "missing_post_type" => UpdateError::MissingPostType,
+            "team_added_to_org" => UpdateError::TeamAddedToOrg,
+            "request_timeout" => UpdateError::RequestTimeout,
+            _ => UpdateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for UpdateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        UpdateError::ListNotFound => "list_not_found: The list wasn't found.",
+UpdateError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+UpdateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+UpdateError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+UpdateError::ItemNotFound => "item_not_found: The item wasn't found.",
+UpdateError::InvalidField => "invalid_field: A field's value didn't match the type of its column.",
+UpdateError::NotAuthed => "not_authed: No authentication token provided.",
+UpdateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+UpdateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+UpdateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+UpdateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+UpdateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+UpdateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+UpdateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+UpdateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+UpdateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+UpdateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        UpdateError::MalformedResponse(_, ref e) => return write!(f, "slackLists.items.update returned a malformed response: {}", e),
+                        UpdateError::Unknown(ref s) => return write!(f, "slackLists.items.update failed: {}", s),
+                        UpdateError::Client(ref inner) => return write!(f, "slackLists.items.update failed: {}", inner),
+                    };
+        write!(f, "slackLists.items.update failed: {}", d)
+    }
+}
+
+impl<E: Error> From<UpdateError<E>> for crate::SlackError<E> {
+    fn from(e: UpdateError<E>) -> Self {
+        let method = "slackLists.items.update";
+        let error = match e {
+            UpdateError::ListNotFound => "list_not_found".to_owned(),
+            UpdateError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            UpdateError::MissingScope => "missing_scope".to_owned(),
+            UpdateError::Ratelimited => "ratelimited".to_owned(),
+            UpdateError::ItemNotFound => "item_not_found".to_owned(),
+            UpdateError::InvalidField => "invalid_field".to_owned(),
+            UpdateError::NotAuthed => "not_authed".to_owned(),
+            UpdateError::InvalidAuth => "invalid_auth".to_owned(),
+            UpdateError::AccountInactive => "account_inactive".to_owned(),
+            UpdateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            UpdateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            UpdateError::InvalidCharset => "invalid_charset".to_owned(),
+            UpdateError::InvalidFormData => "invalid_form_data".to_owned(),
+            UpdateError::InvalidPostType => "invalid_post_type".to_owned(),
+            UpdateError::MissingPostType => "missing_post_type".to_owned(),
+            UpdateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            UpdateError::RequestTimeout => "request_timeout".to_owned(),
+            UpdateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            UpdateError::Unknown(s) => s,
+            UpdateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for UpdateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UpdateError::MalformedResponse(_, ref e) => Some(e),
+            UpdateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,346 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Create and manage lists.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct CreateRequest<'a> {
+    /// The name of the list.
+    pub name: &'a str,
+    /// A list to copy the schema of.
+    pub copy_from_list_id: Option<&'a str>,
+    /// The description of the list, as rich text blocks.
+    pub description_blocks: Option<&'a [crate::blocks::Block]>,
+    /// Whether to copy the items of `copy_from_list_id` too.
+    pub include_copied_list_records: Option<bool>,
+    /// The columns of the list. One of them must be a primary text column.
+    pub schema: Option<&'a [crate::ListColumn]>,
+    /// Whether to add the completed, assignee and due date columns of a to-do list.
+    pub todo_mode: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct CreateResponse {
+    error: Option<String>,
+    pub list_id: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<CreateResponse> for Result<CreateResponse, CreateError<E>> {
+    fn from(response: CreateResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum CreateError<E: Error> {
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// The schema was malformed or had no primary column.
+    InvalidSchema,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for CreateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "not_allowed_token_type" => CreateError::NotAllowedTokenType,
+            "missing_scope" => CreateError::MissingScope,
+            "ratelimited" => CreateError::Ratelimited,
+            "invalid_schema" => CreateError::InvalidSchema,
+            "not_authed" => CreateError::NotAuthed,
+            "invalid_auth" => CreateError::InvalidAuth,
+            "account_inactive" => CreateError::AccountInactive,
+            "invalid_arg_name" => CreateError::InvalidArgName,
+            "invalid_array_arg" => CreateError::InvalidArrayArg,
+            "invalid_charset" => CreateError::InvalidCharset,
+            "invalid_form_data" => CreateError::InvalidFormData,
+            "invalid_post_type" => CreateError::InvalidPostType,
+            "missing_post_type" => CreateError::MissingPostType,
+            "team_added_to_org" => CreateError::TeamAddedToOrg,
+            "request_timeout" => CreateError::RequestTimeout,
+            _ => CreateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for CreateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        CreateError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+CreateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+CreateError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+CreateError::InvalidSchema => "invalid_schema: The schema was malformed or had no primary column.",
+CreateError::NotAuthed => "not_authed: No authentication token provided.",
+CreateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+CreateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+CreateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+CreateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+CreateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+CreateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+CreateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+CreateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+CreateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+CreateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        CreateError::MalformedResponse(_, ref e) => return write!(f, "slackLists.create returned a malformed response: {}", e),
+                        CreateError::Unknown(ref s) => return write!(f, "slackLists.create failed: {}", s),
+                        CreateError::Client(ref inner) => return write!(f, "slackLists.create failed: {}", inner),
+                    };
+        write!(f, "slackLists.create failed: {}", d)
+    }
+}
+
+impl<E: Error> From<CreateError<E>> for crate::SlackError<E> {
+    fn from(e: CreateError<E>) -> Self {
+        let method = "slackLists.create";
+        let error = match e {
+            CreateError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            CreateError::MissingScope => "missing_scope".to_owned(),
+            CreateError::Ratelimited => "ratelimited".to_owned(),
+            CreateError::InvalidSchema => "invalid_schema".to_owned(),
+            CreateError::NotAuthed => "not_authed".to_owned(),
+            CreateError::InvalidAuth => "invalid_auth".to_owned(),
+            CreateError::AccountInactive => "account_inactive".to_owned(),
+            CreateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            CreateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            CreateError::InvalidCharset => "invalid_charset".to_owned(),
+            CreateError::InvalidFormData => "invalid_form_data".to_owned(),
+            CreateError::InvalidPostType => "invalid_post_type".to_owned(),
+            CreateError::MissingPostType => "missing_post_type".to_owned(),
+            CreateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            CreateError::RequestTimeout => "request_timeout".to_owned(),
+            CreateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            CreateError::Unknown(s) => s,
+            CreateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for CreateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            CreateError::MalformedResponse(_, ref e) => Some(e),
+            CreateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Default, Debug)]
+pub struct UpdateRequest<'a> {
+    /// The list.
+    pub id: &'a str,
+    /// The new description, as rich text blocks.
+    pub description_blocks: Option<&'a [crate::blocks::Block]>,
+    /// The new name.
+    pub name: Option<&'a str>,
+    /// Whether the list is a to-do list.
+    pub todo_mode: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UpdateResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<UpdateResponse> for Result<UpdateResponse, UpdateError<E>> {
+    fn from(response: UpdateResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum UpdateError<E: Error> {
+    /// The list wasn't found.
+    ListNotFound,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for UpdateError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "list_not_found" => UpdateError::ListNotFound,
+            "not_allowed_token_type" => UpdateError::NotAllowedTokenType,
+            "missing_scope" => UpdateError::MissingScope,
+            "ratelimited" => UpdateError::Ratelimited,
+            "not_authed" => UpdateError::NotAuthed,
+            "invalid_auth" => UpdateError::InvalidAuth,
+            "account_inactive" => UpdateError::AccountInactive,
+            "invalid_arg_name" => UpdateError::InvalidArgName,
+            "invalid_array_arg" => UpdateError::InvalidArrayArg,
+            "invalid_charset" => UpdateError::InvalidCharset,
+            "invalid_form_data" => UpdateError::InvalidFormData,
+            "invalid_post_type" => UpdateError::InvalidPostType,
+            "missing_post_type" => UpdateError::MissingPostType,
+            "team_added_to_org" => UpdateError::TeamAddedToOrg,
+            "request_timeout" => UpdateError::RequestTimeout,
+            _ => UpdateError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for UpdateError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        UpdateError::ListNotFound => "list_not_found: The list wasn't found.",
+UpdateError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+UpdateError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+UpdateError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+UpdateError::NotAuthed => "not_authed: No authentication token provided.",
+UpdateError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+UpdateError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+UpdateError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+UpdateError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+UpdateError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+UpdateError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+UpdateError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+UpdateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+UpdateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+UpdateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        UpdateError::MalformedResponse(_, ref e) => return write!(f, "slackLists.update returned a malformed response: {}", e),
+                        UpdateError::Unknown(ref s) => return write!(f, "slackLists.update failed: {}", s),
+                        UpdateError::Client(ref inner) => return write!(f, "slackLists.update failed: {}", inner),
+                    };
+        write!(f, "slackLists.update failed: {}", d)
+    }
+}
+
+impl<E: Error> From<UpdateError<E>> for crate::SlackError<E> {
+    fn from(e: UpdateError<E>) -> Self {
+        let method = "slackLists.update";
+        let error = match e {
+            UpdateError::ListNotFound => "list_not_found".to_owned(),
+            UpdateError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            UpdateError::MissingScope => "missing_scope".to_owned(),
+            UpdateError::Ratelimited => "ratelimited".to_owned(),
+            UpdateError::NotAuthed => "not_authed".to_owned(),
+            UpdateError::InvalidAuth => "invalid_auth".to_owned(),
+            UpdateError::AccountInactive => "account_inactive".to_owned(),
+            UpdateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            UpdateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            UpdateError::InvalidCharset => "invalid_charset".to_owned(),
+            UpdateError::InvalidFormData => "invalid_form_data".to_owned(),
+            UpdateError::InvalidPostType => "invalid_post_type".to_owned(),
+            UpdateError::MissingPostType => "missing_post_type".to_owned(),
+            UpdateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            UpdateError::RequestTimeout => "request_timeout".to_owned(),
+            UpdateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            UpdateError::Unknown(s) => s,
+            UpdateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for UpdateError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            UpdateError::MalformedResponse(_, ref e) => Some(e),
+            UpdateError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -30,19 +30,19 @@ pub struct AddRequest<'a> {
     pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AddResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<AddResponse, AddError<E>>> for AddResponse {
-    fn into(self) -> Result<AddResponse, AddError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AddResponse> for Result<AddResponse, AddError<E>> {
+    fn from(response: AddResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -139,11 +139,47 @@ AddError::InvalidPostType => "invalid_post_type: The method was called via a POS
 AddError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 AddError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 AddError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        AddError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        AddError::Unknown(ref s) => return write!(f, "{}", s),
-                        AddError::Client(ref inner) => return write!(f, "{}", inner),
+                        AddError::MalformedResponse(_, ref e) => return write!(f, "stars.add returned a malformed response: {}", e),
+                        AddError::Unknown(ref s) => return write!(f, "stars.add failed: {}", s),
+                        AddError::Client(ref inner) => return write!(f, "stars.add failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "stars.add failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AddError<E>> for crate::SlackError<E> {
+    fn from(e: AddError<E>) -> Self {
+        let method = "stars.add";
+        let error = match e {
+            AddError::BadTimestamp => "bad_timestamp".to_owned(),
+            AddError::MessageNotFound => "message_not_found".to_owned(),
+            AddError::FileNotFound => "file_not_found".to_owned(),
+            AddError::FileCommentNotFound => "file_comment_not_found".to_owned(),
+            AddError::ChannelNotFound => "channel_not_found".to_owned(),
+            AddError::NoItemSpecified => "no_item_specified".to_owned(),
+            AddError::AlreadyStarred => "already_starred".to_owned(),
+            AddError::NotAuthed => "not_authed".to_owned(),
+            AddError::InvalidAuth => "invalid_auth".to_owned(),
+            AddError::AccountInactive => "account_inactive".to_owned(),
+            AddError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AddError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AddError::InvalidCharset => "invalid_charset".to_owned(),
+            AddError::InvalidFormData => "invalid_form_data".to_owned(),
+            AddError::InvalidPostType => "invalid_post_type".to_owned(),
+            AddError::MissingPostType => "missing_post_type".to_owned(),
+            AddError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AddError::RequestTimeout => "request_timeout".to_owned(),
+            AddError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AddError::Unknown(s) => s,
+            AddError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -158,27 +194,45 @@ impl<E: Error + 'static> Error for AddError<E> {
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct ListRequest {
+pub struct ListRequest<'a> {
     /// Number of items to return per page.
     pub count: Option<u32>,
+    /// Parameter for pagination. Set `cursor` equal to the `next_cursor` attribute returned by the previous request's `response_metadata`. This parameter is optional, but pagination is mandatory: the default value simply fetches the first "page" of the collection. See pagination for more details.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the list hasn't been reached.
+    pub limit: Option<u32>,
     /// Page number of results to return.
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponse {
     error: Option<String>,
     pub items: Option<Vec<ListResponseItem>>,
     #[serde(default)]
     ok: bool,
     pub paging: Option<crate::Paging>,
+    pub response_metadata: Option<crate::ResponseMetadata>,
+}
+
+impl crate::HasPagination for ListResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
 }
 
-#[derive(Clone, Debug)]
+impl crate::HasPaging for ListResponse {
+    fn paging(&self) -> Option<&crate::Paging> {
+        self.paging.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum ListResponseItem {
-    Message(ListResponseItemMessage),
-    File(ListResponseItemFile),
-    FileComment(ListResponseItemFileComment),
+    Message(crate::ListResponseItemMessage),
+    File(crate::ListResponseItemFile),
+    FileComment(crate::ListResponseItemFileComment),
     Channel(ListResponseItemChannel),
     Im(ListResponseItemIm),
     Group(ListResponseItemGroup),
@@ -197,26 +251,32 @@ impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
         if let Some(ty_val) = value.get("type") {
             if let Some(ty) = ty_val.as_str() {
                 match ty {
-                    "message" => ::serde_json::from_value::<ListResponseItemMessage>(value.clone())
-                        .map(ListResponseItem::Message)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
-                    "file" => ::serde_json::from_value::<ListResponseItemFile>(value.clone())
-                        .map(ListResponseItem::File)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                    "message" => {
+                        ::serde_json::from_value::<crate::ListResponseItemMessage>(value.clone())
+                            .map(ListResponseItem::Message)
+                            .map_err(D::Error::custom)
+                    }
+                    "file" => {
+                        ::serde_json::from_value::<crate::ListResponseItemFile>(value.clone())
+                            .map(ListResponseItem::File)
+                            .map_err(D::Error::custom)
+                    }
                     "file_comment" => {
-                        ::serde_json::from_value::<ListResponseItemFileComment>(value.clone())
-                            .map(ListResponseItem::FileComment)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                        ::serde_json::from_value::<crate::ListResponseItemFileComment>(
+                            value.clone(),
+                        )
+                        .map(ListResponseItem::FileComment)
+                        .map_err(D::Error::custom)
                     }
                     "channel" => ::serde_json::from_value::<ListResponseItemChannel>(value.clone())
                         .map(ListResponseItem::Channel)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "im" => ::serde_json::from_value::<ListResponseItemIm>(value.clone())
                         .map(ListResponseItem::Im)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "group" => ::serde_json::from_value::<ListResponseItemGroup>(value.clone())
                         .map(ListResponseItem::Group)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
                 }
             } else {
@@ -231,56 +291,34 @@ impl<'de> ::serde::Deserialize<'de> for ListResponseItem {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponseItemChannel {
     pub channel: String,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFile {
-    pub file: crate::File,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemFileComment {
-    pub comment: crate::FileComment,
-    pub file: crate::File,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
-
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponseItemGroup {
     pub group: String,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponseItemIm {
     pub channel: String,
-    #[serde(rename = "type")]
-    pub ty: String,
+    pub r#type: String,
 }
 
-#[derive(Clone, Debug, Deserialize)]
-pub struct ListResponseItemMessage {
-    pub channel: String,
-    pub message: crate::Message,
-    #[serde(rename = "type")]
-    pub ty: String,
-}
+pub type ListResponseItemFile = crate::ListResponseItemFile;
+pub type ListResponseItemFileComment = crate::ListResponseItemFileComment;
+pub type ListResponseItemMessage = crate::ListResponseItemMessage;
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -361,11 +399,43 @@ ListError::InvalidPostType => "invalid_post_type: The method was called via a PO
 ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ListError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ListError::Unknown(ref s) => return write!(f, "{}", s),
-                        ListError::Client(ref inner) => return write!(f, "{}", inner),
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "stars.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "stars.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "stars.list failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "stars.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "stars.list";
+        let error = match e {
+            ListError::UserNotFound => "user_not_found".to_owned(),
+            ListError::UserNotVisible => "user_not_visible".to_owned(),
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::UserIsBot => "user_is_bot".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -391,19 +461,19 @@ pub struct RemoveRequest<'a> {
     pub timestamp: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct RemoveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<RemoveResponse, RemoveError<E>>> for RemoveResponse {
-    fn into(self) -> Result<RemoveResponse, RemoveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<RemoveResponse> for Result<RemoveResponse, RemoveError<E>> {
+    fn from(response: RemoveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -500,11 +570,47 @@ RemoveError::InvalidPostType => "invalid_post_type: The method was called via a
 RemoveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 RemoveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 RemoveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        RemoveError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        RemoveError::Unknown(ref s) => return write!(f, "{}", s),
-                        RemoveError::Client(ref inner) => return write!(f, "{}", inner),
+                        RemoveError::MalformedResponse(_, ref e) => return write!(f, "stars.remove returned a malformed response: {}", e),
+                        RemoveError::Unknown(ref s) => return write!(f, "stars.remove failed: {}", s),
+                        RemoveError::Client(ref inner) => return write!(f, "stars.remove failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "stars.remove failed: {}", d)
+    }
+}
+
+impl<E: Error> From<RemoveError<E>> for crate::SlackError<E> {
+    fn from(e: RemoveError<E>) -> Self {
+        let method = "stars.remove";
+        let error = match e {
+            RemoveError::BadTimestamp => "bad_timestamp".to_owned(),
+            RemoveError::MessageNotFound => "message_not_found".to_owned(),
+            RemoveError::FileNotFound => "file_not_found".to_owned(),
+            RemoveError::FileCommentNotFound => "file_comment_not_found".to_owned(),
+            RemoveError::ChannelNotFound => "channel_not_found".to_owned(),
+            RemoveError::NoItemSpecified => "no_item_specified".to_owned(),
+            RemoveError::NotStarred => "not_starred".to_owned(),
+            RemoveError::NotAuthed => "not_authed".to_owned(),
+            RemoveError::InvalidAuth => "invalid_auth".to_owned(),
+            RemoveError::AccountInactive => "account_inactive".to_owned(),
+            RemoveError::InvalidArgName => "invalid_arg_name".to_owned(),
+            RemoveError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            RemoveError::InvalidCharset => "invalid_charset".to_owned(),
+            RemoveError::InvalidFormData => "invalid_form_data".to_owned(),
+            RemoveError::InvalidPostType => "invalid_post_type".to_owned(),
+            RemoveError::MissingPostType => "missing_post_type".to_owned(),
+            RemoveError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            RemoveError::RequestTimeout => "request_timeout".to_owned(),
+            RemoveError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            RemoveError::Unknown(s) => s,
+            RemoveError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -24,7 +24,7 @@ pub struct GetRequest<'a> {
     pub visibility: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GetResponse {
     error: Option<String>,
     #[serde(default)]
@@ -32,12 +32,12 @@ pub struct GetResponse {
     pub profile: Option<GetResponseProfile>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GetResponseProfile {
     pub fields: Option<Vec<GetResponseProfileField>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GetResponseProfileField {
     pub hint: Option<String>,
     pub id: Option<String>,
@@ -46,16 +46,15 @@ pub struct GetResponseProfileField {
     pub options: Option<HashMap<String, String>>,
     pub ordering: Option<i32>,
     pub possible_values: Option<Vec<String>>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
 }
 
-impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
-    fn into(self) -> Result<GetResponse, GetError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<GetResponse> for Result<GetResponse, GetError<E>> {
+    fn from(response: GetResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -128,11 +127,41 @@ GetError::InvalidPostType => "invalid_post_type: The method was called via a POS
 GetError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 GetError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 GetError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        GetError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        GetError::Unknown(ref s) => return write!(f, "{}", s),
-                        GetError::Client(ref inner) => return write!(f, "{}", inner),
+                        GetError::MalformedResponse(_, ref e) => return write!(f, "team.profile.get returned a malformed response: {}", e),
+                        GetError::Unknown(ref s) => return write!(f, "team.profile.get failed: {}", s),
+                        GetError::Client(ref inner) => return write!(f, "team.profile.get failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "team.profile.get failed: {}", d)
+    }
+}
+
+impl<E: Error> From<GetError<E>> for crate::SlackError<E> {
+    fn from(e: GetError<E>) -> Self {
+        let method = "team.profile.get";
+        let error = match e {
+            GetError::NotAuthed => "not_authed".to_owned(),
+            GetError::InvalidAuth => "invalid_auth".to_owned(),
+            GetError::AccountInactive => "account_inactive".to_owned(),
+            GetError::UserIsBot => "user_is_bot".to_owned(),
+            GetError::InvalidArgName => "invalid_arg_name".to_owned(),
+            GetError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            GetError::InvalidCharset => "invalid_charset".to_owned(),
+            GetError::InvalidFormData => "invalid_form_data".to_owned(),
+            GetError::InvalidPostType => "invalid_post_type".to_owned(),
+            GetError::MissingPostType => "missing_post_type".to_owned(),
+            GetError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            GetError::RequestTimeout => "request_timeout".to_owned(),
+            GetError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            GetError::Unknown(s) => s,
+            GetError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -28,7 +28,7 @@ pub struct AccessLogsRequest {
     pub before: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AccessLogsResponse {
     error: Option<String>,
     pub logins: Option<Vec<AccessLogsResponseLogin>>,
@@ -37,7 +37,13 @@ pub struct AccessLogsResponse {
     pub paging: Option<crate::Paging>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::HasPaging for AccessLogsResponse {
+    fn paging(&self) -> Option<&crate::Paging> {
+        self.paging.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct AccessLogsResponseLogin {
     pub count: Option<i32>,
     pub country: Option<String>,
@@ -51,12 +57,12 @@ pub struct AccessLogsResponseLogin {
     pub username: Option<String>,
 }
 
-impl<E: Error> Into<Result<AccessLogsResponse, AccessLogsError<E>>> for AccessLogsResponse {
-    fn into(self) -> Result<AccessLogsResponse, AccessLogsError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<AccessLogsResponse> for Result<AccessLogsResponse, AccessLogsError<E>> {
+    fn from(response: AccessLogsResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -137,11 +143,43 @@ AccessLogsError::InvalidPostType => "invalid_post_type: The method was called vi
 AccessLogsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 AccessLogsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 AccessLogsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        AccessLogsError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        AccessLogsError::Unknown(ref s) => return write!(f, "{}", s),
-                        AccessLogsError::Client(ref inner) => return write!(f, "{}", inner),
+                        AccessLogsError::MalformedResponse(_, ref e) => return write!(f, "team.accessLogs returned a malformed response: {}", e),
+                        AccessLogsError::Unknown(ref s) => return write!(f, "team.accessLogs failed: {}", s),
+                        AccessLogsError::Client(ref inner) => return write!(f, "team.accessLogs failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "team.accessLogs failed: {}", d)
+    }
+}
+
+impl<E: Error> From<AccessLogsError<E>> for crate::SlackError<E> {
+    fn from(e: AccessLogsError<E>) -> Self {
+        let method = "team.accessLogs";
+        let error = match e {
+            AccessLogsError::PaidOnly => "paid_only".to_owned(),
+            AccessLogsError::OverPaginationLimit => "over_pagination_limit".to_owned(),
+            AccessLogsError::NotAuthed => "not_authed".to_owned(),
+            AccessLogsError::InvalidAuth => "invalid_auth".to_owned(),
+            AccessLogsError::AccountInactive => "account_inactive".to_owned(),
+            AccessLogsError::UserIsBot => "user_is_bot".to_owned(),
+            AccessLogsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            AccessLogsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            AccessLogsError::InvalidCharset => "invalid_charset".to_owned(),
+            AccessLogsError::InvalidFormData => "invalid_form_data".to_owned(),
+            AccessLogsError::InvalidPostType => "invalid_post_type".to_owned(),
+            AccessLogsError::MissingPostType => "missing_post_type".to_owned(),
+            AccessLogsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            AccessLogsError::RequestTimeout => "request_timeout".to_owned(),
+            AccessLogsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            AccessLogsError::Unknown(s) => s,
+            AccessLogsError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -161,7 +199,7 @@ pub struct BillableInfoRequest<'a> {
     pub user: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct BillableInfoResponse {
     pub billable_info: Option<HashMap<String, bool>>,
     error: Option<String>,
@@ -169,12 +207,12 @@ pub struct BillableInfoResponse {
     ok: bool,
 }
 
-impl<E: Error> Into<Result<BillableInfoResponse, BillableInfoError<E>>> for BillableInfoResponse {
-    fn into(self) -> Result<BillableInfoResponse, BillableInfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<BillableInfoResponse> for Result<BillableInfoResponse, BillableInfoError<E>> {
+    fn from(response: BillableInfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -251,11 +289,42 @@ BillableInfoError::InvalidPostType => "invalid_post_type: The method was called
 BillableInfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 BillableInfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 BillableInfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        BillableInfoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        BillableInfoError::Unknown(ref s) => return write!(f, "{}", s),
-                        BillableInfoError::Client(ref inner) => return write!(f, "{}", inner),
+                        BillableInfoError::MalformedResponse(_, ref e) => return write!(f, "team.billableInfo returned a malformed response: {}", e),
+                        BillableInfoError::Unknown(ref s) => return write!(f, "team.billableInfo failed: {}", s),
+                        BillableInfoError::Client(ref inner) => return write!(f, "team.billableInfo failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "team.billableInfo failed: {}", d)
+    }
+}
+
+impl<E: Error> From<BillableInfoError<E>> for crate::SlackError<E> {
+    fn from(e: BillableInfoError<E>) -> Self {
+        let method = "team.billableInfo";
+        let error = match e {
+            BillableInfoError::UserNotFound => "user_not_found".to_owned(),
+            BillableInfoError::NotAuthed => "not_authed".to_owned(),
+            BillableInfoError::InvalidAuth => "invalid_auth".to_owned(),
+            BillableInfoError::AccountInactive => "account_inactive".to_owned(),
+            BillableInfoError::UserIsBot => "user_is_bot".to_owned(),
+            BillableInfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            BillableInfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            BillableInfoError::InvalidCharset => "invalid_charset".to_owned(),
+            BillableInfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            BillableInfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            BillableInfoError::MissingPostType => "missing_post_type".to_owned(),
+            BillableInfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            BillableInfoError::RequestTimeout => "request_timeout".to_owned(),
+            BillableInfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            BillableInfoError::Unknown(s) => s,
+            BillableInfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -269,7 +338,7 @@ impl<E: Error + 'static> Error for BillableInfoError<E> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InfoResponse {
     error: Option<String>,
     #[serde(default)]
@@ -277,12 +346,12 @@ pub struct InfoResponse {
     pub team: Option<crate::Team>,
 }
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -351,11 +420,40 @@ InfoError::InvalidPostType => "invalid_post_type: The method was called via a PO
 InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        InfoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        InfoError::Unknown(ref s) => return write!(f, "{}", s),
-                        InfoError::Client(ref inner) => return write!(f, "{}", inner),
+                        InfoError::MalformedResponse(_, ref e) => return write!(f, "team.info returned a malformed response: {}", e),
+                        InfoError::Unknown(ref s) => return write!(f, "team.info failed: {}", s),
+                        InfoError::Client(ref inner) => return write!(f, "team.info failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "team.info failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(e: InfoError<E>) -> Self {
+        let method = "team.info";
+        let error = match e {
+            InfoError::NotAuthed => "not_authed".to_owned(),
+            InfoError::InvalidAuth => "invalid_auth".to_owned(),
+            InfoError::AccountInactive => "account_inactive".to_owned(),
+            InfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InfoError::InvalidCharset => "invalid_charset".to_owned(),
+            InfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            InfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            InfoError::MissingPostType => "missing_post_type".to_owned(),
+            InfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InfoError::RequestTimeout => "request_timeout".to_owned(),
+            InfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InfoError::Unknown(s) => s,
+            InfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -385,7 +483,7 @@ pub struct IntegrationLogsRequest<'a> {
     pub page: Option<u32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct IntegrationLogsResponse {
     error: Option<String>,
     pub logs: Option<Vec<IntegrationLogsResponseLog>>,
@@ -394,7 +492,13 @@ pub struct IntegrationLogsResponse {
     pub paging: Option<crate::Paging>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+impl crate::HasPaging for IntegrationLogsResponse {
+    fn paging(&self) -> Option<&crate::Paging> {
+        self.paging.as_ref()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct IntegrationLogsResponseLog {
     pub app_id: Option<String>,
     pub app_type: Option<String>,
@@ -409,14 +513,14 @@ pub struct IntegrationLogsResponseLog {
     pub user_name: Option<String>,
 }
 
-impl<E: Error> Into<Result<IntegrationLogsResponse, IntegrationLogsError<E>>>
-    for IntegrationLogsResponse
+impl<E: Error> From<IntegrationLogsResponse>
+    for Result<IntegrationLogsResponse, IntegrationLogsError<E>>
 {
-    fn into(self) -> Result<IntegrationLogsResponse, IntegrationLogsError<E>> {
-        if self.ok {
-            Ok(self)
+    fn from(response: IntegrationLogsResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -489,11 +593,43 @@ IntegrationLogsError::InvalidPostType => "invalid_post_type: The method was call
 IntegrationLogsError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 IntegrationLogsError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 IntegrationLogsError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        IntegrationLogsError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        IntegrationLogsError::Unknown(ref s) => return write!(f, "{}", s),
-                        IntegrationLogsError::Client(ref inner) => return write!(f, "{}", inner),
+                        IntegrationLogsError::MalformedResponse(_, ref e) => return write!(f, "team.integrationLogs returned a malformed response: {}", e),
+                        IntegrationLogsError::Unknown(ref s) => return write!(f, "team.integrationLogs failed: {}", s),
+                        IntegrationLogsError::Client(ref inner) => return write!(f, "team.integrationLogs failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "team.integrationLogs failed: {}", d)
+    }
+}
+
+impl<E: Error> From<IntegrationLogsError<E>> for crate::SlackError<E> {
+    fn from(e: IntegrationLogsError<E>) -> Self {
+        let method = "team.integrationLogs";
+        let error = match e {
+            IntegrationLogsError::NotAuthed => "not_authed".to_owned(),
+            IntegrationLogsError::InvalidAuth => "invalid_auth".to_owned(),
+            IntegrationLogsError::AccountInactive => "account_inactive".to_owned(),
+            IntegrationLogsError::UserIsBot => "user_is_bot".to_owned(),
+            IntegrationLogsError::InvalidArgName => "invalid_arg_name".to_owned(),
+            IntegrationLogsError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            IntegrationLogsError::InvalidCharset => "invalid_charset".to_owned(),
+            IntegrationLogsError::InvalidFormData => "invalid_form_data".to_owned(),
+            IntegrationLogsError::InvalidPostType => "invalid_post_type".to_owned(),
+            IntegrationLogsError::MissingPostType => "missing_post_type".to_owned(),
+            IntegrationLogsError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            IntegrationLogsError::RequestTimeout => "request_timeout".to_owned(),
+            IntegrationLogsError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            IntegrationLogsError::Unknown(s) => s,
+            IntegrationLogsError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -34,7 +34,7 @@ pub struct CreateRequest<'a> {
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct CreateResponse {
     error: Option<String>,
     #[serde(default)]
@@ -42,12 +42,12 @@ pub struct CreateResponse {
     pub usergroup: Option<crate::Usergroup>,
 }
 
-impl<E: Error> Into<Result<CreateResponse, CreateError<E>>> for CreateResponse {
-    fn into(self) -> Result<CreateResponse, CreateError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<CreateResponse> for Result<CreateResponse, CreateError<E>> {
+    fn from(response: CreateResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -124,11 +124,42 @@ CreateError::InvalidPostType => "invalid_post_type: The method was called via a
 CreateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 CreateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 CreateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        CreateError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        CreateError::Unknown(ref s) => return write!(f, "{}", s),
-                        CreateError::Client(ref inner) => return write!(f, "{}", inner),
+                        CreateError::MalformedResponse(_, ref e) => return write!(f, "usergroups.create returned a malformed response: {}", e),
+                        CreateError::Unknown(ref s) => return write!(f, "usergroups.create failed: {}", s),
+                        CreateError::Client(ref inner) => return write!(f, "usergroups.create failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "usergroups.create failed: {}", d)
+    }
+}
+
+impl<E: Error> From<CreateError<E>> for crate::SlackError<E> {
+    fn from(e: CreateError<E>) -> Self {
+        let method = "usergroups.create";
+        let error = match e {
+            CreateError::NotAuthed => "not_authed".to_owned(),
+            CreateError::InvalidAuth => "invalid_auth".to_owned(),
+            CreateError::AccountInactive => "account_inactive".to_owned(),
+            CreateError::UserIsBot => "user_is_bot".to_owned(),
+            CreateError::UserIsRestricted => "user_is_restricted".to_owned(),
+            CreateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            CreateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            CreateError::InvalidCharset => "invalid_charset".to_owned(),
+            CreateError::InvalidFormData => "invalid_form_data".to_owned(),
+            CreateError::InvalidPostType => "invalid_post_type".to_owned(),
+            CreateError::MissingPostType => "missing_post_type".to_owned(),
+            CreateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            CreateError::RequestTimeout => "request_timeout".to_owned(),
+            CreateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            CreateError::Unknown(s) => s,
+            CreateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -150,7 +181,7 @@ pub struct DisableRequest<'a> {
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct DisableResponse {
     error: Option<String>,
     #[serde(default)]
@@ -158,12 +189,12 @@ pub struct DisableResponse {
     pub usergroup: Option<crate::Usergroup>,
 }
 
-impl<E: Error> Into<Result<DisableResponse, DisableError<E>>> for DisableResponse {
-    fn into(self) -> Result<DisableResponse, DisableError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DisableResponse> for Result<DisableResponse, DisableError<E>> {
+    fn from(response: DisableResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -240,11 +271,42 @@ DisableError::InvalidPostType => "invalid_post_type: The method was called via a
 DisableError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 DisableError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 DisableError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        DisableError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        DisableError::Unknown(ref s) => return write!(f, "{}", s),
-                        DisableError::Client(ref inner) => return write!(f, "{}", inner),
+                        DisableError::MalformedResponse(_, ref e) => return write!(f, "usergroups.disable returned a malformed response: {}", e),
+                        DisableError::Unknown(ref s) => return write!(f, "usergroups.disable failed: {}", s),
+                        DisableError::Client(ref inner) => return write!(f, "usergroups.disable failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "usergroups.disable failed: {}", d)
+    }
+}
+
+impl<E: Error> From<DisableError<E>> for crate::SlackError<E> {
+    fn from(e: DisableError<E>) -> Self {
+        let method = "usergroups.disable";
+        let error = match e {
+            DisableError::NotAuthed => "not_authed".to_owned(),
+            DisableError::InvalidAuth => "invalid_auth".to_owned(),
+            DisableError::AccountInactive => "account_inactive".to_owned(),
+            DisableError::UserIsBot => "user_is_bot".to_owned(),
+            DisableError::UserIsRestricted => "user_is_restricted".to_owned(),
+            DisableError::InvalidArgName => "invalid_arg_name".to_owned(),
+            DisableError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            DisableError::InvalidCharset => "invalid_charset".to_owned(),
+            DisableError::InvalidFormData => "invalid_form_data".to_owned(),
+            DisableError::InvalidPostType => "invalid_post_type".to_owned(),
+            DisableError::MissingPostType => "missing_post_type".to_owned(),
+            DisableError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            DisableError::RequestTimeout => "request_timeout".to_owned(),
+            DisableError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            DisableError::Unknown(s) => s,
+            DisableError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -266,7 +328,7 @@ pub struct EnableRequest<'a> {
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct EnableResponse {
     error: Option<String>,
     #[serde(default)]
@@ -274,12 +336,12 @@ pub struct EnableResponse {
     pub usergroup: Option<crate::Usergroup>,
 }
 
-impl<E: Error> Into<Result<EnableResponse, EnableError<E>>> for EnableResponse {
-    fn into(self) -> Result<EnableResponse, EnableError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<EnableResponse> for Result<EnableResponse, EnableError<E>> {
+    fn from(response: EnableResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -356,11 +418,42 @@ EnableError::InvalidPostType => "invalid_post_type: The method was called via a
 EnableError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 EnableError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 EnableError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        EnableError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        EnableError::Unknown(ref s) => return write!(f, "{}", s),
-                        EnableError::Client(ref inner) => return write!(f, "{}", inner),
+                        EnableError::MalformedResponse(_, ref e) => return write!(f, "usergroups.enable returned a malformed response: {}", e),
+                        EnableError::Unknown(ref s) => return write!(f, "usergroups.enable failed: {}", s),
+                        EnableError::Client(ref inner) => return write!(f, "usergroups.enable failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "usergroups.enable failed: {}", d)
+    }
+}
+
+impl<E: Error> From<EnableError<E>> for crate::SlackError<E> {
+    fn from(e: EnableError<E>) -> Self {
+        let method = "usergroups.enable";
+        let error = match e {
+            EnableError::NotAuthed => "not_authed".to_owned(),
+            EnableError::InvalidAuth => "invalid_auth".to_owned(),
+            EnableError::AccountInactive => "account_inactive".to_owned(),
+            EnableError::UserIsBot => "user_is_bot".to_owned(),
+            EnableError::UserIsRestricted => "user_is_restricted".to_owned(),
+            EnableError::InvalidArgName => "invalid_arg_name".to_owned(),
+            EnableError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            EnableError::InvalidCharset => "invalid_charset".to_owned(),
+            EnableError::InvalidFormData => "invalid_form_data".to_owned(),
+            EnableError::InvalidPostType => "invalid_post_type".to_owned(),
+            EnableError::MissingPostType => "missing_post_type".to_owned(),
+            EnableError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            EnableError::RequestTimeout => "request_timeout".to_owned(),
+            EnableError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            EnableError::Unknown(s) => s,
+            EnableError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -384,7 +477,7 @@ pub struct ListRequest {
     pub include_users: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponse {
     error: Option<String>,
     #[serde(default)]
@@ -392,12 +485,12 @@ pub struct ListResponse {
     pub usergroups: Option<Vec<crate::Usergroup>>,
 }
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -474,11 +567,42 @@ ListError::InvalidPostType => "invalid_post_type: The method was called via a PO
 ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ListError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ListError::Unknown(ref s) => return write!(f, "{}", s),
-                        ListError::Client(ref inner) => return write!(f, "{}", inner),
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "usergroups.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "usergroups.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "usergroups.list failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "usergroups.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "usergroups.list";
+        let error = match e {
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::UserIsBot => "user_is_bot".to_owned(),
+            ListError::UserIsRestricted => "user_is_restricted".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -508,7 +632,7 @@ pub struct UpdateRequest<'a> {
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UpdateResponse {
     error: Option<String>,
     #[serde(default)]
@@ -516,12 +640,12 @@ pub struct UpdateResponse {
     pub usergroup: Option<crate::Usergroup>,
 }
 
-impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
-    fn into(self) -> Result<UpdateResponse, UpdateError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<UpdateResponse> for Result<UpdateResponse, UpdateError<E>> {
+    fn from(response: UpdateResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -598,11 +722,42 @@ UpdateError::InvalidPostType => "invalid_post_type: The method was called via a
 UpdateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 UpdateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 UpdateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        UpdateError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        UpdateError::Unknown(ref s) => return write!(f, "{}", s),
-                        UpdateError::Client(ref inner) => return write!(f, "{}", inner),
+                        UpdateError::MalformedResponse(_, ref e) => return write!(f, "usergroups.update returned a malformed response: {}", e),
+                        UpdateError::Unknown(ref s) => return write!(f, "usergroups.update failed: {}", s),
+                        UpdateError::Client(ref inner) => return write!(f, "usergroups.update failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "usergroups.update failed: {}", d)
+    }
+}
+
+impl<E: Error> From<UpdateError<E>> for crate::SlackError<E> {
+    fn from(e: UpdateError<E>) -> Self {
+        let method = "usergroups.update";
+        let error = match e {
+            UpdateError::NotAuthed => "not_authed".to_owned(),
+            UpdateError::InvalidAuth => "invalid_auth".to_owned(),
+            UpdateError::AccountInactive => "account_inactive".to_owned(),
+            UpdateError::UserIsBot => "user_is_bot".to_owned(),
+            UpdateError::UserIsRestricted => "user_is_restricted".to_owned(),
+            UpdateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            UpdateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            UpdateError::InvalidCharset => "invalid_charset".to_owned(),
+            UpdateError::InvalidFormData => "invalid_form_data".to_owned(),
+            UpdateError::InvalidPostType => "invalid_post_type".to_owned(),
+            UpdateError::MissingPostType => "missing_post_type".to_owned(),
+            UpdateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            UpdateError::RequestTimeout => "request_timeout".to_owned(),
+            UpdateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            UpdateError::Unknown(s) => s,
+            UpdateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -26,7 +26,7 @@ pub struct ListRequest<'a> {
     pub include_disabled: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponse {
     error: Option<String>,
     #[serde(default)]
@@ -34,12 +34,12 @@ pub struct ListResponse {
     pub users: Option<Vec<String>>,
 }
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -116,11 +116,42 @@ ListError::InvalidPostType => "invalid_post_type: The method was called via a PO
 ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ListError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ListError::Unknown(ref s) => return write!(f, "{}", s),
-                        ListError::Client(ref inner) => return write!(f, "{}", inner),
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "usergroups.users.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "usergroups.users.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "usergroups.users.list failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "usergroups.users.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "usergroups.users.list";
+        let error = match e {
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::UserIsBot => "user_is_bot".to_owned(),
+            ListError::UserIsRestricted => "user_is_restricted".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -144,7 +175,7 @@ pub struct UpdateRequest<'a> {
     pub include_count: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UpdateResponse {
     error: Option<String>,
     #[serde(default)]
@@ -152,12 +183,12 @@ pub struct UpdateResponse {
     pub usergroup: Option<crate::Usergroup>,
 }
 
-impl<E: Error> Into<Result<UpdateResponse, UpdateError<E>>> for UpdateResponse {
-    fn into(self) -> Result<UpdateResponse, UpdateError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<UpdateResponse> for Result<UpdateResponse, UpdateError<E>> {
+    fn from(response: UpdateResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -234,11 +265,42 @@ UpdateError::InvalidPostType => "invalid_post_type: The method was called via a
 UpdateError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 UpdateError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 UpdateError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        UpdateError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        UpdateError::Unknown(ref s) => return write!(f, "{}", s),
-                        UpdateError::Client(ref inner) => return write!(f, "{}", inner),
+                        UpdateError::MalformedResponse(_, ref e) => return write!(f, "usergroups.users.update returned a malformed response: {}", e),
+                        UpdateError::Unknown(ref s) => return write!(f, "usergroups.users.update failed: {}", s),
+                        UpdateError::Client(ref inner) => return write!(f, "usergroups.users.update failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "usergroups.users.update failed: {}", d)
+    }
+}
+
+impl<E: Error> From<UpdateError<E>> for crate::SlackError<E> {
+    fn from(e: UpdateError<E>) -> Self {
+        let method = "usergroups.users.update";
+        let error = match e {
+            UpdateError::NotAuthed => "not_authed".to_owned(),
+            UpdateError::InvalidAuth => "invalid_auth".to_owned(),
+            UpdateError::AccountInactive => "account_inactive".to_owned(),
+            UpdateError::UserIsBot => "user_is_bot".to_owned(),
+            UpdateError::UserIsRestricted => "user_is_restricted".to_owned(),
+            UpdateError::InvalidArgName => "invalid_arg_name".to_owned(),
+            UpdateError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            UpdateError::InvalidCharset => "invalid_charset".to_owned(),
+            UpdateError::InvalidFormData => "invalid_form_data".to_owned(),
+            UpdateError::InvalidPostType => "invalid_post_type".to_owned(),
+            UpdateError::MissingPostType => "missing_post_type".to_owned(),
+            UpdateError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            UpdateError::RequestTimeout => "request_timeout".to_owned(),
+            UpdateError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            UpdateError::Unknown(s) => s,
+            UpdateError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
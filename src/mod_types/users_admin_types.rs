@@ -0,0 +1,209 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage workspace members through Slack's undocumented admin endpoints.
+//!
+//! These endpoints are not part of Slack's published API and may change or be removed without notice. They are only available with the `unstable-api` feature.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Default, Debug)]
+pub struct InviteRequest<'a> {
+    /// Email address of the person to invite.
+    pub email: &'a str,
+    /// Comma separated list of channel IDs the user will automatically join.
+    pub channels: Option<&'a str>,
+    /// First name of the invitee.
+    pub first_name: Option<&'a str>,
+    /// Last name of the invitee.
+    pub last_name: Option<&'a str>,
+    /// Invite the user as a multi-channel guest.
+    pub restricted: Option<bool>,
+    /// Invite the user as a single-channel guest. Requires exactly one channel.
+    pub ultra_restricted: Option<bool>,
+    /// Resend the invitation if the user has already been invited.
+    pub resend: Option<bool>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct InviteResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+}
+
+impl<E: Error> From<InviteResponse> for Result<InviteResponse, InviteError<E>> {
+    fn from(response: InviteResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum InviteError<E: Error> {
+    /// The user is already a member of the workspace.
+    AlreadyInTeam,
+    /// The user has already been invited and resend was not set.
+    AlreadyInvited,
+    /// A channel passed in channels was invalid.
+    ChannelNotFound,
+    /// The email address was invalid.
+    InvalidEmail,
+    /// The authed user is not allowed to invite users.
+    NotAllowed,
+    /// An invitation was sent to this email address recently.
+    SentRecently,
+    /// The user has been deactivated.
+    UserDisabled,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for InviteError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "already_in_team" => InviteError::AlreadyInTeam,
+            "already_invited" => InviteError::AlreadyInvited,
+            "channel_not_found" => InviteError::ChannelNotFound,
+            "invalid_email" => InviteError::InvalidEmail,
+            "not_allowed" => InviteError::NotAllowed,
+            "sent_recently" => InviteError::SentRecently,
+            "user_disabled" => InviteError::UserDisabled,
+            "missing_scope" => InviteError::MissingScope,
+            "not_authed" => InviteError::NotAuthed,
+            "invalid_auth" => InviteError::InvalidAuth,
+            "account_inactive" => InviteError::AccountInactive,
+            "invalid_arg_name" => InviteError::InvalidArgName,
+            "invalid_array_arg" => InviteError::InvalidArrayArg,
+            "invalid_charset" => InviteError::InvalidCharset,
+            "invalid_form_data" => InviteError::InvalidFormData,
+            "invalid_post_type" => InviteError::InvalidPostType,
+            "missing_post_type" => InviteError::MissingPostType,
+            "team_added_to_org" => InviteError::TeamAddedToOrg,
+            "request_timeout" => InviteError::RequestTimeout,
+            _ => InviteError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for InviteError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        InviteError::AlreadyInTeam => "already_in_team: The user is already a member of the workspace.",
+InviteError::AlreadyInvited => "already_invited: The user has already been invited and resend was not set.",
+InviteError::ChannelNotFound => "channel_not_found: A channel passed in channels was invalid.",
+InviteError::InvalidEmail => "invalid_email: The email address was invalid.",
+InviteError::NotAllowed => "not_allowed: The authed user is not allowed to invite users.",
+InviteError::SentRecently => "sent_recently: An invitation was sent to this email address recently.",
+InviteError::UserDisabled => "user_disabled: The user has been deactivated.",
+InviteError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+InviteError::NotAuthed => "not_authed: No authentication token provided.",
+InviteError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+InviteError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+InviteError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+InviteError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+InviteError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+InviteError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+InviteError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+InviteError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+InviteError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+InviteError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        InviteError::MalformedResponse(_, ref e) => return write!(f, "users.admin.invite returned a malformed response: {}", e),
+                        InviteError::Unknown(ref s) => return write!(f, "users.admin.invite failed: {}", s),
+                        InviteError::Client(ref inner) => return write!(f, "users.admin.invite failed: {}", inner),
+                    };
+        write!(f, "users.admin.invite failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InviteError<E>> for crate::SlackError<E> {
+    fn from(e: InviteError<E>) -> Self {
+        let method = "users.admin.invite";
+        let error = match e {
+            InviteError::AlreadyInTeam => "already_in_team".to_owned(),
+            InviteError::AlreadyInvited => "already_invited".to_owned(),
+            InviteError::ChannelNotFound => "channel_not_found".to_owned(),
+            InviteError::InvalidEmail => "invalid_email".to_owned(),
+            InviteError::NotAllowed => "not_allowed".to_owned(),
+            InviteError::SentRecently => "sent_recently".to_owned(),
+            InviteError::UserDisabled => "user_disabled".to_owned(),
+            InviteError::MissingScope => "missing_scope".to_owned(),
+            InviteError::NotAuthed => "not_authed".to_owned(),
+            InviteError::InvalidAuth => "invalid_auth".to_owned(),
+            InviteError::AccountInactive => "account_inactive".to_owned(),
+            InviteError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InviteError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InviteError::InvalidCharset => "invalid_charset".to_owned(),
+            InviteError::InvalidFormData => "invalid_form_data".to_owned(),
+            InviteError::InvalidPostType => "invalid_post_type".to_owned(),
+            InviteError::MissingPostType => "missing_post_type".to_owned(),
+            InviteError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InviteError::RequestTimeout => "request_timeout".to_owned(),
+            InviteError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InviteError::Unknown(s) => s,
+            InviteError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for InviteError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            InviteError::MalformedResponse(_, ref e) => Some(e),
+            InviteError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
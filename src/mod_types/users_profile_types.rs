@@ -26,7 +26,7 @@ pub struct GetRequest<'a> {
     pub include_labels: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GetResponse {
     error: Option<String>,
     #[serde(default)]
@@ -34,12 +34,12 @@ pub struct GetResponse {
     pub profile: Option<crate::UserProfile>,
 }
 
-impl<E: Error> Into<Result<GetResponse, GetError<E>>> for GetResponse {
-    fn into(self) -> Result<GetResponse, GetError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<GetResponse> for Result<GetResponse, GetError<E>> {
+    fn from(response: GetResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -116,11 +116,42 @@ GetError::InvalidPostType => "invalid_post_type: The method was called via a POS
 GetError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 GetError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 GetError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        GetError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        GetError::Unknown(ref s) => return write!(f, "{}", s),
-                        GetError::Client(ref inner) => return write!(f, "{}", inner),
+                        GetError::MalformedResponse(_, ref e) => return write!(f, "users.profile.get returned a malformed response: {}", e),
+                        GetError::Unknown(ref s) => return write!(f, "users.profile.get failed: {}", s),
+                        GetError::Client(ref inner) => return write!(f, "users.profile.get failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "users.profile.get failed: {}", d)
+    }
+}
+
+impl<E: Error> From<GetError<E>> for crate::SlackError<E> {
+    fn from(e: GetError<E>) -> Self {
+        let method = "users.profile.get";
+        let error = match e {
+            GetError::UserNotFound => "user_not_found".to_owned(),
+            GetError::NotAuthed => "not_authed".to_owned(),
+            GetError::InvalidAuth => "invalid_auth".to_owned(),
+            GetError::AccountInactive => "account_inactive".to_owned(),
+            GetError::UserIsBot => "user_is_bot".to_owned(),
+            GetError::InvalidArgName => "invalid_arg_name".to_owned(),
+            GetError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            GetError::InvalidCharset => "invalid_charset".to_owned(),
+            GetError::InvalidFormData => "invalid_form_data".to_owned(),
+            GetError::InvalidPostType => "invalid_post_type".to_owned(),
+            GetError::MissingPostType => "missing_post_type".to_owned(),
+            GetError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            GetError::RequestTimeout => "request_timeout".to_owned(),
+            GetError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            GetError::Unknown(s) => s,
+            GetError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -146,7 +177,7 @@ pub struct SetRequest<'a> {
     pub value: Option<&'a str>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SetResponse {
     error: Option<String>,
     #[serde(default)]
@@ -154,12 +185,12 @@ pub struct SetResponse {
     pub profile: Option<crate::UserProfile>,
 }
 
-impl<E: Error> Into<Result<SetResponse, SetError<E>>> for SetResponse {
-    fn into(self) -> Result<SetResponse, SetError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetResponse> for Result<SetResponse, SetError<E>> {
+    fn from(response: SetResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -256,11 +287,47 @@ SetError::InvalidPostType => "invalid_post_type: The method was called via a POS
 SetError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 SetError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 SetError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        SetError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        SetError::Unknown(ref s) => return write!(f, "{}", s),
-                        SetError::Client(ref inner) => return write!(f, "{}", inner),
+                        SetError::MalformedResponse(_, ref e) => return write!(f, "users.profile.set returned a malformed response: {}", e),
+                        SetError::Unknown(ref s) => return write!(f, "users.profile.set failed: {}", s),
+                        SetError::Client(ref inner) => return write!(f, "users.profile.set failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "users.profile.set failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetError<E>> for crate::SlackError<E> {
+    fn from(e: SetError<E>) -> Self {
+        let method = "users.profile.set";
+        let error = match e {
+            SetError::ReservedName => "reserved_name".to_owned(),
+            SetError::InvalidProfile => "invalid_profile".to_owned(),
+            SetError::ProfileSetFailed => "profile_set_failed".to_owned(),
+            SetError::NotAdmin => "not_admin".to_owned(),
+            SetError::NotAppAdmin => "not_app_admin".to_owned(),
+            SetError::CannotUpdateAdminUser => "cannot_update_admin_user".to_owned(),
+            SetError::NotAuthed => "not_authed".to_owned(),
+            SetError::InvalidAuth => "invalid_auth".to_owned(),
+            SetError::AccountInactive => "account_inactive".to_owned(),
+            SetError::UserIsBot => "user_is_bot".to_owned(),
+            SetError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetError::InvalidCharset => "invalid_charset".to_owned(),
+            SetError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetError::MissingPostType => "missing_post_type".to_owned(),
+            SetError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetError::RequestTimeout => "request_timeout".to_owned(),
+            SetError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetError::Unknown(s) => s,
+            SetError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -20,19 +20,19 @@ use std::convert::From;
 use std::error::Error;
 use std::fmt;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct DeletePhotoResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<DeletePhotoResponse, DeletePhotoError<E>>> for DeletePhotoResponse {
-    fn into(self) -> Result<DeletePhotoResponse, DeletePhotoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<DeletePhotoResponse> for Result<DeletePhotoResponse, DeletePhotoError<E>> {
+    fn from(response: DeletePhotoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -105,11 +105,41 @@ DeletePhotoError::InvalidPostType => "invalid_post_type: The method was called v
 DeletePhotoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 DeletePhotoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 DeletePhotoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        DeletePhotoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        DeletePhotoError::Unknown(ref s) => return write!(f, "{}", s),
-                        DeletePhotoError::Client(ref inner) => return write!(f, "{}", inner),
+                        DeletePhotoError::MalformedResponse(_, ref e) => return write!(f, "users.deletePhoto returned a malformed response: {}", e),
+                        DeletePhotoError::Unknown(ref s) => return write!(f, "users.deletePhoto failed: {}", s),
+                        DeletePhotoError::Client(ref inner) => return write!(f, "users.deletePhoto failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "users.deletePhoto failed: {}", d)
+    }
+}
+
+impl<E: Error> From<DeletePhotoError<E>> for crate::SlackError<E> {
+    fn from(e: DeletePhotoError<E>) -> Self {
+        let method = "users.deletePhoto";
+        let error = match e {
+            DeletePhotoError::NotAuthed => "not_authed".to_owned(),
+            DeletePhotoError::InvalidAuth => "invalid_auth".to_owned(),
+            DeletePhotoError::AccountInactive => "account_inactive".to_owned(),
+            DeletePhotoError::UserIsBot => "user_is_bot".to_owned(),
+            DeletePhotoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            DeletePhotoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            DeletePhotoError::InvalidCharset => "invalid_charset".to_owned(),
+            DeletePhotoError::InvalidFormData => "invalid_form_data".to_owned(),
+            DeletePhotoError::InvalidPostType => "invalid_post_type".to_owned(),
+            DeletePhotoError::MissingPostType => "missing_post_type".to_owned(),
+            DeletePhotoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            DeletePhotoError::RequestTimeout => "request_timeout".to_owned(),
+            DeletePhotoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            DeletePhotoError::Unknown(s) => s,
+            DeletePhotoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -129,7 +159,7 @@ pub struct GetPresenceRequest<'a> {
     pub user: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GetPresenceResponse {
     error: Option<String>,
     #[serde(default)]
@@ -137,12 +167,12 @@ pub struct GetPresenceResponse {
     pub presence: Option<String>,
 }
 
-impl<E: Error> Into<Result<GetPresenceResponse, GetPresenceError<E>>> for GetPresenceResponse {
-    fn into(self) -> Result<GetPresenceResponse, GetPresenceError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<GetPresenceResponse> for Result<GetPresenceResponse, GetPresenceError<E>> {
+    fn from(response: GetPresenceResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -211,11 +241,40 @@ GetPresenceError::InvalidPostType => "invalid_post_type: The method was called v
 GetPresenceError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 GetPresenceError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 GetPresenceError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        GetPresenceError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        GetPresenceError::Unknown(ref s) => return write!(f, "{}", s),
-                        GetPresenceError::Client(ref inner) => return write!(f, "{}", inner),
+                        GetPresenceError::MalformedResponse(_, ref e) => return write!(f, "users.getPresence returned a malformed response: {}", e),
+                        GetPresenceError::Unknown(ref s) => return write!(f, "users.getPresence failed: {}", s),
+                        GetPresenceError::Client(ref inner) => return write!(f, "users.getPresence failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "users.getPresence failed: {}", d)
+    }
+}
+
+impl<E: Error> From<GetPresenceError<E>> for crate::SlackError<E> {
+    fn from(e: GetPresenceError<E>) -> Self {
+        let method = "users.getPresence";
+        let error = match e {
+            GetPresenceError::NotAuthed => "not_authed".to_owned(),
+            GetPresenceError::InvalidAuth => "invalid_auth".to_owned(),
+            GetPresenceError::AccountInactive => "account_inactive".to_owned(),
+            GetPresenceError::InvalidArgName => "invalid_arg_name".to_owned(),
+            GetPresenceError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            GetPresenceError::InvalidCharset => "invalid_charset".to_owned(),
+            GetPresenceError::InvalidFormData => "invalid_form_data".to_owned(),
+            GetPresenceError::InvalidPostType => "invalid_post_type".to_owned(),
+            GetPresenceError::MissingPostType => "missing_post_type".to_owned(),
+            GetPresenceError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            GetPresenceError::RequestTimeout => "request_timeout".to_owned(),
+            GetPresenceError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            GetPresenceError::Unknown(s) => s,
+            GetPresenceError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -229,7 +288,7 @@ impl<E: Error + 'static> Error for GetPresenceError<E> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct IdentityResponse {
     error: Option<String>,
     #[serde(default)]
@@ -238,12 +297,12 @@ pub struct IdentityResponse {
     pub user: Option<crate::User>,
 }
 
-impl<E: Error> Into<Result<IdentityResponse, IdentityError<E>>> for IdentityResponse {
-    fn into(self) -> Result<IdentityResponse, IdentityError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<IdentityResponse> for Result<IdentityResponse, IdentityError<E>> {
+    fn from(response: IdentityResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -316,11 +375,41 @@ IdentityError::InvalidPostType => "invalid_post_type: The method was called via
 IdentityError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 IdentityError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 IdentityError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        IdentityError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        IdentityError::Unknown(ref s) => return write!(f, "{}", s),
-                        IdentityError::Client(ref inner) => return write!(f, "{}", inner),
+                        IdentityError::MalformedResponse(_, ref e) => return write!(f, "users.identity returned a malformed response: {}", e),
+                        IdentityError::Unknown(ref s) => return write!(f, "users.identity failed: {}", s),
+                        IdentityError::Client(ref inner) => return write!(f, "users.identity failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "users.identity failed: {}", d)
+    }
+}
+
+impl<E: Error> From<IdentityError<E>> for crate::SlackError<E> {
+    fn from(e: IdentityError<E>) -> Self {
+        let method = "users.identity";
+        let error = match e {
+            IdentityError::NotAuthed => "not_authed".to_owned(),
+            IdentityError::InvalidAuth => "invalid_auth".to_owned(),
+            IdentityError::AccountInactive => "account_inactive".to_owned(),
+            IdentityError::UserIsBot => "user_is_bot".to_owned(),
+            IdentityError::InvalidArgName => "invalid_arg_name".to_owned(),
+            IdentityError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            IdentityError::InvalidCharset => "invalid_charset".to_owned(),
+            IdentityError::InvalidFormData => "invalid_form_data".to_owned(),
+            IdentityError::InvalidPostType => "invalid_post_type".to_owned(),
+            IdentityError::MissingPostType => "missing_post_type".to_owned(),
+            IdentityError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            IdentityError::RequestTimeout => "request_timeout".to_owned(),
+            IdentityError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            IdentityError::Unknown(s) => s,
+            IdentityError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -338,9 +427,11 @@ impl<E: Error + 'static> Error for IdentityError<E> {
 pub struct InfoRequest<'a> {
     /// User to get info on
     pub user: &'a str,
+    /// Set this to true to receive the locale for this user. Defaults to false
+    pub include_locale: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct InfoResponse {
     error: Option<String>,
     #[serde(default)]
@@ -348,12 +439,12 @@ pub struct InfoResponse {
     pub user: Option<crate::User>,
 }
 
-impl<E: Error> Into<Result<InfoResponse, InfoError<E>>> for InfoResponse {
-    fn into(self) -> Result<InfoResponse, InfoError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<InfoResponse> for Result<InfoResponse, InfoError<E>> {
+    fn from(response: InfoResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -430,11 +521,42 @@ InfoError::InvalidPostType => "invalid_post_type: The method was called via a PO
 InfoError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 InfoError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 InfoError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        InfoError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        InfoError::Unknown(ref s) => return write!(f, "{}", s),
-                        InfoError::Client(ref inner) => return write!(f, "{}", inner),
+                        InfoError::MalformedResponse(_, ref e) => return write!(f, "users.info returned a malformed response: {}", e),
+                        InfoError::Unknown(ref s) => return write!(f, "users.info failed: {}", s),
+                        InfoError::Client(ref inner) => return write!(f, "users.info failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "users.info failed: {}", d)
+    }
+}
+
+impl<E: Error> From<InfoError<E>> for crate::SlackError<E> {
+    fn from(e: InfoError<E>) -> Self {
+        let method = "users.info";
+        let error = match e {
+            InfoError::UserNotFound => "user_not_found".to_owned(),
+            InfoError::UserNotVisible => "user_not_visible".to_owned(),
+            InfoError::NotAuthed => "not_authed".to_owned(),
+            InfoError::InvalidAuth => "invalid_auth".to_owned(),
+            InfoError::AccountInactive => "account_inactive".to_owned(),
+            InfoError::InvalidArgName => "invalid_arg_name".to_owned(),
+            InfoError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            InfoError::InvalidCharset => "invalid_charset".to_owned(),
+            InfoError::InvalidFormData => "invalid_form_data".to_owned(),
+            InfoError::InvalidPostType => "invalid_post_type".to_owned(),
+            InfoError::MissingPostType => "missing_post_type".to_owned(),
+            InfoError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            InfoError::RequestTimeout => "request_timeout".to_owned(),
+            InfoError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            InfoError::Unknown(s) => s,
+            InfoError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -449,25 +571,38 @@ impl<E: Error + 'static> Error for InfoError<E> {
 }
 
 #[derive(Clone, Default, Debug)]
-pub struct ListRequest {
+pub struct ListRequest<'a> {
     /// Whether to include presence data in the output
     pub presence: Option<bool>,
+    /// Paginate through collections of data by setting the `cursor` parameter to a `next_cursor` attribute returned by a previous request's `response_metadata`. Default value fetches the first "page" of the collection. See pagination for more detail.
+    pub cursor: Option<&'a crate::Cursor>,
+    /// The maximum number of items to return. Fewer than the requested number of items may be returned, even if the end of the users list hasn't been reached.
+    pub limit: Option<u32>,
+    /// Set this to true to receive the locale for users. Defaults to false
+    pub include_locale: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ListResponse {
     error: Option<String>,
     pub members: Option<Vec<crate::User>>,
     #[serde(default)]
     ok: bool,
+    pub response_metadata: Option<crate::ResponseMetadata>,
 }
 
-impl<E: Error> Into<Result<ListResponse, ListError<E>>> for ListResponse {
-    fn into(self) -> Result<ListResponse, ListError<E>> {
-        if self.ok {
-            Ok(self)
+impl crate::HasPagination for ListResponse {
+    fn response_metadata(&self) -> Option<&crate::ResponseMetadata> {
+        self.response_metadata.as_ref()
+    }
+}
+
+impl<E: Error> From<ListResponse> for Result<ListResponse, ListError<E>> {
+    fn from(response: ListResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -536,11 +671,40 @@ ListError::InvalidPostType => "invalid_post_type: The method was called via a PO
 ListError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 ListError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 ListError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        ListError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        ListError::Unknown(ref s) => return write!(f, "{}", s),
-                        ListError::Client(ref inner) => return write!(f, "{}", inner),
+                        ListError::MalformedResponse(_, ref e) => return write!(f, "users.list returned a malformed response: {}", e),
+                        ListError::Unknown(ref s) => return write!(f, "users.list failed: {}", s),
+                        ListError::Client(ref inner) => return write!(f, "users.list failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "users.list failed: {}", d)
+    }
+}
+
+impl<E: Error> From<ListError<E>> for crate::SlackError<E> {
+    fn from(e: ListError<E>) -> Self {
+        let method = "users.list";
+        let error = match e {
+            ListError::NotAuthed => "not_authed".to_owned(),
+            ListError::InvalidAuth => "invalid_auth".to_owned(),
+            ListError::AccountInactive => "account_inactive".to_owned(),
+            ListError::InvalidArgName => "invalid_arg_name".to_owned(),
+            ListError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            ListError::InvalidCharset => "invalid_charset".to_owned(),
+            ListError::InvalidFormData => "invalid_form_data".to_owned(),
+            ListError::InvalidPostType => "invalid_post_type".to_owned(),
+            ListError::MissingPostType => "missing_post_type".to_owned(),
+            ListError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            ListError::RequestTimeout => "request_timeout".to_owned(),
+            ListError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            ListError::Unknown(s) => s,
+            ListError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -554,19 +718,183 @@ impl<E: Error + 'static> Error for ListError<E> {
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Default, Debug)]
+pub struct LookupByEmailRequest<'a> {
+    /// An email address belonging to a user in the workspace
+    pub email: &'a str,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct LookupByEmailResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub user: Option<crate::User>,
+}
+
+impl<E: Error> From<LookupByEmailResponse>
+    for Result<LookupByEmailResponse, LookupByEmailError<E>>
+{
+    fn from(response: LookupByEmailResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum LookupByEmailError<E: Error> {
+    /// Value passed for email was invalid.
+    UsersNotFound,
+    /// The method cannot be called from an Enterprise.
+    EnterpriseIsRestricted,
+    /// The token type isn't allowed to call this method.
+    NotAllowedTokenType,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// The request has been ratelimited. Refer to the Retry-After header for when to retry the request.
+    Ratelimited,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for LookupByEmailError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "users_not_found" => LookupByEmailError::UsersNotFound,
+            "enterprise_is_restricted" => LookupByEmailError::EnterpriseIsRestricted,
+            "not_allowed_token_type" => LookupByEmailError::NotAllowedTokenType,
+            "missing_scope" => LookupByEmailError::MissingScope,
+            "ratelimited" => LookupByEmailError::Ratelimited,
+            "not_authed" => LookupByEmailError::NotAuthed,
+            "invalid_auth" => LookupByEmailError::InvalidAuth,
+            "account_inactive" => LookupByEmailError::AccountInactive,
+            "invalid_arg_name" => LookupByEmailError::InvalidArgName,
+            "invalid_array_arg" => LookupByEmailError::InvalidArrayArg,
+            "invalid_charset" => LookupByEmailError::InvalidCharset,
+            "invalid_form_data" => LookupByEmailError::InvalidFormData,
+            "invalid_post_type" => LookupByEmailError::InvalidPostType,
+            "missing_post_type" => LookupByEmailError::MissingPostType,
+            "team_added_to_org" => LookupByEmailError::TeamAddedToOrg,
+            "request_timeout" => LookupByEmailError::RequestTimeout,
+            _ => LookupByEmailError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for LookupByEmailError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        LookupByEmailError::UsersNotFound => "users_not_found: Value passed for email was invalid.",
+LookupByEmailError::EnterpriseIsRestricted => "enterprise_is_restricted: The method cannot be called from an Enterprise.",
+LookupByEmailError::NotAllowedTokenType => "not_allowed_token_type: The token type isn't allowed to call this method.",
+LookupByEmailError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+LookupByEmailError::Ratelimited => "ratelimited: The request has been ratelimited. Refer to the Retry-After header for when to retry the request.",
+LookupByEmailError::NotAuthed => "not_authed: No authentication token provided.",
+LookupByEmailError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+LookupByEmailError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+LookupByEmailError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+LookupByEmailError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+LookupByEmailError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+LookupByEmailError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+LookupByEmailError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+LookupByEmailError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+LookupByEmailError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+LookupByEmailError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        LookupByEmailError::MalformedResponse(_, ref e) => return write!(f, "users.lookupByEmail returned a malformed response: {}", e),
+                        LookupByEmailError::Unknown(ref s) => return write!(f, "users.lookupByEmail failed: {}", s),
+                        LookupByEmailError::Client(ref inner) => return write!(f, "users.lookupByEmail failed: {}", inner),
+                    };
+        write!(f, "users.lookupByEmail failed: {}", d)
+    }
+}
+
+impl<E: Error> From<LookupByEmailError<E>> for crate::SlackError<E> {
+    fn from(e: LookupByEmailError<E>) -> Self {
+        let method = "users.lookupByEmail";
+        let error = match e {
+            LookupByEmailError::UsersNotFound => "users_not_found".to_owned(),
+            LookupByEmailError::EnterpriseIsRestricted => "enterprise_is_restricted".to_owned(),
+            LookupByEmailError::NotAllowedTokenType => "not_allowed_token_type".to_owned(),
+            LookupByEmailError::MissingScope => "missing_scope".to_owned(),
+            LookupByEmailError::Ratelimited => "ratelimited".to_owned(),
+            LookupByEmailError::NotAuthed => "not_authed".to_owned(),
+            LookupByEmailError::InvalidAuth => "invalid_auth".to_owned(),
+            LookupByEmailError::AccountInactive => "account_inactive".to_owned(),
+            LookupByEmailError::InvalidArgName => "invalid_arg_name".to_owned(),
+            LookupByEmailError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            LookupByEmailError::InvalidCharset => "invalid_charset".to_owned(),
+            LookupByEmailError::InvalidFormData => "invalid_form_data".to_owned(),
+            LookupByEmailError::InvalidPostType => "invalid_post_type".to_owned(),
+            LookupByEmailError::MissingPostType => "missing_post_type".to_owned(),
+            LookupByEmailError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            LookupByEmailError::RequestTimeout => "request_timeout".to_owned(),
+            LookupByEmailError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            LookupByEmailError::Unknown(s) => s,
+            LookupByEmailError::Client(error) => {
+                return crate::SlackError::Client { method, error }
+            }
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for LookupByEmailError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            LookupByEmailError::MalformedResponse(_, ref e) => Some(e),
+            LookupByEmailError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SetActiveResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<SetActiveResponse, SetActiveError<E>>> for SetActiveResponse {
-    fn into(self) -> Result<SetActiveResponse, SetActiveError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetActiveResponse> for Result<SetActiveResponse, SetActiveError<E>> {
+    fn from(response: SetActiveResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -635,11 +963,40 @@ SetActiveError::InvalidPostType => "invalid_post_type: The method was called via
 SetActiveError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 SetActiveError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 SetActiveError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        SetActiveError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        SetActiveError::Unknown(ref s) => return write!(f, "{}", s),
-                        SetActiveError::Client(ref inner) => return write!(f, "{}", inner),
+                        SetActiveError::MalformedResponse(_, ref e) => return write!(f, "users.setActive returned a malformed response: {}", e),
+                        SetActiveError::Unknown(ref s) => return write!(f, "users.setActive failed: {}", s),
+                        SetActiveError::Client(ref inner) => return write!(f, "users.setActive failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "users.setActive failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetActiveError<E>> for crate::SlackError<E> {
+    fn from(e: SetActiveError<E>) -> Self {
+        let method = "users.setActive";
+        let error = match e {
+            SetActiveError::NotAuthed => "not_authed".to_owned(),
+            SetActiveError::InvalidAuth => "invalid_auth".to_owned(),
+            SetActiveError::AccountInactive => "account_inactive".to_owned(),
+            SetActiveError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetActiveError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetActiveError::InvalidCharset => "invalid_charset".to_owned(),
+            SetActiveError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetActiveError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetActiveError::MissingPostType => "missing_post_type".to_owned(),
+            SetActiveError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetActiveError::RequestTimeout => "request_timeout".to_owned(),
+            SetActiveError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetActiveError::Unknown(s) => s,
+            SetActiveError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -659,19 +1016,19 @@ pub struct SetPresenceRequest<'a> {
     pub presence: &'a str,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct SetPresenceResponse {
     error: Option<String>,
     #[serde(default)]
     ok: bool,
 }
 
-impl<E: Error> Into<Result<SetPresenceResponse, SetPresenceError<E>>> for SetPresenceResponse {
-    fn into(self) -> Result<SetPresenceResponse, SetPresenceError<E>> {
-        if self.ok {
-            Ok(self)
+impl<E: Error> From<SetPresenceResponse> for Result<SetPresenceResponse, SetPresenceError<E>> {
+    fn from(response: SetPresenceResponse) -> Self {
+        if response.ok {
+            Ok(response)
         } else {
-            Err(self.error.as_ref().map(String::as_ref).unwrap_or("").into())
+            Err(response.error.as_deref().unwrap_or("").into())
         }
     }
 }
@@ -744,11 +1101,41 @@ SetPresenceError::InvalidPostType => "invalid_post_type: The method was called v
 SetPresenceError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
 SetPresenceError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
 SetPresenceError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
-                        SetPresenceError::MalformedResponse(_, ref e) => return write!(f, "{}", e),
-                        SetPresenceError::Unknown(ref s) => return write!(f, "{}", s),
-                        SetPresenceError::Client(ref inner) => return write!(f, "{}", inner),
+                        SetPresenceError::MalformedResponse(_, ref e) => return write!(f, "users.setPresence returned a malformed response: {}", e),
+                        SetPresenceError::Unknown(ref s) => return write!(f, "users.setPresence failed: {}", s),
+                        SetPresenceError::Client(ref inner) => return write!(f, "users.setPresence failed: {}", inner),
                     };
-        write!(f, "{}", d)
+        write!(f, "users.setPresence failed: {}", d)
+    }
+}
+
+impl<E: Error> From<SetPresenceError<E>> for crate::SlackError<E> {
+    fn from(e: SetPresenceError<E>) -> Self {
+        let method = "users.setPresence";
+        let error = match e {
+            SetPresenceError::InvalidPresence => "invalid_presence".to_owned(),
+            SetPresenceError::NotAuthed => "not_authed".to_owned(),
+            SetPresenceError::InvalidAuth => "invalid_auth".to_owned(),
+            SetPresenceError::AccountInactive => "account_inactive".to_owned(),
+            SetPresenceError::InvalidArgName => "invalid_arg_name".to_owned(),
+            SetPresenceError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            SetPresenceError::InvalidCharset => "invalid_charset".to_owned(),
+            SetPresenceError::InvalidFormData => "invalid_form_data".to_owned(),
+            SetPresenceError::InvalidPostType => "invalid_post_type".to_owned(),
+            SetPresenceError::MissingPostType => "missing_post_type".to_owned(),
+            SetPresenceError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            SetPresenceError::RequestTimeout => "request_timeout".to_owned(),
+            SetPresenceError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            SetPresenceError::Unknown(s) => s,
+            SetPresenceError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
     }
 }
 
@@ -0,0 +1,534 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Publish and manage views, such as App Home tabs.
+
+#[allow(unused_imports)]
+use std::collections::HashMap;
+use std::convert::From;
+use std::error::Error;
+use std::fmt;
+
+#[derive(Clone, Debug)]
+pub struct OpenRequest<'a> {
+    /// Exchange a trigger to post to the user.
+    pub trigger_id: &'a crate::TriggerId,
+    /// A view payload.
+    pub view: &'a crate::blocks::ModalView,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OpenResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub view: Option<crate::View>,
+}
+
+impl<E: Error> From<OpenResponse> for Result<OpenResponse, OpenError<E>> {
+    fn from(response: OpenResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum OpenError<E: Error> {
+    /// The trigger_id has expired, 3 seconds after Slack sent it.
+    ExpiredTriggerId,
+    /// The trigger_id is invalid, or was already used.
+    InvalidTriggerId,
+    /// The trigger_id was already exchanged in a previous call.
+    ExchangedTriggerId,
+    /// A view with the same external_id already exists.
+    DuplicateExternalId,
+    /// The view exceeds the size limit.
+    ViewTooLarge,
+    /// The view payload was invalid, e.g. it has too many blocks.
+    InvalidArguments,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for OpenError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "expired_trigger_id" => OpenError::ExpiredTriggerId,
+            "invalid_trigger_id" => OpenError::InvalidTriggerId,
+            "exchanged_trigger_id" => OpenError::ExchangedTriggerId,
+            "duplicate_external_id" => OpenError::DuplicateExternalId,
+            "view_too_large" => OpenError::ViewTooLarge,
+            "invalid_arguments" => OpenError::InvalidArguments,
+            "missing_scope" => OpenError::MissingScope,
+            "not_authed" => OpenError::NotAuthed,
+            "invalid_auth" => OpenError::InvalidAuth,
+            "account_inactive" => OpenError::AccountInactive,
+            "invalid_arg_name" => OpenError::InvalidArgName,
+            "invalid_array_arg" => OpenError::InvalidArrayArg,
+            "invalid_charset" => OpenError::InvalidCharset,
+            "invalid_form_data" => OpenError::InvalidFormData,
+            "invalid_post_type" => OpenError::InvalidPostType,
+            "missing_post_type" => OpenError::MissingPostType,
+            "team_added_to_org" => OpenError::TeamAddedToOrg,
+            "request_timeout" => OpenError::RequestTimeout,
+            _ => OpenError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for OpenError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        OpenError::ExpiredTriggerId => "expired_trigger_id: The trigger_id has expired, 3 seconds after Slack sent it.",
+OpenError::InvalidTriggerId => "invalid_trigger_id: The trigger_id is invalid, or was already used.",
+OpenError::ExchangedTriggerId => "exchanged_trigger_id: The trigger_id was already exchanged in a previous call.",
+OpenError::DuplicateExternalId => "duplicate_external_id: A view with the same external_id already exists.",
+OpenError::ViewTooLarge => "view_too_large: The view exceeds the size limit.",
+OpenError::InvalidArguments => "invalid_arguments: The view payload was invalid, e.g. it has too many blocks.",
+OpenError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+OpenError::NotAuthed => "not_authed: No authentication token provided.",
+OpenError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+OpenError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+OpenError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+OpenError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+OpenError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+OpenError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+OpenError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+OpenError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+OpenError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+OpenError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        OpenError::MalformedResponse(_, ref e) => return write!(f, "views.open returned a malformed response: {}", e),
+                        OpenError::Unknown(ref s) => return write!(f, "views.open failed: {}", s),
+                        OpenError::Client(ref inner) => return write!(f, "views.open failed: {}", inner),
+                    };
+        write!(f, "views.open failed: {}", d)
+    }
+}
+
+impl<E: Error> From<OpenError<E>> for crate::SlackError<E> {
+    fn from(e: OpenError<E>) -> Self {
+        let method = "views.open";
+        let error = match e {
+            OpenError::ExpiredTriggerId => "expired_trigger_id".to_owned(),
+            OpenError::InvalidTriggerId => "invalid_trigger_id".to_owned(),
+            OpenError::ExchangedTriggerId => "exchanged_trigger_id".to_owned(),
+            OpenError::DuplicateExternalId => "duplicate_external_id".to_owned(),
+            OpenError::ViewTooLarge => "view_too_large".to_owned(),
+            OpenError::InvalidArguments => "invalid_arguments".to_owned(),
+            OpenError::MissingScope => "missing_scope".to_owned(),
+            OpenError::NotAuthed => "not_authed".to_owned(),
+            OpenError::InvalidAuth => "invalid_auth".to_owned(),
+            OpenError::AccountInactive => "account_inactive".to_owned(),
+            OpenError::InvalidArgName => "invalid_arg_name".to_owned(),
+            OpenError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            OpenError::InvalidCharset => "invalid_charset".to_owned(),
+            OpenError::InvalidFormData => "invalid_form_data".to_owned(),
+            OpenError::InvalidPostType => "invalid_post_type".to_owned(),
+            OpenError::MissingPostType => "missing_post_type".to_owned(),
+            OpenError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            OpenError::RequestTimeout => "request_timeout".to_owned(),
+            OpenError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            OpenError::Unknown(s) => s,
+            OpenError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for OpenError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            OpenError::MalformedResponse(_, ref e) => Some(e),
+            OpenError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PublishRequest<'a> {
+    /// id of the user you want publish a view to.
+    pub user_id: &'a str,
+    /// A view payload.
+    pub view: &'a crate::blocks::HomeView,
+    /// A string that represents view state to protect against possible race conditions.
+    pub hash: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PublishResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub view: Option<crate::View>,
+}
+
+impl<E: Error> From<PublishResponse> for Result<PublishResponse, PublishError<E>> {
+    fn from(response: PublishResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PublishError<E: Error> {
+    /// The provided hash doesn't match the current stored value, so the view has been updated since it was last retrieved.
+    HashConflict,
+    /// The view payload was invalid, e.g. it has too many blocks.
+    InvalidArguments,
+    /// The App Home tab isn't enabled for the app.
+    NotEnabled,
+    /// Value passed for user_id was invalid.
+    NotFound,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PublishError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "hash_conflict" => PublishError::HashConflict,
+            "invalid_arguments" => PublishError::InvalidArguments,
+            "not_enabled" => PublishError::NotEnabled,
+            "not_found" => PublishError::NotFound,
+            "missing_scope" => PublishError::MissingScope,
+            "not_authed" => PublishError::NotAuthed,
+            "invalid_auth" => PublishError::InvalidAuth,
+            "account_inactive" => PublishError::AccountInactive,
+            "invalid_arg_name" => PublishError::InvalidArgName,
+            "invalid_array_arg" => PublishError::InvalidArrayArg,
+            "invalid_charset" => PublishError::InvalidCharset,
+            "invalid_form_data" => PublishError::InvalidFormData,
+            "invalid_post_type" => PublishError::InvalidPostType,
+            "missing_post_type" => PublishError::MissingPostType,
+            "team_added_to_org" => PublishError::TeamAddedToOrg,
+            "request_timeout" => PublishError::RequestTimeout,
+            _ => PublishError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for PublishError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        PublishError::HashConflict => "hash_conflict: The provided hash doesn't match the current stored value, so the view has been updated since it was last retrieved.",
+PublishError::InvalidArguments => "invalid_arguments: The view payload was invalid, e.g. it has too many blocks.",
+PublishError::NotEnabled => "not_enabled: The App Home tab isn't enabled for the app.",
+PublishError::NotFound => "not_found: Value passed for user_id was invalid.",
+PublishError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+PublishError::NotAuthed => "not_authed: No authentication token provided.",
+PublishError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+PublishError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+PublishError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+PublishError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+PublishError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+PublishError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+PublishError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+PublishError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+PublishError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+PublishError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        PublishError::MalformedResponse(_, ref e) => return write!(f, "views.publish returned a malformed response: {}", e),
+                        PublishError::Unknown(ref s) => return write!(f, "views.publish failed: {}", s),
+                        PublishError::Client(ref inner) => return write!(f, "views.publish failed: {}", inner),
+                    };
+        write!(f, "views.publish failed: {}", d)
+    }
+}
+
+impl<E: Error> From<PublishError<E>> for crate::SlackError<E> {
+    fn from(e: PublishError<E>) -> Self {
+        let method = "views.publish";
+        let error = match e {
+            PublishError::HashConflict => "hash_conflict".to_owned(),
+            PublishError::InvalidArguments => "invalid_arguments".to_owned(),
+            PublishError::NotEnabled => "not_enabled".to_owned(),
+            PublishError::NotFound => "not_found".to_owned(),
+            PublishError::MissingScope => "missing_scope".to_owned(),
+            PublishError::NotAuthed => "not_authed".to_owned(),
+            PublishError::InvalidAuth => "invalid_auth".to_owned(),
+            PublishError::AccountInactive => "account_inactive".to_owned(),
+            PublishError::InvalidArgName => "invalid_arg_name".to_owned(),
+            PublishError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            PublishError::InvalidCharset => "invalid_charset".to_owned(),
+            PublishError::InvalidFormData => "invalid_form_data".to_owned(),
+            PublishError::InvalidPostType => "invalid_post_type".to_owned(),
+            PublishError::MissingPostType => "missing_post_type".to_owned(),
+            PublishError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            PublishError::RequestTimeout => "request_timeout".to_owned(),
+            PublishError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            PublishError::Unknown(s) => s,
+            PublishError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for PublishError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            PublishError::MalformedResponse(_, ref e) => Some(e),
+            PublishError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PushRequest<'a> {
+    /// Exchange a trigger to post to the user.
+    pub trigger_id: &'a crate::TriggerId,
+    /// A view payload.
+    pub view: &'a crate::blocks::ModalView,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct PushResponse {
+    error: Option<String>,
+    #[serde(default)]
+    ok: bool,
+    pub view: Option<crate::View>,
+}
+
+impl<E: Error> From<PushResponse> for Result<PushResponse, PushError<E>> {
+    fn from(response: PushResponse) -> Self {
+        if response.ok {
+            Ok(response)
+        } else {
+            Err(response.error.as_deref().unwrap_or("").into())
+        }
+    }
+}
+#[derive(Debug)]
+pub enum PushError<E: Error> {
+    /// The trigger_id has expired, 3 seconds after Slack sent it.
+    ExpiredTriggerId,
+    /// The trigger_id is invalid, or was already used.
+    InvalidTriggerId,
+    /// The trigger_id was already exchanged in a previous call.
+    ExchangedTriggerId,
+    /// A view with the same external_id already exists.
+    DuplicateExternalId,
+    /// The view exceeds the size limit.
+    ViewTooLarge,
+    /// The view payload was invalid, e.g. it has too many blocks.
+    InvalidArguments,
+    /// The token used is not granted the specific scope permissions required to complete this request.
+    MissingScope,
+    /// Three views are already on the stack, the most a modal can have.
+    PushLimitReached,
+    /// No authentication token provided.
+    NotAuthed,
+    /// Invalid authentication token.
+    InvalidAuth,
+    /// Authentication token is for a deleted user or team.
+    AccountInactive,
+    /// The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.
+    InvalidArgName,
+    /// The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.
+    InvalidArrayArg,
+    /// The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.
+    InvalidCharset,
+    /// The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.
+    InvalidFormData,
+    /// The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.
+    InvalidPostType,
+    /// The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.
+    MissingPostType,
+    /// The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.
+    TeamAddedToOrg,
+    /// The method was called via a POST request, but the POST data was either missing or truncated.
+    RequestTimeout,
+    /// The response was not parseable as the expected object
+    MalformedResponse(String, serde_json::error::Error),
+    /// The response returned an error that was unknown to the library
+    Unknown(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<'a, E: Error> From<&'a str> for PushError<E> {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "expired_trigger_id" => PushError::ExpiredTriggerId,
+            "invalid_trigger_id" => PushError::InvalidTriggerId,
+            "exchanged_trigger_id" => PushError::ExchangedTriggerId,
+            "duplicate_external_id" => PushError::DuplicateExternalId,
+            "view_too_large" => PushError::ViewTooLarge,
+            "invalid_arguments" => PushError::InvalidArguments,
+            "missing_scope" => PushError::MissingScope,
+            "push_limit_reached" => PushError::PushLimitReached,
+            "not_authed" => PushError::NotAuthed,
+            "invalid_auth" => PushError::InvalidAuth,
+            "account_inactive" => PushError::AccountInactive,
+            "invalid_arg_name" => PushError::InvalidArgName,
+            "invalid_array_arg" => PushError::InvalidArrayArg,
+            "invalid_charset" => PushError::InvalidCharset,
+            "invalid_form_data" => PushError::InvalidFormData,
+            "invalid_post_type" => PushError::InvalidPostType,
+            "missing_post_type" => PushError::MissingPostType,
+            "team_added_to_org" => PushError::TeamAddedToOrg,
+            "request_timeout" => PushError::RequestTimeout,
+            _ => PushError::Unknown(s.to_owned()),
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for PushError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let d = match *self {
+                        PushError::ExpiredTriggerId => "expired_trigger_id: The trigger_id has expired, 3 seconds after Slack sent it.",
+PushError::InvalidTriggerId => "invalid_trigger_id: The trigger_id is invalid, or was already used.",
+PushError::ExchangedTriggerId => "exchanged_trigger_id: The trigger_id was already exchanged in a previous call.",
+PushError::DuplicateExternalId => "duplicate_external_id: A view with the same external_id already exists.",
+PushError::ViewTooLarge => "view_too_large: The view exceeds the size limit.",
+PushError::InvalidArguments => "invalid_arguments: The view payload was invalid, e.g. it has too many blocks.",
+PushError::MissingScope => "missing_scope: The token used is not granted the specific scope permissions required to complete this request.",
+PushError::PushLimitReached => "push_limit_reached: Three views are already on the stack, the most a modal can have.",
+PushError::NotAuthed => "not_authed: No authentication token provided.",
+PushError::InvalidAuth => "invalid_auth: Invalid authentication token.",
+PushError::AccountInactive => "account_inactive: Authentication token is for a deleted user or team.",
+PushError::InvalidArgName => "invalid_arg_name: The method was passed an argument whose name falls outside the bounds of common decency. This includes very long names and names with non-alphanumeric characters other than _. If you get this error, it is typically an indication that you have made a very malformed API call.",
+PushError::InvalidArrayArg => "invalid_array_arg: The method was passed a PHP-style array argument (e.g. with a name like foo[7]). These are never valid with the Slack API.",
+PushError::InvalidCharset => "invalid_charset: The method was called via a POST request, but the charset specified in the Content-Type header was invalid. Valid charset names are: utf-8 iso-8859-1.",
+PushError::InvalidFormData => "invalid_form_data: The method was called via a POST request with Content-Type application/x-www-form-urlencoded or multipart/form-data, but the form data was either missing or syntactically invalid.",
+PushError::InvalidPostType => "invalid_post_type: The method was called via a POST request, but the specified Content-Type was invalid. Valid types are: application/x-www-form-urlencoded multipart/form-data text/plain.",
+PushError::MissingPostType => "missing_post_type: The method was called via a POST request and included a data payload, but the request did not include a Content-Type header.",
+PushError::TeamAddedToOrg => "team_added_to_org: The team associated with your request is currently undergoing migration to an Enterprise Organization. Web API and other platform operations will be intermittently unavailable until the transition is complete.",
+PushError::RequestTimeout => "request_timeout: The method was called via a POST request, but the POST data was either missing or truncated.",
+                        PushError::MalformedResponse(_, ref e) => return write!(f, "views.push returned a malformed response: {}", e),
+                        PushError::Unknown(ref s) => return write!(f, "views.push failed: {}", s),
+                        PushError::Client(ref inner) => return write!(f, "views.push failed: {}", inner),
+                    };
+        write!(f, "views.push failed: {}", d)
+    }
+}
+
+impl<E: Error> From<PushError<E>> for crate::SlackError<E> {
+    fn from(e: PushError<E>) -> Self {
+        let method = "views.push";
+        let error = match e {
+            PushError::ExpiredTriggerId => "expired_trigger_id".to_owned(),
+            PushError::InvalidTriggerId => "invalid_trigger_id".to_owned(),
+            PushError::ExchangedTriggerId => "exchanged_trigger_id".to_owned(),
+            PushError::DuplicateExternalId => "duplicate_external_id".to_owned(),
+            PushError::ViewTooLarge => "view_too_large".to_owned(),
+            PushError::InvalidArguments => "invalid_arguments".to_owned(),
+            PushError::MissingScope => "missing_scope".to_owned(),
+            PushError::PushLimitReached => "push_limit_reached".to_owned(),
+            PushError::NotAuthed => "not_authed".to_owned(),
+            PushError::InvalidAuth => "invalid_auth".to_owned(),
+            PushError::AccountInactive => "account_inactive".to_owned(),
+            PushError::InvalidArgName => "invalid_arg_name".to_owned(),
+            PushError::InvalidArrayArg => "invalid_array_arg".to_owned(),
+            PushError::InvalidCharset => "invalid_charset".to_owned(),
+            PushError::InvalidFormData => "invalid_form_data".to_owned(),
+            PushError::InvalidPostType => "invalid_post_type".to_owned(),
+            PushError::MissingPostType => "missing_post_type".to_owned(),
+            PushError::TeamAddedToOrg => "team_added_to_org".to_owned(),
+            PushError::RequestTimeout => "request_timeout".to_owned(),
+            PushError::MalformedResponse(body, error) => {
+                return crate::SlackError::MalformedResponse {
+                    method,
+                    body,
+                    error,
+                }
+            }
+            PushError::Unknown(s) => s,
+            PushError::Client(error) => return crate::SlackError::Client { method, error },
+        };
+        crate::SlackError::Api { method, error }
+    }
+}
+
+impl<E: Error + 'static> Error for PushError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            PushError::MalformedResponse(_, ref e) => Some(e),
+            PushError::Client(ref inner) => Some(inner),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,118 @@
+//! Types shared by the helpers which bring a user into a workspace.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::ext_types::usergroups::SyncMembersError;
+use crate::mod_types::admin_users_types::{AssignError, InviteError as AdminInviteError};
+use crate::mod_types::conversations_types::InviteError;
+use crate::mod_types::users_types::LookupByEmailError;
+
+#[derive(Clone, Default, Debug)]
+pub struct InviteUserRequest<'a> {
+    /// The email address of the user to bring in.
+    pub email: &'a str,
+    /// The workspace to bring the user into.
+    pub team_id: &'a str,
+    /// Channels of the workspace to add the user to.
+    pub channel_ids: &'a [&'a str],
+    /// User Groups to add the user to.
+    pub usergroups: &'a [&'a str],
+    /// The user's full name, if they're invited.
+    pub real_name: Option<&'a str>,
+    /// A message sent along with the invitation, if they're invited.
+    pub custom_message: Option<&'a str>,
+}
+
+/// How `invite_user` brought the user into the workspace.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Onboarded {
+    /// No user had the email address, so they were sent an invitation. They have no user ID, and
+    /// join the channels, until they accept it.
+    Invited,
+    /// A member of the organization was added to the workspace.
+    Assigned(String),
+    /// The user was already a member of the workspace, and was invited to the channels instead.
+    AlreadyMember(String),
+}
+
+impl Onboarded {
+    /// The ID of the user, unless they've only been invited.
+    pub fn user_id(&self) -> Option<&str> {
+        match *self {
+            Onboarded::Invited => None,
+            Onboarded::Assigned(ref id) | Onboarded::AlreadyMember(ref id) => Some(id),
+        }
+    }
+}
+
+/// The outcome of `invite_user`, once the user is in the workspace or invited to it.
+///
+/// The steps after that carry on past failures, which are reported here so they can be retried
+/// on their own.
+#[derive(Debug)]
+pub struct OnboardingReport<E: Error> {
+    pub onboarded: Onboarded,
+    /// Channels the user couldn't be invited to, each with its error. Only an existing member of
+    /// the workspace is invited to channels one by one.
+    pub channels_failed: Vec<(String, InviteError<E>)>,
+    /// User Groups the user was added to, or was already a member of.
+    pub usergroups_added: Vec<String>,
+    /// User Groups the user couldn't be added to, each with its error.
+    pub usergroups_failed: Vec<(String, SyncMembersError<E>)>,
+    /// User Groups left for when the user accepts their invitation, since until then they have no
+    /// user ID to add.
+    pub usergroups_pending: Vec<String>,
+}
+
+impl<E: Error> OnboardingReport<E> {
+    pub(crate) fn new(onboarded: Onboarded) -> Self {
+        OnboardingReport {
+            onboarded,
+            channels_failed: Vec::new(),
+            usergroups_added: Vec::new(),
+            usergroups_failed: Vec::new(),
+            usergroups_pending: Vec::new(),
+        }
+    }
+
+    /// Whether every step succeeded, with nothing left pending.
+    pub fn is_complete(&self) -> bool {
+        self.channels_failed.is_empty()
+            && self.usergroups_failed.is_empty()
+            && self.usergroups_pending.is_empty()
+    }
+}
+
+/// Why `invite_user` couldn't bring the user into the workspace. Nothing has been changed.
+#[derive(Debug)]
+pub enum InviteUserError<E: Error> {
+    /// Looking up the email address failed.
+    Lookup(LookupByEmailError<E>),
+    /// Inviting a new user failed.
+    Invite(AdminInviteError<E>),
+    /// Adding a member of the organization to the workspace failed.
+    Assign(AssignError<E>),
+}
+
+impl<E: Error> fmt::Display for InviteUserError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            InviteUserError::Lookup(ref e) => write!(f, "Looking up the user failed: {}", e),
+            InviteUserError::Invite(ref e) => write!(f, "Inviting the user failed: {}", e),
+            InviteUserError::Assign(ref e) => {
+                write!(f, "Adding the user to the workspace failed: {}", e)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for InviteUserError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            InviteUserError::Lookup(ref e) => Some(e),
+            InviteUserError::Invite(ref e) => Some(e),
+            InviteUserError::Assign(ref e) => Some(e),
+        }
+    }
+}
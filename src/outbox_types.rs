@@ -0,0 +1,279 @@
+//! Types shared by the durable outbox of fire-and-forget calls.
+
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A call waiting in an [`Outbox`] to be sent.
+///
+/// The token isn't stored with the call; it's given to `flush` instead, so it doesn't end up on
+/// disk.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct OutboxEntry {
+    /// Identifies the call, so enqueueing it twice only sends it once.
+    pub key: String,
+    /// The Web API method, such as `chat.postMessage`.
+    pub method: String,
+    pub params: Vec<(String, String)>,
+    /// When the call was enqueued, in microseconds since the Unix epoch.
+    pub enqueued_at: u64,
+}
+
+/// Where an [`Outbox`] keeps its calls until Slack has accepted them.
+pub trait OutboxStore {
+    type Error: Error + Send + Sync + 'static;
+
+    /// Stores `entry`, returning `false` without storing it if an entry with the same key is
+    /// already waiting.
+    fn insert(&self, entry: &OutboxEntry) -> Result<bool, Self::Error>;
+
+    /// The waiting entries, oldest first.
+    fn pending(&self) -> Result<Vec<OutboxEntry>, Self::Error>;
+
+    /// Removes the entry with `key`, if there is one.
+    fn remove(&self, key: &str) -> Result<(), Self::Error>;
+}
+
+/// Keeps calls in memory. They're lost when the process exits, so this is mostly useful in tests.
+#[derive(Debug, Default)]
+pub struct MemoryStore {
+    entries: Mutex<Vec<OutboxEntry>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        MemoryStore::default()
+    }
+}
+
+impl OutboxStore for MemoryStore {
+    type Error = io::Error;
+
+    fn insert(&self, entry: &OutboxEntry) -> Result<bool, Self::Error> {
+        let mut entries = self.entries.lock().unwrap();
+        if entries.iter().any(|e| e.key == entry.key) {
+            return Ok(false);
+        }
+        entries.push(entry.clone());
+        Ok(true)
+    }
+
+    fn pending(&self) -> Result<Vec<OutboxEntry>, Self::Error> {
+        Ok(self.entries.lock().unwrap().clone())
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Self::Error> {
+        self.entries.lock().unwrap().retain(|e| e.key != key);
+        Ok(())
+    }
+}
+
+/// Keeps each call as a JSON file in a directory, so calls survive a restart.
+///
+/// Files are written to a temporary name and renamed into place, so a crash never leaves a
+/// partly written call behind.
+#[derive(Clone, Debug)]
+pub struct FileStore {
+    dir: PathBuf,
+}
+
+impl FileStore {
+    /// Stores calls in `dir`, creating it if needed.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(FileStore { dir })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        // Hex keeps any key a valid file name.
+        let name: String = key.bytes().map(|b| format!("{:02x}", b)).collect();
+        self.dir.join(name + ".json")
+    }
+}
+
+impl OutboxStore for FileStore {
+    type Error = io::Error;
+
+    fn insert(&self, entry: &OutboxEntry) -> Result<bool, Self::Error> {
+        let path = self.path(&entry.key);
+        if path.exists() {
+            return Ok(false);
+        }
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec(entry)?)?;
+        fs::rename(&tmp, &path)?;
+        Ok(true)
+    }
+
+    fn pending(&self) -> Result<Vec<OutboxEntry>, Self::Error> {
+        let mut entries = Vec::new();
+        for file in fs::read_dir(&self.dir)? {
+            let path = file?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                entries.push(serde_json::from_slice::<OutboxEntry>(&fs::read(&path)?)?);
+            }
+        }
+        entries.sort_by(|a, b| (a.enqueued_at, &a.key).cmp(&(b.enqueued_at, &b.key)));
+        Ok(entries)
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Self::Error> {
+        match fs::remove_file(self.path(key)) {
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+}
+
+/// A durable queue of fire-and-forget calls, such as `chat.postMessage` notifications.
+///
+/// Calls are enqueued under a key and sent by `flush`, which removes each call once Slack has
+/// answered it. A call is only removed after it has been sent, so delivery is at least once: a
+/// crash between Slack accepting a call and its removal sends it again on the next flush. Reuse
+/// keys to make retries by the caller harmless, since a key that's already waiting is ignored.
+#[derive(Debug)]
+pub struct Outbox<S> {
+    pub(crate) store: S,
+}
+
+impl<S: OutboxStore> Outbox<S> {
+    pub fn new(store: S) -> Self {
+        Outbox { store }
+    }
+
+    /// Enqueues a call to `method`, returning `false` if a call with `key` is already waiting.
+    pub fn enqueue<K, M>(
+        &self,
+        key: K,
+        method: M,
+        params: &[(&str, &str)],
+    ) -> Result<bool, S::Error>
+    where
+        K: Into<String>,
+        M: Into<String>,
+    {
+        let enqueued_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |since| since.as_micros() as u64);
+        self.store.insert(&OutboxEntry {
+            key: key.into(),
+            method: method.into(),
+            params: params
+                .iter()
+                .map(|&(k, v)| (k.to_owned(), v.to_owned()))
+                .collect(),
+            enqueued_at,
+        })
+    }
+
+    /// The calls waiting to be sent, oldest first.
+    pub fn pending(&self) -> Result<Vec<OutboxEntry>, S::Error> {
+        self.store.pending()
+    }
+
+    pub fn into_inner(self) -> S {
+        self.store
+    }
+}
+
+/// The outcome of flushing an [`Outbox`].
+#[derive(Clone, Debug, Default)]
+pub struct FlushReport {
+    /// The keys of the calls Slack accepted.
+    pub sent: Vec<String>,
+    /// The keys of the calls Slack refused, such as with `channel_not_found`, each with its error.
+    /// Sending them again wouldn't help, so they're removed too.
+    pub rejected: Vec<(String, String)>,
+    /// How many calls are still waiting, such as after Slack rate limited the flush.
+    pub pending: usize,
+}
+
+#[derive(Debug)]
+pub enum FlushError<S: Error, E: Error> {
+    /// Reading or updating the store failed.
+    Store(S),
+    /// Sending a call failed. Calls before it have been sent, and it and the rest still wait.
+    Client(E),
+}
+
+impl<S: Error, E: Error> fmt::Display for FlushError<S, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            FlushError::Store(ref e) => write!(f, "Outbox store failed: {}", e),
+            FlushError::Client(ref e) => write!(f, "Client Error: {}", e),
+        }
+    }
+}
+
+impl<S: Error + 'static, E: Error + 'static> Error for FlushError<S, E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            FlushError::Store(ref e) => Some(e),
+            FlushError::Client(ref e) => Some(e),
+        }
+    }
+}
+
+/// What to do with a call after Slack answered it.
+pub(crate) enum Outcome {
+    Sent,
+    Rejected(String),
+    /// Stop flushing and leave the call waiting.
+    Retry,
+}
+
+#[derive(Deserialize)]
+struct Status {
+    #[serde(default)]
+    ok: bool,
+    error: Option<String>,
+}
+
+pub(crate) fn outcome(body: &str) -> Outcome {
+    match serde_json::from_str::<Status>(body) {
+        Ok(Status { ok: true, .. }) => Outcome::Sent,
+        Ok(Status {
+            error: Some(ref error),
+            ..
+        }) if error == "ratelimited" => Outcome::Retry,
+        Ok(Status { error, .. }) => Outcome::Rejected(error.unwrap_or_default()),
+        // Not a Slack response, such as a proxy's error page.
+        Err(_) => Outcome::Retry,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_file_store() {
+        let dir = std::env::temp_dir().join(format!("slack_api_outbox_{}", std::process::id()));
+        let outbox = Outbox::new(FileStore::new(&dir).unwrap());
+        assert!(outbox
+            .enqueue("deploy/1", "chat.postMessage", &[("channel", "C1")])
+            .unwrap());
+        assert!(!outbox
+            .enqueue("deploy/1", "chat.postMessage", &[("channel", "C2")])
+            .unwrap());
+        assert!(outbox
+            .enqueue("deploy/2", "chat.postMessage", &[("channel", "C3")])
+            .unwrap());
+
+        let reopened = Outbox::new(FileStore::new(&dir).unwrap());
+        let pending = reopened.pending().unwrap();
+        let keys: Vec<_> = pending.iter().map(|e| e.key.as_str()).collect();
+        assert_eq!(keys, ["deploy/1", "deploy/2"]);
+        assert_eq!(pending[0].params, [("channel".to_owned(), "C1".to_owned())]);
+
+        reopened.store.remove("deploy/1").unwrap();
+        reopened.store.remove("deploy/1").unwrap();
+        assert_eq!(reopened.pending().unwrap().len(), 1);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
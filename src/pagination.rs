@@ -0,0 +1,50 @@
+use crate::{Cursor, Paging, ResponseMetadata};
+
+/// A response from a method paginated by cursor, such as `conversations.list`.
+pub trait HasPagination {
+    /// The response's `response_metadata`, if it had one.
+    fn response_metadata(&self) -> Option<&ResponseMetadata>;
+
+    /// The cursor of the next page, or `None` on the last page.
+    fn next_cursor(&self) -> Option<&Cursor> {
+        self.response_metadata()
+            .and_then(|metadata| metadata.next_cursor.as_ref())
+    }
+}
+
+/// A response from a method paginated by page number, such as `stars.list`.
+pub trait HasPaging {
+    /// The response's `paging`, if it had one.
+    fn paging(&self) -> Option<&Paging>;
+
+    /// How many pages there are, counting a response without `paging` as a single page.
+    fn pages(&self) -> u32 {
+        self.paging()
+            .and_then(|paging| paging.pages)
+            .map_or(1, |pages| pages.max(1) as u32)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pagination_traits() {
+        let conversations: crate::mod_types::conversations_types::ListResponse =
+            serde_json::from_str(
+                r#"{"ok": true, "channels": [], "response_metadata": {"next_cursor": "abc"}}"#,
+            )
+            .unwrap();
+        assert_eq!(conversations.next_cursor().map(Cursor::as_str), Some("abc"));
+
+        let stars: crate::mod_types::stars_types::ListResponse = serde_json::from_str(
+            r#"{"ok": true, "items": [], "paging": {"count": 100, "page": 1, "pages": 3}}"#,
+        )
+        .unwrap();
+        assert_eq!(stars.pages(), 3);
+        let unpaged: crate::mod_types::stars_types::ListResponse =
+            serde_json::from_str(r#"{"ok": true, "items": []}"#).unwrap();
+        assert_eq!(unpaged.pages(), 1);
+    }
+}
@@ -0,0 +1,158 @@
+//! Types shared by the pollers which watch users' presence through the Web API.
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::rate_limit::RetryPolicy;
+
+/// How long to wait between rounds of polling every user, by default.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// How long to wait between two `users.getPresence` calls by default, keeping within its rate
+/// limit of around 50 requests per minute.
+pub const DEFAULT_CALL_INTERVAL: Duration = Duration::from_millis(1200);
+
+/// Whether a user is active in Slack.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Presence {
+    Active,
+    Away,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for Presence {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "active" => Presence::Active,
+            "away" => Presence::Away,
+            _ => Presence::Other(s.to_owned()),
+        }
+    }
+}
+
+/// A user's presence was seen to change between two polls, or was seen for the first time.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PresenceChanged {
+    pub user: String,
+    /// The presence seen before, or `None` the first time the user was polled.
+    pub previous: Option<Presence>,
+    pub presence: Presence,
+}
+
+/// Tracks the presence of a set of users by polling `users.getPresence`, for apps which can't
+/// subscribe to presence over RTM.
+///
+/// `poll` asks for each user's presence in turn, pacing the calls to stay within the method's
+/// rate limit, and returns the changes since the last poll. `run` keeps polling until it's
+/// cancelled. With the default pacing, one round takes over a minute for every 50 users.
+#[derive(Clone, Debug)]
+pub struct PresencePoller {
+    pub(crate) users: Vec<String>,
+    pub(crate) presence: HashMap<String, Presence>,
+    pub(crate) interval: Duration,
+    pub(crate) call_interval: Duration,
+    pub(crate) retry_policy: RetryPolicy,
+}
+
+impl PresencePoller {
+    pub fn new<I, S>(users: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut poller = PresencePoller {
+            users: Vec::new(),
+            presence: HashMap::new(),
+            interval: DEFAULT_POLL_INTERVAL,
+            call_interval: DEFAULT_CALL_INTERVAL,
+            retry_policy: RetryPolicy::new(),
+        };
+        for user in users {
+            poller.add_user(user);
+        }
+        poller
+    }
+
+    /// How long `run` waits after one round of polling before the next. Defaults to
+    /// [`DEFAULT_POLL_INTERVAL`].
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// How long to wait between two calls within a round. Defaults to
+    /// [`DEFAULT_CALL_INTERVAL`].
+    pub fn call_interval(mut self, interval: Duration) -> Self {
+        self.call_interval = interval;
+        self
+    }
+
+    /// How rate limited calls are retried. Defaults to [`RetryPolicy::new()`].
+    pub fn retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Starts tracking a user, from the next round.
+    pub fn add_user<S: Into<String>>(&mut self, user: S) {
+        let user = user.into();
+        if !self.users.contains(&user) {
+            self.users.push(user);
+        }
+    }
+
+    /// Stops tracking a user, forgetting their presence.
+    pub fn remove_user(&mut self, user: &str) {
+        self.users.retain(|u| u != user);
+        self.presence.remove(user);
+    }
+
+    pub fn users(&self) -> &[String] {
+        &self.users
+    }
+
+    /// The user's presence as of the last poll, if they've been polled.
+    pub fn presence(&self, user: &str) -> Option<&Presence> {
+        self.presence.get(user)
+    }
+
+    /// Records the presence a poll found, returning the change if it is one.
+    pub(crate) fn record(&mut self, user: &str, presence: Presence) -> Option<PresenceChanged> {
+        let previous = self.presence.insert(user.to_owned(), presence.clone());
+        if previous.as_ref() == Some(&presence) {
+            return None;
+        }
+        Some(PresenceChanged {
+            user: user.to_owned(),
+            previous,
+            presence,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_reports_changes() {
+        let mut poller = PresencePoller::new(vec!["U1", "U2", "U1"]);
+        assert_eq!(poller.users(), ["U1", "U2"]);
+        assert_eq!(
+            poller.record("U1", Presence::Away),
+            Some(PresenceChanged {
+                user: "U1".into(),
+                previous: None,
+                presence: Presence::Away,
+            })
+        );
+        assert_eq!(poller.record("U1", Presence::Away), None);
+        let change = poller.record("U1", "active".into()).unwrap();
+        assert_eq!(change.previous, Some(Presence::Away));
+        assert_eq!(poller.presence("U1"), Some(&Presence::Active));
+
+        poller.remove_user("U1");
+        assert_eq!(poller.users(), ["U2"]);
+        assert_eq!(poller.presence("U1"), None);
+    }
+}
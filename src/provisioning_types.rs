@@ -0,0 +1,165 @@
+//! Types shared by the helpers which provision channels.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::ext_types::conversations::MembershipReport;
+use crate::mod_types::bookmarks_types::{AddError, ListError as BookmarksListError};
+use crate::mod_types::conversations_types::{
+    CreateError, InviteError, ListError, SetPurposeError, SetTopicError,
+};
+use crate::{Bookmark, Conversation, UserId};
+
+#[derive(Clone, Default, Debug)]
+pub struct EnsureChannelRequest<'a> {
+    /// Name of the channel, without the leading `#`.
+    pub name: &'a str,
+    /// Create a private channel instead of a public one. Ignored if the channel already exists.
+    pub is_private: Option<bool>,
+    /// Encoded team id to create the channel in, required if org token is used.
+    pub team_id: Option<&'a str>,
+    /// The channel's topic, left alone if not given.
+    pub topic: Option<&'a str>,
+    /// The channel's purpose, left alone if not given.
+    pub purpose: Option<&'a str>,
+    /// Users who should be members of the channel.
+    pub members: &'a [UserId],
+    /// Links which should be in the channel's bookmark bar.
+    pub bookmarks: &'a [BookmarkLink<'a>],
+}
+
+/// A link to bookmark in a channel. A bookmark with the same link counts as already added.
+#[derive(Clone, Copy, Default, Debug)]
+pub struct BookmarkLink<'a> {
+    pub title: &'a str,
+    pub link: &'a str,
+    pub emoji: Option<&'a str>,
+}
+
+#[derive(Debug)]
+pub struct EnsuredChannel<E: Error> {
+    pub channel: Conversation,
+    /// Whether the channel was created rather than found.
+    pub created: bool,
+    /// The outcome of inviting the members. Members who were already in the channel are counted
+    /// as succeeded.
+    pub members: MembershipReport<InviteError<E>>,
+    /// The number of bookmarks added.
+    pub bookmarks_added: usize,
+}
+
+#[derive(Debug)]
+pub enum EnsureChannelError<E: Error> {
+    /// Creating the channel failed.
+    Create(CreateError<E>),
+    /// Looking up the channel after its name was taken failed.
+    Lookup(ListError<E>),
+    /// The name is taken, but not by a channel the caller can see, such as a private channel
+    /// they aren't in.
+    NotFound,
+    /// The name is taken by an archived channel.
+    Archived(Box<Conversation>),
+    /// Setting the topic failed.
+    SetTopic(SetTopicError<E>),
+    /// Setting the purpose failed.
+    SetPurpose(SetPurposeError<E>),
+    /// Listing the channel's bookmarks failed.
+    ListBookmarks(BookmarksListError<E>),
+    /// Adding a bookmark failed. Bookmarks before it have already been added.
+    AddBookmark(AddError<E>),
+}
+
+impl<E: Error> fmt::Display for EnsureChannelError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            EnsureChannelError::Create(ref e) => write!(f, "Creating the channel failed: {}", e),
+            EnsureChannelError::Lookup(ref e) => write!(f, "Looking up the channel failed: {}", e),
+            EnsureChannelError::NotFound => {
+                write!(
+                    f,
+                    "The channel name is taken by a channel that can't be found"
+                )
+            }
+            EnsureChannelError::Archived(_) => {
+                write!(f, "The channel name is taken by an archived channel")
+            }
+            EnsureChannelError::SetTopic(ref e) => write!(f, "Setting the topic failed: {}", e),
+            EnsureChannelError::SetPurpose(ref e) => {
+                write!(f, "Setting the purpose failed: {}", e)
+            }
+            EnsureChannelError::ListBookmarks(ref e) => {
+                write!(f, "Listing the bookmarks failed: {}", e)
+            }
+            EnsureChannelError::AddBookmark(ref e) => write!(f, "Adding a bookmark failed: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for EnsureChannelError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            EnsureChannelError::Create(ref e) => Some(e),
+            EnsureChannelError::Lookup(ref e) => Some(e),
+            EnsureChannelError::NotFound | EnsureChannelError::Archived(_) => None,
+            EnsureChannelError::SetTopic(ref e) => Some(e),
+            EnsureChannelError::SetPurpose(ref e) => Some(e),
+            EnsureChannelError::ListBookmarks(ref e) => Some(e),
+            EnsureChannelError::AddBookmark(ref e) => Some(e),
+        }
+    }
+}
+
+/// Checks that a found or created channel can be provisioned, returning its ID.
+pub(crate) fn usable_channel_id<E: Error>(
+    channel: &Conversation,
+) -> Result<String, EnsureChannelError<E>> {
+    if channel.is_archived == Some(true) {
+        return Err(EnsureChannelError::Archived(Box::new(channel.clone())));
+    }
+    channel.id.clone().ok_or(EnsureChannelError::NotFound)
+}
+
+pub(crate) fn find_by_name(
+    channels: Option<Vec<Conversation>>,
+    name: &str,
+) -> Option<Conversation> {
+    channels
+        .into_iter()
+        .flatten()
+        .find(|channel| channel.name.as_deref() == Some(name))
+}
+
+/// The `wanted` value, unless there's none or it's already the `current` one.
+pub(crate) fn needs_update<'a>(current: Option<&str>, wanted: Option<&'a str>) -> Option<&'a str> {
+    wanted.filter(|&wanted| current != Some(wanted))
+}
+
+/// The bookmarks in `wanted` whose links aren't among the `existing` bookmarks.
+pub(crate) fn missing_bookmarks<'a, 'b>(
+    existing: &[Bookmark],
+    wanted: &'b [BookmarkLink<'a>],
+) -> Vec<&'b BookmarkLink<'a>> {
+    wanted
+        .iter()
+        .filter(|bookmark| {
+            !existing
+                .iter()
+                .any(|b| b.link.as_deref() == Some(bookmark.link))
+        })
+        .collect()
+}
+
+/// Counts members who were already in the channel as succeeded.
+pub(crate) fn settle_members<E: Error>(
+    mut report: MembershipReport<InviteError<E>>,
+) -> MembershipReport<InviteError<E>> {
+    let (present, failed) = report
+        .failed
+        .into_iter()
+        .partition::<Vec<_>, _>(|(_, e)| matches!(e, InviteError::AlreadyInChannel));
+    report.failed = failed;
+    report
+        .succeeded
+        .extend(present.into_iter().map(|(user, _)| user));
+    report
+}
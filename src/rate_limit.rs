@@ -0,0 +1,315 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+/// How a [`RateLimitRetry`] waits out rate limited calls.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    max_retries: u32,
+    default_wait: Duration,
+    max_wait: Duration,
+}
+
+impl RetryPolicy {
+    /// Retries a rate limited call up to 3 times, waiting as long as Slack's `Retry-After` asks,
+    /// or 60 seconds if it didn't say, and never more than 5 minutes.
+    pub fn new() -> Self {
+        RetryPolicy {
+            max_retries: 3,
+            default_wait: Duration::from_secs(60),
+            max_wait: Duration::from_secs(300),
+        }
+    }
+
+    /// How many times a call is retried before its rate limited response is returned. `0` turns
+    /// retrying off.
+    pub fn max_retries(mut self, retries: u32) -> Self {
+        self.max_retries = retries;
+        self
+    }
+
+    /// How long to wait when the response didn't say, such as from a sender other than the
+    /// default clients.
+    pub fn default_wait(mut self, wait: Duration) -> Self {
+        self.default_wait = wait;
+        self
+    }
+
+    /// The longest a single wait can be, whatever Slack asked for.
+    pub fn max_wait(mut self, wait: Duration) -> Self {
+        self.max_wait = wait;
+        self
+    }
+
+    /// How long to wait before retry number `retries + 1`, or `None` once they're used up.
+    pub(crate) fn wait(&self, retries: u32, retry_after: Option<Duration>) -> Option<Duration> {
+        if retries >= self.max_retries {
+            return None;
+        }
+        Some(retry_after.unwrap_or(self.default_wait).min(self.max_wait))
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy::new()
+    }
+}
+
+/// The callback given the stats of every call Slack rate limited, once the call is over.
+type OnRateLimited = Arc<dyn Fn(&RateLimitStats) + Send + Sync>;
+
+/// Wraps a sender to wait out `ratelimited` responses and send the same call again.
+///
+/// Because the retried call has the same parameters, a pager such as `users::list_active` or
+/// `stars::list_all` resumes from the cursor or page it was fetching instead of failing partway
+/// through an export. With the default clients the wait honours the `Retry-After` header of
+/// Slack's 429 response. Once the policy's retries are used up the `ratelimited` error is
+/// returned as usual.
+#[derive(Clone)]
+pub struct RateLimitRetry<R> {
+    pub(crate) sender: R,
+    pub(crate) policy: RetryPolicy,
+    on_rate_limited: Option<OnRateLimited>,
+}
+
+impl<R> RateLimitRetry<R> {
+    /// Wraps `sender` with the default [`RetryPolicy`].
+    pub fn new(sender: R) -> Self {
+        RateLimitRetry {
+            sender,
+            policy: RetryPolicy::new(),
+            on_rate_limited: None,
+        }
+    }
+
+    pub fn policy(mut self, policy: RetryPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Calls `callback` with the [`RateLimitStats`] of every call Slack rate limited, once the
+    /// call is over, so throttling can be logged or alerted on before it shows up as latency.
+    pub fn on_rate_limited<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(&RateLimitStats) + Send + Sync + 'static,
+    {
+        self.on_rate_limited = Some(Arc::new(callback));
+        self
+    }
+
+    pub fn into_inner(self) -> R {
+        self.sender
+    }
+
+    /// Records a response to a call in `stats`, returning how long to wait before sending it
+    /// again, or `None` once the response should be returned.
+    pub(crate) fn next_wait(&self, stats: &mut RateLimitStats, body: &str) -> Option<Duration> {
+        let retry_after = match rate_limited(body) {
+            Some(retry_after) => retry_after,
+            None => {
+                self.report(stats);
+                return None;
+            }
+        };
+        stats.retry_after.extend(retry_after);
+        match self.policy.wait(stats.retries, retry_after) {
+            Some(wait) => {
+                stats.retries += 1;
+                stats.total_wait += wait;
+                Some(wait)
+            }
+            None => {
+                stats.gave_up = true;
+                self.report(stats);
+                None
+            }
+        }
+    }
+
+    /// Hands the stats of a finished call to the callback, if the call was rate limited.
+    pub(crate) fn report(&self, stats: &RateLimitStats) {
+        if let Some(ref callback) = self.on_rate_limited {
+            if stats.retries > 0 || stats.gave_up {
+                callback(stats);
+            }
+        }
+    }
+}
+
+impl<R: fmt::Debug> fmt::Debug for RateLimitRetry<R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RateLimitRetry")
+            .field("sender", &self.sender)
+            .field("policy", &self.policy)
+            .field("on_rate_limited", &self.on_rate_limited.is_some())
+            .finish()
+    }
+}
+
+/// How a call sent through a [`RateLimitRetry`] was rate limited.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct RateLimitStats {
+    /// The Web API method called, such as `users.list`.
+    pub method: String,
+    /// How many times the call was sent again.
+    pub retries: u32,
+    /// The `Retry-After` of each rate limited response that had one, in order.
+    pub retry_after: Vec<Duration>,
+    /// How long was spent waiting before retries.
+    pub total_wait: Duration,
+    /// Whether the retries were used up, so the call returned its `ratelimited` error.
+    pub gave_up: bool,
+}
+
+impl RateLimitStats {
+    pub(crate) fn new(method_url: &str) -> Self {
+        RateLimitStats {
+            method: method_url
+                .rsplit('/')
+                .next()
+                .unwrap_or(method_url)
+                .to_owned(),
+            ..RateLimitStats::default()
+        }
+    }
+}
+
+/// Copies the parameters of a call so it can be sent again.
+pub(crate) fn owned_params<I, K, V>(params: I) -> Vec<(String, String)>
+where
+    I: IntoIterator,
+    K: AsRef<str>,
+    V: AsRef<str>,
+    I::Item: Borrow<(K, V)>,
+{
+    params
+        .into_iter()
+        .map(|p| {
+            let (k, v) = p.borrow();
+            (k.as_ref().to_owned(), v.as_ref().to_owned())
+        })
+        .collect()
+}
+
+#[derive(Deserialize)]
+struct Status {
+    #[serde(default)]
+    ok: bool,
+    error: Option<String>,
+    retry_after: Option<u64>,
+}
+
+/// If `body` is a `ratelimited` error, returns how long Slack asked the caller to wait, if it said.
+pub(crate) fn rate_limited(body: &str) -> Option<Option<Duration>> {
+    // Avoid parsing every page of a large export just to find it wasn't rate limited.
+    if !body.contains("ratelimited") {
+        return None;
+    }
+    match serde_json::from_str::<Status>(body) {
+        Ok(ref status) if !status.ok && status.error.as_deref() == Some("ratelimited") => {
+            Some(status.retry_after.map(Duration::from_secs))
+        }
+        _ => None,
+    }
+}
+
+/// Records the `Retry-After` header of a 429 response in its body as `retry_after`, since senders
+/// only hand back the body.
+#[cfg(any(feature = "reqwest", feature = "reqwest_blocking"))]
+pub(crate) fn with_retry_after(body: String, retry_after: Option<&str>) -> String {
+    let seconds = match retry_after.and_then(|s| s.trim().parse::<u64>().ok()) {
+        Some(seconds) => seconds,
+        None => return body,
+    };
+    match serde_json::from_str::<serde_json::Map<String, serde_json::Value>>(&body) {
+        Ok(mut fields) => {
+            fields.insert("retry_after".to_owned(), seconds.into());
+            serde_json::Value::Object(fields).to_string()
+        }
+        Err(_) => body,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limited() {
+        assert_eq!(
+            rate_limited(r#"{"ok": false, "error": "ratelimited", "retry_after": 30}"#),
+            Some(Some(Duration::from_secs(30)))
+        );
+        assert_eq!(
+            rate_limited(r#"{"ok": false, "error": "ratelimited"}"#),
+            Some(None)
+        );
+        assert_eq!(rate_limited(r#"{"ok": true, "text": "ratelimited"}"#), None);
+        assert_eq!(
+            rate_limited(r#"{"ok": false, "error": "not_authed"}"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_rate_limit_stats() {
+        use std::sync::Mutex;
+
+        let reported = Arc::new(Mutex::new(Vec::new()));
+        let retry = {
+            let reported = reported.clone();
+            RateLimitRetry::new(())
+                .policy(RetryPolicy::new().max_retries(1))
+                .on_rate_limited(move |stats| reported.lock().unwrap().push(stats.clone()))
+        };
+        let limited = r#"{"ok": false, "error": "ratelimited", "retry_after": 2}"#;
+
+        let mut stats = RateLimitStats::new("https://slack.com/api/users.list");
+        assert_eq!(
+            retry.next_wait(&mut stats, limited),
+            Some(Duration::from_secs(2))
+        );
+        assert_eq!(retry.next_wait(&mut stats, limited), None);
+        let mut unlimited = RateLimitStats::new("https://slack.com/api/users.info");
+        assert_eq!(retry.next_wait(&mut unlimited, r#"{"ok": true}"#), None);
+
+        assert_eq!(
+            *reported.lock().unwrap(),
+            [RateLimitStats {
+                method: "users.list".to_owned(),
+                retries: 1,
+                retry_after: vec![Duration::from_secs(2), Duration::from_secs(2)],
+                total_wait: Duration::from_secs(2),
+                gave_up: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_retry_policy_wait() {
+        let policy = RetryPolicy::new()
+            .max_retries(2)
+            .default_wait(Duration::from_secs(5))
+            .max_wait(Duration::from_secs(10));
+        assert_eq!(policy.wait(0, None), Some(Duration::from_secs(5)));
+        assert_eq!(
+            policy.wait(1, Some(Duration::from_secs(120))),
+            Some(Duration::from_secs(10))
+        );
+        assert_eq!(policy.wait(2, Some(Duration::from_secs(1))), None);
+    }
+
+    #[cfg(any(feature = "reqwest", feature = "reqwest_blocking"))]
+    #[test]
+    fn test_with_retry_after() {
+        let body = with_retry_after(
+            r#"{"ok":false,"error":"ratelimited"}"#.to_owned(),
+            Some("7"),
+        );
+        assert_eq!(rate_limited(&body), Some(Some(Duration::from_secs(7))));
+        let body = with_retry_after("<html>".to_owned(), Some("7"));
+        assert_eq!(body, "<html>");
+    }
+}
@@ -0,0 +1,81 @@
+use std::error::Error;
+use std::fmt;
+
+use serde_json::Value;
+
+/// A message to post to a channel over the RTM connection.
+///
+/// RTM messages are plain text only; use `chat.postMessage` for attachments or blocks.
+#[derive(Clone, Default, Debug)]
+pub struct OutgoingMessage<'a> {
+    pub channel: &'a str,
+    pub text: &'a str,
+    /// Reply in the thread of this message.
+    pub thread_ts: Option<crate::Timestamp>,
+}
+
+impl<'a> OutgoingMessage<'a> {
+    pub(crate) fn to_value(&self) -> Value {
+        let mut message = serde_json::json!({
+            "type": "message",
+            "channel": self.channel,
+            "text": self.text,
+        });
+        if let Some(ts) = self.thread_ts {
+            message["thread_ts"] = Value::String(ts.to_param_value());
+        }
+        message
+    }
+}
+
+/// Slack's reply to a message sent over RTM.
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageReply {
+    pub text: Option<String>,
+    pub ts: Option<crate::Timestamp>,
+}
+
+#[derive(Debug)]
+pub enum SendError {
+    /// Slack rejected the message.
+    Rejected {
+        code: Option<i64>,
+        msg: Option<String>,
+    },
+    /// The reply couldn't be understood.
+    MalformedReply(Value),
+    /// The client stopped before the message was replied to.
+    ClientStopped,
+}
+
+impl SendError {
+    pub(crate) fn from_reply(reply: Value) -> Result<MessageReply, SendError> {
+        if reply.get("ok").and_then(Value::as_bool) == Some(true) {
+            return serde_json::from_value(reply.clone())
+                .map_err(|_| SendError::MalformedReply(reply));
+        }
+        Err(SendError::Rejected {
+            code: reply.pointer("/error/code").and_then(Value::as_i64),
+            msg: reply
+                .pointer("/error/msg")
+                .and_then(Value::as_str)
+                .map(str::to_owned),
+        })
+    }
+}
+
+impl fmt::Display for SendError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            SendError::Rejected { ref msg, .. } => write!(
+                f,
+                "Message rejected: {}",
+                msg.as_deref().unwrap_or("unknown error")
+            ),
+            SendError::MalformedReply(ref reply) => write!(f, "Malformed reply: {}", reply),
+            SendError::ClientStopped => write!(f, "The RTM client has stopped"),
+        }
+    }
+}
+
+impl Error for SendError {}
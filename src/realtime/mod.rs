@@ -0,0 +1,649 @@
+//! A client for the [Real Time Messaging API](https://api.slack.com/rtm).
+//!
+//! This crate doesn't include a WebSocket implementation. Connect to the URL returned by
+//! `rtm::connect` with the WebSocket library of your choice, implement [`RtmTransport`] for it and
+//! drive the connection with [`RtmClient::run`].
+//!
+//! An [`RtmHandle`] can be taken from the client before it is run to shut it down cleanly or to
+//! report on its health, e.g. from a readiness probe.
+
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, SystemTime};
+
+use async_trait::async_trait;
+use futures_channel::{mpsc, oneshot};
+use futures_timer::Delay;
+use futures_util::{future, FutureExt, StreamExt};
+use serde_json::Value;
+
+use crate::events::Event;
+
+mod messages;
+pub use self::messages::{MessageReply, OutgoingMessage, SendError};
+
+mod queue;
+use self::queue::EventQueue;
+pub use self::queue::{OverflowPolicy, QueueMetrics};
+
+/// A WebSocket connection to Slack's RTM API.
+#[async_trait]
+pub trait RtmTransport: Send {
+    type Error: Error + Send + Sync + 'static;
+
+    /// Sends a text frame.
+    async fn send(&mut self, text: String) -> Result<(), Self::Error>;
+
+    /// Receives the next text frame, or `None` once the connection has been closed.
+    ///
+    /// The returned future may be dropped before it completes when the client needs to send
+    /// something, so it must not lose frames when cancelled.
+    async fn recv(&mut self) -> Option<Result<String, Self::Error>>;
+
+    /// Closes the connection.
+    async fn close(&mut self) -> Result<(), Self::Error>;
+}
+
+/// Receives events from an [`RtmClient`].
+#[async_trait]
+pub trait RtmHandler: Send + Sync + 'static {
+    async fn event(&self, event: Event);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConnectionState {
+    /// Waiting for Slack's `hello` message.
+    Connecting,
+    Connected,
+    /// A shutdown was requested; waiting for replies to messages already sent.
+    ShuttingDown,
+    Closed,
+}
+
+/// A snapshot of a connection's health.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Health {
+    pub state: ConnectionState,
+    /// When Slack's `hello` message was received.
+    pub connected_since: Option<SystemTime>,
+    /// When the last ping was sent.
+    pub last_ping: Option<SystemTime>,
+    /// When the last reply to a ping was received.
+    pub last_pong: Option<SystemTime>,
+    /// The number of messages sent which haven't been replied to yet.
+    pub pending: usize,
+}
+
+impl Health {
+    /// Whether the connection is up and has answered a ping within `max_pong_age`.
+    pub fn is_ready(&self, max_pong_age: Duration) -> bool {
+        let recent_pong = self
+            .last_pong
+            .or(self.connected_since)
+            .and_then(|t| t.elapsed().ok())
+            .is_some_and(|age| age <= max_pong_age);
+        self.state == ConnectionState::Connected && recent_pong
+    }
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Health {
+            state: ConnectionState::Connecting,
+            connected_since: None,
+            last_ping: None,
+            last_pong: None,
+            pending: 0,
+        }
+    }
+}
+
+/// Why [`RtmClient::run`] returned.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Disconnect {
+    /// [`RtmHandle::shutdown`] was called.
+    Shutdown,
+    /// Slack sent a `goodbye` message; a new connection should be made.
+    Goodbye,
+    /// The connection was closed.
+    Closed,
+}
+
+#[derive(Debug)]
+pub enum RtmError<E: Error> {
+    Transport(E),
+}
+
+impl<E: Error> fmt::Display for RtmError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            RtmError::Transport(ref e) => write!(f, "Transport error: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for RtmError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RtmError::Transport(ref e) => Some(e),
+        }
+    }
+}
+
+/// The client a message was sent to has stopped.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClientStopped;
+
+impl fmt::Display for ClientStopped {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("The RTM client has stopped")
+    }
+}
+
+impl Error for ClientStopped {}
+
+#[derive(Debug)]
+enum Command {
+    Shutdown,
+    /// Send a message which isn't replied to.
+    Send(Value),
+    /// Send a message, assigning it an id and passing its reply back.
+    Request(Value, oneshot::Sender<Value>),
+}
+
+/// Controls a running [`RtmClient`].
+#[derive(Clone, Debug)]
+pub struct RtmHandle {
+    commands: mpsc::UnboundedSender<Command>,
+    health: Arc<Mutex<Health>>,
+    queue: Arc<EventQueue<Event>>,
+}
+
+impl RtmHandle {
+    /// Asks the client to stop.
+    ///
+    /// The client stops sending pings, waits (up to its shutdown timeout) for replies to messages
+    /// it has already sent, then closes the connection.
+    pub fn shutdown(&self) {
+        let _ = self.commands.unbounded_send(Command::Shutdown);
+    }
+
+    /// Subscribes to `presence_change` events for `user_ids`, replacing any earlier subscription.
+    ///
+    /// See [presence_sub](https://api.slack.com/docs/presence-and-status#subscriptions).
+    pub fn presence_sub(&self, user_ids: &[&str]) -> Result<(), ClientStopped> {
+        self.send(serde_json::json!({"type": "presence_sub", "ids": user_ids}))
+    }
+
+    /// Asks for a `presence_change` event with the current presence of each of `user_ids`.
+    pub fn presence_query(&self, user_ids: &[&str]) -> Result<(), ClientStopped> {
+        self.send(serde_json::json!({"type": "presence_query", "ids": user_ids}))
+    }
+
+    /// Posts a message, returning a future which resolves with Slack's reply.
+    ///
+    /// The message is assigned an `id` which is used to match it with its reply.
+    pub fn send_message(
+        &self,
+        message: &OutgoingMessage<'_>,
+    ) -> impl Future<Output = Result<MessageReply, SendError>> + Send + 'static {
+        let (tx, rx) = oneshot::channel();
+        let sent = self
+            .commands
+            .unbounded_send(Command::Request(message.to_value(), tx));
+        async move {
+            if sent.is_err() {
+                return Err(SendError::ClientStopped);
+            }
+            match rx.await {
+                Ok(reply) => SendError::from_reply(reply),
+                Err(_) => Err(SendError::ClientStopped),
+            }
+        }
+    }
+
+    fn send(&self, message: Value) -> Result<(), ClientStopped> {
+        self.commands
+            .unbounded_send(Command::Send(message))
+            .map_err(|_| ClientStopped)
+    }
+
+    pub fn health(&self) -> Health {
+        self.health
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    pub fn queue_metrics(&self) -> QueueMetrics {
+        self.queue.metrics()
+    }
+}
+
+/// A connection to the RTM API.
+pub struct RtmClient<T> {
+    transport: T,
+    ping_interval: Duration,
+    shutdown_timeout: Duration,
+    handle: RtmHandle,
+    commands: mpsc::UnboundedReceiver<Command>,
+}
+
+impl<T: RtmTransport> RtmClient<T> {
+    pub fn new(transport: T) -> Self {
+        let (tx, rx) = mpsc::unbounded();
+        RtmClient {
+            transport,
+            ping_interval: Duration::from_secs(30),
+            shutdown_timeout: Duration::from_secs(10),
+            handle: RtmHandle {
+                commands: tx,
+                health: Arc::new(Mutex::new(Health::default())),
+                queue: Arc::new(EventQueue::new(1024, OverflowPolicy::Block)),
+            },
+            commands: rx,
+        }
+    }
+
+    /// Sets how often pings are sent to keep the connection alive. Defaults to 30 seconds.
+    pub fn ping_interval(mut self, interval: Duration) -> Self {
+        self.ping_interval = interval;
+        self
+    }
+
+    /// Sets how long a shutdown waits for outstanding replies. Defaults to 10 seconds.
+    pub fn shutdown_timeout(mut self, timeout: Duration) -> Self {
+        self.shutdown_timeout = timeout;
+        self
+    }
+
+    /// Sets the size of the queue of events waiting for the handler, and what happens when it
+    /// fills up. Defaults to 1024 events, blocking when full.
+    pub fn event_queue(self, capacity: usize, policy: OverflowPolicy) -> Self {
+        self.handle.queue.configure(capacity, policy);
+        self
+    }
+
+    pub fn handle(&self) -> RtmHandle {
+        self.handle.clone()
+    }
+
+    /// Reads from the connection, passing events to `handler`, until it is shut down or closed.
+    ///
+    /// Events are queued for the handler so that a slow handler doesn't stop pings and replies
+    /// from being read. Events still queued when the connection ends are handled before this
+    /// returns.
+    pub async fn run<H: RtmHandler>(self, handler: H) -> Result<Disconnect, RtmError<T::Error>> {
+        let RtmClient {
+            mut transport,
+            ping_interval,
+            shutdown_timeout,
+            handle,
+            mut commands,
+        } = self;
+        let queue = handle.queue;
+        let mut conn = Connection {
+            health: handle.health,
+            next_id: 1,
+            pending: HashMap::new(),
+        };
+
+        let reader = async {
+            let result = async {
+                let mut ping = Delay::new(ping_interval);
+                let mut deadline: Option<Delay> = None;
+
+                let disconnect = loop {
+                    if deadline.is_some() && conn.pending.is_empty() {
+                        break Disconnect::Shutdown;
+                    }
+
+                    let next = {
+                        let recv = transport.recv().fuse();
+                        let command = commands.next().fuse();
+                        let ping = (&mut ping).fuse();
+                        let expired = match deadline {
+                            Some(ref mut d) => d.left_future(),
+                            None => future::pending().right_future(),
+                        }
+                        .fuse();
+                        futures_util::pin_mut!(recv, command, ping, expired);
+                        futures_util::select! {
+                            frame = recv => Next::Frame(frame),
+                            command = command => Next::Command(command),
+                            () = ping => Next::Ping,
+                            () = expired => Next::Expired,
+                        }
+                    };
+
+                    match next {
+                        Next::Frame(None) => break Disconnect::Closed,
+                        Next::Frame(Some(frame)) => {
+                            let frame = frame.map_err(RtmError::Transport)?;
+                            match conn.receive(&frame) {
+                                Incoming::Event(event) => queue.push(*event).await,
+                                Incoming::Goodbye => break Disconnect::Goodbye,
+                                Incoming::Handled => {}
+                            }
+                        }
+                        Next::Command(Some(Command::Shutdown)) if deadline.is_none() => {
+                            conn.update(|h| h.state = ConnectionState::ShuttingDown);
+                            deadline = Some(Delay::new(shutdown_timeout));
+                        }
+                        Next::Command(Some(Command::Send(message))) if deadline.is_none() => {
+                            transport
+                                .send(message.to_string())
+                                .await
+                                .map_err(RtmError::Transport)?;
+                        }
+                        Next::Command(Some(Command::Request(mut message, reply)))
+                            if deadline.is_none() =>
+                        {
+                            message["id"] = conn.track(Some(reply)).into();
+                            transport
+                                .send(message.to_string())
+                                .await
+                                .map_err(RtmError::Transport)?;
+                        }
+                        Next::Command(_) => {}
+                        Next::Ping => {
+                            ping.reset(ping_interval);
+                            if deadline.is_none() {
+                                let id = conn.track(None);
+                                conn.update(|h| h.last_ping = Some(SystemTime::now()));
+                                let ping = serde_json::json!({"id": id, "type": "ping"});
+                                transport
+                                    .send(ping.to_string())
+                                    .await
+                                    .map_err(RtmError::Transport)?;
+                            }
+                        }
+                        Next::Expired => break Disconnect::Shutdown,
+                    }
+                };
+
+                conn.update(|h| h.state = ConnectionState::Closed);
+                if disconnect != Disconnect::Closed {
+                    transport.close().await.map_err(RtmError::Transport)?;
+                }
+                Ok(disconnect)
+            }
+            .await;
+            queue.close();
+            result
+        };
+        let worker = async {
+            while let Some(event) = queue.pop().await {
+                handler.event(event).await;
+            }
+        };
+        future::join(reader, worker).await.0
+    }
+}
+
+enum Next<E> {
+    Frame(Option<Result<String, E>>),
+    Command(Option<Command>),
+    Ping,
+    Expired,
+}
+
+enum Incoming {
+    Event(Box<Event>),
+    Goodbye,
+    Handled,
+}
+
+/// State of a connection shared between reading and writing.
+struct Connection {
+    health: Arc<Mutex<Health>>,
+    next_id: u64,
+    /// Messages waiting for a reply, by id. Pings have no one waiting on their reply.
+    pending: HashMap<u64, Option<oneshot::Sender<Value>>>,
+}
+
+impl Connection {
+    fn update<F: FnOnce(&mut Health)>(&self, f: F) {
+        f(&mut self.health.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    /// Assigns an id to an outgoing message and waits for its reply.
+    fn track(&mut self, reply: Option<oneshot::Sender<Value>>) -> u64 {
+        let id = self.next_id;
+        self.next_id += 1;
+        self.pending.insert(id, reply);
+        let pending = self.pending.len();
+        self.update(|h| h.pending = pending);
+        id
+    }
+
+    fn receive(&mut self, frame: &str) -> Incoming {
+        let value: Value = match serde_json::from_str(frame) {
+            Ok(value) => value,
+            Err(_) => return Incoming::Handled,
+        };
+
+        if let Some(reply_to) = value.get("reply_to").and_then(Value::as_u64) {
+            if let Some(reply) = self.pending.remove(&reply_to) {
+                let pending = self.pending.len();
+                let is_pong = value.get("type").and_then(Value::as_str) == Some("pong");
+                self.update(|h| {
+                    h.pending = pending;
+                    if is_pong {
+                        h.last_pong = Some(SystemTime::now());
+                    }
+                });
+                if let Some(reply) = reply {
+                    let _ = reply.send(value);
+                }
+            }
+            return Incoming::Handled;
+        }
+
+        match value.get("type").and_then(Value::as_str) {
+            Some("hello") => {
+                self.update(|h| {
+                    h.state = ConnectionState::Connected;
+                    h.connected_since = Some(SystemTime::now());
+                });
+                Incoming::Handled
+            }
+            Some("goodbye") => Incoming::Goodbye,
+            Some(_) => match serde_json::from_value(value) {
+                Ok(event) => Incoming::Event(Box::new(event)),
+                Err(_) => Incoming::Handled,
+            },
+            None => Incoming::Handled,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    pub struct Closed;
+
+    impl fmt::Display for Closed {
+        fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("closed")
+        }
+    }
+
+    impl Error for Closed {}
+
+    /// A transport fed by, and sending to, in-memory channels.
+    pub struct MockTransport {
+        pub incoming: mpsc::UnboundedReceiver<String>,
+        pub outgoing: mpsc::UnboundedSender<String>,
+    }
+
+    pub fn mock() -> (
+        MockTransport,
+        mpsc::UnboundedSender<String>,
+        mpsc::UnboundedReceiver<String>,
+    ) {
+        let (in_tx, in_rx) = mpsc::unbounded();
+        let (out_tx, out_rx) = mpsc::unbounded();
+        let transport = MockTransport {
+            incoming: in_rx,
+            outgoing: out_tx,
+        };
+        (transport, in_tx, out_rx)
+    }
+
+    #[async_trait]
+    impl RtmTransport for MockTransport {
+        type Error = Closed;
+
+        async fn send(&mut self, text: String) -> Result<(), Closed> {
+            self.outgoing.unbounded_send(text).map_err(|_| Closed)
+        }
+
+        async fn recv(&mut self) -> Option<Result<String, Closed>> {
+            self.incoming.next().await.map(Ok)
+        }
+
+        async fn close(&mut self) -> Result<(), Closed> {
+            self.outgoing.close_channel();
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    pub struct Recorder(pub Mutex<Vec<Event>>);
+
+    #[async_trait]
+    impl RtmHandler for Arc<Recorder> {
+        async fn event(&self, event: Event) {
+            self.0.lock().unwrap().push(event);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_waits_for_pending_replies() {
+        let (transport, frames, mut sent) = mock();
+        let client = RtmClient::new(transport).ping_interval(Duration::from_millis(5));
+        let handle = client.handle();
+        let recorder = Arc::new(Recorder::default());
+        let run = tokio::spawn(client.run(recorder.clone()));
+
+        frames
+            .unbounded_send(r#"{"type": "hello"}"#.to_owned())
+            .unwrap();
+        frames
+            .unbounded_send(r#"{"type": "app_mention", "text": "hi"}"#.to_owned())
+            .unwrap();
+        let ping: Value = serde_json::from_str(&sent.next().await.unwrap()).unwrap();
+        assert_eq!(ping["type"], "ping");
+
+        handle.shutdown();
+        Delay::new(Duration::from_millis(20)).await;
+        let health = handle.health();
+        assert_eq!(health.state, ConnectionState::ShuttingDown);
+        assert!(health.connected_since.is_some());
+        assert_eq!(health.pending, 1);
+
+        let pong = serde_json::json!({"type": "pong", "reply_to": ping["id"]});
+        frames.unbounded_send(pong.to_string()).unwrap();
+        assert_eq!(run.await.unwrap().unwrap(), Disconnect::Shutdown);
+        assert_eq!(handle.health().state, ConnectionState::Closed);
+        assert!(handle.health().last_pong.is_some());
+        assert_eq!(recorder.0.lock().unwrap().len(), 1);
+        // No further pings are sent once shutting down, and the connection is closed.
+        assert_eq!(sent.next().await, None);
+    }
+
+    #[tokio::test]
+    async fn test_presence_sub() {
+        let (transport, frames, mut sent) = mock();
+        let client = RtmClient::new(transport);
+        let handle = client.handle();
+        let run = tokio::spawn(client.run(Arc::new(Recorder::default())));
+
+        handle.presence_sub(&["U1", "U2"]).unwrap();
+        let sub: Value = serde_json::from_str(&sent.next().await.unwrap()).unwrap();
+        assert_eq!(
+            sub,
+            serde_json::json!({"type": "presence_sub", "ids": ["U1", "U2"]})
+        );
+
+        frames
+            .unbounded_send(r#"{"type": "goodbye"}"#.to_owned())
+            .unwrap();
+        run.await.unwrap().unwrap();
+        assert_eq!(handle.presence_query(&["U1"]), Err(ClientStopped));
+    }
+
+    #[tokio::test]
+    async fn test_send_message_resolves_on_reply() {
+        let (transport, frames, mut sent) = mock();
+        let client = RtmClient::new(transport);
+        let handle = client.handle();
+        let run = tokio::spawn(client.run(Arc::new(Recorder::default())));
+
+        let first = handle.send_message(&OutgoingMessage {
+            channel: "C1",
+            text: "hello",
+            ..Default::default()
+        });
+        let second = handle.send_message(&OutgoingMessage {
+            channel: "C1",
+            text: "",
+            ..Default::default()
+        });
+        let first_sent: Value = serde_json::from_str(&sent.next().await.unwrap()).unwrap();
+        let second_sent: Value = serde_json::from_str(&sent.next().await.unwrap()).unwrap();
+        assert_eq!(first_sent["type"], "message");
+        assert_ne!(first_sent["id"], second_sent["id"]);
+
+        // Replies may arrive in any order.
+        let rejected = serde_json::json!({
+            "ok": false,
+            "reply_to": second_sent["id"],
+            "error": {"code": 2, "msg": "message text is missing"},
+        });
+        let ok = serde_json::json!({
+            "ok": true,
+            "reply_to": first_sent["id"],
+            "ts": "1355517523.000005",
+            "text": "hello",
+        });
+        frames.unbounded_send(rejected.to_string()).unwrap();
+        frames.unbounded_send(ok.to_string()).unwrap();
+
+        let reply = first.await.unwrap();
+        assert_eq!(reply.text.as_deref(), Some("hello"));
+        match second.await {
+            Err(SendError::Rejected { code, .. }) => assert_eq!(code, Some(2)),
+            r => panic!("expected a rejection but got {:?}", r),
+        }
+
+        let unanswered = handle.send_message(&OutgoingMessage {
+            channel: "C1",
+            text: "bye",
+            ..Default::default()
+        });
+        sent.next().await.unwrap();
+        frames
+            .unbounded_send(r#"{"type": "goodbye"}"#.to_owned())
+            .unwrap();
+        run.await.unwrap().unwrap();
+        assert!(matches!(unanswered.await, Err(SendError::ClientStopped)));
+    }
+
+    #[tokio::test]
+    async fn test_goodbye_ends_run() {
+        let (transport, frames, _sent) = mock();
+        let client = RtmClient::new(transport);
+        frames
+            .unbounded_send(r#"{"type": "goodbye"}"#.to_owned())
+            .unwrap();
+        let res = client.run(Arc::new(Recorder::default())).await;
+        assert_eq!(res.unwrap(), Disconnect::Goodbye);
+    }
+}
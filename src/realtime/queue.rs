@@ -0,0 +1,177 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::future::{poll_fn, Future};
+use std::sync::Mutex;
+use std::task::Poll;
+
+use futures_util::task::AtomicWaker;
+
+/// What to do with an incoming event when the event queue is full.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OverflowPolicy {
+    /// Discard the oldest queued event to make room.
+    DropOldest,
+    /// Discard the incoming event.
+    DropNewest,
+    /// Stop reading from the connection until the handler catches up.
+    Block,
+}
+
+/// Counters describing the event queue, for monitoring.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct QueueMetrics {
+    /// The number of events currently queued.
+    pub len: usize,
+    pub capacity: usize,
+    /// The most events that have been queued at once.
+    pub high_water_mark: usize,
+    /// The number of events accepted into the queue.
+    pub enqueued: u64,
+    /// The number of events discarded because the queue was full.
+    pub dropped: u64,
+}
+
+/// A bounded queue between the connection and the handler.
+pub(crate) struct EventQueue<T> {
+    inner: Mutex<Inner<T>>,
+    item_waker: AtomicWaker,
+    space_waker: AtomicWaker,
+}
+
+struct Inner<T> {
+    policy: OverflowPolicy,
+    items: VecDeque<T>,
+    closed: bool,
+    metrics: QueueMetrics,
+}
+
+impl<T> EventQueue<T> {
+    pub(crate) fn new(capacity: usize, policy: OverflowPolicy) -> Self {
+        let capacity = capacity.max(1);
+        EventQueue {
+            inner: Mutex::new(Inner {
+                policy,
+                items: VecDeque::with_capacity(capacity),
+                closed: false,
+                metrics: QueueMetrics {
+                    capacity,
+                    ..Default::default()
+                },
+            }),
+            item_waker: AtomicWaker::new(),
+            space_waker: AtomicWaker::new(),
+        }
+    }
+
+    /// Changes the capacity and overflow policy. Items already queued are kept.
+    pub(crate) fn configure(&self, capacity: usize, policy: OverflowPolicy) {
+        let mut inner = self.lock();
+        inner.metrics.capacity = capacity.max(1);
+        inner.policy = policy;
+        self.space_waker.wake();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Inner<T>> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Queues an item, applying the overflow policy if the queue is full.
+    pub(crate) fn push(&self, item: T) -> impl Future<Output = ()> + '_ {
+        let mut item = Some(item);
+        poll_fn(move |cx| {
+            let mut inner = self.lock();
+            if inner.items.len() >= inner.metrics.capacity {
+                match inner.policy {
+                    OverflowPolicy::DropOldest => {
+                        inner.items.pop_front();
+                        inner.metrics.dropped += 1;
+                    }
+                    OverflowPolicy::DropNewest => {
+                        inner.metrics.dropped += 1;
+                        return Poll::Ready(());
+                    }
+                    OverflowPolicy::Block => {
+                        self.space_waker.register(cx.waker());
+                        return Poll::Pending;
+                    }
+                }
+            }
+            if let Some(item) = item.take() {
+                inner.items.push_back(item);
+                inner.metrics.enqueued += 1;
+                inner.metrics.len = inner.items.len();
+                inner.metrics.high_water_mark =
+                    inner.metrics.high_water_mark.max(inner.metrics.len);
+            }
+            self.item_waker.wake();
+            Poll::Ready(())
+        })
+    }
+
+    /// Takes the next item, or `None` once the queue has been closed and drained.
+    pub(crate) fn pop(&self) -> impl Future<Output = Option<T>> + '_ {
+        poll_fn(move |cx| {
+            let mut inner = self.lock();
+            match inner.items.pop_front() {
+                Some(item) => {
+                    inner.metrics.len = inner.items.len();
+                    self.space_waker.wake();
+                    Poll::Ready(Some(item))
+                }
+                None if inner.closed => Poll::Ready(None),
+                None => {
+                    self.item_waker.register(cx.waker());
+                    Poll::Pending
+                }
+            }
+        })
+    }
+
+    /// Stops the consumer once the remaining items have been taken.
+    pub(crate) fn close(&self) {
+        self.lock().closed = true;
+        self.item_waker.wake();
+    }
+
+    pub(crate) fn metrics(&self) -> QueueMetrics {
+        self.lock().metrics
+    }
+}
+
+impl<T> fmt::Debug for EventQueue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EventQueue")
+            .field("metrics", &self.metrics())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+
+    #[test]
+    fn test_overflow_policies() {
+        let queue = EventQueue::new(2, OverflowPolicy::DropOldest);
+        for i in 0..3 {
+            queue.push(i).now_or_never().unwrap();
+        }
+        assert_eq!(queue.pop().now_or_never(), Some(Some(1)));
+        assert_eq!(queue.metrics().dropped, 1);
+
+        let queue = EventQueue::new(2, OverflowPolicy::DropNewest);
+        for i in 0..3 {
+            queue.push(i).now_or_never().unwrap();
+        }
+        assert_eq!(queue.pop().now_or_never(), Some(Some(0)));
+        assert_eq!(queue.metrics().high_water_mark, 2);
+
+        let queue = EventQueue::new(1, OverflowPolicy::Block);
+        queue.push(0).now_or_never().unwrap();
+        assert!(queue.push(1).now_or_never().is_none());
+        assert_eq!(queue.pop().now_or_never(), Some(Some(0)));
+        queue.close();
+        assert_eq!(queue.pop().now_or_never(), Some(None));
+    }
+}
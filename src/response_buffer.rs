@@ -0,0 +1,113 @@
+//! Response bodies read into pooled buffers, so a bot making many calls doesn't allocate a body
+//! for each of them.
+
+use std::ops::{Deref, DerefMut};
+use std::sync::Mutex;
+
+/// How many idle buffers a pool keeps.
+const MAX_POOLED: usize = 16;
+
+/// Buffers which grew larger than this, such as for a page of a big export, are freed rather
+/// than kept.
+const MAX_POOLED_CAPACITY: usize = 1 << 20;
+
+static POOL: Pool = Pool::new();
+
+struct Pool(Mutex<Vec<Vec<u8>>>);
+
+impl Pool {
+    const fn new() -> Self {
+        Pool(Mutex::new(Vec::new()))
+    }
+
+    fn take(&'static self) -> ResponseBuffer {
+        let buf = self
+            .0
+            .lock()
+            .ok()
+            .and_then(|mut pool| pool.pop())
+            .unwrap_or_default();
+        ResponseBuffer { buf, pool: self }
+    }
+
+    fn give(&self, mut buf: Vec<u8>) {
+        if buf.capacity() == 0 || buf.capacity() > MAX_POOLED_CAPACITY {
+            return;
+        }
+        buf.clear();
+        if let Ok(mut pool) = self.0.lock() {
+            if pool.len() < MAX_POOLED {
+                pool.push(buf);
+            }
+        }
+    }
+}
+
+/// A buffer for a response body, taken from a pool and returned to it when dropped.
+pub(crate) struct ResponseBuffer {
+    buf: Vec<u8>,
+    pool: &'static Pool,
+}
+
+impl ResponseBuffer {
+    pub(crate) fn take() -> Self {
+        POOL.take()
+    }
+
+    /// The body as a string, for errors which quote it.
+    pub(crate) fn to_string_lossy(&self) -> String {
+        String::from_utf8_lossy(&self.buf).into_owned()
+    }
+}
+
+impl Deref for ResponseBuffer {
+    type Target = Vec<u8>;
+
+    fn deref(&self) -> &Vec<u8> {
+        &self.buf
+    }
+}
+
+impl DerefMut for ResponseBuffer {
+    fn deref_mut(&mut self) -> &mut Vec<u8> {
+        &mut self.buf
+    }
+}
+
+impl Drop for ResponseBuffer {
+    fn drop(&mut self) {
+        self.pool.give(std::mem::take(&mut self.buf));
+    }
+}
+
+/// Turns a body read as bytes into the `String` senders return, replacing any invalid UTF-8.
+#[cfg(any(feature = "reqwest", feature = "reqwest_blocking"))]
+pub(crate) fn into_string(body: Vec<u8>) -> String {
+    String::from_utf8(body).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_buffers_are_reused() {
+        static POOL: Pool = Pool::new();
+        let mut buf = POOL.take();
+        buf.extend_from_slice(br#"{"ok": true}"#);
+        let ptr = buf.as_ptr();
+        drop(buf);
+        let buf = POOL.take();
+        assert_eq!(buf.as_ptr(), ptr);
+        assert!(buf.is_empty());
+    }
+
+    #[test]
+    fn test_large_buffers_are_freed() {
+        static POOL: Pool = Pool::new();
+        let mut buf = POOL.take();
+        buf.reserve(MAX_POOLED_CAPACITY + 1);
+        drop(buf);
+        assert_eq!(POOL.take().capacity(), 0);
+    }
+}
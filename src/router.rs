@@ -0,0 +1,382 @@
+//! Routes the commands in messages, such as `!deploy staging` or `@bot deploy staging`, to
+//! handlers, with a help message listing them.
+//!
+//! ```
+//! use slack_api::router::{Routed, Router};
+//!
+//! let router = Router::new()
+//!     .bot_user_id("U0BOT")
+//!     .command("!deploy <branch> [env]", "Deploys a branch", |cmd| {
+//!         let env = cmd.arg(1).unwrap_or("staging");
+//!         format!("Deploying {} to {}", cmd.arg(0).unwrap_or("main"), env)
+//!     })
+//!     .mention("status", "Says how things are", |_| "All good".to_owned())
+//!     .help("!help");
+//!
+//! match router.route_text("!deploy \"fix/login page\" prod") {
+//!     Some(Routed::Handled(reply)) => assert_eq!(reply, "Deploying fix/login page to prod"),
+//!     _ => unreachable!(),
+//! }
+//! assert!(matches!(router.route_text("<@U0BOT> status"), Some(Routed::Handled(_))));
+//! assert!(matches!(router.route_text("!help"), Some(Routed::Help(_))));
+//! assert!(router.route_text("deploy").is_none());
+//! ```
+//!
+//! Routing doesn't send anything: handlers return whatever the app wants to do, such as the text
+//! to reply with, and the app acts on it. Apps subscribed to both `message` and `app_mention` events
+//! receive mentions twice, so should route only one of them.
+
+pub use regex::Regex;
+
+use crate::events::Event;
+use crate::{Message, Timestamp};
+
+/// A message matched by a route, passed to its handler.
+#[derive(Clone, Debug)]
+pub struct Invocation<'a> {
+    /// The whole text of the message.
+    pub text: &'a str,
+    pub user: Option<&'a str>,
+    pub channel: Option<&'a str>,
+    pub ts: Option<&'a Timestamp>,
+    pub thread_ts: Option<&'a Timestamp>,
+    /// The words after a command's name, with quoted words kept together. Empty for regex routes.
+    pub args: Vec<String>,
+    /// The groups a regex route captured, starting with the whole match. Empty for commands.
+    pub captures: Vec<Option<String>>,
+}
+
+impl<'a> Invocation<'a> {
+    fn new(text: &'a str) -> Self {
+        Invocation {
+            text,
+            user: None,
+            channel: None,
+            ts: None,
+            thread_ts: None,
+            args: Vec::new(),
+            captures: Vec::new(),
+        }
+    }
+
+    /// The `i`th argument after the command's name.
+    pub fn arg(&self, i: usize) -> Option<&str> {
+        self.args.get(i).map(String::as_str)
+    }
+
+    /// The `i`th group a regex route captured, where 0 is the whole match.
+    pub fn capture(&self, i: usize) -> Option<&str> {
+        self.captures.get(i).and_then(Option::as_deref)
+    }
+}
+
+/// What routing a message did.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Routed<T> {
+    /// A route matched, and its handler returned this.
+    Handled(T),
+    /// The help command was given, and this is the help to reply with.
+    Help(String),
+}
+
+enum Pattern {
+    /// A message starting with the word, such as `!deploy`.
+    Command(String),
+    /// A message starting with a mention of the bot and then the word.
+    Mention(String),
+    Regex(Regex),
+}
+
+struct Route<T> {
+    pattern: Pattern,
+    usage: String,
+    description: String,
+    handler: Box<dyn Fn(&Invocation<'_>) -> T + Send + Sync>,
+}
+
+/// Matches messages against the routes registered with it, in the order they were registered,
+/// passing the first one that matches to its handler.
+pub struct Router<T> {
+    bot_user_id: Option<String>,
+    routes: Vec<Route<T>>,
+    help: Option<String>,
+}
+
+impl<T> Default for Router<T> {
+    fn default() -> Self {
+        Router {
+            bot_user_id: None,
+            routes: Vec::new(),
+            help: None,
+        }
+    }
+}
+
+impl<T> Router<T> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// The bot's own user ID, as looked up by `SlackClient::whoami`, which
+    /// [`mention()`](#method.mention) routes need.
+    pub fn bot_user_id<S: Into<String>>(mut self, user_id: S) -> Self {
+        self.bot_user_id = Some(user_id.into());
+        self
+    }
+
+    /// Routes messages starting with a command, such as `!deploy`, to `handler`. `usage` is the
+    /// command followed by a description of its arguments, such as `!deploy <branch> [env]`, and is
+    /// shown in the help with `description`.
+    pub fn command<F>(self, usage: &str, description: &str, handler: F) -> Self
+    where
+        F: Fn(&Invocation<'_>) -> T + Send + Sync + 'static,
+    {
+        let name = first_word(usage).to_owned();
+        self.add(Pattern::Command(name), usage, description, handler)
+    }
+
+    /// Routes messages starting with a mention of the bot followed by a command, such as
+    /// `@bot deploy`, to `handler`. `usage` is as for [`command()`](#method.command), without the
+    /// mention.
+    pub fn mention<F>(self, usage: &str, description: &str, handler: F) -> Self
+    where
+        F: Fn(&Invocation<'_>) -> T + Send + Sync + 'static,
+    {
+        let name = first_word(usage).to_owned();
+        self.add(Pattern::Mention(name), usage, description, handler)
+    }
+
+    /// Routes messages matching `regex` anywhere in their text to `handler`, which is given the
+    /// groups it captured.
+    pub fn regex<F>(self, regex: Regex, description: &str, handler: F) -> Self
+    where
+        F: Fn(&Invocation<'_>) -> T + Send + Sync + 'static,
+    {
+        let usage = format!("/{}/", regex.as_str());
+        self.add(Pattern::Regex(regex), &usage, description, handler)
+    }
+
+    /// Answers a command, such as `!help`, with the help for the routes, as does a mention of the
+    /// bot followed by `help`.
+    pub fn help(mut self, command: &str) -> Self {
+        self.help = Some(command.to_owned());
+        self
+    }
+
+    fn add<F>(mut self, pattern: Pattern, usage: &str, description: &str, handler: F) -> Self
+    where
+        F: Fn(&Invocation<'_>) -> T + Send + Sync + 'static,
+    {
+        self.routes.push(Route {
+            pattern,
+            usage: usage.to_owned(),
+            description: description.to_owned(),
+            handler: Box::new(handler),
+        });
+        self
+    }
+
+    /// The help listing the routes, in Slack's `mrkdwn`.
+    pub fn help_text(&self) -> String {
+        let mut help = String::from("Commands:");
+        for route in &self.routes {
+            let usage = match (&route.pattern, &self.bot_user_id) {
+                (Pattern::Mention(_), Some(id)) => format!("<@{}> `{}`", id, route.usage),
+                _ => format!("`{}`", route.usage),
+            };
+            help.push_str(&format!("\n• {}  {}", usage, route.description));
+        }
+        help
+    }
+
+    /// Routes a `message` or `app_mention` event. Messages with a subtype, such as edits and bot
+    /// messages, aren't routed.
+    pub fn route(&self, event: &Event) -> Option<Routed<T>> {
+        let invocation = match *event {
+            Event::Message(ref message) => match **message {
+                Message::Standard(ref m) => Invocation {
+                    user: m.user.as_deref(),
+                    channel: m.channel.as_deref(),
+                    ts: m.ts.as_ref(),
+                    thread_ts: m.thread_ts.as_ref(),
+                    ..Invocation::new(m.text.as_deref()?)
+                },
+                _ => return None,
+            },
+            Event::AppMention(ref m) => Invocation {
+                user: m.user.as_deref(),
+                channel: m.channel.as_deref(),
+                ts: m.ts.as_ref(),
+                thread_ts: m.thread_ts.as_ref(),
+                ..Invocation::new(m.text.as_deref()?)
+            },
+            _ => return None,
+        };
+        self.dispatch(invocation)
+    }
+
+    /// Routes some text, such as the text of a slash command.
+    pub fn route_text(&self, text: &str) -> Option<Routed<T>> {
+        self.dispatch(Invocation::new(text))
+    }
+
+    fn dispatch(&self, mut invocation: Invocation<'_>) -> Option<Routed<T>> {
+        let text = invocation.text.trim();
+        let mentioned = self.strip_mention(text);
+        if let Some(ref help) = self.help {
+            let asked = |text: Option<&str>, name: &str| {
+                text.is_some_and(|text| first_word(text).eq_ignore_ascii_case(name))
+            };
+            if asked(Some(text), help) || asked(mentioned, "help") {
+                return Some(Routed::Help(self.help_text()));
+            }
+        }
+        for route in &self.routes {
+            let matched = match route.pattern {
+                Pattern::Command(ref name) => command_args(text, name),
+                Pattern::Mention(ref name) => mentioned.and_then(|text| command_args(text, name)),
+                Pattern::Regex(ref regex) => regex.captures(text).map(|captures| {
+                    invocation.captures = captures
+                        .iter()
+                        .map(|group| group.map(|g| g.as_str().to_owned()))
+                        .collect();
+                    Vec::new()
+                }),
+            };
+            if let Some(args) = matched {
+                invocation.args = args;
+                return Some(Routed::Handled((route.handler)(&invocation)));
+            }
+        }
+        None
+    }
+
+    /// The text after a mention of the bot at the start of a message, such as `<@U0BOT> deploy`.
+    fn strip_mention<'t>(&self, text: &'t str) -> Option<&'t str> {
+        let id = self.bot_user_id.as_deref()?;
+        let rest = text.strip_prefix("<@")?.strip_prefix(id)?;
+        // Older clients send the name after the ID, as in `<@U0BOT|bot>`.
+        let rest = match rest.strip_prefix('|') {
+            Some(rest) => &rest[rest.find('>')?..],
+            None => rest,
+        };
+        let rest = rest.strip_prefix('>')?;
+        Some(rest.trim_start_matches(|c: char| c == ':' || c == ',' || c.is_whitespace()))
+    }
+}
+
+fn first_word(text: &str) -> &str {
+    text.split_whitespace().next().unwrap_or_default()
+}
+
+/// The arguments after `name`, if the text starts with it.
+fn command_args(text: &str, name: &str) -> Option<Vec<String>> {
+    let word = first_word(text);
+    if word.is_empty() || !word.eq_ignore_ascii_case(name) {
+        return None;
+    }
+    let rest = &text.trim_start()[word.len()..];
+    Some(split_args(rest))
+}
+
+/// Splits arguments at whitespace, keeping words in double quotes together. Slack's clients turn
+/// straight quotes into curly ones, so those are quotes too.
+fn split_args(text: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut arg = String::new();
+    let mut quoted = false;
+    let mut in_arg = false;
+    for c in text.chars() {
+        match c {
+            '"' | '\u{201c}' | '\u{201d}' => {
+                quoted = !quoted;
+                in_arg = true;
+            }
+            c if c.is_whitespace() && !quoted => {
+                if in_arg {
+                    args.push(std::mem::take(&mut arg));
+                    in_arg = false;
+                }
+            }
+            c => {
+                arg.push(c);
+                in_arg = true;
+            }
+        }
+    }
+    if in_arg {
+        args.push(arg);
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn router() -> Router<String> {
+        Router::new()
+            .bot_user_id("U0BOT")
+            .command("!echo <words>...", "Repeats the words", |cmd| {
+                cmd.args.join("|")
+            })
+            .mention("deploy <branch>", "Deploys a branch", |cmd| {
+                format!(
+                    "{} by {}",
+                    cmd.arg(0).unwrap_or("main"),
+                    cmd.user.unwrap_or("?")
+                )
+            })
+            .regex(
+                Regex::new(r"\b([A-Z]+-\d+)\b").unwrap(),
+                "Links to tickets",
+                |cmd| cmd.capture(1).unwrap_or_default().to_owned(),
+            )
+            .help("!help")
+    }
+
+    #[test]
+    fn test_routes_commands() {
+        let router = router();
+        let handled = |text| match router.route_text(text) {
+            Some(Routed::Handled(reply)) => Some(reply),
+            _ => None,
+        };
+        assert_eq!(
+            handled("!ECHO a “b c”  \"\" d").as_deref(),
+            Some("a|b c||d")
+        );
+        assert_eq!(handled("!echo").as_deref(), Some(""));
+        assert_eq!(handled("!echoes"), None);
+        assert_eq!(
+            handled("<@U0BOT|bot>: deploy fix").as_deref(),
+            Some("fix by ?")
+        );
+        assert_eq!(handled("<@U0BOTX> deploy fix"), None);
+        assert_eq!(handled("see ABC-12 for details").as_deref(), Some("ABC-12"));
+        assert_eq!(handled("deploy"), None);
+    }
+
+    #[test]
+    fn test_routes_events_and_help() {
+        let router = router();
+        let event: Event = serde_json::from_str(
+            r#"{"type": "app_mention", "user": "U1", "text": "<@U0BOT> deploy main", "ts": "1.0"}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            router.route(&event),
+            Some(Routed::Handled("main by U1".to_owned()))
+        );
+
+        let help = router.help_text();
+        assert_eq!(
+            help,
+            "Commands:\n• `!echo <words>...`  Repeats the words\n\
+             • <@U0BOT> `deploy <branch>`  Deploys a branch\n\
+             • `/\\b([A-Z]+-\\d+)\\b/`  Links to tickets"
+        );
+        assert_eq!(router.route_text("!help"), Some(Routed::Help(help.clone())));
+        assert_eq!(router.route_text("<@U0BOT> help"), Some(Routed::Help(help)));
+    }
+}
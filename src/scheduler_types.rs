@@ -0,0 +1,241 @@
+//! Types shared by the helpers which schedule messages for a user's local time.
+
+use std::error::Error;
+use std::fmt;
+
+use crate::mod_types::chat_types::{DeleteScheduledMessageError, ScheduleMessageError};
+use crate::mod_types::users_types::InfoError;
+use crate::User;
+
+/// A wall-clock time without a timezone, such as 9:00 on a user's calendar.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LocalDateTime {
+    pub year: i32,
+    /// From 1 to 12.
+    pub month: u32,
+    /// From 1 to the length of the month.
+    pub day: u32,
+    /// From 0 to 23.
+    pub hour: u32,
+    /// From 0 to 59.
+    pub minute: u32,
+}
+
+impl LocalDateTime {
+    pub fn new(year: i32, month: u32, day: u32, hour: u32, minute: u32) -> Self {
+        LocalDateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+        }
+    }
+
+    /// The Unix time of this wall-clock time at `utc_offset` seconds east of UTC, or `None` if
+    /// it isn't a valid date and time or is before 1970.
+    pub fn to_unix(self, utc_offset: i64) -> Option<u64> {
+        if !(1..=12).contains(&self.month)
+            || self.day < 1
+            || self.day > days_in_month(self.year, self.month)
+            || self.hour > 23
+            || self.minute > 59
+        {
+            return None;
+        }
+        let seconds = days_from_civil(self.year, self.month, self.day) * 86_400
+            + i64::from(self.hour) * 3_600
+            + i64::from(self.minute) * 60
+            - utc_offset;
+        if seconds < 0 {
+            None
+        } else {
+            Some(seconds as u64)
+        }
+    }
+
+    /// The Unix time of this wall-clock time in `user`'s timezone, for `chat.scheduleMessage`'s
+    /// `post_at`.
+    ///
+    /// With the `chrono` feature, the user's IANA timezone, such as `America/New_York`, gives the
+    /// UTC offset on the day, so a time on the other side of a daylight saving change is right. A
+    /// time the clocks skip is an `InvalidTime`, and one they repeat is the first of the two.
+    ///
+    /// Without the feature, or for a user without a known IANA timezone, Slack's `tz_offset` is
+    /// used. That is the offset as it is now, so across a daylight saving change it's an hour off.
+    pub fn post_at_for(self, user: &User) -> Result<u64, PostAtError> {
+        #[cfg(feature = "chrono")]
+        {
+            if let Some(tz) = user.tz.as_deref().and_then(|tz| tz.parse().ok()) {
+                return self.to_unix_in(tz).ok_or(PostAtError::InvalidTime);
+            }
+        }
+        let offset = user.tz_offset.ok_or(PostAtError::NoTimezone)?;
+        self.to_unix(offset as i64).ok_or(PostAtError::InvalidTime)
+    }
+
+    #[cfg(feature = "chrono")]
+    fn to_unix_in(self, tz: chrono_tz::Tz) -> Option<u64> {
+        use chrono::{NaiveDate, Offset, TimeZone};
+
+        let local = NaiveDate::from_ymd_opt(self.year, self.month, self.day)?.and_hms_opt(
+            self.hour,
+            self.minute,
+            0,
+        )?;
+        let offset = tz.offset_from_local_datetime(&local).earliest()?;
+        self.to_unix(i64::from(offset.fix().local_minus_utc()))
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// The number of days from 1970-01-01 to a date of the proleptic Gregorian calendar.
+fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((i64::from(month) + 9) % 12) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Why a wall-clock time couldn't be turned into a `post_at` for a user.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PostAtError {
+    /// The user has no timezone, such as a bot user.
+    NoTimezone,
+    /// The time isn't a valid date and time, or is before 1970.
+    InvalidTime,
+}
+
+impl fmt::Display for PostAtError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PostAtError::NoTimezone => write!(f, "The user has no timezone"),
+            PostAtError::InvalidTime => write!(f, "The local time isn't a valid date and time"),
+        }
+    }
+}
+
+impl Error for PostAtError {}
+
+#[derive(Debug)]
+pub enum ScheduleInUserTzError<E: Error> {
+    /// Looking up the user's timezone failed.
+    UserInfo(InfoError<E>),
+    PostAt(PostAtError),
+    /// Scheduling the message failed.
+    Schedule(ScheduleMessageError<E>),
+}
+
+impl<E: Error> fmt::Display for ScheduleInUserTzError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ScheduleInUserTzError::UserInfo(ref e) => {
+                write!(f, "Looking up the user's timezone failed: {}", e)
+            }
+            ScheduleInUserTzError::PostAt(ref e) => write!(f, "{}", e),
+            ScheduleInUserTzError::Schedule(ref e) => {
+                write!(f, "Scheduling the message failed: {}", e)
+            }
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for ScheduleInUserTzError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            ScheduleInUserTzError::UserInfo(ref e) => Some(e),
+            ScheduleInUserTzError::PostAt(ref e) => Some(e),
+            ScheduleInUserTzError::Schedule(ref e) => Some(e),
+        }
+    }
+}
+
+/// The outcome of `cancel_by_channel`.
+#[derive(Debug)]
+pub struct CancelReport<E: Error> {
+    /// The IDs of the scheduled messages deleted.
+    pub cancelled: Vec<String>,
+    /// The scheduled messages which couldn't be deleted, each with its error, such as
+    /// `InvalidScheduledMessageId` for one posted in the meantime.
+    pub failed: Vec<(String, DeleteScheduledMessageError<E>)>,
+}
+
+impl<E: Error> Default for CancelReport<E> {
+    fn default() -> Self {
+        CancelReport {
+            cancelled: Vec::new(),
+            failed: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_unix() {
+        assert_eq!(
+            LocalDateTime::new(2024, 3, 10, 9, 0).to_unix(-5 * 3600),
+            Some(1710079200)
+        );
+        assert_eq!(
+            LocalDateTime::new(2000, 1, 1, 9, 0).to_unix(9 * 3600 + 1800),
+            Some(946683000)
+        );
+        assert_eq!(
+            LocalDateTime::new(2024, 2, 29, 0, 15).to_unix(0),
+            Some(1709165700)
+        );
+        assert_eq!(LocalDateTime::new(2023, 2, 29, 0, 15).to_unix(0), None);
+        assert_eq!(LocalDateTime::new(2024, 4, 1, 24, 0).to_unix(0), None);
+        assert_eq!(LocalDateTime::new(1970, 1, 1, 0, 0).to_unix(3600), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_post_at_for_across_dst() {
+        // Slack gave the offset on the 9th, before New York's clocks went forward on the 10th.
+        let user: User =
+            serde_json::from_str(r#"{"id": "U1", "tz": "America/New_York", "tz_offset": -18000}"#)
+                .unwrap();
+        assert_eq!(
+            LocalDateTime::new(2024, 3, 9, 9, 0).post_at_for(&user),
+            Ok(1709992800)
+        );
+        assert_eq!(
+            LocalDateTime::new(2024, 3, 11, 9, 0).post_at_for(&user),
+            Ok(1710162000)
+        );
+        assert_eq!(
+            LocalDateTime::new(2024, 3, 10, 2, 30).post_at_for(&user),
+            Err(PostAtError::InvalidTime)
+        );
+        assert_eq!(
+            LocalDateTime::new(2024, 11, 3, 1, 30).post_at_for(&user),
+            Ok(1730611800)
+        );
+
+        let user: User =
+            serde_json::from_str(r#"{"id": "U1", "tz": "Mars/Olympus_Mons", "tz_offset": -18000}"#)
+                .unwrap();
+        assert_eq!(
+            LocalDateTime::new(2024, 3, 11, 9, 0).post_at_for(&user),
+            Ok(1710165600)
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::MessageStandard;
+
+/// Why Slack redelivered an event, from the `X-Slack-Retry-Reason` header.
+#[derive(Clone, Debug, PartialEq)]
+pub enum RetryReason {
+    HttpTimeout,
+    TooManyRedirects,
+    ConnectionFailed,
+    SslError,
+    HttpError,
+    UnknownError,
+    Other(String),
+}
+
+impl<'a> From<&'a str> for RetryReason {
+    fn from(s: &'a str) -> Self {
+        match s {
+            "http_timeout" => RetryReason::HttpTimeout,
+            "too_many_redirects" => RetryReason::TooManyRedirects,
+            "connection_failed" => RetryReason::ConnectionFailed,
+            "ssl_error" => RetryReason::SslError,
+            "http_error" => RetryReason::HttpError,
+            "unknown_error" => RetryReason::UnknownError,
+            _ => RetryReason::Other(s.to_owned()),
+        }
+    }
+}
+
+/// Details of a redelivered event.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Retry {
+    /// The value of the `X-Slack-Retry-Num` header; the first retry is `1`.
+    pub num: u32,
+    pub reason: Option<RetryReason>,
+}
+
+/// Remembers recently seen `event_id`s so that events Slack redelivers are only handled once.
+///
+/// Entries are forgotten once they are older than the configured time to live, or when the cache
+/// is over capacity, oldest first. Slack retries an event at most three times within about an
+/// hour, so the defaults keep ids for an hour.
+#[derive(Debug)]
+pub struct DedupeCache {
+    ttl: Duration,
+    capacity: usize,
+    seen: Mutex<Seen>,
+}
+
+#[derive(Debug, Default)]
+struct Seen {
+    ids: HashMap<String, Instant>,
+    order: VecDeque<(Instant, String)>,
+}
+
+impl DedupeCache {
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        DedupeCache {
+            ttl,
+            capacity,
+            seen: Mutex::new(Seen::default()),
+        }
+    }
+
+    /// Records an event id, returning `false` if it has already been seen.
+    pub fn insert(&self, event_id: &str) -> bool {
+        self.insert_at(Instant::now(), event_id)
+    }
+
+    fn insert_at(&self, now: Instant, event_id: &str) -> bool {
+        let mut seen = self.seen.lock().unwrap_or_else(|e| e.into_inner());
+        while let Some(&(at, _)) = seen.order.front() {
+            if now.duration_since(at) < self.ttl && seen.order.len() < self.capacity {
+                break;
+            }
+            if let Some((at, id)) = seen.order.pop_front() {
+                if seen.ids.get(&id) == Some(&at) {
+                    seen.ids.remove(&id);
+                }
+            }
+        }
+        if seen.ids.contains_key(event_id) {
+            return false;
+        }
+        seen.ids.insert(event_id.to_owned(), now);
+        seen.order.push_back((now, event_id.to_owned()));
+        true
+    }
+
+    /// Records a message, returning `false` if it has already been seen: by its `client_msg_id`,
+    /// or if it has none by its channel and `ts`. A message with neither is always new.
+    ///
+    /// This catches the same message arriving in two events, such as a post retried after a
+    /// timeout, which `event_id`s don't.
+    pub fn insert_message(&self, message: &MessageStandard) -> bool {
+        let key = match (&message.client_msg_id, &message.channel, message.ts) {
+            (Some(id), _, _) => id.clone(),
+            (None, Some(channel), Some(ts)) => format!("{}/{}", channel, ts),
+            _ => return true,
+        };
+        self.insert(&key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.seen
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .ids
+            .len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for DedupeCache {
+    fn default() -> Self {
+        DedupeCache::new(Duration::from_secs(60 * 60), 10_000)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dedupe_cache_expires_and_evicts() {
+        let cache = DedupeCache::new(Duration::from_secs(10), 2);
+        let start = Instant::now();
+        assert!(cache.insert_at(start, "Ev1"));
+        assert!(!cache.insert_at(start, "Ev1"));
+        assert!(cache.insert_at(start + Duration::from_secs(11), "Ev2"));
+        assert!(cache.insert_at(start + Duration::from_secs(11), "Ev1"));
+
+        assert!(cache.insert_at(start + Duration::from_secs(12), "Ev3"));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.insert_at(start + Duration::from_secs(12), "Ev2"));
+    }
+
+    #[test]
+    fn test_dedupe_messages() {
+        let cache = DedupeCache::default();
+        let message = |json| serde_json::from_str::<MessageStandard>(json).unwrap();
+        let posted = message(r#"{"client_msg_id": "5d5f", "channel": "C1", "ts": "1.000001"}"#);
+        let retried = message(r#"{"client_msg_id": "5d5f", "channel": "C1", "ts": "1.000002"}"#);
+        assert!(cache.insert_message(&posted));
+        assert!(!cache.insert_message(&retried));
+        let untagged = message(r#"{"channel": "C1", "ts": "1.000003"}"#);
+        assert!(cache.insert_message(&untagged));
+        assert!(!cache.insert_message(&untagged));
+    }
+}
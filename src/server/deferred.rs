@@ -0,0 +1,191 @@
+use std::error::Error;
+use std::fmt;
+
+use async_trait::async_trait;
+use serde_json::Value;
+
+use super::{InteractionPayload, SlashCommand};
+
+/// What deferred work needs to answer a slash command or interaction after it's been
+/// acknowledged.
+///
+/// Messages are sent to the `response_url` with [`respond`](ResponseContext::respond), which
+/// Slack accepts up to 5 times within 30 minutes. A modal is updated by passing `view_id` and
+/// `view_hash` to `views.update`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ResponseContext {
+    pub response_url: Option<String>,
+    /// Opens a modal with `views.open`, for up to 3 seconds after the request was sent.
+    pub trigger_id: Option<crate::TriggerId>,
+    pub team_id: Option<String>,
+    pub user_id: Option<String>,
+    pub channel_id: Option<String>,
+    pub view_id: Option<String>,
+    pub view_hash: Option<String>,
+}
+
+impl ResponseContext {
+    /// Sends `message` to the `response_url`.
+    pub async fn respond<S>(
+        &self,
+        sender: &S,
+        message: &ResponseMessage,
+    ) -> Result<(), RespondError<S::Error>>
+    where
+        S: ResponseUrlSender,
+    {
+        let url = self
+            .response_url
+            .as_deref()
+            .ok_or(RespondError::NoResponseUrl)?;
+        sender
+            .post_json(url, crate::to_json_param(message))
+            .await
+            .map_err(RespondError::Client)
+    }
+}
+
+impl<'a> From<&'a SlashCommand> for ResponseContext {
+    fn from(command: &'a SlashCommand) -> Self {
+        ResponseContext {
+            response_url: command.response_url.clone(),
+            trigger_id: command.trigger_id.clone(),
+            team_id: command.team_id.clone(),
+            user_id: command.user_id.clone(),
+            channel_id: command.channel_id.clone(),
+            view_id: None,
+            view_hash: None,
+        }
+    }
+}
+
+impl<'a> From<&'a InteractionPayload> for ResponseContext {
+    fn from(payload: &'a InteractionPayload) -> Self {
+        let mut context = ResponseContext {
+            team_id: payload.installation_query().team_id.map(str::to_owned),
+            ..ResponseContext::default()
+        };
+        let (user, view) = match *payload {
+            InteractionPayload::BlockActions(ref p) => {
+                context.response_url = p.response_url.clone();
+                context.trigger_id = p.trigger_id.clone();
+                context.channel_id = p.channel.as_ref().and_then(|c| c.id.clone());
+                (p.user.as_ref(), p.view.as_ref())
+            }
+            InteractionPayload::MessageAction(ref p) => {
+                context.response_url = p.response_url.clone();
+                context.trigger_id = p.trigger_id.clone();
+                context.channel_id = p.channel.as_ref().and_then(|c| c.id.clone());
+                (p.user.as_ref(), None)
+            }
+            InteractionPayload::Shortcut(ref p) => {
+                context.trigger_id = p.trigger_id.clone();
+                (p.user.as_ref(), None)
+            }
+            InteractionPayload::ViewClosed(ref p) | InteractionPayload::ViewSubmission(ref p) => {
+                context.trigger_id = p.trigger_id.clone();
+                (p.user.as_ref(), p.view.as_ref())
+            }
+            InteractionPayload::Other(ref value) => {
+                let field = |pointer| {
+                    value
+                        .pointer(pointer)
+                        .and_then(Value::as_str)
+                        .map(str::to_owned)
+                };
+                context.response_url = field("/response_url");
+                context.trigger_id = field("/trigger_id").map(crate::TriggerId::new);
+                context.user_id = field("/user/id");
+                context.channel_id = field("/channel/id");
+                return context;
+            }
+        };
+        context.user_id = user.and_then(|u| u.id.clone());
+        context.view_id = view.and_then(|v| v.id.clone());
+        context.view_hash = view.and_then(|v| v.hash.clone());
+        context
+    }
+}
+
+/// A message sent to a `response_url`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+pub struct ResponseMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    /// Ephemeral by default, so only the user who invoked the command or interaction sees it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub response_type: Option<ResponseType>,
+    /// Replaces the message the interaction came from instead of posting a new one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub replace_original: Option<bool>,
+    /// Deletes the message the interaction came from.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_original: Option<bool>,
+}
+
+impl ResponseMessage {
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        ResponseMessage {
+            text: Some(text.into()),
+            ..ResponseMessage::default()
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseType {
+    Ephemeral,
+    InChannel,
+}
+
+/// Posts JSON to a `response_url`, which unlike the Web API takes a JSON body.
+#[async_trait]
+pub trait ResponseUrlSender {
+    type Error: Error + Send + Sync + 'static;
+
+    async fn post_json(&self, url: &str, body: String) -> Result<(), Self::Error>;
+}
+
+#[cfg(feature = "reqwest")]
+#[async_trait]
+impl ResponseUrlSender for reqwest_::Client {
+    type Error = reqwest_::Error;
+
+    async fn post_json(&self, url: &str, body: String) -> Result<(), Self::Error> {
+        self.post(url)
+            .header(reqwest_::header::CONTENT_TYPE, "application/json")
+            .body(body)
+            .send()
+            .await?
+            .error_for_status()
+            .map(drop)
+    }
+}
+
+#[derive(Debug)]
+pub enum RespondError<E: Error> {
+    /// The command or interaction has no `response_url`, such as a shortcut or a view submission.
+    NoResponseUrl,
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for RespondError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            RespondError::NoResponseUrl => write!(f, "No response_url to respond to"),
+            RespondError::Client(ref e) => write!(f, "Client Error: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for RespondError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            RespondError::NoResponseUrl => None,
+            RespondError::Client(ref e) => Some(e),
+        }
+    }
+}
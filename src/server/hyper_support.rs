@@ -0,0 +1,78 @@
+//! Serving a [`Dispatcher`] with `hyper` 0.13.
+//!
+//! ```ignore
+//! # use std::sync::Arc;
+//! # use std::convert::Infallible;
+//! # use slack_api::server::{Dispatcher, Handler};
+//! # use slack_api::server::hyper_support::handle;
+//! # struct App;
+//! # impl Handler for App {}
+//! # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+//! use hyper::service::{make_service_fn, service_fn};
+//!
+//! let dispatcher = Arc::new(Dispatcher::new("signing-secret", App));
+//! let make_svc = make_service_fn(move |_| {
+//!     let dispatcher = dispatcher.clone();
+//!     async move {
+//!         Ok::<_, Infallible>(service_fn(move |req| handle(dispatcher.clone(), req)))
+//!     }
+//! });
+//! hyper::Server::bind(&([127, 0, 0, 1], 3000).into()).serve(make_svc).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::convert::Infallible;
+use std::sync::Arc;
+
+use hyper_::header::{HeaderMap, CONTENT_TYPE};
+use hyper_::{Body, Request, Response};
+
+use super::{Dispatcher, Handler, IncomingRequest};
+
+/// Handles a request from Slack, spawning any work which outlives the acknowledgement onto the
+/// current tokio runtime.
+pub async fn handle<H: Handler>(
+    dispatcher: Arc<Dispatcher<H>>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    let (parts, body) = request.into_parts();
+    let body = match hyper_::body::to_bytes(body).await {
+        Ok(body) => body,
+        Err(_) => return Ok(status(400)),
+    };
+    let incoming = IncomingRequest {
+        timestamp: header(&parts.headers, "x-slack-request-timestamp"),
+        signature: header(&parts.headers, "x-slack-signature"),
+        retry_num: header(&parts.headers, "x-slack-retry-num"),
+        retry_reason: header(&parts.headers, "x-slack-retry-reason"),
+        content_type: header(&parts.headers, CONTENT_TYPE.as_str()),
+        body: body.to_vec(),
+    };
+
+    let dispatched = dispatcher.dispatch(&incoming).await;
+    if let Some(background) = dispatched.background {
+        tokio::spawn(background);
+    }
+
+    let mut response = Response::builder().status(dispatched.response.status);
+    if let Some(content_type) = dispatched.response.content_type {
+        response = response.header(CONTENT_TYPE, content_type);
+    }
+    Ok(response
+        .body(Body::from(dispatched.response.body))
+        .unwrap_or_else(|_| status(500)))
+}
+
+fn header(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_owned)
+}
+
+fn status(code: u16) -> Response<Body> {
+    let mut response = Response::new(Body::empty());
+    *response.status_mut() = hyper_::StatusCode::from_u16(code).unwrap_or_default();
+    response
+}
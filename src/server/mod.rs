@@ -0,0 +1,511 @@
+//! Receiving requests from Slack: the Events API, slash commands and interactive components.
+//!
+//! A [`Dispatcher`] verifies each request's signature, answers `url_verification` challenges and
+//! hands typed payloads to a [`Handler`]. If the handler doesn't finish within the acknowledgement
+//! timeout, the request is acknowledged with an empty `200 OK` and the remainder of the handler is
+//! returned to be run in the background, so that Slack never sees a request take longer than its
+//! 3 second limit.
+//!
+//! The dispatcher is independent of any HTTP server. An adapter for `hyper` 0.13 is available
+//! behind the `hyper` feature.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::time::Duration;
+
+use async_trait::async_trait;
+use futures_timer::Delay;
+use futures_util::future::{self, Either, FutureExt};
+use serde_json::Value;
+
+mod dedupe;
+pub use self::dedupe::*;
+
+mod deferred;
+pub use self::deferred::*;
+
+mod payloads;
+pub use self::payloads::*;
+
+mod signature;
+pub use self::signature::*;
+
+mod view_state;
+pub use self::view_state::*;
+
+#[cfg(feature = "hyper")]
+pub mod hyper_support;
+
+/// A boxed future which runs to completion after a request has been acknowledged.
+pub type BackgroundTask = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// How long handlers are given before a request is acknowledged on their behalf.
+pub const DEFAULT_ACK_TIMEOUT: Duration = Duration::from_millis(2500);
+
+/// Callbacks for the requests Slack sends to an app.
+///
+/// Every method has a default implementation, so only the payloads an app cares about need to be
+/// handled.
+#[async_trait]
+pub trait Handler: Send + Sync + 'static {
+    async fn event(&self, _callback: EventCallback) {}
+
+    async fn slash_command(&self, _command: SlashCommand) -> Ack {
+        Ack::Empty
+    }
+
+    async fn interaction(&self, _payload: InteractionPayload) -> Ack {
+        Ack::Empty
+    }
+}
+
+/// The body used to acknowledge a slash command or interaction.
+pub enum Ack {
+    Empty,
+    Text(String),
+    Json(Value),
+    /// Acknowledges with the inner ack and runs the task once Slack has been answered. See
+    /// [`Ack::then`].
+    Deferred(Box<Ack>, BackgroundTask),
+}
+
+impl Ack {
+    /// Acknowledges straight away with `self`, then runs `work` in the background, handed to the
+    /// caller as [`Dispatched::background`].
+    ///
+    /// Slack gives up on a request after 3 seconds, so slow work such as calling other services
+    /// belongs in `work`, which answers later through a [`ResponseContext`] or `views.update`:
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use slack_api::server::{Ack, ResponseContext, ResponseMessage, ResponseUrlSender, SlashCommand};
+    /// # async fn build_report(_: &str) -> String { String::new() }
+    /// fn report_command<S>(sender: Arc<S>, command: SlashCommand) -> Ack
+    /// where
+    ///     S: ResponseUrlSender + Send + Sync + 'static,
+    /// {
+    ///     let context = ResponseContext::from(&command);
+    ///     Ack::Text("Working on it...".to_owned()).then(async move {
+    ///         let report = build_report(&command.text).await;
+    ///         let _ = context.respond(&*sender, &ResponseMessage::text(report)).await;
+    ///     })
+    /// }
+    /// ```
+    pub fn then<F>(self, work: F) -> Ack
+    where
+        F: Future<Output = ()> + Send + 'static,
+    {
+        Ack::Deferred(Box::new(self), work.boxed())
+    }
+
+    /// Splits off the deferred work, if there is any.
+    fn into_parts(self) -> (Ack, Option<BackgroundTask>) {
+        match self {
+            Ack::Deferred(ack, work) => {
+                let (ack, inner) = ack.into_parts();
+                let work = match inner {
+                    Some(inner) => inner.then(|()| work).boxed(),
+                    None => work,
+                };
+                (ack, Some(work))
+            }
+            ack => (ack, None),
+        }
+    }
+}
+
+impl fmt::Debug for Ack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Ack::Empty => f.write_str("Empty"),
+            Ack::Text(ref text) => f.debug_tuple("Text").field(text).finish(),
+            Ack::Json(ref value) => f.debug_tuple("Json").field(value).finish(),
+            Ack::Deferred(ref ack, _) => f.debug_tuple("Deferred").field(ack).finish(),
+        }
+    }
+}
+
+/// A request received from Slack, as seen by the HTTP server.
+#[derive(Clone, Debug, Default)]
+pub struct IncomingRequest {
+    /// The value of the `X-Slack-Request-Timestamp` header.
+    pub timestamp: Option<String>,
+    /// The value of the `X-Slack-Signature` header.
+    pub signature: Option<String>,
+    /// The value of the `X-Slack-Retry-Num` header, sent when an event is being redelivered.
+    pub retry_num: Option<String>,
+    /// The value of the `X-Slack-Retry-Reason` header.
+    pub retry_reason: Option<String>,
+    pub content_type: Option<String>,
+    pub body: Vec<u8>,
+}
+
+/// The response to send back to Slack.
+#[derive(Clone, Debug, PartialEq)]
+pub struct HttpResponse {
+    pub status: u16,
+    pub content_type: Option<&'static str>,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    fn empty(status: u16) -> Self {
+        HttpResponse {
+            status,
+            content_type: None,
+            body: Vec::new(),
+        }
+    }
+
+    fn from_ack(ack: Ack) -> Self {
+        match ack {
+            Ack::Deferred(ack, _) => HttpResponse::from_ack(*ack),
+            Ack::Empty => HttpResponse::empty(200),
+            Ack::Text(text) => HttpResponse {
+                status: 200,
+                content_type: Some("text/plain; charset=utf-8"),
+                body: text.into_bytes(),
+            },
+            Ack::Json(value) => HttpResponse {
+                status: 200,
+                content_type: Some("application/json"),
+                body: value.to_string().into_bytes(),
+            },
+        }
+    }
+}
+
+/// The result of dispatching a request.
+pub struct Dispatched {
+    pub response: HttpResponse,
+    /// Work left over from a handler which didn't finish before the acknowledgement timeout.
+    ///
+    /// This must be spawned onto an executor by the caller.
+    pub background: Option<BackgroundTask>,
+}
+
+/// Verifies and routes requests from Slack to a [`Handler`].
+pub struct Dispatcher<H> {
+    verifier: SignatureVerifier,
+    handler: std::sync::Arc<H>,
+    ack_timeout: Duration,
+    dedupe: Option<DedupeCache>,
+}
+
+impl<H: Handler> Dispatcher<H> {
+    pub fn new<S: Into<String>>(signing_secret: S, handler: H) -> Self {
+        Dispatcher {
+            verifier: SignatureVerifier::new(signing_secret),
+            handler: std::sync::Arc::new(handler),
+            ack_timeout: DEFAULT_ACK_TIMEOUT,
+            dedupe: None,
+        }
+    }
+
+    /// Verifies requests with `verifier`, such as one accepting both secrets during a rotation.
+    pub fn verifier(mut self, verifier: SignatureVerifier) -> Self {
+        self.verifier = verifier;
+        self
+    }
+
+    /// Sets how long handlers are given before the request is acknowledged on their behalf.
+    pub fn ack_timeout(mut self, timeout: Duration) -> Self {
+        self.ack_timeout = timeout;
+        self
+    }
+
+    /// Skips events whose `event_id` has already been handled, acknowledging them without calling
+    /// the handler again.
+    pub fn dedupe(mut self, cache: DedupeCache) -> Self {
+        self.dedupe = Some(cache);
+        self
+    }
+
+    pub fn handler(&self) -> &H {
+        &self.handler
+    }
+
+    pub async fn dispatch(&self, request: &IncomingRequest) -> Dispatched {
+        if self
+            .verifier
+            .verify(
+                request.timestamp.as_deref(),
+                request.signature.as_deref(),
+                &request.body,
+            )
+            .is_err()
+        {
+            return Dispatched {
+                response: HttpResponse::empty(401),
+                background: None,
+            };
+        }
+
+        let is_json = request
+            .content_type
+            .as_deref()
+            .is_some_and(|ct| ct.starts_with("application/json"));
+        if is_json {
+            self.dispatch_event(request).await
+        } else {
+            self.dispatch_form(&request.body).await
+        }
+    }
+
+    async fn dispatch_event(&self, request: &IncomingRequest) -> Dispatched {
+        let payload = match serde_json::from_slice::<EventsApiPayload>(&request.body) {
+            Ok(payload) => payload,
+            Err(_) => return bad_request(),
+        };
+        match payload {
+            EventsApiPayload::UrlVerification { challenge } => Dispatched {
+                response: HttpResponse::from_ack(Ack::Text(challenge)),
+                background: None,
+            },
+            EventsApiPayload::EventCallback(mut callback) => {
+                callback.retry = request
+                    .retry_num
+                    .as_deref()
+                    .and_then(|n| n.trim().parse().ok())
+                    .map(|num| Retry {
+                        num,
+                        reason: request.retry_reason.as_deref().map(RetryReason::from),
+                    });
+                let duplicate = match (&self.dedupe, &callback.event_id) {
+                    (Some(cache), Some(event_id)) => !cache.insert(event_id),
+                    _ => false,
+                };
+                if duplicate {
+                    return Dispatched {
+                        response: HttpResponse::empty(200),
+                        background: None,
+                    };
+                }
+                let handler = self.handler.clone();
+                let fut = async move { handler.event(*callback).await }.map(|()| Ack::Empty);
+                self.run(fut).await
+            }
+            EventsApiPayload::AppRateLimited { .. } => Dispatched {
+                response: HttpResponse::empty(200),
+                background: None,
+            },
+        }
+    }
+
+    async fn dispatch_form(&self, body: &[u8]) -> Dispatched {
+        let form: Vec<(String, String)> = match serde_urlencoded::from_bytes(body) {
+            Ok(form) => form,
+            Err(_) => return bad_request(),
+        };
+
+        if let Some((_, payload)) = form.iter().find(|(k, _)| k == "payload") {
+            let payload = match serde_json::from_str::<InteractionPayload>(payload) {
+                Ok(payload) => payload,
+                Err(_) => return bad_request(),
+            };
+            let handler = self.handler.clone();
+            return self
+                .run(async move { handler.interaction(payload).await })
+                .await;
+        }
+
+        if form.iter().any(|(k, _)| k == "command") {
+            let command = match serde_urlencoded::from_bytes::<SlashCommand>(body) {
+                Ok(command) => command,
+                Err(_) => return bad_request(),
+            };
+            let handler = self.handler.clone();
+            return self
+                .run(async move { handler.slash_command(command).await })
+                .await;
+        }
+
+        bad_request()
+    }
+
+    /// Runs a handler until it completes or the acknowledgement timeout passes, whichever is first.
+    async fn run<F>(&self, fut: F) -> Dispatched
+    where
+        F: Future<Output = Ack> + Send + 'static,
+    {
+        let fut = fut.boxed();
+        match future::select(fut, Delay::new(self.ack_timeout)).await {
+            Either::Left((ack, _)) => {
+                let (ack, background) = ack.into_parts();
+                Dispatched {
+                    response: HttpResponse::from_ack(ack),
+                    background,
+                }
+            }
+            Either::Right(((), fut)) => Dispatched {
+                response: HttpResponse::empty(200),
+                // The ack comes too late to send, but its deferred work still has to run.
+                background: Some(
+                    fut.then(|ack| match ack.into_parts().1 {
+                        Some(work) => work,
+                        None => future::ready(()).boxed(),
+                    })
+                    .boxed(),
+                ),
+            },
+        }
+    }
+}
+
+fn bad_request() -> Dispatched {
+    Dispatched {
+        response: HttpResponse::empty(400),
+        background: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hmac::{Hmac, Mac};
+    use sha2::Sha256;
+    use std::sync::{Arc, Mutex};
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    const SECRET: &str = "secret";
+
+    #[derive(Default)]
+    struct Recorder {
+        events: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl Handler for Recorder {
+        async fn event(&self, callback: EventCallback) {
+            let ty = callback.event.event_type().unwrap_or_default().to_owned();
+            self.events.lock().unwrap().push(ty);
+        }
+
+        async fn slash_command(&self, command: SlashCommand) -> Ack {
+            let ack = Ack::Text(format!("{} {}", command.command, command.text));
+            if command.command != "/report" {
+                return ack;
+            }
+            let context = ResponseContext::from(&command);
+            let events = self.events.clone();
+            ack.then(async move {
+                let url = context.response_url.unwrap_or_default();
+                events.lock().unwrap().push(url);
+            })
+        }
+
+        async fn interaction(&self, payload: InteractionPayload) -> Ack {
+            if let InteractionPayload::ViewSubmission(_) = payload {
+                Delay::new(Duration::from_millis(200)).await;
+            }
+            Ack::Empty
+        }
+    }
+
+    fn signed(content_type: &str, body: &str) -> IncomingRequest {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .to_string();
+        let mut mac = Hmac::<Sha256>::new_from_slice(SECRET.as_bytes()).unwrap();
+        mac.update(format!("v0:{}:{}", timestamp, body).as_bytes());
+        let signature = format!("v0={}", hex::encode(mac.finalize().into_bytes()));
+        IncomingRequest {
+            timestamp: Some(timestamp),
+            signature: Some(signature),
+            content_type: Some(content_type.to_owned()),
+            body: body.as_bytes().to_vec(),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_url_verification_and_events() {
+        let dispatcher = Dispatcher::new(SECRET, Recorder::default());
+
+        let res = dispatcher
+            .dispatch(&signed(
+                "application/json",
+                r#"{"type": "url_verification", "challenge": "abc", "token": "t"}"#,
+            ))
+            .await;
+        assert_eq!(res.response.body, b"abc".to_vec());
+
+        let res = dispatcher
+            .dispatch(&signed(
+                "application/json",
+                r#"{"type": "event_callback", "team_id": "T1", "event": {"type": "app_mention", "text": "hi"}}"#,
+            ))
+            .await;
+        assert_eq!(res.response.status, 200);
+        assert!(res.background.is_none());
+        assert_eq!(
+            *dispatcher.handler().events.lock().unwrap(),
+            vec!["app_mention".to_owned()]
+        );
+
+        let mut unsigned = signed("application/json", "{}");
+        unsigned.signature = Some("v0=00".to_owned());
+        assert_eq!(dispatcher.dispatch(&unsigned).await.response.status, 401);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_skips_redelivered_events() {
+        let dispatcher =
+            Dispatcher::new(SECRET, Recorder::default()).dedupe(DedupeCache::default());
+        let body =
+            r#"{"type": "event_callback", "event_id": "Ev1", "event": {"type": "app_mention"}}"#;
+
+        dispatcher.dispatch(&signed("application/json", body)).await;
+        let mut retry = signed("application/json", body);
+        retry.retry_num = Some("1".to_owned());
+        retry.retry_reason = Some("http_timeout".to_owned());
+        let res = dispatcher.dispatch(&retry).await;
+
+        assert_eq!(res.response, HttpResponse::empty(200));
+        assert_eq!(dispatcher.handler().events.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_commands_and_slow_interactions() {
+        let dispatcher =
+            Dispatcher::new(SECRET, Recorder::default()).ack_timeout(Duration::from_millis(10));
+
+        let res = dispatcher
+            .dispatch(&signed(
+                "application/x-www-form-urlencoded",
+                "command=%2Fweather&text=today&team_id=T1",
+            ))
+            .await;
+        assert_eq!(res.response.body, b"/weather today".to_vec());
+
+        let payload = r#"{"type": "view_submission", "team": {"id": "T1"}}"#;
+        let body = serde_urlencoded::to_string([("payload", payload)]).unwrap();
+        let res = dispatcher
+            .dispatch(&signed("application/x-www-form-urlencoded", &body))
+            .await;
+        assert_eq!(res.response, HttpResponse::empty(200));
+        res.background.expect("handler should have timed out").await;
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_deferred_ack() {
+        let dispatcher = Dispatcher::new(SECRET, Recorder::default());
+        let res = dispatcher
+            .dispatch(&signed(
+                "application/x-www-form-urlencoded",
+                "command=%2Freport&text=weekly&response_url=https%3A%2F%2Fhooks.slack.com%2Fr",
+            ))
+            .await;
+        assert_eq!(res.response.body, b"/report weekly".to_vec());
+        assert!(dispatcher.handler().events.lock().unwrap().is_empty());
+
+        res.background.expect("work should be deferred").await;
+        assert_eq!(
+            *dispatcher.handler().events.lock().unwrap(),
+            vec!["https://hooks.slack.com/r".to_owned()]
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use serde_json::Value;
+
+use super::{Retry, ViewState};
+use crate::events::Event;
+use crate::InstallationQuery;
+
+/// The outer envelope of a request sent to an Events API request URL.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum EventsApiPayload {
+    /// Sent once when the request URL is configured; must be answered with the challenge.
+    UrlVerification {
+        challenge: String,
+    },
+    EventCallback(Box<EventCallback>),
+    /// Sent when the app has exceeded the Events API rate limit for a workspace.
+    AppRateLimited {
+        team_id: Option<String>,
+        minute_rate_limited: Option<u64>,
+        api_app_id: Option<String>,
+    },
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventCallback {
+    pub api_app_id: Option<String>,
+    pub authorizations: Option<Vec<EventAuthorization>>,
+    pub enterprise_id: Option<String>,
+    pub event: Event,
+    pub event_id: Option<String>,
+    pub event_time: Option<u64>,
+    #[serde(default)]
+    pub is_ext_shared_channel: bool,
+    pub team_id: Option<String>,
+    /// Set when Slack is redelivering the event, from the request's retry headers.
+    #[serde(skip)]
+    pub retry: Option<Retry>,
+}
+
+impl EventCallback {
+    /// The workspace the event was delivered for.
+    pub fn installation_query(&self) -> InstallationQuery<'_> {
+        InstallationQuery {
+            enterprise_id: self.enterprise_id.as_deref(),
+            team_id: self.team_id.as_deref(),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct EventAuthorization {
+    pub enterprise_id: Option<String>,
+    #[serde(default)]
+    pub is_bot: bool,
+    #[serde(default)]
+    pub is_enterprise_install: bool,
+    pub team_id: Option<String>,
+    pub user_id: Option<String>,
+}
+
+/// A slash command invocation, sent as a form-encoded body.
+#[derive(Clone, Debug, Deserialize)]
+pub struct SlashCommand {
+    pub api_app_id: Option<String>,
+    pub channel_id: Option<String>,
+    pub channel_name: Option<String>,
+    pub command: String,
+    pub enterprise_id: Option<String>,
+    pub enterprise_name: Option<String>,
+    #[serde(default)]
+    pub is_enterprise_install: Option<String>,
+    pub response_url: Option<String>,
+    pub team_domain: Option<String>,
+    pub team_id: Option<String>,
+    #[serde(default)]
+    pub text: String,
+    pub trigger_id: Option<crate::TriggerId>,
+    pub user_id: Option<String>,
+    pub user_name: Option<String>,
+}
+
+impl SlashCommand {
+    /// The workspace the command was invoked in.
+    pub fn installation_query(&self) -> InstallationQuery<'_> {
+        InstallationQuery {
+            enterprise_id: self.enterprise_id.as_deref(),
+            team_id: self.team_id.as_deref(),
+        }
+    }
+}
+
+/// The payload of an interactive component request, sent as JSON in the `payload` form field.
+#[derive(Clone, Debug)]
+pub enum InteractionPayload {
+    BlockActions(BlockActions),
+    MessageAction(MessageAction),
+    Shortcut(Shortcut),
+    ViewClosed(ViewInteraction),
+    ViewSubmission(ViewInteraction),
+    /// An interaction of a type that isn't typed by this library.
+    Other(Value),
+}
+
+impl InteractionPayload {
+    /// The workspace the interaction happened in.
+    pub fn installation_query(&self) -> InstallationQuery<'_> {
+        let team = match *self {
+            InteractionPayload::BlockActions(ref p) => p.team.as_ref(),
+            InteractionPayload::MessageAction(ref p) => p.team.as_ref(),
+            InteractionPayload::Shortcut(ref p) => p.team.as_ref(),
+            InteractionPayload::ViewClosed(ref p) | InteractionPayload::ViewSubmission(ref p) => {
+                p.team.as_ref()
+            }
+            InteractionPayload::Other(ref value) => {
+                return InstallationQuery {
+                    enterprise_id: value.pointer("/enterprise/id").and_then(Value::as_str),
+                    team_id: value.pointer("/team/id").and_then(Value::as_str),
+                }
+            }
+        };
+        InstallationQuery {
+            enterprise_id: team.and_then(|t| t.enterprise_id.as_deref()),
+            team_id: team.and_then(|t| t.id.as_deref()),
+        }
+    }
+}
+
+impl<'de> ::serde::Deserialize<'de> for InteractionPayload {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: ::serde::Deserializer<'de>,
+    {
+        use ::serde::de::Error as SerdeError;
+
+        fn parse<T, E>(
+            value: Value,
+            f: fn(T) -> InteractionPayload,
+        ) -> Result<InteractionPayload, E>
+        where
+            T: ::serde::de::DeserializeOwned,
+            E: SerdeError,
+        {
+            ::serde_json::from_value::<T>(value)
+                .map(f)
+                .map_err(E::custom)
+        }
+
+        let value = Value::deserialize(deserializer)?;
+        let ty = value.get("type").and_then(Value::as_str).map(str::to_owned);
+        match ty.as_deref() {
+            Some("block_actions") => parse(value, InteractionPayload::BlockActions),
+            Some("message_action") => parse(value, InteractionPayload::MessageAction),
+            Some("shortcut") => parse(value, InteractionPayload::Shortcut),
+            Some("view_closed") => parse(value, InteractionPayload::ViewClosed),
+            Some("view_submission") => parse(value, InteractionPayload::ViewSubmission),
+            Some(_) => Ok(InteractionPayload::Other(value)),
+            None => Err(D::Error::missing_field("type")),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InteractionTeam {
+    pub domain: Option<String>,
+    pub enterprise_id: Option<String>,
+    pub id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InteractionUser {
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub team_id: Option<String>,
+    pub username: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InteractionChannel {
+    pub id: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct BlockActions {
+    pub actions: Option<Vec<Value>>,
+    pub api_app_id: Option<String>,
+    pub channel: Option<InteractionChannel>,
+    pub container: Option<Value>,
+    pub message: Option<Value>,
+    pub response_url: Option<String>,
+    pub team: Option<InteractionTeam>,
+    pub trigger_id: Option<crate::TriggerId>,
+    pub user: Option<InteractionUser>,
+    pub view: Option<View>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct MessageAction {
+    pub callback_id: Option<String>,
+    pub channel: Option<InteractionChannel>,
+    pub message: Option<Value>,
+    pub response_url: Option<String>,
+    pub team: Option<InteractionTeam>,
+    pub trigger_id: Option<crate::TriggerId>,
+    pub user: Option<InteractionUser>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct Shortcut {
+    pub callback_id: Option<String>,
+    pub team: Option<InteractionTeam>,
+    pub trigger_id: Option<crate::TriggerId>,
+    pub user: Option<InteractionUser>,
+}
+
+/// A `view_submission` or `view_closed` interaction.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ViewInteraction {
+    pub api_app_id: Option<String>,
+    pub team: Option<InteractionTeam>,
+    pub trigger_id: Option<crate::TriggerId>,
+    pub user: Option<InteractionUser>,
+    pub view: Option<View>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct View {
+    pub callback_id: Option<String>,
+    pub hash: Option<String>,
+    pub id: Option<String>,
+    pub private_metadata: Option<String>,
+    /// The values entered into the view's input blocks, keyed by block and action id.
+    pub state: Option<ViewState>,
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+}
@@ -0,0 +1,182 @@
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::blocks::Text;
+
+/// The values entered into a view's input blocks, keyed by block id and then action id.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct ViewState {
+    #[serde(default)]
+    pub values: HashMap<String, HashMap<String, StateValue>>,
+}
+
+/// The value of one input element. Which field is set depends on the element's `type`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct StateValue {
+    #[serde(rename = "type")]
+    pub ty: Option<String>,
+    /// Set by plain text, number, email and URL inputs.
+    pub value: Option<String>,
+    /// Set by static and external selects, radio buttons and overflow menus.
+    pub selected_option: Option<SelectedOption>,
+    /// Set by multi-selects and checkboxes.
+    pub selected_options: Option<Vec<SelectedOption>>,
+    /// A date picker's date, as `YYYY-MM-DD`.
+    pub selected_date: Option<String>,
+    /// A time picker's time, as `HH:mm`.
+    pub selected_time: Option<String>,
+    /// A datetime picker's time, as a UNIX timestamp.
+    pub selected_date_time: Option<i64>,
+    pub selected_user: Option<String>,
+    pub selected_users: Option<Vec<String>>,
+    pub selected_channel: Option<String>,
+    pub selected_channels: Option<Vec<String>>,
+    pub selected_conversation: Option<String>,
+    pub selected_conversations: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct SelectedOption {
+    pub text: Option<Text>,
+    pub value: Option<String>,
+}
+
+impl ViewState {
+    pub fn get(&self, block_id: &str, action_id: &str) -> Option<&StateValue> {
+        self.values.get(block_id)?.get(action_id)
+    }
+
+    /// The text entered into a plain text, number, email or URL input.
+    pub fn get_text(&self, block_id: &str, action_id: &str) -> Option<&str> {
+        self.get(block_id, action_id)?.value.as_deref()
+    }
+
+    pub fn get_selected_option(&self, block_id: &str, action_id: &str) -> Option<&SelectedOption> {
+        self.get(block_id, action_id)?.selected_option.as_ref()
+    }
+
+    pub fn get_selected_options(
+        &self,
+        block_id: &str,
+        action_id: &str,
+    ) -> Option<&[SelectedOption]> {
+        self.get(block_id, action_id)?.selected_options.as_deref()
+    }
+
+    /// A date picker's date, as `YYYY-MM-DD`.
+    pub fn get_selected_date(&self, block_id: &str, action_id: &str) -> Option<&str> {
+        self.get(block_id, action_id)?.selected_date.as_deref()
+    }
+
+    pub fn get_selected_user(&self, block_id: &str, action_id: &str) -> Option<&str> {
+        self.get(block_id, action_id)?.selected_user.as_deref()
+    }
+
+    /// Deserializes the state into a struct with a field per action id.
+    ///
+    /// Each element contributes its value under its action id: the text of text inputs, the
+    /// `value` of selected options, the date, time or timestamp of pickers and the ids of
+    /// selected users and conversations, with multi-selects giving arrays. Elements left empty
+    /// contribute `null`, so optional inputs map to `Option` fields. Action ids must be unique
+    /// across the view's blocks.
+    pub fn parse<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        let fields = self
+            .values
+            .values()
+            .flatten()
+            .map(|(action_id, value)| (action_id.clone(), value.to_json()))
+            .collect::<Map<_, _>>();
+        serde_json::from_value(Value::Object(fields))
+    }
+}
+
+impl StateValue {
+    /// The element's value as the JSON `ViewState::parse` deserializes from.
+    fn to_json(&self) -> Value {
+        let option_value = |o: &SelectedOption| o.value.clone().map_or(Value::Null, Value::from);
+        if let Some(ref value) = self.value {
+            Value::from(value.clone())
+        } else if let Some(ref option) = self.selected_option {
+            option_value(option)
+        } else if let Some(ref options) = self.selected_options {
+            options.iter().map(option_value).collect()
+        } else if let Some(ref date) = self.selected_date {
+            Value::from(date.clone())
+        } else if let Some(ref time) = self.selected_time {
+            Value::from(time.clone())
+        } else if let Some(timestamp) = self.selected_date_time {
+            Value::from(timestamp)
+        } else if let Some(id) = self
+            .selected_user
+            .as_ref()
+            .or(self.selected_channel.as_ref())
+            .or(self.selected_conversation.as_ref())
+        {
+            Value::from(id.to_string())
+        } else if let Some(ids) = self
+            .selected_users
+            .as_ref()
+            .or(self.selected_channels.as_ref())
+            .or(self.selected_conversations.as_ref())
+        {
+            Value::from(ids.to_vec())
+        } else {
+            Value::Null
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_view_state_accessors_and_parse() {
+        let state: ViewState = serde_json::from_str(
+            r#"{"values": {
+                "title_block": {"title": {"type": "plain_text_input", "value": "Outage"}},
+                "severity_block": {"severity": {
+                    "type": "static_select",
+                    "selected_option": {"text": {"type": "plain_text", "text": "High"}, "value": "high"}
+                }},
+                "due_block": {"due": {"type": "datepicker", "selected_date": "2024-05-01"}},
+                "owners_block": {"owners": {"type": "multi_users_select", "selected_users": ["U1", "U2"]}},
+                "notes_block": {"notes": {"type": "plain_text_input", "value": null}}
+            }}"#,
+        )
+        .unwrap();
+        assert_eq!(state.get_text("title_block", "title"), Some("Outage"));
+        assert_eq!(
+            state
+                .get_selected_option("severity_block", "severity")
+                .and_then(|o| o.value.as_deref()),
+            Some("high")
+        );
+        assert_eq!(
+            state.get_selected_date("due_block", "due"),
+            Some("2024-05-01")
+        );
+        assert_eq!(state.get_text("title_block", "missing"), None);
+
+        #[derive(Debug, Deserialize, PartialEq)]
+        struct Incident {
+            title: String,
+            severity: String,
+            due: Option<String>,
+            owners: Vec<String>,
+            notes: Option<String>,
+        }
+        assert_eq!(
+            state.parse::<Incident>().unwrap(),
+            Incident {
+                title: "Outage".into(),
+                severity: "high".into(),
+                due: Some("2024-05-01".into()),
+                owners: vec!["U1".into(), "U2".into()],
+                notes: None,
+            }
+        );
+    }
+}
@@ -0,0 +1,97 @@
+//! An error any of the generated functions can be converted into, for applications calling many
+//! methods which propagate their errors with `?`.
+
+use std::error::Error;
+use std::fmt;
+
+/// The error of any generated function, converted from its method's `*Error` by `From`.
+#[derive(Debug)]
+pub enum SlackError<E: Error> {
+    /// The response returned an error, given as Slack's error code
+    Api { method: &'static str, error: String },
+    /// The response was not parseable as the expected object
+    MalformedResponse {
+        method: &'static str,
+        body: String,
+        error: serde_json::error::Error,
+    },
+    /// The client had an error sending the request to Slack
+    Client { method: &'static str, error: E },
+}
+
+impl<E: Error> SlackError<E> {
+    /// The method which failed, such as `chat.postMessage`.
+    pub fn method(&self) -> &'static str {
+        match *self {
+            SlackError::Api { method, .. }
+            | SlackError::MalformedResponse { method, .. }
+            | SlackError::Client { method, .. } => method,
+        }
+    }
+
+    /// Slack's error code, if the response returned one.
+    pub fn api_error(&self) -> Option<&str> {
+        match *self {
+            SlackError::Api { ref error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl<E: Error> fmt::Display for SlackError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            SlackError::Api { method, ref error } => write!(f, "{} failed: {}", method, error),
+            SlackError::MalformedResponse {
+                method, ref error, ..
+            } => write!(f, "{} returned a malformed response: {}", method, error),
+            SlackError::Client { method, ref error } => write!(f, "{} failed: {}", method, error),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for SlackError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            SlackError::MalformedResponse { ref error, .. } => Some(error),
+            SlackError::Client { ref error, .. } => Some(error),
+            SlackError::Api { .. } => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::mod_types::chat_types::PostMessageError;
+    use crate::mod_types::conversations_types::HistoryError;
+
+    fn post() -> Result<(), SlackError<std::io::Error>> {
+        Err(PostMessageError::from("channel_not_found"))?
+    }
+
+    fn history() -> Result<(), SlackError<std::io::Error>> {
+        Err(HistoryError::from("some_new_error"))?
+    }
+
+    #[test]
+    fn test_from_generated_errors() {
+        let error = post().unwrap_err();
+        assert_eq!(error.method(), "chat.postMessage");
+        assert_eq!(error.api_error(), Some("channel_not_found"));
+        assert_eq!(
+            error.to_string(),
+            "chat.postMessage failed: channel_not_found"
+        );
+
+        let error = history().unwrap_err();
+        assert_eq!(error.method(), "conversations.history");
+        assert_eq!(error.api_error(), Some("some_new_error"));
+
+        let client: SlackError<_> =
+            PostMessageError::Client(std::io::Error::other("timed out")).into();
+        assert_eq!(client.method(), "chat.postMessage");
+        assert!(client.source().is_some());
+    }
+}
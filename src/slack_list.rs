@@ -0,0 +1,260 @@
+use crate::blocks::{Block, RichTextBlock, RichTextElement, RichTextObject, RichTextSection};
+
+/// The type of a column of a list.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListColumnType {
+    Text,
+    Number,
+    Select,
+    Date,
+    User,
+    Channel,
+    Checkbox,
+    Email,
+    Phone,
+    Rating,
+    Attachment,
+    /// A type Slack added after this version of the crate. It can't be used to create a column.
+    #[serde(other)]
+    Unknown,
+}
+
+/// A column of a list's schema, given to `slackLists.create`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListColumn {
+    /// Identifies the column within the schema.
+    pub key: String,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub column_type: ListColumnType,
+    /// Whether the column holds the items' names. A list has exactly one primary column, which
+    /// must be a text column.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub is_primary_column: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<ListColumnOptions>,
+}
+
+impl ListColumn {
+    pub fn new<K: Into<String>, N: Into<String>>(
+        key: K,
+        name: N,
+        column_type: ListColumnType,
+    ) -> Self {
+        ListColumn {
+            key: key.into(),
+            name: name.into(),
+            column_type,
+            is_primary_column: false,
+            options: None,
+        }
+    }
+
+    /// Makes this the primary column.
+    pub fn primary(mut self) -> Self {
+        self.is_primary_column = true;
+        self
+    }
+
+    /// Sets the choices of a select column.
+    pub fn choices(mut self, choices: Vec<ListSelectChoice>) -> Self {
+        self.options
+            .get_or_insert_with(ListColumnOptions::default)
+            .choices = Some(choices);
+        self
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ListColumnOptions {
+    /// The choices of a select column.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub choices: Option<Vec<ListSelectChoice>>,
+    /// How a number or date column is displayed, such as `currency` or `DATE_FORMAT_ISO8601`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+    /// The number of decimal places shown by a number column.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub precision: Option<u32>,
+}
+
+/// A choice of a select column.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ListSelectChoice {
+    /// Identifies the choice in a [`ListFieldValue::Select`].
+    pub value: String,
+    pub label: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<String>,
+}
+
+impl ListSelectChoice {
+    pub fn new<V: Into<String>, L: Into<String>>(value: V, label: L) -> Self {
+        ListSelectChoice {
+            value: value.into(),
+            label: label.into(),
+            color: None,
+        }
+    }
+}
+
+/// The value of an item's field, matching the type of its column.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ListFieldValue {
+    /// The value of a text column, as rich text blocks.
+    RichText(Vec<Block>),
+    Number(Vec<f64>),
+    /// The `value`s of the chosen [`ListSelectChoice`]s.
+    Select(Vec<String>),
+    /// Dates formatted as `YYYY-MM-DD`.
+    Date(Vec<String>),
+    /// User IDs.
+    User(Vec<String>),
+    /// Channel IDs.
+    Channel(Vec<String>),
+    Checkbox(bool),
+    Email(Vec<String>),
+    Phone(Vec<String>),
+    Rating(Vec<u32>),
+}
+
+impl ListFieldValue {
+    /// The value of a text column holding plain `text`.
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        ListFieldValue::RichText(vec![Block::RichText(RichTextBlock {
+            elements: vec![RichTextObject::Section(RichTextSection::new(vec![
+                RichTextElement::text(text),
+            ]))],
+            block_id: None,
+        })])
+    }
+}
+
+/// A field of an item created with `slackLists.items.create`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ListField {
+    pub column_id: String,
+    #[serde(flatten)]
+    pub value: ListFieldValue,
+}
+
+impl ListField {
+    pub fn new<S: Into<String>>(column_id: S, value: ListFieldValue) -> Self {
+        ListField {
+            column_id: column_id.into(),
+            value,
+        }
+    }
+}
+
+/// A field of an existing item, set with `slackLists.items.update`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct ListCell {
+    /// The ID of the item.
+    pub row_id: String,
+    pub column_id: String,
+    #[serde(flatten)]
+    pub value: ListFieldValue,
+}
+
+impl ListCell {
+    pub fn new<R: Into<String>, C: Into<String>>(
+        row_id: R,
+        column_id: C,
+        value: ListFieldValue,
+    ) -> Self {
+        ListCell {
+            row_id: row_id.into(),
+            column_id: column_id.into(),
+            value,
+        }
+    }
+}
+
+/// An item of a list.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListItem {
+    pub id: Option<String>,
+    pub list_id: Option<String>,
+    pub created_by: Option<String>,
+    pub date_created: Option<crate::Timestamp>,
+    pub updated_by: Option<String>,
+    pub updated_timestamp: Option<crate::Timestamp>,
+    /// The item this is a subtask of.
+    pub parent_item_id: Option<String>,
+    pub fields: Option<Vec<ListItemField>>,
+}
+
+impl ListItem {
+    /// The item's field in the column `column_id`, if it has a value there.
+    pub fn field(&self, column_id: &str) -> Option<&ListItemField> {
+        self.fields
+            .as_ref()?
+            .iter()
+            .find(|field| field.column_id.as_deref() == Some(column_id))
+    }
+}
+
+/// A field of a list item, with the typed value matching its column's type.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListItemField {
+    pub column_id: Option<String>,
+    /// The key of the field's column in the list's schema.
+    pub key: Option<String>,
+    /// The value as plain text.
+    pub text: Option<String>,
+    pub rich_text: Option<Vec<Block>>,
+    pub number: Option<Vec<f64>>,
+    pub select: Option<Vec<String>>,
+    pub date: Option<Vec<String>>,
+    pub user: Option<Vec<String>>,
+    pub channel: Option<Vec<String>>,
+    pub checkbox: Option<bool>,
+    pub email: Option<Vec<String>>,
+    pub phone: Option<Vec<String>>,
+    pub rating: Option<Vec<u32>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_params() {
+        let fields = [
+            ListField::new("Col1", ListFieldValue::text("Ship it")),
+            ListField::new("Col2", ListFieldValue::Checkbox(true)),
+        ];
+        assert_eq!(
+            crate::to_json_param(&fields[..]),
+            r#"[{"column_id":"Col1","rich_text":[{"type":"rich_text","elements":[{"type":"rich_text_section","elements":[{"type":"text","text":"Ship it"}]}]}]},{"column_id":"Col2","checkbox":true}]"#
+        );
+        let schema = [
+            ListColumn::new("task", "Task", ListColumnType::Text).primary(),
+            ListColumn::new("status", "Status", ListColumnType::Select)
+                .choices(vec![ListSelectChoice::new("done", "Done")]),
+        ];
+        assert_eq!(
+            crate::to_json_param(&schema[..]),
+            r#"[{"key":"task","name":"Task","type":"text","is_primary_column":true},{"key":"status","name":"Status","type":"select","options":{"choices":[{"value":"done","label":"Done"}]}}]"#
+        );
+    }
+
+    #[test]
+    fn test_list_item() {
+        let item: ListItem = serde_json::from_str(
+            r#"{"id": "Rec1", "list_id": "F1", "date_created": 1700000000,
+                "fields": [{"key": "done", "column_id": "Col2", "checkbox": true, "text": ""},
+                           {"key": "owner", "column_id": "Col3", "user": ["U1"]}]}"#,
+        )
+        .unwrap();
+        assert_eq!(item.field("Col2").unwrap().checkbox, Some(true));
+        assert_eq!(
+            item.field("Col3").unwrap().user,
+            Some(vec!["U1".to_owned()])
+        );
+        assert!(item.field("Col4").is_none());
+    }
+}
@@ -0,0 +1,609 @@
+//! A key-value store the crate's stateful parts can share, so an app keeps its installations,
+//! conversation states and outbox in one place.
+//!
+//! Any [`Storage`] is also an `InstallationStore`, a `conversation_state::StateStore` and an
+//! `OutboxStore`, each keeping its values as JSON under its own prefix of keys. [`MemoryStorage`]
+//! and [`FileStorage`] are always available, and `RedisStorage` and `SledStorage` with the `redis`
+//! and `sled` features.
+
+use std::collections::BTreeMap;
+use std::convert::Infallible;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use crate::conversation_state::{ConversationKey, StateStore, StoredState};
+use crate::outbox_types::{OutboxEntry, OutboxStore};
+use crate::{Installation, InstallationQuery};
+
+/// A store of byte values by string keys.
+///
+/// Calls block until the store has answered, so stores over the network should be quick to
+/// answer when used from async code.
+pub trait Storage {
+    type Error: Error + Send + Sync + 'static;
+
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Self::Error>;
+
+    /// Stores `value`, replacing any value `key` had.
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), Self::Error>;
+
+    /// Stores `value` unless `key` already has one, returning whether it did.
+    fn insert(&self, key: &str, value: &[u8]) -> Result<bool, Self::Error>;
+
+    /// Removes `key`'s value, if it has one.
+    fn remove(&self, key: &str) -> Result<(), Self::Error>;
+
+    /// The keys starting with `prefix`, in order.
+    fn keys(&self, prefix: &str) -> Result<Vec<String>, Self::Error>;
+}
+
+macro_rules! forward_storage {
+    ($($ty:ty),*) => {
+        $(
+            /// Shares a storage between several stores.
+            impl<S: Storage + ?Sized> Storage for $ty {
+                type Error = S::Error;
+
+                fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+                    (**self).get(key)
+                }
+
+                fn set(&self, key: &str, value: &[u8]) -> Result<(), Self::Error> {
+                    (**self).set(key, value)
+                }
+
+                fn insert(&self, key: &str, value: &[u8]) -> Result<bool, Self::Error> {
+                    (**self).insert(key, value)
+                }
+
+                fn remove(&self, key: &str) -> Result<(), Self::Error> {
+                    (**self).remove(key)
+                }
+
+                fn keys(&self, prefix: &str) -> Result<Vec<String>, Self::Error> {
+                    (**self).keys(prefix)
+                }
+            }
+        )*
+    };
+}
+
+forward_storage!(&S, Arc<S>);
+
+/// An error of a store kept in a [`Storage`].
+#[derive(Debug)]
+pub enum StorageError<E: Error> {
+    /// The storage failed.
+    Storage(E),
+    /// A value couldn't be converted to or from JSON.
+    Json(serde_json::Error),
+}
+
+impl<E: Error> fmt::Display for StorageError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            StorageError::Storage(ref e) => write!(f, "Storage failed: {}", e),
+            StorageError::Json(ref e) => write!(f, "Invalid stored value: {}", e),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for StorageError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            StorageError::Storage(ref e) => Some(e),
+            StorageError::Json(ref e) => Some(e),
+        }
+    }
+}
+
+/// Reads the JSON value of `key`.
+pub(crate) fn get_json<S, T>(storage: &S, key: &str) -> Result<Option<T>, StorageError<S::Error>>
+where
+    S: Storage + ?Sized,
+    T: serde::de::DeserializeOwned,
+{
+    match storage.get(key).map_err(StorageError::Storage)? {
+        Some(value) => serde_json::from_slice(&value)
+            .map(Some)
+            .map_err(StorageError::Json),
+        None => Ok(None),
+    }
+}
+
+pub(crate) fn to_json<T, E>(value: &T) -> Result<Vec<u8>, StorageError<E>>
+where
+    T: serde::Serialize + ?Sized,
+    E: Error,
+{
+    serde_json::to_vec(value).map_err(StorageError::Json)
+}
+
+impl<T: Storage> StateStore for T {
+    type Error = StorageError<T::Error>;
+
+    fn get(&self, key: &ConversationKey) -> Result<Option<StoredState>, Self::Error> {
+        get_json(self, &state_key(key))
+    }
+
+    fn put(&self, key: &ConversationKey, state: &StoredState) -> Result<(), Self::Error> {
+        let value = to_json(state)?;
+        self.set(&state_key(key), &value)
+            .map_err(StorageError::Storage)
+    }
+
+    fn remove(&self, key: &ConversationKey) -> Result<(), Self::Error> {
+        Storage::remove(self, &state_key(key)).map_err(StorageError::Storage)
+    }
+}
+
+fn state_key(key: &ConversationKey) -> String {
+    format!(
+        "conversation_state/{}/{}/{}",
+        key.user,
+        key.channel,
+        key.thread_ts.as_deref().unwrap_or("")
+    )
+}
+
+impl<T: Storage> OutboxStore for T {
+    type Error = StorageError<T::Error>;
+
+    fn insert(&self, entry: &OutboxEntry) -> Result<bool, Self::Error> {
+        let value = to_json(entry)?;
+        Storage::insert(self, &format!("outbox/{}", entry.key), &value)
+            .map_err(StorageError::Storage)
+    }
+
+    fn pending(&self) -> Result<Vec<OutboxEntry>, Self::Error> {
+        let mut entries = Vec::new();
+        for key in self.keys("outbox/").map_err(StorageError::Storage)? {
+            // An entry removed since listing the keys has been sent already.
+            if let Some(entry) = get_json::<_, OutboxEntry>(self, &key)? {
+                entries.push(entry);
+            }
+        }
+        entries.sort_by(|a, b| (a.enqueued_at, &a.key).cmp(&(b.enqueued_at, &b.key)));
+        Ok(entries)
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Self::Error> {
+        Storage::remove(self, &format!("outbox/{}", key)).map_err(StorageError::Storage)
+    }
+}
+
+fn installation_key(enterprise_id: Option<&str>, team_id: Option<&str>) -> String {
+    format!(
+        "installations/{}/{}",
+        enterprise_id.unwrap_or(""),
+        team_id.unwrap_or("")
+    )
+}
+
+/// Finds an installation the way `MemoryInstallationStore::get` does, for the `InstallationStore`
+/// of every [`Storage`].
+pub(crate) fn find_installation<S: Storage + ?Sized>(
+    storage: &S,
+    query: &InstallationQuery<'_>,
+) -> Result<Option<Installation>, StorageError<S::Error>> {
+    let mut keys = vec![installation_key(query.enterprise_id, query.team_id)];
+    if query.enterprise_id.is_some() {
+        keys.push(installation_key(query.enterprise_id, None));
+    }
+    if query.team_id.is_some() {
+        keys.push(installation_key(None, query.team_id));
+    }
+    for key in keys {
+        if let Some(installation) = get_json(storage, &key)? {
+            return Ok(Some(installation));
+        }
+    }
+    Ok(None)
+}
+
+pub(crate) fn save_installation<S: Storage + ?Sized>(
+    storage: &S,
+    installation: &Installation,
+) -> Result<(), StorageError<S::Error>> {
+    let team_id = if installation.is_enterprise_install {
+        None
+    } else {
+        installation.team_id.as_deref()
+    };
+    let key = installation_key(installation.enterprise_id.as_deref(), team_id);
+    storage
+        .set(&key, &to_json(installation)?)
+        .map_err(StorageError::Storage)
+}
+
+pub(crate) fn delete_installation<S: Storage + ?Sized>(
+    storage: &S,
+    query: &InstallationQuery<'_>,
+) -> Result<(), StorageError<S::Error>> {
+    storage
+        .remove(&installation_key(query.enterprise_id, query.team_id))
+        .map_err(StorageError::Storage)
+}
+
+/// Keeps values in memory. They're lost when the process exits, so this is mostly useful in tests.
+#[derive(Debug, Default)]
+pub struct MemoryStorage {
+    values: Mutex<BTreeMap<String, Vec<u8>>>,
+}
+
+impl MemoryStorage {
+    pub fn new() -> Self {
+        MemoryStorage::default()
+    }
+}
+
+impl Storage for MemoryStorage {
+    type Error = Infallible;
+
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+        Ok(self.values.lock().unwrap().get(key).cloned())
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), Self::Error> {
+        self.values
+            .lock()
+            .unwrap()
+            .insert(key.to_owned(), value.to_owned());
+        Ok(())
+    }
+
+    fn insert(&self, key: &str, value: &[u8]) -> Result<bool, Self::Error> {
+        let mut values = self.values.lock().unwrap();
+        if values.contains_key(key) {
+            return Ok(false);
+        }
+        values.insert(key.to_owned(), value.to_owned());
+        Ok(true)
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Self::Error> {
+        self.values.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    fn keys(&self, prefix: &str) -> Result<Vec<String>, Self::Error> {
+        let values = self.values.lock().unwrap();
+        Ok(values
+            .range(prefix.to_owned()..)
+            .map(|(key, _)| key)
+            .take_while(|key| key.starts_with(prefix))
+            .cloned()
+            .collect())
+    }
+}
+
+/// Keeps each value in a file in a directory, so values survive a restart.
+///
+/// Files are written to a temporary name and moved into place, so a crash never leaves a partly
+/// written value behind.
+#[derive(Clone, Debug)]
+pub struct FileStorage {
+    dir: PathBuf,
+}
+
+impl FileStorage {
+    /// Stores values in `dir`, creating it if needed.
+    pub fn new<P: Into<PathBuf>>(dir: P) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(FileStorage { dir })
+    }
+
+    fn path(&self, key: &str) -> PathBuf {
+        // Hex keeps any key a valid file name.
+        let name: String = key.bytes().map(|b| format!("{:02x}", b)).collect();
+        self.dir.join(name)
+    }
+
+    /// Writes `value` to a temporary file next to where it's stored.
+    fn write_tmp(&self, key: &str, value: &[u8]) -> io::Result<PathBuf> {
+        let tmp = self.path(key).with_extension("tmp");
+        fs::write(&tmp, value)?;
+        Ok(tmp)
+    }
+}
+
+impl Storage for FileStorage {
+    type Error = io::Error;
+
+    fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+        match fs::read(self.path(key)) {
+            Ok(value) => Ok(Some(value)),
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn set(&self, key: &str, value: &[u8]) -> Result<(), Self::Error> {
+        let tmp = self.write_tmp(key, value)?;
+        fs::rename(tmp, self.path(key))
+    }
+
+    fn insert(&self, key: &str, value: &[u8]) -> Result<bool, Self::Error> {
+        let tmp = self.write_tmp(key, value)?;
+        // Unlike renaming, linking fails if the file is already there.
+        let linked = match fs::hard_link(&tmp, self.path(key)) {
+            Ok(()) => Ok(true),
+            Err(ref e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+            Err(e) => Err(e),
+        };
+        fs::remove_file(tmp)?;
+        linked
+    }
+
+    fn remove(&self, key: &str) -> Result<(), Self::Error> {
+        match fs::remove_file(self.path(key)) {
+            Err(ref e) if e.kind() == io::ErrorKind::NotFound => Ok(()),
+            result => result,
+        }
+    }
+
+    fn keys(&self, prefix: &str) -> Result<Vec<String>, Self::Error> {
+        let mut keys = Vec::new();
+        for file in fs::read_dir(&self.dir)? {
+            let path = file?.path();
+            if path.extension().is_some() {
+                continue;
+            }
+            let key = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(decode_hex)
+                .and_then(|key| String::from_utf8(key).ok());
+            if let Some(key) = key.filter(|key| key.starts_with(prefix)) {
+                keys.push(key);
+            }
+        }
+        keys.sort();
+        Ok(keys)
+    }
+}
+
+fn decode_hex(name: &str) -> Option<Vec<u8>> {
+    (0..name.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(name.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(feature = "redis")]
+pub use self::redis_support::RedisStorage;
+
+#[cfg(feature = "redis")]
+mod redis_support {
+    use super::Storage;
+    use redis::Commands;
+    use std::sync::Mutex;
+
+    /// Keeps values in Redis, under a prefix so several apps can share a database.
+    pub struct RedisStorage {
+        connection: Mutex<redis::Connection>,
+        prefix: String,
+    }
+
+    impl RedisStorage {
+        /// Connects to the Redis server at `url`, such as `redis://127.0.0.1/`, keeping values
+        /// under keys starting with `prefix`.
+        pub fn connect(url: &str, prefix: &str) -> redis::RedisResult<Self> {
+            let connection = redis::Client::open(url)?.get_connection()?;
+            Ok(RedisStorage::new(connection, prefix))
+        }
+
+        pub fn new(connection: redis::Connection, prefix: &str) -> Self {
+            RedisStorage {
+                connection: Mutex::new(connection),
+                prefix: prefix.to_owned(),
+            }
+        }
+
+        fn key(&self, key: &str) -> String {
+            format!("{}{}", self.prefix, key)
+        }
+
+        fn connection(&self) -> std::sync::MutexGuard<'_, redis::Connection> {
+            self.connection.lock().unwrap_or_else(|e| e.into_inner())
+        }
+    }
+
+    impl Storage for RedisStorage {
+        type Error = redis::RedisError;
+
+        fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+            self.connection().get(self.key(key))
+        }
+
+        fn set(&self, key: &str, value: &[u8]) -> Result<(), Self::Error> {
+            self.connection().set(self.key(key), value)
+        }
+
+        fn insert(&self, key: &str, value: &[u8]) -> Result<bool, Self::Error> {
+            self.connection().set_nx(self.key(key), value)
+        }
+
+        fn remove(&self, key: &str) -> Result<(), Self::Error> {
+            self.connection().del(self.key(key))
+        }
+
+        fn keys(&self, prefix: &str) -> Result<Vec<String>, Self::Error> {
+            // Glob characters in the prefix are escaped so they match themselves.
+            let pattern = self
+                .key(prefix)
+                .chars()
+                .fold(String::new(), |mut pattern, c| {
+                    if "*?[]\\".contains(c) {
+                        pattern.push('\\');
+                    }
+                    pattern.push(c);
+                    pattern
+                });
+            let mut connection = self.connection();
+            let mut keys = connection
+                .scan_match::<_, String>(pattern + "*")?
+                .map(|key| key[self.prefix.len()..].to_owned())
+                .collect::<Vec<_>>();
+            keys.sort();
+            keys.dedup();
+            Ok(keys)
+        }
+    }
+}
+
+#[cfg(feature = "sled")]
+pub use self::sled_support::SledStorage;
+
+#[cfg(feature = "sled")]
+mod sled_support {
+    use super::Storage;
+
+    /// Keeps values in a sled tree, an embedded database kept on disk.
+    #[derive(Clone, Debug)]
+    pub struct SledStorage {
+        tree: sled::Tree,
+    }
+
+    impl SledStorage {
+        /// Keeps values in `tree`, such as a `sled::Db` or one of its trees.
+        pub fn new<T: Into<sled::Tree>>(tree: T) -> Self {
+            SledStorage { tree: tree.into() }
+        }
+    }
+
+    impl Storage for SledStorage {
+        type Error = sled::Error;
+
+        fn get(&self, key: &str) -> Result<Option<Vec<u8>>, Self::Error> {
+            Ok(self.tree.get(key)?.map(|value| value.to_vec()))
+        }
+
+        fn set(&self, key: &str, value: &[u8]) -> Result<(), Self::Error> {
+            self.tree.insert(key, value).map(drop)
+        }
+
+        fn insert(&self, key: &str, value: &[u8]) -> Result<bool, Self::Error> {
+            let swapped = self
+                .tree
+                .compare_and_swap(key, None::<&[u8]>, Some(value))?;
+            Ok(swapped.is_ok())
+        }
+
+        fn remove(&self, key: &str) -> Result<(), Self::Error> {
+            self.tree.remove(key).map(drop)
+        }
+
+        fn keys(&self, prefix: &str) -> Result<Vec<String>, Self::Error> {
+            self.tree
+                .scan_prefix(prefix)
+                .keys()
+                .map(|key| Ok(String::from_utf8_lossy(&key?).into_owned()))
+                .collect()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_storage<S: Storage>(storage: &S) {
+        assert_eq!(storage.get("a/1").unwrap(), None);
+        storage.set("a/1", b"one").unwrap();
+        storage.set("a/2", b"two").unwrap();
+        storage.set("b/1", b"other").unwrap();
+        assert_eq!(storage.get("a/1").unwrap().as_deref(), Some(&b"one"[..]));
+        assert!(!storage.insert("a/2", b"again").unwrap());
+        assert!(storage.insert("a/3", b"three").unwrap());
+        assert_eq!(storage.get("a/2").unwrap().as_deref(), Some(&b"two"[..]));
+        assert_eq!(storage.keys("a/").unwrap(), vec!["a/1", "a/2", "a/3"]);
+        storage.remove("a/1").unwrap();
+        storage.remove("a/1").unwrap();
+        assert_eq!(storage.keys("a").unwrap(), vec!["a/2", "a/3"]);
+    }
+
+    #[test]
+    fn test_memory_storage() {
+        check_storage(&MemoryStorage::new());
+    }
+
+    #[test]
+    fn test_file_storage() {
+        let dir = std::env::temp_dir().join(format!("slack-storage-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        check_storage(&FileStorage::new(&dir).unwrap());
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_stores_share_a_storage() {
+        use crate::conversation_state::ConversationState;
+
+        let storage = MemoryStorage::new();
+        let states = ConversationState::<u32, _>::new(&storage);
+        let key = ConversationKey::new("U1", "D1");
+        states.set(&key, &1).unwrap();
+        assert_eq!(states.get(&key).unwrap(), Some(1));
+
+        let entry = |key: &str, enqueued_at| OutboxEntry {
+            key: key.to_owned(),
+            method: "chat.postMessage".to_owned(),
+            params: vec![],
+            enqueued_at,
+        };
+        assert!(OutboxStore::insert(&storage, &entry("b", 1)).unwrap());
+        assert!(OutboxStore::insert(&storage, &entry("a", 2)).unwrap());
+        assert!(!OutboxStore::insert(&storage, &entry("b", 3)).unwrap());
+        let pending: Vec<_> = storage.pending().unwrap();
+        assert_eq!(pending, vec![entry("b", 1), entry("a", 2)]);
+
+        assert_eq!(
+            Storage::keys(&storage, "").unwrap(),
+            vec!["conversation_state/U1/D1/", "outbox/a", "outbox/b"]
+        );
+    }
+
+    #[test]
+    fn test_installations_fall_back_to_organization() {
+        let storage = MemoryStorage::new();
+        save_installation(
+            &storage,
+            &Installation {
+                enterprise_id: Some("E1".into()),
+                team_id: Some("T1".into()),
+                is_enterprise_install: true,
+                bot_token: Some("xoxb-org".into()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let query = InstallationQuery {
+            enterprise_id: Some("E1"),
+            team_id: Some("T2"),
+        };
+        let found = find_installation(&storage, &query).unwrap().unwrap();
+        assert_eq!(found.bot_token.as_deref(), Some("xoxb-org"));
+
+        delete_installation(&storage, &query).unwrap();
+        assert!(find_installation(&storage, &query).unwrap().is_some());
+        let org = InstallationQuery {
+            enterprise_id: Some("E1"),
+            team_id: None,
+        };
+        delete_installation(&storage, &org).unwrap();
+        assert!(find_installation(&storage, &query).unwrap().is_none());
+    }
+
+    #[cfg(feature = "sled")]
+    #[test]
+    fn test_sled_storage() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        check_storage(&SledStorage::new(db.open_tree("slack").unwrap()));
+    }
+}
@@ -0,0 +1,48 @@
+//! Statistics about the activity in channels, such as for internal dashboards.
+
+pub use crate::analytics_types::*;
+
+use std::ops::Range;
+
+use super::conversations;
+use super::requests::SlackWebRequestSender;
+use crate::threads_types::message_ts;
+use crate::Timestamp;
+
+/// Computes statistics about the messages posted in a channel between the start of `range` and,
+/// not including, its end, walking every page of the channel's history.
+pub fn channel_activity<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    range: Range<Timestamp>,
+) -> Result<ChannelActivity, conversations::HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut activity = ChannelActivity::new(channel);
+    let mut cursor = None;
+    loop {
+        let page = conversations::history(
+            client,
+            token,
+            &conversations::HistoryRequest {
+                channel,
+                cursor: cursor.as_ref(),
+                oldest: Some(range.start),
+                latest: Some(range.end),
+                inclusive: Some(true),
+                limit: Some(200),
+            },
+        )?;
+        for message in page.messages.iter().flatten() {
+            if message_ts(message) != Some(range.end) {
+                activity.add(message);
+            }
+        }
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(activity);
+        }
+    }
+}
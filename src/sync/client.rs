@@ -0,0 +1,146 @@
+//! A request sender bundled with the token it authenticates with.
+
+use crate::custom_method::{check_ok, parse_response, to_params};
+use crate::mod_types::auth_types::TestError;
+use crate::response_buffer::ResponseBuffer;
+use crate::sync::requests::{SlackWebRequestSender, SlackWebStreamSender};
+use crate::sync::streaming::{parse_stream, StreamError, StreamedPage};
+use crate::{CallMethodError, Identity};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::sync::{Arc, OnceLock};
+
+/// Pairs a [`SlackWebRequestSender`](crate::sync::requests::SlackWebRequestSender) with the token to
+/// send requests with.
+///
+/// The generated API functions take the sender and token separately; use [`sender()`] and
+/// [`token()`] to pass them along.
+///
+/// [`sender()`]: #method.sender
+/// [`token()`]: #method.token
+#[derive(Clone, Debug)]
+pub struct SlackClient<R> {
+    sender: R,
+    token: String,
+    /// Shared by clones, so `auth.test` is called once for all of them.
+    identity: Arc<OnceLock<Identity>>,
+}
+
+impl<R> SlackClient<R> {
+    pub fn new<T: Into<String>>(sender: R, token: T) -> Self {
+        SlackClient {
+            sender,
+            token: token.into(),
+            identity: Arc::default(),
+        }
+    }
+
+    pub fn sender(&self) -> &R {
+        &self.sender
+    }
+
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// Who the token belongs to, once [`whoami()`](#method.whoami) has looked it up.
+    pub fn identity(&self) -> Option<&Identity> {
+        self.identity.get()
+    }
+
+    /// The token's user ID, such as the bot user's, once [`whoami()`](#method.whoami) has looked it
+    /// up.
+    pub fn self_user_id(&self) -> Option<&str> {
+        self.identity().map(|identity| identity.user_id.as_str())
+    }
+
+    /// The token's team ID, once [`whoami()`](#method.whoami) has looked it up.
+    pub fn team_id(&self) -> Option<&str> {
+        self.identity().map(|identity| identity.team_id.as_str())
+    }
+}
+
+impl<R: SlackWebRequestSender> SlackClient<R> {
+    /// Looks up who the token belongs to with `auth.test`, caching the answer for this client and
+    /// its clones, so later calls and [`self_user_id()`](#method.self_user_id) don't send a
+    /// request.
+    pub fn whoami(&self) -> Result<&Identity, TestError<R::Error>> {
+        if let Some(identity) = self.identity.get() {
+            return Ok(identity);
+        }
+        let response = crate::sync::auth::test(&self.sender, &self.token)?;
+        Ok(self.identity.get_or_init(|| response.into()))
+    }
+
+    /// Calls a Web API method this crate doesn't wrap, such as an internal or undocumented one.
+    ///
+    /// The fields of `request` are sent as parameters alongside the token: `None` fields are left
+    /// out, booleans are sent as `1`/`0`, and nested values as JSON. The response is checked for
+    /// `ok` before being deserialized as `Resp`.
+    pub fn call_method<Req, Resp>(
+        &self,
+        method: &str,
+        request: &Req,
+    ) -> Result<Resp, CallMethodError<R::Error>>
+    where
+        Req: Serialize + ?Sized,
+        Resp: DeserializeOwned,
+    {
+        let mut params = to_params(request).map_err(CallMethodError::InvalidRequest)?;
+        params.push(("token".to_owned(), self.token.clone()));
+        let url = crate::get_slack_url_for_method(method);
+        let mut body = ResponseBuffer::take();
+        self.sender
+            .send_into(&url, &params[..], &mut body)
+            .map_err(CallMethodError::Client)?;
+        parse_response(&body)
+    }
+
+    /// Like [`call_method()`](#method.call_method), but returns the response body once `ok` is
+    /// checked, for parsing into the borrowed types in [`crate::borrowed`].
+    pub fn call_method_raw<Req>(
+        &self,
+        method: &str,
+        request: &Req,
+    ) -> Result<String, CallMethodError<R::Error>>
+    where
+        Req: Serialize + ?Sized,
+    {
+        let mut params = to_params(request).map_err(CallMethodError::InvalidRequest)?;
+        params.push(("token".to_owned(), self.token.clone()));
+        let url = crate::get_slack_url_for_method(method);
+        self.sender
+            .send(&url, &params[..])
+            .map_err(CallMethodError::Client)
+            .and_then(check_ok)
+    }
+}
+
+impl<R: SlackWebStreamSender> SlackClient<R> {
+    /// Calls a method returning a list, handing each item of the list in `field` to `on_item` as
+    /// it's decoded rather than buffering the whole response.
+    ///
+    /// `request` is sent as in [`call_method()`](#method.call_method). Use the returned page's
+    /// `response_metadata` to fetch the next page.
+    pub fn stream_method<Req, T, F>(
+        &self,
+        method: &str,
+        request: &Req,
+        field: &str,
+        on_item: F,
+    ) -> Result<StreamedPage, StreamError<R::Error>>
+    where
+        Req: Serialize + ?Sized,
+        T: DeserializeOwned,
+        F: FnMut(T),
+    {
+        let mut params = to_params(request).map_err(StreamError::InvalidRequest)?;
+        params.push(("token".to_owned(), self.token.clone()));
+        let url = crate::get_slack_url_for_method(method);
+        let body = self
+            .sender
+            .send_streaming(&url, &params[..])
+            .map_err(StreamError::Client)?;
+        parse_stream(body, field, on_item)
+    }
+}
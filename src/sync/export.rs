@@ -0,0 +1,260 @@
+//! Archives a workspace into a directory of JSON Lines files.
+//!
+//! An export holds `users.jsonl`, `conversations.jsonl`, `emoji.jsonl` and `files.jsonl` (file
+//! metadata, not their contents), and a file of messages for each channel in [`MESSAGES_DIR`],
+//! where each thread's replies follow the message they reply to. Each line is a record as Slack
+//! returned it.
+
+pub use crate::export_types::*;
+
+use std::fs;
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::requests::{RateLimitRetry, SlackWebRequestSender};
+use super::SlackClient;
+use crate::export_types::{
+    is_not_in_channel, next_cursor, read_ids, take_records, thread_ts, Checkpoint, JsonLines,
+    PageRequest, Part, CONVERSATIONS, EMOJI, FILES, MESSAGE_COLUMNS, USERS,
+};
+use crate::rate_limit::RetryPolicy;
+use crate::CallMethodError;
+
+/// Exports the users, conversations, messages, file metadata and custom emoji of a workspace into
+/// `request.dir`.
+///
+/// Requests are paced by the request's `interval`, and rate limited requests are retried as the
+/// request's `retry_policy` allows. Each finished file is recorded in a checkpoint, so exporting into the same directory
+/// again after an error only fetches what's missing.
+pub fn export<R>(
+    client: &SlackClient<R>,
+    request: &ExportRequest<'_>,
+) -> Result<ExportSummary, ExportError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+{
+    let dir = request.dir;
+    fs::create_dir_all(dir.join(MESSAGES_DIR))?;
+    let mut checkpoint = Checkpoint::load(dir)?;
+    let interval = request.interval.unwrap_or(DEFAULT_EXPORT_INTERVAL);
+    let mut pacer = Pacer::new(client, interval, request.retry_policy.clone());
+    let mut summary = ExportSummary::default();
+
+    if !checkpoint.is_done(&USERS) {
+        let page = PageRequest {
+            limit: Some(200),
+            ..Default::default()
+        };
+        summary.users = pacer.export_part(request, &USERS, "users.list", page, "members")?;
+        checkpoint.done(dir, &USERS)?;
+    }
+
+    if !checkpoint.is_done(&CONVERSATIONS) {
+        let page = PageRequest {
+            types: request.types,
+            limit: Some(200),
+            ..Default::default()
+        };
+        summary.conversations = pacer.export_part(
+            request,
+            &CONVERSATIONS,
+            "conversations.list",
+            page,
+            "channels",
+        )?;
+        checkpoint.done(dir, &CONVERSATIONS)?;
+    }
+
+    if !checkpoint.is_done(&EMOJI) {
+        let mut out = JsonLines::create(dir, EMOJI.name, EMOJI.columns)?;
+        let mut page = pacer.call("emoji.list", &PageRequest::default())?;
+        if let Value::Object(emoji) = page["emoji"].take() {
+            for (name, url) in emoji {
+                out.write(&json!({ "name": name, "url": url }))?;
+            }
+        }
+        summary.emoji = out.finish(request)?;
+        checkpoint.done(dir, &EMOJI)?;
+    }
+
+    if !checkpoint.is_done(&FILES) {
+        let mut out = JsonLines::create(dir, FILES.name, FILES.columns)?;
+        let mut number = 1;
+        loop {
+            let request = PageRequest {
+                page: Some(number),
+                count: Some(200),
+                ..Default::default()
+            };
+            let mut page = pacer.call("files.list", &request)?;
+            for file in take_records(&mut page, "files") {
+                out.write(&file)?;
+            }
+            let pages = page["paging"]["pages"].as_u64().unwrap_or(0);
+            if u64::from(number) >= pages {
+                break;
+            }
+            number += 1;
+        }
+        summary.files = out.finish(request)?;
+        checkpoint.done(dir, &FILES)?;
+    }
+
+    let oldest = request.oldest.map(|ts| ts.to_param_value());
+    for channel in read_ids(dir, &CONVERSATIONS)? {
+        if checkpoint.is_channel_done(&channel) {
+            continue;
+        }
+        let name = format!("{}/{}", MESSAGES_DIR, channel);
+        let mut out = JsonLines::create(dir, &name, MESSAGE_COLUMNS)?;
+        match pacer.export_messages(&mut out, &channel, oldest.as_deref()) {
+            Ok(()) => {}
+            Err(ref e) if is_not_in_channel(e) => {
+                drop(out);
+                fs::remove_file(dir.join(format!("{}.jsonl", name)))?;
+                summary.skipped_channels.push(channel);
+                continue;
+            }
+            Err(e) => return Err(e),
+        }
+        summary.messages += out.finish(request)?;
+        checkpoint.channel_done(dir, &channel)?;
+    }
+
+    Ok(summary)
+}
+
+/// Spaces out the requests of an export or import and retries those which were rate limited.
+pub(crate) struct Pacer<'a, R> {
+    client: SlackClient<RateLimitRetry<&'a R>>,
+    interval: Duration,
+    sent: bool,
+}
+
+impl<'a, R: SlackWebRequestSender + Sync> Pacer<'a, R> {
+    pub(crate) fn new(
+        client: &'a SlackClient<R>,
+        interval: Duration,
+        policy: Option<RetryPolicy>,
+    ) -> Self {
+        let sender = RateLimitRetry::new(client.sender()).policy(policy.unwrap_or_default());
+        Pacer {
+            client: SlackClient::new(sender, client.token()),
+            interval,
+            sent: false,
+        }
+    }
+
+    pub(crate) fn send<Req>(
+        &mut self,
+        method: &str,
+        request: &Req,
+    ) -> Result<Value, CallMethodError<R::Error>>
+    where
+        Req: Serialize + ?Sized,
+    {
+        if self.sent {
+            thread::sleep(self.interval);
+        }
+        self.sent = true;
+        self.client.call_method(method, request)
+    }
+
+    fn call(
+        &mut self,
+        method: &'static str,
+        request: &PageRequest<'_>,
+    ) -> Result<Value, ExportError<R::Error>> {
+        self.send(method, request)
+            .map_err(|error| ExportError::Slack { method, error })
+    }
+
+    /// Writes the `key` records of every page of a cursor paginated method to `part`'s file.
+    fn export_part(
+        &mut self,
+        request: &ExportRequest<'_>,
+        part: &Part,
+        method: &'static str,
+        first: PageRequest<'_>,
+        key: &str,
+    ) -> Result<usize, ExportError<R::Error>> {
+        let mut out = JsonLines::create(request.dir, part.name, part.columns)?;
+        let mut cursor = None;
+        loop {
+            let page_request = PageRequest {
+                cursor: cursor.as_deref(),
+                ..first
+            };
+            let mut page = self.call(method, &page_request)?;
+            for record in take_records(&mut page, key) {
+                out.write(&record)?;
+            }
+            cursor = next_cursor(&page);
+            if cursor.is_none() {
+                return out.finish(request);
+            }
+        }
+    }
+
+    /// Writes a channel's messages, each followed by its thread's replies.
+    fn export_messages(
+        &mut self,
+        out: &mut JsonLines,
+        channel: &str,
+        oldest: Option<&str>,
+    ) -> Result<(), ExportError<R::Error>> {
+        let mut cursor = None;
+        loop {
+            let request = PageRequest {
+                channel: Some(channel),
+                oldest,
+                cursor: cursor.as_deref(),
+                limit: Some(200),
+                ..Default::default()
+            };
+            let mut page = self.call("conversations.history", &request)?;
+            for message in take_records(&mut page, "messages") {
+                out.write(&message)?;
+                if let Some(ts) = thread_ts(&message) {
+                    self.export_replies(out, channel, ts)?;
+                }
+            }
+            cursor = next_cursor(&page);
+            if cursor.is_none() {
+                return Ok(());
+            }
+        }
+    }
+
+    fn export_replies(
+        &mut self,
+        out: &mut JsonLines,
+        channel: &str,
+        ts: &str,
+    ) -> Result<(), ExportError<R::Error>> {
+        let mut cursor = None;
+        loop {
+            let request = PageRequest {
+                channel: Some(channel),
+                ts: Some(ts),
+                cursor: cursor.as_deref(),
+                limit: Some(200),
+                ..Default::default()
+            };
+            let mut page = self.call("conversations.replies", &request)?;
+            for reply in take_records(&mut page, "messages") {
+                // Every page starts with the parent message, which is already written.
+                if reply["ts"].as_str() != Some(ts) {
+                    out.write(&reply)?;
+                }
+            }
+            cursor = next_cursor(&page);
+            if cursor.is_none() {
+                return Ok(());
+            }
+        }
+    }
+}
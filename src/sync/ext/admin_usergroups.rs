@@ -0,0 +1,70 @@
+pub use crate::ext_types::admin_usergroups::*;
+
+use crate::mod_types::admin_usergroups_types::{
+    AddChannelsRequest, ListChannelsRequest, RemoveChannelsRequest,
+};
+use crate::sync::admin_usergroups::{add_channels, list_channels, remove_channels};
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Makes `desired` the default channels of a User Group, returning which channels were added and
+/// removed.
+///
+/// Channels are only added or removed when they differ, so an IdP sync can call this for every
+/// group on each run. `team_id` picks the workspace when the group is in more than one.
+pub fn sync_default_channels<R>(
+    client: &R,
+    token: &str,
+    usergroup_id: &str,
+    team_id: Option<&str>,
+    desired: &[&str],
+) -> Result<MembershipChange, SyncDefaultChannelsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let current = list_channels(
+        client,
+        token,
+        &ListChannelsRequest {
+            usergroup_id,
+            team_id,
+            include_num_members: None,
+        },
+    )
+    .map_err(SyncDefaultChannelsError::List)?
+    .channels
+    .into_iter()
+    .flatten()
+    .filter_map(|channel| channel.id)
+    .collect::<Vec<_>>();
+    let change = MembershipChange::diff(&current, desired);
+    if !change.added.is_empty() {
+        let channel_ids = change.added.iter().map(String::as_str).collect::<Vec<_>>();
+        add_channels(
+            client,
+            token,
+            &AddChannelsRequest {
+                channel_ids: &channel_ids,
+                usergroup_id,
+                team_id,
+            },
+        )
+        .map_err(SyncDefaultChannelsError::Add)?;
+    }
+    if !change.removed.is_empty() {
+        let channel_ids = change
+            .removed
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<_>>();
+        remove_channels(
+            client,
+            token,
+            &RemoveChannelsRequest {
+                channel_ids: &channel_ids,
+                usergroup_id,
+            },
+        )
+        .map_err(SyncDefaultChannelsError::Remove)?;
+    }
+    Ok(change)
+}
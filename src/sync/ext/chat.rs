@@ -0,0 +1,117 @@
+pub use crate::ext_types::chat::*;
+pub use crate::sync::history::{
+    delete_range, DeleteRangeError, DeleteRangeRequest, DEFAULT_DELETE_INTERVAL,
+};
+
+use std::borrow::Borrow;
+
+use crate::ext_types::chat::form_body;
+use crate::ext_types::files::UploadProgress;
+use crate::mod_types::chat_types::{
+    PostEphemeralError, PostEphemeralRequest, PostMessageError, PostMessageRequest,
+    PostMessageResponse,
+};
+use crate::mod_types::conversations_types::OpenRequest;
+use crate::sync::chat::{post_ephemeral, post_message};
+use crate::sync::conversations::open;
+use crate::sync::requests::{SlackWebRequestSender, SlackWebUploadSender};
+
+/// Posts an ephemeral message to `request.user` in `request.channel`, or sends it to them as a
+/// direct message if they aren't in the channel.
+///
+/// The direct message isn't threaded, since `thread_ts` belongs to the channel.
+pub fn post_ephemeral_or_dm<R>(
+    client: &R,
+    token: &str,
+    request: &PostEphemeralRequest<'_>,
+) -> Result<EphemeralDelivery, PostEphemeralOrDmError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    match post_ephemeral(client, token, request) {
+        Ok(response) => return Ok(EphemeralDelivery::Ephemeral(response)),
+        Err(PostEphemeralError::UserNotInChannel) => {}
+        Err(e) => return Err(PostEphemeralOrDmError::Ephemeral(e)),
+    }
+    let im = open(
+        client,
+        token,
+        &OpenRequest {
+            users: Some(request.user),
+            ..OpenRequest::default()
+        },
+    )
+    .map_err(PostEphemeralOrDmError::Open)?
+    .channel
+    .and_then(|c| c.id)
+    // Slack also takes the user's ID as the channel of their direct message with the app.
+    .unwrap_or_else(|| request.user.to_owned());
+    post_message(
+        client,
+        token,
+        &PostMessageRequest {
+            channel: &im,
+            text: request.text,
+            parse: request.parse,
+            link_names: request.link_names,
+            attachments: request.attachments,
+            blocks: request.blocks,
+            username: request.username,
+            as_user: request.as_user,
+            icon_url: request.icon_url,
+            icon_emoji: request.icon_emoji,
+            ..PostMessageRequest::default()
+        },
+    )
+    .map(|response| EphemeralDelivery::Direct(Box::new(response)))
+    .map_err(PostEphemeralOrDmError::Direct)
+}
+
+/// Posts a message like `chat::post_message`, but sends it as a POST body in chunks, calling
+/// `on_progress` as they're sent, for messages large enough to show the progress of.
+pub fn post_message_with_progress<R>(
+    client: &R,
+    token: &str,
+    request: &PostMessageRequest<'_>,
+    on_progress: &dyn Fn(UploadProgress),
+) -> Result<PostMessageResponse, PostMessageError<R::Error>>
+where
+    R: SlackWebUploadSender,
+{
+    let client = FormSender {
+        sender: client,
+        on_progress,
+    };
+    post_message(&client, token, request)
+}
+
+/// Sends the parameters of a call as a form body with `upload`, rather than in the query string.
+struct FormSender<'a, R> {
+    sender: &'a R,
+    on_progress: &'a dyn Fn(UploadProgress),
+}
+
+impl<R> SlackWebRequestSender for FormSender<'_, R>
+where
+    R: SlackWebUploadSender,
+{
+    type Error = R::Error;
+
+    fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let body = form_body(params);
+        self.sender.upload(
+            method_url.as_ref(),
+            "application/x-www-form-urlencoded",
+            body.len() as u64,
+            body.as_bytes(),
+            self.on_progress,
+        )
+    }
+}
@@ -0,0 +1,187 @@
+pub use crate::ext_types::conversations::*;
+
+use std::collections::HashSet;
+use std::slice;
+
+use crate::ext_types::conversations::{is_user_invite_error, is_user_kick_error, MAX_INVITE_USERS};
+use crate::mod_types::bookmarks_types::{
+    ListError as BookmarksListError, ListRequest as BookmarksListRequest,
+};
+use crate::mod_types::conversations_types::{
+    InfoError, InfoRequest, InviteError, InviteRequest, KickError, KickRequest, MembersError,
+    MembersRequest,
+};
+use crate::sync::bookmarks;
+use crate::sync::conversations::{info, invite, kick, members};
+use crate::sync::requests::SlackWebRequestSender;
+use crate::UserId;
+
+/// Invites `users` to `channel`, carrying on past users who can't be invited.
+///
+/// Users are invited up to 1000 per call. Slack reports only one failure per call, so a call that
+/// fails because of a user is retried one user at a time to find out who can't be invited. An
+/// error that isn't about a user, such as `ChannelNotFound`, stops the batch.
+pub fn invite_many<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    users: &[UserId],
+) -> MembershipReport<InviteError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut report = MembershipReport::default();
+    for chunk in users.chunks(MAX_INVITE_USERS) {
+        if report.aborted.is_some() {
+            report.skip(chunk);
+            continue;
+        }
+        match invite_users(client, token, channel, chunk) {
+            Err(ref e) if chunk.len() > 1 && is_user_invite_error(e) => {
+                for user in chunk {
+                    let user = slice::from_ref(user);
+                    if report.aborted.is_some() {
+                        report.skip(user);
+                        continue;
+                    }
+                    let result = invite_users(client, token, channel, user);
+                    report.record(user, result, is_user_invite_error);
+                }
+            }
+            result => report.record(chunk, result, is_user_invite_error),
+        }
+    }
+    report
+}
+
+/// Removes `users` from `channel` one at a time, carrying on past users who can't be removed.
+///
+/// An error that isn't about a user, such as `ChannelNotFound`, stops the batch.
+pub fn kick_many<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    users: &[UserId],
+) -> MembershipReport<KickError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut report = MembershipReport::default();
+    for user in users {
+        let user = slice::from_ref(user);
+        if report.aborted.is_some() {
+            report.skip(user);
+            continue;
+        }
+        let result = kick(
+            client,
+            token,
+            &KickRequest {
+                channel,
+                user: user[0].as_str(),
+            },
+        )
+        .map(drop);
+        report.record(user, result, is_user_kick_error);
+    }
+    report
+}
+
+/// Fetches every member of `channel`, following `next_cursor` until the last page.
+///
+/// Compare the result with the members the channel should have using [`MembershipDiff`].
+pub fn members_all<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<HashSet<UserId>, MembersError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut users = HashSet::new();
+    let mut cursor = None;
+    loop {
+        let page = members(
+            client,
+            token,
+            &MembersRequest {
+                channel,
+                cursor: cursor.as_ref(),
+                limit: Some(1000),
+            },
+        )?;
+        users.extend(page.members.into_iter().flatten().map(UserId::new));
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(users);
+        }
+    }
+}
+
+/// Looks up the file ID of `channel`'s canvas, or `None` if it doesn't have one.
+pub fn canvas_id<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<Option<String>, InfoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let response = info(
+        client,
+        token,
+        &InfoRequest {
+            channel,
+            ..InfoRequest::default()
+        },
+    )?;
+    Ok(response
+        .channel
+        .as_ref()
+        .and_then(|channel| channel.canvas_id())
+        .map(str::to_owned))
+}
+
+/// Counts the bookmarks of `channel`.
+pub fn bookmark_count<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<usize, BookmarksListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let response = bookmarks::list(
+        client,
+        token,
+        &BookmarksListRequest {
+            channel_id: channel,
+        },
+    )?;
+    Ok(response.bookmarks.map_or(0, |bookmarks| bookmarks.len()))
+}
+
+fn invite_users<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    users: &[UserId],
+) -> Result<(), InviteError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    invite(
+        client,
+        token,
+        &InviteRequest {
+            channel,
+            users: &users
+                .iter()
+                .map(UserId::as_str)
+                .collect::<Vec<_>>()
+                .join(","),
+            force: None,
+        },
+    )
+    .map(drop)
+}
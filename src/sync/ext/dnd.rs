@@ -0,0 +1,69 @@
+pub use crate::ext_types::dnd::*;
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::ext_types::dnd::schedule_request;
+use crate::mod_types::chat_types::PostMessageRequest;
+use crate::mod_types::dnd_types::InfoRequest;
+use crate::sync::chat::{post_message, schedule_message};
+use crate::sync::dnd::info;
+use crate::sync::requests::SlackWebRequestSender;
+use crate::Timestamp;
+
+/// Sends `message` to `user`, unless they're in Do Not Disturb, in which case `policy` decides
+/// whether it waits until Do Not Disturb ends or goes to another channel.
+///
+/// If the message's `channel` is empty, it's sent to the user as a direct message from the app.
+pub fn send_respecting_dnd<R>(
+    client: &R,
+    token: &str,
+    user: &str,
+    message: &PostMessageRequest<'_>,
+    policy: DndPolicy<'_>,
+) -> Result<Delivery, SendRespectingDndError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let status = info(client, token, &InfoRequest { user: Some(user) })
+        .map_err(SendRespectingDndError::Info)?;
+    let channel = if message.channel.is_empty() {
+        user
+    } else {
+        message.channel
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0.0, |since| since.as_secs_f64());
+    let ends_at = match dnd_ends_at(&status, Timestamp::from(now)) {
+        Some(ends_at) => ends_at,
+        None => {
+            return post_message(
+                client,
+                token,
+                &PostMessageRequest {
+                    channel,
+                    ..message.clone()
+                },
+            )
+            .map(Delivery::Sent)
+            .map_err(SendRespectingDndError::Post)
+        }
+    };
+    match policy {
+        DndPolicy::Delay => {
+            schedule_message(client, token, &schedule_request(message, channel, ends_at))
+                .map(Delivery::Scheduled)
+                .map_err(SendRespectingDndError::Schedule)
+        }
+        DndPolicy::Fallback(channel) => post_message(
+            client,
+            token,
+            &PostMessageRequest {
+                channel,
+                ..message.clone()
+            },
+        )
+        .map(Delivery::FellBack)
+        .map_err(SendRespectingDndError::Post),
+    }
+}
@@ -0,0 +1,114 @@
+pub use crate::ext_types::files::*;
+
+use std::io::Read;
+
+use super::pages::prefetch_pages;
+use crate::ext_types::files::{completed_files, parse_completed, parse_upload_url};
+use crate::mod_types::files_types::{ListError, ListRequest};
+use crate::sync::files::list;
+use crate::sync::requests::{SlackWebRequestSender, SlackWebUploadSender};
+use crate::File;
+
+/// Fetches every page of `files.list`, returning the files in order.
+///
+/// Once the first page reveals how many pages there are, the rest are fetched on at most
+/// `max_concurrent` threads. `request.page` is ignored.
+pub fn list_all<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+    max_concurrent: usize,
+) -> Result<Vec<File>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+    R::Error: Send,
+{
+    let first = list(
+        client,
+        token,
+        &ListRequest {
+            page: Some(1),
+            ..request.clone()
+        },
+    )?;
+    let pages = prefetch_pages(first, max_concurrent, |page| {
+        list(
+            client,
+            token,
+            &ListRequest {
+                page: Some(page),
+                ..request.clone()
+            },
+        )
+    })?;
+    Ok(pages
+        .into_iter()
+        .flat_map(|page| page.files.unwrap_or_default())
+        .collect())
+}
+
+/// Uploads a file read from `body` and, with `request.channel_id`, shares it, returning the
+/// uploaded files.
+///
+/// This is Slack's external upload flow: `files.getUploadURLExternal` gives a URL the file is
+/// streamed to in chunks, calling `on_progress` as they're sent, then
+/// `files.completeUploadExternal` finishes the upload. `body` must give exactly
+/// `request.length` bytes.
+pub fn upload_external<R, B>(
+    client: &R,
+    token: &str,
+    request: &UploadExternalRequest<'_>,
+    body: B,
+    on_progress: &dyn Fn(UploadProgress),
+) -> Result<Vec<File>, UploadExternalError<R::Error>>
+where
+    R: SlackWebUploadSender,
+    B: Read,
+{
+    let length = request.length.to_string();
+    let params = [
+        Some(("token", token)),
+        Some(("filename", request.filename)),
+        Some(("length", &length[..])),
+        request.alt_txt.map(|alt_txt| ("alt_txt", alt_txt)),
+        request
+            .snippet_type
+            .map(|snippet_type| ("snippet_type", snippet_type)),
+    ];
+    let url = crate::get_slack_url_for_method("files.getUploadURLExternal");
+    let result = client
+        .send(&url, IntoIterator::into_iter(params).flatten())
+        .map_err(UploadExternalError::Client)?;
+    let target = parse_upload_url(result)?;
+
+    client
+        .upload(
+            &target.upload_url,
+            "application/octet-stream",
+            request.length,
+            body,
+            on_progress,
+        )
+        .map_err(UploadExternalError::Client)?;
+
+    let files = completed_files(&target.file_id, request);
+    let thread_ts = request.thread_ts.map(|t| t.to_param_value());
+    let params = [
+        Some(("token", token)),
+        Some(("files", &files[..])),
+        request
+            .channel_id
+            .map(|channel_id| ("channel_id", channel_id)),
+        request
+            .initial_comment
+            .map(|initial_comment| ("initial_comment", initial_comment)),
+        thread_ts
+            .as_ref()
+            .map(|thread_ts| ("thread_ts", &thread_ts[..])),
+    ];
+    let url = crate::get_slack_url_for_method("files.completeUploadExternal");
+    let result = client
+        .send(&url, IntoIterator::into_iter(params).flatten())
+        .map_err(UploadExternalError::Client)?;
+    parse_completed(result)
+}
@@ -0,0 +1,15 @@
+//! Hand-written helpers, re-exported from the generated module they extend.
+
+mod pages;
+
+pub mod admin_usergroups;
+pub mod chat;
+pub mod conversations;
+pub mod dnd;
+pub mod files;
+pub mod reactions;
+pub mod stars;
+pub mod team;
+pub mod usergroups;
+pub mod users;
+pub mod views;
@@ -0,0 +1,46 @@
+use crate::HasPaging;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+/// Fetches the pages after `first`, up to the number of pages it reports, on at most
+/// `max_concurrent` threads, returning them after `first` in page order.
+pub(crate) fn prefetch_pages<T, E, F>(
+    first: T,
+    max_concurrent: usize,
+    fetch: F,
+) -> Result<Vec<T>, E>
+where
+    T: HasPaging + Send,
+    E: Send,
+    F: Fn(u32) -> Result<T, E> + Sync,
+{
+    let pages = first.pages();
+    let next = AtomicU32::new(2);
+    let fetched = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..max_concurrent.max(1).min(pages as usize) {
+            scope.spawn(|| loop {
+                let page = next.fetch_add(1, Ordering::Relaxed);
+                if page > pages {
+                    return;
+                }
+                let result = fetch(page);
+                let failed = result.is_err();
+                fetched.lock().unwrap().push((page, result));
+                if failed {
+                    // Stop handing out pages; the other threads finish the ones they started.
+                    next.store(pages + 1, Ordering::Relaxed);
+                    return;
+                }
+            });
+        }
+    });
+    let mut fetched = fetched.into_inner().unwrap();
+    fetched.sort_by_key(|&(page, _)| page);
+    let mut results = vec![first];
+    for (_, result) in fetched {
+        results.push(result?);
+    }
+    Ok(results)
+}
@@ -0,0 +1,93 @@
+pub use crate::ext_types::reactions::*;
+
+use super::pages::prefetch_pages;
+use crate::mod_types::reactions_types::{
+    AddError, AddRequest, ListError, ListRequest, ListResponseItem, RemoveError, RemoveRequest,
+};
+use crate::sync::reactions::{add, list, remove};
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Adds a reaction to an item, or removes it if the authed user has already reacted with it.
+///
+/// Adding and removing are idempotent: a reaction removed by someone else in the meantime is
+/// still reported as removed.
+pub fn toggle<R>(
+    client: &R,
+    token: &str,
+    name: &str,
+    target: ReactionTarget<'_>,
+) -> Result<Toggled, ToggleError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let (file, file_comment, channel, timestamp) = target.fields();
+    let added = add(
+        client,
+        token,
+        &AddRequest {
+            name,
+            file,
+            file_comment,
+            channel,
+            timestamp,
+        },
+    );
+    match added {
+        Ok(_) => return Ok(Toggled::Added),
+        Err(AddError::AlreadyReacted) => {}
+        Err(e) => return Err(ToggleError::Add(e)),
+    }
+    let removed = remove(
+        client,
+        token,
+        &RemoveRequest {
+            name,
+            file,
+            file_comment,
+            channel,
+            timestamp,
+        },
+    );
+    match removed {
+        Ok(_) | Err(RemoveError::NoReaction) => Ok(Toggled::Removed),
+        Err(e) => Err(ToggleError::Remove(e)),
+    }
+}
+
+/// Fetches every page of `reactions.list`, returning the items in order.
+///
+/// Once the first page reveals how many pages there are, the rest are fetched on at most
+/// `max_concurrent` threads. `request.page` is ignored.
+pub fn list_all<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+    max_concurrent: usize,
+) -> Result<Vec<ListResponseItem>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+    R::Error: Send,
+{
+    let first = list(
+        client,
+        token,
+        &ListRequest {
+            page: Some(1),
+            ..request.clone()
+        },
+    )?;
+    let pages = prefetch_pages(first, max_concurrent, |page| {
+        list(
+            client,
+            token,
+            &ListRequest {
+                page: Some(page),
+                ..request.clone()
+            },
+        )
+    })?;
+    Ok(pages
+        .into_iter()
+        .flat_map(|page| page.items.unwrap_or_default())
+        .collect())
+}
@@ -0,0 +1,76 @@
+use super::pages::prefetch_pages;
+use crate::mod_types::stars_types::{ListError, ListRequest, ListResponseItem};
+use crate::sync::requests::SlackWebRequestSender;
+use crate::sync::stars::list;
+
+/// Fetches every page of `stars.list`, returning the items in order.
+///
+/// Once the first page reveals how many pages there are, the rest are fetched on at most
+/// `max_concurrent` threads. `request.page` and `request.cursor` are ignored.
+pub fn list_all<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+    max_concurrent: usize,
+) -> Result<Vec<ListResponseItem>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+    R::Error: Send,
+{
+    let first = list(
+        client,
+        token,
+        &ListRequest {
+            page: Some(1),
+            cursor: None,
+            ..request.clone()
+        },
+    )?;
+    let pages = prefetch_pages(first, max_concurrent, |page| {
+        list(
+            client,
+            token,
+            &ListRequest {
+                page: Some(page),
+                cursor: None,
+                ..request.clone()
+            },
+        )
+    })?;
+    Ok(pages
+        .into_iter()
+        .flat_map(|page| page.items.unwrap_or_default())
+        .collect())
+}
+
+/// Fetches every page of `stars.list` by following `next_cursor`, returning the items in order.
+///
+/// Pages are fetched one after another, starting from `request.cursor`. `request.page` is
+/// ignored.
+pub fn list_all_by_cursor<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<Vec<ListResponseItem>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut items = Vec::new();
+    let mut cursor = request.cursor.cloned();
+    loop {
+        let page = list(
+            client,
+            token,
+            &ListRequest {
+                cursor: cursor.as_ref(),
+                page: None,
+                ..request.clone()
+            },
+        )?;
+        items.extend(page.items.into_iter().flatten());
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(items);
+        }
+    }
+}
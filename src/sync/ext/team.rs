@@ -0,0 +1,42 @@
+use super::pages::prefetch_pages;
+use crate::mod_types::team_types::{AccessLogsError, AccessLogsRequest, AccessLogsResponseLogin};
+use crate::sync::requests::SlackWebRequestSender;
+use crate::sync::team::access_logs;
+
+/// Fetches every page of `team.accessLogs`, returning the logins in order.
+///
+/// Once the first page reveals how many pages there are, the rest are fetched on at most
+/// `max_concurrent` threads. `request.page` is ignored.
+pub fn access_logs_all<R>(
+    client: &R,
+    token: &str,
+    request: &AccessLogsRequest,
+    max_concurrent: usize,
+) -> Result<Vec<AccessLogsResponseLogin>, AccessLogsError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+    R::Error: Send,
+{
+    let first = access_logs(
+        client,
+        token,
+        &AccessLogsRequest {
+            page: Some(1),
+            ..request.clone()
+        },
+    )?;
+    let pages = prefetch_pages(first, max_concurrent, |page| {
+        access_logs(
+            client,
+            token,
+            &AccessLogsRequest {
+                page: Some(page),
+                ..request.clone()
+            },
+        )
+    })?;
+    Ok(pages
+        .into_iter()
+        .flat_map(|page| page.logins.unwrap_or_default())
+        .collect())
+}
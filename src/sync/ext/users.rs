@@ -0,0 +1,32 @@
+pub use crate::ext_types::users::*;
+
+use crate::mod_types::users_types::{ListError, ListRequest};
+use crate::sync::requests::SlackWebRequestSender;
+use crate::sync::users::list;
+use crate::User;
+
+/// Lists the active, full members of the workspace across all pages, leaving out deactivated
+/// users, guests and bots.
+pub fn list_active<R>(client: &R, token: &str) -> Result<Vec<User>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut members = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = list(
+            client,
+            token,
+            &ListRequest {
+                cursor: cursor.as_ref(),
+                limit: Some(200),
+                ..Default::default()
+            },
+        )?;
+        members.extend(page.members.into_iter().flatten().filter(is_active_member));
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(members);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+pub use crate::ext_types::views::*;
+
+use crate::blocks::HomeView;
+use crate::ext_types::views::view_hash;
+use crate::mod_types::views_types::{PublishError, PublishRequest};
+use crate::sync::requests::SlackWebRequestSender;
+use crate::sync::views::publish;
+
+/// Publishes a view to a user's Home tab unless `cache` records it as already published there.
+///
+/// A view is only recorded once Slack accepts it, so a failed publish is tried again next time.
+pub fn publish_home<R>(
+    client: &R,
+    token: &str,
+    cache: &HomeTabCache,
+    user_id: &str,
+    view: &HomeView,
+) -> Result<HomePublished, PublishError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let hash = view_hash(view);
+    if cache.is_current(user_id, hash) {
+        return Ok(HomePublished::Unchanged);
+    }
+    publish(
+        client,
+        token,
+        &PublishRequest {
+            user_id,
+            view,
+            hash: None,
+        },
+    )?;
+    cache.remember(user_id, hash);
+    Ok(HomePublished::Published)
+}
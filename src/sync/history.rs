@@ -0,0 +1,117 @@
+//! Helpers which walk a channel's history.
+
+pub use crate::history_types::*;
+
+use std::thread;
+
+use super::requests::RateLimitRetry;
+use super::requests::SlackWebRequestSender;
+use super::{chat, conversations};
+use crate::history_types::bot_message_ts;
+
+/// Deletes the messages posted by bots in a channel between `oldest` and `latest`,
+/// returning how many were deleted.
+///
+/// Deletions are paced by the request's `interval`, and rate limited calls are retried as the
+/// request's `retry_policy` allows.
+pub fn delete_range<R>(
+    client: &R,
+    token: &str,
+    request: &DeleteRangeRequest<'_>,
+) -> Result<usize, DeleteRangeError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+{
+    let client =
+        &RateLimitRetry::new(client).policy(request.retry_policy.clone().unwrap_or_default());
+    let interval = request.interval.unwrap_or(DEFAULT_DELETE_INTERVAL);
+    let mut cursor = None;
+    let mut deleted = 0;
+    loop {
+        let page = conversations::history(
+            client,
+            token,
+            &conversations::HistoryRequest {
+                channel: request.channel,
+                cursor: cursor.as_ref(),
+                latest: request.latest,
+                oldest: request.oldest,
+                limit: Some(200),
+                ..Default::default()
+            },
+        )
+        .map_err(DeleteRangeError::History)?;
+        let messages = page.messages.unwrap_or_default();
+        for ts in messages.iter().filter_map(bot_message_ts) {
+            if deleted > 0 {
+                thread::sleep(interval);
+            }
+            let result = chat::delete(
+                client,
+                token,
+                &chat::DeleteRequest {
+                    ts,
+                    channel: request.channel,
+                    as_user: request.as_user,
+                },
+            );
+            match result {
+                Ok(_) => deleted += 1,
+                // Deleted by someone else since the history was fetched.
+                Err(chat::DeleteError::MessageNotFound) => {}
+                Err(error) => return Err(DeleteRangeError::Delete { deleted, error }),
+            }
+        }
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(deleted);
+        }
+    }
+}
+
+/// Counts the messages in a channel posted after the authed user's read cursor, as set by
+/// `conversations::mark`.
+///
+/// Every message is counted as unread if the channel has no read cursor, which is the case for
+/// bot tokens.
+pub fn unread_count<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<usize, UnreadCountError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let info = conversations::info(
+        client,
+        token,
+        &conversations::InfoRequest {
+            channel,
+            ..Default::default()
+        },
+    )
+    .map_err(UnreadCountError::Info)?;
+    let last_read = info.channel.and_then(|c| c.last_read);
+    let mut cursor = None;
+    let mut unread = 0;
+    loop {
+        let page = conversations::history(
+            client,
+            token,
+            &conversations::HistoryRequest {
+                channel,
+                cursor: cursor.as_ref(),
+                oldest: last_read,
+                inclusive: Some(false),
+                limit: Some(200),
+                ..Default::default()
+            },
+        )
+        .map_err(UnreadCountError::History)?;
+        unread += page.messages.map_or(0, |m| m.len());
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(unread);
+        }
+    }
+}
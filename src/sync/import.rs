@@ -0,0 +1,170 @@
+//! Replays an export made by [`export`](super::export) into another workspace.
+//!
+//! Channels are matched to the workspace's channels by name, and created if it doesn't have them.
+//! Messages are posted oldest first under the name and icon of their original author, which needs
+//! the `chat:write.customize` scope, and replies are posted to the thread they belonged to. Direct
+//! messages can't be recreated and are skipped, as are files, whose contents aren't exported.
+
+pub use crate::import_types::*;
+
+use std::collections::HashMap;
+
+use super::export::Pacer;
+use super::requests::SlackWebRequestSender;
+use super::SlackClient;
+use crate::export_types::{next_cursor, take_records, PageRequest};
+use crate::import_types::{
+    author_of, is_replayable, read_authors, read_channels, read_messages, CreateRequest, ImportLog,
+    JoinRequest, PostRequest, TargetChannel,
+};
+
+/// Imports the export in `request.dir` into the workspace `client` belongs to.
+///
+/// Requests are paced by the request's `interval`, and rate limited requests are retried as the
+/// request's `retry_policy` allows. Every channel found or created and every message posted is logged in the export's
+/// directory, so importing again after an error doesn't post anything twice.
+pub fn import<R>(
+    client: &SlackClient<R>,
+    request: &ImportRequest<'_>,
+) -> Result<ImportSummary, ImportError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+{
+    let dir = request.dir;
+    let authors = read_authors(dir)?;
+    let mut log = ImportLog::open(dir)?;
+    let interval = request.interval.unwrap_or(DEFAULT_IMPORT_INTERVAL);
+    let mut pacer = Pacer::new(client, interval, request.retry_policy.clone());
+    let mut targets = None;
+    let mut summary = ImportSummary::default();
+
+    for channel in read_channels(dir)? {
+        let messages = match read_messages(dir, &channel.id)? {
+            Some(messages) if !channel.is_im && !channel.is_mpim => messages,
+            _ => {
+                summary.skipped_channels.push(channel.id);
+                continue;
+            }
+        };
+        let target = match log.channel(&channel.id) {
+            Some(target) => target.to_owned(),
+            None => {
+                let name = channel.name.as_deref().unwrap_or(&channel.id);
+                if targets.is_none() {
+                    targets = Some(list_channels(&mut pacer)?);
+                }
+                let target = match targets.as_ref().and_then(|t| t.get(name)) {
+                    Some(target) => {
+                        if !target.is_private {
+                            call(
+                                &mut pacer,
+                                "conversations.join",
+                                &JoinRequest {
+                                    channel: &target.id,
+                                },
+                            )?;
+                        }
+                        target.id.clone()
+                    }
+                    None => {
+                        let create = CreateRequest {
+                            name,
+                            is_private: channel.is_private,
+                        };
+                        let created = call(&mut pacer, "conversations.create", &create)?;
+                        summary.channels_created += 1;
+                        created["channel"]["id"]
+                            .as_str()
+                            .unwrap_or_default()
+                            .to_owned()
+                    }
+                };
+                log.record_channel(&channel.id, &target)?;
+                target
+            }
+        };
+
+        for message in &messages {
+            let ts = match message["ts"].as_str() {
+                Some(ts) if log.message(&channel.id, ts).is_none() => ts,
+                _ => continue,
+            };
+            if !is_replayable(message) {
+                summary.skipped_messages += 1;
+                continue;
+            }
+            // A reply whose thread started before the export's `oldest` is posted on its own.
+            let thread_ts = message["thread_ts"]
+                .as_str()
+                .filter(|&thread_ts| thread_ts != ts)
+                .and_then(|thread_ts| log.message(&channel.id, thread_ts))
+                .map(str::to_owned);
+            let (username, icon_url) = author_of(message, &authors);
+            let post = PostRequest {
+                channel: &target,
+                text: message["text"].as_str().unwrap_or_default(),
+                blocks: Some(&message["blocks"]).filter(|b| b.is_array()),
+                attachments: Some(&message["attachments"]).filter(|a| a.is_array()),
+                username,
+                icon_url,
+                thread_ts: thread_ts.as_deref(),
+                unfurl_links: false,
+                unfurl_media: false,
+            };
+            let posted = call(&mut pacer, "chat.postMessage", &post)?;
+            let new_ts = posted["ts"].as_str().unwrap_or_default();
+            log.record_message(&channel.id, ts, new_ts)?;
+            summary.messages += 1;
+        }
+    }
+
+    Ok(summary)
+}
+
+fn call<R, Req>(
+    pacer: &mut Pacer<'_, R>,
+    method: &'static str,
+    request: &Req,
+) -> Result<serde_json::Value, ImportError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+    Req: serde::Serialize,
+{
+    pacer
+        .send(method, request)
+        .map_err(|error| ImportError::Slack { method, error })
+}
+
+/// Maps the names of the workspace's unarchived channels to the channels.
+fn list_channels<R>(
+    pacer: &mut Pacer<'_, R>,
+) -> Result<HashMap<String, TargetChannel>, ImportError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+{
+    let mut channels = HashMap::new();
+    let mut cursor = None;
+    loop {
+        let request = PageRequest {
+            types: Some("public_channel,private_channel"),
+            exclude_archived: Some(true),
+            cursor: cursor.as_deref(),
+            limit: Some(200),
+            ..Default::default()
+        };
+        let mut page = call(pacer, "conversations.list", &request)?;
+        for channel in take_records(&mut page, "channels") {
+            if let (Some(id), Some(name)) = (channel["id"].as_str(), channel["name"].as_str()) {
+                let target = TargetChannel {
+                    id: id.to_owned(),
+                    is_private: channel["is_private"].as_bool().unwrap_or(false),
+                };
+                channels.insert(name.to_owned(), target);
+            }
+        }
+        cursor = next_cursor(&page);
+        if cursor.is_none() {
+            return Ok(channels);
+        }
+    }
+}
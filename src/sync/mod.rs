@@ -1,10 +1,42 @@
-pub use crate::timestamp::*;
-pub use crate::types::*;
-
-mod mods;
-pub use self::mods::*;
-
-pub mod requests;
-
-#[cfg(feature = "reqwest_blocking")]
-pub use self::requests::default_client;
+pub use crate::installation::*;
+pub use crate::timestamp::*;
+pub use crate::types::*;
+
+mod ext;
+mod mods;
+pub use self::mods::*;
+
+pub mod analytics;
+
+mod client;
+pub use self::client::SlackClient;
+
+pub mod export;
+
+pub mod history;
+
+pub mod import;
+
+pub mod installations;
+
+#[cfg(feature = "oidc")]
+pub mod oidc;
+
+pub mod onboarding;
+
+pub mod outbox;
+
+pub mod presence;
+
+pub mod provisioning;
+
+pub mod requests;
+
+pub mod scheduler;
+
+pub mod streaming;
+
+pub mod threads;
+
+#[cfg(feature = "reqwest_blocking")]
+pub use self::requests::default_client;
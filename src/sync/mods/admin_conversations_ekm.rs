@@ -0,0 +1,56 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Look up the channels of an Enterprise Grid organization using Enterprise Key Management.
+
+pub use crate::mod_types::admin_conversations_ekm_types::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Lists the original connected channel information of channels shared by Slack Connect before EKM was enabled.
+///
+/// Wraps https://api.slack.com/methods/admin.conversations.ekm.listOriginalConnectedChannelInfo
+pub fn list_original_connected_channel_info<R>(
+    client: &R,
+    token: &str,
+    request: &ListOriginalConnectedChannelInfoRequest<'_>,
+) -> Result<ListOriginalConnectedChannelInfoResponse, ListOriginalConnectedChannelInfoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let channel_ids = request.channel_ids.map(|channel_ids| channel_ids.join(","));
+    let limit = request.limit.map(|limit| limit.to_string());
+    let team_ids = request.team_ids.map(|team_ids| team_ids.join(","));
+    let params = [
+        Some(("token", token)),
+        channel_ids
+            .as_ref()
+            .map(|channel_ids| ("channel_ids", &channel_ids[..])),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        team_ids
+            .as_ref()
+            .map(|team_ids| ("team_ids", &team_ids[..])),
+    ];
+    let url =
+        crate::get_slack_url_for_method("admin.conversations.ekm.listOriginalConnectedChannelInfo");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ListOriginalConnectedChannelInfoError::Client)?;
+    serde_json::from_slice::<ListOriginalConnectedChannelInfoResponse>(&body)
+        .map_err(|e| {
+            ListOriginalConnectedChannelInfoError::MalformedResponse(body.to_string_lossy(), e)
+        })
+        .and_then(|o| o.into())
+}
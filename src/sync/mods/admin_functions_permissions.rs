@@ -0,0 +1,75 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage who can run the custom functions of an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_functions_permissions_types::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Looks up who can run functions, keyed by function ID.
+///
+/// Wraps https://api.slack.com/methods/admin.functions.permissions.lookup
+pub fn lookup<R>(
+    client: &R,
+    token: &str,
+    request: &LookupRequest<'_>,
+) -> Result<LookupResponse, LookupError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let function_ids = request.function_ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("function_ids", &function_ids[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.functions.permissions.lookup");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(LookupError::Client)?;
+    serde_json::from_slice::<LookupResponse>(&body)
+        .map_err(|e| LookupError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Sets who can run a function.
+///
+/// Wraps https://api.slack.com/methods/admin.functions.permissions.set
+pub fn set<R>(
+    client: &R,
+    token: &str,
+    request: &SetRequest<'_>,
+) -> Result<SetResponse, SetError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let visibility = request.visibility.to_param_value();
+    let user_ids = request.user_ids.map(|user_ids| user_ids.join(","));
+    let params = [
+        Some(("token", token)),
+        Some(("function_id", request.function_id)),
+        Some(("visibility", &visibility[..])),
+        user_ids
+            .as_ref()
+            .map(|user_ids| ("user_ids", &user_ids[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.functions.permissions.set");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(SetError::Client)?;
+    serde_json::from_slice::<SetResponse>(&body)
+        .map_err(|e| SetError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,134 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Assign the admin roles of an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_roles_types::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Assigns users to a role, scoped to the given entities.
+///
+/// Wraps https://api.slack.com/methods/admin.roles.addAssignments
+pub fn add_assignments<R>(
+    client: &R,
+    token: &str,
+    request: &AddAssignmentsRequest<'_>,
+) -> Result<AddAssignmentsResponse, AddAssignmentsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let entity_ids = request
+        .entity_ids
+        .iter()
+        .map(|id| id.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+    let user_ids = request.user_ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("entity_ids", &entity_ids[..])),
+        Some(("role_id", request.role_id.as_str())),
+        Some(("user_ids", &user_ids[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.roles.addAssignments");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(AddAssignmentsError::Client)?;
+    serde_json::from_slice::<AddAssignmentsResponse>(&body)
+        .map_err(|e| AddAssignmentsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Lists the assignments of roles.
+///
+/// Wraps https://api.slack.com/methods/admin.roles.listAssignments
+pub fn list_assignments<R>(
+    client: &R,
+    token: &str,
+    request: &ListAssignmentsRequest<'_>,
+) -> Result<ListAssignmentsResponse, ListAssignmentsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let entity_ids = request.entity_ids.map(|entity_ids| {
+        entity_ids
+            .iter()
+            .map(|id| id.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+    let limit = request.limit.map(|limit| limit.to_string());
+    let role_ids = request.role_ids.map(|role_ids| {
+        role_ids
+            .iter()
+            .map(|id| id.as_str())
+            .collect::<Vec<_>>()
+            .join(",")
+    });
+    let params = [
+        Some(("token", token)),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        entity_ids
+            .as_ref()
+            .map(|entity_ids| ("entity_ids", &entity_ids[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        role_ids
+            .as_ref()
+            .map(|role_ids| ("role_ids", &role_ids[..])),
+        request.sort_dir.map(|sort_dir| ("sort_dir", sort_dir)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.roles.listAssignments");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ListAssignmentsError::Client)?;
+    serde_json::from_slice::<ListAssignmentsResponse>(&body)
+        .map_err(|e| ListAssignmentsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Removes users from a role, scoped to the given entities.
+///
+/// Wraps https://api.slack.com/methods/admin.roles.removeAssignments
+pub fn remove_assignments<R>(
+    client: &R,
+    token: &str,
+    request: &RemoveAssignmentsRequest<'_>,
+) -> Result<RemoveAssignmentsResponse, RemoveAssignmentsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let entity_ids = request
+        .entity_ids
+        .iter()
+        .map(|id| id.as_str())
+        .collect::<Vec<_>>()
+        .join(",");
+    let user_ids = request.user_ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("entity_ids", &entity_ids[..])),
+        Some(("role_id", request.role_id.as_str())),
+        Some(("user_ids", &user_ids[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.roles.removeAssignments");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(RemoveAssignmentsError::Client)?;
+    serde_json::from_slice::<RemoveAssignmentsResponse>(&body)
+        .map_err(|e| RemoveAssignmentsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
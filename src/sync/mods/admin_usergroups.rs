@@ -0,0 +1,136 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage the default channels and workspaces of the user groups of an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_usergroups_types::*;
+pub use crate::sync::ext::admin_usergroups::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Adds channels to the default channels of a user group, which its members join automatically.
+///
+/// Wraps https://api.slack.com/methods/admin.usergroups.addChannels
+pub fn add_channels<R>(
+    client: &R,
+    token: &str,
+    request: &AddChannelsRequest<'_>,
+) -> Result<AddChannelsResponse, AddChannelsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let channel_ids = request.channel_ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("channel_ids", &channel_ids[..])),
+        Some(("usergroup_id", request.usergroup_id)),
+        request.team_id.map(|team_id| ("team_id", team_id)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.usergroups.addChannels");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(AddChannelsError::Client)?;
+    serde_json::from_slice::<AddChannelsResponse>(&body)
+        .map_err(|e| AddChannelsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Makes an organization-wide user group available in more workspaces.
+///
+/// Wraps https://api.slack.com/methods/admin.usergroups.addTeams
+pub fn add_teams<R>(
+    client: &R,
+    token: &str,
+    request: &AddTeamsRequest<'_>,
+) -> Result<AddTeamsResponse, AddTeamsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let team_ids = request.team_ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("team_ids", &team_ids[..])),
+        Some(("usergroup_id", request.usergroup_id)),
+        request
+            .auto_provision
+            .map(|auto_provision| ("auto_provision", if auto_provision { "1" } else { "0" })),
+    ];
+    let url = crate::get_slack_url_for_method("admin.usergroups.addTeams");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(AddTeamsError::Client)?;
+    serde_json::from_slice::<AddTeamsResponse>(&body)
+        .map_err(|e| AddTeamsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Lists the default channels of a user group.
+///
+/// Wraps https://api.slack.com/methods/admin.usergroups.listChannels
+pub fn list_channels<R>(
+    client: &R,
+    token: &str,
+    request: &ListChannelsRequest<'_>,
+) -> Result<ListChannelsResponse, ListChannelsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("usergroup_id", request.usergroup_id)),
+        request.include_num_members.map(|include_num_members| {
+            (
+                "include_num_members",
+                if include_num_members { "1" } else { "0" },
+            )
+        }),
+        request.team_id.map(|team_id| ("team_id", team_id)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.usergroups.listChannels");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ListChannelsError::Client)?;
+    serde_json::from_slice::<ListChannelsResponse>(&body)
+        .map_err(|e| ListChannelsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Removes channels from the default channels of a user group.
+///
+/// Wraps https://api.slack.com/methods/admin.usergroups.removeChannels
+pub fn remove_channels<R>(
+    client: &R,
+    token: &str,
+    request: &RemoveChannelsRequest<'_>,
+) -> Result<RemoveChannelsResponse, RemoveChannelsError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let channel_ids = request.channel_ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("channel_ids", &channel_ids[..])),
+        Some(("usergroup_id", request.usergroup_id)),
+    ];
+    let url = crate::get_slack_url_for_method("admin.usergroups.removeChannels");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(RemoveChannelsError::Client)?;
+    serde_json::from_slice::<RemoveChannelsResponse>(&body)
+        .map_err(|e| RemoveChannelsError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
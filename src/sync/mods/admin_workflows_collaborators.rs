@@ -0,0 +1,74 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage who can edit the workflows of an Enterprise Grid organization.
+
+pub use crate::mod_types::admin_workflows_collaborators_types::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Adds collaborators to workflows.
+///
+/// Wraps https://api.slack.com/methods/admin.workflows.collaborators.add
+pub fn add<R>(
+    client: &R,
+    token: &str,
+    request: &AddRequest<'_>,
+) -> Result<AddResponse, AddError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let collaborator_ids = request.collaborator_ids.join(",");
+    let workflow_ids = request.workflow_ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("collaborator_ids", &collaborator_ids[..])),
+        Some(("workflow_ids", &workflow_ids[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.workflows.collaborators.add");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(AddError::Client)?;
+    serde_json::from_slice::<AddResponse>(&body)
+        .map_err(|e| AddError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Removes collaborators from workflows.
+///
+/// Wraps https://api.slack.com/methods/admin.workflows.collaborators.remove
+pub fn remove<R>(
+    client: &R,
+    token: &str,
+    request: &RemoveRequest<'_>,
+) -> Result<RemoveResponse, RemoveError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let collaborator_ids = request.collaborator_ids.join(",");
+    let workflow_ids = request.workflow_ids.join(",");
+    let params = [
+        Some(("token", token)),
+        Some(("collaborator_ids", &collaborator_ids[..])),
+        Some(("workflow_ids", &workflow_ids[..])),
+    ];
+    let url = crate::get_slack_url_for_method("admin.workflows.collaborators.remove");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(RemoveError::Client)?;
+    serde_json::from_slice::<RemoveResponse>(&body)
+        .map_err(|e| RemoveError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
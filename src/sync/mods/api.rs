@@ -18,23 +18,20 @@ use crate::sync::requests::SlackWebRequestSender;
 /// Checks API calling code.
 ///
 /// Wraps https://api.slack.com/methods/api.test
-
 pub fn test<R>(client: &R, request: &TestRequest<'_>) -> Result<TestResponse, TestError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         request.error.map(|error| ("error", error)),
         request.foo.map(|foo| ("foo", foo)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("api.test");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(TestError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<TestResponse>(&result)
-                .map_err(|e| TestError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(TestError::Client)?;
+    serde_json::from_slice::<TestResponse>(&body)
+        .map_err(|e| TestError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -18,7 +18,6 @@ use crate::sync::requests::SlackWebRequestSender;
 /// Revokes a token.
 ///
 /// Wraps https://api.slack.com/methods/auth.revoke
-
 pub fn revoke<R>(
     client: &R,
     token: &str,
@@ -27,40 +26,36 @@ pub fn revoke<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request
             .test
             .map(|test| ("test", if test { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("auth.revoke");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(RevokeError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RevokeResponse>(&result)
-                .map_err(|e| RevokeError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(RevokeError::Client)?;
+    serde_json::from_slice::<RevokeResponse>(&body)
+        .map_err(|e| RevokeError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Checks authentication & identity.
 ///
 /// Wraps https://api.slack.com/methods/auth.test
-
 pub fn test<R>(client: &R, token: &str) -> Result<TestResponse, TestError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("auth.test");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(TestError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<TestResponse>(&result)
-                .map_err(|e| TestError::MalformedResponse(result, e))
-        })
+        .send_into(&url, &params[..], &mut body)
+        .map_err(TestError::Client)?;
+    serde_json::from_slice::<TestResponse>(&body)
+        .map_err(|e| TestError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
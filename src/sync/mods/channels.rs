@@ -20,7 +20,6 @@ use crate::sync::requests::SlackWebRequestSender;
 /// Archives a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.archive
-
 pub fn archive<R>(
     client: &R,
     token: &str,
@@ -29,23 +28,20 @@ pub fn archive<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("channels.archive");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(ArchiveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ArchiveResponse>(&result)
-                .map_err(|e| ArchiveError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ArchiveError::Client)?;
+    serde_json::from_slice::<ArchiveResponse>(&body)
+        .map_err(|e| ArchiveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Creates a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.create
-
 pub fn create<R>(
     client: &R,
     token: &str,
@@ -54,29 +50,26 @@ pub fn create<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("name", request.name)),
         request
             .validate
             .map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.create");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(CreateError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result)
-                .map_err(|e| CreateError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(CreateError::Client)?;
+    serde_json::from_slice::<CreateResponse>(&body)
+        .map_err(|e| CreateError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Fetches history of messages and events from a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.history
-
 pub fn history<R>(
     client: &R,
     token: &str,
@@ -88,7 +81,7 @@ where
     let latest = request.latest.as_ref().map(|t| t.to_param_value());
     let oldest = request.oldest.as_ref().map(|t| t.to_param_value());
     let count = request.count.map(|count| count.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         latest.as_ref().map(|latest| ("latest", &latest[..])),
@@ -101,22 +94,19 @@ where
             .unreads
             .map(|unreads| ("unreads", if unreads { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.history");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(HistoryError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<HistoryResponse>(&result)
-                .map_err(|e| HistoryError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(HistoryError::Client)?;
+    serde_json::from_slice::<HistoryResponse>(&body)
+        .map_err(|e| HistoryError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets information about a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.info
-
 pub fn info<R>(
     client: &R,
     token: &str,
@@ -125,23 +115,20 @@ pub fn info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("channels.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Invites a user to a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.invite
-
 pub fn invite<R>(
     client: &R,
     token: &str,
@@ -150,27 +137,24 @@ pub fn invite<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("user", request.user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.invite");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(InviteError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InviteResponse>(&result)
-                .map_err(|e| InviteError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(InviteError::Client)?;
+    serde_json::from_slice::<InviteResponse>(&body)
+        .map_err(|e| InviteError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Joins a channel, creating it if needed.
 ///
 /// Wraps https://api.slack.com/methods/channels.join
-
 pub fn join<R>(
     client: &R,
     token: &str,
@@ -179,29 +163,26 @@ pub fn join<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("name", request.name)),
         request
             .validate
             .map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.join");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(JoinError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<JoinResponse>(&result)
-                .map_err(|e| JoinError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(JoinError::Client)?;
+    serde_json::from_slice::<JoinResponse>(&body)
+        .map_err(|e| JoinError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Removes a user from a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.kick
-
 pub fn kick<R>(
     client: &R,
     token: &str,
@@ -210,27 +191,24 @@ pub fn kick<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("user", request.user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.kick");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(KickError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<KickResponse>(&result)
-                .map_err(|e| KickError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(KickError::Client)?;
+    serde_json::from_slice::<KickResponse>(&body)
+        .map_err(|e| KickError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Leaves a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.leave
-
 pub fn leave<R>(
     client: &R,
     token: &str,
@@ -239,23 +217,20 @@ pub fn leave<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("channels.leave");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(LeaveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<LeaveResponse>(&result)
-                .map_err(|e| LeaveError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(LeaveError::Client)?;
+    serde_json::from_slice::<LeaveResponse>(&body)
+        .map_err(|e| LeaveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists all channels in a Slack team.
 ///
 /// Wraps https://api.slack.com/methods/channels.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -264,7 +239,7 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request
             .exclude_archived
@@ -273,22 +248,19 @@ where
             .exclude_members
             .map(|exclude_members| ("exclude_members", if exclude_members { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the read cursor in a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.mark
-
 pub fn mark<R>(
     client: &R,
     token: &str,
@@ -298,27 +270,24 @@ where
     R: SlackWebRequestSender,
 {
     let ts = request.ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("ts", &ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.mark");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(MarkError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<MarkResponse>(&result)
-                .map_err(|e| MarkError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(MarkError::Client)?;
+    serde_json::from_slice::<MarkResponse>(&body)
+        .map_err(|e| MarkError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Renames a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.rename
-
 pub fn rename<R>(
     client: &R,
     token: &str,
@@ -327,7 +296,7 @@ pub fn rename<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("name", request.name)),
@@ -335,22 +304,19 @@ where
             .validate
             .map(|validate| ("validate", if validate { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.rename");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(RenameError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RenameResponse>(&result)
-                .map_err(|e| RenameError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(RenameError::Client)?;
+    serde_json::from_slice::<RenameResponse>(&body)
+        .map_err(|e| RenameError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Retrieve a thread of messages posted to a channel
 ///
 /// Wraps https://api.slack.com/methods/channels.replies
-
 pub fn replies<R>(
     client: &R,
     token: &str,
@@ -360,27 +326,24 @@ where
     R: SlackWebRequestSender,
 {
     let thread_ts = request.thread_ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("thread_ts", &thread_ts[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.replies");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(RepliesError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RepliesResponse>(&result)
-                .map_err(|e| RepliesError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(RepliesError::Client)?;
+    serde_json::from_slice::<RepliesResponse>(&body)
+        .map_err(|e| RepliesError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the purpose for a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.setPurpose
-
 pub fn set_purpose<R>(
     client: &R,
     token: &str,
@@ -389,27 +352,24 @@ pub fn set_purpose<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("purpose", request.purpose)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.setPurpose");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(SetPurposeError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetPurposeResponse>(&result)
-                .map_err(|e| SetPurposeError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(SetPurposeError::Client)?;
+    serde_json::from_slice::<SetPurposeResponse>(&body)
+        .map_err(|e| SetPurposeError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sets the topic for a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.setTopic
-
 pub fn set_topic<R>(
     client: &R,
     token: &str,
@@ -418,27 +378,24 @@ pub fn set_topic<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("topic", request.topic)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("channels.setTopic");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(SetTopicError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SetTopicResponse>(&result)
-                .map_err(|e| SetTopicError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(SetTopicError::Client)?;
+    serde_json::from_slice::<SetTopicResponse>(&body)
+        .map_err(|e| SetTopicError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Unarchives a channel.
 ///
 /// Wraps https://api.slack.com/methods/channels.unarchive
-
 pub fn unarchive<R>(
     client: &R,
     token: &str,
@@ -447,15 +404,13 @@ pub fn unarchive<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("channel", request.channel))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("channel", request.channel))];
     let url = crate::get_slack_url_for_method("channels.unarchive");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(UnarchiveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<UnarchiveResponse>(&result)
-                .map_err(|e| UnarchiveError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(UnarchiveError::Client)?;
+    serde_json::from_slice::<UnarchiveResponse>(&body)
+        .map_err(|e| UnarchiveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -15,12 +15,12 @@
 //! Post chat messages to Slack.
 
 pub use crate::mod_types::chat_types::*;
+pub use crate::sync::ext::chat::*;
 use crate::sync::requests::SlackWebRequestSender;
 
 /// Deletes a message.
 ///
 /// Wraps https://api.slack.com/methods/chat.delete
-
 pub fn delete<R>(
     client: &R,
     token: &str,
@@ -30,7 +30,7 @@ where
     R: SlackWebRequestSender,
 {
     let ts = request.ts.to_param_value();
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("ts", &ts[..])),
         Some(("channel", request.channel)),
@@ -38,22 +38,48 @@ where
             .as_user
             .map(|as_user| ("as_user", if as_user { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("chat.delete");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(DeleteError::Client)?;
+    serde_json::from_slice::<DeleteResponse>(&body)
+        .map_err(|e| DeleteError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Deletes a pending scheduled message from the queue.
+///
+/// Wraps https://api.slack.com/methods/chat.deleteScheduledMessage
+pub fn delete_scheduled_message<R>(
+    client: &R,
+    token: &str,
+    request: &DeleteScheduledMessageRequest<'_>,
+) -> Result<DeleteScheduledMessageResponse, DeleteScheduledMessageError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        Some(("scheduled_message_id", request.scheduled_message_id)),
+        request
+            .as_user
+            .map(|as_user| ("as_user", if as_user { "1" } else { "0" })),
+    ];
+    let url = crate::get_slack_url_for_method("chat.deleteScheduledMessage");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(DeleteError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result)
-                .map_err(|e| DeleteError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(DeleteScheduledMessageError::Client)?;
+    serde_json::from_slice::<DeleteScheduledMessageResponse>(&body)
+        .map_err(|e| DeleteScheduledMessageError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Share a me message into a channel.
 ///
 /// Wraps https://api.slack.com/methods/chat.meMessage
-
 pub fn me_message<R>(
     client: &R,
     token: &str,
@@ -62,27 +88,73 @@ pub fn me_message<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("text", request.text)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("chat.meMessage");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(MeMessageError::Client)?;
+    serde_json::from_slice::<MeMessageResponse>(&body)
+        .map_err(|e| MeMessageError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Sends an ephemeral message to a user in a channel.
+///
+/// Wraps https://api.slack.com/methods/chat.postEphemeral
+pub fn post_ephemeral<R>(
+    client: &R,
+    token: &str,
+    request: &PostEphemeralRequest<'_>,
+) -> Result<PostEphemeralResponse, PostEphemeralError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let attachments = request.attachments.map(crate::to_json_param);
+    let blocks = request.blocks.map(crate::to_json_param);
+    let thread_ts = request.thread_ts.as_ref().map(|t| t.to_param_value());
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        Some(("text", request.text)),
+        Some(("user", request.user)),
+        request
+            .as_user
+            .map(|as_user| ("as_user", if as_user { "1" } else { "0" })),
+        attachments
+            .as_ref()
+            .map(|attachments| ("attachments", &attachments[..])),
+        blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
+        request
+            .icon_emoji
+            .map(|icon_emoji| ("icon_emoji", icon_emoji)),
+        request.icon_url.map(|icon_url| ("icon_url", icon_url)),
+        request
+            .link_names
+            .map(|link_names| ("link_names", if link_names { "1" } else { "0" })),
+        request.parse.map(|parse| ("parse", parse)),
+        thread_ts
+            .as_ref()
+            .map(|thread_ts| ("thread_ts", &thread_ts[..])),
+        request.username.map(|username| ("username", username)),
+    ];
+    let url = crate::get_slack_url_for_method("chat.postEphemeral");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(MeMessageError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<MeMessageResponse>(&result)
-                .map_err(|e| MeMessageError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(PostEphemeralError::Client)?;
+    serde_json::from_slice::<PostEphemeralResponse>(&body)
+        .map_err(|e| PostEphemeralError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Sends a message to a channel.
 ///
 /// Wraps https://api.slack.com/methods/chat.postMessage
-
 pub fn post_message<R>(
     client: &R,
     token: &str,
@@ -91,8 +163,11 @@ pub fn post_message<R>(
 where
     R: SlackWebRequestSender,
 {
+    let attachments = request.attachments.map(crate::to_json_param);
+    let blocks = request.blocks.map(crate::to_json_param);
     let thread_ts = request.thread_ts.as_ref().map(|t| t.to_param_value());
-    let params = vec![
+    let metadata = request.metadata.map(crate::to_json_param);
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("text", request.text)),
@@ -100,9 +175,10 @@ where
         request
             .link_names
             .map(|link_names| ("link_names", if link_names { "1" } else { "0" })),
-        request
-            .attachments
-            .map(|attachments| ("attachments", attachments)),
+        attachments
+            .as_ref()
+            .map(|attachments| ("attachments", &attachments[..])),
+        blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
         request
             .unfurl_links
             .map(|unfurl_links| ("unfurl_links", if unfurl_links { "1" } else { "0" })),
@@ -123,23 +199,87 @@ where
         request
             .reply_broadcast
             .map(|reply_broadcast| ("reply_broadcast", if reply_broadcast { "1" } else { "0" })),
+        request
+            .mrkdwn
+            .map(|mrkdwn| ("mrkdwn", if mrkdwn { "1" } else { "0" })),
+        metadata
+            .as_ref()
+            .map(|metadata| ("metadata", &metadata[..])),
+        request
+            .client_msg_id
+            .map(|client_msg_id| ("client_msg_id", client_msg_id)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("chat.postMessage");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(PostMessageError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<PostMessageResponse>(&result)
-                .map_err(|e| PostMessageError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(PostMessageError::Client)?;
+    serde_json::from_slice::<PostMessageResponse>(&body)
+        .map_err(|e| PostMessageError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Schedules a message to be sent to a channel.
+///
+/// Wraps https://api.slack.com/methods/chat.scheduleMessage
+pub fn schedule_message<R>(
+    client: &R,
+    token: &str,
+    request: &ScheduleMessageRequest<'_>,
+) -> Result<ScheduleMessageResponse, ScheduleMessageError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let post_at = request.post_at.to_string();
+    let attachments = request.attachments.map(crate::to_json_param);
+    let blocks = request.blocks.map(crate::to_json_param);
+    let metadata = request.metadata.map(crate::to_json_param);
+    let thread_ts = request.thread_ts.as_ref().map(|t| t.to_param_value());
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        Some(("post_at", &post_at[..])),
+        request.text.map(|text| ("text", text)),
+        request
+            .as_user
+            .map(|as_user| ("as_user", if as_user { "1" } else { "0" })),
+        attachments
+            .as_ref()
+            .map(|attachments| ("attachments", &attachments[..])),
+        blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
+        request
+            .link_names
+            .map(|link_names| ("link_names", if link_names { "1" } else { "0" })),
+        metadata
+            .as_ref()
+            .map(|metadata| ("metadata", &metadata[..])),
+        request.parse.map(|parse| ("parse", parse)),
+        request
+            .reply_broadcast
+            .map(|reply_broadcast| ("reply_broadcast", if reply_broadcast { "1" } else { "0" })),
+        thread_ts
+            .as_ref()
+            .map(|thread_ts| ("thread_ts", &thread_ts[..])),
+        request
+            .unfurl_links
+            .map(|unfurl_links| ("unfurl_links", if unfurl_links { "1" } else { "0" })),
+        request
+            .unfurl_media
+            .map(|unfurl_media| ("unfurl_media", if unfurl_media { "1" } else { "0" })),
+    ];
+    let url = crate::get_slack_url_for_method("chat.scheduleMessage");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ScheduleMessageError::Client)?;
+    serde_json::from_slice::<ScheduleMessageResponse>(&body)
+        .map_err(|e| ScheduleMessageError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Unfurl a URL that a user posted
 ///
 /// Wraps https://api.slack.com/methods/chat.unfurl
-
 pub fn unfurl<R>(
     client: &R,
     token: &str,
@@ -148,7 +288,7 @@ pub fn unfurl<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("channel", request.channel)),
         Some(("ts", request.ts)),
@@ -160,22 +300,19 @@ where
             )
         }),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("chat.unfurl");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(UnfurlError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<UnfurlResponse>(&result)
-                .map_err(|e| UnfurlError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(UnfurlError::Client)?;
+    serde_json::from_slice::<UnfurlResponse>(&body)
+        .map_err(|e| UnfurlError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Updates a message.
 ///
 /// Wraps https://api.slack.com/methods/chat.update
-
 pub fn update<R>(
     client: &R,
     token: &str,
@@ -185,14 +322,17 @@ where
     R: SlackWebRequestSender,
 {
     let ts = request.ts.to_param_value();
-    let params = vec![
+    let attachments = request.attachments.map(crate::to_json_param);
+    let blocks = request.blocks.map(crate::to_json_param);
+    let params = [
         Some(("token", token)),
         Some(("ts", &ts[..])),
         Some(("channel", request.channel)),
         Some(("text", request.text)),
-        request
-            .attachments
-            .map(|attachments| ("attachments", attachments)),
+        attachments
+            .as_ref()
+            .map(|attachments| ("attachments", &attachments[..])),
+        blocks.as_ref().map(|blocks| ("blocks", &blocks[..])),
         request.parse.map(|parse| ("parse", parse)),
         request
             .link_names
@@ -200,15 +340,14 @@ where
         request
             .as_user
             .map(|as_user| ("as_user", if as_user { "1" } else { "0" })),
+        request.file_ids.map(|file_ids| ("file_ids", file_ids)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("chat.update");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(UpdateError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result)
-                .map_err(|e| UpdateError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(UpdateError::Client)?;
+    serde_json::from_slice::<UpdateResponse>(&body)
+        .map_err(|e| UpdateError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -0,0 +1,378 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage conversations of all types: public and private channels, direct messages and multi-person direct messages.
+
+pub use crate::mod_types::conversations_types::*;
+pub use crate::sync::ext::conversations::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Initiates a public or private channel-based conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.create
+pub fn create<R>(
+    client: &R,
+    token: &str,
+    request: &CreateRequest<'_>,
+) -> Result<CreateResponse, CreateError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("name", request.name)),
+        request
+            .is_private
+            .map(|is_private| ("is_private", if is_private { "1" } else { "0" })),
+        request.team_id.map(|team_id| ("team_id", team_id)),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.create");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(CreateError::Client)?;
+    serde_json::from_slice::<CreateResponse>(&body)
+        .map_err(|e| CreateError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Fetches a conversation's history of messages and events.
+///
+/// Wraps https://api.slack.com/methods/conversations.history
+pub fn history<R>(
+    client: &R,
+    token: &str,
+    request: &HistoryRequest<'_>,
+) -> Result<HistoryResponse, HistoryError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let latest = request.latest.as_ref().map(|t| t.to_param_value());
+    let limit = request.limit.map(|limit| limit.to_string());
+    let oldest = request.oldest.as_ref().map(|t| t.to_param_value());
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        request
+            .inclusive
+            .map(|inclusive| ("inclusive", if inclusive { "1" } else { "0" })),
+        latest.as_ref().map(|latest| ("latest", &latest[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.history");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(HistoryError::Client)?;
+    serde_json::from_slice::<HistoryResponse>(&body)
+        .map_err(|e| HistoryError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Retrieve a thread of messages posted to a conversation
+///
+/// Wraps https://api.slack.com/methods/conversations.replies
+pub fn replies<R>(
+    client: &R,
+    token: &str,
+    request: &RepliesRequest<'_>,
+) -> Result<RepliesResponse, RepliesError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let ts = request.ts.to_param_value();
+    let latest = request.latest.as_ref().map(|t| t.to_param_value());
+    let limit = request.limit.map(|limit| limit.to_string());
+    let oldest = request.oldest.as_ref().map(|t| t.to_param_value());
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        Some(("ts", &ts[..])),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        request
+            .inclusive
+            .map(|inclusive| ("inclusive", if inclusive { "1" } else { "0" })),
+        latest.as_ref().map(|latest| ("latest", &latest[..])),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        oldest.as_ref().map(|oldest| ("oldest", &oldest[..])),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.replies");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(RepliesError::Client)?;
+    serde_json::from_slice::<RepliesResponse>(&body)
+        .map_err(|e| RepliesError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Invites users to a channel.
+///
+/// Wraps https://api.slack.com/methods/conversations.invite
+pub fn invite<R>(
+    client: &R,
+    token: &str,
+    request: &InviteRequest<'_>,
+) -> Result<InviteResponse, InviteError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        Some(("users", request.users)),
+        request
+            .force
+            .map(|force| ("force", if force { "1" } else { "0" })),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.invite");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(InviteError::Client)?;
+    serde_json::from_slice::<InviteResponse>(&body)
+        .map_err(|e| InviteError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Removes a user from a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.kick
+pub fn kick<R>(
+    client: &R,
+    token: &str,
+    request: &KickRequest<'_>,
+) -> Result<KickResponse, KickError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        Some(("user", request.user)),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.kick");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(KickError::Client)?;
+    serde_json::from_slice::<KickResponse>(&body)
+        .map_err(|e| KickError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Lists all channels in a Slack team.
+///
+/// Wraps https://api.slack.com/methods/conversations.list
+pub fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let types = request.types.as_ref().map(|t| t.to_param_value());
+    let params = [
+        Some(("token", token)),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        request
+            .exclude_archived
+            .map(|exclude_archived| ("exclude_archived", if exclude_archived { "1" } else { "0" })),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        request.team_id.map(|team_id| ("team_id", team_id)),
+        types.as_ref().map(|types| ("types", &types[..])),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Retrieve members of a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.members
+pub fn members<R>(
+    client: &R,
+    token: &str,
+    request: &MembersRequest<'_>,
+) -> Result<MembersResponse, MembersError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.members");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(MembersError::Client)?;
+    serde_json::from_slice::<MembersResponse>(&body)
+        .map_err(|e| MembersError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Opens or resumes a direct message or multi-person direct message.
+///
+/// Wraps https://api.slack.com/methods/conversations.open
+pub fn open<R>(
+    client: &R,
+    token: &str,
+    request: &OpenRequest<'_>,
+) -> Result<OpenResponse, OpenError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        request.channel.map(|channel| ("channel", channel)),
+        request
+            .return_im
+            .map(|return_im| ("return_im", if return_im { "1" } else { "0" })),
+        request.users.map(|users| ("users", users)),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.open");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(OpenError::Client)?;
+    serde_json::from_slice::<OpenResponse>(&body)
+        .map_err(|e| OpenError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Retrieve information about a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.info
+pub fn info<R>(
+    client: &R,
+    token: &str,
+    request: &InfoRequest<'_>,
+) -> Result<InfoResponse, InfoError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        request
+            .include_locale
+            .map(|include_locale| ("include_locale", if include_locale { "1" } else { "0" })),
+        request.include_num_members.map(|include_num_members| {
+            (
+                "include_num_members",
+                if include_num_members { "1" } else { "0" },
+            )
+        }),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Sets the read cursor in a channel.
+///
+/// Wraps https://api.slack.com/methods/conversations.mark
+pub fn mark<R>(
+    client: &R,
+    token: &str,
+    request: &MarkRequest<'_>,
+) -> Result<MarkResponse, MarkError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let ts = request.ts.to_param_value();
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        Some(("ts", &ts[..])),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.mark");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(MarkError::Client)?;
+    serde_json::from_slice::<MarkResponse>(&body)
+        .map_err(|e| MarkError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Sets the purpose for a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.setPurpose
+pub fn set_purpose<R>(
+    client: &R,
+    token: &str,
+    request: &SetPurposeRequest<'_>,
+) -> Result<SetPurposeResponse, SetPurposeError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        Some(("purpose", request.purpose)),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.setPurpose");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(SetPurposeError::Client)?;
+    serde_json::from_slice::<SetPurposeResponse>(&body)
+        .map_err(|e| SetPurposeError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Sets the topic for a conversation.
+///
+/// Wraps https://api.slack.com/methods/conversations.setTopic
+pub fn set_topic<R>(
+    client: &R,
+    token: &str,
+    request: &SetTopicRequest<'_>,
+) -> Result<SetTopicResponse, SetTopicError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("channel", request.channel)),
+        Some(("topic", request.topic)),
+    ];
+    let url = crate::get_slack_url_for_method("conversations.setTopic");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(SetTopicError::Client)?;
+    serde_json::from_slice::<SetTopicResponse>(&body)
+        .map_err(|e| SetTopicError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
@@ -0,0 +1,44 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Open legacy dialogs, which modals have superseded.
+
+pub use crate::mod_types::dialog_types::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Open a dialog with a user
+///
+/// Wraps https://api.slack.com/methods/dialog.open
+pub fn open<R>(
+    client: &R,
+    token: &str,
+    request: &OpenRequest<'_>,
+) -> Result<OpenResponse, OpenError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("dialog", request.dialog)),
+        Some(("trigger_id", request.trigger_id.as_str())),
+    ];
+    let url = crate::get_slack_url_for_method("dialog.open");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(OpenError::Client)?;
+    serde_json::from_slice::<OpenResponse>(&body)
+        .map_err(|e| OpenError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
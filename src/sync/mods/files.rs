@@ -15,12 +15,12 @@
 //! Get info on files uploaded to Slack, upload new files to Slack.
 
 pub use crate::mod_types::files_types::*;
+pub use crate::sync::ext::files::*;
 use crate::sync::requests::SlackWebRequestSender;
 
 /// Deletes a file.
 ///
 /// Wraps https://api.slack.com/methods/files.delete
-
 pub fn delete<R>(
     client: &R,
     token: &str,
@@ -29,23 +29,20 @@ pub fn delete<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("file", request.file))];
     let url = crate::get_slack_url_for_method("files.delete");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(DeleteError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result)
-                .map_err(|e| DeleteError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(DeleteError::Client)?;
+    serde_json::from_slice::<DeleteResponse>(&body)
+        .map_err(|e| DeleteError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets information about a team file.
 ///
 /// Wraps https://api.slack.com/methods/files.info
-
 pub fn info<R>(
     client: &R,
     token: &str,
@@ -56,28 +53,28 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
-    let params = vec![
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = [
         Some(("token", token)),
         Some(("file", request.file)),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("files.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists & filters team files.
 ///
 /// Wraps https://api.slack.com/methods/files.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -86,36 +83,34 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-    let ts_from = request.ts_from.map(|ts_from| ts_from.to_string());
-    let ts_to = request.ts_to.map(|ts_to| ts_to.to_string());
+    let ts_from = request.ts_from.as_ref().map(|t| t.to_param_value());
+    let ts_to = request.ts_to.as_ref().map(|t| t.to_param_value());
+    let types = request.types.as_ref().map(|t| t.to_param_value());
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.user.map(|user| ("user", user)),
         request.channel.map(|channel| ("channel", channel)),
         ts_from.as_ref().map(|ts_from| ("ts_from", &ts_from[..])),
         ts_to.as_ref().map(|ts_to| ("ts_to", &ts_to[..])),
-        request.types.map(|types| ("types", types)),
+        types.as_ref().map(|types| ("types", &types[..])),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("files.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Revokes public/external sharing access for a file
 ///
 /// Wraps https://api.slack.com/methods/files.revokePublicURL
-
 pub fn revoke_public_url<R>(
     client: &R,
     token: &str,
@@ -124,23 +119,20 @@ pub fn revoke_public_url<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("file", request.file))];
     let url = crate::get_slack_url_for_method("files.revokePublicURL");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(RevokePublicURLError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RevokePublicURLResponse>(&result)
-                .map_err(|e| RevokePublicURLError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(RevokePublicURLError::Client)?;
+    serde_json::from_slice::<RevokePublicURLResponse>(&body)
+        .map_err(|e| RevokePublicURLError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Enables a file for public/external sharing.
 ///
 /// Wraps https://api.slack.com/methods/files.sharedPublicURL
-
 pub fn shared_public_url<R>(
     client: &R,
     token: &str,
@@ -149,15 +141,13 @@ pub fn shared_public_url<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![Some(("token", token)), Some(("file", request.file))];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
+    let params = [Some(("token", token)), Some(("file", request.file))];
     let url = crate::get_slack_url_for_method("files.sharedPublicURL");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(SharedPublicURLError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<SharedPublicURLResponse>(&result)
-                .map_err(|e| SharedPublicURLError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(SharedPublicURLError::Client)?;
+    serde_json::from_slice::<SharedPublicURLResponse>(&body)
+        .map_err(|e| SharedPublicURLError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -18,7 +18,7 @@ use crate::sync::requests::SlackWebRequestSender;
 /// Add a comment to an existing file.
 ///
 /// Wraps https://api.slack.com/methods/files.comments.add
-
+#[deprecated(note = "File comments are retired; reply in the file's message thread instead")]
 pub fn add<R>(
     client: &R,
     token: &str,
@@ -27,27 +27,25 @@ pub fn add<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("file", request.file)),
         Some(("comment", request.comment)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("files.comments.add");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(AddError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(AddError::Client)?;
+    serde_json::from_slice::<AddResponse>(&body)
+        .map_err(|e| AddError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Deletes an existing comment on a file.
 ///
 /// Wraps https://api.slack.com/methods/files.comments.delete
-
+#[deprecated(note = "File comments are retired; reply in the file's message thread instead")]
 pub fn delete<R>(
     client: &R,
     token: &str,
@@ -56,27 +54,25 @@ pub fn delete<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("file", request.file)),
         Some(("id", request.id)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("files.comments.delete");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(DeleteError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<DeleteResponse>(&result)
-                .map_err(|e| DeleteError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(DeleteError::Client)?;
+    serde_json::from_slice::<DeleteResponse>(&body)
+        .map_err(|e| DeleteError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Edit an existing file comment.
 ///
 /// Wraps https://api.slack.com/methods/files.comments.edit
-
+#[deprecated(note = "File comments are retired; reply in the file's message thread instead")]
 pub fn edit<R>(
     client: &R,
     token: &str,
@@ -85,20 +81,18 @@ pub fn edit<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("file", request.file)),
         Some(("id", request.id)),
         Some(("comment", request.comment)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("files.comments.edit");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(EditError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<EditResponse>(&result)
-                .map_err(|e| EditError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(EditError::Client)?;
+    serde_json::from_slice::<EditResponse>(&body)
+        .map_err(|e| EditError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -1,8 +1,25 @@
+pub mod admin_auth_policy;
+pub mod admin_conversations_ekm;
+pub mod admin_conversations_restrict_access;
+pub mod admin_functions;
+pub mod admin_functions_permissions;
+pub mod admin_roles;
+pub mod admin_usergroups;
+pub mod admin_users;
+pub mod admin_users_session;
+pub mod admin_workflows;
+pub mod admin_workflows_collaborators;
+pub mod admin_workflows_permissions;
 pub mod api;
+pub mod assistant_threads;
 pub mod auth;
+pub mod bookmarks;
 pub mod bots;
 pub mod channels;
 pub mod chat;
+pub mod chat_scheduled_messages;
+pub mod conversations;
+pub mod dialog;
 pub mod dnd;
 pub mod emoji;
 pub mod files_comments;
@@ -16,10 +33,17 @@ pub mod reactions;
 pub mod reminders;
 pub mod rtm;
 pub mod search;
+pub mod slack_lists;
+pub mod slack_lists_items;
 pub mod stars;
 pub mod team;
+pub mod team_external_teams;
+pub mod team_preferences;
 pub mod team_profile;
 pub mod usergroups;
 pub mod usergroups_users;
 pub mod users;
-pub mod users_profile;
\ No newline at end of file
+#[cfg(feature = "unstable-api")]
+pub mod users_admin;
+pub mod users_profile;
+pub mod views;
\ No newline at end of file
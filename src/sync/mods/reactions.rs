@@ -13,12 +13,12 @@
 //=============================================================================
 
 pub use crate::mod_types::reactions_types::*;
+pub use crate::sync::ext::reactions::*;
 use crate::sync::requests::SlackWebRequestSender;
 
 /// Adds a reaction to an item.
 ///
 /// Wraps https://api.slack.com/methods/reactions.add
-
 pub fn add<R>(
     client: &R,
     token: &str,
@@ -28,7 +28,7 @@ where
     R: SlackWebRequestSender,
 {
     let timestamp = request.timestamp.as_ref().map(|t| t.to_param_value());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("name", request.name)),
         request.file.map(|file| ("file", file)),
@@ -40,22 +40,19 @@ where
             .as_ref()
             .map(|timestamp| ("timestamp", &timestamp[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("reactions.add");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(AddError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AddResponse>(&result)
-                .map_err(|e| AddError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(AddError::Client)?;
+    serde_json::from_slice::<AddResponse>(&body)
+        .map_err(|e| AddError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets reactions for an item.
 ///
 /// Wraps https://api.slack.com/methods/reactions.get
-
 pub fn get<R>(
     client: &R,
     token: &str,
@@ -65,7 +62,7 @@ where
     R: SlackWebRequestSender,
 {
     let timestamp = request.timestamp.as_ref().map(|t| t.to_param_value());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.file.map(|file| ("file", file)),
         request
@@ -79,22 +76,19 @@ where
             .full
             .map(|full| ("full", if full { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("reactions.get");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(GetError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<GetResponse>(&result)
-                .map_err(|e| GetError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(GetError::Client)?;
+    serde_json::from_slice::<GetResponse>(&body)
+        .map_err(|e| GetError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Lists reactions made by a user.
 ///
 /// Wraps https://api.slack.com/methods/reactions.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -105,7 +99,8 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
-    let params = vec![
+    let limit = request.limit.map(|limit| limit.to_string());
+    let params = [
         Some(("token", token)),
         request.user.map(|user| ("user", user)),
         request
@@ -113,23 +108,22 @@ where
             .map(|full| ("full", if full { "1" } else { "0" })),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("reactions.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Removes a reaction from an item.
 ///
 /// Wraps https://api.slack.com/methods/reactions.remove
-
 pub fn remove<R>(
     client: &R,
     token: &str,
@@ -139,7 +133,7 @@ where
     R: SlackWebRequestSender,
 {
     let timestamp = request.timestamp.as_ref().map(|t| t.to_param_value());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("name", request.name)),
         request.file.map(|file| ("file", file)),
@@ -151,14 +145,12 @@ where
             .as_ref()
             .map(|timestamp| ("timestamp", &timestamp[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("reactions.remove");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(RemoveError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<RemoveResponse>(&result)
-                .map_err(|e| RemoveError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(RemoveError::Client)?;
+    serde_json::from_slice::<RemoveResponse>(&body)
+        .map_err(|e| RemoveError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -18,27 +18,24 @@ use crate::sync::requests::SlackWebRequestSender;
 /// Starts a Real Time Messaging session.
 ///
 /// Wraps https://api.slack.com/methods/rtm.connect
-
 pub fn connect<R>(client: &R, token: &str) -> Result<ConnectResponse, ConnectError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("rtm.connect");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(ConnectError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ConnectResponse>(&result)
-                .map_err(|e| ConnectError::MalformedResponse(result, e))
-        })
+        .send_into(&url, &params[..], &mut body)
+        .map_err(ConnectError::Client)?;
+    serde_json::from_slice::<ConnectResponse>(&body)
+        .map_err(|e| ConnectError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Starts a Real Time Messaging session.
 ///
 /// Wraps https://api.slack.com/methods/rtm.start
-
 pub fn start<R>(
     client: &R,
     token: &str,
@@ -47,7 +44,7 @@ pub fn start<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request
             .no_unreads
@@ -68,14 +65,12 @@ where
             .include_locale
             .map(|include_locale| ("include_locale", if include_locale { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("rtm.start");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(StartError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<StartResponse>(&result)
-                .map_err(|e| StartError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(StartError::Client)?;
+    serde_json::from_slice::<StartResponse>(&body)
+        .map_err(|e| StartError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -13,12 +13,12 @@
 //=============================================================================
 
 pub use crate::mod_types::team_types::*;
+pub use crate::sync::ext::team::*;
 use crate::sync::requests::SlackWebRequestSender;
 
 /// Gets the access logs for the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.accessLogs
-
 pub fn access_logs<R>(
     client: &R,
     token: &str,
@@ -30,28 +30,25 @@ where
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
     let before = request.before.map(|before| before.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
         before.as_ref().map(|before| ("before", &before[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("team.accessLogs");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(AccessLogsError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<AccessLogsResponse>(&result)
-                .map_err(|e| AccessLogsError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(AccessLogsError::Client)?;
+    serde_json::from_slice::<AccessLogsResponse>(&body)
+        .map_err(|e| AccessLogsError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets billable users information for the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.billableInfo
-
 pub fn billable_info<R>(
     client: &R,
     token: &str,
@@ -60,46 +57,41 @@ pub fn billable_info<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request.user.map(|user| ("user", user)),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("team.billableInfo");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(BillableInfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<BillableInfoResponse>(&result)
-                .map_err(|e| BillableInfoError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(BillableInfoError::Client)?;
+    serde_json::from_slice::<BillableInfoResponse>(&body)
+        .map_err(|e| BillableInfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets information about the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.info
-
 pub fn info<R>(client: &R, token: &str) -> Result<InfoResponse, InfoError<R::Error>>
 where
     R: SlackWebRequestSender,
 {
     let params = &[("token", token)];
     let url = crate::get_slack_url_for_method("team.info");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(InfoError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<InfoResponse>(&result)
-                .map_err(|e| InfoError::MalformedResponse(result, e))
-        })
+        .send_into(&url, &params[..], &mut body)
+        .map_err(InfoError::Client)?;
+    serde_json::from_slice::<InfoResponse>(&body)
+        .map_err(|e| InfoError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Gets the integration logs for the current team.
 ///
 /// Wraps https://api.slack.com/methods/team.integrationLogs
-
 pub fn integration_logs<R>(
     client: &R,
     token: &str,
@@ -110,7 +102,7 @@ where
 {
     let count = request.count.map(|count| count.to_string());
     let page = request.page.map(|page| page.to_string());
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request
             .service_id
@@ -123,14 +115,12 @@ where
         count.as_ref().map(|count| ("count", &count[..])),
         page.as_ref().map(|page| ("page", &page[..])),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("team.integrationLogs");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(IntegrationLogsError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<IntegrationLogsResponse>(&result)
-                .map_err(|e| IntegrationLogsError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(IntegrationLogsError::Client)?;
+    serde_json::from_slice::<IntegrationLogsResponse>(&body)
+        .map_err(|e| IntegrationLogsError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -0,0 +1,93 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! List and disconnect the external organizations connected to a workspace through Slack Connect.
+
+pub use crate::mod_types::team_external_teams_types::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Lists the external organizations connected to the workspace.
+///
+/// Wraps https://api.slack.com/methods/team.externalTeams.list
+pub fn list<R>(
+    client: &R,
+    token: &str,
+    request: &ListRequest<'_>,
+) -> Result<ListResponse, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let limit = request.limit.map(|limit| limit.to_string());
+    let slack_connect_pref_filter = request
+        .slack_connect_pref_filter
+        .map(|slack_connect_pref_filter| slack_connect_pref_filter.join(","));
+    let workspace_filter = request
+        .workspace_filter
+        .map(|workspace_filter| workspace_filter.join(","));
+    let params = [
+        Some(("token", token)),
+        request
+            .connection_status_filter
+            .map(|connection_status_filter| ("connection_status_filter", connection_status_filter)),
+        request.cursor.map(|cursor| ("cursor", cursor.as_str())),
+        limit.as_ref().map(|limit| ("limit", &limit[..])),
+        slack_connect_pref_filter
+            .as_ref()
+            .map(|slack_connect_pref_filter| {
+                ("slack_connect_pref_filter", &slack_connect_pref_filter[..])
+            }),
+        request
+            .sort_direction
+            .map(|sort_direction| ("sort_direction", sort_direction)),
+        request
+            .sort_field
+            .map(|sort_field| ("sort_field", sort_field)),
+        workspace_filter
+            .as_ref()
+            .map(|workspace_filter| ("workspace_filter", &workspace_filter[..])),
+    ];
+    let url = crate::get_slack_url_for_method("team.externalTeams.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Disconnects an external organization, removing it from every shared channel.
+///
+/// Wraps https://api.slack.com/methods/team.externalTeams.disconnect
+pub fn disconnect<R>(
+    client: &R,
+    token: &str,
+    request: &DisconnectRequest<'_>,
+) -> Result<DisconnectResponse, DisconnectError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("target_team", request.target_team)),
+    ];
+    let url = crate::get_slack_url_for_method("team.externalTeams.disconnect");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(DisconnectError::Client)?;
+    serde_json::from_slice::<DisconnectResponse>(&body)
+        .map_err(|e| DisconnectError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
@@ -15,12 +15,12 @@
 //! Get info on your team's User Groups.
 
 pub use crate::mod_types::usergroups_types::*;
+pub use crate::sync::ext::usergroups::*;
 use crate::sync::requests::SlackWebRequestSender;
 
 /// Create a User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.create
-
 pub fn create<R>(
     client: &R,
     token: &str,
@@ -29,7 +29,7 @@ pub fn create<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("name", request.name)),
         request.handle.map(|handle| ("handle", handle)),
@@ -41,22 +41,19 @@ where
             .include_count
             .map(|include_count| ("include_count", if include_count { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("usergroups.create");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(CreateError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<CreateResponse>(&result)
-                .map_err(|e| CreateError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(CreateError::Client)?;
+    serde_json::from_slice::<CreateResponse>(&body)
+        .map_err(|e| CreateError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Disable an existing User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.disable
-
 pub fn disable<R>(
     client: &R,
     token: &str,
@@ -65,29 +62,26 @@ pub fn disable<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("usergroup", request.usergroup)),
         request
             .include_count
             .map(|include_count| ("include_count", if include_count { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("usergroups.disable");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(DisableError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<DisableResponse>(&result)
-                .map_err(|e| DisableError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(DisableError::Client)?;
+    serde_json::from_slice::<DisableResponse>(&body)
+        .map_err(|e| DisableError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Enable a User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.enable
-
 pub fn enable<R>(
     client: &R,
     token: &str,
@@ -96,29 +90,26 @@ pub fn enable<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("usergroup", request.usergroup)),
         request
             .include_count
             .map(|include_count| ("include_count", if include_count { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("usergroups.enable");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(EnableError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<EnableResponse>(&result)
-                .map_err(|e| EnableError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(EnableError::Client)?;
+    serde_json::from_slice::<EnableResponse>(&body)
+        .map_err(|e| EnableError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// List all User Groups for a team
 ///
 /// Wraps https://api.slack.com/methods/usergroups.list
-
 pub fn list<R>(
     client: &R,
     token: &str,
@@ -127,7 +118,7 @@ pub fn list<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         request
             .include_disabled
@@ -139,22 +130,19 @@ where
             .include_users
             .map(|include_users| ("include_users", if include_users { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("usergroups.list");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(ListError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<ListResponse>(&result)
-                .map_err(|e| ListError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(ListError::Client)?;
+    serde_json::from_slice::<ListResponse>(&body)
+        .map_err(|e| ListError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
 
 /// Update an existing User Group
 ///
 /// Wraps https://api.slack.com/methods/usergroups.update
-
 pub fn update<R>(
     client: &R,
     token: &str,
@@ -163,7 +151,7 @@ pub fn update<R>(
 where
     R: SlackWebRequestSender,
 {
-    let params = vec![
+    let params = [
         Some(("token", token)),
         Some(("usergroup", request.usergroup)),
         request.name.map(|name| ("name", name)),
@@ -176,14 +164,12 @@ where
             .include_count
             .map(|include_count| ("include_count", if include_count { "1" } else { "0" })),
     ];
-    let params = params.into_iter().filter_map(|x| x).collect::<Vec<_>>();
     let url = crate::get_slack_url_for_method("usergroups.update");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
     client
-        .send(&url, &params[..])
-        .map_err(UpdateError::Client)
-        .and_then(|result| {
-            serde_json::from_str::<UpdateResponse>(&result)
-                .map_err(|e| UpdateError::MalformedResponse(result, e))
-        })
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(UpdateError::Client)?;
+    serde_json::from_slice::<UpdateResponse>(&body)
+        .map_err(|e| UpdateError::MalformedResponse(body.to_string_lossy(), e))
         .and_then(|o| o.into())
 }
@@ -0,0 +1,59 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Manage workspace members through Slack's undocumented admin endpoints.
+//!
+//! These endpoints are not part of Slack's published API and may change or be removed without notice. They are only available with the `unstable-api` feature.
+
+pub use crate::mod_types::users_admin_types::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Invites a user to the workspace by email. Not available on Enterprise Grid, which uses admin.users.invite instead.
+///
+/// Wraps https://api.slack.com/methods/users.admin.invite
+pub fn invite<R>(
+    client: &R,
+    token: &str,
+    request: &InviteRequest<'_>,
+) -> Result<InviteResponse, InviteError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let params = [
+        Some(("token", token)),
+        Some(("email", request.email)),
+        request.channels.map(|channels| ("channels", channels)),
+        request
+            .first_name
+            .map(|first_name| ("first_name", first_name)),
+        request.last_name.map(|last_name| ("last_name", last_name)),
+        request
+            .restricted
+            .map(|restricted| ("restricted", if restricted { "1" } else { "0" })),
+        request
+            .ultra_restricted
+            .map(|ultra_restricted| ("ultra_restricted", if ultra_restricted { "1" } else { "0" })),
+        request
+            .resend
+            .map(|resend| ("resend", if resend { "1" } else { "0" })),
+    ];
+    let url = crate::get_slack_url_for_method("users.admin.invite");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(InviteError::Client)?;
+    serde_json::from_slice::<InviteResponse>(&body)
+        .map_err(|e| InviteError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
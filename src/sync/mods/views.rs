@@ -0,0 +1,101 @@
+//=============================================================================
+//
+//                    WARNING: This file is AUTO-GENERATED
+//
+// Do not make changes directly to this file.
+//
+// If you would like to make a change to the library, please update the schema
+// definitions at https://github.com/slack-rs/slack-api-schemas
+//
+// If you would like to make a change how the library was generated,
+// please edit https://github.com/slack-rs/slack-rs-api/tree/master/codegen
+//
+//=============================================================================
+
+//! Publish and manage views, such as App Home tabs.
+
+pub use crate::mod_types::views_types::*;
+pub use crate::sync::ext::views::*;
+use crate::sync::requests::SlackWebRequestSender;
+
+/// Open a view for a user.
+///
+/// Wraps https://api.slack.com/methods/views.open
+pub fn open<R>(
+    client: &R,
+    token: &str,
+    request: &OpenRequest<'_>,
+) -> Result<OpenResponse, OpenError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let view = crate::to_json_param(request.view);
+    let params = [
+        Some(("token", token)),
+        Some(("trigger_id", request.trigger_id.as_str())),
+        Some(("view", &view[..])),
+    ];
+    let url = crate::get_slack_url_for_method("views.open");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(OpenError::Client)?;
+    serde_json::from_slice::<OpenResponse>(&body)
+        .map_err(|e| OpenError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Publish a static view for a User.
+///
+/// Wraps https://api.slack.com/methods/views.publish
+pub fn publish<R>(
+    client: &R,
+    token: &str,
+    request: &PublishRequest<'_>,
+) -> Result<PublishResponse, PublishError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let view = crate::to_json_param(request.view);
+    let params = [
+        Some(("token", token)),
+        Some(("user_id", request.user_id)),
+        Some(("view", &view[..])),
+        request.hash.map(|hash| ("hash", hash)),
+    ];
+    let url = crate::get_slack_url_for_method("views.publish");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(PublishError::Client)?;
+    serde_json::from_slice::<PublishResponse>(&body)
+        .map_err(|e| PublishError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
+
+/// Push a view onto the stack of a root view.
+///
+/// Wraps https://api.slack.com/methods/views.push
+pub fn push<R>(
+    client: &R,
+    token: &str,
+    request: &PushRequest<'_>,
+) -> Result<PushResponse, PushError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let view = crate::to_json_param(request.view);
+    let params = [
+        Some(("token", token)),
+        Some(("trigger_id", request.trigger_id.as_str())),
+        Some(("view", &view[..])),
+    ];
+    let url = crate::get_slack_url_for_method("views.push");
+    let mut body = crate::response_buffer::ResponseBuffer::take();
+    client
+        .send_into(&url, IntoIterator::into_iter(params).flatten(), &mut body)
+        .map_err(PushError::Client)?;
+    serde_json::from_slice::<PushResponse>(&body)
+        .map_err(|e| PushError::MalformedResponse(body.to_string_lossy(), e))
+        .and_then(|o| o.into())
+}
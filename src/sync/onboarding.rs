@@ -0,0 +1,105 @@
+//! Brings a user into a workspace, with their channels and User Groups, in one call.
+
+pub use crate::onboarding_types::*;
+
+use super::requests::SlackWebRequestSender;
+use crate::mod_types::admin_users_types::{AssignError, AssignRequest, InviteRequest};
+use crate::mod_types::conversations_types::{InviteError, InviteRequest as ChannelInviteRequest};
+use crate::mod_types::users_types::{LookupByEmailError, LookupByEmailRequest};
+use crate::sync::usergroups::add_member;
+use crate::sync::{admin_users, conversations, users};
+
+/// Brings the user with `email` into a workspace and adds them to channels and User Groups.
+///
+/// Someone without a Slack account is invited with `admin.users.invite`, joining the channels
+/// when they accept; their User Groups are left pending. A member of the organization is added
+/// to the workspace with `admin.users.assign`, or invited to each channel if they're already a
+/// member of it, and then added to each User Group.
+///
+/// The token needs the `admin.users:write` scope. Once the user is in the workspace or invited,
+/// failures are collected in the report instead of stopping the rest.
+pub fn invite_user<R>(
+    client: &R,
+    token: &str,
+    request: &InviteUserRequest<'_>,
+) -> Result<OnboardingReport<R::Error>, InviteUserError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let lookup = users::lookup_by_email(
+        client,
+        token,
+        &LookupByEmailRequest {
+            email: request.email,
+        },
+    );
+    let user_id = match lookup {
+        Ok(response) => response.user.and_then(|u| u.id),
+        Err(LookupByEmailError::UsersNotFound) => None,
+        Err(e) => return Err(InviteUserError::Lookup(e)),
+    };
+
+    let user_id = match user_id {
+        Some(user_id) => user_id,
+        None => {
+            admin_users::invite(
+                client,
+                token,
+                &InviteRequest {
+                    channel_ids: request.channel_ids,
+                    email: request.email,
+                    team_id: request.team_id,
+                    custom_message: request.custom_message,
+                    real_name: request.real_name,
+                    ..InviteRequest::default()
+                },
+            )
+            .map_err(InviteUserError::Invite)?;
+            let mut report = OnboardingReport::new(Onboarded::Invited);
+            report.usergroups_pending = request.usergroups.iter().map(|&g| g.to_owned()).collect();
+            return Ok(report);
+        }
+    };
+
+    let assign = admin_users::assign(
+        client,
+        token,
+        &AssignRequest {
+            team_id: request.team_id,
+            user_id: &user_id,
+            channel_ids: Some(request.channel_ids).filter(|c| !c.is_empty()),
+            ..AssignRequest::default()
+        },
+    );
+    let mut report = match assign {
+        Ok(_) => OnboardingReport::new(Onboarded::Assigned(user_id.clone())),
+        Err(AssignError::UserAlreadyTeamMember) => {
+            let mut report = OnboardingReport::new(Onboarded::AlreadyMember(user_id.clone()));
+            for &channel in request.channel_ids {
+                let invite = conversations::invite(
+                    client,
+                    token,
+                    &ChannelInviteRequest {
+                        channel,
+                        users: &user_id,
+                        force: None,
+                    },
+                );
+                match invite {
+                    Ok(_) | Err(InviteError::AlreadyInChannel) => {}
+                    Err(e) => report.channels_failed.push((channel.to_owned(), e)),
+                }
+            }
+            report
+        }
+        Err(e) => return Err(InviteUserError::Assign(e)),
+    };
+
+    for &usergroup in request.usergroups {
+        match add_member(client, token, usergroup, &user_id) {
+            Ok(_) => report.usergroups_added.push(usergroup.to_owned()),
+            Err(e) => report.usergroups_failed.push((usergroup.to_owned(), e)),
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,40 @@
+//! A durable outbox for fire-and-forget calls, which survive restarts until Slack accepts them.
+
+pub use crate::outbox_types::*;
+
+use super::requests::SlackWebRequestSender;
+use crate::outbox_types::{outcome, Outcome};
+
+/// Sends the calls waiting in `outbox` with `token`, oldest first.
+///
+/// Flushing stops early, leaving the rest waiting, when Slack rate limits a call or the client
+/// fails to send one.
+pub fn flush<S, R>(
+    outbox: &Outbox<S>,
+    client: &R,
+    token: &str,
+) -> Result<FlushReport, FlushError<S::Error, R::Error>>
+where
+    S: OutboxStore,
+    R: SlackWebRequestSender,
+{
+    let pending = outbox.store.pending().map_err(FlushError::Store)?;
+    let mut report = FlushReport::default();
+    for (i, entry) in pending.iter().enumerate() {
+        let url = crate::get_slack_url_for_method(&entry.method);
+        let params = Some(("token", token))
+            .into_iter()
+            .chain(entry.params.iter().map(|(k, v)| (&k[..], &v[..])));
+        let body = client.send(&url, params).map_err(FlushError::Client)?;
+        match outcome(&body) {
+            Outcome::Sent => report.sent.push(entry.key.clone()),
+            Outcome::Rejected(error) => report.rejected.push((entry.key.clone(), error)),
+            Outcome::Retry => {
+                report.pending = pending.len() - i;
+                return Ok(report);
+            }
+        }
+        outbox.store.remove(&entry.key).map_err(FlushError::Store)?;
+    }
+    Ok(report)
+}
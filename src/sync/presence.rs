@@ -0,0 +1,76 @@
+//! Watching users' presence by polling `users.getPresence`, for apps without RTM.
+
+pub use crate::presence_types::*;
+
+use std::thread;
+use std::time::{Duration, Instant};
+
+use super::requests::{RateLimitRetry, SlackWebRequestSender};
+use super::users;
+use crate::CancelToken;
+
+/// Polls the presence of each of the poller's users once, returning the changes since the last
+/// poll.
+///
+/// Rate limited calls are retried as the poller's `retry_policy` allows.
+pub fn poll<R>(
+    client: &R,
+    token: &str,
+    poller: &mut PresencePoller,
+) -> Result<Vec<PresenceChanged>, users::GetPresenceError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+{
+    let client = &RateLimitRetry::new(client).policy(poller.retry_policy.clone());
+    let mut changes = Vec::new();
+    for (i, user) in poller.users.clone().iter().enumerate() {
+        if i > 0 {
+            thread::sleep(poller.call_interval);
+        }
+        let response = users::get_presence(client, token, &users::GetPresenceRequest { user })?;
+        if let Some(presence) = response.presence {
+            changes.extend(poller.record(user, presence.as_str().into()));
+        }
+    }
+    Ok(changes)
+}
+
+/// Polls the presence of the poller's users every `interval` until `cancel` is cancelled, passing
+/// each change to `on_change`.
+///
+/// Returns the first error a poll fails with, after which the poller can be run again.
+pub fn run<R, F>(
+    client: &R,
+    token: &str,
+    poller: &mut PresencePoller,
+    cancel: &CancelToken,
+    mut on_change: F,
+) -> Result<(), users::GetPresenceError<R::Error>>
+where
+    R: SlackWebRequestSender + Sync,
+    F: FnMut(PresenceChanged),
+{
+    while !cancel.is_cancelled() {
+        poll(client, token, poller)?
+            .into_iter()
+            .for_each(&mut on_change);
+        if wait(poller.interval, cancel) {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Waits for `duration`, returning early with `true` if `cancel` is cancelled.
+fn wait(duration: Duration, cancel: &CancelToken) -> bool {
+    // Checks for cancellation a few times a second rather than sleeping through the wait.
+    let until = Instant::now() + duration;
+    while !cancel.is_cancelled() {
+        let now = Instant::now();
+        if now >= until {
+            return false;
+        }
+        thread::sleep((until - now).min(Duration::from_millis(250)));
+    }
+    true
+}
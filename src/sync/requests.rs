@@ -1,13 +1,15 @@
 //! Functionality for sending requests to Slack.
 
-use std::{borrow::Borrow, error};
+use std::{borrow::Borrow, error, io::Read, thread};
+
+use crate::ext_types::files::UploadProgress;
 
 /// Functionality for sending authenticated and unauthenticated requests to Slack via HTTP.
 ///
 /// If you do not have a custom client to integrate with and just want to send requests, use
 /// the [`default_client()`] function to get a simple request sender.
 pub trait SlackWebRequestSender {
-    type Error: error::Error;
+    type Error: error::Error + Send + Sync + 'static;
 
     /// Make an API call to Slack. Takes a map of parameters that get appended to the request as query
     /// params.
@@ -18,15 +20,144 @@ pub trait SlackWebRequestSender {
         V: AsRef<str>,
         I::Item: Borrow<(K, V)>,
         S: AsRef<str> + Send;
+
+    /// Make an API call like [`send()`](Self::send), also returning the HTTP status of the
+    /// response if the sender knows it. Unless overridden the status is `None`.
+    fn send_with_status<I, K, V, S>(
+        &self,
+        method: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        self.send(method, params).map(|body| (None, body))
+    }
+
+    /// Make an API call like [`send_with_status()`](Self::send_with_status), reading the response
+    /// body into `body` in place of its contents, so one buffer can serve many calls. Unless
+    /// overridden the `String` from `send_with_status()` becomes the new `body`.
+    fn send_into<I, K, V, S>(
+        &self,
+        method: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (status, response) = self.send_with_status(method, params)?;
+        *body = response.into_bytes();
+        Ok(status)
+    }
+}
+
+/// Lets helpers wrap a borrowed sender, such as in a [`RateLimitRetry`].
+impl<R> SlackWebRequestSender for &R
+where
+    R: SlackWebRequestSender + ?Sized,
+{
+    type Error = R::Error;
+
+    fn send<I, K, V, S>(&self, method: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        (**self).send(method, params)
+    }
+
+    fn send_with_status<I, K, V, S>(
+        &self,
+        method: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        (**self).send_with_status(method, params)
+    }
+
+    fn send_into<I, K, V, S>(
+        &self,
+        method: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        (**self).send_into(method, params, body)
+    }
+}
+
+/// A sender that can hand back a response body as it arrives, so large responses can be parsed
+/// without buffering them. See [`crate::sync::streaming`].
+pub trait SlackWebStreamSender: SlackWebRequestSender {
+    type Body: Read;
+
+    /// Make an API call to Slack like [`send()`](SlackWebRequestSender::send), returning a reader
+    /// over the response body.
+    fn send_streaming<I, K, V, S>(&self, method: S, params: I) -> Result<Self::Body, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send;
+}
+
+/// A sender that can stream a request body, for uploading files and large messages.
+///
+/// Bodies are read and sent [`UPLOAD_CHUNK_SIZE`](crate::sync::files::UPLOAD_CHUNK_SIZE) bytes at
+/// a time, so an upload doesn't hold the whole file in memory.
+pub trait SlackWebUploadSender: SlackWebRequestSender {
+    /// POSTs the `length` bytes read from `body` to `url` as `content_type`, returning the response
+    /// body. `on_progress` is called each time a chunk of the body has been sent.
+    fn upload<B>(
+        &self,
+        url: &str,
+        content_type: &str,
+        length: u64,
+        body: B,
+        on_progress: &dyn Fn(UploadProgress),
+    ) -> Result<String, Self::Error>
+    where
+        B: Read;
 }
 
 #[cfg(feature = "reqwest_blocking")]
 mod reqwest_support {
     pub use self::reqwest::Error;
+    use reqwest::header::CONTENT_TYPE;
     use reqwest_ as reqwest;
     use std::borrow::Borrow;
+    use std::io::{self, Cursor, Read};
+    use std::sync::mpsc::{sync_channel, Receiver};
+    use std::thread;
+    use std::time::Duration;
 
-    use super::SlackWebRequestSender;
+    use super::{SlackWebRequestSender, SlackWebStreamSender, SlackWebUploadSender};
+    use crate::ext_types::files::{UploadProgress, UPLOAD_CHUNK_SIZE};
 
     type Client = reqwest::blocking::Client;
 
@@ -34,6 +165,40 @@ mod reqwest_support {
         type Error = reqwest::Error;
 
         fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            let (_, body) = self.send_with_status(method_url, params)?;
+            Ok(body)
+        }
+
+        fn send_with_status<I, K, V, S>(
+            &self,
+            method_url: S,
+            params: I,
+        ) -> Result<(Option<u16>, String), Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            let mut body = Vec::new();
+            let status = self.send_into(method_url, params, &mut body)?;
+            Ok((status, crate::response_buffer::into_string(body)))
+        }
+
+        fn send_into<I, K, V, S>(
+            &self,
+            method_url: S,
+            params: I,
+            body: &mut Vec<u8>,
+        ) -> Result<Option<u16>, Self::Error>
         where
             I: IntoIterator + Send,
             K: AsRef<str>,
@@ -45,12 +210,146 @@ mod reqwest_support {
 
             url.query_pairs_mut().extend_pairs(params);
 
-            Ok(self.get(url).send()?.text()?)
+            let mut response = self.get(url).send()?;
+            let status = Some(response.status().as_u16());
+            body.clear();
+            if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                let retry_after = response
+                    .headers()
+                    .get(reqwest::header::RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .map(str::to_owned);
+                let text = response.text()?;
+                *body =
+                    crate::rate_limit::with_retry_after(text, retry_after.as_deref()).into_bytes();
+                return Ok(status);
+            }
+            response.copy_to(body)?;
+            Ok(status)
+        }
+    }
+
+    impl SlackWebStreamSender for Client {
+        type Body = reqwest::blocking::Response;
+
+        fn send_streaming<I, K, V, S>(
+            &self,
+            method_url: S,
+            params: I,
+        ) -> Result<Self::Body, Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            let mut url = reqwest::Url::parse(method_url.as_ref()).expect("Unable to parse url");
+
+            url.query_pairs_mut().extend_pairs(params);
+
+            self.get(url).send()
+        }
+    }
+
+    impl SlackWebUploadSender for Client {
+        fn upload<B>(
+            &self,
+            url: &str,
+            content_type: &str,
+            length: u64,
+            mut body: B,
+            on_progress: &dyn Fn(UploadProgress),
+        ) -> Result<String, Self::Error>
+        where
+            B: Read,
+        {
+            // The request is sent from another thread, which takes the chunks read here through
+            // a channel without room for any, so the next chunk is only read once the connection
+            // has taken the last.
+            let (chunks, receiver) = sync_channel(0);
+            thread::scope(|scope| {
+                let response = scope.spawn(move || {
+                    let body = ChunkReader {
+                        chunks: receiver,
+                        chunk: Cursor::default(),
+                    };
+                    self.post(url)
+                        .header(CONTENT_TYPE, content_type)
+                        .body(reqwest::blocking::Body::sized(body, length))
+                        .send()
+                });
+                let mut buf = vec![0; UPLOAD_CHUNK_SIZE];
+                let mut sent = 0;
+                loop {
+                    let chunk = match body.read(&mut buf) {
+                        Ok(0) => break,
+                        Ok(n) => Ok(buf[..n].to_vec()),
+                        Err(ref e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                        Err(e) => Err(e),
+                    };
+                    let len = chunk.as_ref().map_or(0, Vec::len) as u64;
+                    let failed = chunk.is_err();
+                    // The channel closes if the request fails, which then returns the error.
+                    if chunks.send(chunk).is_err() || failed {
+                        break;
+                    }
+                    sent += len;
+                    on_progress(UploadProgress {
+                        sent,
+                        total: length,
+                    });
+                }
+                drop(chunks);
+
+                let response = response.join().expect("the upload's thread panicked")?;
+                if response.status() == reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    let retry_after = response
+                        .headers()
+                        .get(reqwest::header::RETRY_AFTER)
+                        .and_then(|value| value.to_str().ok())
+                        .map(str::to_owned);
+                    let body = response.text()?;
+                    return Ok(crate::rate_limit::with_retry_after(
+                        body,
+                        retry_after.as_deref(),
+                    ));
+                }
+                // Unlike API methods, upload URLs answer failures with an error status.
+                response.error_for_status()?.text()
+            })
+        }
+    }
+
+    /// The body of an upload, as its chunks arrive from the thread reading them.
+    struct ChunkReader {
+        chunks: Receiver<io::Result<Vec<u8>>>,
+        chunk: Cursor<Vec<u8>>,
+    }
+
+    impl Read for ChunkReader {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            loop {
+                let n = self.chunk.read(buf)?;
+                if n > 0 || buf.is_empty() {
+                    return Ok(n);
+                }
+                match self.chunks.recv() {
+                    Ok(chunk) => self.chunk = Cursor::new(chunk?),
+                    Err(_) => return Ok(0),
+                }
+            }
         }
     }
 
     /// Provides a default `reqwest` client to give to the API functions to send requests.
     ///
+    /// The client identifies itself as this crate in its `User-Agent` and asks for gzip or
+    /// brotli compressed responses, decompressing them. It keeps
+    /// connections alive and pools them, so reuse it (or clones of it, which share the pool)
+    /// rather than creating one per request. Use [`DefaultClientBuilder`] to tune
+    /// the pool.
+    ///
     /// # Examples
     ///
     /// ```
@@ -60,9 +359,564 @@ mod reqwest_support {
     /// let response = slack_api::channels::list(&client, &token, &params);
     /// ```
     pub fn default_client() -> Result<Client, reqwest::Error> {
-        Ok(Client::new())
+        DefaultClientBuilder::new().build()
+    }
+
+    /// Configures the connection pool of the default `reqwest` client.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// let client = slack_api::sync::requests::DefaultClientBuilder::new()
+    ///     .pool_max_idle_per_host(4)
+    ///     .timeout(Duration::from_secs(10))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[derive(Clone, Debug)]
+    pub struct DefaultClientBuilder {
+        pool_max_idle_per_host: usize,
+        pool_idle_timeout: Option<Duration>,
+        tcp_keepalive: Option<Duration>,
+        timeout: Option<Duration>,
+        user_agent: String,
+    }
+
+    impl DefaultClientBuilder {
+        pub fn new() -> Self {
+            DefaultClientBuilder {
+                pool_max_idle_per_host: 32,
+                pool_idle_timeout: Some(Duration::from_secs(90)),
+                tcp_keepalive: Some(Duration::from_secs(60)),
+                timeout: None,
+                user_agent: crate::USER_AGENT.to_owned(),
+            }
+        }
+
+        /// The most idle connections kept open to Slack. Defaults to 32.
+        pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+            self.pool_max_idle_per_host = max;
+            self
+        }
+
+        /// How long an idle connection is kept before closing it, or `None` to keep it
+        /// indefinitely. Defaults to 90 seconds.
+        pub fn pool_idle_timeout<D: Into<Option<Duration>>>(mut self, timeout: D) -> Self {
+            self.pool_idle_timeout = timeout.into();
+            self
+        }
+
+        /// The interval of TCP keepalive probes on open connections, or `None` to disable them.
+        /// Defaults to 60 seconds.
+        pub fn tcp_keepalive<D: Into<Option<Duration>>>(mut self, interval: D) -> Self {
+            self.tcp_keepalive = interval.into();
+            self
+        }
+
+        /// A timeout for each whole request, from connecting until the body is read. Defaults to
+        /// `reqwest`'s own default.
+        pub fn timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = Some(timeout);
+            self
+        }
+
+        /// Identifies the app in the `User-Agent` header, after the crate's own name and version,
+        /// e.g. `"my-bot/1.2"`.
+        pub fn user_agent_suffix(mut self, suffix: &str) -> Self {
+            self.user_agent = format!("{} {}", crate::USER_AGENT, suffix);
+            self
+        }
+
+        pub fn build(self) -> Result<Client, reqwest::Error> {
+            let builder = Client::builder()
+                .user_agent(self.user_agent)
+                .gzip(true)
+                .brotli(true)
+                .pool_max_idle_per_host(self.pool_max_idle_per_host)
+                .pool_idle_timeout(self.pool_idle_timeout)
+                .tcp_keepalive(self.tcp_keepalive);
+            match self.timeout {
+                Some(timeout) => builder.timeout(timeout),
+                None => builder,
+            }
+            .build()
+        }
+    }
+
+    impl Default for DefaultClientBuilder {
+        fn default() -> Self {
+            DefaultClientBuilder::new()
+        }
     }
 }
 
 #[cfg(feature = "reqwest_blocking")]
 pub use self::reqwest_support::*;
+
+mod bounded {
+    use std::borrow::Borrow;
+    use std::error::Error;
+    use std::fmt;
+    use std::mem;
+    use std::sync::mpsc::{self, RecvTimeoutError};
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::SlackWebRequestSender;
+    use crate::CancelToken;
+
+    /// Wraps a sender to bound the time spent on Slack calls and to stop making them on request.
+    ///
+    /// A timeout bounds each call on its own, while a deadline is shared by every call made
+    /// through the wrapper. A blocking call in progress can't be interrupted, so the deadline and
+    /// token are checked before each call, and a call with a timeout is made on its own thread,
+    /// which is left to finish in the background if the timeout passes.
+    #[derive(Clone, Debug)]
+    pub struct Bounded<R> {
+        sender: R,
+        deadline: Option<Instant>,
+        timeout: Option<Duration>,
+        cancel: Option<CancelToken>,
+    }
+
+    impl<R> Bounded<R> {
+        pub fn new(sender: R) -> Self {
+            Bounded {
+                sender,
+                deadline: None,
+                timeout: None,
+                cancel: None,
+            }
+        }
+
+        /// Fails calls started after `deadline`.
+        pub fn deadline(mut self, deadline: Instant) -> Self {
+            self.deadline = Some(deadline);
+            self
+        }
+
+        /// Fails each call that hasn't finished within `timeout` of being sent.
+        pub fn timeout(mut self, timeout: Duration) -> Self {
+            self.timeout = Some(timeout);
+            self
+        }
+
+        /// Fails calls started once `token` is cancelled.
+        pub fn cancel_on(mut self, token: CancelToken) -> Self {
+            self.cancel = Some(token);
+            self
+        }
+
+        pub fn into_inner(self) -> R {
+            self.sender
+        }
+
+        /// Fails a call which is cancelled or past its deadline before being sent, returning the
+        /// call's timeout otherwise.
+        fn check(&self) -> Result<Option<Duration>, BoundedError<R::Error>>
+        where
+            R: SlackWebRequestSender,
+        {
+            if self.cancel.as_ref().is_some_and(CancelToken::is_cancelled) {
+                return Err(BoundedError::Cancelled);
+            }
+            if self
+                .deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+            {
+                return Err(BoundedError::DeadlineExceeded);
+            }
+            Ok(self.timeout)
+        }
+    }
+
+    impl<R> SlackWebRequestSender for Bounded<R>
+    where
+        R: SlackWebRequestSender + Clone + Send + 'static,
+    {
+        type Error = BoundedError<R::Error>;
+
+        fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            let (_, body) = self.send_with_status(method_url, params)?;
+            Ok(body)
+        }
+
+        fn send_with_status<I, K, V, S>(
+            &self,
+            method_url: S,
+            params: I,
+        ) -> Result<(Option<u16>, String), Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            let timeout = match self.check()? {
+                Some(timeout) => timeout,
+                None => {
+                    return self
+                        .sender
+                        .send_with_status(method_url, params)
+                        .map_err(BoundedError::Sender)
+                }
+            };
+
+            let sender = self.sender.clone();
+            let method_url = method_url.as_ref().to_owned();
+            let params = crate::rate_limit::owned_params(params);
+            let (result, received) = mpsc::sync_channel(1);
+            thread::spawn(move || {
+                // Nobody's waiting for the result once the timeout has passed.
+                let _ = result.send(sender.send_with_status(method_url, &params));
+            });
+            match received.recv_timeout(timeout) {
+                Ok(result) => result.map_err(BoundedError::Sender),
+                Err(RecvTimeoutError::Timeout) => Err(BoundedError::DeadlineExceeded),
+                Err(RecvTimeoutError::Disconnected) => panic!("the Slack call panicked"),
+            }
+        }
+
+        fn send_into<I, K, V, S>(
+            &self,
+            method_url: S,
+            params: I,
+            body: &mut Vec<u8>,
+        ) -> Result<Option<u16>, Self::Error>
+        where
+            I: IntoIterator + Send,
+            K: AsRef<str>,
+            V: AsRef<str>,
+            I::Item: Borrow<(K, V)>,
+            S: AsRef<str> + Send,
+        {
+            let timeout = match self.check()? {
+                Some(timeout) => timeout,
+                None => {
+                    return self
+                        .sender
+                        .send_into(method_url, params, body)
+                        .map_err(BoundedError::Sender)
+                }
+            };
+
+            let sender = self.sender.clone();
+            let method_url = method_url.as_ref().to_owned();
+            let params = crate::rate_limit::owned_params(params);
+            // The buffer goes with the call, and is lost if the call times out.
+            let mut buf = mem::take(body);
+            let (result, received) = mpsc::sync_channel(1);
+            thread::spawn(move || {
+                let status = sender.send_into(method_url, &params, &mut buf);
+                let _ = result.send((status, buf));
+            });
+            match received.recv_timeout(timeout) {
+                Ok((status, buf)) => {
+                    *body = buf;
+                    status.map_err(BoundedError::Sender)
+                }
+                Err(RecvTimeoutError::Timeout) => Err(BoundedError::DeadlineExceeded),
+                Err(RecvTimeoutError::Disconnected) => panic!("the Slack call panicked"),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    pub enum BoundedError<E> {
+        /// The deadline passed before the call was made, or the call's timeout before it finished
+        DeadlineExceeded,
+        /// The `CancelToken` was cancelled before the call was made
+        Cancelled,
+        /// The wrapped sender failed
+        Sender(E),
+    }
+
+    impl<E: Error> fmt::Display for BoundedError<E> {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            match *self {
+                BoundedError::DeadlineExceeded => write!(f, "deadline exceeded"),
+                BoundedError::Cancelled => write!(f, "cancelled"),
+                BoundedError::Sender(ref inner) => write!(f, "{}", inner),
+            }
+        }
+    }
+
+    impl<E: Error + 'static> Error for BoundedError<E> {
+        fn source(&self) -> Option<&(dyn Error + 'static)> {
+            match *self {
+                BoundedError::Sender(ref inner) => Some(inner),
+                _ => None,
+            }
+        }
+    }
+}
+
+pub use self::bounded::*;
+
+pub use crate::circuit_breaker::{CircuitBreaker, CircuitError, CircuitState};
+
+impl<R> SlackWebRequestSender for CircuitBreaker<R>
+where
+    R: SlackWebRequestSender + Send + Sync,
+    R::Error: 'static,
+{
+    type Error = CircuitError<R::Error>;
+
+    fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (_, body) = self.send_with_status(method_url, params)?;
+        Ok(body)
+    }
+
+    fn send_with_status<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let permit = self.permit().ok_or(CircuitError::Open)?;
+        let result = self.sender.send_with_status(method_url, params);
+        permit.record(&result);
+        result.map_err(CircuitError::Sender)
+    }
+
+    fn send_into<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let permit = self.permit().ok_or(CircuitError::Open)?;
+        let result = self.sender.send_into(method_url, params, body);
+        permit.record(&result.as_ref().map(|status| (*status, ())));
+        result.map_err(CircuitError::Sender)
+    }
+}
+
+pub use crate::rate_limit::{RateLimitRetry, RateLimitStats, RetryPolicy};
+
+impl<R> SlackWebRequestSender for RateLimitRetry<R>
+where
+    R: SlackWebRequestSender + Send + Sync,
+{
+    type Error = R::Error;
+
+    fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (_, body) = self.send_with_status(method_url, params)?;
+        Ok(body)
+    }
+
+    fn send_with_status<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let params = crate::rate_limit::owned_params(params);
+        let mut stats = RateLimitStats::new(method_url.as_ref());
+        loop {
+            let (status, body) = match self.sender.send_with_status(method_url.as_ref(), &params) {
+                Ok(response) => response,
+                Err(e) => {
+                    self.report(&stats);
+                    return Err(e);
+                }
+            };
+            match self.next_wait(&mut stats, &body) {
+                Some(wait) => thread::sleep(wait),
+                None => return Ok((status, body)),
+            }
+        }
+    }
+
+    fn send_into<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let params = crate::rate_limit::owned_params(params);
+        let mut stats = RateLimitStats::new(method_url.as_ref());
+        loop {
+            let status = match self.sender.send_into(method_url.as_ref(), &params, body) {
+                Ok(status) => status,
+                Err(e) => {
+                    self.report(&stats);
+                    return Err(e);
+                }
+            };
+            let wait = self.next_wait(&mut stats, &String::from_utf8_lossy(body));
+            match wait {
+                Some(wait) => thread::sleep(wait),
+                None => return Ok(status),
+            }
+        }
+    }
+}
+
+/// Uploads aren't retried, since their body can't be read again.
+impl<R> SlackWebUploadSender for RateLimitRetry<R>
+where
+    R: SlackWebUploadSender + Send + Sync,
+{
+    fn upload<B>(
+        &self,
+        url: &str,
+        content_type: &str,
+        length: u64,
+        body: B,
+        on_progress: &dyn Fn(UploadProgress),
+    ) -> Result<String, Self::Error>
+    where
+        B: Read,
+    {
+        self.sender
+            .upload(url, content_type, length, body, on_progress)
+    }
+}
+
+pub use crate::lossy::{DeserializeIssue, Lossy};
+
+impl<R> SlackWebRequestSender for Lossy<R>
+where
+    R: SlackWebRequestSender + Send + Sync,
+{
+    type Error = R::Error;
+
+    fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (_, body) = self.send_with_status(method_url, params)?;
+        Ok(body)
+    }
+
+    fn send_with_status<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (status, body) = self.sender.send_with_status(method_url.as_ref(), params)?;
+        Ok((status, self.skip_invalid(method_url.as_ref(), body)))
+    }
+}
+
+#[cfg(feature = "log")]
+pub use crate::logged::Logged;
+
+#[cfg(feature = "log")]
+impl<R> SlackWebRequestSender for Logged<R>
+where
+    R: SlackWebRequestSender + Send + Sync,
+{
+    type Error = R::Error;
+
+    fn send<I, K, V, S>(&self, method_url: S, params: I) -> Result<String, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let (_, body) = self.send_with_status(method_url, params)?;
+        Ok(body)
+    }
+
+    fn send_with_status<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+    ) -> Result<(Option<u16>, String), Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let params = self.log_request(method_url.as_ref(), params);
+        let result = self.sender.send_with_status(method_url, &params);
+        self.log_response(&result);
+        result
+    }
+
+    fn send_into<I, K, V, S>(
+        &self,
+        method_url: S,
+        params: I,
+        body: &mut Vec<u8>,
+    ) -> Result<Option<u16>, Self::Error>
+    where
+        I: IntoIterator + Send,
+        K: AsRef<str>,
+        V: AsRef<str>,
+        I::Item: Borrow<(K, V)>,
+        S: AsRef<str> + Send,
+    {
+        let params = self.log_request(method_url.as_ref(), params);
+        let result = self.sender.send_into(method_url, &params, body);
+        self.log_response(&result.as_ref().map(|status| (*status, &body[..])));
+        result
+    }
+}
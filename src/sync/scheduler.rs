@@ -0,0 +1,120 @@
+//! Schedules messages for a time on a user's clock, and lists or cancels a channel's scheduled
+//! messages.
+
+pub use crate::scheduler_types::*;
+
+use super::requests::SlackWebRequestSender;
+use crate::mod_types::chat_scheduled_messages_types::{ListError, ListRequest};
+use crate::mod_types::chat_types::{
+    DeleteScheduledMessageRequest, ScheduleMessageRequest, ScheduleMessageResponse,
+};
+use crate::mod_types::users_types::InfoRequest;
+use crate::sync::{chat, chat_scheduled_messages, users};
+use crate::ScheduledMessage;
+
+/// Schedules a message for `at` on `user`'s clock, looking up their timezone with `users.info`.
+///
+/// The `post_at` of `request` is replaced. If its `channel` is empty, the message is sent to the
+/// user as a direct message from the app.
+pub fn schedule_in_user_tz<R>(
+    client: &R,
+    token: &str,
+    user: &str,
+    at: LocalDateTime,
+    request: &ScheduleMessageRequest<'_>,
+) -> Result<ScheduleMessageResponse, ScheduleInUserTzError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let info = users::info(
+        client,
+        token,
+        &InfoRequest {
+            user,
+            include_locale: None,
+        },
+    )
+    .map_err(ScheduleInUserTzError::UserInfo)?;
+    let post_at = match info.user {
+        Some(ref user) => at.post_at_for(user),
+        None => Err(PostAtError::NoTimezone),
+    }
+    .map_err(ScheduleInUserTzError::PostAt)?;
+    let channel = if request.channel.is_empty() {
+        user
+    } else {
+        request.channel
+    };
+    chat::schedule_message(
+        client,
+        token,
+        &ScheduleMessageRequest {
+            channel,
+            post_at,
+            ..request.clone()
+        },
+    )
+    .map_err(ScheduleInUserTzError::Schedule)
+}
+
+/// Lists every message scheduled in `channel`, following the cursor through each page.
+pub fn list_by_channel<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<Vec<ScheduledMessage>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut messages = Vec::new();
+    let mut cursor = None;
+    loop {
+        let page = chat_scheduled_messages::list(
+            client,
+            token,
+            &ListRequest {
+                channel: Some(channel),
+                cursor: cursor.as_ref(),
+                ..ListRequest::default()
+            },
+        )?;
+        messages.extend(page.scheduled_messages.into_iter().flatten());
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(messages);
+        }
+    }
+}
+
+/// Deletes every message scheduled in `channel`, carrying on past messages which can't be
+/// deleted.
+pub fn cancel_by_channel<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+) -> Result<CancelReport<R::Error>, ListError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut report = CancelReport::default();
+    for message in list_by_channel(client, token, channel)? {
+        let id = match message.id {
+            Some(id) => id,
+            None => continue,
+        };
+        let result = chat::delete_scheduled_message(
+            client,
+            token,
+            &DeleteScheduledMessageRequest {
+                channel,
+                scheduled_message_id: &id,
+                as_user: None,
+            },
+        );
+        match result {
+            Ok(_) => report.cancelled.push(id),
+            Err(e) => report.failed.push((id, e)),
+        }
+    }
+    Ok(report)
+}
@@ -0,0 +1,233 @@
+//! Parsing list responses item by item as the body arrives.
+//!
+//! A multi-hundred-megabyte `conversations.history` export doesn't need to be held in memory:
+//! [`SlackClient::stream_method`](crate::sync::SlackClient::stream_method) hands each item of a
+//! list to a callback as soon as it's decoded, keeping only the item being parsed.
+//!
+//! Streaming needs a sender that implements
+//! [`SlackWebStreamSender`](crate::sync::requests::SlackWebStreamSender). There is no async
+//! equivalent yet, since `serde_json` can only parse incrementally from a blocking reader.
+
+use crate::ResponseMetadata;
+use serde::de::{self, DeserializeOwned, DeserializeSeed, IgnoredAny, MapAccess, SeqAccess};
+use std::error::Error;
+use std::fmt;
+use std::io::Read;
+use std::marker::PhantomData;
+
+#[derive(Debug)]
+pub enum StreamError<E: Error> {
+    /// The request didn't serialize to a JSON object of parameters
+    InvalidRequest(serde_json::error::Error),
+    /// The response was not parseable as the expected list
+    MalformedResponse(serde_json::error::Error),
+    /// The response returned an error, given as Slack's error code
+    Slack(String),
+    /// The client had an error sending the request to Slack
+    Client(E),
+}
+
+impl<E: Error> fmt::Display for StreamError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            StreamError::InvalidRequest(ref e) => write!(f, "invalid request: {}", e),
+            StreamError::MalformedResponse(ref e) => write!(f, "{}", e),
+            StreamError::Slack(ref s) => write!(f, "{}", s),
+            StreamError::Client(ref inner) => write!(f, "{}", inner),
+        }
+    }
+}
+
+impl<E: Error + 'static> Error for StreamError<E> {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            StreamError::InvalidRequest(ref e) => Some(e),
+            StreamError::MalformedResponse(ref e) => Some(e),
+            StreamError::Client(ref inner) => Some(inner),
+            StreamError::Slack(_) => None,
+        }
+    }
+}
+
+/// What's left of a streamed page once its items have been handed out.
+#[derive(Clone, Debug)]
+pub struct StreamedPage {
+    pub response_metadata: Option<ResponseMetadata>,
+}
+
+#[derive(Default)]
+struct Summary {
+    ok: bool,
+    error: Option<String>,
+    response_metadata: Option<ResponseMetadata>,
+}
+
+/// Walks the top-level response object, streaming the `field` list into `on_item`.
+struct Page<'f, T, F> {
+    field: &'f str,
+    on_item: F,
+    item: PhantomData<T>,
+}
+
+impl<'de, 'f, T, F> de::Visitor<'de> for Page<'f, T, F>
+where
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    type Value = Summary;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a Slack response object")
+    }
+
+    fn visit_map<A>(mut self, mut map: A) -> Result<Summary, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut summary = Summary::default();
+        while let Some(key) = map.next_key::<String>()? {
+            match &key[..] {
+                "ok" => summary.ok = map.next_value()?,
+                "error" => summary.error = map.next_value()?,
+                "response_metadata" => summary.response_metadata = map.next_value()?,
+                field if field == self.field => map.next_value_seed(Items {
+                    on_item: &mut self.on_item,
+                    item: PhantomData,
+                })?,
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        Ok(summary)
+    }
+}
+
+impl<'de, 'f, T, F> DeserializeSeed<'de> for Page<'f, T, F>
+where
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    type Value = Summary;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Summary, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_map(self)
+    }
+}
+
+struct Items<'a, T, F> {
+    on_item: &'a mut F,
+    item: PhantomData<T>,
+}
+
+impl<'de, 'a, T, F> de::Visitor<'de> for Items<'a, T, F>
+where
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a list")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<(), A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        while let Some(item) = seq.next_element::<T>()? {
+            (self.on_item)(item);
+        }
+        Ok(())
+    }
+}
+
+impl<'de, 'a, T, F> DeserializeSeed<'de> for Items<'a, T, F>
+where
+    T: DeserializeOwned,
+    F: FnMut(T),
+{
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+/// Parses a response from `body`, handing each item of its `field` list to `on_item`.
+///
+/// Items are handed out before Slack's `ok` flag is known if it comes later in the body, but an
+/// error response carries no list to stream.
+pub(crate) fn parse_stream<B, T, F, E>(
+    body: B,
+    field: &str,
+    on_item: F,
+) -> Result<StreamedPage, StreamError<E>>
+where
+    B: Read,
+    T: DeserializeOwned,
+    F: FnMut(T),
+    E: Error,
+{
+    let mut deserializer = serde_json::Deserializer::from_reader(body);
+    let summary = Page {
+        field,
+        on_item,
+        item: PhantomData,
+    }
+    .deserialize(&mut deserializer)
+    .and_then(|summary| deserializer.end().map(|()| summary))
+    .map_err(StreamError::MalformedResponse)?;
+    if summary.ok {
+        Ok(StreamedPage {
+            response_metadata: summary.response_metadata,
+        })
+    } else {
+        Err(StreamError::Slack(summary.error.unwrap_or_default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_stream_hands_out_items() {
+        let body = br#"{"ok": true, "messages": [
+            {"type": "message", "user": "U1", "text": "one", "ts": "1.000000"},
+            {"type": "message", "user": "U2", "text": "two", "ts": "2.000000"}
+        ], "has_more": true, "response_metadata": {"next_cursor": "next"}}"#;
+        let mut texts = Vec::new();
+        let page = parse_stream::<_, serde_json::Value, _, std::io::Error>(
+            &body[..],
+            "messages",
+            |message| texts.push(message["text"].as_str().unwrap().to_owned()),
+        )
+        .unwrap();
+        assert_eq!(texts, ["one", "two"]);
+        assert_eq!(
+            page.response_metadata
+                .unwrap()
+                .next_cursor
+                .as_ref()
+                .map(crate::Cursor::as_str),
+            Some("next")
+        );
+    }
+
+    #[test]
+    fn test_parse_stream_reports_slack_errors() {
+        let body = br#"{"ok": false, "error": "channel_not_found"}"#;
+        match parse_stream::<_, serde_json::Value, _, std::io::Error>(&body[..], "messages", |_| {})
+        {
+            Err(StreamError::Slack(error)) => assert_eq!(error, "channel_not_found"),
+            other => panic!("expected a Slack error but got {:?}", other),
+        }
+    }
+}
@@ -0,0 +1,40 @@
+//! Fetching a whole thread at once, such as for bots which summarize threads.
+
+pub use crate::threads_types::*;
+
+use super::conversations;
+use super::requests::SlackWebRequestSender;
+use crate::Timestamp;
+
+/// Fetches the thread started by the message at `thread_ts`, with every page of its replies, the
+/// users who took part and the reactions to all of its messages.
+pub fn fetch<R>(
+    client: &R,
+    token: &str,
+    channel: &str,
+    thread_ts: Timestamp,
+) -> Result<Thread, conversations::RepliesError<R::Error>>
+where
+    R: SlackWebRequestSender,
+{
+    let mut cursor = None;
+    let mut messages = Vec::new();
+    loop {
+        let page = conversations::replies(
+            client,
+            token,
+            &conversations::RepliesRequest {
+                channel,
+                ts: thread_ts,
+                cursor: cursor.as_ref(),
+                limit: Some(200),
+                ..Default::default()
+            },
+        )?;
+        messages.extend(page.messages.unwrap_or_default());
+        cursor = page.response_metadata.and_then(|m| m.next_cursor);
+        if cursor.is_none() {
+            return Ok(Thread::from_messages(channel, thread_ts, messages));
+        }
+    }
+}
@@ -0,0 +1,176 @@
+//! Types shared by the helpers which fetch a whole thread.
+
+use std::collections::HashSet;
+
+use serde::Deserialize;
+
+use crate::events::message_author;
+use crate::{Message, Reaction, Timestamp};
+
+/// A thread with all of its replies, as returned by `threads::fetch`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Thread {
+    pub channel: String,
+    pub thread_ts: Timestamp,
+    /// The message which started the thread.
+    pub parent: Option<Message>,
+    /// The replies, oldest first.
+    pub replies: Vec<Message>,
+    /// The users who posted the parent or a reply, in the order they first posted.
+    pub participants: Vec<String>,
+    /// The reactions to the parent and the replies, merged by emoji.
+    pub reactions: Vec<ThreadReaction>,
+}
+
+/// An emoji used to react to messages in a thread.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThreadReaction {
+    pub name: String,
+    /// How many times it was used, over all the messages.
+    pub count: u32,
+    /// The users who used it, each once, as far as Slack listed them.
+    pub users: Vec<String>,
+}
+
+impl Thread {
+    /// Assembles a thread from the messages of every page of `conversations.replies`, which
+    /// repeats the parent at the top of each page.
+    pub(crate) fn from_messages(
+        channel: &str,
+        thread_ts: Timestamp,
+        messages: Vec<Message>,
+    ) -> Self {
+        let mut seen = HashSet::new();
+        let mut messages = messages
+            .into_iter()
+            .filter(|message| match message_ts(message) {
+                Some(ts) => seen.insert(ts),
+                None => true,
+            });
+        let parent = messages.next();
+        let replies: Vec<_> = messages.collect();
+
+        let mut participants: Vec<String> = Vec::new();
+        let mut reactions: Vec<ThreadReaction> = Vec::new();
+        for message in parent.iter().chain(&replies) {
+            if let Some(user) = message_author(message).0 {
+                if !participants.iter().any(|p| p == user) {
+                    participants.push(user.to_owned());
+                }
+            }
+            for reaction in message_reactions(message) {
+                let name = match reaction.name {
+                    Some(name) => name,
+                    None => continue,
+                };
+                let index = match reactions.iter().position(|r| r.name == name) {
+                    Some(index) => index,
+                    None => {
+                        reactions.push(ThreadReaction {
+                            name,
+                            ..Default::default()
+                        });
+                        reactions.len() - 1
+                    }
+                };
+                let merged = &mut reactions[index];
+                let users = reaction.users.unwrap_or_default();
+                merged.count += reaction
+                    .count
+                    .map_or(users.len() as u32, |c| c.max(0) as u32);
+                for user in users {
+                    if !merged.users.contains(&user) {
+                        merged.users.push(user);
+                    }
+                }
+            }
+        }
+
+        Thread {
+            channel: channel.to_owned(),
+            thread_ts,
+            parent,
+            replies,
+            participants,
+            reactions,
+        }
+    }
+}
+
+/// The timestamp of a message, for the kinds of messages posted in threads.
+pub(crate) fn message_ts(message: &Message) -> Option<Timestamp> {
+    match *message {
+        Message::Standard(ref m) => m.ts,
+        Message::BotMessage(ref m) => m.ts,
+        Message::FileShare(ref m) => m.ts,
+        Message::MeMessage(ref m) => m.ts,
+        Message::ThreadBroadcast(ref m) => m.ts,
+        Message::Tombstone(ref m) => m.ts,
+        Message::Other(ref value) => value
+            .get("ts")
+            .and_then(|ts| Timestamp::deserialize(ts).ok()),
+        _ => None,
+    }
+}
+
+/// The reactions to a message, for the kinds of messages which can have them.
+pub(crate) fn message_reactions(message: &Message) -> Vec<Reaction> {
+    let reactions = match *message {
+        Message::Standard(ref m) => &m.reactions,
+        Message::BotMessage(ref m) => &m.reactions,
+        Message::FileShare(ref m) => &m.reactions,
+        Message::MeMessage(ref m) => &m.reactions,
+        Message::ThreadBroadcast(ref m) => &m.reactions,
+        Message::Other(ref value) => {
+            return value
+                .get("reactions")
+                .and_then(|r| Vec::deserialize(r).ok())
+                .unwrap_or_default()
+        }
+        _ => return Vec::new(),
+    };
+    reactions.clone().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_thread_merges_pages() {
+        let messages: Vec<Message> = serde_json::from_value(serde_json::json!([
+            {"type": "message", "user": "U1", "text": "lunch?", "ts": "1.000000",
+             "reactions": [{"name": "taco", "count": 2, "users": ["U2", "U3"]}]},
+            {"type": "message", "user": "U2", "text": "yes", "ts": "2.000000",
+             "thread_ts": "1.000000",
+             "reactions": [{"name": "taco", "count": 1, "users": ["U3"]},
+                           {"name": "+1", "count": 1, "users": ["U1"]}]},
+            {"type": "message", "user": "U1", "text": "lunch?", "ts": "1.000000"},
+            {"type": "message", "user": "U1", "text": "noon", "ts": "3.000000",
+             "thread_ts": "1.000000"},
+        ]))
+        .unwrap();
+        let thread = Thread::from_messages("C1", Timestamp::from(1.0), messages);
+        assert_eq!(
+            message_ts(thread.parent.as_ref().unwrap()),
+            Some(thread.thread_ts)
+        );
+        assert_eq!(thread.replies.len(), 2);
+        assert_eq!(thread.participants, vec!["U1", "U2"]);
+        assert_eq!(
+            thread.reactions,
+            vec![
+                ThreadReaction {
+                    name: "taco".into(),
+                    count: 3,
+                    users: vec!["U2".into(), "U3".into()],
+                },
+                ThreadReaction {
+                    name: "+1".into(),
+                    count: 1,
+                    users: vec!["U1".into()],
+                },
+            ]
+        );
+    }
+}
@@ -73,10 +73,25 @@ impl<'de> ::serde::Deserialize<'de> for Timestamp {
     }
 }
 
+/// Written as Slack writes a message's `ts`, which reads back as the same timestamp.
+impl ::serde::Serialize for Timestamp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ::serde::Serializer,
+    {
+        serializer.collect_str(self)
+    }
+}
+
 impl Timestamp {
     pub fn to_param_value(self) -> String {
         format!("{}", self)
     }
+
+    /// Microseconds since the Unix epoch.
+    pub fn to_micros(self) -> u64 {
+        self.0
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,89 @@
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Deserializer};
+
+/// How long Slack accepts a trigger ID for after sending it.
+pub const TRIGGER_ID_LIFETIME: Duration = Duration::from_secs(3);
+
+/// A short-lived ID from a slash command or interaction, which lets the app open a modal with
+/// `views.open` or a dialog with `dialog.open`.
+///
+/// It remembers when it was received, which for one deserialized from a payload is when the
+/// payload was parsed. Two trigger IDs are equal if their IDs are, whenever they were received.
+#[derive(Clone, Debug)]
+pub struct TriggerId {
+    id: String,
+    received_at: Instant,
+}
+
+impl TriggerId {
+    /// A trigger ID received just now.
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        TriggerId::received_at(id, Instant::now())
+    }
+
+    /// A trigger ID received at `received_at`, such as when the request carrying it arrived.
+    pub fn received_at<S: Into<String>>(id: S, received_at: Instant) -> Self {
+        TriggerId {
+            id: id.into(),
+            received_at,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.id
+    }
+
+    pub fn received(&self) -> Instant {
+        self.received_at
+    }
+
+    /// Whether the trigger ID is older than [`TRIGGER_ID_LIFETIME`], so Slack will probably
+    /// refuse it with `expired_trigger_id`. Slack's clock started when it sent the request, a
+    /// little before it was received, so one that isn't expired by this measure may still be.
+    pub fn is_probably_expired(&self) -> bool {
+        self.received_at.elapsed() >= TRIGGER_ID_LIFETIME
+    }
+}
+
+impl PartialEq for TriggerId {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+    }
+}
+
+impl Eq for TriggerId {}
+
+impl Hash for TriggerId {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state)
+    }
+}
+
+impl<'de> Deserialize<'de> for TriggerId {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(TriggerId::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_trigger_id_expiry() {
+        let fresh: TriggerId = serde_json::from_str(r#""13345224609.738474920.8088""#).unwrap();
+        assert_eq!(fresh.as_str(), "13345224609.738474920.8088");
+        assert!(!fresh.is_probably_expired());
+        let stale = TriggerId::received_at(
+            "13345224609.738474920.8088",
+            Instant::now() - Duration::from_secs(4),
+        );
+        assert!(stale.is_probably_expired());
+        assert_eq!(fresh, stale);
+    }
+}
@@ -14,7 +14,27 @@
 
 use std::collections::HashMap;
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Bookmark {
+    pub app_id: Option<String>,
+    pub channel_id: Option<String>,
+    pub date_created: Option<i64>,
+    pub date_updated: Option<i64>,
+    pub emoji: Option<String>,
+    pub entity_id: Option<String>,
+    pub icon_url: Option<String>,
+    pub id: Option<String>,
+    pub last_updated_by_team_id: Option<String>,
+    pub last_updated_by_user_id: Option<String>,
+    pub link: Option<String>,
+    pub parent_id: Option<String>,
+    pub rank: Option<String>,
+    pub shortcut_id: Option<String>,
+    pub title: Option<String>,
+    pub r#type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Bot {
     pub app_id: Option<String>,
     pub deleted: Option<bool>,
@@ -23,16 +43,32 @@ pub struct Bot {
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct BotIcons {
     pub image_36: Option<String>,
     pub image_48: Option<String>,
     pub image_72: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// The bot that posted a message, as Slack includes it in the message.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct BotProfile {
+    pub app_id: Option<String>,
+    pub deleted: Option<bool>,
+    pub icons: Option<BotIcons>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub team_id: Option<String>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
+    pub updated: Option<i64>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Channel {
     pub accepted_user: Option<String>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub created: Option<i32>,
     pub creator: Option<String>,
     pub id: Option<String>,
@@ -52,34 +88,133 @@ pub struct Channel {
     pub members: Option<Vec<String>>,
     pub name: Option<String>,
     pub name_normalized: Option<String>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub num_members: Option<i32>,
     pub previous_names: Option<Vec<String>>,
     pub priority: Option<f32>,
     pub purpose: Option<ChannelPurpose>,
     pub topic: Option<ChannelTopic>,
     pub unlinked: Option<i32>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub unread_count: Option<i32>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub unread_count_display: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Eq, Hash)]
 pub struct ChannelPurpose {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Eq, Hash)]
 pub struct ChannelTopic {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Conversation {
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
+    pub created: Option<i32>,
+    pub creator: Option<String>,
+    pub id: Option<String>,
+    pub is_archived: Option<bool>,
+    pub is_channel: Option<bool>,
+    pub is_general: Option<bool>,
+    pub is_group: Option<bool>,
+    pub is_im: Option<bool>,
+    pub is_member: Option<bool>,
+    pub is_mpim: Option<bool>,
+    pub is_open: Option<bool>,
+    pub is_org_shared: Option<bool>,
+    pub is_private: Option<bool>,
+    pub is_shared: Option<bool>,
+    pub last_read: Option<crate::Timestamp>,
+    pub latest: Option<crate::Message>,
+    pub locale: Option<crate::Locale>,
+    pub name: Option<String>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
+    pub num_members: Option<i32>,
+    pub priority: Option<f32>,
+    /// The channel's canvas and tabs.
+    pub properties: Option<ConversationProperties>,
+    pub purpose: Option<ConversationPurpose>,
+    pub topic: Option<ConversationTopic>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
+    pub unread_count: Option<i32>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
+    pub unread_count_display: Option<i32>,
+    pub user: Option<String>,
+}
+
+impl Conversation {
+    /// The file ID of the channel's canvas, if it has one.
+    pub fn canvas_id(&self) -> Option<&str> {
+        self.properties
+            .as_ref()?
+            .canvas
+            .as_ref()?
+            .file_id
+            .as_deref()
+    }
+}
+
+/// A channel's canvas.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ConversationCanvas {
+    /// The ID of the canvas' file, for the `canvases.*` and `files.*` methods.
+    pub file_id: Option<String>,
+    pub is_empty: Option<bool>,
+    pub quip_thread_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ConversationProperties {
+    pub canvas: Option<ConversationCanvas>,
+    /// The tabs shown at the top of the channel, such as its canvas and bookmarks.
+    pub tabs: Option<Vec<ConversationTab>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Eq, Hash)]
+pub struct ConversationPurpose {
+    pub creator: Option<String>,
+    pub last_set: Option<i32>,
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ConversationTab {
+    pub id: Option<String>,
+    pub label: Option<String>,
+    /// The kind of tab, such as `canvas`, `files` or `bookmarks`.
+    pub r#type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Eq, Hash)]
+pub struct ConversationTopic {
+    pub creator: Option<String>,
+    pub last_set: Option<i32>,
+    pub value: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct File {
+    /// Alt text describing an image.
+    pub alt_txt: Option<String>,
     pub channels: Option<Vec<String>>,
     pub comments_count: Option<i32>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub created: Option<i32>,
     pub display_as_bot: Option<bool>,
     pub edit_link: Option<String>,
@@ -107,26 +242,25 @@ pub struct File {
     pub preview_highlight: Option<String>,
     pub public_url_shared: Option<bool>,
     pub reactions: Option<Vec<crate::Reaction>>,
-    pub size: Option<i32>,
-    pub thumb_160: Option<String>,
-    pub thumb_360: Option<String>,
-    pub thumb_360_gif: Option<String>,
-    pub thumb_360_h: Option<i32>,
-    pub thumb_360_w: Option<i32>,
-    pub thumb_480: Option<String>,
-    pub thumb_480_h: Option<i32>,
-    pub thumb_480_w: Option<i32>,
-    pub thumb_64: Option<String>,
-    pub thumb_80: Option<String>,
+    pub shares: Option<FileShares>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
+    pub size: Option<i64>,
+    pub subtype: Option<String>,
+    /// The `thumb_*` fields, by size.
+    #[serde(flatten)]
+    pub thumbnails: crate::FileThumbnails,
     pub timestamp: Option<crate::Timestamp>,
     pub title: Option<String>,
+    /// The transcript of an audio or video clip.
+    pub transcription: Option<FileTranscription>,
     pub url_private: Option<String>,
     pub url_private_download: Option<String>,
     pub user: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct FileComment {
     pub comment: Option<String>,
     pub id: Option<String>,
@@ -135,8 +269,52 @@ pub struct FileComment {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FileShare {
+    pub channel_name: Option<String>,
+    pub latest_reply: Option<crate::Timestamp>,
+    pub reply_count: Option<i32>,
+    pub reply_users: Option<Vec<String>>,
+    pub reply_users_count: Option<i32>,
+    pub team_id: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
+    pub ts: Option<crate::Timestamp>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FileShares {
+    pub private: Option<HashMap<String, Vec<FileShare>>>,
+    pub public: Option<HashMap<String, Vec<FileShare>>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FileTranscription {
+    pub locale: Option<String>,
+    pub preview: Option<FileTranscriptionPreview>,
+    /// `"complete"`, `"processing"`, `"failed"` or `"none"`.
+    pub status: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FileTranscriptionPreview {
+    pub content: Option<String>,
+    pub has_more: Option<bool>,
+}
+
+/// Who can run a custom function or workflow.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct FunctionPermission {
+    pub channel_ids: Option<Vec<String>>,
+    pub org_ids: Option<Vec<String>>,
+    pub permission_type: Option<crate::FunctionVisibility>,
+    pub team_ids: Option<Vec<String>>,
+    pub user_ids: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Group {
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub created: Option<i32>,
     pub creator: Option<String>,
     pub id: Option<String>,
@@ -149,26 +327,32 @@ pub struct Group {
     pub name: Option<String>,
     pub purpose: Option<GroupPurpose>,
     pub topic: Option<GroupTopic>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub unread_count: Option<i32>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub unread_count_display: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GroupPurpose {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct GroupTopic {
     pub creator: Option<String>,
     pub last_set: Option<i32>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Im {
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub created: Option<i32>,
     pub id: Option<String>,
     pub is_im: Option<bool>,
@@ -176,7 +360,8 @@ pub struct Im {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
 pub enum Message {
     Standard(MessageStandard),
     BotAdd(MessageBotAdd),
@@ -191,6 +376,7 @@ pub enum Message {
     ChannelPurpose(MessageChannelPurpose),
     ChannelTopic(MessageChannelTopic),
     ChannelUnarchive(MessageChannelUnarchive),
+    EkmAccessDenied(MessageEkmAccessDenied),
     FileComment(MessageFileComment),
     FileMention(MessageFileMention),
     FileShare(MessageFileShare),
@@ -209,9 +395,12 @@ pub enum Message {
     ReminderAdd(MessageReminderAdd),
     ReplyBroadcast(MessageReplyBroadcast),
     ThreadBroadcast(MessageThreadBroadcast),
+    Tombstone(MessageTombstone),
     UnpinnedItem(MessageUnpinnedItem),
     ShRoomCreated(MessageShRoomCreated),
     SlackbotResponse(MessageSlackbotResponse),
+    /// A message of a subtype that isn't typed by this library.
+    Other(::serde_json::Value),
 }
 
 impl<'de> ::serde::Deserialize<'de> for Message {
@@ -221,182 +410,153 @@ impl<'de> ::serde::Deserialize<'de> for Message {
     {
         use ::serde::de::Error as SerdeError;
 
-        const VARIANTS: &[&str] = &[
-            "standard",
-            "bot_add",
-            "bot_disable",
-            "bot_enable",
-            "bot_remove",
-            "bot_message",
-            "channel_archive",
-            "channel_join",
-            "channel_leave",
-            "channel_name",
-            "channel_purpose",
-            "channel_topic",
-            "channel_unarchive",
-            "file_comment",
-            "file_mention",
-            "file_share",
-            "group_archive",
-            "group_join",
-            "group_leave",
-            "group_name",
-            "group_purpose",
-            "group_topic",
-            "group_unarchive",
-            "me_message",
-            "message_changed",
-            "message_deleted",
-            "message_replied",
-            "pinned_item",
-            "reminder_add",
-            "reply_broadcast",
-            "thread_broadcast",
-            "unpinned_item",
-            "sh_room_created",
-            "slackbot_response",
-        ];
-
         let value = ::serde_json::Value::deserialize(deserializer)?;
         if let Some(ty_val) = value.get("subtype") {
             if let Some(ty) = ty_val.as_str() {
                 match ty {
                     "standard" => ::serde_json::from_value::<MessageStandard>(value.clone())
                         .map(Message::Standard)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "bot_add" => ::serde_json::from_value::<MessageBotAdd>(value.clone())
                         .map(Message::BotAdd)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "bot_disable" => ::serde_json::from_value::<MessageBotDisable>(value.clone())
                         .map(Message::BotDisable)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "bot_enable" => ::serde_json::from_value::<MessageBotEnable>(value.clone())
                         .map(Message::BotEnable)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "bot_remove" => ::serde_json::from_value::<MessageBotRemove>(value.clone())
                         .map(Message::BotRemove)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "bot_message" => ::serde_json::from_value::<MessageBotMessage>(value.clone())
                         .map(Message::BotMessage)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "channel_archive" => {
                         ::serde_json::from_value::<MessageChannelArchive>(value.clone())
                             .map(Message::ChannelArchive)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "channel_join" => ::serde_json::from_value::<MessageChannelJoin>(value.clone())
                         .map(Message::ChannelJoin)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "channel_leave" => {
                         ::serde_json::from_value::<MessageChannelLeave>(value.clone())
                             .map(Message::ChannelLeave)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "channel_name" => ::serde_json::from_value::<MessageChannelName>(value.clone())
                         .map(Message::ChannelName)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "channel_purpose" => {
                         ::serde_json::from_value::<MessageChannelPurpose>(value.clone())
                             .map(Message::ChannelPurpose)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "channel_topic" => {
                         ::serde_json::from_value::<MessageChannelTopic>(value.clone())
                             .map(Message::ChannelTopic)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "channel_unarchive" => {
                         ::serde_json::from_value::<MessageChannelUnarchive>(value.clone())
                             .map(Message::ChannelUnarchive)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
+                    }
+                    "ekm_access_denied" => {
+                        ::serde_json::from_value::<MessageEkmAccessDenied>(value.clone())
+                            .map(Message::EkmAccessDenied)
+                            .map_err(D::Error::custom)
                     }
                     "file_comment" => ::serde_json::from_value::<MessageFileComment>(value.clone())
                         .map(Message::FileComment)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "file_mention" => ::serde_json::from_value::<MessageFileMention>(value.clone())
                         .map(Message::FileMention)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "file_share" => ::serde_json::from_value::<MessageFileShare>(value.clone())
                         .map(Message::FileShare)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "group_archive" => {
                         ::serde_json::from_value::<MessageGroupArchive>(value.clone())
                             .map(Message::GroupArchive)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "group_join" => ::serde_json::from_value::<MessageGroupJoin>(value.clone())
                         .map(Message::GroupJoin)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "group_leave" => ::serde_json::from_value::<MessageGroupLeave>(value.clone())
                         .map(Message::GroupLeave)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "group_name" => ::serde_json::from_value::<MessageGroupName>(value.clone())
                         .map(Message::GroupName)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "group_purpose" => {
                         ::serde_json::from_value::<MessageGroupPurpose>(value.clone())
                             .map(Message::GroupPurpose)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "group_topic" => ::serde_json::from_value::<MessageGroupTopic>(value.clone())
                         .map(Message::GroupTopic)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "group_unarchive" => {
                         ::serde_json::from_value::<MessageGroupUnarchive>(value.clone())
                             .map(Message::GroupUnarchive)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "me_message" => ::serde_json::from_value::<MessageMeMessage>(value.clone())
                         .map(Message::MeMessage)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "message_changed" => {
                         ::serde_json::from_value::<MessageMessageChanged>(value.clone())
                             .map(Message::MessageChanged)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "message_deleted" => {
                         ::serde_json::from_value::<MessageMessageDeleted>(value.clone())
                             .map(Message::MessageDeleted)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "message_replied" => {
                         ::serde_json::from_value::<MessageMessageReplied>(value.clone())
                             .map(Message::MessageReplied)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "pinned_item" => ::serde_json::from_value::<MessagePinnedItem>(value.clone())
                         .map(Message::PinnedItem)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "reminder_add" => ::serde_json::from_value::<MessageReminderAdd>(value.clone())
                         .map(Message::ReminderAdd)
-                        .map_err(|e| D::Error::custom(&format!("{}", e))),
+                        .map_err(D::Error::custom),
                     "reply_broadcast" => {
                         ::serde_json::from_value::<MessageReplyBroadcast>(value.clone())
                             .map(Message::ReplyBroadcast)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "thread_broadcast" => {
                         ::serde_json::from_value::<MessageThreadBroadcast>(value.clone())
                             .map(Message::ThreadBroadcast)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
+                    "tombstone" => ::serde_json::from_value::<MessageTombstone>(value.clone())
+                        .map(Message::Tombstone)
+                        .map_err(D::Error::custom),
                     "unpinned_item" => {
                         ::serde_json::from_value::<MessageUnpinnedItem>(value.clone())
                             .map(Message::UnpinnedItem)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "sh_room_created" => {
                         ::serde_json::from_value::<MessageShRoomCreated>(value.clone())
                             .map(Message::ShRoomCreated)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
                     "slackbot_response" => {
                         ::serde_json::from_value::<MessageSlackbotResponse>(value.clone())
                             .map(Message::SlackbotResponse)
-                            .map_err(|e| D::Error::custom(&format!("{}", e)))
+                            .map_err(D::Error::custom)
                     }
-                    _ => Err(D::Error::unknown_variant(ty, VARIANTS)),
+                    _ => Ok(Message::Other(value)),
                 }
             } else {
                 Err(D::Error::invalid_type(
@@ -407,12 +567,12 @@ impl<'de> ::serde::Deserialize<'de> for Message {
         } else {
             ::serde_json::from_value::<MessageStandard>(value.clone())
                 .map(Message::Standard)
-                .map_err(|e| D::Error::custom(&format!("{}", e)))
+                .map_err(D::Error::custom)
         }
     }
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageBotAdd {
     pub bot_id: Option<String>,
     pub bot_link: Option<String>,
@@ -421,12 +581,11 @@ pub struct MessageBotAdd {
     pub team: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageBotDisable {
     pub bot_id: Option<String>,
     pub bot_link: Option<String>,
@@ -435,12 +594,11 @@ pub struct MessageBotDisable {
     pub team: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageBotEnable {
     pub bot_id: Option<String>,
     pub bot_link: Option<String>,
@@ -449,30 +607,33 @@ pub struct MessageBotEnable {
     pub team: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageBotMessage {
+    pub app_id: Option<String>,
     pub attachments: Option<Vec<MessageBotMessageAttachment>>,
+    #[serde(default, deserialize_with = "crate::blocks::known_blocks")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<String>,
+    pub bot_profile: Option<Box<BotProfile>>,
     pub channel: Option<String>,
     #[serde(deserialize_with = "crate::optional_struct_or_empty_array")]
     #[serde(default)]
     pub icons: Option<MessageBotMessageIcons>,
+    pub reactions: Option<Vec<crate::Reaction>>,
     pub subtype: Option<String>,
     pub team: Option<String>,
     pub text: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub username: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageBotMessageAttachment {
     pub author_icon: Option<String>,
     pub author_link: Option<String>,
@@ -491,14 +652,14 @@ pub struct MessageBotMessageAttachment {
     pub ts: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageBotMessageAttachmentField {
     pub short: Option<bool>,
     pub title: Option<String>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageBotMessageIcons {
     pub emoji: Option<String>,
     pub image_36: Option<String>,
@@ -507,7 +668,7 @@ pub struct MessageBotMessageIcons {
     pub image_72: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageBotRemove {
     pub bot_id: Option<String>,
     pub bot_link: Option<String>,
@@ -516,208 +677,207 @@ pub struct MessageBotRemove {
     pub team: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageChannelArchive {
     pub members: Option<Vec<String>>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageChannelJoin {
     pub inviter: Option<String>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageChannelLeave {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageChannelName {
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageChannelPurpose {
     pub purpose: Option<String>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageChannelTopic {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub topic: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageChannelUnarchive {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
+    pub user: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MessageEkmAccessDenied {
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub ts: Option<crate::Timestamp>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageFileComment {
     pub comment: Option<crate::FileComment>,
     pub file: Option<crate::File>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageFileMention {
     pub file: Option<crate::File>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageFileShare {
+    pub app_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::blocks::known_blocks")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    pub bot_id: Option<String>,
+    pub bot_profile: Option<Box<BotProfile>>,
     pub file: Option<crate::File>,
+    pub reactions: Option<Vec<crate::Reaction>>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub upload: Option<bool>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageGroupArchive {
     pub members: Option<Vec<String>>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageGroupJoin {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageGroupLeave {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageGroupName {
     pub name: Option<String>,
     pub old_name: Option<String>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageGroupPurpose {
     pub purpose: Option<String>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageGroupTopic {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub topic: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageGroupUnarchive {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMeMessage {
+    #[serde(default, deserialize_with = "crate::blocks::known_blocks")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub channel: Option<String>,
+    pub reactions: Option<Vec<crate::Reaction>>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageChanged {
     pub channel: Option<String>,
     pub event_ts: Option<crate::Timestamp>,
@@ -726,11 +886,10 @@ pub struct MessageMessageChanged {
     pub previous_message: Option<MessageMessageChangedPreviousMessage>,
     pub subtype: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageChangedMessage {
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageChangedMessageEdited>,
@@ -742,25 +901,24 @@ pub struct MessageMessageChangedMessage {
     pub text: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub unread_count: Option<i32>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageChangedMessageEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageChangedMessageReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageChangedPreviousMessage {
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageChangedPreviousMessageEdited>,
@@ -772,25 +930,24 @@ pub struct MessageMessageChangedPreviousMessage {
     pub text: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub unread_count: Option<i32>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageChangedPreviousMessageEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageChangedPreviousMessageReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageDeleted {
     pub channel: Option<String>,
     pub deleted_ts: Option<crate::Timestamp>,
@@ -799,11 +956,10 @@ pub struct MessageMessageDeleted {
     pub previous_message: Option<MessageMessageDeletedPreviousMessage>,
     pub subtype: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageDeletedPreviousMessage {
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageDeletedPreviousMessageEdited>,
@@ -815,25 +971,24 @@ pub struct MessageMessageDeletedPreviousMessage {
     pub text: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub unread_count: Option<i32>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageDeletedPreviousMessageEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageDeletedPreviousMessageReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageReplied {
     pub channel: Option<String>,
     pub event_ts: Option<crate::Timestamp>,
@@ -842,11 +997,10 @@ pub struct MessageMessageReplied {
     pub subtype: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageRepliedMessage {
     pub bot_id: Option<String>,
     pub edited: Option<MessageMessageRepliedMessageEdited>,
@@ -858,25 +1012,24 @@ pub struct MessageMessageRepliedMessage {
     pub text: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub unread_count: Option<i32>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageRepliedMessageEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageMessageRepliedMessageReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessagePinnedItem {
     pub channel: Option<String>,
     pub item: Option<MessagePinnedItemItem>,
@@ -884,39 +1037,36 @@ pub struct MessagePinnedItem {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessagePinnedItemItem {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageReminderAdd {
     pub channel: Option<String>,
     pub subtype: Option<String>,
     pub team: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageReplyBroadcast {
     pub attachments: Option<Vec<MessageReplyBroadcastAttachment>>,
     pub channel: Option<String>,
     pub event_ts: Option<crate::Timestamp>,
     pub subtype: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageReplyBroadcastAttachment {
     pub author_icon: Option<String>,
     pub author_link: Option<String>,
@@ -932,7 +1082,7 @@ pub struct MessageReplyBroadcastAttachment {
     pub ts: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageShRoomCreated {
     pub channel: Option<String>,
     pub no_notifications: Option<bool>,
@@ -941,12 +1091,11 @@ pub struct MessageShRoomCreated {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageShRoomCreatedRoot {
     pub app_id: Option<String>,
     pub channels: Option<Vec<String>>,
@@ -971,37 +1120,43 @@ pub struct MessageShRoomCreatedRoot {
     pub was_rejected: Option<bool>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageSlackbotResponse {
     pub channel: Option<String>,
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageStandard {
+    pub app_id: Option<String>,
     pub attachments: Option<Vec<MessageStandardAttachment>>,
+    #[serde(default, deserialize_with = "crate::blocks::known_blocks")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
     pub bot_id: Option<String>,
+    pub bot_profile: Option<Box<BotProfile>>,
     pub channel: Option<String>,
+    /// Identifies the message across redeliveries, for messages sent by Slack's clients or with
+    /// a `client_msg_id`.
+    pub client_msg_id: Option<String>,
     pub edited: Option<MessageStandardEdited>,
     pub event_ts: Option<crate::Timestamp>,
+    pub reactions: Option<Vec<crate::Reaction>>,
     pub reply_broadcast: Option<bool>,
     pub source_team: Option<String>,
     pub team: Option<String>,
     pub text: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageStandardAttachment {
     pub author_icon: Option<String>,
     pub author_link: Option<String>,
@@ -1020,31 +1175,36 @@ pub struct MessageStandardAttachment {
     pub ts: Option<crate::Timestamp>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageStandardAttachmentField {
     pub short: Option<bool>,
     pub title: Option<String>,
     pub value: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageStandardEdited {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageThreadBroadcast {
+    pub app_id: Option<String>,
+    #[serde(default, deserialize_with = "crate::blocks::known_blocks")]
+    pub blocks: Option<Vec<crate::blocks::Block>>,
+    pub bot_id: Option<String>,
+    pub bot_profile: Option<Box<BotProfile>>,
+    pub reactions: Option<Vec<crate::Reaction>>,
     pub root: Option<MessageThreadBroadcastRoot>,
     pub subtype: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageThreadBroadcastRoot {
     pub latest_reply: Option<crate::Timestamp>,
     pub replies: Option<Vec<MessageThreadBroadcastRootReply>>,
@@ -1055,17 +1215,31 @@ pub struct MessageThreadBroadcastRoot {
     pub text: Option<String>,
     pub thread_ts: Option<crate::Timestamp>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageThreadBroadcastRootReply {
     pub ts: Option<crate::Timestamp>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// Stands in for the deleted parent of a thread which still has replies.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct MessageTombstone {
+    pub hidden: Option<bool>,
+    pub latest_reply: Option<crate::Timestamp>,
+    pub reply_count: Option<i32>,
+    pub reply_users: Option<Vec<String>>,
+    pub subtype: Option<String>,
+    pub text: Option<String>,
+    pub thread_ts: Option<crate::Timestamp>,
+    pub ts: Option<crate::Timestamp>,
+    pub r#type: Option<String>,
+    pub user: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageUnpinnedItem {
     pub channel: Option<String>,
     pub item: Option<MessageUnpinnedItemItem>,
@@ -1073,16 +1247,17 @@ pub struct MessageUnpinnedItem {
     pub subtype: Option<String>,
     pub text: Option<String>,
     pub ts: Option<crate::Timestamp>,
-    #[serde(rename = "type")]
-    pub ty: Option<String>,
+    pub r#type: Option<String>,
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct MessageUnpinnedItemItem {}
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Mpim {
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub created: Option<i32>,
     pub creator: Option<String>,
     pub id: Option<String>,
@@ -1092,26 +1267,58 @@ pub struct Mpim {
     pub latest: Option<crate::Message>,
     pub members: Option<Vec<String>>,
     pub name: Option<String>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub unread_count: Option<i32>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub unread_count_display: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// Where a channel shared by Slack Connect was originally hosted, from before Enterprise Key
+/// Management was enabled.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct OriginalConnectedChannel {
+    pub id: Option<String>,
+    pub internal_team_ids: Option<Vec<String>>,
+    pub original_connected_channel_id: Option<String>,
+    pub original_connected_host_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Eq, Hash)]
 pub struct Paging {
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub count: Option<i32>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub page: Option<i32>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub pages: Option<i32>,
+    #[serde(deserialize_with = "crate::de::optional_string_or_number")]
+    #[serde(default)]
     pub total: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Eq, Hash)]
 pub struct Reaction {
     pub count: Option<i32>,
     pub name: Option<String>,
     pub users: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize, Eq, Hash)]
+pub struct ResponseMetadata {
+    /// Details of the `warning` or `error` a method returned.
+    pub messages: Option<Vec<String>>,
+    #[serde(deserialize_with = "crate::cursor::next_cursor")]
+    #[serde(default)]
+    pub next_cursor: Option<crate::Cursor>,
+    pub warnings: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Reminder {
     pub complete_ts: Option<crate::Timestamp>,
     pub creator: Option<String>,
@@ -1122,46 +1329,104 @@ pub struct Reminder {
     pub user: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+/// A message waiting to be posted, from `chat.scheduledMessages.list`.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ScheduledMessage {
+    pub channel_id: Option<String>,
+    pub date_created: Option<crate::Timestamp>,
+    pub id: Option<String>,
+    pub post_at: Option<crate::Timestamp>,
+    pub text: Option<String>,
+}
+
+/// The device and client a session was created or last used from.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SessionDevice {
+    pub device_hardware: Option<String>,
+    pub ip: Option<String>,
+    pub os: Option<String>,
+    pub os_version: Option<String>,
+    pub slack_client_version: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SessionSettings {
+    #[serde(default)]
+    pub desktop_app_browser_quit: bool,
+    /// How long the user's sessions last, if it's set for them rather than the organization.
+    #[serde(default, deserialize_with = "crate::optional_duration_secs")]
+    pub duration: Option<std::time::Duration>,
+    pub user_id: Option<String>,
+}
+
+/// A custom function defined by an app.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct SlackFunction {
+    pub app_id: Option<String>,
+    pub callback_id: Option<String>,
+    pub date_created: Option<crate::Timestamp>,
+    pub date_updated: Option<crate::Timestamp>,
+    pub description: Option<String>,
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub r#type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Team {
+    pub default_channels: Option<Vec<String>>,
     pub domain: Option<String>,
     pub email_domain: Option<String>,
+    pub enterprise_id: Option<String>,
+    pub enterprise_name: Option<String>,
     pub icon: Option<TeamIcon>,
     pub id: Option<String>,
     pub name: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct TeamIcon {
     pub image_102: Option<String>,
     pub image_132: Option<String>,
+    pub image_230: Option<String>,
     pub image_34: Option<String>,
     pub image_44: Option<String>,
     pub image_68: Option<String>,
     pub image_88: Option<String>,
     pub image_default: Option<bool>,
+    pub image_original: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct ThreadInfo {
     pub complete: Option<bool>,
     pub count: Option<i32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct User {
     pub color: Option<String>,
     pub deleted: Option<bool>,
     pub has_2fa: Option<bool>,
     pub id: Option<String>,
+    #[serde(deserialize_with = "crate::de::optional_lenient_bool")]
+    #[serde(default)]
     pub is_admin: Option<bool>,
     pub is_app_user: Option<bool>,
     pub is_bot: Option<bool>,
+    #[serde(deserialize_with = "crate::de::optional_lenient_bool")]
+    #[serde(default)]
     pub is_owner: Option<bool>,
+    #[serde(deserialize_with = "crate::de::optional_lenient_bool")]
+    #[serde(default)]
     pub is_primary_owner: Option<bool>,
+    #[serde(deserialize_with = "crate::de::optional_lenient_bool")]
+    #[serde(default)]
     pub is_restricted: Option<bool>,
+    #[serde(deserialize_with = "crate::de::optional_lenient_bool")]
+    #[serde(default)]
     pub is_ultra_restricted: Option<bool>,
-    pub locale: Option<String>,
+    pub locale: Option<crate::Locale>,
     pub name: Option<String>,
     pub profile: Option<crate::UserProfile>,
     pub real_name: Option<String>,
@@ -1173,7 +1438,7 @@ pub struct User {
     pub updated: Option<f32>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct Usergroup {
     pub auto_type: Option<String>,
     pub created_by: Option<String>,
@@ -1193,13 +1458,13 @@ pub struct Usergroup {
     pub user_count: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UsergroupPrefs {
     pub channels: Option<Vec<String>>,
     pub groups: Option<Vec<String>>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UserProfile {
     pub avatar_hash: Option<String>,
     pub display_name: Option<String>,
@@ -1228,9 +1493,80 @@ pub struct UserProfile {
     pub title: Option<String>,
 }
 
-#[derive(Clone, Debug, Deserialize)]
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
 pub struct UserProfileFields {
     pub alt: Option<String>,
     pub label: Option<String>,
     pub value: Option<String>,
 }
+
+/// An active session of a user in an Enterprise Grid organization.
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct UserSession {
+    pub created: Option<SessionDevice>,
+    pub recent: Option<SessionDevice>,
+    pub session_id: Option<u64>,
+    pub team_id: Option<String>,
+    pub user_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct View {
+    pub app_id: Option<String>,
+    pub bot_id: Option<String>,
+    pub callback_id: Option<String>,
+    pub external_id: Option<String>,
+    /// Changes whenever the view is updated; pass it back to guard against overwriting a newer
+    /// update.
+    pub hash: Option<String>,
+    pub id: Option<String>,
+    pub previous_view_id: Option<String>,
+    pub private_metadata: Option<String>,
+    pub root_view_id: Option<String>,
+    pub team_id: Option<String>,
+    pub r#type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct Workflow {
+    pub app_id: Option<String>,
+    pub collaborators: Option<Vec<String>>,
+    pub date_updated: Option<crate::Timestamp>,
+    pub description: Option<String>,
+    pub id: Option<String>,
+    #[serde(default)]
+    pub is_published: bool,
+    pub last_updated_by: Option<String>,
+    pub source: Option<String>,
+    pub title: Option<String>,
+    pub trigger_ids: Option<Vec<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct WorkflowPermission {
+    /// Whether every trigger of the workflow was considered, or only the first
+    /// `max_workflow_triggers`.
+    #[serde(default)]
+    pub complete: bool,
+    pub who_can_run: Option<FunctionPermission>,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListResponseItemFile {
+    pub file: crate::File,
+    pub r#type: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListResponseItemFileComment {
+    pub comment: crate::FileComment,
+    pub file: crate::File,
+    pub r#type: String,
+}
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Serialize)]
+pub struct ListResponseItemMessage {
+    pub channel: String,
+    pub message: crate::Message,
+    pub r#type: String,
+}
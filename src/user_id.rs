@@ -0,0 +1,25 @@
+use std::fmt;
+
+/// The ID of a user, such as `U0123ABCD`, or `W0123ABCD` for an Enterprise Grid user.
+///
+/// Helpers acting on several users take their IDs as this rather than as strings, so that a
+/// channel ID can't be passed in their place.
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[serde(transparent)]
+pub struct UserId(String);
+
+impl UserId {
+    pub fn new<S: Into<String>>(id: S) -> Self {
+        UserId(id.into())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for UserId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
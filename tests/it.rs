@@ -0,0 +1,232 @@
+//! Calls against a real workspace, exercising the whole stack from form encoding to rate limiting.
+//!
+//! These create, change and archive channels, so run them against a workspace kept for testing,
+//! with a bot token having the `channels:manage`, `channels:read`, `chat:write`, `pins:read`,
+//! `pins:write`, `reactions:read` and `reactions:write` scopes:
+//!
+//! ```text
+//! SLACK_IT_TOKEN=xoxb-... cargo test --features it --test it
+//! ```
+//!
+//! Without `SLACK_IT_TOKEN` every test passes without calling Slack.
+
+use std::env;
+use std::error::Error;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde_json::{json, Value};
+use slack::requests::{default_client, RateLimitRetry, SlackWebRequestSender};
+use slack::{Message, SlackClient, Timestamp};
+use slack_api as slack;
+
+type Result<T> = std::result::Result<T, Box<dyn Error>>;
+
+/// A client for the test workspace, or `None` if `SLACK_IT_TOKEN` isn't set.
+///
+/// Calls Slack rate limits are retried, counting each retry in the returned counter.
+fn setup() -> Result<Option<(SlackClient<impl SlackWebRequestSender>, Arc<AtomicU32>)>> {
+    let token = match env::var("SLACK_IT_TOKEN") {
+        Ok(token) => token,
+        Err(_) => {
+            eprintln!("SLACK_IT_TOKEN isn't set, skipping");
+            return Ok(None);
+        }
+    };
+    let retries = Arc::new(AtomicU32::new(0));
+    let sender = {
+        let retries = retries.clone();
+        RateLimitRetry::new(default_client()?).on_rate_limited(move |stats| {
+            retries.fetch_add(stats.retries, Ordering::SeqCst);
+        })
+    };
+    Ok(Some((SlackClient::new(sender, token), retries)))
+}
+
+/// A channel name no earlier run has used.
+fn channel_name(test: &str) -> String {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap();
+    format!("it-{}-{}-{}", test, now.as_secs(), std::process::id())
+}
+
+async fn create_channel<R: SlackWebRequestSender>(
+    client: &SlackClient<R>,
+    name: &str,
+) -> Result<String> {
+    let created = slack::conversations::create(
+        client.sender(),
+        client.token(),
+        &slack::conversations::CreateRequest {
+            name,
+            ..Default::default()
+        },
+    )
+    .await?;
+    let channel = created.channel.ok_or("Expected the created channel")?;
+    assert_eq!(channel.name.as_deref(), Some(name));
+    Ok(channel.id.ok_or("Expected a channel id")?)
+}
+
+/// Archives the channel, which the crate doesn't wrap, through `call_method`.
+async fn archive_channel<R: SlackWebRequestSender>(
+    client: &SlackClient<R>,
+    channel: &str,
+) -> Result<()> {
+    let archived: Value = client
+        .call_method("conversations.archive", &json!({ "channel": channel }))
+        .await?;
+    assert_eq!(archived["ok"], true, "{}", archived);
+    Ok(())
+}
+
+fn message_text(message: &Message) -> Option<&str> {
+    match *message {
+        Message::Standard(ref m) => m.text.as_deref(),
+        _ => None,
+    }
+}
+
+#[tokio::test]
+async fn it_channel_lifecycle() -> Result<()> {
+    let (client, _) = match setup()? {
+        Some(setup) => setup,
+        None => return Ok(()),
+    };
+    let channel = create_channel(&client, &channel_name("lifecycle")).await?;
+    let result = channel_lifecycle(&client, &channel).await;
+    // Archive the channel whether or not the steps passed, so failed runs don't pile up channels.
+    archive_channel(&client, &channel).await?;
+    result
+}
+
+async fn channel_lifecycle<R: SlackWebRequestSender>(
+    client: &SlackClient<R>,
+    channel: &str,
+) -> Result<()> {
+    let (sender, token) = (client.sender(), client.token());
+
+    // Characters which need escaping in a form body must reach Slack intact.
+    let text = "it: a&b=c+d %20 ünïcødé 🚀 <not a link>";
+    let posted = slack::chat::post_message(
+        sender,
+        token,
+        &slack::chat::PostMessageRequest {
+            channel,
+            text,
+            ..Default::default()
+        },
+    )
+    .await?;
+    let ts: Timestamp = posted.ts.ok_or("Expected the posted message's ts")?;
+    let message = posted.message.ok_or("Expected the posted message")?;
+    assert_eq!(
+        message_text(&message),
+        Some("it: a&amp;b=c+d %20 ünïcødé 🚀 &lt;not a link&gt;")
+    );
+
+    let updated = slack::chat::update(
+        sender,
+        token,
+        &slack::chat::UpdateRequest {
+            channel,
+            ts,
+            text: "it: updated",
+            ..Default::default()
+        },
+    )
+    .await?;
+    assert_eq!(updated.ts, Some(ts));
+    assert_eq!(updated.text.as_deref(), Some("it: updated"));
+
+    slack::reactions::add(
+        sender,
+        token,
+        &slack::reactions::AddRequest {
+            name: "white_check_mark",
+            channel: Some(channel),
+            timestamp: Some(ts),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let reacted = slack::reactions::get(
+        sender,
+        token,
+        &slack::reactions::GetRequest {
+            channel: Some(channel),
+            timestamp: Some(ts),
+            full: Some(true),
+            ..Default::default()
+        },
+    )
+    .await?;
+    match reacted {
+        slack::reactions::GetResponse::Message(ref m) => match m.message {
+            Message::Standard(ref m) => {
+                let reactions = m.reactions.as_deref().unwrap_or_default();
+                assert!(reactions
+                    .iter()
+                    .any(|r| r.name.as_deref() == Some("white_check_mark")));
+            }
+            ref other => panic!("expected a standard message but got {:?}", other),
+        },
+        other => panic!("expected a message but got {:?}", other),
+    }
+
+    slack::pins::add(
+        sender,
+        token,
+        &slack::pins::AddRequest {
+            channel,
+            timestamp: Some(ts),
+            ..Default::default()
+        },
+    )
+    .await?;
+    let pins = slack::pins::list(sender, token, &slack::pins::ListRequest { channel })
+        .await?
+        .items
+        .unwrap_or_default();
+    assert_eq!(pins.len(), 1);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn it_post_burst_is_rate_limited_and_retried() -> Result<()> {
+    let (client, retries) = match setup()? {
+        Some(setup) => setup,
+        None => return Ok(()),
+    };
+    let channel = create_channel(&client, &channel_name("burst")).await?;
+    // Slack allows about one message a second per channel, so a concurrent burst is likely to be
+    // rate limited, and every message must still be posted.
+    let posts = (0..10).map(|i| {
+        let text = format!("it: burst {}", i);
+        let client = &client;
+        let channel = &channel;
+        async move {
+            slack::chat::post_message(
+                client.sender(),
+                client.token(),
+                &slack::chat::PostMessageRequest {
+                    channel,
+                    text: &text,
+                    ..Default::default()
+                },
+            )
+            .await
+        }
+    });
+    let results = futures_util::future::join_all(posts).await;
+    archive_channel(&client, &channel).await?;
+    for result in results {
+        result?;
+    }
+    eprintln!(
+        "{} rate limited calls were retried",
+        retries.load(Ordering::SeqCst)
+    );
+    Ok(())
+}
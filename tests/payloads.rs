@@ -0,0 +1,278 @@
+//! Deserializes a corpus of payloads captured from Slack, and property tests that deserializing
+//! `Message`, `Event` and `File` tolerates whatever else Slack might send: it may fail, but it
+//! mustn't panic, and fields the library doesn't know about must be ignored.
+//!
+//! Add a payload by saving it under `tests/payloads/<message|event|file>/`. The same corpus seeds
+//! the fuzz targets in `fuzz/`.
+
+use std::fmt::Debug;
+use std::fs;
+use std::path::Path;
+
+use proptest::prelude::*;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+use slack::events::Event;
+use slack::{File, Message};
+use slack_api as slack;
+
+/// The payloads in a directory of the corpus, by file name.
+fn corpus(kind: &str) -> Vec<(String, Value)> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/payloads")
+        .join(kind);
+    let mut payloads: Vec<_> = fs::read_dir(&dir)
+        .unwrap_or_else(|e| panic!("reading {}: {}", dir.display(), e))
+        .map(|entry| {
+            let path = entry.unwrap().path();
+            let json = fs::read_to_string(&path).unwrap();
+            let value = serde_json::from_str(&json)
+                .unwrap_or_else(|e| panic!("{} isn't JSON: {}", path.display(), e));
+            (
+                path.file_name().unwrap().to_string_lossy().into_owned(),
+                value,
+            )
+        })
+        .collect();
+    payloads.sort_by(|a, b| a.0.cmp(&b.0));
+    assert!(!payloads.is_empty(), "no payloads in {}", dir.display());
+    payloads
+}
+
+fn parse<T: DeserializeOwned>(name: &str, value: &Value) -> T {
+    serde_json::from_value(value.clone()).unwrap_or_else(|e| panic!("{}: {}", name, e))
+}
+
+#[test]
+fn test_corpus_messages() {
+    for (name, value) in corpus("message") {
+        let message: Message = parse(&name, &value);
+        let is_other = matches!(message, Message::Other(_));
+        // Subtypes the library doesn't type are kept as they were sent.
+        assert_eq!(is_other, name == "huddle_thread.json", "{}", name);
+    }
+}
+
+#[test]
+fn test_corpus_events() {
+    for (name, value) in corpus("event") {
+        let event: Event = parse(&name, &value);
+        assert_eq!(
+            event.event_type(),
+            value["type"].as_str(),
+            "{}: {:?}",
+            name,
+            event
+        );
+        let is_other = matches!(event, Event::Other(_));
+        assert_eq!(is_other, name == "user_huddle_changed.json", "{}", name);
+    }
+}
+
+#[test]
+fn test_corpus_files() {
+    for (name, value) in corpus("file") {
+        let file: File = parse(&name, &value);
+        assert_eq!(file.id.as_deref(), value["id"].as_str(), "{}", name);
+    }
+}
+
+/// Field names the types know, so that generated objects reach past the first field.
+const FIELDS: &[&str] = &[
+    "type",
+    "subtype",
+    "ts",
+    "thread_ts",
+    "event_ts",
+    "user",
+    "text",
+    "channel",
+    "message",
+    "previous_message",
+    "reactions",
+    "files",
+    "attachments",
+    "item",
+    "users",
+    "created",
+    "size",
+    "shares",
+    "transcription",
+    "thumb_64",
+    "thumb_360_w",
+];
+
+/// `type`s and `subtype`s which pick the type to deserialize into.
+const TAGS: &[&str] = &[
+    "message",
+    "app_mention",
+    "app_home_opened",
+    "member_joined_channel",
+    "presence_change",
+    "reaction_added",
+    "tokens_revoked",
+    "bot_message",
+    "file_share",
+    "message_changed",
+    "message_deleted",
+    "thread_broadcast",
+    "tombstone",
+];
+
+fn json() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        Just(Value::Null),
+        any::<bool>().prop_map(Value::from),
+        any::<i64>().prop_map(Value::from),
+        any::<f64>().prop_map(Value::from),
+        "[0-9]{1,10}(\\.[0-9]{0,6})?".prop_map(Value::from),
+        ".{0,12}".prop_map(Value::from),
+        prop::sample::select(TAGS).prop_map(Value::from),
+    ];
+    leaf.prop_recursive(4, 64, 8, |inner| {
+        let key = prop_oneof![
+            prop::sample::select(FIELDS).prop_map(str::to_owned),
+            "[a-z_]{1,10}"
+        ];
+        prop_oneof![
+            prop::collection::vec(inner.clone(), 0..8).prop_map(Value::from),
+            prop::collection::btree_map(key, inner, 0..8)
+                .prop_map(|fields| Value::Object(fields.into_iter().collect())),
+        ]
+    })
+}
+
+/// Deserializes the value into every type, from a `Value` and from its text.
+fn deserialize_all(value: &Value) {
+    fn both<T: DeserializeOwned>(value: &Value) {
+        let _ = serde_json::from_value::<T>(value.clone());
+        let _ = serde_json::from_str::<T>(&value.to_string());
+    }
+    both::<Message>(value);
+    both::<Event>(value);
+    both::<File>(value);
+}
+
+/// The JSON pointers of every value within `value`, itself included.
+fn pointers(value: &Value, pointer: String, found: &mut Vec<String>) {
+    match *value {
+        Value::Object(ref fields) => {
+            for (key, field) in fields {
+                let key = key.replace('~', "~0").replace('/', "~1");
+                pointers(field, format!("{}/{}", pointer, key), found);
+            }
+        }
+        Value::Array(ref items) => {
+            for (i, item) in items.iter().enumerate() {
+                pointers(item, format!("{}/{}", pointer, i), found);
+            }
+        }
+        _ => {}
+    }
+    found.push(pointer);
+}
+
+/// Checks that a changed payload, the second argument, deserializes the same as the original.
+type Same = fn(&Value, &Value);
+
+/// Every payload of the corpus, with the function checking it still deserializes the same.
+fn payloads() -> Vec<(Value, Same)> {
+    fn same<T: DeserializeOwned + Debug>(original: &Value, changed: &Value) {
+        let original: T = serde_json::from_value(original.clone()).unwrap();
+        let changed: T = serde_json::from_value(changed.clone())
+            .unwrap_or_else(|e| panic!("{}: {}", e, changed));
+        let (original, changed) = (format!("{:?}", original), format!("{:?}", changed));
+        // Untyped payloads are kept whole, so they keep the new field too.
+        if !original.starts_with("Other(") {
+            assert_eq!(original, changed);
+        }
+    }
+    let mut payloads: Vec<(_, Same)> = Vec::new();
+    for (_, value) in corpus("message") {
+        payloads.push((value, same::<Message>));
+    }
+    for (_, value) in corpus("event") {
+        payloads.push((value, same::<Event>));
+    }
+    for (_, value) in corpus("file") {
+        payloads.push((value, same::<File>));
+    }
+    payloads
+}
+
+proptest! {
+    #[test]
+    fn prop_arbitrary_json_never_panics(value in json()) {
+        deserialize_all(&value);
+    }
+
+    #[test]
+    fn prop_arbitrary_tagged_objects_never_panic(
+        ty in prop::sample::select(TAGS),
+        subtype in prop::option::of(prop::sample::select(TAGS)),
+        fields in prop::collection::btree_map(prop::sample::select(FIELDS), json(), 0..8),
+    ) {
+        let mut object: Map<String, Value> = fields
+            .into_iter()
+            .map(|(k, v)| (k.to_owned(), v))
+            .collect();
+        object.insert("type".into(), ty.into());
+        if let Some(subtype) = subtype {
+            object.insert("subtype".into(), subtype.into());
+        }
+        deserialize_all(&Value::Object(object));
+    }
+
+    #[test]
+    fn prop_unknown_fields_are_ignored(
+        payload in any::<prop::sample::Index>(),
+        key in "x_[a-z_]{1,10}",
+        extra in json(),
+    ) {
+        let payloads = payloads();
+        let (ref original, same) = payloads[payload.index(payloads.len())];
+        let mut changed = original.clone();
+        changed.as_object_mut().unwrap().insert(key, extra);
+        same(original, &changed);
+    }
+
+    #[test]
+    fn prop_dropped_fields_never_panic(
+        payload in any::<prop::sample::Index>(),
+        field in any::<prop::sample::Index>(),
+    ) {
+        let payloads = payloads();
+        let (ref original, _) = payloads[payload.index(payloads.len())];
+        let mut found = Vec::new();
+        pointers(original, String::new(), &mut found);
+        let pointer = &found[field.index(found.len())];
+        let mut changed = original.clone();
+        if let Some((parent, key)) = pointer.rsplit_once('/') {
+            match changed.pointer_mut(parent) {
+                Some(Value::Object(fields)) => {
+                    fields.remove(&key.replace("~1", "/").replace("~0", "~"));
+                }
+                Some(Value::Array(items)) => {
+                    items.remove(key.parse().unwrap());
+                }
+                _ => unreachable!(),
+            }
+        }
+        deserialize_all(&changed);
+    }
+
+    #[test]
+    fn prop_changed_fields_never_panic(
+        payload in any::<prop::sample::Index>(),
+        field in any::<prop::sample::Index>(),
+        replacement in json(),
+    ) {
+        let payloads = payloads();
+        let (ref original, _) = payloads[payload.index(payloads.len())];
+        let mut found = Vec::new();
+        pointers(original, String::new(), &mut found);
+        let mut changed = original.clone();
+        *changed.pointer_mut(&found[field.index(found.len())]).unwrap() = replacement;
+        deserialize_all(&changed);
+    }
+}